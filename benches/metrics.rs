@@ -0,0 +1,137 @@
+//! Rendering and data-path benchmarks over generated metrics
+//!
+//! Virtualized lists, render caches and damage tracking change how much work a
+//! frame does; these measure that work so a redesign that makes it slower
+//! shows up in criterion's comparison with the previous run:
+//!
+//! ```bash
+//! cargo bench
+//! ```
+
+use awscw::aws::metric_builder::DynamicMetricBuilder;
+use awscw::aws::metrics::generator::{Series, SeriesBuilder, ServiceMetricsBuilder};
+use awscw::aws::metrics::retention;
+use awscw::aws::metrics::types::ServiceMetrics;
+use awscw::config::Config;
+use awscw::models::{App, AwsService, MetricType};
+use awscw::ui::components::metric_list_utils::render_enhanced_metric_list;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use ratatui::{backend::TestBackend, Terminal};
+
+/// Series per resource in the data-path cases
+const METRICS: usize = 50;
+
+/// The RDS metrics, then generated names up to `METRICS` series
+fn metric_names() -> Vec<String> {
+    MetricType::ALL
+        .iter()
+        .map(|metric| metric.metric_name().to_string())
+        .chain((MetricType::ALL.len()..METRICS).map(|i| format!("Custom{i}")))
+        .collect()
+}
+
+/// `METRICS` series of `points` datapoints, seeded per metric
+fn service_metrics(points: usize, seed: u64) -> ServiceMetrics {
+    metric_names()
+        .iter()
+        .zip(seed..)
+        .fold(
+            ServiceMetricsBuilder::new(AwsService::Rds),
+            |builder, (name, seed)| {
+                let series = SeriesBuilder::new(points)
+                    .seed(seed)
+                    .baseline(50.0)
+                    .diurnal(20.0)
+                    .noise(0.1)
+                    .spikes(0.02, 3.0)
+                    .build();
+                builder.series(name, series)
+            },
+        )
+        .build()
+}
+
+/// A frame of the metrics grid filled with every metric it holds
+fn render_sparkline_grid(c: &mut Criterion) {
+    let mut app = App::new(Config::default());
+    app.view.metrics = DynamicMetricBuilder::new()
+        .build_from_service_metrics(service_metrics(retention::DEFAULT_MAX_POINTS, 1))
+        .expect("RDS provider is registered");
+    assert_eq!(app.get_available_metrics().len(), MetricType::ALL.len());
+
+    let mut terminal = Terminal::new(TestBackend::new(240, 80)).unwrap();
+    app.update_metrics_per_screen(80, 3);
+    c.bench_function("render sparkline grid", |b| {
+        b.iter(|| {
+            terminal
+                .draw(|f| render_enhanced_metric_list(f, &mut app, f.area()))
+                .unwrap();
+        })
+    });
+}
+
+/// A month of 30-second datapoints per metric capped at the default history size
+fn downsample_metrics(c: &mut Criterion) {
+    let series: Vec<Series> = (0..METRICS)
+        .map(|_| Series::spiky_latency(100_000))
+        .collect();
+    c.bench_function("downsample 50 metrics of 100k points", |b| {
+        b.iter_batched(
+            || series.clone(),
+            |series| {
+                for series in series {
+                    let (values, _) = retention::downsample(
+                        series.values,
+                        series.timestamps,
+                        retention::DEFAULT_MAX_POINTS,
+                    );
+                    black_box(values);
+                }
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+/// A refresh fetching half the metrics and passing the loaded series of the rest through
+fn merge_incremental_update(c: &mut Criterion) {
+    let names = metric_names();
+    let loaded = service_metrics(retention::DEFAULT_MAX_POINTS, 1);
+    let refreshed = service_metrics(retention::DEFAULT_MAX_POINTS * 2, 100);
+    c.bench_function("merge incremental update of 50 metrics", |b| {
+        b.iter(|| {
+            let merged = names
+                .iter()
+                .enumerate()
+                .fold(
+                    ServiceMetricsBuilder::new(AwsService::Rds),
+                    |builder, (i, name)| {
+                        let (source, timestamps) = if i % 2 == 0 {
+                            (&loaded, loaded.timestamps.clone())
+                        } else {
+                            (&refreshed, refreshed.timestamps.clone())
+                        };
+                        let (values, timestamps) = retention::cap_series(
+                            source.raw_metrics[name].history.clone(),
+                            timestamps,
+                        );
+                        builder.series(name, Series { timestamps, values })
+                    },
+                )
+                .build();
+            black_box(
+                DynamicMetricBuilder::new()
+                    .build_from_service_metrics(merged)
+                    .unwrap(),
+            );
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    render_sparkline_grid,
+    downsample_metrics,
+    merge_incremental_update
+);
+criterion_main!(benches);
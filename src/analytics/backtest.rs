@@ -0,0 +1,218 @@
+// Alert rule backtesting
+//
+// Replays a threshold rule over a metric's history the way CloudWatch
+// evaluates a metric alarm: the series is averaged into the rule's period,
+// and the rule fires once `datapoints_to_alarm` of the last
+// `evaluation_periods` datapoints breach the threshold. It recovers when that
+// no longer holds. Missing periods are skipped rather than treated as
+// breaching, matching the default `TreatMissingData = missing`. Seeing how
+// often, and for how long, a threshold would have fired on real history helps
+// tune it before an alarm depends on it.
+
+use crate::aws::alarms::Comparison;
+use std::time::{Duration, SystemTime};
+
+/// A threshold rule defined locally, before (or instead of) creating an alarm
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlertRule {
+    pub comparison: Comparison,
+    pub threshold: f64,
+    pub period_secs: u64,
+    pub evaluation_periods: usize,
+    pub datapoints_to_alarm: usize, // At most evaluation_periods
+}
+
+impl AlertRule {
+    fn breaches(&self, value: f64) -> bool {
+        match self.comparison {
+            Comparison::Above => value >= self.threshold,
+            Comparison::Below => value <= self.threshold,
+        }
+    }
+}
+
+/// One stretch of history during which the rule would have been in ALARM
+#[derive(Debug, Clone, PartialEq)]
+pub struct Firing {
+    pub start: SystemTime,
+    pub end: Option<SystemTime>, // None while still firing at the end of the history
+    pub worst: f64,              // Furthest value past the threshold while firing
+}
+
+impl Firing {
+    pub fn duration(&self, history_end: SystemTime) -> Duration {
+        self.end
+            .unwrap_or(history_end)
+            .duration_since(self.start)
+            .unwrap_or_default()
+    }
+}
+
+/// Outcome of replaying a rule over one metric's history
+#[derive(Debug, Clone, PartialEq)]
+pub struct Backtest {
+    pub firings: Vec<Firing>,
+    pub from: SystemTime,
+    pub to: SystemTime,
+    pub coarser_history: bool, // Datapoints are further apart than the rule's period
+}
+
+impl Backtest {
+    /// Replay `rule` over a series (oldest first); None without any datapoints
+    pub fn run(rule: &AlertRule, timestamps: &[SystemTime], values: &[f64]) -> Option<Self> {
+        let (from, to) = (*timestamps.first()?, *timestamps.last()?);
+        let spacing = timestamps
+            .windows(2)
+            .filter_map(|pair| pair[1].duration_since(pair[0]).ok())
+            .min();
+        Some(Self {
+            firings: backtest(rule, timestamps, values),
+            from,
+            to,
+            coarser_history: spacing.is_some_and(|gap| gap.as_secs() > rule.period_secs),
+        })
+    }
+
+    /// Total time the rule would have spent in ALARM
+    pub fn time_firing(&self) -> Duration {
+        self.firings
+            .iter()
+            .map(|firing| firing.duration(self.to))
+            .sum()
+    }
+}
+
+/// Periods of `timestamps`/`values` (oldest first) in which `rule` would have fired
+pub fn backtest(rule: &AlertRule, timestamps: &[SystemTime], values: &[f64]) -> Vec<Firing> {
+    let datapoints = aggregate(timestamps, values, rule.period_secs.max(1));
+    let window = rule.evaluation_periods.max(1);
+    let needed = rule.datapoints_to_alarm.clamp(1, window);
+
+    let mut firings: Vec<Firing> = Vec::new();
+    let mut firing = false;
+    for (index, (timestamp, value)) in datapoints.iter().enumerate() {
+        let recent = &datapoints[(index + 1).saturating_sub(window)..=index];
+        let breaching = recent
+            .iter()
+            .filter(|(_, value)| rule.breaches(*value))
+            .count();
+
+        if breaching >= needed {
+            let current = firings.last_mut().filter(|_| firing);
+            match current {
+                Some(current) if rule.breaches(*value) => {
+                    current.worst = match rule.comparison {
+                        Comparison::Above => current.worst.max(*value),
+                        Comparison::Below => current.worst.min(*value),
+                    };
+                }
+                Some(_) => {}
+                None => {
+                    firings.push(Firing {
+                        start: *timestamp,
+                        end: None,
+                        worst: *value,
+                    });
+                    firing = true;
+                }
+            }
+        } else if firing {
+            if let Some(last) = firings.last_mut() {
+                last.end = Some(*timestamp);
+            }
+            firing = false;
+        }
+    }
+    firings
+}
+
+/// Average datapoints into `period_secs` buckets aligned to the epoch, as CloudWatch does
+///
+/// A series already at (or coarser than) the period passes through unchanged.
+fn aggregate(
+    timestamps: &[SystemTime],
+    values: &[f64],
+    period_secs: u64,
+) -> Vec<(SystemTime, f64)> {
+    let mut buckets: Vec<(u64, f64, usize)> = Vec::new();
+    for (timestamp, value) in timestamps.iter().zip(values) {
+        if !value.is_finite() {
+            continue;
+        }
+        let secs = timestamp
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let bucket = secs - secs % period_secs;
+        match buckets.last_mut() {
+            Some((start, sum, count)) if *start == bucket => {
+                *sum += value;
+                *count += 1;
+            }
+            _ => buckets.push((bucket, *value, 1)),
+        }
+    }
+    buckets
+        .into_iter()
+        .map(|(start, sum, count)| {
+            (
+                SystemTime::UNIX_EPOCH + Duration::from_secs(start),
+                sum / count as f64,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backtest_fires_on_m_of_n_breaching_periods() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_100);
+        let minute = |i: u64| start + Duration::from_secs(i * 60);
+        let timestamps: Vec<SystemTime> = (0..10).map(minute).collect();
+        let cpu = [50.0, 85.0, 60.0, 90.0, 95.0, 70.0, 88.0, 40.0, 30.0, 92.0];
+
+        // 2 of the last 3 one-minute datapoints at or above 80%
+        let rule = AlertRule {
+            comparison: Comparison::Above,
+            threshold: 80.0,
+            period_secs: 60,
+            evaluation_periods: 3,
+            datapoints_to_alarm: 2,
+        };
+        let firings = backtest(&rule, &timestamps, &cpu);
+        assert_eq!(firings.len(), 1);
+        assert_eq!(firings[0].start, minute(3));
+        assert_eq!(firings[0].end, Some(minute(7)));
+        assert_eq!(firings[0].worst, 95.0);
+        assert_eq!(firings[0].duration(minute(9)), Duration::from_secs(4 * 60));
+
+        // Every datapoint must breach: only the 90, 95 run qualifies for 2 of 2
+        let strict = AlertRule {
+            evaluation_periods: 2,
+            ..rule
+        };
+        let firings = backtest(&strict, &timestamps, &cpu);
+        assert_eq!(firings.len(), 1);
+        assert_eq!(firings[0].start, minute(4));
+        assert_eq!(firings[0].end, Some(minute(5)));
+
+        // Five-minute averages (76, 64) never reach the threshold
+        let coarse = AlertRule {
+            period_secs: 300,
+            evaluation_periods: 1,
+            datapoints_to_alarm: 1,
+            ..rule
+        };
+        assert!(backtest(&coarse, &timestamps, &cpu).is_empty());
+
+        let summary = Backtest::run(&strict, &timestamps, &cpu).unwrap();
+        assert_eq!(summary.time_firing(), Duration::from_secs(60));
+        assert!(!summary.coarser_history);
+        assert!(Backtest::run(&coarse, &timestamps, &cpu)
+            .is_some_and(|summary| summary.firings.is_empty()));
+        assert_eq!(Backtest::run(&rule, &[], &[]), None);
+    }
+}
@@ -0,0 +1,187 @@
+// Seasonal baselines learned from loaded metrics
+//
+// Every series the detail pages load is folded into per-metric statistics for
+// each hour of the week (local time), so a recurring workload such as a
+// nightly batch or weekday office hours builds up a "typical value" for every
+// hour. The statistics are stored locally and drawn as a band (mean ± one
+// standard deviation) behind the detail charts; values outside it stand out
+// without a CloudWatch anomaly detector. Only datapoints newer than the last
+// one learned are folded in, so reloading a chart does not count it twice.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, Local, Timelike};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+const BASELINE_FILE: &str = "baselines.json";
+
+const HOURS_PER_WEEK: usize = 7 * 24;
+
+/// Samples an hour needs before its band is shown
+const MIN_SAMPLES: u32 = 3;
+
+/// Samples after which older weeks are gradually forgotten, so the baseline follows drift
+const MAX_SAMPLES: u32 = 60;
+
+/// Running mean and variance of one hour of the week (Welford's method)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct HourStats {
+    pub count: u32,
+    pub mean: f64,
+    m2: f64, // Sum of squared deviations from the mean
+}
+
+impl HourStats {
+    fn add(&mut self, value: f64) {
+        // Capping the count turns the update into an exponentially weighted one
+        let count = (self.count + 1).min(MAX_SAMPLES);
+        let delta = value - self.mean;
+        self.mean += delta / f64::from(count);
+        self.m2 = self.m2 * f64::from(count - 1) / f64::from(self.count.max(1))
+            + delta * (value - self.mean);
+        self.count = count;
+    }
+
+    fn std_dev(&self) -> f64 {
+        if self.count < 2 {
+            return 0.0;
+        }
+        (self.m2 / f64::from(self.count - 1)).max(0.0).sqrt()
+    }
+
+    /// Typical range, or None until enough samples were seen
+    pub fn band(&self) -> Option<(f64, f64)> {
+        (self.count >= MIN_SAMPLES).then(|| {
+            let spread = self.std_dev();
+            ((self.mean - spread).max(0.0), self.mean + spread)
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricBaseline {
+    pub hours: Vec<HourStats>, // Indexed by hour of the week, Monday 00:00 first
+    pub learned_until: u64,    // Newest datapoint folded in, seconds since the Unix epoch
+}
+
+impl Default for MetricBaseline {
+    fn default() -> Self {
+        Self {
+            hours: vec![HourStats::default(); HOURS_PER_WEEK],
+            learned_until: 0,
+        }
+    }
+}
+
+/// Baselines of every metric seen, keyed by `service/resource/metric`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BaselineStore {
+    metrics: BTreeMap<String, MetricBaseline>,
+}
+
+impl BaselineStore {
+    /// Stored baselines; missing or unreadable files start empty
+    pub fn load() -> Self {
+        store_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = store_path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        std::fs::write(&path, serde_json::to_string(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Fold datapoints newer than the last learned one into the baseline; returns whether any were
+    pub fn learn(&mut self, key: &str, timestamps: &[SystemTime], values: &[f64]) -> bool {
+        let baseline = self.metrics.entry(key.to_string()).or_default();
+        let mut learned = false;
+        for (timestamp, value) in timestamps.iter().zip(values) {
+            let secs = epoch_secs(*timestamp);
+            if secs <= baseline.learned_until || !value.is_finite() {
+                continue;
+            }
+            baseline.hours[hour_of_week(*timestamp)].add(*value);
+            baseline.learned_until = secs;
+            learned = true;
+        }
+        learned
+    }
+
+    /// Typical (low, high) at each timestamp; NaN where the hour has too few samples
+    pub fn band(&self, key: &str, timestamps: &[SystemTime]) -> Option<(Vec<f64>, Vec<f64>)> {
+        let baseline = self.metrics.get(key)?;
+        let bands: Vec<Option<(f64, f64)>> = timestamps
+            .iter()
+            .map(|timestamp| baseline.hours[hour_of_week(*timestamp)].band())
+            .collect();
+        if bands.iter().all(Option::is_none) {
+            return None;
+        }
+        Some(
+            bands
+                .into_iter()
+                .map(|band| band.unwrap_or((f64::NAN, f64::NAN)))
+                .unzip(),
+        )
+    }
+}
+
+fn hour_of_week(timestamp: SystemTime) -> usize {
+    let local = DateTime::<Local>::from(timestamp);
+    local.weekday().num_days_from_monday() as usize * 24 + local.hour() as usize
+}
+
+fn epoch_secs(timestamp: SystemTime) -> u64 {
+    timestamp
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_secs()
+}
+
+/// Location of the stored baselines (e.g. `~/.local/share/awscw/baselines.json`)
+fn store_path() -> Option<PathBuf> {
+    dirs::data_local_dir().map(|dir| dir.join("awscw").join(BASELINE_FILE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_baseline_learns_each_hour_of_week_once() {
+        const WEEK: u64 = 7 * 86400;
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let mut store = BaselineStore::default();
+
+        // The same hour on three consecutive weeks
+        for (week, value) in [10.0, 20.0, 30.0].into_iter().enumerate() {
+            let at = start + Duration::from_secs(week as u64 * WEEK);
+            assert!(store.learn("rds/orders-db/CPUUtilization", &[at], &[value]));
+            // Reloading the same datapoint is not counted again
+            assert!(!store.learn("rds/orders-db/CPUUtilization", &[at], &[value]));
+        }
+
+        let next_week = start + Duration::from_secs(3 * WEEK);
+        let other_hour = next_week + Duration::from_secs(3600);
+        let (low, high) = store
+            .band("rds/orders-db/CPUUtilization", &[next_week, other_hour])
+            .unwrap();
+        assert_eq!((low[0], high[0]), (10.0, 30.0));
+        assert!(low[1].is_nan() && high[1].is_nan());
+
+        assert!(store
+            .band("rds/other-db/CPUUtilization", &[next_week])
+            .is_none());
+    }
+}
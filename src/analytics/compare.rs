@@ -0,0 +1,140 @@
+// Comparing a metric between two time windows
+//
+// Compare mode loads the shown range twice: ending now, and ending the same
+// offset (a day, a week, four weeks) earlier. Each metric's mean is compared
+// between the two, and the change is called significant when Welch's t
+// statistic is at least SIGNIFICANT_T. With the dozens of datapoints a window
+// holds that is roughly a 95% confidence the means differ, so a percent
+// change on a noisy metric is not flagged just for being large.
+
+use std::time::Duration;
+
+/// Welch's t above which a change is significant
+const SIGNIFICANT_T: f64 = 2.0;
+
+/// Datapoints each window needs before a change can be significant
+const MIN_SAMPLES: usize = 3;
+
+/// How far before the current window the earlier one is taken
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompareOffset {
+    Day,
+    #[default]
+    Week,
+    FourWeeks,
+}
+
+impl CompareOffset {
+    pub fn duration(self) -> Duration {
+        const DAY: u64 = 24 * 60 * 60;
+        match self {
+            CompareOffset::Day => Duration::from_secs(DAY),
+            CompareOffset::Week => Duration::from_secs(7 * DAY),
+            CompareOffset::FourWeeks => Duration::from_secs(28 * DAY),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CompareOffset::Day => "a day earlier",
+            CompareOffset::Week => "a week earlier",
+            CompareOffset::FourWeeks => "four weeks earlier",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            CompareOffset::Day => CompareOffset::Week,
+            CompareOffset::Week => CompareOffset::FourWeeks,
+            CompareOffset::FourWeeks => CompareOffset::Day,
+        }
+    }
+}
+
+/// How one metric's mean moved from the earlier window to the current one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowDiff {
+    pub current_mean: f64,
+    pub previous_mean: f64,
+    pub change_pct: Option<f64>, // None when the earlier mean is zero
+    pub significant: bool,
+}
+
+/// Compare two windows' values; None when either window has no data
+pub fn diff_windows(current: &[f64], previous: &[f64]) -> Option<WindowDiff> {
+    let (current_mean, current_var) = mean_and_variance(current)?;
+    let (previous_mean, previous_var) = mean_and_variance(previous)?;
+
+    let change_pct = (previous_mean != 0.0)
+        .then(|| 100.0 * (current_mean - previous_mean) / previous_mean.abs());
+    let significant = current.len() >= MIN_SAMPLES
+        && previous.len() >= MIN_SAMPLES
+        && current_mean != previous_mean
+        && {
+            let standard_error =
+                (current_var / current.len() as f64 + previous_var / previous.len() as f64).sqrt();
+            // Two flat windows at different levels differ with certainty
+            standard_error == 0.0
+                || ((current_mean - previous_mean) / standard_error).abs() >= SIGNIFICANT_T
+        };
+
+    Some(WindowDiff {
+        current_mean,
+        previous_mean,
+        change_pct,
+        significant,
+    })
+}
+
+/// Mean and sample variance (zero for a single value)
+fn mean_and_variance(values: &[f64]) -> Option<(f64, f64)> {
+    if values.is_empty() {
+        return None;
+    }
+    let count = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / count;
+    let variance = if values.len() > 1 {
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (count - 1.0)
+    } else {
+        0.0
+    };
+    Some((mean, variance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_windows_significance() {
+        // A clear shift well outside the noise
+        let diff = diff_windows(&[20.0, 22.0, 21.0, 19.0], &[10.0, 11.0, 9.0, 10.0]).unwrap();
+        assert_eq!(diff.current_mean, 20.5);
+        assert_eq!(diff.previous_mean, 10.0);
+        assert_eq!(diff.change_pct, Some(105.0));
+        assert!(diff.significant);
+
+        // A large percent change that noise explains
+        let diff = diff_windows(&[1.0, 30.0, 2.0, 25.0], &[12.0, 1.0, 20.0, 2.0]).unwrap();
+        assert!(diff.change_pct.unwrap() > 50.0);
+        assert!(!diff.significant);
+
+        // Too few datapoints, and an earlier window at zero
+        assert!(!diff_windows(&[50.0], &[10.0]).unwrap().significant);
+        let diff = diff_windows(&[1.0, 1.0, 1.0], &[0.0, 0.0, 0.0]).unwrap();
+        assert_eq!(diff.change_pct, None);
+        assert!(diff.significant);
+
+        assert_eq!(diff_windows(&[], &[1.0]), None);
+    }
+
+    #[test]
+    fn test_offsets_cycle() {
+        let mut offset = CompareOffset::default();
+        for _ in 0..3 {
+            offset = offset.next();
+        }
+        assert_eq!(offset, CompareOffset::Week);
+        assert!(CompareOffset::Day.duration() < CompareOffset::FourWeeks.duration());
+    }
+}
@@ -0,0 +1,183 @@
+// FIFO message group behavior
+//
+// A FIFO queue hands out each message group's messages strictly in order:
+// while a batch from a group is in flight, nothing more from that group is
+// received. How many messages are processed at once is bounded by the number
+// of groups with messages, not by the number of consumers, so a backlog piled
+// into a few groups drains one batch at a time however many consumers poll.
+// On top of that each queue, or each group in high throughput mode, has a
+// send rate limit. Sends repeated within the five-minute deduplication
+// interval are accepted but dropped, which NumberOfDeduplicatedSentMessages
+// counts and NumberOfMessagesSent leaves out.
+
+/// Metrics only FIFO queues publish, shown in their own panel
+pub const FIFO_METRICS: [&str; 2] = [
+    "ApproximateNumberOfGroupsWithInflightMessages",
+    "NumberOfDeduplicatedSentMessages",
+];
+
+/// Messages per second per API action, per queue or per group in high throughput mode
+pub const FIFO_SEND_LIMIT: f64 = 300.0;
+
+/// Periods averaged for the rates
+const RATE_PERIODS: usize = 3;
+
+/// Fraction of the send limit at which sends are flagged
+const LIMIT_FRACTION: f64 = 0.8;
+
+/// Waiting messages per group in flight at which the groups are flagged as the bottleneck
+const BACKLOG_PER_GROUP: f64 = 100.0;
+
+/// Fraction of sends dropped as duplicates worth pointing out
+const DUPLICATE_FRACTION: f64 = 0.05;
+
+/// Counters and settings of a FIFO queue; series are per period, oldest first
+#[derive(Debug, Clone, Copy)]
+pub struct GroupFlow<'a> {
+    pub backlog: f64, // ApproximateNumberOfMessages
+    pub groups_in_flight: &'a [f64],
+    pub sent: &'a [f64],
+    pub deduplicated: &'a [f64],
+    pub received: &'a [f64],
+    pub period_secs: f64,
+    pub high_throughput: bool, // FifoThroughputLimit = perMessageGroupId
+    pub content_based_deduplication: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupHint {
+    pub text: String,
+    pub warning: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupActivity {
+    pub groups_in_flight: Option<f64>,
+    pub send_rate: f64, // Messages per second
+    pub receive_rate_per_group: Option<f64>,
+    pub duplicate_fraction: Option<f64>, // Of all sends in the window, duplicates included
+    pub hints: Vec<GroupHint>,
+}
+
+pub fn analyze(flow: &GroupFlow) -> GroupActivity {
+    let period = flow.period_secs.max(1.0);
+    let groups = flow.groups_in_flight.last().copied().filter(|g| *g >= 0.0);
+    let send_rate = recent_rate(flow.sent, period);
+    let receive_rate_per_group = groups
+        .filter(|groups| *groups > 0.0)
+        .map(|groups| recent_rate(flow.received, period) / groups);
+
+    let sent: f64 = flow.sent.iter().map(|v| v.max(0.0)).sum();
+    let duplicates: f64 = flow.deduplicated.iter().map(|v| v.max(0.0)).sum();
+    let duplicate_fraction = (!flow.deduplicated.is_empty() && sent + duplicates > 0.0)
+        .then(|| duplicates / (sent + duplicates));
+
+    let mut hints = Vec::new();
+    if let Some(groups) = groups.filter(|groups| *groups > 0.0) {
+        if flow.backlog >= groups * BACKLOG_PER_GROUP {
+            hints.push(GroupHint {
+                text: format!(
+                    "{:.0} waiting behind {groups:.0} group(s) in flight: each group is received one batch at a time, so more consumers will not help - spread sends over more message group IDs",
+                    flow.backlog
+                ),
+                warning: true,
+            });
+        }
+    }
+    if flow.high_throughput {
+        if let Some(per_group) =
+            receive_rate_per_group.filter(|rate| *rate >= FIFO_SEND_LIMIT * LIMIT_FRACTION)
+        {
+            hints.push(GroupHint {
+                text: format!(
+                    "~{per_group:.0}/s per group, near the {FIFO_SEND_LIMIT:.0}/s each group allows in high throughput mode - busy groups are throttled first"
+                ),
+                warning: true,
+            });
+        }
+    } else if send_rate >= FIFO_SEND_LIMIT * LIMIT_FRACTION {
+        hints.push(GroupHint {
+            text: format!(
+                "Sending {send_rate:.0}/s, near the {FIFO_SEND_LIMIT:.0} calls/s a FIFO queue allows per action - batch sends (up to 10 per call) or enable high throughput mode"
+            ),
+            warning: true,
+        });
+    }
+    if let Some(fraction) = duplicate_fraction.filter(|f| *f >= DUPLICATE_FRACTION) {
+        let cause = if flow.content_based_deduplication {
+            "content-based deduplication is dropping identical bodies"
+        } else {
+            "producers are resending with the same deduplication ID"
+        };
+        hints.push(GroupHint {
+            text: format!(
+                "{:.0}% of sends dropped as duplicates within 5 minutes - {cause}",
+                fraction * 100.0
+            ),
+            warning: false,
+        });
+    }
+
+    GroupActivity {
+        groups_in_flight: groups,
+        send_rate,
+        receive_rate_per_group,
+        duplicate_fraction,
+        hints,
+    }
+}
+
+/// Average per second over the last RATE_PERIODS periods
+fn recent_rate(series: &[f64], period_secs: f64) -> f64 {
+    let recent = &series[series.len().saturating_sub(RATE_PERIODS)..];
+    if recent.is_empty() {
+        return 0.0;
+    }
+    recent.iter().map(|v| v.max(0.0)).sum::<f64>() / (recent.len() as f64 * period_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_few_groups_and_duplicates_are_flagged() {
+        let flow = GroupFlow {
+            backlog: 900.0,
+            groups_in_flight: &[2.0, 3.0],
+            sent: &[5400.0, 5400.0, 5400.0],
+            deduplicated: &[600.0, 0.0, 0.0],
+            received: &[1800.0, 1800.0, 1800.0],
+            period_secs: 60.0,
+            high_throughput: false,
+            content_based_deduplication: true,
+        };
+        let activity = analyze(&flow);
+        assert_eq!(activity.groups_in_flight, Some(3.0));
+        assert_eq!(activity.send_rate, 90.0);
+        assert_eq!(activity.receive_rate_per_group, Some(10.0));
+        assert_eq!(activity.duplicate_fraction, Some(600.0 / 16800.0));
+        assert_eq!(activity.hints.len(), 1);
+        assert!(activity.hints[0].warning);
+
+        // Near the queue's send limit, with more duplicates
+        let busy = analyze(&GroupFlow {
+            backlog: 0.0,
+            sent: &[15000.0, 15000.0, 15000.0],
+            deduplicated: &[3000.0, 3000.0, 3000.0],
+            ..flow
+        });
+        let texts: Vec<&str> = busy.hints.iter().map(|h| h.text.as_str()).collect();
+        assert!(texts[0].starts_with("Sending 250/s"));
+        assert!(texts[1].contains("content-based"));
+
+        // A standard queue publishes none of these
+        let quiet = analyze(&GroupFlow {
+            groups_in_flight: &[],
+            deduplicated: &[],
+            ..flow
+        });
+        assert_eq!(quiet.groups_in_flight, None);
+        assert_eq!(quiet.duplicate_fraction, None);
+    }
+}
@@ -0,0 +1,164 @@
+// Downtime and gap detection
+//
+// A heartbeat-style metric (database connections, requests, messages sent)
+// normally reports a non-zero value every period. CloudWatch publishes no
+// datapoint at all while a resource is stopped or not emitting, and a zero
+// while it is up but idle or unreachable, so runs of missing or zero periods
+// are a quick approximation of downtime over a long range.
+
+use serde::Serialize;
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GapKind {
+    Missing, // No datapoint published
+    Zero,    // Datapoints published with a value of zero
+}
+
+impl GapKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            GapKind::Missing => "no data",
+            GapKind::Zero => "zero",
+        }
+    }
+}
+
+/// A run of consecutive periods of one kind
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gap {
+    pub start: SystemTime,
+    pub end: SystemTime,
+    pub kind: GapKind,
+}
+
+impl Gap {
+    pub fn duration(&self) -> Duration {
+        self.end.duration_since(self.start).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GapReport {
+    pub from: SystemTime,
+    pub to: SystemTime,
+    pub gaps: Vec<Gap>,
+}
+
+impl GapReport {
+    pub fn total_gap(&self) -> Duration {
+        self.gaps.iter().map(Gap::duration).sum()
+    }
+
+    /// Share of the range with non-zero datapoints, 0-100
+    pub fn availability(&self) -> f64 {
+        let range = self.to.duration_since(self.from).unwrap_or_default();
+        if range.is_zero() {
+            return 100.0;
+        }
+        100.0 * (1.0 - self.total_gap().as_secs_f64() / range.as_secs_f64()).max(0.0)
+    }
+}
+
+/// Gaps in a series (oldest first) expected to have a datapoint every `period_secs`
+/// between `from` and `to`
+pub fn find_gaps(
+    timestamps: &[SystemTime],
+    values: &[f64],
+    from: SystemTime,
+    to: SystemTime,
+    period_secs: u64,
+) -> GapReport {
+    let period = period_secs.max(1);
+    // Periods are aligned to the epoch, as CloudWatch aligns datapoints
+    let first = epoch_secs(from).div_ceil(period) * period;
+    let last = epoch_secs(to);
+    let slots = last.saturating_sub(first) / period;
+
+    let mut state: Vec<Option<GapKind>> = vec![Some(GapKind::Missing); slots as usize];
+    for (timestamp, value) in timestamps.iter().zip(values) {
+        let secs = epoch_secs(*timestamp);
+        if secs < first {
+            continue;
+        }
+        let Some(slot) = state.get_mut(((secs - first) / period) as usize) else {
+            continue;
+        };
+        *slot = if *value == 0.0 {
+            Some(GapKind::Zero)
+        } else {
+            None
+        };
+    }
+
+    let at =
+        |slot: usize| SystemTime::UNIX_EPOCH + Duration::from_secs(first + slot as u64 * period);
+    let mut gaps: Vec<Gap> = Vec::new();
+    for (slot, kind) in state.iter().enumerate() {
+        let Some(kind) = kind else {
+            continue;
+        };
+        match gaps.last_mut() {
+            Some(gap) if gap.kind == *kind && gap.end == at(slot) => gap.end = at(slot + 1),
+            _ => gaps.push(Gap {
+                start: at(slot),
+                end: at(slot + 1),
+                kind: *kind,
+            }),
+        }
+    }
+
+    GapReport {
+        from: at(0),
+        to: at(slots as usize),
+        gaps,
+    }
+}
+
+fn epoch_secs(timestamp: SystemTime) -> u64 {
+    timestamp
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_and_zero_periods_become_gaps() {
+        let start = 1_700_000_100; // A multiple of 300
+        let at = |period: u64| SystemTime::UNIX_EPOCH + Duration::from_secs(start + period * 300);
+
+        // Twelve 5-minute periods: 3 and 4 missing, 7-9 zero
+        let present = [0, 1, 2, 5, 6, 7, 8, 9, 10, 11];
+        let timestamps: Vec<SystemTime> = present.iter().map(|&p| at(p)).collect();
+        let values = [4.0, 5.0, 4.0, 6.0, 3.0, 0.0, 0.0, 0.0, 2.0, 5.0];
+
+        let report = find_gaps(&timestamps, &values, at(0), at(12), 300);
+        assert_eq!(
+            report.gaps,
+            vec![
+                Gap {
+                    start: at(3),
+                    end: at(5),
+                    kind: GapKind::Missing
+                },
+                Gap {
+                    start: at(7),
+                    end: at(10),
+                    kind: GapKind::Zero
+                },
+            ]
+        );
+        assert_eq!(report.total_gap(), Duration::from_secs(5 * 300));
+        assert!((report.availability() - 700.0 / 12.0).abs() < 1e-9);
+
+        // Nothing at all in the range is one long gap
+        let silent = find_gaps(&[], &[], at(0), at(12), 300);
+        assert_eq!(silent.gaps.len(), 1);
+        assert_eq!(silent.availability(), 0.0);
+    }
+}
@@ -0,0 +1,22 @@
+// Estimates derived from CloudWatch series beyond what the metrics state directly
+
+// Age distribution of an SQS backlog
+pub mod queue_age;
+
+// Message group behavior of SQS FIFO queues
+pub mod fifo_groups;
+
+// Typical values per hour of the week, learned locally
+pub mod baseline;
+
+// Replaying threshold rules over history
+pub mod backtest;
+
+// Missing and zero periods of heartbeat metrics
+pub mod gaps;
+
+// Changes between the current window and an earlier one
+pub mod compare;
+
+// Per-interval change of counter-like metrics
+pub mod rate;
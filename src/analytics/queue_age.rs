@@ -0,0 +1,165 @@
+// SQS backlog age estimation
+//
+// SQS only reports the age of the oldest message, not how old the rest of the
+// backlog is. Assuming consumers take the oldest messages first, the messages
+// still waiting are the most recent arrivals: walking NumberOfMessagesSent
+// back from the newest period until the visible count is covered gives the
+// arrival period, and so the approximate age, of every waiting message. The
+// age of the oldest message caps the estimate, and its trend shows whether
+// messages are heading for the retention limit, after which SQS deletes them.
+
+/// Upper bound (exclusive, seconds) and label of each age bucket
+pub const AGE_BUCKETS: [(f64, &str); 8] = [
+    (60.0, "<1m"),
+    (300.0, "1-5m"),
+    (900.0, "5-15m"),
+    (3600.0, "15m-1h"),
+    (6.0 * 3600.0, "1-6h"),
+    (86400.0, "6h-1d"),
+    (4.0 * 86400.0, "1-4d"),
+    (f64::INFINITY, ">4d"),
+];
+
+/// Periods averaged for the arrival and drain rates and the age trend
+const RATE_PERIODS: usize = 3;
+
+/// Fraction of the retention period at which the oldest message is flagged
+const RISK_FRACTION: f64 = 0.8;
+
+/// Flag the queue when the oldest message would reach retention within this many seconds
+const RISK_HORIZON_SECS: f64 = 6.0 * 3600.0;
+
+/// Queue counters the estimate is based on; series are per period, oldest first
+#[derive(Debug, Clone, Copy)]
+pub struct QueueFlow<'a> {
+    pub backlog: f64, // ApproximateNumberOfMessages
+    pub sent: &'a [f64],
+    pub deleted: &'a [f64],
+    pub oldest_age: &'a [f64], // ApproximateAgeOfOldestMessage, seconds
+    pub period_secs: f64,
+    pub retention_secs: Option<f64>, // MessageRetentionPeriod
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueAgeEstimate {
+    pub buckets: [f64; AGE_BUCKETS.len()], // Estimated messages per age bucket
+    pub oldest_age_secs: f64,
+    pub arrival_rate: f64, // Messages per second
+    pub drain_rate: f64,   // Messages per second
+    pub time_to_retention_secs: Option<f64>,
+    pub at_risk: bool,
+}
+
+impl QueueAgeEstimate {
+    /// Arrivals minus deletions per second; positive means the backlog grows
+    pub fn net_rate(&self) -> f64 {
+        self.arrival_rate - self.drain_rate
+    }
+}
+
+pub fn estimate(flow: &QueueFlow) -> QueueAgeEstimate {
+    let period = flow.period_secs.max(1.0);
+    let oldest = flow.oldest_age.last().copied().unwrap_or(0.0).max(0.0);
+    let mut buckets = [0.0; AGE_BUCKETS.len()];
+    let mut add = |age: f64, messages: f64| {
+        let age = if oldest > 0.0 { age.min(oldest) } else { age };
+        let index = AGE_BUCKETS
+            .iter()
+            .position(|(limit, _)| age < *limit)
+            .unwrap_or(AGE_BUCKETS.len() - 1);
+        buckets[index] += messages;
+    };
+
+    // Newest arrivals are the ones still waiting
+    let mut remaining = flow.backlog.max(0.0);
+    for (periods_ago, sent) in flow.sent.iter().rev().enumerate() {
+        if remaining <= 0.0 {
+            break;
+        }
+        let messages = sent.max(0.0).min(remaining);
+        add((periods_ago as f64 + 0.5) * period, messages);
+        remaining -= messages;
+    }
+    // The rest arrived before the series starts
+    if remaining > 0.0 {
+        add(oldest.max(flow.sent.len() as f64 * period), remaining);
+    }
+
+    let recent_rate = |series: &[f64]| {
+        let recent = &series[series.len().saturating_sub(RATE_PERIODS)..];
+        if recent.is_empty() {
+            return 0.0;
+        }
+        recent.iter().sum::<f64>() / recent.len() as f64 / period
+    };
+
+    // How fast the oldest message ages: ~1 s/s when it is stuck, <= 0 while it is consumed
+    let age_slope = match flow.oldest_age.len() {
+        0 | 1 => 0.0,
+        len => {
+            let span = (len - 1).min(RATE_PERIODS);
+            (oldest - flow.oldest_age[len - 1 - span]) / (span as f64 * period)
+        }
+    };
+    let time_to_retention_secs = flow
+        .retention_secs
+        .filter(|_| age_slope > 0.0)
+        .map(|retention| (retention - oldest).max(0.0) / age_slope);
+    let at_risk = flow
+        .retention_secs
+        .is_some_and(|retention| oldest >= retention * RISK_FRACTION)
+        || time_to_retention_secs.is_some_and(|secs| secs <= RISK_HORIZON_SECS);
+
+    QueueAgeEstimate {
+        buckets,
+        oldest_age_secs: oldest,
+        arrival_rate: recent_rate(flow.sent),
+        drain_rate: recent_rate(flow.deleted),
+        time_to_retention_secs,
+        at_risk,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backlog_ages_follow_recent_arrivals() {
+        // 5-minute periods; 250 waiting messages, 100 sent in each of the last periods
+        let flow = QueueFlow {
+            backlog: 250.0,
+            sent: &[100.0, 100.0, 100.0, 100.0],
+            deleted: &[90.0, 60.0, 60.0, 60.0],
+            oldest_age: &[600.0, 900.0, 1200.0, 1500.0],
+            period_secs: 300.0,
+            retention_secs: Some(3600.0),
+        };
+        let aging = estimate(&flow);
+
+        // Newest 100 are ~2.5 minutes old, the next 100 ~7.5, the last 50 ~12.5
+        assert_eq!(aging.buckets[1], 100.0);
+        assert_eq!(aging.buckets[2], 150.0);
+        assert_eq!(aging.buckets.iter().sum::<f64>(), 250.0);
+        assert_eq!(aging.arrival_rate, 100.0 / 300.0);
+        assert_eq!(aging.drain_rate, 60.0 / 300.0);
+        assert!(aging.net_rate() > 0.0);
+
+        // The oldest message ages 1 s/s: 2100 s left before the 1 h retention
+        assert_eq!(aging.time_to_retention_secs, Some(2100.0));
+        assert!(aging.at_risk);
+
+        // A backlog older than the series is capped at the oldest age
+        let stale = estimate(&QueueFlow {
+            backlog: 10.0,
+            sent: &[0.0, 0.0],
+            deleted: &[0.0, 0.0],
+            oldest_age: &[7200.0, 7200.0],
+            period_secs: 300.0,
+            retention_secs: Some(14.0 * 86400.0),
+        });
+        assert_eq!(stale.buckets[4], 10.0);
+        assert_eq!(stale.time_to_retention_secs, None);
+        assert!(!stale.at_risk);
+    }
+}
@@ -0,0 +1,43 @@
+// Per-interval change of counter-like metrics
+//
+// Some RDS metrics only ever climb between resets: the highest transaction ID
+// in use until a vacuum freezes old rows, or binlog and WAL disk usage until
+// old files are purged. Charted as they are, they are a slow ramp with cliffs;
+// the change from one datapoint to the next shows how fast they are moving.
+
+/// Change of each datapoint from the one before it, NaN for the first
+///
+/// A drop is taken as a reset: the counter started again from zero, so the
+/// change across it is the new value rather than a large negative step. A
+/// missing datapoint (NaN) leaves NaN on both sides of it.
+pub fn per_interval_change(values: &[f64]) -> Vec<f64> {
+    let mut changes = Vec::with_capacity(values.len());
+    let mut previous = f64::NAN;
+    for &value in values {
+        changes.push(match value - previous {
+            change if change >= 0.0 => change,
+            change if change < 0.0 => value.max(0.0),
+            _ => f64::NAN,
+        });
+        previous = value;
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changes_survive_resets_and_gaps() {
+        let values = [100.0, 150.0, 150.0, 20.0, 50.0, f64::NAN, 70.0, 90.0];
+        let changes = per_interval_change(&values);
+
+        assert!(changes[0].is_nan());
+        assert_eq!(changes[1..5], [50.0, 0.0, 20.0, 30.0]);
+        // The datapoints on either side of a gap have nothing to compare with
+        assert!(changes[5].is_nan() && changes[6].is_nan());
+        assert_eq!(changes[7], 20.0);
+        assert!(per_interval_change(&[]).is_empty());
+    }
+}
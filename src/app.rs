@@ -1,55 +1,225 @@
-use crate::aws::time_range::{TimeRange, TimeUnit};
-use crate::aws::{cloudwatch_service::load_metrics, load_rds_instances, rds::RdsInstanceManager};
-use crate::models::{App, AppState, AwsService, FocusedPanel, MetricType, ServiceInstance};
+use crate::aws::metrics::percentiles::fetch_percentiles;
+use crate::aws::metrics::providers::RdsMetricProvider;
+use crate::aws::metrics::registry::MetricRegistry;
+use crate::aws::metrics::staleness::{
+    canary_staleness, compute_environment_staleness, function_staleness, has_recent_datapoints,
+    rds_staleness, rule_staleness, sqs_staleness,
+};
+use crate::aws::metrics::types::{ServiceMetrics, StatisticType};
+use crate::aws::metrics::widget_image::{fetch_widget_image, WidgetRequest};
+use crate::aws::time_range::{calculate_period_seconds, TimeRange, TimeUnit};
+use crate::aws::{
+    appregistry::AppRegistryClient,
+    cloudwatch_service::{detect_resolution, load_metrics, ReusedMetrics},
+    limiter, load_rds_instances,
+    rds::RdsInstanceManager,
+    resource_groups::{GroupMembers, ResourceGroupsClient},
+    session::AwsSessionManager,
+    usage,
+};
+use crate::models::{
+    App, AppState, AwsService, FocusedPanel, GlobalDatabasePage, LogInput, LogTailPage, MetricType,
+    ServiceInstance, ViewState,
+};
 use anyhow::Result;
-use std::time::{Duration, Instant};
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::analytics::backtest::Backtest;
+use crate::analytics::baseline::BaselineStore;
+use crate::analytics::fifo_groups::{self, GroupActivity, GroupFlow};
+use crate::analytics::queue_age::{self, QueueAgeEstimate, QueueFlow};
+use crate::analytics::rate::per_interval_change;
+use crate::aws::alarms::composite::AlarmStates;
+use crate::aws::alarms::{self, AlarmForm, AlarmMaintenance, AlarmsManager, MetricAlarm, NewAlarm};
+use crate::aws::batch::BatchClientManager;
+use crate::aws::cloudformation::{physical_resource_id, CloudFormationClientManager};
+use crate::aws::cloudtrail::{trail_resource_name, CloudTrailClientManager};
+use crate::aws::compute_optimizer::ComputeOptimizerClientManager;
+use crate::aws::eventbridge::EventBridgeClientManager;
+use crate::aws::lambda;
+use crate::aws::lambda::LambdaClient;
+use crate::aws::logs::slow_query::{self, SlowQuery};
+use crate::aws::logs::tail::{self, TailBuffer};
+use crate::aws::logs::{self, LogsClientManager};
+use crate::aws::metric_fetcher::{every_metric_failed, FailedMetrics};
+use crate::aws::metrics::factory::MetricServiceFactory;
+use crate::aws::metrics::fetcher::{fetch_service_metrics, refetch_service_metrics};
+use crate::aws::profiles::{self, ProfilesPanel};
+use crate::aws::quotas::{QuotaCache, CLOUDWATCH_SERVICE_CODE, SQS_SERVICE_CODE};
+use crate::aws::rds::blue_green::{identifier_from_arn, BlueGreenDeployment};
+use crate::aws::rds::client::RdsClientManager;
+use crate::aws::rds::compare::load_window_comparison;
+use crate::aws::rds::global::{load_secondary_lag, GlobalMember, SecondaryLag};
+use crate::aws::rds::probe::probe_endpoint;
+use crate::aws::rds::replicas::{is_cross_region, load_replica_lag};
+use crate::aws::rds::snapshots;
+use crate::aws::regions::{self, RegionsPanel};
+use crate::aws::secrets::{secrets_for_instance, DbSecret, SecretsClientManager};
+use crate::aws::sns::SnsClientManager;
+use crate::aws::sqs::{
+    actions::{redrive_dead_letter_queue, send_test_message},
+    folders::{self, Folder, FolderRow},
+    policy::{self, QueueAccess},
+    SqsClientManager,
+};
+use crate::aws::ssm::SsmClientManager;
+use crate::aws::synthetics::{artifacts_console_url, SyntheticsClientManager};
+use crate::bookmarks::{self, Bookmark, BookmarksPanel};
+use crate::config::Config;
+use crate::confirmation::{self, Confirmation, Environment};
+use crate::control;
+use crate::export::{self, ExportFormat};
+use crate::fleet::{self, FleetRow};
+use crate::history::ViewSnapshot;
+use crate::incident::{self, Incident, Recording, Restore};
+use crate::internals::{Internals, Readings};
+use crate::jobs::{JobManager, JobsPanel};
+use crate::journal::{unix_now, Journal, JournalEntry, MutedAlarms};
+use crate::models::{
+    AlarmBrowserPage, BatchJobQueue, Canary, ChartImage, CompositeAlarmPage, ComputeEnvironment,
+    EventRule, GroupSource, LambdaFunction, ParameterPage, PendingAction, QueueAccessPanel,
+    RdsInstance, ResourceGroupPage, ResourceKey, SentTestMessage, SqsQueue, StackedView,
+    StickySelection,
+};
+use crate::perf::{self, HeldHistory, PageTimings, PerfStats};
+use crate::policy::Feature;
+use crate::prefetch::{self, Prefetch};
+use crate::quiet_hours::{self, Held};
+use crate::reference_lines::{self, parse_line, PinPrompt, PinnedLine};
+use crate::resource_history::ResourceHistory;
+use crate::scripting::{ScriptHost, ScriptOutput};
+use crate::thresholds::{self, ThresholdEditor};
+use crate::ui::accessibility::Announcer;
+use crate::ui::charts::metrics_chart::{metric_color, Band};
+use crate::ui::charts::raster::RasterChart;
+use crate::ui::charts::rendering::stacked_area::{
+    family_of, MetricFamily, StackLayer, StackedChart, FAMILIES,
+};
+use crate::ui::charts::text_art::render_text_chart;
+use crate::ui::components::metric_utils::{format_value, get_metric_unit};
+use crate::ui::graphics::{self, GraphicsProtocol, ImageSource, RasterImage};
+use crate::ui::theme::Severity;
+use crate::update_check::{self, Release};
+use crate::utils::clipboard::copy_to_clipboard;
+use crate::utils::export::{write_series, Column, ExportPrompt, SeriesFormat};
+use crate::watchlist::Watchlist;
+use futures::FutureExt;
+use ratatui::layout::Rect;
+
+/// Characters moved per Left/Right press when scrolling long attribute values
+const DETAILS_HSCROLL_STEP: usize = 8;
+
+/// How often the blue/green page refreshes while a switchover is in progress
+const SWITCHOVER_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long queue counters are polled after sending a test message, and how often
+const TEST_MESSAGE_WATCH_DURATION: Duration = Duration::from_secs(5 * 60);
+const TEST_MESSAGE_WATCH_INTERVAL: Duration = Duration::from_secs(10);
 
-use crate::models::RdsInstance;
 impl App {
     // ================================
     // 1. INITIALIZATION
     // ================================
 
-    pub fn new() -> App {
-        let mut app = App {
-            // Service selection initialization (RDS-focused for now)
-            available_services: vec![AwsService::Rds], // Focus on RDS only
-            service_list_state: ratatui::widgets::ListState::default(),
-            selected_service: None, // No service selected initially
+    pub fn new(config: Config) -> App {
+        let watchlist = Watchlist::load_cached()
+            .with_overrides(&config.watchlist.include, &config.watchlist.exclude);
+        // Marks and muted alarms survive a crash or a dropped SSH session
+        let journal = Journal::open();
+        let restored = journal.state().clone();
+        // Screen-reader output is text only
+        let graphics = GraphicsProtocol::select(config.charts.images)
+            .filter(|_| !config.accessibility.screen_reader);
 
-            // Instance list initialization
-            instances: Vec::new(),
-            rds_instances: Vec::new(),
-            list_state: ratatui::widgets::ListState::default(),
+        App {
+            view: ViewState::default(),
+            tabs: vec![ViewState::default()],
+            active_tab: 0,
+            pending_g: false,
+
+            available_services: vec![
+                AwsService::Rds,
+                AwsService::Sqs,
+                AwsService::Synthetics,
+                AwsService::EventBridge,
+                AwsService::Batch,
+                AwsService::Lambda,
+            ],
             loading: false,
-            state: AppState::ServiceList, // Start with service selection
-            selected_instance: None,
-            metrics: crate::models::MetricData::default(),
             metrics_loading: false,
-            last_refresh: None,
-            auto_refresh_enabled: true,
-            scroll_offset: 0,
-            metrics_per_screen: 1,
-            metrics_summary_scroll: 0,
-            time_range_scroll: 2,
-            focused_panel: FocusedPanel::TimeRanges,
-            saved_focused_panel: FocusedPanel::TimeRanges,
-            time_range: TimeRange::new(3, TimeUnit::Hours, 1).unwrap(),
-
-            // Initialize sparkline grid state
-            selected_metric: None,
-            sparkline_grid_scroll: 0,
-            sparkline_grid_selected_index: 0,
-            saved_sparkline_grid_selected_index: 0,
+            auto_refresh_enabled: config.auto_refresh_enabled,
+            metrics_per_screen: config.metrics_per_screen,
+            metric_grid_columns: 1,
 
             // Initialize error handling
             error_message: None,
 
             // Initialize loading timeout
             loading_start_time: None,
-        };
-        app.service_list_state.select(Some(0));
-        app
+
+            status_message: None,
+
+            // Load user scripts from the config directory
+            script_host: ScriptHost::load(),
+            script_output: ScriptOutput::default(),
+
+            config,
+
+            pending_action: None,
+            sent_test_message: None,
+
+            watchlist,
+            watchlist_only: false,
+            watchlist_refreshed_at: None,
+            watchlist_error: None,
+
+            quotas: QuotaCache::default(),
+
+            alarms: Vec::new(),
+            marked_instances: restored.marked,
+            alarm_maintenance: restored.muted.map(restore_maintenance),
+            alarm_form: None,
+            incident: None,
+            held_notifications: Held::default(),
+
+            threshold_editor: None,
+            pin_prompt: None,
+            export_prompt: None,
+
+            bookmarks_panel: None,
+            profiles_panel: None,
+
+            jobs: JobManager::default(),
+            prefetch: Prefetch::default(),
+            active_account: None,
+            regions_panel: None,
+            active_region: None,
+            all_regions: false,
+            jobs_panel: None,
+
+            perf_hud_open: false,
+            perf: PerfStats::default(),
+            timings: PageTimings::default(),
+            internals: Internals::default(),
+            command_line: None,
+
+            announcer: Announcer::default(),
+
+            update_check: None,
+            available_update: None,
+            release_notes_open: false,
+
+            journal,
+
+            baselines: BaselineStore::load(),
+            resource_history: ResourceHistory::load(),
+
+            graphics,
+            loaded_chart_image: None,
+            chart_image_area: None,
+        }
     }
 
     // ================================
@@ -60,14 +230,40 @@ impl App {
         if !self.auto_refresh_enabled {
             return false;
         }
-        match self.last_refresh {
+        let interval = match self.incident {
+            Some(_) => self.config.refresh_interval.min(incident::REFRESH_INTERVAL),
+            None => self.quiet_interval(self.config.refresh_interval),
+        };
+        // CloudWatch is asked less often until throttling has cooled down
+        let interval = limiter::cloudwatch().stretched(interval);
+        match self.view.last_refresh {
             None => true,
-            Some(last) => last.elapsed() > Duration::from_secs(30), // Reduced from 60 to 30 seconds
+            Some(last) => last.elapsed() > interval,
+        }
+    }
+
+    /// Whether a recently sent test message is still being watched and is due a refresh
+    pub fn needs_watch_refresh(&self) -> bool {
+        let Some(sent) = &self.sent_test_message else {
+            return false;
+        };
+        if sent.sent_at.elapsed() > TEST_MESSAGE_WATCH_DURATION {
+            return false;
+        }
+        // Only while the watched queue is still the one open
+        if self
+            .get_selected_sqs_queue()
+            .is_none_or(|queue| queue.name != sent.queue_name)
+        {
+            return false;
         }
+        self.view
+            .last_refresh
+            .is_none_or(|last| last.elapsed() > TEST_MESSAGE_WATCH_INTERVAL)
     }
 
     pub fn mark_refreshed(&mut self) {
-        self.last_refresh = Some(Instant::now());
+        self.view.last_refresh = Some(Instant::now());
     }
 
     pub fn clear_error(&mut self) {
@@ -100,7 +296,7 @@ impl App {
         if self.available_services.is_empty() {
             return;
         }
-        let i = match self.service_list_state.selected() {
+        let i = match self.view.service_list_state.selected() {
             Some(i) => {
                 if i >= self.available_services.len() - 1 {
                     0
@@ -110,14 +306,14 @@ impl App {
             }
             None => 0,
         };
-        self.service_list_state.select(Some(i));
+        self.view.service_list_state.select(Some(i));
     }
 
     pub fn service_previous(&mut self) {
         if self.available_services.is_empty() {
             return;
         }
-        let i = match self.service_list_state.selected() {
+        let i = match self.view.service_list_state.selected() {
             Some(i) => {
                 if i == 0 {
                     self.available_services.len() - 1
@@ -127,16 +323,20 @@ impl App {
             }
             None => 0,
         };
-        self.service_list_state.select(Some(i));
+        self.view.service_list_state.select(Some(i));
     }
 
     pub fn next(&mut self) {
-        if self.instances.is_empty() {
+        if self.folders_shown() {
+            self.move_folder_cursor(true);
+            return;
+        }
+        if self.view.instances.is_empty() {
             return;
         }
-        let i = match self.list_state.selected() {
+        let i = match self.view.list_state.selected() {
             Some(i) => {
-                if i >= self.instances.len() - 1 {
+                if i >= self.view.instances.len() - 1 {
                     0
                 } else {
                     i + 1
@@ -144,24 +344,28 @@ impl App {
             }
             None => 0,
         };
-        self.list_state.select(Some(i));
+        self.view.list_state.select(Some(i));
     }
 
     pub fn previous(&mut self) {
-        if self.instances.is_empty() {
+        if self.folders_shown() {
+            self.move_folder_cursor(false);
+            return;
+        }
+        if self.view.instances.is_empty() {
             return;
         }
-        let i = match self.list_state.selected() {
+        let i = match self.view.list_state.selected() {
             Some(i) => {
                 if i == 0 {
-                    self.instances.len() - 1
+                    self.view.instances.len() - 1
                 } else {
                     i - 1
                 }
             }
             None => 0,
         };
-        self.list_state.select(Some(i));
+        self.view.list_state.select(Some(i));
     }
 
     // ================================
@@ -169,11 +373,12 @@ impl App {
     // ================================
 
     pub fn select_service(&mut self) -> Option<&AwsService> {
-        if let Some(index) = self.service_list_state.selected() {
+        self.remember_view();
+        if let Some(index) = self.view.service_list_state.selected() {
             if let Some(service) = self.available_services.get(index) {
-                self.selected_service = Some(service.clone());
-                self.state = AppState::InstanceList;
-                self.list_state.select(Some(0));
+                self.view.selected_service = Some(service.clone());
+                self.view.state = AppState::InstanceList;
+                self.view.list_state.select(Some(0));
                 return Some(service);
             }
         }
@@ -181,68 +386,235 @@ impl App {
     }
 
     pub fn back_to_service_list(&mut self) {
-        self.state = AppState::ServiceList;
-        self.selected_service = None;
-        self.instances.clear();
-        self.rds_instances.clear();
+        self.remember_view();
+        self.view.state = AppState::ServiceList;
+        self.view.selected_service = None;
+        self.view.instances.clear();
+        self.view.rds_instances.clear();
+        self.view.queue_folders.selected = None;
+        if !self.marked_instances.is_empty() {
+            self.marked_instances.clear();
+            self.record_journal(JournalEntry::MarksCleared);
+        }
         self.loading = true;
     }
 
     pub async fn load_service_instances(&mut self, service: &AwsService) -> Result<()> {
+        if self.all_regions {
+            return self.load_instances_in_all_regions(service).await;
+        }
+        self.view.instance_regions.clear();
         match service {
             AwsService::Rds => match load_rds_instances().await {
                 Ok(rds_instances) => {
-                    self.rds_instances = rds_instances.clone();
-                    self.instances = rds_instances
+                    let sticky = self.sticky_selection();
+                    self.view.rds_instances = rds_instances.clone();
+                    self.view.instances = rds_instances
                         .into_iter()
                         .map(ServiceInstance::Rds)
                         .collect();
+                    self.append_departed_resources(service);
+                    self.apply_watchlist_filter();
+                    self.load_blue_green_deployments().await;
+                    self.load_db_secrets().await;
+                    self.load_alarms().await;
                     self.clear_error();
                     self.loading = false;
                     self.mark_refreshed();
-
-                    if !self.instances.is_empty() {
-                        let current_selection = self.list_state.selected().unwrap_or(0);
-                        let new_selection = if current_selection < self.instances.len() {
-                            current_selection
-                        } else {
-                            0
-                        };
-                        self.list_state.select(Some(new_selection));
-                    } else {
-                        self.list_state.select(None);
-                    }
+                    self.restore_selection(&sticky);
                     Ok(())
                 }
                 Err(e) => {
                     self.error_message = Some(format!("AWS Error: {e}"));
                     self.loading = false;
-                    self.instances = Vec::new();
-                    self.rds_instances = Vec::new();
-                    self.list_state.select(None);
+                    self.view.instances = Vec::new();
+                    self.view.rds_instances = Vec::new();
+                    self.view.list_state.select(None);
                     Ok(())
                 }
             },
+            AwsService::Sqs => {
+                let queues = SqsClientManager::new().await.load_queues().await;
+                self.finish_loading_instances(
+                    queues.map(|queues| queues.into_iter().map(ServiceInstance::Sqs).collect()),
+                )
+                .await?;
+                if self.view.queue_folders.shown {
+                    self.load_queue_ages().await;
+                }
+                Ok(())
+            }
+            AwsService::Synthetics => {
+                let canaries = SyntheticsClientManager::new().await.load_canaries().await;
+                self.finish_loading_instances(
+                    canaries.map(|canaries| {
+                        canaries.into_iter().map(ServiceInstance::Canary).collect()
+                    }),
+                )
+                .await
+            }
+            AwsService::EventBridge => {
+                let rules = EventBridgeClientManager::new().await.load_rules().await;
+                self.finish_loading_instances(
+                    rules.map(|rules| rules.into_iter().map(ServiceInstance::EventRule).collect()),
+                )
+                .await
+            }
+            AwsService::Batch => {
+                let queues = BatchClientManager::new().await.load_job_queues().await;
+                self.finish_loading_instances(queues.map(|queues| {
+                    queues
+                        .into_iter()
+                        .map(ServiceInstance::BatchQueue)
+                        .collect()
+                }))
+                .await
+            }
+            AwsService::Lambda => {
+                let functions = LambdaClient::new().await.list_functions().await;
+                self.finish_loading_instances(functions.map(|functions| {
+                    functions
+                        .into_iter()
+                        .map(ServiceInstance::Function)
+                        .collect()
+                }))
+                .await
+            }
+        }
+    }
+
+    /// Show the resources of a non-RDS service, keeping the selection in range
+    async fn finish_loading_instances(
+        &mut self,
+        result: Result<Vec<ServiceInstance>>,
+    ) -> Result<()> {
+        match result {
+            Ok(instances) => {
+                let sticky = self.sticky_selection();
+                self.view.instances = instances;
+                if let Some(service) = self.view.selected_service.clone() {
+                    self.append_departed_resources(&service);
+                }
+                self.apply_watchlist_filter();
+                self.load_alarms().await;
+                self.clear_error();
+                self.loading = false;
+                self.mark_refreshed();
+                self.restore_selection(&sticky);
+                Ok(())
+            }
+            Err(e) => {
+                self.error_message = Some(format!("AWS Error: {e}"));
+                self.loading = false;
+                self.view.instances = Vec::new();
+                self.view.list_state.select(None);
+                Ok(())
+            }
+        }
+    }
+
+    /// Record the listed resources and append the ones listed before but gone now
+    fn append_departed_resources(&mut self, service: &AwsService) {
+        let listed: Vec<String> = self
+            .view
+            .instances
+            .iter()
+            .map(|instance| instance.as_aws_instance().id().to_string())
+            .collect();
+        let departed = self.resource_history.record(
+            service.short_name(),
+            listed.iter().map(String::as_str),
+            unix_now(),
+        );
+        // Like the baselines, the history only adds context; a failed write is not worth interrupting for
+        let _ = self.resource_history.save();
+
+        for (id, _) in &departed {
+            let instance = ServiceInstance::departed(service, id);
+            if let ServiceInstance::Rds(rds) = &instance {
+                self.view.rds_instances.push(rds.clone());
+            }
+            self.view.instances.push(instance);
+        }
+        self.view.departed = departed.into_iter().collect();
+    }
+
+    /// The highlighted and open resources by ID, to find again once the list is replaced
+    fn sticky_selection(&self) -> StickySelection {
+        StickySelection {
+            highlighted: self
+                .view
+                .list_state
+                .selected()
+                .and_then(|index| Some((index, self.listed_key(index)?))),
+            open: self
+                .view
+                .selected_instance
+                .and_then(|index| self.listed_key(index)),
+            offset: self.view.list_state.offset(),
+        }
+    }
+
+    /// Region and ID of a listed resource; the region is only set when the list spans all regions
+    pub fn listed_key(&self, index: usize) -> Option<ResourceKey> {
+        let instance = self.view.instances.get(index)?;
+        Some((
+            self.instance_region(index).map(str::to_string),
+            instance.as_aws_instance().id().to_string(),
+        ))
+    }
+
+    /// Point the highlight, scroll position and open resource back at the
+    /// resources `sticky` was taken from; a fresh list starts at the top
+    fn restore_selection(&mut self, sticky: &StickySelection) {
+        let keys: Vec<ResourceKey> = (0..self.view.instances.len())
+            .filter_map(|index| self.listed_key(index))
+            .collect();
+        let (highlighted, open, offset) = sticky.resolve(&keys);
+        let highlighted = highlighted.or((!keys.is_empty()).then_some(0));
+        self.view.list_state.select(highlighted);
+        if self.folder_selected() {
+            // The cursor stays on the folder rather than a queue in it
+            self.view.list_state.select(None);
+        }
+        *self.view.list_state.offset_mut() = offset;
+        if self.view.selected_instance.is_some() {
+            self.view.selected_instance = open;
         }
     }
 
+    /// Whether a listed resource is one that has gone, and when it was last seen
+    pub fn departed_since(&self, instance: &ServiceInstance) -> Option<u64> {
+        self.view
+            .departed
+            .get(instance.as_aws_instance().id())
+            .copied()
+    }
+
     pub async fn load_rds_instances(&mut self) -> Result<()> {
+        if self.all_regions {
+            return self.load_instances_in_all_regions(&AwsService::Rds).await;
+        }
+        self.view.instance_regions.clear();
         self.loading = true;
         self.loading_start_time = Some(Instant::now());
         self.error_message = None;
 
         match RdsInstanceManager::load_instances().await {
             Ok(instances) => {
+                let sticky = self.sticky_selection();
                 // Store in both places for compatibility
-                self.rds_instances = instances.clone();
-                self.instances = instances.into_iter().map(ServiceInstance::Rds).collect();
+                self.view.rds_instances = instances.clone();
+                self.view.instances = instances.into_iter().map(ServiceInstance::Rds).collect();
+                self.append_departed_resources(&AwsService::Rds);
+                self.apply_watchlist_filter();
+                self.load_blue_green_deployments().await;
+                self.load_db_secrets().await;
+                self.load_alarms().await;
 
                 self.loading = false;
                 self.loading_start_time = None;
-                self.list_state = ratatui::widgets::ListState::default();
-                if !self.instances.is_empty() {
-                    self.list_state.select(Some(0));
-                }
+                self.restore_selection(&sticky);
 
                 // Mark as refreshed to prevent continuous refresh loops
                 self.mark_refreshed();
@@ -262,12 +634,12 @@ impl App {
     // ================================
 
     pub fn get_current_instances(&self) -> &Vec<ServiceInstance> {
-        &self.instances
+        &self.view.instances
     }
 
     pub fn get_selected_instance(&self) -> Option<&ServiceInstance> {
-        if let Some(index) = self.list_state.selected() {
-            self.instances.get(index)
+        if let Some(index) = self.view.list_state.selected() {
+            self.view.instances.get(index)
         } else {
             None
         }
@@ -280,8 +652,9 @@ impl App {
 
     /// Safely get the selected RDS instance with bounds checking
     pub fn get_selected_rds_instance(&self) -> Option<&RdsInstance> {
-        self.selected_instance
-            .and_then(|index| self.rds_instances.get(index))
+        self.view
+            .selected_instance
+            .and_then(|index| self.view.rds_instances.get(index))
     }
 
     /// Safely get the selected RDS instance ID with bounds checking
@@ -290,286 +663,5944 @@ impl App {
             .map(|instance| instance.identifier.clone())
     }
 
+    /// Safely get the queue currently shown in the metrics view
+    pub fn get_selected_sqs_queue(&self) -> Option<&SqsQueue> {
+        match self
+            .view
+            .selected_instance
+            .and_then(|index| self.view.instances.get(index))
+        {
+            Some(ServiceInstance::Sqs(queue)) => Some(queue),
+            _ => None,
+        }
+    }
+
+    /// Safely get the canary currently shown in the metrics view
+    pub fn get_selected_canary(&self) -> Option<&Canary> {
+        match self
+            .view
+            .selected_instance
+            .and_then(|index| self.view.instances.get(index))
+        {
+            Some(ServiceInstance::Canary(canary)) => Some(canary),
+            _ => None,
+        }
+    }
+
+    /// Safely get the EventBridge rule currently shown in the metrics view
+    pub fn get_selected_event_rule(&self) -> Option<&EventRule> {
+        match self
+            .view
+            .selected_instance
+            .and_then(|index| self.view.instances.get(index))
+        {
+            Some(ServiceInstance::EventRule(rule)) => Some(rule),
+            _ => None,
+        }
+    }
+
+    /// Safely get the Batch job queue currently shown in the metrics view
+    pub fn get_selected_batch_queue(&self) -> Option<&BatchJobQueue> {
+        match self
+            .view
+            .selected_instance
+            .and_then(|index| self.view.instances.get(index))
+        {
+            Some(ServiceInstance::BatchQueue(queue)) => Some(queue),
+            _ => None,
+        }
+    }
+
+    /// Safely get the Lambda function currently shown in the metrics view
+    pub fn get_selected_lambda_function(&self) -> Option<&LambdaFunction> {
+        match self
+            .view
+            .selected_instance
+            .and_then(|index| self.view.instances.get(index))
+        {
+            Some(ServiceInstance::Function(function)) => Some(function),
+            _ => None,
+        }
+    }
+
+    /// Compute environment of the selected job queue whose metrics are charted
+    pub fn get_selected_compute_environment(&self) -> Option<&ComputeEnvironment> {
+        self.get_selected_batch_queue()?
+            .compute_environments
+            .get(self.view.compute_environment_index)
+    }
+
     // ================================
     // 6. METRICS MANAGEMENT
     // ================================
 
-    pub async fn load_metrics(&mut self, instance_id: &str) -> Result<()> {
+    /// Load metrics for a non-RDS resource through its registered metric provider
+    pub async fn load_service_metrics(&mut self, resource_id: &str) -> Result<()> {
+        let Some(service) = self.view.selected_service.clone() else {
+            return Ok(());
+        };
         self.metrics_loading = true;
+        self.load_quotas(&service).await;
 
-        let _service = self.selected_service.as_ref().unwrap_or(&AwsService::Rds);
+        let factory = MetricServiceFactory::new();
+        let result = match factory.get_provider(&service) {
+            Ok(provider) => {
+                let attempted = provider.get_metrics_config().len();
+                fetch_service_metrics(provider, resource_id, self.view.time_range)
+                    .await
+                    .and_then(|(metrics, failures)| {
+                        match every_metric_failed(&failures, attempted) {
+                            Some(e) => Err(e),
+                            None => Ok((metrics, failures)),
+                        }
+                    })
+            }
+            Err(e) => Err(e),
+        };
 
-        match load_metrics(instance_id, self.time_range).await {
-            Ok(metrics) => {
-                self.metrics = metrics;
-                self.metrics_loading = false;
+        self.metrics_loading = false;
+        match result {
+            Ok((metrics, failures)) => {
+                self.view.service_metrics = Some(metrics);
+                self.view.failed_metrics = failures;
                 self.clear_error();
-                self.initialize_sparkline_grid();
-                // Mark as refreshed after successful metrics load
                 self.mark_refreshed();
-                Ok(())
             }
             Err(e) => {
-                self.metrics_loading = false;
                 self.error_message = Some(format!("CloudWatch Error: {e}"));
-                self.metrics = crate::models::MetricData::default();
-                self.selected_metric = None;
-                self.sparkline_grid_selected_index = 0;
-                Ok(())
+                self.view.service_metrics = None;
+                self.view.failed_metrics.clear();
             }
         }
+        Ok(())
     }
 
-    pub fn get_available_metrics(&self) -> Vec<MetricType> {
-        self.metrics.get_available_metrics()
-    }
-
-    pub fn get_sparkline_grid_selected_index(&self) -> usize {
-        self.sparkline_grid_selected_index
-    }
-
-    pub fn update_selected_metric(&mut self) {
-        let available_metrics = self.metrics.get_available_metrics();
-        if let Some(metric) = available_metrics.get(self.sparkline_grid_selected_index) {
-            self.selected_metric = Some(metric.clone());
+    /// Refetch the metrics whose last fetch failed, or only `metric_name`
+    ///
+    /// The metrics that loaded are kept as they are; only the failed tiles
+    /// are asked for again.
+    pub async fn retry_failed_metrics(&mut self, metric_name: Option<&str>) -> Result<()> {
+        let retry: FailedMetrics = self
+            .view
+            .failed_metrics
+            .iter()
+            .filter(|(name, _)| metric_name.is_none_or(|only| only == name.as_str()))
+            .map(|(name, reason)| (name.clone(), reason.clone()))
+            .collect();
+        if retry.is_empty() {
+            self.status_message = Some("No failed metrics to retry".to_string());
+            return Ok(());
         }
-    }
 
-    pub fn initialize_sparkline_grid(&mut self) {
-        let available_metrics = self.metrics.get_available_metrics();
-        if !available_metrics.is_empty() {
-            if self.selected_metric.is_none() {
-                self.selected_metric = Some(available_metrics[0].clone());
-                self.sparkline_grid_selected_index = 0;
-            } else if let Some(ref current_metric) = self.selected_metric {
-                if let Some(index) = available_metrics.iter().position(|m| m == current_metric) {
-                    self.sparkline_grid_selected_index = index;
-                } else {
-                    self.selected_metric = Some(available_metrics[0].clone());
-                    self.sparkline_grid_selected_index = 0;
-                }
+        let failures = match self.view.selected_service.clone() {
+            None | Some(AwsService::Rds) => {
+                let Some(instance_id) = self.get_selected_rds_instance_id() else {
+                    return Ok(());
+                };
+                let metrics: Vec<_> = MetricType::ALL
+                    .into_iter()
+                    .filter(|metric| retry.contains_key(metric.metric_name()))
+                    .collect();
+                self.refetch_rds_metrics(&instance_id, &metrics).await?
             }
-        } else {
-            self.selected_metric = None;
-            self.sparkline_grid_selected_index = 0;
-        }
-    }
-
-    // ================================
-    // 7. TIME RANGE MANAGEMENT
-    // ================================
+            Some(service) => {
+                let names: Vec<_> = retry.keys().cloned().collect();
+                self.refetch_service_metrics(service, &names).await?
+            }
+        };
 
-    pub fn update_time_range(
-        &mut self,
-        value: u32,
-        unit: TimeUnit,
-        period_days: u32,
-    ) -> Result<()> {
-        self.time_range = TimeRange::new(value, unit, period_days)?;
+        self.view
+            .failed_metrics
+            .retain(|name, _| !retry.contains_key(name));
+        self.status_message = Some(match failures.len() {
+            0 => format!("Loaded {} metric(s) that had failed", retry.len()),
+            still => format!("{still} of {} metric(s) failed again", retry.len()),
+        });
+        self.view.failed_metrics.extend(failures);
         Ok(())
     }
 
-    pub fn get_time_range_options() -> Vec<(&'static str, u32, TimeUnit, u32)> {
-        vec![
-            ("5 minutes", 5, TimeUnit::Minutes, 1),
-            ("1 hour", 1, TimeUnit::Hours, 1),
-            ("3 hours", 3, TimeUnit::Hours, 1),
-            ("6 hours", 6, TimeUnit::Hours, 1),
-            ("12 hours", 12, TimeUnit::Hours, 1),
-            ("1 day", 1, TimeUnit::Days, 1),
-            ("3 days", 3, TimeUnit::Days, 1),
-            ("1 week", 1, TimeUnit::Weeks, 7),
-            ("2 weeks", 2, TimeUnit::Weeks, 14),
-            ("1 month", 1, TimeUnit::Months, 30),
-        ]
-    }
+    /// Refresh only the metric in focus: the charted one, or the selected grid tile or row
+    ///
+    /// The other metrics are passed through as loaded, so this is a single
+    /// CloudWatch call instead of one per metric.
+    pub async fn refresh_focused_metric(&mut self) -> Result<()> {
+        let Some(name) = self.focused_metric() else {
+            return Ok(());
+        };
 
-    pub fn get_current_time_range_index(&self) -> usize {
-        self.time_range_scroll
-    }
+        let (label, failures) = match self.view.selected_service.clone() {
+            None | Some(AwsService::Rds) => {
+                let Some(instance_id) = self.get_selected_rds_instance_id() else {
+                    return Ok(());
+                };
+                let Some(metric) = MetricType::ALL
+                    .into_iter()
+                    .find(|metric| metric.metric_name() == name)
+                else {
+                    return Ok(());
+                };
+                let charted = self.detail_chart_metric().as_ref() == Some(&metric);
+                let failures = self
+                    .refetch_rds_metrics(&instance_id, std::slice::from_ref(&metric))
+                    .await?;
+                // A CloudWatch-rendered image is keyed by the request alone, so new data would not replace it
+                if charted {
+                    self.loaded_chart_image = None;
+                }
+                (metric.display_name().to_string(), failures)
+            }
+            Some(service) => {
+                let failures = self
+                    .refetch_service_metrics(service, std::slice::from_ref(&name))
+                    .await?;
+                (name.clone(), failures)
+            }
+        };
 
-    pub fn select_time_range(&mut self, index: usize) -> Result<()> {
-        let options = Self::get_time_range_options();
-        if let Some(&(_, value, unit, period_days)) = options.get(index) {
-            self.time_range_scroll = index;
-            self.time_range = TimeRange::new(value, unit, period_days)?;
-            Ok(())
-        } else {
-            Ok(())
-        }
+        self.status_message = Some(match failures.get(&name) {
+            Some(reason) => format!("{label} failed to refresh: {reason}"),
+            None => format!("Refreshed {label}"),
+        });
+        self.view.failed_metrics.remove(&name);
+        self.view.failed_metrics.extend(failures);
+        Ok(())
     }
 
-    pub fn time_range_scroll_up(&mut self) {
-        if self.time_range_scroll > 0 {
-            self.time_range_scroll -= 1;
+    /// CloudWatch name of the metric in focus
+    ///
+    /// For RDS that is the charted metric or the selected grid tile; for other
+    /// services, the selected row of the metrics the summary shows.
+    pub fn focused_metric(&self) -> Option<String> {
+        match self.view.selected_service.clone() {
+            None | Some(AwsService::Rds) => {
+                let metric = match self.view.state {
+                    AppState::InstanceDetails => self.detail_chart_metric(),
+                    _ => self
+                        .get_available_metrics()
+                        .into_iter()
+                        .nth(self.view.sparkline_grid_selected_index),
+                };
+                metric.map(|metric| metric.metric_name().to_string())
+            }
+            Some(service) => {
+                let factory = MetricServiceFactory::new();
+                let loaded = self.view.service_metrics.as_ref()?;
+                // The rows the summary shows, in the provider's order
+                factory
+                    .get_provider(&service)
+                    .ok()?
+                    .get_metrics_config()
+                    .into_iter()
+                    .map(|definition| definition.name)
+                    .filter(|name| {
+                        loaded.raw_metrics.contains_key(name)
+                            || self.view.failed_metrics.contains_key(name)
+                    })
+                    .nth(self.view.sparkline_grid_selected_index)
+            }
         }
     }
 
-    pub fn time_range_scroll_down(&mut self) {
-        let options = Self::get_time_range_options();
-        if self.time_range_scroll < options.len() - 1 {
-            self.time_range_scroll += 1;
-        }
+    /// Fetch only the provider metrics `names` of the open non-RDS resource again
+    ///
+    /// Returns the ones whose fetch failed; the caller records them.
+    async fn refetch_service_metrics(
+        &mut self,
+        service: AwsService,
+        names: &[String],
+    ) -> Result<FailedMetrics> {
+        let resource_id = match service {
+            AwsService::Batch => self
+                .get_selected_compute_environment()
+                .and_then(|environment| environment.ecs_cluster.clone()),
+            _ => self.get_selected_instance_id(),
+        };
+        let Some(resource_id) = resource_id else {
+            return Ok(FailedMetrics::new());
+        };
+        let factory = MetricServiceFactory::new();
+        let provider = factory.get_provider(&service)?;
+        let mut metrics = self
+            .view
+            .service_metrics
+            .take()
+            .unwrap_or_else(|| ServiceMetrics::new(service.clone()));
+        let result = refetch_service_metrics(
+            &mut metrics,
+            provider,
+            names,
+            &resource_id,
+            self.view.time_range,
+        )
+        .await;
+        self.view.service_metrics = Some(metrics);
+        result
     }
 
-    // ================================
-    // 8. SCREEN NAVIGATION & STATE TRANSITIONS
-    // ================================
+    /// Fetch only `metrics` again, passing every other loaded series through
+    ///
+    /// Returns the ones whose fetch failed; the caller records them.
+    async fn refetch_rds_metrics(
+        &mut self,
+        instance_id: &str,
+        metrics: &[MetricType],
+    ) -> Result<FailedMetrics> {
+        let reused = self.reused_metrics(|metric| !metrics.contains(metric));
+        let (loaded, failures) = load_metrics(instance_id, self.view.time_range, &reused).await?;
+        self.view.metrics = loaded;
+        self.learn_baselines(instance_id);
+        self.script_output = self.script_host.evaluate(&self.view.metrics);
+        self.initialize_sparkline_grid();
 
-    pub fn enter_metrics_summary(&mut self) {
-        if let Some(i) = self.list_state.selected() {
-            self.selected_instance = Some(i);
-            self.state = AppState::MetricsSummary;
-            self.metrics_summary_scroll = 0;
-            self.scroll_offset = 0;
-            self.focused_panel = FocusedPanel::TimeRanges;
-            self.sparkline_grid_selected_index = 0;
-            self.initialize_sparkline_grid();
-        }
+        let fetched = metrics
+            .iter()
+            .filter(|metric| !failures.contains_key(metric.metric_name()))
+            .cloned();
+        self.view
+            .metric_schedule
+            .mark_fetched(fetched, Instant::now());
+        self.view.metric_schedule.mark_failed(failures.keys());
+        Ok(failures)
     }
 
-    pub fn back_to_metrics_summary(&mut self) {
-        self.state = AppState::MetricsSummary;
-        self.scroll_offset = self.metrics_summary_scroll;
-        self.focused_panel = self.saved_focused_panel.clone();
-        self.sparkline_grid_selected_index = self.saved_sparkline_grid_selected_index;
-        self.update_selected_metric();
+    /// CloudWatch name of the selected grid tile when its fetch failed
+    pub fn selected_failed_metric(&self) -> Option<String> {
+        let metric = self
+            .get_available_metrics()
+            .into_iter()
+            .nth(self.view.sparkline_grid_selected_index)?;
+        let name = metric.metric_name();
+        self.view
+            .failed_metrics
+            .contains_key(name)
+            .then(|| name.to_string())
     }
 
-    pub fn enter_instance_details(&mut self) {
-        if let Some(i) = self.list_state.selected() {
-            self.selected_instance = Some(i);
-            self.state = AppState::InstanceDetails;
-            self.metrics_summary_scroll = self.scroll_offset;
-            self.saved_focused_panel = self.focused_panel.clone();
-            self.saved_sparkline_grid_selected_index = self.sparkline_grid_selected_index;
-
-            let available_metrics_count = self.metrics.count_available_metrics();
-            self.scroll_offset = self
-                .sparkline_grid_selected_index
-                .min(available_metrics_count.saturating_sub(1));
+    /// Reload the selected queue's attributes and metrics
+    pub async fn refresh_selected_queue(&mut self) -> Result<()> {
+        let Some(queue) = self.get_selected_sqs_queue().cloned() else {
+            return Ok(());
+        };
+
+        match SqsClientManager::new().await.load_queue(queue.url).await {
+            Ok(updated) => {
+                if let Some(slot) = self
+                    .view
+                    .selected_instance
+                    .and_then(|index| self.view.instances.get_mut(index))
+                {
+                    *slot = ServiceInstance::Sqs(updated);
+                }
+            }
+            Err(e) => self.error_message = Some(format!("AWS Error: {e}")),
         }
-    }
 
-    pub fn back_to_list(&mut self) {
-        self.state = AppState::InstanceList;
-        self.selected_instance = None;
-        self.scroll_offset = 0;
-        self.metrics_summary_scroll = 0;
+        self.load_service_metrics(&queue.name).await
     }
 
-    // ================================
-    // 9. SCROLLING & PANEL MANAGEMENT
-    // ================================
+    /// Reload the selected canary's last run and metrics
+    pub async fn refresh_selected_canary(&mut self) -> Result<()> {
+        let Some(name) = self.get_selected_canary().map(|canary| canary.name.clone()) else {
+            return Ok(());
+        };
 
-    pub fn scroll_up(&mut self) {
-        match self.state {
-            AppState::MetricsSummary => match self.focused_panel {
-                FocusedPanel::TimeRanges => {
-                    self.time_range_scroll_up();
-                }
-                FocusedPanel::SparklineGrid => {
-                    self.sparkline_grid_scroll_up();
-                }
-            },
-            _ => {
-                if self.scroll_offset > 0 {
-                    self.scroll_offset -= 1;
+        match SyntheticsClientManager::new()
+            .await
+            .load_canary(&name)
+            .await
+        {
+            Ok(updated) => {
+                if let Some(slot) = self
+                    .view
+                    .selected_instance
+                    .and_then(|index| self.view.instances.get_mut(index))
+                {
+                    *slot = ServiceInstance::Canary(updated);
                 }
             }
+            Err(e) => self.error_message = Some(format!("AWS Error: {e}")),
         }
+
+        self.load_service_metrics(&name).await
     }
 
-    pub fn scroll_down(&mut self) {
-        match self.state {
-            AppState::MetricsSummary => match self.focused_panel {
-                FocusedPanel::TimeRanges => {
-                    self.time_range_scroll_down();
-                }
-                FocusedPanel::SparklineGrid => {
-                    self.sparkline_grid_scroll_down();
-                }
-            },
-            AppState::InstanceDetails => {
-                let total_individual_metrics = self.metrics.count_available_metrics();
-                let max_offset = total_individual_metrics.saturating_sub(1);
-                if self.scroll_offset < max_offset {
-                    self.scroll_offset += 1;
+    /// Reload the selected rule's targets and metrics
+    ///
+    /// Targets are re-listed so a dead-letter queue added in the console
+    /// shows up without reloading every rule.
+    pub async fn refresh_selected_event_rule(&mut self) -> Result<()> {
+        let Some(rule) = self.get_selected_event_rule().cloned() else {
+            return Ok(());
+        };
+
+        match EventBridgeClientManager::new()
+            .await
+            .load_targets(&rule.event_bus, &rule.name)
+            .await
+        {
+            Ok(targets) => {
+                if let Some(slot) = self
+                    .view
+                    .selected_instance
+                    .and_then(|index| self.view.instances.get_mut(index))
+                {
+                    *slot = ServiceInstance::EventRule(rule.clone().with_targets(targets));
                 }
             }
-            _ => {}
+            Err(e) => self.error_message = Some(format!("AWS Error: {e}")),
         }
+
+        self.load_service_metrics(&rule.id).await
     }
 
-    pub fn reset_scroll(&mut self) {
-        match self.state {
-            AppState::MetricsSummary => {
-                self.metrics_summary_scroll = 0;
-                self.scroll_offset = 0;
-                self.focused_panel = FocusedPanel::TimeRanges;
-                self.saved_focused_panel = FocusedPanel::TimeRanges;
-                self.sparkline_grid_scroll = 0;
-                self.sparkline_grid_selected_index = 0;
-                self.saved_sparkline_grid_selected_index = 0;
-                self.initialize_sparkline_grid();
-            }
-            _ => {
-                self.scroll_offset = 0;
-                self.sparkline_grid_selected_index = 0;
-                self.metrics_summary_scroll = 0;
+    /// Reload the selected function's configuration and metrics
+    ///
+    /// Listing functions leaves out their state, so the configuration is read
+    /// on refresh to show whether the function is active or failed.
+    pub async fn refresh_selected_function(&mut self) -> Result<()> {
+        let Some(name) = self
+            .get_selected_lambda_function()
+            .map(|function| function.name.clone())
+        else {
+            return Ok(());
+        };
+
+        match LambdaClient::new().await.function(&name).await {
+            Ok(updated) => {
+                if let Some(slot) = self
+                    .view
+                    .selected_instance
+                    .and_then(|index| self.view.instances.get_mut(index))
+                {
+                    *slot = ServiceInstance::Function(updated);
+                }
             }
+            Err(e) => self.error_message = Some(format!("AWS Error: {e}")),
         }
+
+        self.load_service_metrics(&name).await
     }
 
-    pub fn switch_panel(&mut self) {
-        self.focused_panel = match self.focused_panel {
-            FocusedPanel::TimeRanges => FocusedPanel::SparklineGrid,
-            FocusedPanel::SparklineGrid => FocusedPanel::TimeRanges,
+    /// Load the ECS metrics of the compute environment charted for the selected job queue
+    pub async fn load_compute_environment_metrics(&mut self) -> Result<()> {
+        let Some(environment) = self.get_selected_compute_environment() else {
+            self.view.service_metrics = None;
+            return Ok(());
+        };
+        let Some(cluster) = environment.ecs_cluster.clone() else {
+            self.status_message = Some(format!(
+                "Compute environment {} has no ECS cluster yet",
+                environment.name
+            ));
+            self.view.service_metrics = None;
+            return Ok(());
         };
+        self.load_service_metrics(&cluster).await
     }
 
-    pub fn get_focused_panel(&self) -> &FocusedPanel {
-        &self.focused_panel
+    /// Chart the next (or previous) compute environment of the selected job queue
+    pub async fn cycle_compute_environment(&mut self, forward: bool) -> Result<()> {
+        let count = self
+            .get_selected_batch_queue()
+            .map_or(0, |queue| queue.compute_environments.len());
+        if count < 2 {
+            return Ok(());
+        }
+        let index = self.view.compute_environment_index;
+        self.view.compute_environment_index = if forward {
+            (index + 1) % count
+        } else {
+            (index + count - 1) % count
+        };
+        self.load_compute_environment_metrics().await
     }
 
-    /// Update metrics_per_screen based on available area
-    /// This should be called before rendering to ensure navigation functions work correctly
-    pub fn update_metrics_per_screen(&mut self, area_height: u16) {
-        let items_per_screen = (area_height.saturating_sub(2)) as usize; // Account for borders
-                                                                         // Each metric takes 3 lines (frame only)
-        let actual_metrics_per_screen = items_per_screen.div_ceil(3);
-        self.metrics_per_screen = actual_metrics_per_screen;
-    }
+    /// Reload the selected job queue's job counts, compute environments and metrics
+    pub async fn refresh_selected_batch_queue(&mut self) -> Result<()> {
+        let Some(name) = self
+            .get_selected_batch_queue()
+            .map(|queue| queue.name.clone())
+        else {
+            return Ok(());
+        };
 
-    // ================================
-    // 10. SPARKLINE GRID NAVIGATION
-    // ================================
+        match BatchClientManager::new().await.load_job_queue(&name).await {
+            Ok(updated) => {
+                self.view.compute_environment_index = self
+                    .view
+                    .compute_environment_index
+                    .min(updated.compute_environments.len().saturating_sub(1));
+                if let Some(slot) = self
+                    .view
+                    .selected_instance
+                    .and_then(|index| self.view.instances.get_mut(index))
+                {
+                    *slot = ServiceInstance::BatchQueue(updated);
+                }
+            }
+            Err(e) => self.error_message = Some(format!("AWS Error: {e}")),
+        }
 
-    pub fn sparkline_grid_scroll_up(&mut self) {
-        if self.sparkline_grid_selected_index > 0 {
-            self.sparkline_grid_selected_index -= 1;
-            self.update_selected_metric();
+        self.load_compute_environment_metrics().await
+    }
+
+    /// Copy the S3 console link for the selected canary's latest artifacts
+    pub fn copy_canary_artifacts_link(&mut self) {
+        let Some(canary) = self.get_selected_canary() else {
+            return;
+        };
+        let location = canary
+            .last_run
+            .as_ref()
+            .map(|run| run.artifact_location.as_str())
+            .filter(|location| !location.is_empty())
+            .unwrap_or(&canary.artifact_location);
+        let Some(url) = artifacts_console_url(location) else {
+            self.status_message = Some("Canary has no artifacts location".to_string());
+            return;
+        };
+
+        self.status_message = Some(match copy_to_clipboard(&url) {
+            Ok(()) => "Copied artifacts link to clipboard".to_string(),
+            Err(e) => format!("Copy failed: {e}"),
+        });
+    }
+
+    /// Estimated age distribution of the selected queue's backlog
+    pub fn queue_age_estimate(&self) -> Option<QueueAgeEstimate> {
+        let queue = self.get_selected_sqs_queue()?;
+        let metrics = self.view.service_metrics.as_ref()?;
+        let history = |name: &str| {
+            metrics
+                .raw_metrics
+                .get(name)
+                .map_or(&[][..], |value| value.history.as_slice())
+        };
+        let number = |name: &str| {
+            queue
+                .attribute(name)
+                .and_then(|value| value.parse::<f64>().ok())
+        };
+        Some(queue_age::estimate(&QueueFlow {
+            backlog: number("ApproximateNumberOfMessages").unwrap_or(0.0),
+            sent: history("NumberOfMessagesSent"),
+            deleted: history("NumberOfMessagesDeleted"),
+            oldest_age: history("ApproximateAgeOfOldestMessage"),
+            period_secs: f64::from(calculate_period_seconds(&self.view.time_range)),
+            retention_secs: number("MessageRetentionPeriod"),
+        }))
+    }
+
+    /// Message group behavior of the selected queue, when it is FIFO
+    pub fn fifo_group_activity(&self) -> Option<GroupActivity> {
+        let queue = self
+            .get_selected_sqs_queue()
+            .filter(|queue| queue.is_fifo())?;
+        let metrics = self.view.service_metrics.as_ref()?;
+        let history = |name: &str| {
+            metrics
+                .raw_metrics
+                .get(name)
+                .map_or(&[][..], |value| value.history.as_slice())
+        };
+        Some(fifo_groups::analyze(&GroupFlow {
+            backlog: queue
+                .attribute("ApproximateNumberOfMessages")
+                .and_then(|value| value.parse::<f64>().ok())
+                .unwrap_or(0.0),
+            groups_in_flight: history("ApproximateNumberOfGroupsWithInflightMessages"),
+            sent: history("NumberOfMessagesSent"),
+            deduplicated: history("NumberOfDeduplicatedSentMessages"),
+            received: history("NumberOfMessagesReceived"),
+            period_secs: f64::from(calculate_period_seconds(&self.view.time_range)),
+            high_throughput: queue.attribute("FifoThroughputLimit") == Some("perMessageGroupId"),
+            content_based_deduplication: queue.attribute("ContentBasedDeduplication")
+                == Some("true"),
+        }))
+    }
+
+    pub async fn load_metrics(&mut self, instance_id: &str) -> Result<()> {
+        self.metrics_loading = true;
+
+        let service = self
+            .view
+            .selected_service
+            .clone()
+            .unwrap_or(AwsService::Rds);
+        self.load_quotas(&service).await;
+
+        // A favorite warmed at startup renders without waiting on CloudWatch
+        let result = match self.prefetch.take(instance_id, self.view.time_range) {
+            Some(warmed) => Ok((warmed.metrics, warmed.failures, warmed.fetched_at)),
+            None => {
+                // Metrics the instance never publishes are passed through empty
+                let unpublished = self.unpublished_metrics(instance_id);
+                let skipped: ReusedMetrics = unpublished
+                    .iter()
+                    .map(|metric| (metric.metric_name(), (Vec::new(), Vec::new())))
+                    .collect();
+                let attempted = MetricType::ALL.len() - unpublished.len();
+                load_metrics(instance_id, self.view.time_range, &skipped)
+                    .await
+                    .and_then(|(metrics, failures)| {
+                        match every_metric_failed(&failures, attempted) {
+                            Some(e) => Err(e),
+                            None => Ok((metrics, failures, Instant::now())),
+                        }
+                    })
+            }
+        };
+        match result {
+            Ok((metrics, failures, fetched_at)) => {
+                self.view.metrics = metrics;
+                self.learn_baselines(instance_id);
+                self.view.metric_schedule.reset(instance_id, fetched_at);
+                self.view.metric_schedule.mark_failed(failures.keys());
+                self.view.failed_metrics = failures;
+                self.script_output = self.script_host.evaluate(&self.view.metrics);
+                self.metrics_loading = false;
+                self.clear_error();
+                self.initialize_sparkline_grid();
+                self.load_percentiles(instance_id).await;
+                // Mark as refreshed after successful metrics load
+                self.mark_refreshed();
+                Ok(())
+            }
+            Err(e) => {
+                self.metrics_loading = false;
+                self.error_message = Some(format!("CloudWatch Error: {e}"));
+                self.view.metrics = crate::models::MetricData::default();
+                self.view.failed_metrics.clear();
+                self.view.percentiles.clear();
+                self.view.metric_schedule.clear();
+                self.view.selected_metric = None;
+                self.view.sparkline_grid_selected_index = 0;
+                Ok(())
+            }
+        }
+    }
+
+    /// Auto-refresh the selected instance, refetching only metrics that can have a new datapoint
+    pub async fn refresh_metrics(&mut self) -> Result<()> {
+        let Some(instance_id) = self.get_selected_rds_instance_id() else {
+            return Ok(());
+        };
+        if !self.view.metric_schedule.tracks(&instance_id) {
+            return self.load_metrics(&instance_id).await;
+        }
+
+        let now = Instant::now();
+        let not_due = self.view.metric_schedule.not_due(now);
+        let unpublished = self.unpublished_metrics(&instance_id);
+        let skip = |metric: &MetricType| not_due.contains(metric) || unpublished.contains(metric);
+        if MetricType::ALL.iter().all(skip) {
+            return Ok(());
+        }
+        let reused = self.reused_metrics(skip);
+
+        match load_metrics(&instance_id, self.view.time_range, &reused).await {
+            Ok((metrics, failures)) => {
+                self.view.metrics = metrics;
+                self.learn_baselines(&instance_id);
+                self.script_output = self.script_host.evaluate(&self.view.metrics);
+                self.initialize_sparkline_grid();
+                let fetched = MetricType::ALL
+                    .into_iter()
+                    .filter(|metric| !skip(metric) && !failures.contains_key(metric.metric_name()));
+                self.view.metric_schedule.mark_fetched(fetched, now);
+                // Failed metrics stay unmarked, so the next refresh asks for them again
+                self.view.failed_metrics = failures;
+                let latency_due = MetricType::ALL.iter().any(|metric| {
+                    MetricRegistry::percentiles(metric).is_some() && !not_due.contains(metric)
+                });
+                if latency_due {
+                    self.load_percentiles(&instance_id).await;
+                }
+            }
+            Err(e) => self.error_message = Some(format!("CloudWatch Error: {e}")),
+        }
+        Ok(())
+    }
+
+    /// Fetch the percentiles drawn on latency charts in place of their averages
+    ///
+    /// Percentiles only add to the charts: when they cannot be fetched, e.g.
+    /// without cloudwatch:GetMetricData, the charts fall back to the averages.
+    async fn load_percentiles(&mut self, instance_id: &str) {
+        let end_time = SystemTime::now();
+        let start_time = end_time - self.view.time_range.duration();
+        let period = calculate_period_seconds(&self.view.time_range);
+        self.view.percentiles = fetch_percentiles(instance_id, start_time, end_time, period)
+            .await
+            .unwrap_or_default();
+    }
+
+    /// Metrics CloudWatch does not publish for the instance's engine and class
+    ///
+    /// Empty until the instance list is loaded, so nothing is skipped on a guess.
+    fn unpublished_metrics(&self, instance_id: &str) -> Vec<MetricType> {
+        let Some(instance) = self
+            .view
+            .rds_instances
+            .iter()
+            .find(|instance| instance.identifier == instance_id)
+        else {
+            return Vec::new();
+        };
+        MetricType::ALL
+            .into_iter()
+            .filter(|metric| {
+                !RdsMetricProvider::publishes(
+                    metric.metric_name(),
+                    &instance.engine,
+                    &instance.instance_class,
+                )
+            })
+            .collect()
+    }
+
+    /// The loaded series of the metrics `keep` selects, to pass through a partial fetch
+    fn reused_metrics(&self, keep: impl Fn(&MetricType) -> bool) -> ReusedMetrics {
+        MetricType::ALL
+            .iter()
+            .filter(|metric| keep(metric))
+            .map(|metric| {
+                let timestamps = self
+                    .view
+                    .metrics
+                    .series_timestamps
+                    .get(metric.metric_name())
+                    .cloned()
+                    .unwrap_or_default();
+                let history = self.view.metrics.get_metric_history(metric).clone();
+                (metric.metric_name(), (history, timestamps))
+            })
+            .collect()
+    }
+
+    /// Fold the loaded series into the stored hour-of-week baselines
+    fn learn_baselines(&mut self, instance_id: &str) {
+        let mut learned = false;
+        for metric in self.view.metrics.get_available_metrics() {
+            let history = self.view.metrics.get_metric_history(&metric);
+            if history.len() == self.view.metrics.timestamps.len() {
+                learned |= self.baselines.learn(
+                    &baseline_key(instance_id, &metric),
+                    &self.view.metrics.timestamps,
+                    history,
+                );
+            }
+        }
+        // Baselines only improve the charts; a failed write is not worth interrupting for
+        if learned {
+            let _ = self.baselines.save();
+        }
+    }
+
+    /// Typical (low, high) band of each metric over the loaded timestamps, keyed by CloudWatch name
+    pub fn baseline_bands(&self) -> HashMap<String, (Vec<f64>, Vec<f64>)> {
+        let Some(instance_id) = self.get_selected_rds_instance_id() else {
+            return HashMap::new();
+        };
+        self.view
+            .metrics
+            .get_available_metrics()
+            .into_iter()
+            .filter_map(|metric| {
+                let band = self.baselines.band(
+                    &baseline_key(&instance_id, &metric),
+                    &self.view.metrics.timestamps,
+                )?;
+                Some((metric.metric_name().to_string(), band))
+            })
+            .collect()
+    }
+
+    /// Fetch the Service Quotas relevant to a service's metrics (once per session)
+    async fn load_quotas(&mut self, service: &AwsService) {
+        self.quotas.ensure_loaded(CLOUDWATCH_SERVICE_CODE).await;
+        if *service == AwsService::Sqs {
+            self.quotas.ensure_loaded(SQS_SERVICE_CODE).await;
+        }
+    }
+
+    pub async fn detect_metric_resolution(&mut self, instance_id: &str) {
+        self.view.metric_resolution = detect_resolution(instance_id).await;
+
+        // Drop a sub-minute period that is no longer valid for this instance
+        if let Some(period) = self.view.time_range.period_override {
+            if !self
+                .view
+                .time_range
+                .valid_periods(self.view.metric_resolution)
+                .contains(&period)
+            {
+                self.view.time_range.period_override = None;
+            }
+        }
+    }
+
+    /// Metrics shown in the grid: those with data, then those whose fetch failed
+    ///
+    /// Failed tiles come last so the metrics with data keep the indices the
+    /// detail chart uses.
+    pub fn get_available_metrics(&self) -> Vec<MetricType> {
+        let mut metrics = self.view.metrics.get_available_metrics();
+        let failed: Vec<_> = MetricType::ALL
+            .into_iter()
+            .filter(|metric| {
+                self.view.failed_metrics.contains_key(metric.metric_name())
+                    && !metrics.contains(metric)
+            })
+            .collect();
+        metrics.extend(failed);
+        metrics
+    }
+
+    /// Why the selected resource has no recent datapoints, when its state explains it
+    pub fn stale_data_reason(&self) -> Option<String> {
+        let period = calculate_period_seconds(&self.view.time_range);
+        let now = SystemTime::now();
+        match self.view.selected_service {
+            Some(AwsService::Sqs) => {
+                let queue = self.get_selected_sqs_queue()?;
+                let timestamps = self
+                    .view
+                    .service_metrics
+                    .as_ref()
+                    .map(|metrics| metrics.timestamps.as_slice())
+                    .unwrap_or_default();
+                let queue_is_empty = [
+                    "ApproximateNumberOfMessages",
+                    "ApproximateNumberOfMessagesNotVisible",
+                    "ApproximateNumberOfMessagesDelayed",
+                ]
+                .iter()
+                .all(|name| queue.attribute(name).is_none_or(|count| count == "0"));
+                sqs_staleness(
+                    queue_is_empty,
+                    has_recent_datapoints(timestamps, period, now),
+                )
+            }
+            Some(AwsService::Synthetics) => {
+                let canary = self.get_selected_canary()?;
+                let timestamps = self
+                    .view
+                    .service_metrics
+                    .as_ref()
+                    .map(|metrics| metrics.timestamps.as_slice())
+                    .unwrap_or_default();
+                canary_staleness(
+                    &canary.state,
+                    has_recent_datapoints(timestamps, period, now),
+                )
+            }
+            Some(AwsService::EventBridge) => {
+                let rule = self.get_selected_event_rule()?;
+                let timestamps = self
+                    .view
+                    .service_metrics
+                    .as_ref()
+                    .map(|metrics| metrics.timestamps.as_slice())
+                    .unwrap_or_default();
+                rule_staleness(&rule.state, has_recent_datapoints(timestamps, period, now))
+            }
+            Some(AwsService::Batch) => {
+                let environment = self.get_selected_compute_environment()?;
+                let timestamps = self
+                    .view
+                    .service_metrics
+                    .as_ref()
+                    .map(|metrics| metrics.timestamps.as_slice())
+                    .unwrap_or_default();
+                compute_environment_staleness(
+                    environment.desired_vcpus,
+                    has_recent_datapoints(timestamps, period, now),
+                )
+            }
+            Some(AwsService::Lambda) => {
+                let function = self.get_selected_lambda_function()?;
+                let timestamps = self
+                    .view
+                    .service_metrics
+                    .as_ref()
+                    .map(|metrics| metrics.timestamps.as_slice())
+                    .unwrap_or_default();
+                function_staleness(
+                    function.state.as_deref(),
+                    has_recent_datapoints(timestamps, period, now),
+                )
+            }
+            _ => {
+                let instance = self.get_selected_rds_instance()?;
+                rds_staleness(
+                    &instance.status,
+                    has_recent_datapoints(&self.view.metrics.timestamps, period, now),
+                )
+            }
+        }
+    }
+
+    pub fn get_sparkline_grid_selected_index(&self) -> usize {
+        self.view.sparkline_grid_selected_index
+    }
+
+    pub fn update_selected_metric(&mut self) {
+        let available_metrics = self.get_available_metrics();
+        if let Some(metric) = available_metrics.get(self.view.sparkline_grid_selected_index) {
+            self.view.selected_metric = Some(metric.clone());
+        }
+    }
+
+    pub fn initialize_sparkline_grid(&mut self) {
+        let available_metrics = self.get_available_metrics();
+        if !available_metrics.is_empty() {
+            if self.view.selected_metric.is_none() {
+                self.view.selected_metric = Some(available_metrics[0].clone());
+                self.view.sparkline_grid_selected_index = 0;
+            } else if let Some(ref current_metric) = self.view.selected_metric {
+                if let Some(index) = available_metrics.iter().position(|m| m == current_metric) {
+                    self.view.sparkline_grid_selected_index = index;
+                } else {
+                    self.view.selected_metric = Some(available_metrics[0].clone());
+                    self.view.sparkline_grid_selected_index = 0;
+                }
+            }
+        } else {
+            self.view.selected_metric = None;
+            self.view.sparkline_grid_selected_index = 0;
+        }
+    }
+
+    // ================================
+    // 7. TIME RANGE MANAGEMENT
+    // ================================
+
+    pub fn update_time_range(
+        &mut self,
+        value: u32,
+        unit: TimeUnit,
+        period_days: u32,
+    ) -> Result<()> {
+        self.remember_view();
+        self.view.time_range = TimeRange::new(value, unit, period_days)?;
+        Ok(())
+    }
+
+    pub fn get_time_range_options() -> Vec<(&'static str, u32, TimeUnit, u32)> {
+        vec![
+            ("5 minutes", 5, TimeUnit::Minutes, 1),
+            ("1 hour", 1, TimeUnit::Hours, 1),
+            ("3 hours", 3, TimeUnit::Hours, 1),
+            ("6 hours", 6, TimeUnit::Hours, 1),
+            ("12 hours", 12, TimeUnit::Hours, 1),
+            ("1 day", 1, TimeUnit::Days, 1),
+            ("3 days", 3, TimeUnit::Days, 1),
+            ("1 week", 1, TimeUnit::Weeks, 7),
+            ("2 weeks", 2, TimeUnit::Weeks, 14),
+            ("1 month", 1, TimeUnit::Months, 30),
+        ]
+    }
+
+    pub fn get_current_time_range_index(&self) -> usize {
+        self.view.time_range_scroll
+    }
+
+    pub fn select_time_range(&mut self, index: usize) -> Result<()> {
+        self.remember_view();
+        let options = Self::get_time_range_options();
+        if let Some(&(_, value, unit, period_days)) = options.get(index) {
+            self.view.time_range_scroll = index;
+            self.view.time_range = TimeRange::new(value, unit, period_days)?;
+            Ok(())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Cycle through the periods valid for the current range, ending back on automatic
+    pub fn cycle_period(&mut self) {
+        self.remember_view();
+        let periods = self
+            .view
+            .time_range
+            .valid_periods(self.view.metric_resolution);
+        self.view.time_range.period_override = match self.view.time_range.period_override {
+            None => periods.first().copied(),
+            Some(current) => periods
+                .iter()
+                .position(|&p| p == current)
+                .and_then(|i| periods.get(i + 1))
+                .copied(),
+        };
+    }
+
+    pub fn time_range_scroll_up(&mut self) {
+        if self.view.time_range_scroll > 0 {
+            self.view.time_range_scroll -= 1;
+        }
+    }
+
+    pub fn time_range_scroll_down(&mut self) {
+        let options = Self::get_time_range_options();
+        if self.view.time_range_scroll < options.len() - 1 {
+            self.view.time_range_scroll += 1;
+        }
+    }
+
+    // ================================
+    // 8. SCREEN NAVIGATION & STATE TRANSITIONS
+    // ================================
+
+    pub fn enter_metrics_summary(&mut self) {
+        self.remember_view();
+        if let Some(i) = self.view.list_state.selected() {
+            self.view.selected_instance = Some(i);
+            self.view.state = AppState::MetricsSummary;
+            self.view.metrics_summary_scroll = 0;
+            self.view.scroll_offset = 0;
+            self.view.focused_panel = FocusedPanel::TimeRanges;
+            self.view.sparkline_grid_selected_index = 0;
+            self.initialize_sparkline_grid();
+            let snapshot = self.view_snapshot();
+            self.view.jumps.visit(snapshot);
+        }
+    }
+
+    pub fn back_to_metrics_summary(&mut self) {
+        self.remember_view();
+        self.view.state = AppState::MetricsSummary;
+        self.view.scroll_offset = self.view.metrics_summary_scroll;
+        self.view.focused_panel = self.view.saved_focused_panel.clone();
+        self.view.sparkline_grid_selected_index = self.view.saved_sparkline_grid_selected_index;
+        self.update_selected_metric();
+    }
+
+    pub fn enter_instance_details(&mut self) {
+        self.remember_view();
+        if let Some(i) = self.view.list_state.selected() {
+            self.view.selected_instance = Some(i);
+            self.view.state = AppState::InstanceDetails;
+            self.view.metrics_summary_scroll = self.view.scroll_offset;
+            self.view.saved_focused_panel = self.view.focused_panel.clone();
+            self.view.saved_sparkline_grid_selected_index = self.view.sparkline_grid_selected_index;
+
+            let available_metrics_count = self.view.metrics.count_available_metrics();
+            self.view.scroll_offset = self
+                .view
+                .sparkline_grid_selected_index
+                .min(available_metrics_count.saturating_sub(1));
+        }
+    }
+
+    pub fn back_to_list(&mut self) {
+        self.remember_view();
+        self.view.state = AppState::InstanceList;
+        self.view.selected_instance = None;
+        self.view.scroll_offset = 0;
+        self.view.metrics_summary_scroll = 0;
+    }
+
+    // ================================
+    // 9. SCROLLING & PANEL MANAGEMENT
+    // ================================
+
+    pub fn scroll_up(&mut self) {
+        match self.view.state {
+            AppState::MetricsSummary => match self.view.focused_panel {
+                FocusedPanel::TimeRanges => {
+                    self.time_range_scroll_up();
+                }
+                FocusedPanel::SparklineGrid => {
+                    self.sparkline_grid_scroll_up();
+                }
+            },
+            _ => {
+                if self.view.scroll_offset > 0 {
+                    self.view.scroll_offset -= 1;
+                }
+            }
+        }
+    }
+
+    pub fn scroll_down(&mut self) {
+        match self.view.state {
+            AppState::MetricsSummary => match self.view.focused_panel {
+                FocusedPanel::TimeRanges => {
+                    self.time_range_scroll_down();
+                }
+                FocusedPanel::SparklineGrid => {
+                    self.sparkline_grid_scroll_down();
+                }
+            },
+            AppState::InstanceDetails => {
+                let total_individual_metrics = self.view.metrics.count_available_metrics();
+                let max_offset = total_individual_metrics.saturating_sub(1);
+                if self.view.scroll_offset < max_offset {
+                    self.view.scroll_offset += 1;
+                }
+            }
+            _ => {}
+        }
+    }
 
-            if self.sparkline_grid_selected_index < self.scroll_offset {
-                self.scroll_offset = self.sparkline_grid_selected_index;
-                self.metrics_summary_scroll = self.scroll_offset;
+    pub fn reset_scroll(&mut self) {
+        match self.view.state {
+            AppState::MetricsSummary => {
+                self.view.metrics_summary_scroll = 0;
+                self.view.scroll_offset = 0;
+                self.view.focused_panel = FocusedPanel::TimeRanges;
+                self.view.saved_focused_panel = FocusedPanel::TimeRanges;
+                self.view.sparkline_grid_scroll = 0;
+                self.view.sparkline_grid_selected_index = 0;
+                self.view.saved_sparkline_grid_selected_index = 0;
+                self.initialize_sparkline_grid();
+            }
+            _ => {
+                self.view.scroll_offset = 0;
+                self.view.sparkline_grid_selected_index = 0;
+                self.view.metrics_summary_scroll = 0;
             }
         }
     }
 
+    pub fn switch_panel(&mut self) {
+        self.view.focused_panel = match self.view.focused_panel {
+            FocusedPanel::TimeRanges => FocusedPanel::SparklineGrid,
+            FocusedPanel::SparklineGrid => FocusedPanel::TimeRanges,
+        };
+    }
+
+    pub fn get_focused_panel(&self) -> &FocusedPanel {
+        &self.view.focused_panel
+    }
+
+    /// Update metrics_per_screen based on available area and grid column count
+    /// This should be called before rendering to ensure navigation functions work correctly
+    pub fn update_metrics_per_screen(&mut self, area_height: u16, columns: usize) {
+        let items_per_screen = (area_height.saturating_sub(2)) as usize; // Account for borders
+                                                                         // Each metric takes 3 lines (frame only)
+        let rows = items_per_screen.div_ceil(3);
+        self.metric_grid_columns = columns.max(1);
+        self.metrics_per_screen = rows * self.metric_grid_columns;
+        // A resize can change both rows and columns; re-anchor on the selection
+        self.keep_selected_metric_visible();
+    }
+
+    /// Scroll by whole grid rows so the selected metric stays on screen
+    fn keep_selected_metric_visible(&mut self) {
+        let columns = self.metric_grid_columns.max(1);
+        let rows = (self.metrics_per_screen / columns).max(1);
+        let selected_row = self.view.sparkline_grid_selected_index / columns;
+        let first_row = (self.view.scroll_offset / columns).min(selected_row);
+        let first_row = first_row.max((selected_row + 1).saturating_sub(rows));
+        self.view.scroll_offset = first_row * columns;
+        self.view.metrics_summary_scroll = self.view.scroll_offset;
+    }
+
+    // ================================
+    // 10. SPARKLINE GRID NAVIGATION
+    // ================================
+
+    pub fn sparkline_grid_scroll_up(&mut self) {
+        if self.view.sparkline_grid_selected_index > 0 {
+            self.view.sparkline_grid_selected_index -= 1;
+            self.update_selected_metric();
+            self.keep_selected_metric_visible();
+        }
+    }
+
     pub fn sparkline_grid_scroll_down(&mut self) {
-        let available_metrics = self.metrics.get_available_metrics();
-        if self.sparkline_grid_selected_index < available_metrics.len().saturating_sub(1) {
-            self.sparkline_grid_selected_index += 1;
+        let available_metrics = self.get_available_metrics();
+        if self.view.sparkline_grid_selected_index < available_metrics.len().saturating_sub(1) {
+            self.view.sparkline_grid_selected_index += 1;
             self.update_selected_metric();
+            self.keep_selected_metric_visible();
+        }
+    }
 
-            let max_visible_index = self.scroll_offset + self.metrics_per_screen.saturating_sub(1);
-            if self.sparkline_grid_selected_index > max_visible_index {
-                self.scroll_offset = self
-                    .sparkline_grid_selected_index
-                    .saturating_sub(self.metrics_per_screen.saturating_sub(1));
-                self.metrics_summary_scroll = self.scroll_offset;
-            }
+    // ================================
+    // 11. DETAILS PANE
+    // ================================
+
+    pub fn toggle_details_pane(&mut self) {
+        self.view.details_pane.open = !self.view.details_pane.open;
+        self.view.details_pane.selected_index = 0;
+        self.view.details_pane.hscroll = 0;
+    }
+
+    pub fn get_selected_attributes(&self) -> &[(String, String)] {
+        match self
+            .view
+            .selected_instance
+            .and_then(|index| self.view.instances.get(index))
+        {
+            Some(ServiceInstance::Rds(instance)) => &instance.attributes,
+            Some(ServiceInstance::Sqs(queue)) => &queue.attributes,
+            Some(ServiceInstance::Canary(canary)) => &canary.attributes,
+            Some(ServiceInstance::EventRule(rule)) => &rule.attributes,
+            Some(ServiceInstance::BatchQueue(queue)) => &queue.attributes,
+            Some(ServiceInstance::Function(function)) => &function.attributes,
+            None => &[],
+        }
+    }
+
+    pub fn details_scroll_up(&mut self) {
+        self.view.details_pane.selected_index =
+            self.view.details_pane.selected_index.saturating_sub(1);
+        self.view.details_pane.hscroll = 0;
+    }
+
+    pub fn details_scroll_down(&mut self) {
+        let count = self.get_selected_attributes().len();
+        if self.view.details_pane.selected_index + 1 < count {
+            self.view.details_pane.selected_index += 1;
+            self.view.details_pane.hscroll = 0;
         }
     }
+
+    pub fn details_scroll_left(&mut self) {
+        self.view.details_pane.hscroll = self
+            .view
+            .details_pane
+            .hscroll
+            .saturating_sub(DETAILS_HSCROLL_STEP);
+    }
+
+    pub fn details_scroll_right(&mut self) {
+        let value_len = self
+            .get_selected_attributes()
+            .get(self.view.details_pane.selected_index)
+            .map(|(_, value)| value.chars().count())
+            .unwrap_or(0);
+        if self.view.details_pane.hscroll + DETAILS_HSCROLL_STEP < value_len {
+            self.view.details_pane.hscroll += DETAILS_HSCROLL_STEP;
+        }
+    }
+
+    /// Copy the selected attribute, either the bare value or as `key: value`
+    pub fn copy_selected_attribute(&mut self, include_key: bool) {
+        let Some((key, value)) = self
+            .get_selected_attributes()
+            .get(self.view.details_pane.selected_index)
+            .cloned()
+        else {
+            return;
+        };
+
+        let text = if include_key {
+            format!("{key}: {value}")
+        } else {
+            value
+        };
+
+        self.status_message = Some(match copy_to_clipboard(&text) {
+            Ok(()) => format!("Copied {key} to clipboard"),
+            Err(e) => format!("Copy failed: {e}"),
+        });
+    }
+
+    // ================================
+    // 12. CONNECTIVITY PROBE
+    // ================================
+
+    /// Probe the selected RDS endpoint from this machine and report in the status line
+    pub async fn probe_selected_instance(&mut self, with_tls: bool) {
+        let Some(instance) = self.get_selected_rds_instance() else {
+            return;
+        };
+        let (Some(host), Some(port)) = (instance.endpoint.clone(), instance.port) else {
+            self.status_message = Some("Instance has no endpoint to probe".to_string());
+            return;
+        };
+        let engine = instance.engine.clone();
+
+        let result = probe_endpoint(&host, port, &engine, with_tls).await;
+        self.status_message = Some(result.summary());
+    }
+
+    // ================================
+    // 13. WRITE ACTIONS
+    // ================================
+
+    /// Ask for confirmation before sending a test message to the selected queue
+    pub fn request_send_test_message(&mut self) {
+        let Some(queue_name) = self.get_selected_sqs_queue().map(|q| q.name.clone()) else {
+            return;
+        };
+
+        let question = format!("Send test message to {queue_name}?");
+        self.request_action(PendingAction::SendTestMessage { queue_name }, question);
+    }
+
+    /// Hold a write action until its confirmation phrase is typed
+    ///
+    /// Every write action is requested here. The phrase is the resource's name
+    /// when the environment tag of a resource acted on calls for it, and a
+    /// random code otherwise.
+    fn request_action(&mut self, action: PendingAction, question: String) {
+        if !self.check_write_gate(&action.resources()) {
+            return;
+        }
+        let environments: Vec<Environment> = action
+            .resources()
+            .into_iter()
+            .flat_map(|id| self.resource_environments(id))
+            .collect();
+        let actions = &self.config.actions;
+        let strictness = confirmation::strictness(actions, &environments);
+        let environment = environments
+            .iter()
+            .filter(|environment| confirmation::strictness(actions, [*environment]) == strictness)
+            .find_map(Environment::describe);
+
+        let confirmation = Confirmation::new(action, question, strictness, environment);
+        self.status_message = Some(format!(
+            "{} Type {} and press Enter to confirm",
+            confirmation.question, confirmation.phrase
+        ));
+        self.pending_action = Some(confirmation);
+    }
+
+    /// Environments of the listed resources with this ID, from the tag named in `[actions]`
+    ///
+    /// A resource that is not listed, e.g. filtered out or not loaded yet, or
+    /// that is only remembered from an earlier session, has tags that can't be
+    /// read and is `Unknown`. A list spanning all regions can hold one per region.
+    fn resource_environments(&self, id: &str) -> Vec<Environment> {
+        let tag = format!("Tag: {}", self.config.actions.environment_tag);
+        let environments: Vec<Environment> = self
+            .view
+            .instances
+            .iter()
+            .filter(|instance| instance.as_aws_instance().id() == id)
+            .map(|instance| {
+                if self.departed_since(instance).is_some() {
+                    return Environment::Unknown;
+                }
+                instance
+                    .attributes()
+                    .iter()
+                    .find(|(key, _)| key.eq_ignore_ascii_case(&tag))
+                    .map_or(Environment::Untagged, |(_, value)| {
+                        Environment::Tagged(value.clone())
+                    })
+            })
+            .collect();
+        if environments.is_empty() {
+            vec![Environment::Unknown]
+        } else {
+            environments
+        }
+    }
+
+    /// Whether write actions are enabled on the resources; explains why when not
+    fn check_write_gate(&mut self, resources: &[&str]) -> bool {
+        if !self.check_policy(Feature::Writes, resources) {
+            return false;
+        }
+        if self.config.actions.read_only {
+            self.status_message =
+                Some("Write actions are disabled - awscw was started with --read-only".to_string());
+            return false;
+        }
+        if !self.config.actions.allow_writes {
+            self.status_message = Some(
+                "Write actions are disabled - set allow_writes = true under [actions] in config.toml"
+                    .to_string(),
+            );
+        }
+        self.config.actions.allow_writes
+    }
+
+    /// Whether the feature policy allows `feature` on the resources; says which environment disables it
+    fn check_policy(&mut self, feature: Feature, resources: &[&str]) -> bool {
+        let environments: Vec<Environment> = resources
+            .iter()
+            .flat_map(|id| self.resource_environments(id))
+            .collect();
+        let policy = &self.config.policy;
+        match policy.denies(feature, &environments) {
+            Some(environment) => {
+                self.status_message = Some(policy.denial(feature, &environment));
+                false
+            }
+            None => true,
+        }
+    }
+
+    pub fn cancel_pending_action(&mut self) {
+        self.pending_action = None;
+        self.status_message = Some("Cancelled".to_string());
+    }
+
+    /// Execute the pending action if its phrase has been typed
+    pub async fn confirm_pending_action(&mut self) -> Result<()> {
+        if let Some(confirmation) = self.pending_action.as_ref().filter(|c| !c.is_typed()) {
+            self.status_message = Some(format!(
+                "Type {} to confirm, or Esc to cancel",
+                confirmation.phrase
+            ));
+            return Ok(());
+        }
+        let Some(action) = self
+            .pending_action
+            .take()
+            .and_then(Confirmation::into_action)
+        else {
+            return Ok(());
+        };
+        match action {
+            PendingAction::SendTestMessage { queue_name } => {
+                self.send_test_message(&queue_name).await
+            }
+            PendingAction::SetAlarmActions {
+                alarm_names,
+                resources,
+                enabled,
+            } => {
+                self.set_alarm_actions(alarm_names, resources, enabled)
+                    .await;
+                Ok(())
+            }
+            PendingAction::CreateAlarm { alarm } => {
+                self.create_alarm(alarm).await;
+                Ok(())
+            }
+            PendingAction::CreateSnapshot { instance_id } => {
+                self.status_message =
+                    Some(format!("Creating a snapshot of {instance_id} - J: Jobs"));
+                self.jobs
+                    .start(format!("Snapshot {instance_id}"), |reporter| {
+                        snapshots::create_snapshot(instance_id, reporter)
+                    });
+                Ok(())
+            }
+            PendingAction::RedriveQueue {
+                queue_name,
+                queue_arn,
+            } => {
+                self.status_message = Some(format!("Redriving {queue_name} - J: Jobs"));
+                self.jobs
+                    .start(format!("Redrive {queue_name}"), |reporter| {
+                        redrive_dead_letter_queue(queue_arn, reporter)
+                    });
+                Ok(())
+            }
+        }
+    }
+
+    async fn send_test_message(&mut self, queue_name: &str) -> Result<()> {
+        let Some(queue) = self
+            .get_selected_sqs_queue()
+            .filter(|q| q.name == queue_name)
+            .cloned()
+        else {
+            return Ok(());
+        };
+
+        let sent_at = SystemTime::now();
+        let template = &self.config.sqs.test_message;
+        let body = template.render_body(&queue.name, sent_at);
+        let attributes = template.render_attributes(&queue.name, sent_at);
+
+        match send_test_message(&queue.url, body, attributes, queue.is_fifo()).await {
+            Ok(message_id) => {
+                self.status_message = Some(format!(
+                    "Sent test message {message_id} - watching {} for consumer activity",
+                    queue.name
+                ));
+                self.sent_test_message = Some(SentTestMessage {
+                    queue_name: queue.name.clone(),
+                    message_id,
+                    sent_at: Instant::now(),
+                });
+                self.refresh_selected_queue().await
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Send failed: {e}"));
+                Ok(())
+            }
+        }
+    }
+
+    // ================================
+    // 14. WATCHLIST
+    // ================================
+
+    /// Whether a shared watchlist is configured and due to be fetched again
+    pub fn needs_watchlist_refresh(&self) -> bool {
+        if self.config.watchlist.parameter.is_none() {
+            return false;
+        }
+        self.watchlist_refreshed_at.is_none_or(|last| {
+            last.elapsed() > self.quiet_interval(self.config.watchlist.refresh_interval)
+        })
+    }
+
+    /// Fetch the shared watchlist from Parameter Store and cache it locally
+    ///
+    /// On failure the previous (or cached) watchlist stays in effect.
+    pub async fn refresh_watchlist(&mut self) {
+        let Some(parameter) = self.config.watchlist.parameter.clone() else {
+            return;
+        };
+        self.watchlist_refreshed_at = Some(Instant::now());
+
+        let fetched = SsmClientManager::new()
+            .await
+            .get_parameter(&parameter)
+            .await
+            .and_then(|contents| {
+                let shared = Watchlist::parse(&contents)?;
+                Watchlist::save_cache(&contents)?;
+                Ok(shared)
+            });
+
+        match fetched {
+            Ok(shared) => {
+                let watchlist = shared.with_overrides(
+                    &self.config.watchlist.include,
+                    &self.config.watchlist.exclude,
+                );
+                if self.watchlist_only && watchlist != self.watchlist {
+                    // Reload so the filtered list reflects the new watchlist
+                    self.view.last_refresh = None;
+                }
+                self.watchlist = watchlist;
+                self.watchlist_error = None;
+            }
+            Err(e) => self.watchlist_error = Some(format!("Watchlist: {e}")),
+        }
+    }
+
+    pub fn toggle_watchlist_only(&mut self) {
+        self.remember_view();
+        self.watchlist_only = !self.watchlist_only;
+    }
+
+    /// Whether the listed resource at `index` is on the watchlist, in its region
+    pub fn is_watched(&self, index: usize) -> bool {
+        self.view.instances.get(index).is_some_and(|instance| {
+            self.watchlist
+                .contains(instance, self.instance_region(index))
+        })
+    }
+
+    /// Drop unwatched resources from the loaded lists when filtering is on
+    fn apply_watchlist_filter(&mut self) {
+        if !self.watchlist_only {
+            return;
+        }
+        let watched: Vec<bool> = (0..self.view.instances.len())
+            .map(|index| self.is_watched(index))
+            .collect();
+        let mut keep = watched.iter().copied();
+        self.view.instances.retain(|_| keep.next().unwrap_or(false));
+        if self.view.instance_regions.is_empty() {
+            let watchlist = &self.watchlist;
+            self.view
+                .rds_instances
+                .retain(|instance| watchlist.rds.contains(&instance.identifier));
+            return;
+        }
+        let mut keep = watched.iter().copied();
+        self.view
+            .instance_regions
+            .retain(|_| keep.next().unwrap_or(false));
+        self.view.rds_instances = self
+            .view
+            .instances
+            .iter()
+            .filter_map(|instance| match instance {
+                ServiceInstance::Rds(rds) => Some(rds.clone()),
+                _ => None,
+            })
+            .collect();
+    }
+
+    // ================================
+    // 15. REPLICA TOPOLOGY
+    // ================================
+
+    /// Open the replica view for the selected instance, or for its primary if it is a replica
+    pub async fn enter_replica_topology(&mut self) {
+        let Some(instance) = self.get_selected_rds_instance() else {
+            return;
+        };
+
+        let primary = if !instance.topology.replicas.is_empty() {
+            instance.identifier.clone()
+        } else if let Some(source) = &instance.topology.source {
+            source.clone()
+        } else {
+            self.status_message = Some(format!("{} has no read replicas", instance.identifier));
+            return;
+        };
+
+        if !self
+            .view
+            .rds_instances
+            .iter()
+            .any(|instance| instance.identifier == primary)
+        {
+            self.status_message = Some(format!(
+                "Primary {primary} is not in the current instance list"
+            ));
+            return;
+        }
+
+        self.view.topology.primary = Some(primary);
+        self.view.state = AppState::ReplicaTopology;
+        self.view.topology.list_state.select(Some(0));
+        self.load_replica_topology().await;
+    }
+
+    /// Refresh ReplicaLag for every replica of the primary being shown
+    pub async fn load_replica_topology(&mut self) {
+        let Some(replicas) = self
+            .view
+            .topology
+            .primary
+            .as_ref()
+            .and_then(|primary| {
+                self.view
+                    .rds_instances
+                    .iter()
+                    .find(|instance| &instance.identifier == primary)
+            })
+            .map(|primary| primary.topology.replicas.clone())
+        else {
+            return;
+        };
+
+        self.metrics_loading = true;
+        self.view.topology.lags = load_replica_lag(&replicas, self.view.time_range).await;
+        self.metrics_loading = false;
+        self.mark_refreshed();
+    }
+
+    pub fn replica_next(&mut self) {
+        if self.view.topology.lags.is_empty() {
+            return;
+        }
+        let i = self
+            .view
+            .topology
+            .list_state
+            .selected()
+            .map_or(0, |i| (i + 1) % self.view.topology.lags.len());
+        self.view.topology.list_state.select(Some(i));
+    }
+
+    pub fn replica_previous(&mut self) {
+        if self.view.topology.lags.is_empty() {
+            return;
+        }
+        let i = match self.view.topology.list_state.selected() {
+            Some(0) | None => self.view.topology.lags.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.view.topology.list_state.select(Some(i));
+    }
+
+    /// Jump to the metrics view of the selected replica
+    pub async fn open_selected_replica(&mut self) -> Result<()> {
+        let Some(replica) = self
+            .view
+            .topology
+            .list_state
+            .selected()
+            .and_then(|i| self.view.topology.lags.get(i))
+            .map(|lag| lag.identifier.clone())
+        else {
+            return Ok(());
+        };
+
+        if is_cross_region(&replica) {
+            self.status_message = Some(format!(
+                "{replica} is in another region; switch AWS_REGION to view it"
+            ));
+            return Ok(());
+        }
+        let Some(index) = self
+            .view
+            .instances
+            .iter()
+            .position(|instance| instance.as_aws_instance().id() == replica)
+        else {
+            self.status_message = Some(format!("{replica} is not in the current instance list"));
+            return Ok(());
+        };
+
+        self.view.list_state.select(Some(index));
+        self.enter_metrics_summary();
+        self.detect_metric_resolution(&replica).await;
+        self.load_metrics(&replica).await
+    }
+
+    pub fn back_from_replica_topology(&mut self) {
+        self.view.state = AppState::MetricsSummary;
+        self.view.topology.primary = None;
+        self.view.topology.lags.clear();
+    }
+
+    // ================================
+    // 16. BLUE/GREEN DEPLOYMENTS
+    // ================================
+
+    /// Refresh the deployment list; failures leave the previous list in place
+    ///
+    /// Deployments are optional context for the instance list, so a missing
+    /// DescribeBlueGreenDeployments permission should not block loading.
+    async fn load_blue_green_deployments(&mut self) {
+        if let Ok(deployments) = RdsClientManager::new()
+            .await
+            .load_blue_green_deployments()
+            .await
+        {
+            self.view.blue_green.deployments = deployments;
+        }
+    }
+
+    /// Load the secrets that may hold RDS credentials, when `[secrets]` is enabled
+    ///
+    /// Like blue/green deployments, rotation state is context for the metrics
+    /// page, so a missing ListSecrets permission should not block loading.
+    async fn load_db_secrets(&mut self) {
+        if !self.config.secrets.enabled {
+            return;
+        }
+        if let Ok(secrets) = SecretsClientManager::new().await.load_secrets().await {
+            self.view.db_secrets = secrets;
+        }
+    }
+
+    /// Secrets holding the credentials of an RDS instance
+    pub fn db_secrets_for(&self, instance: &RdsInstance) -> Vec<&DbSecret> {
+        secrets_for_instance(&self.view.db_secrets, &self.config.secrets, instance)
+    }
+
+    /// Active deployment the instance takes part in, if any
+    pub fn blue_green_deployment_for(&self, instance_id: &str) -> Option<&BlueGreenDeployment> {
+        self.view
+            .blue_green
+            .deployments
+            .iter()
+            .find(|deployment| deployment.is_active() && deployment.involves(instance_id))
+    }
+
+    pub fn get_selected_blue_green_deployment(&self) -> Option<&BlueGreenDeployment> {
+        let identifier = self.view.blue_green.selected.as_ref()?;
+        self.view
+            .blue_green
+            .deployments
+            .iter()
+            .find(|deployment| &deployment.identifier == identifier)
+    }
+
+    /// Open the blue/green page for the deployment the selected instance belongs to
+    pub async fn enter_blue_green(&mut self) {
+        let Some(instance_id) = self.get_selected_rds_instance_id() else {
+            return;
+        };
+        let Some(identifier) = self
+            .blue_green_deployment_for(&instance_id)
+            .map(|deployment| deployment.identifier.clone())
+        else {
+            self.status_message = Some(format!(
+                "{instance_id} is not part of an active blue/green deployment"
+            ));
+            return;
+        };
+
+        self.view.blue_green.selected = Some(identifier);
+        self.view.state = AppState::BlueGreen;
+        self.refresh_blue_green().await;
+    }
+
+    /// Whether the blue/green page is due a refresh; faster while switching over
+    pub fn needs_blue_green_refresh(&self) -> bool {
+        let switching = self
+            .get_selected_blue_green_deployment()
+            .is_some_and(|deployment| deployment.is_switching_over());
+        if !switching {
+            return self.needs_refresh();
+        }
+        self.view
+            .last_refresh
+            .is_none_or(|last| last.elapsed() > SWITCHOVER_REFRESH_INTERVAL)
+    }
+
+    /// Reload deployment status and the green members' replication lag
+    pub async fn refresh_blue_green(&mut self) {
+        self.load_blue_green_deployments().await;
+
+        let targets: Vec<String> = self
+            .get_selected_blue_green_deployment()
+            .map(|deployment| {
+                deployment
+                    .members
+                    .iter()
+                    .map(|member| identifier_from_arn(&member.target_arn).to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.view.blue_green.lags = load_replica_lag(&targets, self.view.time_range).await;
+        self.mark_refreshed();
+    }
+
+    /// Engine version of an instance in the loaded list, by ARN
+    pub fn engine_version_for_arn(&self, arn: &str) -> Option<&str> {
+        let identifier = identifier_from_arn(arn);
+        self.view
+            .rds_instances
+            .iter()
+            .find(|instance| instance.identifier == identifier)
+            .and_then(|instance| instance.attribute("Engine Version"))
+    }
+
+    pub fn back_from_blue_green(&mut self) {
+        self.view.state = AppState::MetricsSummary;
+        self.view.blue_green.selected = None;
+        self.view.blue_green.lags.clear();
+    }
+
+    // ================================
+    // 17. ALARM MAINTENANCE
+    // ================================
+
+    /// Refresh the alarm list; failures leave the previous list in place
+    async fn load_alarms(&mut self) {
+        if let Ok(alarms) = AlarmsManager::new().await.load_alarms().await {
+            self.alarms = alarms;
+        }
+    }
+
+    pub fn alarms_for(&self, instance: &ServiceInstance) -> Vec<&MetricAlarm> {
+        self.alarms
+            .iter()
+            .filter(|alarm| alarm.targets(instance))
+            .collect()
+    }
+
+    /// Whether the listed resource at `index` is in the bulk selection
+    pub fn is_marked(&self, index: usize) -> bool {
+        self.listed_key(index)
+            .is_some_and(|key| self.marked_instances.contains(&key))
+    }
+
+    /// Add or remove the selected resource from the bulk selection
+    pub fn toggle_mark_selected(&mut self) {
+        let Some((region, id)) = self
+            .view
+            .list_state
+            .selected()
+            .and_then(|index| self.listed_key(index))
+        else {
+            return;
+        };
+        if self.marked_instances.remove(&(region.clone(), id.clone())) {
+            self.record_journal(JournalEntry::Unmarked { id, region });
+        } else {
+            self.marked_instances.insert((region.clone(), id.clone()));
+            self.record_journal(JournalEntry::Marked { id, region });
+        }
+    }
+
+    /// Marked resources, or the selected one when nothing is marked
+    fn bulk_targets(&self) -> Vec<&ServiceInstance> {
+        if self.marked_instances.is_empty() {
+            return self.get_selected_instance().into_iter().collect();
+        }
+        self.view
+            .instances
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.is_marked(*index))
+            .map(|(_, instance)| instance)
+            .collect()
+    }
+
+    /// Ask for confirmation before enabling or disabling alarm actions in bulk
+    pub fn request_alarm_actions(&mut self, enabled: bool) {
+        let targets = self.bulk_targets();
+        let resources: Vec<String> = targets
+            .iter()
+            .map(|instance| instance.as_aws_instance().id().to_string())
+            .collect();
+        let alarm_names: Vec<String> = targets
+            .iter()
+            .flat_map(|instance| self.alarms_for(instance))
+            .filter(|alarm| alarm.actions_enabled != enabled)
+            .map(|alarm| alarm.name.clone())
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        if resources.is_empty() {
+            return;
+        }
+        if alarm_names.is_empty() {
+            let state = if enabled { "enabled" } else { "disabled" };
+            self.status_message = Some(format!(
+                "No alarms to change - actions already {state} for {} resource(s)",
+                resources.len()
+            ));
+            return;
+        }
+        let verb = if enabled { "Enable" } else { "Disable" };
+        let question = format!(
+            "{verb} actions on {} alarm(s) for {} resource(s)?",
+            alarm_names.len(),
+            resources.len()
+        );
+        self.request_action(
+            PendingAction::SetAlarmActions {
+                alarm_names,
+                resources,
+                enabled,
+            },
+            question,
+        );
+    }
+
+    async fn set_alarm_actions(
+        &mut self,
+        alarm_names: Vec<String>,
+        resources: Vec<String>,
+        enabled: bool,
+    ) {
+        if let Err(e) = AlarmsManager::new()
+            .await
+            .set_actions_enabled(&alarm_names, enabled)
+            .await
+        {
+            self.status_message = Some(format!("Alarm update failed: {e}"));
+            return;
+        }
+
+        for alarm in &mut self.alarms {
+            if alarm_names.contains(&alarm.name) {
+                alarm.actions_enabled = enabled;
+            }
+        }
+        self.marked_instances.clear();
+        self.record_journal(JournalEntry::MarksCleared);
+
+        if enabled {
+            self.record_journal(JournalEntry::AlarmsUnmuted {
+                alarm_names: alarm_names.clone(),
+                resources: resources.clone(),
+            });
+            if let Some(maintenance) = &mut self.alarm_maintenance {
+                maintenance
+                    .alarm_names
+                    .retain(|name| !alarm_names.contains(name));
+                maintenance
+                    .resources
+                    .retain(|resource| !resources.contains(resource));
+                if maintenance.alarm_names.is_empty() {
+                    self.alarm_maintenance = None;
+                }
+            }
+            self.status_message =
+                Some(format!("Enabled actions on {} alarm(s)", alarm_names.len()));
+        } else {
+            let count = alarm_names.len();
+            self.record_journal(JournalEntry::AlarmsMuted {
+                alarm_names: alarm_names.clone(),
+                resources: resources.clone(),
+                at: unix_now(),
+            });
+            let maintenance = self
+                .alarm_maintenance
+                .get_or_insert_with(|| AlarmMaintenance {
+                    alarm_names: Vec::new(),
+                    resources: Vec::new(),
+                    started_at: Instant::now(),
+                    reminded_at: Instant::now(),
+                });
+            maintenance.alarm_names.extend(alarm_names);
+            maintenance.resources.extend(resources);
+            self.status_message = Some(format!(
+                "Disabled actions on {count} alarm(s) - you will be reminded to re-enable them"
+            ));
+        }
+    }
+
+    /// Whether muted alarms have gone long enough without a reminder
+    pub fn needs_alarm_reminder(&self) -> bool {
+        self.alarm_maintenance.as_ref().is_some_and(|maintenance| {
+            maintenance.reminded_at.elapsed() > self.config.alarms.maintenance_reminder
+        })
+    }
+
+    pub fn remind_alarm_maintenance(&mut self) {
+        let Some(maintenance) = &mut self.alarm_maintenance else {
+            return;
+        };
+        maintenance.reminded_at = Instant::now();
+        let reminder = format!(
+            "Reminder: actions on {} alarm(s) for {} have been disabled for {} min - press M on the list to re-enable",
+            maintenance.alarm_names.len(),
+            maintenance.resources.join(", "),
+            maintenance.started_at.elapsed().as_secs() / 60
+        );
+        if self.config.quiet_hours.is_now() {
+            self.held_notifications.hold(&reminder);
+        } else {
+            self.status_message = Some(reminder);
+        }
+    }
+
+    // ================================
+    // 18. ALARM CREATION
+    // ================================
+
+    /// Metric shown on the single-chart detail view
+    pub fn detail_chart_metric(&self) -> Option<MetricType> {
+        let available = self.view.metrics.get_available_metrics();
+        let index = self.view.scroll_offset.min(available.len().checked_sub(1)?);
+        available.get(index).cloned()
+    }
+
+    /// Whether the charted metric is drawn as a heat strip
+    pub fn showing_heat_strip(&self) -> bool {
+        self.view.heat_strip
+            && self
+                .detail_chart_metric()
+                .is_some_and(|metric| metric.is_latency())
+    }
+
+    /// Switch latency charts between a line and a heat strip
+    pub fn toggle_heat_strip(&mut self) {
+        if self
+            .detail_chart_metric()
+            .is_some_and(|metric| metric.is_latency())
+        {
+            self.view.heat_strip = !self.view.heat_strip;
+        }
+    }
+
+    /// Whether the charted metric is drawn as its change per interval
+    pub fn showing_derivative(&self) -> bool {
+        self.view.derivative
+            && self
+                .detail_chart_metric()
+                .is_some_and(|metric| metric.is_counter())
+    }
+
+    /// Whether the charted metric is drawn from its percentiles rather than its average
+    pub fn showing_percentiles(&self) -> bool {
+        self.detail_chart_metric()
+            .is_some_and(|metric| self.view.percentiles.contains_key(metric.metric_name()))
+    }
+
+    /// Switch counter charts between their value and its change per interval
+    pub fn toggle_derivative(&mut self) {
+        if self
+            .detail_chart_metric()
+            .is_some_and(|metric| metric.is_counter())
+        {
+            self.view.derivative = !self.view.derivative;
+        }
+    }
+
+    /// Families the current page can stack: the charted metric's on the
+    /// chart page, every queue family on the SQS page
+    pub fn stackable_families(&self) -> Vec<&'static MetricFamily> {
+        match (&self.view.state, &self.view.selected_service) {
+            (AppState::InstanceDetails, _) => self
+                .detail_chart_metric()
+                .and_then(|metric| family_of(&AwsService::Rds, metric.metric_name()))
+                .into_iter()
+                .collect(),
+            (AppState::MetricsSummary, Some(AwsService::Sqs)) => FAMILIES
+                .iter()
+                .filter(|family| family.service == AwsService::Sqs)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Stack the page's next family, going back to single series after the last
+    pub fn cycle_stacked_family(&mut self) {
+        let families = self.stackable_families();
+        let current = self
+            .view
+            .stacked
+            .family
+            .and_then(|name| families.iter().position(|family| family.name == name));
+        let next = match current {
+            Some(index) => families.get(index + 1),
+            None => families.first(),
+        };
+        self.view.stacked = StackedView {
+            family: next.map(|family| family.name),
+            hidden: BTreeSet::new(),
+        };
+    }
+
+    /// Hide or show the `index`th layer of the stacked family
+    pub fn toggle_stack_layer(&mut self, index: usize) {
+        let Some(chart) = self.stacked_chart() else {
+            return;
+        };
+        if index < chart.layers.len() && !self.view.stacked.hidden.remove(&index) {
+            self.view.stacked.hidden.insert(index);
+        }
+    }
+
+    /// The stacked family shown on this page, with each layer's series
+    pub fn stacked_chart(&self) -> Option<StackedChart> {
+        let name = self.view.stacked.family?;
+        let family = self
+            .stackable_families()
+            .into_iter()
+            .find(|family| family.name == name)?;
+        let service_metrics = self.view.service_metrics.as_ref();
+        let history = |metric_name: &str| match family.service {
+            AwsService::Rds => MetricType::ALL
+                .iter()
+                .find(|metric| metric.metric_name() == metric_name)
+                .map(|metric| self.view.metrics.get_metric_history(metric).clone()),
+            _ => service_metrics
+                .and_then(|metrics| metrics.raw_metrics.get(metric_name))
+                .map(|value| value.history.clone()),
+        };
+        let timestamps = match family.service {
+            AwsService::Rds => self.view.metrics.timestamps.clone(),
+            _ => service_metrics.map_or_else(Vec::new, |metrics| metrics.timestamps.clone()),
+        };
+        Some(StackedChart {
+            family,
+            timestamps,
+            layers: family
+                .layers
+                .iter()
+                .enumerate()
+                .map(|(index, (metric_name, label))| StackLayer {
+                    label,
+                    values: history(metric_name).unwrap_or_default(),
+                    hidden: self.view.stacked.hidden.contains(&index),
+                })
+                .collect(),
+        })
+    }
+
+    /// Open the create-alarm form for the charted metric, prefilled from its latest value
+    pub async fn open_alarm_form(&mut self) {
+        let (Some(instance_id), Some(metric)) = (
+            self.get_selected_rds_instance_id(),
+            self.detail_chart_metric(),
+        ) else {
+            return;
+        };
+        if !self.check_write_gate(&[&instance_id]) {
+            return;
+        }
+
+        let latest = self
+            .view
+            .metrics
+            .get_metric_history(&metric)
+            .last()
+            .copied();
+        // Topics are optional; without ListTopics the alarm is created without notifications
+        let topics = SnsClientManager::new()
+            .await
+            .list_topics()
+            .await
+            .unwrap_or_default();
+
+        self.alarm_form = Some(AlarmForm::new(
+            "AWS/RDS",
+            ("DBInstanceIdentifier", &instance_id),
+            metric.metric_name(),
+            latest,
+            topics,
+        ));
+    }
+
+    /// Open the create-alarm form with the alarm usually wanted on the queue being viewed
+    ///
+    /// Any message in its dead-letter queue, or an old oldest message when it
+    /// has none; see `AlarmForm::queue_preset`. Only the topic is left to pick.
+    pub async fn open_queue_alarm_preset(&mut self) {
+        let Some(queue) = self.get_selected_sqs_queue() else {
+            return;
+        };
+        let queue_name = queue.name.clone();
+        let dead_letter_queue = queue.dead_letter_queue();
+        let is_dead_letter_queue = queue.is_dead_letter_queue();
+        if !self.check_write_gate(&[&queue_name]) {
+            return;
+        }
+
+        let topics = SnsClientManager::new()
+            .await
+            .list_topics()
+            .await
+            .unwrap_or_default();
+        self.alarm_form = Some(AlarmForm::queue_preset(
+            &queue_name,
+            dead_letter_queue.as_deref(),
+            is_dead_letter_queue,
+            topics,
+        ));
+    }
+
+    /// How the rule in the alarm form would have behaved over the loaded history
+    pub fn alarm_form_backtest(&self) -> Option<Backtest> {
+        let form = self.alarm_form.as_ref()?;
+        let rule = form.rule()?;
+        if form.namespace == "AWS/SQS" {
+            // Only the queue being viewed has its history loaded, not its dead-letter queue
+            let queue = self.get_selected_sqs_queue()?;
+            let metrics = self.view.service_metrics.as_ref()?;
+            if form.dimension.1 != queue.name {
+                return None;
+            }
+            let history = &metrics.raw_metrics.get(&form.metric_name)?.history;
+            if history.len() != metrics.timestamps.len() {
+                return None;
+            }
+            return Backtest::run(&rule, &metrics.timestamps, history);
+        }
+        let metric = self.detail_chart_metric()?;
+        let history = self.view.metrics.get_metric_history(&metric);
+        if history.len() != self.view.metrics.timestamps.len() {
+            return None;
+        }
+        Backtest::run(&rule, &self.view.metrics.timestamps, history)
+    }
+
+    pub fn close_alarm_form(&mut self) {
+        self.alarm_form = None;
+    }
+
+    /// Validate the form and ask for confirmation before creating the alarm
+    pub fn submit_alarm_form(&mut self) {
+        let Some(form) = &self.alarm_form else {
+            return;
+        };
+        match form.to_new_alarm() {
+            Ok(alarm) => {
+                let question = format!(
+                    "Create alarm {} ({} {} {} for {} x {}s)?",
+                    alarm.name,
+                    alarm.metric_name,
+                    alarm.comparison.symbol(),
+                    alarm.threshold,
+                    alarm.evaluation_periods,
+                    alarm.period_secs
+                );
+                self.alarm_form = None;
+                self.request_action(PendingAction::CreateAlarm { alarm }, question);
+            }
+            Err(e) => self.status_message = Some(e.to_string()),
+        }
+    }
+
+    async fn create_alarm(&mut self, alarm: NewAlarm) {
+        if let Err(e) = AlarmsManager::new().await.put_metric_alarm(&alarm).await {
+            self.status_message = Some(format!("Alarm creation failed: {e}"));
+            return;
+        }
+
+        // Show the alarm right away rather than waiting for the next list refresh
+        let created = alarm.to_metric_alarm();
+        self.alarms.retain(|existing| existing.name != created.name);
+        self.alarms.push(created);
+        self.status_message = Some(format!("Created alarm {}", alarm.name));
+    }
+
+    /// Alarm thresholds on the selected RDS instance, keyed by CloudWatch metric name
+    pub fn alarm_thresholds(&self) -> HashMap<String, Vec<f64>> {
+        let mut thresholds: HashMap<String, Vec<f64>> = HashMap::new();
+        let Some(instance) = self.get_selected_instance() else {
+            return thresholds;
+        };
+        for alarm in self.alarms_for(instance) {
+            if let Some(threshold) = alarm.threshold {
+                thresholds
+                    .entry(alarm.metric_name.clone())
+                    .or_default()
+                    .push(threshold);
+            }
+        }
+        thresholds
+    }
+
+    // ================================
+    // 19. UPDATE CHECK
+    // ================================
+
+    /// Use today's cached answer, or look up the latest release in the background
+    pub fn start_update_check(&mut self) {
+        if !self.config.updates.check {
+            return;
+        }
+        match update_check::load_fresh_cache() {
+            Some(release) => self.offer_update(release),
+            None => {
+                self.update_check = Some(tokio::spawn(async {
+                    update_check::check_latest().await.ok()
+                }));
+            }
+        }
+    }
+
+    /// Pick up the background lookup once it has finished; never waits for it
+    pub fn poll_update_check(&mut self) {
+        if !self
+            .update_check
+            .as_ref()
+            .is_some_and(|check| check.is_finished())
+        {
+            return;
+        }
+        let Some(check) = self.update_check.take() else {
+            return;
+        };
+        if let Some(Ok(Some(release))) = check.now_or_never() {
+            self.offer_update(release);
+        }
+    }
+
+    fn offer_update(&mut self, release: Release) {
+        if release.is_newer_than(env!("CARGO_PKG_VERSION")) {
+            self.available_update = Some(release);
+        }
+    }
+
+    pub fn toggle_release_notes(&mut self) {
+        self.release_notes_open = !self.release_notes_open && self.available_update.is_some();
+    }
+
+    // ================================
+    // 20. SESSION JOURNAL
+    // ================================
+
+    /// Persist one change to marks or muted alarms; a failed write is reported, not fatal
+    fn record_journal(&mut self, entry: JournalEntry) {
+        if let Err(e) = self.journal.record(entry) {
+            self.status_message = Some(format!("Could not save session state: {e:#}"));
+        }
+    }
+
+    /// Rewrite the journal as a single snapshot, e.g. on a clean exit
+    pub fn compact_journal(&mut self) {
+        let _ = self.journal.compact();
+    }
+
+    // ================================
+    // 21. SLOW QUERIES
+    // ================================
+
+    /// Open the slow query page for the selected RDS instance
+    pub async fn enter_slow_queries(&mut self) {
+        let Some(instance) = self.get_selected_rds_instance() else {
+            return;
+        };
+        let Some(log_group) = slow_query::log_group(
+            &instance.identifier,
+            instance.attribute("Cluster"),
+            &instance.engine,
+        ) else {
+            self.status_message = Some(format!(
+                "Slow query logs are not supported for {}",
+                instance.engine
+            ));
+            return;
+        };
+        let id = instance.identifier.clone();
+        if !self.check_policy(Feature::SlowQueries, &[&id]) {
+            return;
+        }
+
+        self.view.slow_query.log = Some(log_group);
+        self.view.state = AppState::SlowQueries;
+        self.load_slow_queries().await;
+    }
+
+    /// Read the slow query log over the selected time range
+    pub async fn load_slow_queries(&mut self) {
+        let Some(log_group) = self.view.slow_query.log.clone() else {
+            return;
+        };
+        let start_time = SystemTime::now() - self.view.time_range.duration();
+        self.view.slow_query.notice = None;
+
+        match LogsClientManager::new()
+            .await
+            .recent_events(&log_group, start_time)
+            .await
+        {
+            Ok(Some(events)) => {
+                self.view.slow_query.queries = slow_query::parse_events(&events);
+                self.view
+                    .slow_query
+                    .sort
+                    .sort(&mut self.view.slow_query.queries);
+                if self.view.slow_query.queries.is_empty() {
+                    self.view.slow_query.notice = Some(format!(
+                        "No slow queries in {log_group} over the selected time range"
+                    ));
+                }
+            }
+            Ok(None) => {
+                self.view.slow_query.queries.clear();
+                self.view.slow_query.notice = Some(format!(
+                    "{log_group} does not exist - enable the slow query log and publish it to CloudWatch Logs"
+                ));
+            }
+            Err(e) => {
+                self.view.slow_query.queries.clear();
+                self.view.slow_query.notice = Some(format!("{e:#}"));
+            }
+        }
+        self.view
+            .slow_query
+            .table_state
+            .select((!self.view.slow_query.queries.is_empty()).then_some(0));
+        self.mark_refreshed();
+    }
+
+    pub fn cycle_slow_query_sort(&mut self) {
+        self.view.slow_query.sort = self.view.slow_query.sort.next();
+        self.view
+            .slow_query
+            .sort
+            .sort(&mut self.view.slow_query.queries);
+        self.view
+            .slow_query
+            .table_state
+            .select((!self.view.slow_query.queries.is_empty()).then_some(0));
+    }
+
+    pub fn slow_query_next(&mut self) {
+        if let Some(index) = self.view.slow_query.table_state.selected() {
+            if index + 1 < self.view.slow_query.queries.len() {
+                self.view.slow_query.table_state.select(Some(index + 1));
+            }
+        }
+    }
+
+    pub fn slow_query_previous(&mut self) {
+        if let Some(index) = self.view.slow_query.table_state.selected() {
+            self.view
+                .slow_query
+                .table_state
+                .select(Some(index.saturating_sub(1)));
+        }
+    }
+
+    pub fn get_selected_slow_query(&self) -> Option<&SlowQuery> {
+        self.view
+            .slow_query
+            .queries
+            .get(self.view.slow_query.table_state.selected()?)
+    }
+
+    /// CPU utilization while the query ran, from the loaded CPU history
+    pub fn cpu_during(&self, query: &SlowQuery) -> Option<f64> {
+        slow_query::value_at(
+            query.timestamp,
+            &self.view.metrics.timestamps,
+            &self.view.metrics.cpu_history,
+            Duration::from_secs(calculate_period_seconds(&self.view.time_range).max(1) as u64),
+        )
+    }
+
+    pub fn back_from_slow_queries(&mut self) {
+        self.view.state = AppState::MetricsSummary;
+        self.view.slow_query.queries.clear();
+        self.view.slow_query.log = None;
+        self.view.slow_query.notice = None;
+    }
+
+    // ================================
+    // 22. CHART IMAGES
+    // ================================
+
+    /// Key of the raster chart the detail view would show as last rendered, if images are enabled
+    fn chart_image_key(&self) -> Option<String> {
+        self.graphics?;
+        if self.view.state != AppState::InstanceDetails {
+            return None;
+        }
+        let area = self.chart_image_area?;
+        match self.config.charts.image_source {
+            ImageSource::CloudWatch => self.widget_image_request().map(|request| request.key()),
+            ImageSource::Local => {
+                let instance_id = self.get_selected_rds_instance_id()?;
+                let metric = self.detail_chart_metric()?;
+                let bands = self.baseline_bands();
+                let chart = self.detail_raster_chart(bands.get(metric.metric_name()))?;
+                let (cell_width, cell_height) = graphics::cell_size_px();
+                // Anything drawn changes the key, so new data or overlays re-plot the image
+                Some(format!(
+                    "local/{instance_id}/{}/{}/{:?}/{:?}/{:?}/{}/{}x{}",
+                    metric.metric_name(),
+                    chart.history.len(),
+                    chart.timestamps.last(),
+                    chart.history.last(),
+                    chart.reference_lines,
+                    chart.band.is_some(),
+                    u32::from(area.width) * cell_width,
+                    u32::from(area.height) * cell_height
+                ))
+            }
+        }
+    }
+
+    /// The detail chart's series and overlays, for plotting as an image
+    pub fn detail_raster_chart<'a>(&'a self, band: Option<&'a Band>) -> Option<RasterChart<'a>> {
+        let metric = self.detail_chart_metric()?;
+        let index = self
+            .view
+            .metrics
+            .get_available_metrics()
+            .iter()
+            .position(|available| *available == metric)?;
+        let history = self.view.metrics.get_metric_history(&metric);
+        if history.is_empty() || history.len() != self.view.metrics.timestamps.len() {
+            return None;
+        }
+
+        let theme = &self.config.theme;
+        let mut reference_lines: Vec<(f64, ratatui::style::Color)> = self
+            .alarm_thresholds()
+            .remove(metric.metric_name())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|threshold| (threshold, theme.color(Severity::Critical)))
+            .collect();
+        if metric == MetricType::FreeStorageSpace {
+            if let Some(ceiling) = self.get_selected_rds_instance().and_then(|instance| {
+                instance.autoscaling_free_storage_ceiling(self.view.metrics.free_storage_space)
+            }) {
+                reference_lines.push((ceiling, theme.color(Severity::Warning)));
+            }
+        }
+        reference_lines.extend(
+            self.pinned_lines(metric.metric_name())
+                .iter()
+                .map(|line| (line.value, ratatui::style::Color::White)),
+        );
+
+        Some(RasterChart {
+            timestamps: &self.view.metrics.timestamps,
+            history,
+            color: metric_color(&self.view.metrics, index)?,
+            band,
+            reference_lines,
+        })
+    }
+
+    /// CloudWatch-rendered chart for the detail view as last rendered
+    fn widget_image_request(&self) -> Option<WidgetRequest> {
+        let area = self.chart_image_area?;
+        let instance_id = self.get_selected_rds_instance_id()?;
+        let metric = self.detail_chart_metric()?;
+        let statistic = MetricServiceFactory::new()
+            .get_provider(&AwsService::Rds)
+            .ok()?
+            .get_metrics_config()
+            .into_iter()
+            .find(|definition| definition.name == metric.metric_name())
+            .map_or(StatisticType::Average, |definition| definition.statistic);
+        let (cell_width, cell_height) = graphics::cell_size_px();
+
+        Some(WidgetRequest {
+            namespace: "AWS/RDS".to_string(),
+            metric_name: metric.metric_name().to_string(),
+            dimension: ("DBInstanceIdentifier".to_string(), instance_id),
+            statistic: match statistic {
+                StatisticType::Average => "Average",
+                StatisticType::Sum => "Sum",
+                StatisticType::Maximum => "Maximum",
+                StatisticType::Minimum => "Minimum",
+            }
+            .to_string(),
+            period_secs: calculate_period_seconds(&self.view.time_range),
+            range_secs: self.view.time_range.duration().as_secs(),
+            width_px: u32::from(area.width) * cell_width,
+            height_px: u32::from(area.height) * cell_height,
+            annotations: self
+                .pinned_lines(metric.metric_name())
+                .iter()
+                .map(|line| (line.label.clone(), line.value))
+                .collect(),
+        })
+    }
+
+    /// Plot or fetch the chart image for the current view unless it is already there (or failed)
+    pub async fn load_chart_image(&mut self) {
+        let Some(key) = self.chart_image_key() else {
+            return;
+        };
+        if self
+            .loaded_chart_image
+            .as_ref()
+            .is_some_and(|image| image.key == key)
+        {
+            return;
+        }
+        let image = match self.config.charts.image_source {
+            ImageSource::CloudWatch => match self.widget_image_request() {
+                Some(request) => fetch_widget_image(&request).await.map(RasterImage::Png),
+                None => return,
+            },
+            ImageSource::Local => {
+                let Some(area) = self.chart_image_area else {
+                    return;
+                };
+                let (cell_width, cell_height) = graphics::cell_size_px();
+                let bands = self.baseline_bands();
+                let band = self
+                    .detail_chart_metric()
+                    .and_then(|metric| bands.get(metric.metric_name()));
+                match self.detail_raster_chart(band) {
+                    Some(chart) => chart.plot(
+                        u32::from(area.width) * cell_width,
+                        u32::from(area.height) * cell_height,
+                    ),
+                    None => return,
+                }
+            }
+        };
+        let image = match image {
+            Ok(image) => Some(image),
+            Err(e) => {
+                self.status_message = Some(format!("Showing the Braille chart: {e}"));
+                None
+            }
+        };
+        self.loaded_chart_image = Some(ChartImage { key, image });
+    }
+
+    /// Image for the current view and the cells to draw it in
+    pub fn chart_image(&self) -> Option<(&ChartImage, Rect)> {
+        let area = self.chart_image_area?;
+        let image = self
+            .loaded_chart_image
+            .as_ref()
+            .filter(|image| image.image.is_some())?;
+        (self.chart_image_key()? == image.key).then_some((image, area))
+    }
+
+    // ================================
+    // 23. TABS
+    // ================================
+
+    /// Open a new tab at the service list and switch to it
+    pub fn open_tab(&mut self) {
+        self.tabs.push(ViewState::default());
+        self.switch_tab(self.tabs.len() - 1);
+    }
+
+    /// Close the active tab and show its right-hand neighbour (or the last tab)
+    pub fn close_tab(&mut self) {
+        if self.tabs.len() == 1 {
+            self.status_message = Some("Only one tab is open".to_string());
+            return;
+        }
+        self.tabs.remove(self.active_tab);
+        self.active_tab = self.active_tab.min(self.tabs.len() - 1);
+        self.view = std::mem::take(&mut self.tabs[self.active_tab]);
+        self.after_tab_switch();
+    }
+
+    /// Show tab `index` (zero-based), keeping the current tab's view as it is
+    pub fn switch_tab(&mut self, index: usize) {
+        if index >= self.tabs.len() || index == self.active_tab {
+            return;
+        }
+        self.tabs[self.active_tab] = std::mem::take(&mut self.view);
+        self.view = std::mem::take(&mut self.tabs[index]);
+        self.active_tab = index;
+        self.after_tab_switch();
+    }
+
+    pub fn next_tab(&mut self) {
+        self.switch_tab((self.active_tab + 1) % self.tabs.len());
+    }
+
+    pub fn previous_tab(&mut self) {
+        self.switch_tab((self.active_tab + self.tabs.len() - 1) % self.tabs.len());
+    }
+
+    /// Label of each tab, in order
+    pub fn tab_labels(&self) -> Vec<String> {
+        (0..self.tabs.len())
+            .map(|index| {
+                if index == self.active_tab {
+                    tab_label(&self.view)
+                } else {
+                    tab_label(&self.tabs[index])
+                }
+            })
+            .collect()
+    }
+
+    fn after_tab_switch(&mut self) {
+        // Popups and confirmations belong to the page they were opened on
+        self.alarm_form = None;
+        self.pending_action = None;
+        self.pending_g = false;
+        let label = tab_label(&self.view);
+        self.status_message = Some(format!(
+            "Tab {}/{}: {label}",
+            self.active_tab + 1,
+            self.tabs.len()
+        ));
+    }
+
+    // ================================
+    // 24. UNDO/REDO AND JUMP LIST
+    // ================================
+
+    fn view_snapshot(&self) -> ViewSnapshot {
+        let on_resource = !matches!(
+            self.view.state,
+            AppState::ServiceList
+                | AppState::InstanceList
+                | AppState::Fleet
+                | AppState::Timings
+                | AppState::ApiCalls
+                | AppState::Internals
+                | AppState::ResourceGroups
+                | AppState::CompositeAlarms
+                | AppState::Alarms
+        );
+        ViewSnapshot {
+            state: self.view.state.clone(),
+            service: self.view.selected_service.clone(),
+            instance_id: self.get_selected_instance_id().filter(|_| on_resource),
+            list_selected: self.view.list_state.selected(),
+            time_range: self.view.time_range,
+            time_range_scroll: self.view.time_range_scroll,
+            watchlist_only: self.watchlist_only,
+            focused_panel: self.view.focused_panel.clone(),
+            selected_metric_index: self.view.sparkline_grid_selected_index,
+            scroll_offset: self.view.scroll_offset,
+        }
+    }
+
+    /// Record the current view before navigation or a setting changes it
+    fn remember_view(&mut self) {
+        let snapshot = self.view_snapshot();
+        self.view.history.record(snapshot);
+    }
+
+    pub async fn undo_view(&mut self) -> Result<()> {
+        let current = self.view_snapshot();
+        match self.view.history.undo(current) {
+            Some(snapshot) => self.restore_view(snapshot).await,
+            None => {
+                self.status_message = Some("Nothing to undo".to_string());
+                Ok(())
+            }
+        }
+    }
+
+    pub async fn redo_view(&mut self) -> Result<()> {
+        let current = self.view_snapshot();
+        match self.view.history.redo(current) {
+            Some(snapshot) => self.restore_view(snapshot).await,
+            None => {
+                self.status_message = Some("Nothing to redo".to_string());
+                Ok(())
+            }
+        }
+    }
+
+    /// Go back to the resource opened before this one (Ctrl+o)
+    pub async fn jump_back(&mut self) -> Result<()> {
+        let current = self.view_snapshot();
+        match self.view.jumps.back(current) {
+            Some(snapshot) => self.jump_to(snapshot).await,
+            None => {
+                self.status_message = Some("No earlier resource in this tab".to_string());
+                Ok(())
+            }
+        }
+    }
+
+    /// Go forward again through the resources gone back from (Ctrl+i)
+    pub async fn jump_forward(&mut self) -> Result<()> {
+        let current = self.view_snapshot();
+        match self.view.jumps.forward(current) {
+            Some(snapshot) => self.jump_to(snapshot).await,
+            None => {
+                self.status_message = Some("No later resource in this tab".to_string());
+                Ok(())
+            }
+        }
+    }
+
+    async fn jump_to(&mut self, snapshot: ViewSnapshot) -> Result<()> {
+        // A jump is a view change like any other, so it can be undone
+        self.remember_view();
+        self.restore_view(snapshot).await
+    }
+
+    /// Go back to a recorded view, reloading the resources and metrics it shows
+    async fn restore_view(&mut self, snapshot: ViewSnapshot) -> Result<()> {
+        let previous = self.view_snapshot();
+        self.watchlist_only = snapshot.watchlist_only;
+        self.view.time_range = snapshot.time_range;
+        self.view.time_range_scroll = snapshot.time_range_scroll;
+        self.view.list_state.select(snapshot.list_selected);
+
+        let Some(service) = snapshot.service else {
+            self.view.state = AppState::ServiceList;
+            self.view.selected_service = None;
+            self.view.selected_instance = None;
+            self.view.instances.clear();
+            self.view.rds_instances.clear();
+            return Ok(());
+        };
+        if previous.service.as_ref() != Some(&service)
+            || previous.watchlist_only != snapshot.watchlist_only
+        {
+            self.view.selected_service = Some(service.clone());
+            self.load_service_instances(&service).await?;
+        }
+
+        // The resource may have gone since; the list is shown instead
+        let Some(index) = snapshot.instance_id.as_ref().and_then(|id| {
+            self.view
+                .instances
+                .iter()
+                .position(|instance| instance.as_aws_instance().id() == id)
+        }) else {
+            self.view.state = AppState::InstanceList;
+            self.view.selected_instance = None;
+            return Ok(());
+        };
+        self.view.list_state.select(Some(index));
+        self.view.selected_instance = Some(index);
+        // Pages with their own data are left for the metrics they were opened from
+        self.view.state = match snapshot.state {
+            AppState::InstanceDetails => AppState::InstanceDetails,
+            _ => AppState::MetricsSummary,
+        };
+        self.view.focused_panel = snapshot.focused_panel;
+        self.view.scroll_offset = snapshot.scroll_offset;
+        self.view.sparkline_grid_selected_index = snapshot.selected_metric_index;
+
+        if previous.instance_id != snapshot.instance_id
+            || previous.time_range != snapshot.time_range
+        {
+            let id = snapshot.instance_id.unwrap_or_default();
+            self.load_resource_metrics(&service, &id).await?;
+            self.view.sparkline_grid_selected_index = snapshot.selected_metric_index;
+        }
+        self.update_selected_metric();
+        Ok(())
+    }
+
+    /// Load the metrics of the open resource, however its service fetches them
+    async fn load_resource_metrics(&mut self, service: &AwsService, id: &str) -> Result<()> {
+        match service {
+            AwsService::Sqs
+            | AwsService::Synthetics
+            | AwsService::EventBridge
+            | AwsService::Lambda => self.load_service_metrics(id).await,
+            AwsService::Batch => self.load_compute_environment_metrics().await,
+            AwsService::Rds => {
+                self.detect_metric_resolution(id).await;
+                self.load_metrics(id).await
+            }
+        }
+    }
+
+    /// Open a resource's metrics, or its chart page, over `time_range`; like a
+    /// jump, it can be undone. False when the resource does not exist.
+    pub async fn open_resource(
+        &mut self,
+        service: AwsService,
+        resource: &str,
+        time_range: TimeRange,
+        chart: bool,
+    ) -> Result<bool> {
+        let chart = chart && service == AwsService::Rds;
+        let snapshot = ViewSnapshot {
+            state: if chart {
+                AppState::InstanceDetails
+            } else {
+                AppState::MetricsSummary
+            },
+            service: Some(service),
+            instance_id: Some(resource.to_string()),
+            list_selected: None,
+            time_range,
+            watchlist_only: false,
+            selected_metric_index: 0,
+            scroll_offset: 0,
+            ..self.view_snapshot()
+        };
+        self.jump_to(snapshot).await?;
+        Ok(self.view.selected_instance.is_some())
+    }
+
+    /// Select an RDS metric by display name or CloudWatch name; false when it has no data
+    pub fn select_metric(&mut self, name: &str) -> bool {
+        let Some(index) = self
+            .view
+            .metrics
+            .get_available_metrics()
+            .iter()
+            .position(|metric| metric.display_name() == name || metric.metric_name() == name)
+        else {
+            return false;
+        };
+        self.view.sparkline_grid_selected_index = index;
+        self.view.saved_sparkline_grid_selected_index = index;
+        if self.view.state == AppState::InstanceDetails {
+            self.view.scroll_offset = index;
+        }
+        self.update_selected_metric();
+        true
+    }
+
+    /// Show the open resource over another time range, reloading its metrics
+    pub async fn set_time_range(&mut self, time_range: TimeRange) -> Result<()> {
+        self.remember_view();
+        self.view.time_range = time_range;
+        self.reload_open_resource().await
+    }
+
+    /// Load the open resource's metrics again, e.g. for a script
+    pub async fn reload_open_resource(&mut self) -> Result<()> {
+        let (Some(service), Some(id)) = (
+            self.view.selected_service.clone(),
+            self.get_selected_instance_id(),
+        ) else {
+            return Ok(());
+        };
+        if matches!(
+            self.view.state,
+            AppState::MetricsSummary | AppState::InstanceDetails
+        ) {
+            self.load_resource_metrics(&service, &id).await?;
+        }
+        Ok(())
+    }
+
+    // ================================
+    // 25. THRESHOLD EDITOR
+    // ================================
+
+    /// Open the threshold editor, previewing against every RDS instance
+    pub async fn open_threshold_editor(&mut self) {
+        let instances = if self.view.rds_instances.is_empty() {
+            RdsInstanceManager::load_instances()
+                .await
+                .unwrap_or_default()
+        } else {
+            self.view.rds_instances.clone()
+        };
+        self.threshold_editor = Some(ThresholdEditor::new(
+            &self.config.thresholds,
+            instances
+                .into_iter()
+                .map(|instance| instance.identifier)
+                .collect(),
+        ));
+        self.load_threshold_previews().await;
+    }
+
+    /// Fetch the latest values of metrics that got a threshold since the last fetch
+    pub async fn load_threshold_previews(&mut self) {
+        let Some(editor) = &self.threshold_editor else {
+            return;
+        };
+        let missing = editor.missing_previews();
+        if missing.is_empty() {
+            return;
+        }
+        let instances = editor.instances.clone();
+        let latest = futures::future::join_all(
+            missing
+                .iter()
+                .map(|metric| thresholds::fetch_latest(metric, &instances)),
+        )
+        .await;
+        if let Some(editor) = self.threshold_editor.as_mut() {
+            for (metric, latest) in missing.iter().zip(latest) {
+                editor.set_latest(metric, latest);
+            }
+        }
+    }
+
+    pub fn close_threshold_editor(&mut self) {
+        self.threshold_editor = None;
+    }
+
+    /// Validate the edited thresholds, write them to the config file and use them
+    pub fn save_thresholds(&mut self) {
+        let Some(editor) = &self.threshold_editor else {
+            return;
+        };
+        let thresholds = match editor.thresholds() {
+            Ok(thresholds) => thresholds,
+            Err(e) => {
+                self.status_message = Some(e.to_string());
+                return;
+            }
+        };
+        match thresholds.save() {
+            Ok(path) => {
+                self.config.thresholds = thresholds;
+                self.threshold_editor = None;
+                self.status_message = Some(format!("Saved thresholds to {}", path.display()));
+            }
+            Err(e) => self.status_message = Some(format!("Could not save thresholds: {e:#}")),
+        }
+    }
+
+    // ================================
+    // 26. CLOUDTRAIL ACTIVITY
+    // ================================
+
+    /// Open the API activity panel with the selected resource's recent changes, or close it
+    pub async fn toggle_activity_panel(&mut self) {
+        if self.view.activity.open {
+            self.view.activity.open = false;
+            return;
+        }
+        let Some(id) = self.get_selected_instance_id() else {
+            return;
+        };
+        if !self.check_policy(Feature::Activity, &[&id]) {
+            return;
+        }
+        self.view.activity.open = true;
+        self.view.activity.selected_index = 0;
+        self.load_activity().await;
+    }
+
+    /// Look up write API calls against the selected resource over the shown time range
+    pub async fn load_activity(&mut self) {
+        let Some(resource) = self
+            .get_selected_instance()
+            .map(|instance| trail_resource_name(instance).to_string())
+        else {
+            return;
+        };
+        let start_time = SystemTime::now() - self.view.time_range.duration();
+
+        match CloudTrailClientManager::new()
+            .await
+            .lookup_resource_events(&resource, start_time)
+            .await
+        {
+            Ok(events) => {
+                self.view.activity.events = events;
+                self.view.activity.resource = Some(resource);
+            }
+            Err(e) => {
+                self.view.activity.events = Vec::new();
+                self.view.activity.resource = None;
+                self.status_message = Some(format!("CloudTrail Error: {e}"));
+            }
+        }
+        self.view.activity.selected_index = self
+            .view
+            .activity
+            .selected_index
+            .min(self.view.activity.events.len().saturating_sub(1));
+    }
+
+    pub fn activity_scroll_up(&mut self) {
+        self.view.activity.selected_index = self.view.activity.selected_index.saturating_sub(1);
+    }
+
+    pub fn activity_scroll_down(&mut self) {
+        if self.view.activity.selected_index + 1 < self.view.activity.events.len() {
+            self.view.activity.selected_index += 1;
+        }
+    }
+
+    /// When the selected resource was changed, for marking on its charts
+    ///
+    /// Only successful calls from the last lookup of this resource count; the
+    /// markers disappear when another resource is opened.
+    pub fn activity_markers(&self) -> Vec<SystemTime> {
+        let current = self.get_selected_instance().map(trail_resource_name);
+        if current.is_none() || self.view.activity.resource.as_deref() != current {
+            return Vec::new();
+        }
+        self.view
+            .activity
+            .events
+            .iter()
+            .filter(|event| event.error.is_none())
+            .filter_map(|event| event.time)
+            .collect()
+    }
+
+    // ================================
+    // 27. RIGHT-SIZING
+    // ================================
+
+    /// Open the right-sizing panel with the selected RDS instance's recommendation, or close it
+    pub async fn toggle_rightsizing_panel(&mut self) {
+        if self.view.rightsizing.open {
+            self.view.rightsizing.open = false;
+            return;
+        }
+        self.view.rightsizing.open = true;
+        self.load_rightsizing().await;
+    }
+
+    /// Fetch Compute Optimizer's recommendation for the selected RDS instance
+    pub async fn load_rightsizing(&mut self) {
+        let Some(instance) = self.get_selected_rds_instance() else {
+            return;
+        };
+        let identifier = instance.identifier.clone();
+        let Some(arn) = instance.attribute("ARN").map(str::to_string) else {
+            self.status_message = Some(format!("No ARN known for {identifier}"));
+            return;
+        };
+
+        match ComputeOptimizerClientManager::new()
+            .await
+            .rds_recommendation(&arn)
+            .await
+        {
+            Ok(recommendation) => {
+                self.view.rightsizing.recommendation = recommendation;
+                self.view.rightsizing.instance = Some(identifier);
+            }
+            Err(e) => {
+                self.view.rightsizing.recommendation = None;
+                self.view.rightsizing.instance = None;
+                self.status_message = Some(format!("Compute Optimizer Error: {e}"));
+            }
+        }
+    }
+
+    // ================================
+    // 28. BOOKMARKS
+    // ================================
+
+    pub fn toggle_bookmarks_panel(&mut self) {
+        self.bookmarks_panel = match self.bookmarks_panel {
+            Some(_) => None,
+            None => Some(BookmarksPanel::default()),
+        };
+    }
+
+    pub fn bookmarks_scroll_up(&mut self) {
+        if let Some(panel) = self.bookmarks_panel.as_mut() {
+            panel.selected_index = panel.selected_index.saturating_sub(1);
+        }
+    }
+
+    pub fn bookmarks_scroll_down(&mut self) {
+        let count = self.config.bookmarks.len();
+        if let Some(panel) = self.bookmarks_panel.as_mut() {
+            if panel.selected_index + 1 < count {
+                panel.selected_index += 1;
+            }
+        }
+    }
+
+    /// Start naming a bookmark of the view behind the panel, suggesting a name
+    pub fn start_bookmark(&mut self) {
+        let Some(bookmark) = self.bookmark_current_view(String::new()) else {
+            self.status_message = Some("Open a resource's metrics to bookmark them".to_string());
+            return;
+        };
+        let name = [
+            Some(bookmark.resource),
+            bookmark.metric,
+            Some(bookmark.range),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ");
+        if let Some(panel) = self.bookmarks_panel.as_mut() {
+            panel.name_input = Some(name);
+        }
+    }
+
+    pub fn bookmark_name_input(&mut self) -> Option<&mut String> {
+        self.bookmarks_panel.as_mut()?.name_input.as_mut()
+    }
+
+    pub fn cancel_bookmark(&mut self) {
+        if let Some(panel) = self.bookmarks_panel.as_mut() {
+            panel.name_input = None;
+        }
+    }
+
+    /// Save the view behind the panel under the typed name, replacing a bookmark of that name
+    pub fn save_bookmark(&mut self) {
+        let Some(name) = self
+            .bookmarks_panel
+            .as_mut()
+            .and_then(|panel| panel.name_input.take())
+        else {
+            return;
+        };
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            self.status_message = Some("A bookmark needs a name".to_string());
+            return;
+        }
+        let Some(bookmark) = self.bookmark_current_view(name.clone()) else {
+            return;
+        };
+
+        let mut bookmarks = self.config.bookmarks.clone();
+        match bookmarks.iter().position(|existing| existing.name == name) {
+            Some(index) => bookmarks[index] = bookmark,
+            None => bookmarks.push(bookmark),
+        }
+        self.store_bookmarks(bookmarks, format!("Bookmarked {name}"));
+        if let Some(panel) = self.bookmarks_panel.as_mut() {
+            panel.selected_index = self
+                .config
+                .bookmarks
+                .iter()
+                .position(|bookmark| bookmark.name == name)
+                .unwrap_or_default();
+        }
+    }
+
+    pub fn delete_selected_bookmark(&mut self) {
+        let Some(index) = self.selected_bookmark_index() else {
+            return;
+        };
+        let mut bookmarks = self.config.bookmarks.clone();
+        let removed = bookmarks.remove(index);
+        self.store_bookmarks(bookmarks, format!("Deleted bookmark {}", removed.name));
+        let count = self.config.bookmarks.len();
+        if let Some(panel) = self.bookmarks_panel.as_mut() {
+            panel.selected_index = panel.selected_index.min(count.saturating_sub(1));
+        }
+    }
+
+    /// Copy the selected bookmark as JSON, for `awscw bookmarks --import` elsewhere
+    pub fn copy_selected_bookmark(&mut self) {
+        let Some(bookmark) = self
+            .selected_bookmark_index()
+            .map(|index| &self.config.bookmarks[index])
+        else {
+            return;
+        };
+        let copied = bookmark.to_json().and_then(|json| copy_to_clipboard(&json));
+        self.status_message = Some(match copied {
+            Ok(()) => format!("Copied bookmark {} as JSON", bookmark.name),
+            Err(e) => format!("Copy failed: {e}"),
+        });
+    }
+
+    /// Go to the selected bookmark's view; like a jump, it can be undone
+    pub async fn open_selected_bookmark(&mut self) -> Result<()> {
+        let Some(bookmark) = self
+            .selected_bookmark_index()
+            .map(|index| self.config.bookmarks[index].clone())
+        else {
+            return Ok(());
+        };
+        let (service, time_range) = match (bookmark.service(), bookmark.time_range()) {
+            (Ok(service), Ok(time_range)) => (service, time_range),
+            (Err(e), _) | (_, Err(e)) => {
+                self.status_message = Some(format!("Bookmark {}: {e}", bookmark.name));
+                return Ok(());
+            }
+        };
+        self.bookmarks_panel = None;
+
+        if !self
+            .open_resource(service, &bookmark.resource, time_range, bookmark.chart)
+            .await?
+        {
+            self.status_message = Some(format!("{} no longer exists", bookmark.resource));
+            return Ok(());
+        }
+        if let Some(metric) = &bookmark.metric {
+            self.select_metric(metric);
+        }
+        Ok(())
+    }
+
+    fn selected_bookmark_index(&self) -> Option<usize> {
+        let index = self.bookmarks_panel.as_ref()?.selected_index;
+        (index < self.config.bookmarks.len()).then_some(index)
+    }
+
+    /// The resource, metric and range being shown, as a bookmark named `name`
+    fn bookmark_current_view(&self, name: String) -> Option<Bookmark> {
+        if !matches!(
+            self.view.state,
+            AppState::MetricsSummary | AppState::InstanceDetails
+        ) {
+            return None;
+        }
+        let service = self.view.selected_service.as_ref()?;
+        let metric = match service {
+            AwsService::Rds => self
+                .view
+                .selected_metric
+                .as_ref()
+                .map(|metric| metric.display_name().to_string()),
+            _ => None,
+        };
+        Some(Bookmark::new(
+            name,
+            service,
+            self.get_selected_instance_id()?,
+            metric,
+            self.view.time_range,
+            self.view.state == AppState::InstanceDetails,
+        ))
+    }
+
+    fn store_bookmarks(&mut self, bookmarks: Vec<Bookmark>, done: String) {
+        match bookmarks::save(&bookmarks) {
+            Ok(_) => {
+                self.config.bookmarks = bookmarks;
+                self.status_message = Some(done);
+            }
+            Err(e) => self.status_message = Some(format!("Could not save bookmarks: {e:#}")),
+        }
+    }
+
+    // ================================
+    // 29. WINDOW COMPARISON
+    // ================================
+
+    /// Open the comparison of the selected RDS instance's range with an earlier one, or close it
+    pub async fn toggle_compare_panel(&mut self) {
+        if self.view.compare.open {
+            self.view.compare.open = false;
+            return;
+        }
+        self.view.compare.open = true;
+        self.load_comparison().await;
+    }
+
+    /// Compare against the next offset (a day, a week, four weeks earlier)
+    pub async fn cycle_compare_offset(&mut self) {
+        self.view.compare.offset = self.view.compare.offset.next();
+        self.load_comparison().await;
+    }
+
+    /// Fetch every metric for the shown range and for the same range at the panel's offset
+    pub async fn load_comparison(&mut self) {
+        let Some(identifier) = self.get_selected_rds_instance_id() else {
+            return;
+        };
+        let offset = self.view.compare.offset;
+        if offset.duration() < self.view.time_range.duration() {
+            self.status_message = Some(format!(
+                "The windows overlap: the range is longer than {}",
+                offset.label()
+            ));
+        }
+        self.view.compare.rows =
+            load_window_comparison(&identifier, self.view.time_range, offset).await;
+        self.view.compare.selected_index = 0;
+        self.view.compare.instance = Some(identifier);
+    }
+
+    pub fn compare_scroll_up(&mut self) {
+        self.view.compare.selected_index = self.view.compare.selected_index.saturating_sub(1);
+    }
+
+    pub fn compare_scroll_down(&mut self) {
+        if self.view.compare.selected_index + 1 < self.view.compare.rows.len() {
+            self.view.compare.selected_index += 1;
+        }
+    }
+
+    // ================================
+    // 30. BACKGROUND JOBS
+    // ================================
+
+    /// Ask for confirmation before snapshotting the selected RDS instance
+    pub fn request_snapshot(&mut self) {
+        let Some(instance_id) = self.get_selected_rds_instance_id() else {
+            return;
+        };
+        let question = format!("Create a manual snapshot of {instance_id}?");
+        self.request_action(PendingAction::CreateSnapshot { instance_id }, question);
+    }
+
+    /// Ask for confirmation before moving the selected dead-letter queue's messages back
+    pub fn request_redrive(&mut self) {
+        let Some(queue) = self.get_selected_sqs_queue() else {
+            return;
+        };
+        let queue_name = queue.name.clone();
+        let Some(queue_arn) = queue.attribute("QueueArn").map(str::to_string) else {
+            self.status_message = Some(format!("No ARN known for {queue_name}"));
+            return;
+        };
+        let question = format!("Move every message in {queue_name} back to its source queues?");
+        self.request_action(
+            PendingAction::RedriveQueue {
+                queue_name,
+                queue_arn,
+            },
+            question,
+        );
+    }
+
+    /// Write the fleet summary to a Markdown file in the working directory
+    pub fn start_fleet_export(&mut self) {
+        let path = PathBuf::from(format!(
+            "awscw-fleet-{}.md",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        let thresholds = self.config.thresholds.clone();
+        let reference_lines = self.config.reference_lines.clone();
+        self.status_message = Some(format!(
+            "Exporting the fleet summary to {} - J: Jobs",
+            path.display()
+        ));
+        self.jobs.start("Fleet export".to_string(), |reporter| {
+            export::export_fleet_to_file(
+                ExportFormat::Markdown,
+                thresholds,
+                reference_lines,
+                path,
+                reporter,
+            )
+        });
+    }
+
+    /// Pick up progress and outcomes of background jobs; never waits for them
+    pub fn poll_jobs(&mut self) {
+        self.jobs.poll();
+        if self.config.quiet_hours.is_now() {
+            for toast in self.jobs.take_toasts() {
+                self.held_notifications.hold(&toast.text);
+            }
+        }
+    }
+
+    pub fn toggle_jobs_panel(&mut self) {
+        self.jobs_panel = match self.jobs_panel {
+            Some(_) => None,
+            None => Some(JobsPanel::default()),
+        };
+    }
+
+    pub fn jobs_scroll_up(&mut self) {
+        if let Some(panel) = self.jobs_panel.as_mut() {
+            panel.selected_index = panel.selected_index.saturating_sub(1);
+        }
+    }
+
+    pub fn jobs_scroll_down(&mut self) {
+        let count = self.jobs.jobs().len();
+        if let Some(panel) = self.jobs_panel.as_mut() {
+            if panel.selected_index + 1 < count {
+                panel.selected_index += 1;
+            }
+        }
+    }
+
+    pub fn cancel_selected_job(&mut self) {
+        if let Some(panel) = &self.jobs_panel {
+            self.jobs.cancel(panel.selected_index);
+        }
+    }
+
+    pub fn clear_finished_jobs(&mut self) {
+        self.jobs.clear_finished();
+        if let Some(panel) = self.jobs_panel.as_mut() {
+            panel.selected_index = 0;
+        }
+    }
+
+    // ================================
+    // 31. PERFORMANCE HUD
+    // ================================
+
+    pub fn toggle_perf_hud(&mut self) {
+        self.perf_hud_open = !self.perf_hud_open;
+        self.perf = PerfStats::default();
+    }
+
+    /// Metric history held by every tab, for the HUD's memory figures
+    pub fn held_history(&self) -> HeldHistory {
+        let mut held = HeldHistory::default();
+        let views = std::iter::once(&self.view).chain(
+            self.tabs
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| *index != self.active_tab)
+                .map(|(_, view)| view),
+        );
+        for view in views {
+            if !view.metrics.timestamps.is_empty() {
+                held.timestamps += view.metrics.timestamps.len();
+                for metric in MetricType::ALL.iter() {
+                    held.add_series(view.metrics.get_metric_history(metric).len());
+                }
+            }
+            if let Some(metrics) = &view.service_metrics {
+                held.timestamps += metrics.timestamps.len();
+                for value in metrics.raw_metrics.values() {
+                    held.add_series(value.history.len());
+                }
+            }
+            for lag in &view.topology.lags {
+                held.timestamps += lag.timestamps.len();
+                held.add_series(lag.history.len());
+            }
+            for row in &view.compare.rows {
+                held.timestamps += row.current_timestamps.len() + row.previous_timestamps.len();
+                held.add_series(row.current.len());
+                held.add_series(row.previous.len());
+            }
+        }
+        held
+    }
+
+    // ================================
+    // 32. CLOUDFORMATION STACK
+    // ================================
+
+    /// Open the stack panel with the CloudFormation stack of the resource being viewed, or close it
+    pub async fn toggle_stack_panel(&mut self) {
+        if self.view.stack.open {
+            self.view.stack.open = false;
+            return;
+        }
+        self.view.stack.open = true;
+        self.load_stack().await;
+    }
+
+    /// Look up which stack the selected resource belongs to, and their drift
+    pub async fn load_stack(&mut self) {
+        let Some(physical_id) = self.get_selected_instance().and_then(physical_resource_id) else {
+            self.view.stack.membership = None;
+            self.view.stack.resource = None;
+            return;
+        };
+
+        match CloudFormationClientManager::new()
+            .await
+            .stack_membership(&physical_id)
+            .await
+        {
+            Ok(membership) => {
+                self.view.stack.membership = membership;
+                self.view.stack.resource = Some(physical_id);
+            }
+            Err(e) => {
+                self.view.stack.membership = None;
+                self.view.stack.resource = None;
+                self.status_message = Some(format!("CloudFormation Error: {e}"));
+            }
+        }
+    }
+
+    // ================================
+    // 33. REFERENCE LINES
+    // ================================
+
+    /// Lines pinned on a metric's chart, by CloudWatch metric name
+    pub fn pinned_lines(&self, metric_name: &str) -> &[PinnedLine] {
+        self.config
+            .reference_lines
+            .get(metric_name)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Start typing a reference line for the charted metric
+    pub fn open_pin_prompt(&mut self) {
+        if let Some(metric) = self.detail_chart_metric() {
+            self.pin_prompt = Some(PinPrompt::new(metric));
+        }
+    }
+
+    pub fn cancel_pin_prompt(&mut self) {
+        self.pin_prompt = None;
+    }
+
+    /// Pin the typed line, or clear the metric's lines when nothing was typed,
+    /// and write them to the config file; an invalid line keeps the prompt open
+    pub fn submit_pin_prompt(&mut self) {
+        let Some(prompt) = &self.pin_prompt else {
+            return;
+        };
+        let metric_name = prompt.metric.metric_name().to_string();
+        let mut lines = self.config.reference_lines.clone();
+        let message = if prompt.text.trim().is_empty() {
+            lines.remove(&metric_name);
+            format!("Cleared the lines on {}", prompt.metric.display_name())
+        } else {
+            match parse_line(&prompt.text, prompt.unit()) {
+                Ok(line) => {
+                    let message = format!("Pinned {}", line.describe(prompt.unit()));
+                    lines.entry(metric_name).or_default().push(line);
+                    message
+                }
+                Err(e) => {
+                    self.status_message = Some(e.to_string());
+                    return;
+                }
+            }
+        };
+        match reference_lines::save(&lines) {
+            Ok(path) => {
+                self.config.reference_lines = lines;
+                self.pin_prompt = None;
+                self.status_message = Some(format!("{message} in {}", path.display()));
+            }
+            Err(e) => self.status_message = Some(format!("Could not save reference lines: {e:#}")),
+        }
+    }
+
+    // ================================
+    // 34. PREFETCH
+    // ================================
+
+    /// Warm the metrics of the watchlist's and bookmarked RDS instances in the background
+    pub fn start_prefetch(&mut self) {
+        if !self.config.prefetch.enabled {
+            return;
+        }
+        let instances = prefetch::targets(
+            &self.watchlist,
+            &self.config.bookmarks,
+            self.config.prefetch.max_instances,
+        );
+        if !instances.is_empty() {
+            self.prefetch = Prefetch::start(instances, self.view.time_range);
+        }
+    }
+
+    // ================================
+    // 35. FLEET
+    // ================================
+
+    /// Open the fleet page, listing a service across the configured accounts
+    pub async fn enter_fleet(&mut self) {
+        if self.config.accounts.is_empty() {
+            self.status_message =
+                Some("Add [[accounts]] to config.toml to list a fleet".to_string());
+            return;
+        }
+        self.view.state = AppState::Fleet;
+        self.load_fleet().await;
+    }
+
+    /// List the fleet page's service in every account and region at once
+    pub async fn load_fleet(&mut self) {
+        let service = self
+            .view
+            .fleet
+            .service
+            .get_or_insert(AwsService::Rds)
+            .clone();
+        let locations = fleet::locations(&self.config.accounts);
+        self.view.fleet.inventory = fleet::enumerate(&service, &locations).await;
+        self.view
+            .fleet
+            .table_state
+            .select((!self.view.fleet.inventory.rows.is_empty()).then_some(0));
+        self.mark_refreshed();
+    }
+
+    /// List the next service on the fleet page
+    pub async fn cycle_fleet_service(&mut self) {
+        let current = self.view.fleet.service.clone().unwrap_or(AwsService::Rds);
+        let index = self
+            .available_services
+            .iter()
+            .position(|service| *service == current)
+            .map_or(0, |index| (index + 1) % self.available_services.len());
+        self.view.fleet.service = self.available_services.get(index).cloned();
+        self.load_fleet().await;
+    }
+
+    pub fn fleet_next(&mut self) {
+        if let Some(index) = self.view.fleet.table_state.selected() {
+            if index + 1 < self.view.fleet.inventory.rows.len() {
+                self.view.fleet.table_state.select(Some(index + 1));
+            }
+        }
+    }
+
+    pub fn fleet_previous(&mut self) {
+        if let Some(index) = self.view.fleet.table_state.selected() {
+            self.view
+                .fleet
+                .table_state
+                .select(Some(index.saturating_sub(1)));
+        }
+    }
+
+    pub fn get_selected_fleet_row(&self) -> Option<&FleetRow> {
+        self.view
+            .fleet
+            .inventory
+            .rows
+            .get(self.view.fleet.table_state.selected()?)
+    }
+
+    /// Switch the session to the selected row's account and region and open its resource
+    ///
+    /// The switch applies to every tab, as they share one session.
+    pub async fn open_fleet_row(&mut self) -> Result<()> {
+        let Some(row) = self.get_selected_fleet_row().cloned() else {
+            return Ok(());
+        };
+        let service = self.view.fleet.service.clone().unwrap_or(AwsService::Rds);
+        AwsSessionManager::switch_to(AwsSessionManager::load_context(&row.context).await).await;
+        // Quotas and warmed series were loaded in the session switched away from
+        self.quotas = QuotaCache::default();
+        self.prefetch = Prefetch::default();
+        self.active_account = Some(row.location_label());
+        // The instance list is of the old session too, so it is always listed again
+        self.view.selected_service = None;
+
+        let id = fleet::resource_id(&row);
+        let opened = self
+            .open_resource(service, id, self.view.time_range, false)
+            .await?;
+        self.status_message = Some(if opened {
+            format!("Switched to {}", row.location_label())
+        } else {
+            format!("{id} is no longer in {}", row.location_label())
+        });
+        Ok(())
+    }
+
+    pub fn back_from_fleet(&mut self) {
+        self.view.state = AppState::ServiceList;
+    }
+
+    // ================================
+    // 36. PAGE TIMINGS AND COMMAND LINE
+    // ================================
+
+    /// The tab and kind of page shown, to tell when an input changed them
+    pub fn page_position(&self) -> (usize, String) {
+        (self.active_tab, page_name(&self.view))
+    }
+
+    /// Start timing a page load if the input that started at `started` left `before`
+    pub fn note_page_change(&mut self, before: (usize, String), started: Instant) {
+        let after = self.page_position();
+        if after != before {
+            self.timings.fetched(after.1, started);
+        }
+    }
+
+    /// Open the page listing how long page loads took
+    pub fn enter_timings(&mut self) {
+        if self.view.state != AppState::Timings {
+            self.view.timings_from = Some(self.view.state.clone());
+            self.view.state = AppState::Timings;
+        }
+    }
+
+    pub fn back_from_timings(&mut self) {
+        self.view.state = self
+            .view
+            .timings_from
+            .take()
+            .unwrap_or(AppState::ServiceList);
+    }
+
+    pub fn clear_timings(&mut self) {
+        self.timings.clear();
+    }
+
+    /// Put the API calls made since the last frame down to the page now shown
+    pub fn attribute_api_calls(&self) {
+        usage::attribute(&page_name(&self.view));
+    }
+
+    /// Open the page listing the API calls made this session
+    pub fn enter_api_calls(&mut self) {
+        if self.view.state != AppState::ApiCalls {
+            self.view.api_calls_from = Some(self.view.state.clone());
+            self.view.state = AppState::ApiCalls;
+        }
+    }
+
+    pub fn back_from_api_calls(&mut self) {
+        self.view.state = self
+            .view
+            .api_calls_from
+            .take()
+            .unwrap_or(AppState::ServiceList);
+    }
+
+    /// Sample the app's own figures for the internals page, at most once a second
+    pub fn sample_internals(&mut self) {
+        if !self.internals.is_due() {
+            return;
+        }
+        let warming = self
+            .prefetch
+            .progress()
+            .map_or(0, |(done, total)| total - done);
+        self.internals.sample(Readings {
+            calls: usage::call_time(),
+            cache_hit_rate: self.prefetch.hit_rate(),
+            pending_fetches: warming + self.jobs.running(),
+            memory: perf::resident_memory(),
+        });
+    }
+
+    /// Open the page charting the app's own figures
+    pub fn enter_internals(&mut self) {
+        if self.view.state != AppState::Internals {
+            self.view.internals_from = Some(self.view.state.clone());
+            self.view.state = AppState::Internals;
+        }
+    }
+
+    pub fn back_from_internals(&mut self) {
+        self.view.state = self
+            .view
+            .internals_from
+            .take()
+            .unwrap_or(AppState::ServiceList);
+    }
+
+    /// Copy the latest internals, for a bug report
+    pub fn copy_internals(&mut self) {
+        self.status_message = Some(match copy_to_clipboard(&self.internals.report()) {
+            Ok(()) => "Copied internals to clipboard".to_string(),
+            Err(e) => format!("Copy failed: {e}"),
+        });
+    }
+
+    pub fn open_command_line(&mut self) {
+        self.command_line = Some(String::new());
+    }
+
+    pub fn cancel_command_line(&mut self) {
+        self.command_line = None;
+    }
+
+    /// Run the typed commands as the control socket would; true when one asked to quit
+    pub async fn submit_command_line(&mut self) -> bool {
+        let Some(line) = self.command_line.take() else {
+            return false;
+        };
+        let (quit, answer) = control::run_line(self, &line).await;
+        if answer != "ok" {
+            self.status_message = Some(answer);
+        }
+        quit
+    }
+
+    // ================================
+    // 37. INCIDENT MODE
+    // ================================
+
+    /// Start incident mode on the selected RDS instance, or end it
+    pub async fn toggle_incident(&mut self) -> Result<()> {
+        if self.incident.is_some() {
+            return self.end_incident().await;
+        }
+        let Some(instance) = self.get_selected_rds_instance() else {
+            return Ok(());
+        };
+        let resource = instance.identifier.clone();
+        let log_group = incident::error_log_group(
+            &instance.identifier,
+            instance.attribute("Cluster"),
+            &instance.engine,
+        );
+        let restore = Restore {
+            time_range: self.view.time_range,
+            time_range_scroll: self.view.time_range_scroll,
+            auto_refresh: self.auto_refresh_enabled,
+        };
+        let recording = Recording::start(&resource, SystemTime::now());
+        self.status_message = Some(match &recording {
+            Ok(recording) => format!("Incident mode: recording to {}", recording.path.display()),
+            Err(e) => format!("Incident mode, not recording: {e:#}"),
+        });
+
+        let mut incident = Incident::new(resource, log_group, recording.ok(), restore);
+        incident.note(&format!(
+            "Incident mode on: last hour, refreshed every {}s",
+            incident::REFRESH_INTERVAL.as_secs()
+        ));
+        self.incident = Some(incident);
+        self.auto_refresh_enabled = true;
+        let time_range = incident::time_range();
+        if let Some(index) = Self::get_time_range_options()
+            .iter()
+            .position(|&(_, value, unit, _)| value == time_range.value && unit == time_range.unit)
+        {
+            self.view.time_range_scroll = index;
+        }
+        self.set_time_range(time_range).await?;
+        self.refresh_incident().await;
+        Ok(())
+    }
+
+    /// Close the recording and put back the time range and auto-refresh
+    async fn end_incident(&mut self) -> Result<()> {
+        let Some(mut incident) = self.incident.take() else {
+            return Ok(());
+        };
+        let minutes = incident.started_at.elapsed().unwrap_or_default().as_secs() / 60;
+        incident.note(&format!("Incident mode off after {minutes} min"));
+        self.status_message = Some(match &incident.recording {
+            Some(recording) => format!(
+                "Incident mode off; timeline in {}",
+                recording.path.display()
+            ),
+            None => "Incident mode off".to_string(),
+        });
+        self.auto_refresh_enabled = incident.restore.auto_refresh;
+        self.view.time_range_scroll = incident.restore.time_range_scroll;
+        self.set_time_range(incident.restore.time_range).await
+    }
+
+    pub fn needs_incident_refresh(&self) -> bool {
+        self.incident.as_ref().is_some_and(Incident::needs_refresh)
+    }
+
+    /// Reload the alarms and read the error log written since the last refresh
+    pub async fn refresh_incident(&mut self) {
+        let Some(incident) = &self.incident else {
+            return;
+        };
+        let resource = incident.resource.clone();
+        let log_group = incident.log_group.clone();
+        let since = incident.log_since();
+
+        self.load_alarms().await;
+        // Off the resource's list (e.g. on another service) its alarms are left as they were
+        let firing: Option<BTreeSet<String>> = self
+            .view
+            .instances
+            .iter()
+            .find(|instance| instance.as_aws_instance().id() == resource)
+            .map(|instance| {
+                self.alarms_for(instance)
+                    .into_iter()
+                    .filter(|alarm| alarm.is_firing())
+                    .map(|alarm| alarm.name.clone())
+                    .collect()
+            });
+        let events = match &log_group {
+            Some(log_group) => Some(
+                LogsClientManager::new()
+                    .await
+                    .recent_events(log_group, since)
+                    .await,
+            ),
+            None => None,
+        };
+
+        let Some(incident) = self.incident.as_mut() else {
+            return;
+        };
+        if let Some(firing) = firing {
+            incident.update_alarms(firing);
+        }
+        match (events, log_group) {
+            (Some(Ok(Some(events))), _) => {
+                incident.log_notice = None;
+                incident.append_log(events);
+            }
+            (Some(Ok(None)), Some(log_group)) => {
+                incident.log_notice = Some(format!(
+                    "{log_group} does not exist - publish the error log to CloudWatch Logs"
+                ));
+            }
+            (Some(Err(e)), _) => incident.log_notice = Some(format!("{e:#}")),
+            _ => {}
+        }
+        incident.last_refresh = Some(Instant::now());
+    }
+
+    /// The incident, when it is on the RDS instance being shown
+    pub fn incident_on_selected(&self) -> Option<&Incident> {
+        let selected = self.get_selected_rds_instance_id()?;
+        self.incident
+            .as_ref()
+            .filter(|incident| incident.resource == selected)
+    }
+
+    // ================================
+    // 38. QUIET HOURS
+    // ================================
+
+    /// `interval`, stretched to the quiet hours' one while they are on
+    fn quiet_interval(&self, interval: Duration) -> Duration {
+        let quiet_hours = &self.config.quiet_hours;
+        if quiet_hours.is_now() {
+            interval.max(quiet_hours.refresh_interval)
+        } else {
+            interval
+        }
+    }
+
+    /// Track quiet hours starting and ending, and say what was logged once they end
+    pub fn poll_quiet_hours(&mut self) {
+        let quiet = self.config.quiet_hours.is_now();
+        if let Some(count) = self.held_notifications.update(quiet) {
+            let log = quiet_hours::log_path().map_or_else(
+                || "the notification log".to_string(),
+                |path| path.display().to_string(),
+            );
+            self.status_message = Some(format!(
+                "{count} notification(s) held during quiet hours - see {log}"
+            ));
+        }
+    }
+
+    // ================================
+    // 39. QUEUE ACCESS
+    // ================================
+
+    /// Open the policy and encryption of the queue being viewed, or close them
+    pub fn toggle_queue_access_panel(&mut self) {
+        if !self.view.queue_access.open {
+            let Some(queue_name) = self.get_selected_sqs_queue().map(|q| q.name.clone()) else {
+                return;
+            };
+            if !self.check_policy(Feature::QueuePolicy, &[&queue_name]) {
+                return;
+            }
+        }
+        self.view.queue_access = QueueAccessPanel {
+            open: !self.view.queue_access.open,
+            scroll: 0,
+        };
+    }
+
+    /// Policy and encryption of the queue being viewed
+    pub fn queue_access(&self) -> Option<QueueAccess> {
+        self.get_selected_sqs_queue().map(policy::inspect)
+    }
+
+    pub fn queue_access_scroll_down(&mut self) {
+        let lines = self.queue_access().map_or(0, |access| access.lines.len());
+        if self.view.queue_access.scroll + 1 < lines {
+            self.view.queue_access.scroll += 1;
+        }
+    }
+
+    pub fn queue_access_scroll_up(&mut self) {
+        self.view.queue_access.scroll = self.view.queue_access.scroll.saturating_sub(1);
+    }
+
+    /// Copy the pretty-printed queue policy
+    pub fn copy_queue_policy(&mut self) {
+        let Some(policy) = self.queue_access().and_then(|access| access.policy) else {
+            self.status_message = Some("This queue has no policy to copy".to_string());
+            return;
+        };
+        self.status_message = Some(match copy_to_clipboard(&policy) {
+            Ok(()) => "Copied queue policy to clipboard".to_string(),
+            Err(e) => format!("Copy failed: {e}"),
+        });
+    }
+
+    /// Copy the ID or alias of the KMS key encrypting the queue
+    pub fn copy_queue_kms_key(&mut self) {
+        let key = self
+            .queue_access()
+            .and_then(|access| access.encryption)
+            .and_then(|encryption| encryption.kms_key().map(str::to_string));
+        let Some(key) = key else {
+            self.status_message = Some("This queue is not encrypted with KMS".to_string());
+            return;
+        };
+        self.status_message = Some(match copy_to_clipboard(&key) {
+            Ok(()) => format!("Copied {key} to clipboard"),
+            Err(e) => format!("Copy failed: {e}"),
+        });
+    }
+
+    // ================================
+    // 40. PARAMETER DRIFT
+    // ================================
+
+    /// Open the parameters the selected RDS instance's parameter group overrides
+    pub async fn enter_parameters(&mut self) {
+        if self.get_selected_rds_instance_id().is_none() {
+            return;
+        }
+        self.view.state = AppState::Parameters;
+        self.load_parameters().await;
+    }
+
+    /// Compare the instance's parameter group with its engine's defaults
+    pub async fn load_parameters(&mut self) {
+        let Some(instance_id) = self.get_selected_rds_instance_id() else {
+            return;
+        };
+        self.view.parameters.notice = None;
+        match RdsClientManager::new()
+            .await
+            .parameter_drift(&instance_id)
+            .await
+        {
+            Ok(drift) => {
+                if drift.overrides.is_empty() {
+                    self.view.parameters.notice = Some(if drift.is_default_group() {
+                        format!("{} is a default parameter group: every parameter has its engine default", drift.group)
+                    } else {
+                        format!(
+                            "{} sets no parameter to other than its engine default",
+                            drift.group
+                        )
+                    });
+                }
+                self.view.parameters.drift = Some(drift);
+            }
+            Err(e) => {
+                self.view.parameters.drift = None;
+                self.view.parameters.notice = Some(format!("{e:#}"));
+            }
+        }
+        let rows = self
+            .view
+            .parameters
+            .drift
+            .as_ref()
+            .map_or(0, |drift| drift.overrides.len());
+        self.view
+            .parameters
+            .table_state
+            .select((rows > 0).then_some(0));
+        self.mark_refreshed();
+    }
+
+    pub fn parameters_next(&mut self) {
+        let rows = self
+            .view
+            .parameters
+            .drift
+            .as_ref()
+            .map_or(0, |drift| drift.overrides.len());
+        if let Some(index) = self.view.parameters.table_state.selected() {
+            if index + 1 < rows {
+                self.view.parameters.table_state.select(Some(index + 1));
+            }
+        }
+    }
+
+    pub fn parameters_previous(&mut self) {
+        if let Some(index) = self.view.parameters.table_state.selected() {
+            self.view
+                .parameters
+                .table_state
+                .select(Some(index.saturating_sub(1)));
+        }
+    }
+
+    pub fn back_from_parameters(&mut self) {
+        self.view.state = AppState::MetricsSummary;
+        self.view.parameters = ParameterPage::default();
+    }
+
+    // ================================
+    // 41. RESOURCE GROUPS
+    // ================================
+
+    /// Open the list of the account's resource groups or AppRegistry applications
+    pub async fn enter_resource_groups(&mut self, source: GroupSource) {
+        self.view.state = AppState::ResourceGroups;
+        self.view.resource_groups = ResourceGroupPage {
+            source,
+            ..ResourceGroupPage::default()
+        };
+        self.load_resource_groups().await;
+    }
+
+    /// List the groups, or the members of the group open with their health
+    pub async fn load_resource_groups(&mut self) {
+        let source = self.view.resource_groups.source;
+        let group = self.view.resource_groups.group.clone();
+        let page = &mut self.view.resource_groups;
+        page.notice = None;
+        match group {
+            Some(group) => {
+                let members = match source {
+                    GroupSource::ResourceGroups => {
+                        ResourceGroupsClient::new().await.list_members(&group).await
+                    }
+                    GroupSource::Applications => {
+                        AppRegistryClient::new().await.list_members(&group).await
+                    }
+                };
+                match members {
+                    Ok(members) => {
+                        if members.members.is_empty() {
+                            page.notice = Some(format!("{group} has no members"));
+                        }
+                        page.health = fleet::member_health(&members.members).await;
+                        page.members = members;
+                    }
+                    Err(e) => {
+                        page.members = GroupMembers::default();
+                        page.health.clear();
+                        page.notice = Some(format!("{e:#}"));
+                    }
+                }
+            }
+            None => {
+                let groups = match source {
+                    GroupSource::ResourceGroups => {
+                        ResourceGroupsClient::new().await.list_groups().await
+                    }
+                    GroupSource::Applications => {
+                        AppRegistryClient::new().await.list_applications().await
+                    }
+                };
+                match groups {
+                    Ok(groups) => {
+                        if groups.is_empty() {
+                            page.notice = Some(format!(
+                                "No {} in this account and region",
+                                source.title().to_lowercase()
+                            ));
+                        }
+                        page.groups = groups;
+                    }
+                    Err(e) => {
+                        page.groups.clear();
+                        page.notice = Some(format!("{e:#}"));
+                    }
+                }
+            }
+        }
+        let rows = page.rows();
+        page.table_state.select((rows > 0).then_some(0));
+        self.mark_refreshed();
+    }
+
+    pub fn resource_groups_next(&mut self) {
+        let rows = self.view.resource_groups.rows();
+        if let Some(index) = self.view.resource_groups.table_state.selected() {
+            if index + 1 < rows {
+                self.view
+                    .resource_groups
+                    .table_state
+                    .select(Some(index + 1));
+            }
+        }
+    }
+
+    pub fn resource_groups_previous(&mut self) {
+        if let Some(index) = self.view.resource_groups.table_state.selected() {
+            self.view
+                .resource_groups
+                .table_state
+                .select(Some(index.saturating_sub(1)));
+        }
+    }
+
+    /// Open the selected group's members, or the selected member's metrics
+    pub async fn open_resource_group_row(&mut self) -> Result<()> {
+        let page = &self.view.resource_groups;
+        let Some(index) = page.table_state.selected() else {
+            return Ok(());
+        };
+        if page.group.is_none() {
+            let Some(group) = page.groups.get(index).cloned() else {
+                return Ok(());
+            };
+            self.view.resource_groups.group = Some(group);
+            self.load_resource_groups().await;
+            return Ok(());
+        }
+
+        let Some(member) = page.members.members.get(index).cloned() else {
+            return Ok(());
+        };
+        let Some(service) = member.service() else {
+            self.status_message = Some(format!(
+                "{} metrics are not shown here; {} is listed as part of the group",
+                member.kind(),
+                member.id()
+            ));
+            return Ok(());
+        };
+        if !self
+            .open_resource(service, member.id(), self.view.time_range, false)
+            .await?
+        {
+            self.status_message = Some(format!("{} is no longer listed", member.id()));
+        }
+        Ok(())
+    }
+
+    /// From a group's members back to the groups, and from there to the services
+    pub fn back_from_resource_groups(&mut self) {
+        let page = &mut self.view.resource_groups;
+        let Some(group) = page.group.take() else {
+            self.view.state = AppState::ServiceList;
+            return;
+        };
+        page.members = GroupMembers::default();
+        page.health.clear();
+        page.notice = None;
+        let index = page.groups.iter().position(|name| *name == group);
+        page.table_state.select(index);
+    }
+
+    // ================================
+    // 42. COMPOSITE ALARMS
+    // ================================
+
+    /// Open the list of the account's composite alarms
+    pub async fn enter_composite_alarms(&mut self) {
+        self.view.state = AppState::CompositeAlarms;
+        self.view.composite_alarms = CompositeAlarmPage::default();
+        self.load_composite_alarms().await;
+    }
+
+    /// Reload the composites and their children's states, and the open tree with them
+    pub async fn load_composite_alarms(&mut self) {
+        self.load_alarms().await;
+        let page = &mut self.view.composite_alarms;
+        page.notice = None;
+        match AlarmsManager::new().await.load_composite_alarms().await {
+            Ok(alarms) => {
+                if alarms.is_empty() {
+                    page.notice =
+                        Some("No composite alarms in this account and region".to_string());
+                }
+                page.alarms = alarms;
+            }
+            Err(e) => {
+                page.alarms.clear();
+                page.notice = Some(format!("{e:#}"));
+            }
+        }
+        let selected = page.table_state.selected();
+        self.build_composite_tree();
+        let rows = self.view.composite_alarms.rows();
+        self.view.composite_alarms.table_state.select(
+            selected
+                .filter(|index| *index < rows)
+                .or((rows > 0).then_some(0)),
+        );
+        self.mark_refreshed();
+    }
+
+    /// Lay out the open composite's rule against the states last loaded
+    fn build_composite_tree(&mut self) {
+        let states = self
+            .alarms
+            .iter()
+            .map(|alarm| (alarm.name.clone(), alarm.state.clone()))
+            .collect();
+        let page = &mut self.view.composite_alarms;
+        page.lines.clear();
+        let Some(alarm) = page.open_alarm() else {
+            if page.open.take().is_some() {
+                page.notice = Some("The composite alarm is no longer listed".to_string());
+            }
+            return;
+        };
+        match AlarmStates::new(states, &page.alarms).tree(alarm) {
+            Ok(lines) => page.lines = lines,
+            Err(e) => page.notice = Some(format!("{}: {e}", alarm.rule)),
+        }
+    }
+
+    pub fn composite_alarms_next(&mut self) {
+        let rows = self.view.composite_alarms.rows();
+        if let Some(index) = self.view.composite_alarms.table_state.selected() {
+            if index + 1 < rows {
+                self.view
+                    .composite_alarms
+                    .table_state
+                    .select(Some(index + 1));
+            }
+        }
+    }
+
+    pub fn composite_alarms_previous(&mut self) {
+        if let Some(index) = self.view.composite_alarms.table_state.selected() {
+            self.view
+                .composite_alarms
+                .table_state
+                .select(Some(index.saturating_sub(1)));
+        }
+    }
+
+    /// Open the selected composite's tree, or the metric the selected child alarm watches
+    pub async fn open_composite_row(&mut self) -> Result<()> {
+        let page = &self.view.composite_alarms;
+        let Some(index) = page.table_state.selected() else {
+            return Ok(());
+        };
+        if page.open.is_none() {
+            let Some(alarm) = page.alarms.get(index) else {
+                return Ok(());
+            };
+            self.view.composite_alarms.open = Some(alarm.name.clone());
+            self.build_composite_tree();
+            let rows = self.view.composite_alarms.rows();
+            self.view
+                .composite_alarms
+                .table_state
+                .select((rows > 0).then_some(0));
+            return Ok(());
+        }
+
+        let Some(name) = page.lines.get(index).and_then(|line| line.alarm.clone()) else {
+            return Ok(());
+        };
+        if page.alarms.iter().any(|alarm| alarm.name == name) {
+            self.status_message =
+                Some(format!("{name} is a composite alarm; its rule is below it"));
+            return Ok(());
+        }
+        let Some(alarm) = self.alarms.iter().find(|alarm| alarm.name == name).cloned() else {
+            self.status_message = Some(format!("{name} is not a metric alarm in this region"));
+            return Ok(());
+        };
+        let Some((service, resource)) = alarm.resource() else {
+            self.status_message = Some(format!(
+                "{name} watches {} {}, which is not shown here",
+                alarm.namespace, alarm.metric_name
+            ));
+            return Ok(());
+        };
+        if !self
+            .open_resource(service.clone(), &resource, self.view.time_range, false)
+            .await?
+        {
+            self.status_message = Some(format!("{resource} is no longer listed"));
+        } else if service == AwsService::Rds {
+            self.select_metric(&alarm.metric_name);
+        }
+        Ok(())
+    }
+
+    /// From a composite's tree back to the composites, and from there to the services
+    pub fn back_from_composite_alarms(&mut self) {
+        let page = &mut self.view.composite_alarms;
+        let Some(open) = page.open.take() else {
+            self.view.state = AppState::ServiceList;
+            return;
+        };
+        page.lines.clear();
+        page.notice = None;
+        let index = page.alarms.iter().position(|alarm| alarm.name == open);
+        page.table_state.select(index);
+    }
+
+    // ================================
+    // 43. LAMBDA CONSUMERS
+    // ================================
+
+    /// Open the Lambda functions consuming the queue being viewed, or close them
+    pub async fn toggle_consumers_panel(&mut self) {
+        if self.view.consumers.open {
+            self.view.consumers.open = false;
+            return;
+        }
+        self.view.consumers.open = true;
+        self.load_consumers().await;
+    }
+
+    /// Look up the queue's event source mappings and each consumer's last hour
+    pub async fn load_consumers(&mut self) {
+        self.view.consumers.scroll = 0;
+        let Some(queue) = self.get_selected_sqs_queue() else {
+            self.view.consumers.consumers.clear();
+            self.view.consumers.queue = None;
+            return;
+        };
+        let queue_name = queue.name.clone();
+        let Some(queue_arn) = queue.attribute("QueueArn").map(str::to_string) else {
+            self.status_message = Some(format!("No ARN known for {queue_name}"));
+            return;
+        };
+
+        match lambda::load_consumers(&queue_arn).await {
+            Ok(consumers) => {
+                self.view.consumers.consumers = consumers;
+                self.view.consumers.queue = Some(queue_name);
+            }
+            Err(e) => {
+                self.view.consumers.consumers.clear();
+                self.view.consumers.queue = None;
+                self.status_message = Some(format!("Lambda Error: {e}"));
+            }
+        }
+    }
+
+    /// Latest ApproximateAgeOfOldestMessage of the queue being viewed, in seconds
+    pub fn queue_oldest_age(&self) -> Option<f64> {
+        self.get_selected_sqs_queue()?;
+        let metrics = self.view.service_metrics.as_ref()?;
+        let age = metrics.raw_metrics.get("ApproximateAgeOfOldestMessage")?;
+        age.history.last().copied()
+    }
+
+    pub fn consumers_scroll_down(&mut self) {
+        self.view.consumers.scroll += 1;
+    }
+
+    pub fn consumers_scroll_up(&mut self) {
+        self.view.consumers.scroll = self.view.consumers.scroll.saturating_sub(1);
+    }
+
+    // ================================
+    // 44. ALARM BROWSER
+    // ================================
+
+    /// Open the list of the account's metric alarms, firing ones first
+    pub async fn enter_alarm_browser(&mut self) {
+        self.view.state = AppState::Alarms;
+        self.view.alarm_browser = AlarmBrowserPage::default();
+        self.load_alarm_browser().await;
+    }
+
+    /// The alarms listed on the alarm browser, in the order shown
+    pub fn browsed_alarms(&self) -> Vec<&MetricAlarm> {
+        alarms::browse(&self.alarms, self.view.alarm_browser.namespace.as_deref())
+    }
+
+    /// Reload the alarms, keeping the selection on the same alarm where it is still listed
+    pub async fn load_alarm_browser(&mut self) {
+        let selected = self
+            .view
+            .alarm_browser
+            .table_state
+            .selected()
+            .and_then(|index| {
+                self.browsed_alarms()
+                    .get(index)
+                    .map(|alarm| alarm.name.clone())
+            });
+        self.view.alarm_browser.notice = None;
+        match AlarmsManager::new().await.load_alarms().await {
+            Ok(alarms) => {
+                if alarms.is_empty() {
+                    self.view.alarm_browser.notice =
+                        Some("No metric alarms in this account and region".to_string());
+                }
+                self.alarms = alarms;
+            }
+            Err(e) => self.view.alarm_browser.notice = Some(format!("{e:#}")),
+        }
+        self.select_browsed_alarm(selected.as_deref());
+        self.mark_refreshed();
+    }
+
+    /// Select the named alarm, else keep the row in range
+    fn select_browsed_alarm(&mut self, name: Option<&str>) {
+        let shown = self.browsed_alarms();
+        let index = name
+            .and_then(|name| shown.iter().position(|alarm| alarm.name == name))
+            .or_else(|| {
+                self.view
+                    .alarm_browser
+                    .table_state
+                    .selected()
+                    .map(|index| index.min(shown.len().saturating_sub(1)))
+            })
+            .or(Some(0))
+            .filter(|_| !shown.is_empty());
+        self.view.alarm_browser.table_state.select(index);
+    }
+
+    pub fn alarm_browser_next(&mut self) {
+        let rows = self.browsed_alarms().len();
+        if let Some(index) = self.view.alarm_browser.table_state.selected() {
+            if index + 1 < rows {
+                self.view.alarm_browser.table_state.select(Some(index + 1));
+            }
+        }
+    }
+
+    pub fn alarm_browser_previous(&mut self) {
+        if let Some(index) = self.view.alarm_browser.table_state.selected() {
+            self.view
+                .alarm_browser
+                .table_state
+                .select(Some(index.saturating_sub(1)));
+        }
+    }
+
+    /// List only the next namespace's alarms, or all of them after the last namespace
+    pub fn cycle_alarm_namespace(&mut self) {
+        let namespaces = alarms::namespaces(&self.alarms);
+        let next = match self.view.alarm_browser.namespace.as_deref() {
+            None => namespaces.first(),
+            Some(current) => namespaces
+                .iter()
+                .position(|namespace| *namespace == current)
+                .and_then(|index| namespaces.get(index + 1)),
+        };
+        self.view.alarm_browser.namespace = next.map(|namespace| namespace.to_string());
+        self.view.alarm_browser.table_state.select(None);
+        self.select_browsed_alarm(None);
+    }
+
+    /// Open the chart of the metric the selected alarm watches
+    pub async fn open_browsed_alarm(&mut self) -> Result<()> {
+        let Some(alarm) = self
+            .view
+            .alarm_browser
+            .table_state
+            .selected()
+            .and_then(|index| self.browsed_alarms().get(index).copied().cloned())
+        else {
+            return Ok(());
+        };
+        let Some((service, resource)) = alarm.resource() else {
+            self.status_message = Some(format!(
+                "{} watches {} {}, which is not shown here",
+                alarm.name, alarm.namespace, alarm.metric_name
+            ));
+            return Ok(());
+        };
+        if !self
+            .open_resource(service.clone(), &resource, self.view.time_range, true)
+            .await?
+        {
+            self.status_message = Some(format!("{resource} is no longer listed"));
+        } else if service == AwsService::Rds {
+            self.select_metric(&alarm.metric_name);
+        }
+        Ok(())
+    }
+
+    // ================================
+    // 45. PROFILE SWITCHER
+    // ================================
+
+    /// Open or close the profiles of the shared AWS config files
+    pub fn toggle_profiles_panel(&mut self) {
+        self.profiles_panel = match self.profiles_panel {
+            Some(_) => None,
+            None => Some(ProfilesPanel {
+                profiles: profiles::list_profiles(),
+                selected_index: 0,
+            }),
+        };
+    }
+
+    pub fn profiles_scroll_up(&mut self) {
+        if let Some(panel) = self.profiles_panel.as_mut() {
+            panel.selected_index = panel.selected_index.saturating_sub(1);
+        }
+    }
+
+    pub fn profiles_scroll_down(&mut self) {
+        if let Some(panel) = self.profiles_panel.as_mut() {
+            if panel.selected_index + 1 < panel.profiles.len() {
+                panel.selected_index += 1;
+            }
+        }
+    }
+
+    /// Switch the session to the selected profile without restarting
+    ///
+    /// Every tab goes back to the service list, as the resources and metrics
+    /// they hold were loaded under the old profile; they are listed again when
+    /// opened. Refused while alarms are muted or an incident is recorded, as
+    /// ending either has to reach the account they were started in.
+    pub async fn switch_to_selected_profile(&mut self) {
+        let Some(profile) = self
+            .profiles_panel
+            .as_ref()
+            .and_then(|panel| panel.profiles.get(panel.selected_index))
+            .cloned()
+        else {
+            return;
+        };
+        if self.alarm_maintenance.is_some() {
+            self.status_message =
+                Some("Re-enable the muted alarms before switching profile".to_string());
+            return;
+        }
+        if self.incident.is_some() {
+            self.status_message = Some("End incident mode before switching profile".to_string());
+            return;
+        }
+
+        let account =
+            match AwsSessionManager::switch_profile(&profile.name, self.active_region.as_deref())
+                .await
+            {
+                Ok(account) => account,
+                Err(e) => {
+                    self.status_message = Some(e.to_string());
+                    return;
+                }
+            };
+        self.profiles_panel = None;
+        self.forget_session_data();
+        self.active_account = Some(format!("{} ({account})", profile.name));
+        self.load_alarms().await;
+        self.status_message = Some(format!(
+            "Switched to profile {} - account {account}",
+            profile.name
+        ));
+    }
+
+    /// Drop what was loaded under the session switched away from
+    fn forget_session_data(&mut self) {
+        for tab in &mut self.tabs {
+            *tab = ViewState::default();
+        }
+        self.view = ViewState::default();
+        self.quotas = QuotaCache::default();
+        self.prefetch = Prefetch::default();
+        self.alarms.clear();
+        if !self.marked_instances.is_empty() {
+            self.marked_instances.clear();
+            self.record_journal(JournalEntry::MarksCleared);
+        }
+        self.pending_action = None;
+        self.alarm_form = None;
+        self.sent_test_message = None;
+        self.loaded_chart_image = None;
+        self.error_message = None;
+        // The shared watchlist is read from the new account's Parameter Store
+        self.watchlist_refreshed_at = None;
+    }
+
+    // ================================
+    // 46. QUEUE FOLDERS
+    // ================================
+
+    /// Whether the SQS list is shown as folders
+    pub fn folders_shown(&self) -> bool {
+        self.view.queue_folders.shown && self.view.selected_service == Some(AwsService::Sqs)
+    }
+
+    /// The listed queues' folders and the rows of the tree as it is expanded
+    pub fn folder_tree(&self) -> (Vec<Folder>, Vec<FolderRow>) {
+        let page = &self.view.queue_folders;
+        let folders = folders::group(
+            &self.config.sqs.folders,
+            &self.view.instances,
+            &page.oldest_ages,
+        );
+        let rows = folders::rows(&folders, &page.collapsed);
+        (folders, rows)
+    }
+
+    /// Row under the cursor: the selected folder, else the selected queue or
+    /// its folder when that is collapsed
+    pub fn folder_cursor(&self, folders: &[Folder], rows: &[FolderRow]) -> Option<usize> {
+        let folder_row = |name: &str| {
+            let index = folders.iter().position(|folder| folder.name == name)?;
+            rows.iter().position(|row| *row == FolderRow::Folder(index))
+        };
+        if let Some(name) = &self.view.queue_folders.selected {
+            return folder_row(name).or((!rows.is_empty()).then_some(0));
+        }
+        let queue = self.view.list_state.selected()?;
+        rows.iter()
+            .position(|row| *row == FolderRow::Queue(queue))
+            .or_else(|| {
+                let folder = folders
+                    .iter()
+                    .find(|folder| folder.queues.contains(&queue))?;
+                folder_row(&folder.name)
+            })
+    }
+
+    fn select_folder_row(&mut self, folders: &[Folder], row: FolderRow) {
+        match row {
+            FolderRow::Folder(index) => {
+                self.view.queue_folders.selected = Some(folders[index].name.clone());
+                self.view.list_state.select(None);
+            }
+            FolderRow::Queue(index) => {
+                self.view.queue_folders.selected = None;
+                self.view.list_state.select(Some(index));
+            }
+        }
+    }
+
+    /// Move the cursor a row down or up the tree, wrapping around like the flat list
+    fn move_folder_cursor(&mut self, forward: bool) {
+        let (folders, rows) = self.folder_tree();
+        if rows.is_empty() {
+            return;
+        }
+        let row = match self.folder_cursor(&folders, &rows) {
+            Some(current) if forward => (current + 1) % rows.len(),
+            Some(current) => (current + rows.len() - 1) % rows.len(),
+            None => 0,
+        };
+        self.select_folder_row(&folders, rows[row]);
+    }
+
+    /// Show the SQS list as folders or flat again
+    pub async fn toggle_queue_folders(&mut self) {
+        let page = &mut self.view.queue_folders;
+        page.shown = !page.shown;
+        if !page.shown {
+            // Leave the cursor on the folder's first queue
+            if let Some(name) = page.selected.take() {
+                let (folders, _) = self.folder_tree();
+                let first = folders
+                    .iter()
+                    .find(|folder| folder.name == name)
+                    .and_then(|folder| folder.queues.first().copied());
+                self.view.list_state.select(first);
+            }
+            return;
+        }
+        self.load_queue_ages().await;
+        let (folders, _) = self.folder_tree();
+        self.status_message = Some(format!(
+            "{} queues in {} folders - Enter opens or closes a folder",
+            self.view.instances.len(),
+            folders.len()
+        ));
+    }
+
+    /// Whether the cursor is on a folder rather than a queue
+    pub fn folder_selected(&self) -> bool {
+        self.folders_shown() && self.view.queue_folders.selected.is_some()
+    }
+
+    /// Open or close the folder under the cursor
+    pub fn toggle_selected_folder(&mut self) {
+        let page = &mut self.view.queue_folders;
+        if let Some(name) = &page.selected {
+            if !page.collapsed.remove(name) {
+                page.collapsed.insert(name.clone());
+            }
+        }
+    }
+
+    /// Close the folder under the cursor, or the selected queue's, and put the cursor on it
+    pub fn collapse_folder(&mut self) {
+        let (folders, rows) = self.folder_tree();
+        let Some(FolderRow::Queue(queue) | FolderRow::Folder(queue)) =
+            self.folder_cursor(&folders, &rows).map(|row| rows[row])
+        else {
+            return;
+        };
+        let folder = match self.view.queue_folders.selected.clone() {
+            Some(name) => folders.iter().position(|folder| folder.name == name),
+            None => folders
+                .iter()
+                .position(|folder| folder.queues.contains(&queue)),
+        };
+        if let Some(index) = folder {
+            self.view
+                .queue_folders
+                .collapsed
+                .insert(folders[index].name.clone());
+            self.select_folder_row(&folders, FolderRow::Folder(index));
+        }
+    }
+
+    /// Open the folder under the cursor
+    pub fn expand_folder(&mut self) {
+        let page = &mut self.view.queue_folders;
+        if let Some(name) = &page.selected {
+            page.collapsed.remove(name);
+        }
+    }
+
+    /// Fetch every listed queue's oldest message age for the folder rollups
+    async fn load_queue_ages(&mut self) {
+        let names: Vec<String> = self
+            .view
+            .instances
+            .iter()
+            .filter_map(|instance| match instance {
+                ServiceInstance::Sqs(queue) => Some(queue.name.clone()),
+                _ => None,
+            })
+            .collect();
+        match folders::load_oldest_ages(&names).await {
+            Ok(ages) => self.view.queue_folders.oldest_ages = ages,
+            Err(e) => self.status_message = Some(format!("Folder ages unavailable: {e}")),
+        }
+    }
+
+    // ================================
+    // 47. REGIONS
+    // ================================
+
+    /// Open or close the regions to switch the session to
+    pub fn toggle_regions_panel(&mut self) {
+        self.regions_panel = match self.regions_panel {
+            Some(_) => None,
+            None => Some(RegionsPanel {
+                regions: regions::choices(&self.config.regions),
+                selected_index: 0,
+            }),
+        };
+    }
+
+    pub fn regions_scroll_up(&mut self) {
+        if let Some(panel) = self.regions_panel.as_mut() {
+            panel.selected_index = panel.selected_index.saturating_sub(1);
+        }
+    }
+
+    pub fn regions_scroll_down(&mut self) {
+        if let Some(panel) = self.regions_panel.as_mut() {
+            // One row past the regions for "All regions"
+            if panel.selected_index < panel.regions.len() {
+                panel.selected_index += 1;
+            }
+        }
+    }
+
+    /// Switch the session to the selected region, or list every region at once
+    ///
+    /// Like switching profile, every tab goes back to the service list and
+    /// the switch is refused while alarms are muted or an incident is recorded.
+    pub async fn switch_to_selected_region(&mut self) {
+        let Some(panel) = self.regions_panel.as_ref() else {
+            return;
+        };
+        let region = panel.selected_region().map(str::to_string);
+        if self.alarm_maintenance.is_some() {
+            self.status_message =
+                Some("Re-enable the muted alarms before switching region".to_string());
+            return;
+        }
+        if self.incident.is_some() {
+            self.status_message = Some("End incident mode before switching region".to_string());
+            return;
+        }
+
+        self.regions_panel = None;
+        self.forget_session_data();
+        match region {
+            Some(region) => {
+                AwsSessionManager::switch_region(&region).await;
+                self.all_regions = false;
+                self.status_message = Some(format!("Switched to region {region}"));
+                self.active_region = Some(region);
+                self.load_alarms().await;
+            }
+            None => {
+                self.all_regions = true;
+                self.status_message = Some(format!(
+                    "Listing {} regions at once - opening a resource switches to its region",
+                    regions::choices(&self.config.regions).len()
+                ));
+            }
+        }
+    }
+
+    /// List `service` in every region, each row tagged with its region
+    ///
+    /// Resource history is left alone, as it tracks what one region lists,
+    /// and so are the per-region extras such as blue/green deployments.
+    async fn load_instances_in_all_regions(&mut self, service: &AwsService) -> Result<()> {
+        let regions = regions::choices(&self.config.regions);
+        let listing = regions::list_in_regions(service, &regions).await;
+        self.loading = false;
+        if listing.rows.is_empty() && !listing.failures.is_empty() {
+            self.error_message = Some(format!(
+                "AWS Error: no region listed\n{}",
+                listing.failures.join("\n")
+            ));
+            self.view.instances = Vec::new();
+            self.view.rds_instances = Vec::new();
+            self.view.instance_regions = Vec::new();
+            self.view.list_state.select(None);
+            return Ok(());
+        }
+
+        let sticky = self.sticky_selection();
+        let (instance_regions, instances): (Vec<String>, Vec<ServiceInstance>) =
+            listing.rows.into_iter().unzip();
+        self.view.rds_instances = instances
+            .iter()
+            .filter_map(|instance| match instance {
+                ServiceInstance::Rds(rds) => Some(rds.clone()),
+                _ => None,
+            })
+            .collect();
+        self.view.instances = instances;
+        self.view.instance_regions = instance_regions;
+        self.view.departed.clear();
+        self.apply_watchlist_filter();
+        self.load_alarms().await;
+        self.clear_error();
+        self.mark_refreshed();
+        self.restore_selection(&sticky);
+        if !listing.failures.is_empty() {
+            self.status_message = Some(format!("Not listed in {}", listing.failures.join("; ")));
+        }
+        Ok(())
+    }
+
+    /// Region of a listed resource when the list spans all regions
+    pub fn instance_region(&self, index: usize) -> Option<&str> {
+        self.view.instance_regions.get(index).map(String::as_str)
+    }
+
+    /// Switch the session to the selected resource's region before it is opened
+    ///
+    /// Only the list spanning all regions holds resources outside the
+    /// session's region; the list itself stays across every region.
+    pub async fn follow_selected_region(&mut self) {
+        let Some(region) = self
+            .view
+            .list_state
+            .selected()
+            .and_then(|index| self.instance_region(index))
+            .map(str::to_string)
+        else {
+            return;
+        };
+        if self.active_region.as_deref() == Some(region.as_str()) {
+            return;
+        }
+        AwsSessionManager::switch_region(&region).await;
+        // Quotas and warmed series were loaded in the region switched away from
+        self.quotas = QuotaCache::default();
+        self.prefetch = Prefetch::default();
+        self.active_region = Some(region);
+        self.load_alarms().await;
+    }
+
+    // ================================
+    // 48. LOG TAIL
+    // ================================
+
+    /// Open the log groups of the selected RDS instance or Lambda function
+    ///
+    /// A function has one log group, which is followed right away.
+    pub async fn enter_log_tail(&mut self) {
+        let (prefix, exact) = match self.view.selected_service {
+            Some(AwsService::Rds) => {
+                let Some(instance) = self.get_selected_rds_instance() else {
+                    return;
+                };
+                let prefix = logs::rds_log_group(
+                    &instance.identifier,
+                    instance.attribute("Cluster"),
+                    &instance.engine,
+                    "",
+                );
+                (prefix, false)
+            }
+            Some(AwsService::Lambda) => {
+                let Some(function) = self.get_selected_lambda_function() else {
+                    return;
+                };
+                (logs::lambda_log_group(&function.name), true)
+            }
+            _ => return,
+        };
+
+        self.view.log_tail = LogTailPage::default();
+        self.view.state = AppState::LogTail;
+        match LogsClientManager::new().await.log_groups(&prefix).await {
+            Ok(mut groups) => {
+                if exact {
+                    groups.retain(|group| *group == prefix);
+                }
+                self.withhold_slow_query_log(&mut groups);
+                if groups.is_empty() {
+                    self.view.log_tail.notice = Some(format!(
+                        "No log groups under {prefix} - publish the logs to CloudWatch Logs"
+                    ));
+                }
+                self.view.log_tail.groups = groups;
+            }
+            Err(e) => self.view.log_tail.notice = Some(format!("{e:#}")),
+        }
+        let page = &mut self.view.log_tail;
+        page.group_state
+            .select((!page.groups.is_empty()).then_some(0));
+        if exact && page.groups.len() == 1 {
+            self.start_log_tail().await;
+        }
+    }
+
+    /// Drop the open RDS instance's slow query log from `groups` where the policy disables slow queries
+    fn withhold_slow_query_log(&mut self, groups: &mut Vec<String>) {
+        let Some(instance) = self.get_selected_rds_instance() else {
+            return;
+        };
+        let Some(log_group) = slow_query::log_group(
+            &instance.identifier,
+            instance.attribute("Cluster"),
+            &instance.engine,
+        ) else {
+            return;
+        };
+        let id = instance.identifier.clone();
+        if groups.contains(&log_group) && !self.check_policy(Feature::SlowQueries, &[&id]) {
+            groups.retain(|group| *group != log_group);
+        }
+    }
+
+    /// Follow the log group under the cursor from a few minutes back
+    pub async fn start_log_tail(&mut self) {
+        let page = &mut self.view.log_tail;
+        let Some(group) = page
+            .group_state
+            .selected()
+            .and_then(|index| page.groups.get(index))
+            .cloned()
+        else {
+            return;
+        };
+        page.group = Some(group);
+        self.restart_log_tail().await;
+    }
+
+    /// Read the followed group again from scratch, e.g. with a new filter pattern
+    async fn restart_log_tail(&mut self) {
+        let page = &mut self.view.log_tail;
+        page.buffer = TailBuffer::default();
+        page.scroll = 0;
+        page.paused = false;
+        self.poll_log_tail().await;
+    }
+
+    /// Whether the followed log group is due for its next poll
+    pub fn needs_log_tail_poll(&self) -> bool {
+        let page = &self.view.log_tail;
+        self.view.state == AppState::LogTail
+            && page.group.is_some()
+            && !page.paused
+            && page
+                .polled_at
+                .is_none_or(|polled| polled.elapsed() >= tail::POLL_INTERVAL)
+    }
+
+    /// Append the events logged since the last poll
+    ///
+    /// Lines scrolled back to stay in place as new ones arrive below them.
+    pub async fn poll_log_tail(&mut self) {
+        let page = &mut self.view.log_tail;
+        let Some(group) = page.group.clone() else {
+            return;
+        };
+        page.polled_at = Some(Instant::now());
+        let start_time = page.buffer.poll_from(SystemTime::now());
+        let logs = LogsClientManager::new().await;
+        let events = logs
+            .latest_events(&group, start_time, page.filter.as_deref(), tail::SCROLLBACK)
+            .await;
+        let page = &mut self.view.log_tail;
+        match events {
+            Ok(Some(events)) => {
+                page.notice = None;
+                let appended = page.buffer.push(events);
+                if page.scroll > 0 {
+                    page.scroll = (page.scroll + appended).min(page.buffer.len().saturating_sub(1));
+                }
+            }
+            Ok(None) => page.notice = Some(format!("{group} no longer exists")),
+            Err(e) => page.notice = Some(format!("{e:#}")),
+        }
+    }
+
+    pub fn log_groups_next(&mut self) {
+        let page = &mut self.view.log_tail;
+        if let Some(index) = page.group_state.selected() {
+            if index + 1 < page.groups.len() {
+                page.group_state.select(Some(index + 1));
+            }
+        }
+    }
+
+    pub fn log_groups_previous(&mut self) {
+        let page = &mut self.view.log_tail;
+        if let Some(index) = page.group_state.selected() {
+            page.group_state.select(Some(index.saturating_sub(1)));
+        }
+    }
+
+    /// Scroll `lines` back through older lines, or forward with a negative count
+    pub fn scroll_log_tail(&mut self, lines: isize) {
+        let page = &mut self.view.log_tail;
+        let oldest = page.buffer.len().saturating_sub(1);
+        page.scroll = page.scroll.saturating_add_signed(lines).min(oldest);
+    }
+
+    /// Jump to the newest line and keep following
+    pub fn follow_log_tail(&mut self) {
+        self.view.log_tail.scroll = 0;
+    }
+
+    /// Stop or resume polling; the lines logged while paused are read on resume
+    pub fn toggle_log_tail_pause(&mut self) {
+        let page = &mut self.view.log_tail;
+        page.paused = !page.paused;
+        if !page.paused {
+            page.polled_at = None;
+        }
+    }
+
+    /// Start typing a search, or a filter pattern prefilled with the current one
+    pub fn start_log_input(&mut self, filter: bool) {
+        let page = &mut self.view.log_tail;
+        page.input = Some(if filter {
+            LogInput::Filter(page.filter.clone().unwrap_or_default())
+        } else {
+            LogInput::Search(String::new())
+        });
+    }
+
+    pub fn log_input_char(&mut self, c: char) {
+        if let Some(LogInput::Search(text) | LogInput::Filter(text)) =
+            self.view.log_tail.input.as_mut()
+        {
+            text.push(c);
+        }
+    }
+
+    pub fn log_input_backspace(&mut self) {
+        if let Some(LogInput::Search(text) | LogInput::Filter(text)) =
+            self.view.log_tail.input.as_mut()
+        {
+            text.pop();
+        }
+    }
+
+    pub fn cancel_log_input(&mut self) {
+        self.view.log_tail.input = None;
+    }
+
+    /// Search for the typed text from the bottom line up, or read the tail again with the typed filter
+    ///
+    /// An empty search or filter clears it.
+    pub async fn submit_log_input(&mut self) {
+        let page = &mut self.view.log_tail;
+        match page.input.take() {
+            Some(LogInput::Search(text)) => {
+                page.search = (!text.is_empty()).then_some(text);
+                if page.search.is_some() {
+                    self.find_log_match(true, true);
+                }
+            }
+            Some(LogInput::Filter(text)) => {
+                let text = text.trim().to_string();
+                page.filter = (!text.is_empty()).then_some(text);
+                self.restart_log_tail().await;
+            }
+            None => {}
+        }
+    }
+
+    /// Put the next older (or newer) line matching the search at the bottom of the view
+    pub fn jump_to_log_match(&mut self, older: bool) {
+        self.find_log_match(older, false);
+    }
+
+    /// Like `jump_to_log_match`; `inclusive` also matches the bottom line, for a fresh search
+    fn find_log_match(&mut self, older: bool, inclusive: bool) {
+        let page = &mut self.view.log_tail;
+        let Some(query) = page.search.as_deref() else {
+            return;
+        };
+        let len = page.buffer.len();
+        let bottom = len.saturating_sub(1 + page.scroll);
+        let from = if older && inclusive {
+            bottom + 1
+        } else {
+            bottom
+        };
+        match tail::find_match(page.buffer.events(), query, from, older) {
+            Some(index) => page.scroll = len - 1 - index,
+            None => {
+                self.status_message = Some(format!(
+                    "No {} lines matching \"{query}\"",
+                    if older { "older" } else { "newer" }
+                ))
+            }
+        }
+    }
+
+    /// Back to the log groups, or to the metrics from the groups or a function's only group
+    pub fn back_from_log_tail(&mut self) {
+        let page = &mut self.view.log_tail;
+        if page.group.is_some() && page.groups.len() > 1 {
+            page.group = None;
+            page.buffer = TailBuffer::default();
+            page.filter = None;
+            page.search = None;
+            page.notice = None;
+            return;
+        }
+        self.view.log_tail = LogTailPage::default();
+        self.view.state = AppState::MetricsSummary;
+    }
+
+    // ================================
+    // 49. GLOBAL DATABASES
+    // ================================
+
+    /// Open the Aurora global database the selected instance's cluster belongs to
+    pub async fn enter_global_database(&mut self) {
+        let Some(instance) = self.get_selected_rds_instance() else {
+            return;
+        };
+        let Some(cluster_id) = instance.attribute("Cluster").map(str::to_string) else {
+            self.status_message = Some(format!(
+                "{} is not part of an Aurora cluster",
+                instance.identifier
+            ));
+            return;
+        };
+
+        let databases = match RdsClientManager::new().await.load_global_databases().await {
+            Ok(databases) => databases,
+            Err(e) => {
+                self.status_message = Some(format!("{e:#}"));
+                return;
+            }
+        };
+        let Some(database) = databases
+            .into_iter()
+            .find(|database| database.involves(&cluster_id))
+        else {
+            self.status_message = Some(format!(
+                "{cluster_id} is not part of an Aurora global database"
+            ));
+            return;
+        };
+
+        let has_secondaries = database.secondaries().next().is_some();
+        self.view.global_database.database = Some(database);
+        self.view
+            .global_database
+            .list_state
+            .select(has_secondaries.then_some(0));
+        self.view.state = AppState::GlobalDatabase;
+        self.load_global_lag().await;
+    }
+
+    /// Reload the global database's members, then every secondary's lag
+    ///
+    /// A failed DescribeGlobalClusters leaves the previous members in place.
+    pub async fn refresh_global_database(&mut self) {
+        let Some(identifier) = self
+            .view
+            .global_database
+            .database
+            .as_ref()
+            .map(|database| database.identifier.clone())
+        else {
+            return;
+        };
+        if let Ok(databases) = RdsClientManager::new().await.load_global_databases().await {
+            self.view.global_database.database = databases
+                .into_iter()
+                .find(|database| database.identifier == identifier);
+        }
+        self.load_global_lag().await;
+    }
+
+    async fn load_global_lag(&mut self) {
+        let secondaries: Vec<GlobalMember> = self
+            .view
+            .global_database
+            .database
+            .as_ref()
+            .map(|database| database.secondaries().cloned().collect())
+            .unwrap_or_default();
+
+        self.metrics_loading = true;
+        self.view.global_database.lags =
+            load_secondary_lag(&secondaries, self.view.time_range).await;
+        self.metrics_loading = false;
+
+        let selected = self.view.global_database.list_state.selected();
+        if selected.is_none_or(|index| index >= secondaries.len()) {
+            self.view
+                .global_database
+                .list_state
+                .select((!secondaries.is_empty()).then_some(0));
+        }
+        self.mark_refreshed();
+    }
+
+    /// The selected secondary and its lag, when loaded
+    pub fn get_selected_global_secondary(&self) -> Option<(&GlobalMember, &SecondaryLag)> {
+        let index = self.view.global_database.list_state.selected()?;
+        let secondary = self
+            .view
+            .global_database
+            .database
+            .as_ref()?
+            .secondaries()
+            .nth(index)?;
+        let lag = self
+            .view
+            .global_database
+            .lags
+            .iter()
+            .find(|lag| lag.cluster_arn == secondary.cluster_arn)?;
+        Some((secondary, lag))
+    }
+
+    pub fn global_secondary_next(&mut self) {
+        let count = self.view.global_database.lags.len();
+        if count == 0 {
+            return;
+        }
+        let state = &mut self.view.global_database.list_state;
+        state.select(Some(state.selected().map_or(0, |i| (i + 1) % count)));
+    }
+
+    pub fn global_secondary_previous(&mut self) {
+        let count = self.view.global_database.lags.len();
+        if count == 0 {
+            return;
+        }
+        let state = &mut self.view.global_database.list_state;
+        let i = match state.selected() {
+            Some(0) | None => count - 1,
+            Some(i) => i - 1,
+        };
+        state.select(Some(i));
+    }
+
+    pub fn back_from_global_database(&mut self) {
+        self.view.state = AppState::MetricsSummary;
+        self.view.global_database = GlobalDatabasePage::default();
+    }
+
+    // ================================
+    // 50. METRIC EXPORT
+    // ================================
+
+    /// Per-metric timestamped histories of the open resource, RDS metrics by CloudWatch name
+    pub fn loaded_series(&self) -> Option<Vec<Column>> {
+        if !matches!(
+            self.view.state,
+            AppState::MetricsSummary | AppState::InstanceDetails
+        ) {
+            return None;
+        }
+        match self.view.selected_service.as_ref()? {
+            AwsService::Rds => {
+                let metrics = &self.view.metrics;
+                let columns = metrics
+                    .get_available_metrics()
+                    .iter()
+                    .map(|metric| {
+                        let name = metric.metric_name();
+                        let timestamps = metrics
+                            .series_timestamps
+                            .get(name)
+                            .unwrap_or(&metrics.timestamps);
+                        (
+                            name.to_string(),
+                            timestamped(timestamps, metrics.get_metric_history(metric)),
+                        )
+                    })
+                    .collect();
+                Some(columns)
+            }
+            _ => {
+                let metrics = self.view.service_metrics.as_ref()?;
+                let mut columns: Vec<Column> = metrics
+                    .raw_metrics
+                    .iter()
+                    .map(|(name, value)| {
+                        let timestamps = metrics
+                            .series_timestamps
+                            .get(name)
+                            .unwrap_or(&metrics.timestamps);
+                        (name.clone(), timestamped(timestamps, &value.history))
+                    })
+                    .collect();
+                columns.sort_by(|a, b| a.0.cmp(&b.0));
+                Some(columns)
+            }
+        }
+    }
+
+    /// Ask where to export the open resource's metrics
+    pub fn open_export_prompt(&mut self) {
+        let Some(resource) = self.get_selected_instance_id() else {
+            return;
+        };
+        if self
+            .loaded_series()
+            .is_none_or(|columns| columns.is_empty())
+        {
+            self.status_message = Some(format!("No metrics of {resource} are loaded to export"));
+            return;
+        }
+        self.export_prompt = Some(ExportPrompt::new(&resource));
+    }
+
+    pub fn cancel_export_prompt(&mut self) {
+        self.export_prompt = None;
+    }
+
+    /// Write the loaded series to the typed path, as JSON for a `.json` path and
+    /// CSV otherwise; a failed write keeps the prompt open
+    pub fn submit_export_prompt(&mut self) {
+        let Some(prompt) = &self.export_prompt else {
+            return;
+        };
+        let path = PathBuf::from(prompt.text.trim());
+        if path.as_os_str().is_empty() {
+            self.status_message = Some("Enter a file to export to".to_string());
+            return;
+        }
+        let (Some(resource), Some(columns)) =
+            (self.get_selected_instance_id(), self.loaded_series())
+        else {
+            self.export_prompt = None;
+            return;
+        };
+        let format = SeriesFormat::from_path(&path);
+        match write_series(&path, format, &resource, &columns) {
+            Ok(()) => {
+                self.export_prompt = None;
+                self.status_message = Some(format!(
+                    "Exported {} metrics of {resource} to {}",
+                    columns.len(),
+                    path.display()
+                ));
+            }
+            Err(e) => self.status_message = Some(format!("{e:#}")),
+        }
+    }
+
+    // ================================
+    // 51. CHART TEXT
+    // ================================
+
+    /// Copy the charted metric as text art, its change per interval when that is shown
+    ///
+    /// Services without charts copy the focused row of their summary instead.
+    pub fn copy_chart_as_text(&mut self) {
+        let Some((label, text)) = self.chart_text() else {
+            self.status_message = Some("No chart to copy".to_string());
+            return;
+        };
+        self.status_message = Some(match copy_to_clipboard(&text) {
+            Ok(()) => format!("Copied the {label} chart as text"),
+            Err(e) => format!("Copy failed: {e}"),
+        });
+    }
+
+    /// The focused metric's name and its chart as text art
+    fn chart_text(&self) -> Option<(String, String)> {
+        let ascii = self.config.theme.ascii_enabled();
+        match self.view.selected_service.clone() {
+            None | Some(AwsService::Rds) => {
+                let instance_id = self.get_selected_rds_instance_id()?;
+                let metric = self.detail_chart_metric()?;
+                let history = self.view.metrics.get_metric_history(&metric);
+                let (history, title) = if self.showing_derivative() {
+                    (
+                        per_interval_change(history),
+                        format!(
+                            "{instance_id} {} (change per interval)",
+                            metric.display_name()
+                        ),
+                    )
+                } else {
+                    (
+                        history.clone(),
+                        format!("{instance_id} {}", metric.display_name()),
+                    )
+                };
+                let unit = get_metric_unit(&metric);
+                let text = render_text_chart(
+                    &title,
+                    &self.view.metrics.timestamps,
+                    &history,
+                    |value| format_value(value, unit),
+                    ascii,
+                );
+                Some((metric.display_name().to_string(), text))
+            }
+            Some(service) => {
+                let resource = self.get_selected_instance_id()?;
+                let name = self.focused_metric()?;
+                let metrics = self.view.service_metrics.as_ref()?;
+                let value = metrics.raw_metrics.get(&name)?;
+                let unit = MetricServiceFactory::new()
+                    .get_provider(&service)
+                    .ok()?
+                    .get_metrics_config()
+                    .into_iter()
+                    .find(|definition| definition.name == name)
+                    .and_then(|definition| definition.unit)
+                    .unwrap_or_default();
+                let text = render_text_chart(
+                    &format!("{resource} {name}"),
+                    &metrics.timestamps,
+                    &value.history,
+                    |value| format_value(value, &unit),
+                    ascii,
+                );
+                Some((name, text))
+            }
+        }
+    }
+}
+
+/// What a tab shows: the resource it is on, else the service or the service list
+fn tab_label(view: &ViewState) -> String {
+    let instance = view
+        .selected_instance
+        .and_then(|index| view.instances.get(index))
+        .filter(|_| !matches!(view.state, AppState::ServiceList | AppState::InstanceList));
+    match view.state {
+        AppState::Fleet => return "Fleet".to_string(),
+        AppState::Timings => return "Timings".to_string(),
+        AppState::ApiCalls => return "API calls".to_string(),
+        AppState::Internals => return "Internals".to_string(),
+        AppState::ResourceGroups => return view.resource_groups.source.title().to_string(),
+        AppState::CompositeAlarms => return "Composite alarms".to_string(),
+        AppState::Alarms => return "Alarms".to_string(),
+        _ => {}
+    }
+    match (instance, &view.selected_service) {
+        (Some(instance), _) => instance.as_aws_instance().id().to_string(),
+        (None, Some(service)) => service.short_name().to_string(),
+        (None, None) => "Services".to_string(),
+    }
+}
+
+/// The kind of page a tab shows, under which its loads are timed, e.g. "RDS metrics"
+fn page_name(view: &ViewState) -> String {
+    let service = view
+        .selected_service
+        .as_ref()
+        .map_or("", AwsService::short_name);
+    match view.state {
+        AppState::ServiceList => "Services".to_string(),
+        AppState::InstanceList => format!("{service} list"),
+        AppState::MetricsSummary => format!("{service} metrics"),
+        AppState::InstanceDetails => format!("{service} chart"),
+        AppState::ReplicaTopology => "Replicas".to_string(),
+        AppState::BlueGreen => "Blue/green".to_string(),
+        AppState::GlobalDatabase => "Global DB".to_string(),
+        AppState::SlowQueries => "Slow queries".to_string(),
+        AppState::Parameters => "Parameters".to_string(),
+        AppState::LogTail => "Logs".to_string(),
+        AppState::Fleet => "Fleet".to_string(),
+        AppState::Timings => "Timings".to_string(),
+        AppState::ApiCalls => "API calls".to_string(),
+        AppState::Internals => "Internals".to_string(),
+        AppState::ResourceGroups => view.resource_groups.source.title().to_string(),
+        AppState::CompositeAlarms => "Composite alarms".to_string(),
+        AppState::Alarms => "Alarms".to_string(),
+    }
+}
+
+fn baseline_key(instance_id: &str, metric: &MetricType) -> String {
+    format!("rds/{instance_id}/{}", metric.metric_name())
+}
+
+/// A series' values paired with its timestamps, for export
+fn timestamped(timestamps: &[SystemTime], history: &[f64]) -> Vec<(SystemTime, f64)> {
+    timestamps
+        .iter()
+        .copied()
+        .zip(history.iter().copied())
+        .collect()
+}
+
+/// Rebuild muted-alarm tracking from the journal; the reminder fires on the next check
+fn restore_maintenance(muted: MutedAlarms) -> AlarmMaintenance {
+    let now = Instant::now();
+    let started_at = now.checked_sub(muted.elapsed()).unwrap_or(now);
+    AlarmMaintenance {
+        alarm_names: muted.alarm_names,
+        resources: muted.resources,
+        started_at,
+        reminded_at: started_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aws::logs::slow_query::SlowQuerySort;
+    use crate::aws::metric_builder::DynamicMetricBuilder;
+    use crate::aws::metrics::generator::{Series, ServiceMetricsBuilder};
+    use crate::aws::rds::replicas::{ReplicaLag, ReplicaTopology};
+    use crate::policy::Policy;
+
+    fn queue(name: &str) -> ServiceInstance {
+        ServiceInstance::Sqs(SqsQueue {
+            name: name.to_string(),
+            url: format!("https://sqs.us-east-1.amazonaws.com/123456789012/{name}"),
+            attributes: Vec::new(),
+            error: None,
+        })
+    }
+
+    #[test]
+    fn test_watch_refresh_only_while_the_watched_queue_is_open() {
+        let mut app = App::new(Config::default());
+        app.view.selected_service = Some(AwsService::Sqs);
+        app.view.instances = vec![queue("orders"), queue("payments")];
+        app.view.selected_instance = Some(0);
+        app.sent_test_message = Some(SentTestMessage {
+            queue_name: "orders".to_string(),
+            message_id: "m-1".to_string(),
+            sent_at: Instant::now(),
+        });
+        assert!(app.needs_watch_refresh());
+
+        app.view.selected_instance = Some(1);
+        assert!(!app.needs_watch_refresh());
+    }
+
+    #[test]
+    fn test_open_and_close_tabs() {
+        let mut app = App::new(Config::default());
+        app.close_tab();
+        assert_eq!(app.status_message.as_deref(), Some("Only one tab is open"));
+
+        app.view.selected_service = Some(AwsService::Sqs);
+        app.open_tab();
+        assert_eq!(app.tabs.len(), 2);
+        assert_eq!(app.active_tab, 1);
+        assert_eq!(app.view.selected_service, None);
+        assert_eq!(app.tab_labels(), ["SQS", "Services"]);
+
+        // Closing the last tab shows its left-hand neighbour
+        app.close_tab();
+        assert_eq!(app.tabs.len(), 1);
+        assert_eq!(app.active_tab, 0);
+        assert_eq!(app.view.selected_service, Some(AwsService::Sqs));
+    }
+
+    #[test]
+    fn test_tabs_keep_their_own_view() {
+        let mut app = App::new(Config::default());
+        app.view.selected_service = Some(AwsService::Sqs);
+        app.view.instances = vec![queue("orders")];
+        app.view.selected_instance = Some(0);
+        app.view.state = AppState::MetricsSummary;
+        app.open_tab();
+        app.view.selected_service = Some(AwsService::Lambda);
+        app.view.state = AppState::InstanceList;
+        assert_eq!(app.tab_labels(), ["orders", "Lambda"]);
+
+        app.switch_tab(0);
+        assert_eq!(app.view.state, AppState::MetricsSummary);
+        assert_eq!(app.view.selected_service, Some(AwsService::Sqs));
+        assert_eq!(app.view.instances.len(), 1);
+        assert_eq!(app.status_message.as_deref(), Some("Tab 1/2: orders"));
+
+        app.next_tab();
+        assert_eq!(app.active_tab, 1);
+        assert_eq!(app.view.selected_service, Some(AwsService::Lambda));
+        assert!(app.view.instances.is_empty());
+
+        // Out of range does nothing; previous wraps around
+        app.switch_tab(5);
+        assert_eq!(app.active_tab, 1);
+        app.previous_tab();
+        app.previous_tab();
+        assert_eq!(app.active_tab, 1);
+    }
+
+    #[test]
+    fn test_details_pane_scrolls_within_the_attributes() {
+        let mut app = App::new(Config::default());
+        let ServiceInstance::Sqs(mut orders) = queue("orders") else {
+            unreachable!()
+        };
+        orders.attributes = vec![
+            ("Policy".to_string(), "x".repeat(20)),
+            ("VisibilityTimeout".to_string(), "30".to_string()),
+        ];
+        app.view.instances = vec![ServiceInstance::Sqs(orders)];
+        app.view.selected_instance = Some(0);
+        app.toggle_details_pane();
+        assert!(app.view.details_pane.open);
+
+        // Scrolls by a step while there is more of the value to show
+        app.details_scroll_right();
+        app.details_scroll_right();
+        app.details_scroll_right();
+        assert_eq!(app.view.details_pane.hscroll, 2 * DETAILS_HSCROLL_STEP);
+
+        // Moving to another row starts it from the left; the last row stays selected
+        app.details_scroll_down();
+        app.details_scroll_down();
+        assert_eq!(app.view.details_pane.selected_index, 1);
+        assert_eq!(app.view.details_pane.hscroll, 0);
+    }
+
+    #[test]
+    fn test_replica_and_slow_query_selection() {
+        let mut app = App::new(Config::default());
+        app.view.topology.lags = ["replica-a", "replica-b", "replica-c"]
+            .map(|identifier| ReplicaLag {
+                identifier: identifier.to_string(),
+                current: None,
+                history: Vec::new(),
+                timestamps: Vec::new(),
+            })
+            .into();
+        app.view.topology.list_state.select(Some(0));
+        app.replica_previous();
+        assert_eq!(app.view.topology.list_state.selected(), Some(2));
+        app.replica_next();
+        assert_eq!(app.view.topology.list_state.selected(), Some(0));
+
+        app.view.slow_query.queries = [(1.0, 500), (9.0, 10)]
+            .map(|(duration_secs, rows)| SlowQuery {
+                timestamp: SystemTime::UNIX_EPOCH,
+                duration_secs,
+                rows_examined: Some(rows),
+                statement: "SELECT ?".to_string(),
+            })
+            .into();
+        app.cycle_slow_query_sort();
+        assert_eq!(app.view.slow_query.sort, SlowQuerySort::RowsExamined);
+        assert_eq!(
+            app.get_selected_slow_query().unwrap().rows_examined,
+            Some(500)
+        );
+        app.slow_query_next();
+        app.slow_query_next();
+        assert_eq!(
+            app.get_selected_slow_query().unwrap().rows_examined,
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn test_page_state_stays_with_its_tab() {
+        let mut app = App::new(Config::default());
+        app.view.topology.primary = Some("orders-db".to_string());
+        app.view.details_pane.open = true;
+        app.open_tab();
+        assert_eq!(app.view.topology.primary, None);
+        assert!(!app.view.details_pane.open);
+
+        app.switch_tab(0);
+        assert_eq!(app.view.topology.primary.as_deref(), Some("orders-db"));
+        assert!(app.view.details_pane.open);
+    }
+
+    #[test]
+    fn test_focused_metric_follows_the_selection_for_every_service() {
+        let mut app = App::new(Config::default());
+        let rds = ServiceMetricsBuilder::new(AwsService::Rds)
+            .series("CPUUtilization", Series::diurnal_cpu(10))
+            .series("FreeableMemory", Series::diurnal_cpu(10))
+            .build();
+        app.view.metrics = DynamicMetricBuilder::new()
+            .build_from_service_metrics(rds)
+            .unwrap();
+        app.view.sparkline_grid_selected_index = 1;
+        assert_eq!(app.focused_metric().as_deref(), Some("FreeableMemory"));
+
+        // Other services count the rows their summary shows: data or a failed fetch
+        app.view.selected_service = Some(AwsService::Lambda);
+        app.view.service_metrics = Some(
+            ServiceMetricsBuilder::new(AwsService::Lambda)
+                .series("Duration", Series::spiky_latency(10))
+                .series("Invocations", Series::diurnal_cpu(10))
+                .build(),
+        );
+        app.view
+            .failed_metrics
+            .insert("Throttles".to_string(), "Throttled".to_string());
+        assert_eq!(app.focused_metric().as_deref(), Some("Throttles"));
+        app.view.sparkline_grid_selected_index = 2;
+        assert_eq!(app.focused_metric().as_deref(), Some("Duration"));
+        app.view.sparkline_grid_selected_index = 3;
+        assert_eq!(app.focused_metric(), None);
+    }
+
+    #[test]
+    fn test_denied_environment_never_lists_the_slow_query_log() {
+        let mut app = App::new(Config::default());
+        app.config.policy = Policy::parse(
+            r#"
+[environments.production]
+disable = ["slow_queries"]
+"#,
+        )
+        .unwrap();
+        let instance = RdsInstance {
+            identifier: "orders".to_string(),
+            engine: "mysql".to_string(),
+            status: "available".to_string(),
+            instance_class: "db.r6g.large".to_string(),
+            endpoint: None,
+            port: None,
+            allocated_storage_gib: None,
+            max_allocated_storage_gib: None,
+            pending_modifications: Vec::new(),
+            topology: ReplicaTopology::default(),
+            attributes: vec![("Tag: Environment".to_string(), "production".to_string())],
+            certificate: None,
+        };
+        app.view.selected_service = Some(AwsService::Rds);
+        app.view.instances = vec![ServiceInstance::Rds(instance.clone())];
+        app.view.rds_instances = vec![instance];
+        app.view.selected_instance = Some(0);
+
+        let listed = || {
+            vec![
+                "/aws/rds/instance/orders/error".to_string(),
+                "/aws/rds/instance/orders/slowquery".to_string(),
+            ]
+        };
+        let mut groups = listed();
+        app.withhold_slow_query_log(&mut groups);
+        assert_eq!(groups, vec!["/aws/rds/instance/orders/error".to_string()]);
+        assert!(app.status_message.is_some());
+
+        app.config.policy = Policy::default();
+        let mut groups = listed();
+        app.withhold_slow_query_log(&mut groups);
+        assert_eq!(groups, listed());
+    }
+
+    #[test]
+    fn test_chart_text_for_a_service_without_charts() {
+        let mut app = App::new(Config::default());
+        app.view.selected_service = Some(AwsService::Sqs);
+        app.view.instances = vec![queue("orders")];
+        app.view.list_state.select(Some(0));
+        app.copy_chart_as_text();
+        assert_eq!(app.status_message.as_deref(), Some("No chart to copy"));
+
+        app.view.service_metrics = Some(
+            ServiceMetricsBuilder::new(AwsService::Sqs)
+                .series(
+                    "ApproximateNumberOfMessagesVisible",
+                    Series::diurnal_cpu(10),
+                )
+                .build(),
+        );
+        let (label, text) = app.chart_text().unwrap();
+        assert_eq!(label, "ApproximateNumberOfMessagesVisible");
+        assert!(text.contains("orders ApproximateNumberOfMessagesVisible"));
+    }
 }
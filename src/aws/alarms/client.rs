@@ -0,0 +1,210 @@
+use super::{Comparison, CompositeAlarm, MetricAlarm, NewAlarm, MAX_ALARMS_PER_CALL};
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::metrics::types::StatisticType;
+use crate::aws::session::AwsSessionManager;
+use anyhow::Result;
+use aws_sdk_cloudwatch::types::{AlarmType, ComparisonOperator, Dimension, Statistic};
+use aws_sdk_cloudwatch::Client as CloudWatchClient;
+
+/// CloudWatch alarm operations - listing and toggling alarm actions
+pub struct AlarmsManager {
+    client: CloudWatchClient,
+}
+
+impl AlarmsManager {
+    /// Create a new alarms manager using shared AWS session
+    pub async fn new() -> Self {
+        let client = AwsSessionManager::cloudwatch_client().await;
+        Self { client }
+    }
+
+    /// All metric alarms in the account/region
+    pub async fn load_alarms(&self) -> Result<Vec<MetricAlarm>> {
+        let mut alarms = Vec::new();
+        let mut next_token: Option<String> = None;
+
+        loop {
+            let resp = match self
+                .client
+                .describe_alarms()
+                .alarm_types(AlarmType::MetricAlarm)
+                .set_next_token(next_token.clone())
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "describe CloudWatch alarms",
+                        "CloudWatch DescribeAlarms permissions",
+                    ));
+                }
+            };
+
+            alarms.extend(resp.metric_alarms().iter().map(|alarm| {
+                MetricAlarm {
+                    name: alarm.alarm_name().unwrap_or_default().to_string(),
+                    namespace: alarm.namespace().unwrap_or_default().to_string(),
+                    metric_name: alarm.metric_name().unwrap_or_default().to_string(),
+                    dimensions: alarm
+                        .dimensions()
+                        .iter()
+                        .map(|d| {
+                            (
+                                d.name().unwrap_or_default().to_string(),
+                                d.value().unwrap_or_default().to_string(),
+                            )
+                        })
+                        .collect(),
+                    threshold: alarm.threshold(),
+                    state: alarm
+                        .state_value()
+                        .map(|state| state.as_str().to_string())
+                        .unwrap_or_default(),
+                    reason: alarm.state_reason().map(str::to_string),
+                    actions_enabled: alarm.actions_enabled().unwrap_or(true),
+                }
+            }));
+
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(alarms)
+    }
+
+    /// All composite alarms in the account/region, with their rules
+    pub async fn load_composite_alarms(&self) -> Result<Vec<CompositeAlarm>> {
+        let mut alarms = Vec::new();
+        let mut next_token: Option<String> = None;
+
+        loop {
+            let resp = match self
+                .client
+                .describe_alarms()
+                .alarm_types(AlarmType::CompositeAlarm)
+                .set_next_token(next_token.clone())
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "describe CloudWatch composite alarms",
+                        "CloudWatch DescribeAlarms permissions",
+                    ));
+                }
+            };
+
+            alarms.extend(resp.composite_alarms().iter().map(|alarm| {
+                CompositeAlarm {
+                    name: alarm.alarm_name().unwrap_or_default().to_string(),
+                    rule: alarm.alarm_rule().unwrap_or_default().to_string(),
+                    state: alarm
+                        .state_value()
+                        .map(|state| state.as_str().to_string())
+                        .unwrap_or_default(),
+                    reason: alarm.state_reason().map(str::to_string),
+                }
+            }));
+
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(alarms)
+    }
+
+    /// Enable or disable the actions of the named alarms
+    ///
+    /// This is a write call; callers are responsible for checking the write gate
+    /// before invoking it.
+    pub async fn set_actions_enabled(&self, alarm_names: &[String], enabled: bool) -> Result<()> {
+        for chunk in alarm_names.chunks(MAX_ALARMS_PER_CALL) {
+            let result = if enabled {
+                self.client
+                    .enable_alarm_actions()
+                    .set_alarm_names(Some(chunk.to_vec()))
+                    .send()
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| {
+                        AwsErrorHandler::handle_aws_error(
+                            e,
+                            "enable alarm actions",
+                            "CloudWatch EnableAlarmActions permissions",
+                        )
+                    })
+            } else {
+                self.client
+                    .disable_alarm_actions()
+                    .set_alarm_names(Some(chunk.to_vec()))
+                    .send()
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| {
+                        AwsErrorHandler::handle_aws_error(
+                            e,
+                            "disable alarm actions",
+                            "CloudWatch DisableAlarmActions permissions",
+                        )
+                    })
+            };
+            result?;
+        }
+        Ok(())
+    }
+
+    /// Create (or overwrite) a metric alarm
+    ///
+    /// This is a write call; callers are responsible for checking the write gate
+    /// before invoking it.
+    pub async fn put_metric_alarm(&self, alarm: &NewAlarm) -> Result<()> {
+        let comparison = match alarm.comparison {
+            Comparison::Above => ComparisonOperator::GreaterThanOrEqualToThreshold,
+            Comparison::Below => ComparisonOperator::LessThanOrEqualToThreshold,
+        };
+        let statistic = match alarm.statistic {
+            StatisticType::Average => Statistic::Average,
+            StatisticType::Sum => Statistic::Sum,
+            StatisticType::Maximum => Statistic::Maximum,
+            StatisticType::Minimum => Statistic::Minimum,
+        };
+        let dimension = Dimension::builder()
+            .name(&alarm.dimension.0)
+            .value(&alarm.dimension.1)
+            .build();
+
+        match self
+            .client
+            .put_metric_alarm()
+            .alarm_name(&alarm.name)
+            .alarm_description("Created with awscw")
+            .namespace(&alarm.namespace)
+            .metric_name(&alarm.metric_name)
+            .dimensions(dimension)
+            .statistic(statistic)
+            .comparison_operator(comparison)
+            .threshold(alarm.threshold)
+            .period(alarm.period_secs)
+            .evaluation_periods(alarm.evaluation_periods)
+            .set_alarm_actions(Some(alarm.alarm_actions.clone()))
+            .set_treat_missing_data(alarm.treat_missing_data.clone())
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(AwsErrorHandler::handle_aws_error(
+                e,
+                "create CloudWatch alarm",
+                "CloudWatch PutMetricAlarm permissions",
+            )),
+        }
+    }
+}
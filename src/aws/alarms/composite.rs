@@ -0,0 +1,383 @@
+// Composite alarms and the rule expressions combining their children
+//
+// A composite alarm's rule is an expression over other alarms' states, e.g.
+// `ALARM("orders-cpu") AND (ALARM(orders-latency) OR NOT OK("orders-dlq"))`.
+// Parsed into a tree and evaluated against the children's current states, it
+// shows which leaf conditions hold and which of them drive the composite into
+// ALARM: a leaf drives it when it holds on every branch that makes the rule
+// true. Children may be composite alarms themselves; they are expanded in
+// place, each at most once per path.
+
+use anyhow::{anyhow, bail, Result};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompositeAlarm {
+    pub name: String,
+    pub rule: String,
+    pub state: String,
+    pub reason: Option<String>,
+}
+
+impl CompositeAlarm {
+    pub fn is_firing(&self) -> bool {
+        self.state == "ALARM"
+    }
+}
+
+/// A parsed alarm rule
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rule {
+    State { state: String, alarm: String }, // ALARM(name), OK(name) or INSUFFICIENT_DATA(name)
+    Constant(bool),
+    Not(Box<Rule>),
+    And(Vec<Rule>),
+    Or(Vec<Rule>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String), // Operators, states, constants and unquoted alarm names or ARNs
+    Quoted(String),
+    Open,
+    Close,
+}
+
+fn tokenize(rule: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = rule.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            '"' => {
+                chars.next();
+                let mut quoted = String::new();
+                loop {
+                    match chars.next() {
+                        Some('\\') => quoted.extend(chars.next()),
+                        Some('"') => break,
+                        Some(c) => quoted.push(c),
+                        None => bail!("Unterminated quote in alarm rule"),
+                    }
+                }
+                tokens.push(Token::Quoted(quoted));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' || c == '"' {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent over the tokens; NOT binds tightest, then AND, then OR
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek_word(&self, word: &str) -> bool {
+        matches!(self.tokens.get(self.position), Some(Token::Word(w)) if w.eq_ignore_ascii_case(word))
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn or(&mut self) -> Result<Rule> {
+        let mut terms = vec![self.and()?];
+        while self.peek_word("OR") {
+            self.position += 1;
+            terms.push(self.and()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Rule::Or(terms)
+        })
+    }
+
+    fn and(&mut self) -> Result<Rule> {
+        let mut terms = vec![self.not()?];
+        while self.peek_word("AND") {
+            self.position += 1;
+            terms.push(self.not()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Rule::And(terms)
+        })
+    }
+
+    fn not(&mut self) -> Result<Rule> {
+        if self.peek_word("NOT") {
+            self.position += 1;
+            return Ok(Rule::Not(Box::new(self.not()?)));
+        }
+        self.term()
+    }
+
+    fn term(&mut self) -> Result<Rule> {
+        match self.next() {
+            Some(Token::Open) => {
+                let rule = self.or()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(rule),
+                    _ => Err(anyhow!("Missing ) in alarm rule")),
+                }
+            }
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case("TRUE") => {
+                Ok(Rule::Constant(true))
+            }
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case("FALSE") => {
+                Ok(Rule::Constant(false))
+            }
+            Some(Token::Word(state)) => {
+                let state = state.to_uppercase();
+                if !matches!(state.as_str(), "ALARM" | "OK" | "INSUFFICIENT_DATA") {
+                    bail!("Unexpected {state} in alarm rule");
+                }
+                if self.next() != Some(Token::Open) {
+                    bail!("Expected ( after {state} in alarm rule");
+                }
+                let alarm = match self.next() {
+                    Some(Token::Quoted(name)) | Some(Token::Word(name)) => name,
+                    _ => bail!("Expected an alarm name after {state}("),
+                };
+                if self.next() != Some(Token::Close) {
+                    bail!("Missing ) after {state}({alarm}");
+                }
+                // Children may be given by ARN, arn:aws:cloudwatch:<region>:<account>:alarm:<name>
+                let alarm = match alarm.split_once(":alarm:") {
+                    Some((_, name)) if alarm.starts_with("arn:") => name.to_string(),
+                    _ => alarm,
+                };
+                Ok(Rule::State { state, alarm })
+            }
+            _ => Err(anyhow!("Incomplete alarm rule")),
+        }
+    }
+}
+
+impl Rule {
+    pub fn parse(rule: &str) -> Result<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(rule)?,
+            position: 0,
+        };
+        let parsed = parser.or()?;
+        if parser.position < parser.tokens.len() {
+            bail!("Unexpected text after the end of the alarm rule");
+        }
+        Ok(parsed)
+    }
+
+    /// Whether the rule holds, given each alarm's state; an unknown alarm is in no state
+    pub fn holds(&self, states: &HashMap<String, String>) -> bool {
+        match self {
+            Rule::State { state, alarm } => states.get(alarm) == Some(state),
+            Rule::Constant(value) => *value,
+            Rule::Not(rule) => !rule.holds(states),
+            Rule::And(rules) => rules.iter().all(|rule| rule.holds(states)),
+            Rule::Or(rules) => rules.iter().any(|rule| rule.holds(states)),
+        }
+    }
+}
+
+/// One line of a composite alarm's tree, depth first
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleLine {
+    pub depth: usize,
+    pub label: String,         // e.g. AND, or ALARM(orders-cpu)
+    pub alarm: Option<String>, // Child alarm of a state condition
+    pub state: Option<String>, // The child's current state
+    pub holds: bool,
+    pub driving: bool, // Part of what makes the composite fire
+}
+
+/// Resolves child alarms for `tree`: their states, and the rules of composite ones
+pub struct AlarmStates<'a> {
+    pub states: HashMap<String, String>,
+    pub composites: HashMap<&'a str, &'a CompositeAlarm>,
+}
+
+impl<'a> AlarmStates<'a> {
+    pub fn new(metric_states: HashMap<String, String>, composites: &'a [CompositeAlarm]) -> Self {
+        let mut states = metric_states;
+        states.extend(
+            composites
+                .iter()
+                .map(|alarm| (alarm.name.clone(), alarm.state.clone())),
+        );
+        Self {
+            states,
+            composites: composites
+                .iter()
+                .map(|alarm| (alarm.name.as_str(), alarm))
+                .collect(),
+        }
+    }
+
+    /// The composite's rule as lines, composite children expanded below their condition
+    pub fn tree(&self, alarm: &CompositeAlarm) -> Result<Vec<RuleLine>> {
+        let rule = Rule::parse(&alarm.rule)?;
+        let mut lines = Vec::new();
+        let mut path = vec![alarm.name.clone()];
+        self.push_node(&rule, 0, alarm.is_firing(), true, &mut path, &mut lines);
+        Ok(lines)
+    }
+
+    /// A node drives when its parent does and its value is the one that makes the parent's
+    /// (`wanted`): true below AND and OR, inverted below NOT
+    fn push_node(
+        &self,
+        rule: &Rule,
+        depth: usize,
+        driving_parent: bool,
+        wanted: bool,
+        path: &mut Vec<String>,
+        lines: &mut Vec<RuleLine>,
+    ) {
+        let holds = rule.holds(&self.states);
+        let driving = driving_parent && holds == wanted;
+        let line = |label: String| RuleLine {
+            depth,
+            label,
+            alarm: None,
+            state: None,
+            holds,
+            driving,
+        };
+        match rule {
+            Rule::State { state, alarm } => {
+                lines.push(RuleLine {
+                    alarm: Some(alarm.clone()),
+                    state: self.states.get(alarm).cloned(),
+                    ..line(format!("{state}({alarm})"))
+                });
+                // A nested composite is in its state because of its own rule
+                let nested = self.composites.get(alarm.as_str());
+                if let Some(nested) = nested.filter(|_| !path.contains(alarm)) {
+                    if let Ok(nested_rule) = Rule::parse(&nested.rule) {
+                        // The child is in its state because its rule has the value it has
+                        let wanted = nested_rule.holds(&self.states);
+                        path.push(alarm.clone());
+                        self.push_node(&nested_rule, depth + 1, driving, wanted, path, lines);
+                        path.pop();
+                    }
+                }
+            }
+            Rule::Constant(value) => lines.push(line(value.to_string().to_uppercase())),
+            Rule::Not(inner) => {
+                lines.push(line("NOT".to_string()));
+                self.push_node(inner, depth + 1, driving, !wanted, path, lines);
+            }
+            Rule::And(rules) | Rule::Or(rules) => {
+                let label = if matches!(rule, Rule::And(_)) {
+                    "AND"
+                } else {
+                    "OR"
+                };
+                lines.push(line(label.to_string()));
+                for rule in rules {
+                    self.push_node(rule, depth + 1, driving, wanted, path, lines);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn composite(name: &str, rule: &str, state: &str) -> CompositeAlarm {
+        CompositeAlarm {
+            name: name.to_string(),
+            rule: rule.to_string(),
+            state: state.to_string(),
+            reason: None,
+        }
+    }
+
+    #[test]
+    fn test_tree_marks_the_leaves_driving_the_composite() {
+        let rule = Rule::parse(r#"ALARM("cpu") AND (ALARM(latency) OR NOT OK("dlq"))"#).unwrap();
+        let state = |alarm: &str| Rule::State {
+            state: "ALARM".to_string(),
+            alarm: alarm.to_string(),
+        };
+        assert_eq!(
+            rule,
+            Rule::And(vec![
+                state("cpu"),
+                Rule::Or(vec![
+                    state("latency"),
+                    Rule::Not(Box::new(Rule::State {
+                        state: "OK".to_string(),
+                        alarm: "dlq".to_string(),
+                    })),
+                ]),
+            ])
+        );
+        assert_eq!(
+            Rule::parse("OK(arn:aws:cloudwatch:us-east-1:1:alarm:dlq)").unwrap(),
+            Rule::State {
+                state: "OK".to_string(),
+                alarm: "dlq".to_string(),
+            }
+        );
+        assert!(Rule::parse("ALARM(cpu) AND").is_err());
+        assert!(Rule::parse("ALARM(cpu))").is_err());
+
+        let metric_states = [("cpu", "ALARM"), ("latency", "OK"), ("dlq", "ALARM")]
+            .into_iter()
+            .map(|(name, state)| (name.to_string(), state.to_string()))
+            .collect();
+        let composites = [
+            composite("orders", r#"ALARM("cpu") AND ALARM(backend)"#, "ALARM"),
+            composite("backend", "ALARM(latency) OR NOT OK(dlq)", "ALARM"),
+        ];
+        let states = AlarmStates::new(metric_states, &composites);
+        let lines = states.tree(&composites[0]).unwrap();
+        let driving: Vec<(&str, usize, bool)> = lines
+            .iter()
+            .map(|line| (line.label.as_str(), line.depth, line.driving))
+            .collect();
+        assert_eq!(
+            driving,
+            [
+                ("AND", 0, true),
+                ("ALARM(cpu)", 1, true),
+                ("ALARM(backend)", 1, true),
+                ("OR", 2, true),
+                ("ALARM(latency)", 3, false),
+                ("NOT", 3, true),
+                ("OK(dlq)", 4, true),
+            ]
+        );
+        assert_eq!(lines[6].state.as_deref(), Some("ALARM"));
+    }
+}
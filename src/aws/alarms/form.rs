@@ -0,0 +1,313 @@
+use super::{Comparison, NewAlarm};
+use crate::analytics::backtest::AlertRule;
+use crate::aws::metrics::types::StatisticType;
+use anyhow::{anyhow, Result};
+
+/// Alarm periods offered by the form, in seconds
+pub const PERIODS_SECS: [i32; 4] = [60, 300, 900, 3600];
+const DEFAULT_PERIOD_INDEX: usize = 1;
+
+const DEFAULT_EVALUATION_PERIODS: i32 = 3;
+const MAX_EVALUATION_PERIODS: i32 = 100;
+
+/// Oldest message age the queue preset alarms at when the queue has no dead-letter queue
+const PRESET_OLDEST_AGE_SECS: f64 = 900.0;
+
+/// How the queue preset reads periods without datapoints
+const PRESET_TREAT_MISSING_DATA: &str = "notBreaching";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormField {
+    Threshold,
+    Comparison,
+    Period,
+    EvaluationPeriods,
+    Topic,
+}
+
+impl FormField {
+    pub const ALL: [FormField; 5] = [
+        FormField::Threshold,
+        FormField::Comparison,
+        FormField::Period,
+        FormField::EvaluationPeriods,
+        FormField::Topic,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FormField::Threshold => "Threshold",
+            FormField::Comparison => "Trigger when",
+            FormField::Period => "Period",
+            FormField::EvaluationPeriods => "Evaluation periods",
+            FormField::Topic => "Notify SNS topic",
+        }
+    }
+}
+
+/// Guided form for creating an alarm on the metric being viewed
+#[derive(Debug, Clone)]
+pub struct AlarmForm {
+    pub namespace: String,
+    pub dimension: (String, String),
+    pub metric_name: String,
+    pub statistic: StatisticType,
+    pub threshold: String, // Edited as text, parsed on submit
+    pub comparison: Comparison,
+    pub period_index: usize,
+    pub evaluation_periods: i32,
+    pub topics: Vec<String>,
+    pub topic_index: Option<usize>, // None sends no notification
+    pub treat_missing_data: Option<String>,
+    pub focused: FormField,
+}
+
+impl AlarmForm {
+    /// Form prefilled with the metric's latest value as the threshold
+    pub fn new(
+        namespace: &str,
+        dimension: (&str, &str),
+        metric_name: &str,
+        latest: Option<f64>,
+        topics: Vec<String>,
+    ) -> Self {
+        // Metrics that measure remaining headroom alarm when they drop
+        let comparison = if metric_name.starts_with("Free") || metric_name.ends_with("Balance") {
+            Comparison::Below
+        } else {
+            Comparison::Above
+        };
+        Self {
+            namespace: namespace.to_string(),
+            dimension: (dimension.0.to_string(), dimension.1.to_string()),
+            metric_name: metric_name.to_string(),
+            statistic: StatisticType::Average,
+            threshold: latest.map(format_threshold).unwrap_or_default(),
+            comparison,
+            period_index: DEFAULT_PERIOD_INDEX,
+            evaluation_periods: DEFAULT_EVALUATION_PERIODS,
+            topic_index: if topics.is_empty() { None } else { Some(0) },
+            topics,
+            treat_missing_data: None,
+            focused: FormField::Threshold,
+        }
+    }
+
+    /// The alarm usually created by hand for a queue, left on the topic to notify
+    ///
+    /// A queue with a dead-letter queue alarms as soon as any message lands
+    /// there, as does a dead-letter queue itself. Any other queue alarms when
+    /// its oldest message has waited 15 minutes.
+    pub fn queue_preset(
+        queue: &str,
+        dead_letter_queue: Option<&str>,
+        is_dead_letter_queue: bool,
+        topics: Vec<String>,
+    ) -> Self {
+        let (target, metric_name, threshold) = match dead_letter_queue {
+            Some(dlq) => (dlq, "ApproximateNumberOfMessagesVisible", 1.0),
+            None if is_dead_letter_queue => (queue, "ApproximateNumberOfMessagesVisible", 1.0),
+            None => (
+                queue,
+                "ApproximateAgeOfOldestMessage",
+                PRESET_OLDEST_AGE_SECS,
+            ),
+        };
+        Self {
+            statistic: StatisticType::Maximum,
+            period_index: 0,
+            evaluation_periods: 1,
+            // SQS stops publishing metrics for idle queues, and an empty queue is healthy
+            treat_missing_data: Some(PRESET_TREAT_MISSING_DATA.to_string()),
+            focused: FormField::Topic,
+            ..Self::new(
+                "AWS/SQS",
+                ("QueueName", target),
+                metric_name,
+                Some(threshold),
+                topics,
+            )
+        }
+    }
+
+    pub fn alarm_name(&self) -> String {
+        let suffix = match self.comparison {
+            Comparison::Above => "high",
+            Comparison::Below => "low",
+        };
+        format!("{}-{}-{suffix}", self.dimension.1, self.metric_name)
+    }
+
+    pub fn period_secs(&self) -> i32 {
+        PERIODS_SECS[self.period_index]
+    }
+
+    pub fn topic(&self) -> Option<&str> {
+        self.topic_index
+            .and_then(|index| self.topics.get(index))
+            .map(String::as_str)
+    }
+
+    pub fn next_field(&mut self) {
+        let index = FormField::ALL.iter().position(|f| *f == self.focused);
+        self.focused = FormField::ALL[index.map_or(0, |i| (i + 1) % FormField::ALL.len())];
+    }
+
+    pub fn previous_field(&mut self) {
+        let index = FormField::ALL.iter().position(|f| *f == self.focused);
+        let len = FormField::ALL.len();
+        self.focused = FormField::ALL[index.map_or(0, |i| (i + len - 1) % len)];
+    }
+
+    /// Step the focused choice field forwards or backwards
+    pub fn adjust(&mut self, forward: bool) {
+        match self.focused {
+            FormField::Threshold => {}
+            FormField::Comparison => {
+                self.comparison = match self.comparison {
+                    Comparison::Above => Comparison::Below,
+                    Comparison::Below => Comparison::Above,
+                }
+            }
+            FormField::Period => {
+                let len = PERIODS_SECS.len();
+                self.period_index = if forward {
+                    (self.period_index + 1) % len
+                } else {
+                    (self.period_index + len - 1) % len
+                };
+            }
+            FormField::EvaluationPeriods => {
+                let step = if forward { 1 } else { -1 };
+                self.evaluation_periods =
+                    (self.evaluation_periods + step).clamp(1, MAX_EVALUATION_PERIODS);
+            }
+            FormField::Topic => {
+                // Cycle through "none" followed by each topic
+                let options = self.topics.len() + 1;
+                let current = self.topic_index.map_or(0, |i| i + 1);
+                let next = if forward {
+                    (current + 1) % options
+                } else {
+                    (current + options - 1) % options
+                };
+                self.topic_index = next.checked_sub(1);
+            }
+        }
+    }
+
+    /// Type into the threshold field
+    pub fn input_char(&mut self, c: char) {
+        if self.focused == FormField::Threshold && (c.is_ascii_digit() || c == '.' || c == '-') {
+            self.threshold.push(c);
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        if self.focused == FormField::Threshold {
+            self.threshold.pop();
+        }
+    }
+
+    /// The rule as currently entered, for backtesting; None until the threshold is a number
+    pub fn rule(&self) -> Option<AlertRule> {
+        Some(AlertRule {
+            comparison: self.comparison,
+            threshold: self.threshold.parse().ok()?,
+            period_secs: self.period_secs() as u64,
+            evaluation_periods: self.evaluation_periods as usize,
+            datapoints_to_alarm: self.evaluation_periods as usize,
+        })
+    }
+
+    pub fn to_new_alarm(&self) -> Result<NewAlarm> {
+        let threshold: f64 = self
+            .threshold
+            .parse()
+            .map_err(|_| anyhow!("Threshold must be a number"))?;
+        Ok(NewAlarm {
+            name: self.alarm_name(),
+            namespace: self.namespace.clone(),
+            metric_name: self.metric_name.clone(),
+            dimension: self.dimension.clone(),
+            statistic: self.statistic.clone(),
+            comparison: self.comparison,
+            threshold,
+            period_secs: self.period_secs(),
+            evaluation_periods: self.evaluation_periods,
+            alarm_actions: self.topic().map(str::to_string).into_iter().collect(),
+            treat_missing_data: self.treat_missing_data.clone(),
+        })
+    }
+}
+
+/// Threshold text for a prefilled value, without noise digits
+fn format_threshold(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_form_builds_alarm_from_prefilled_values() {
+        let mut form = AlarmForm::new(
+            "AWS/RDS",
+            ("DBInstanceIdentifier", "orders-db"),
+            "FreeStorageSpace",
+            Some(1234.567),
+            vec!["arn:aws:sns:us-east-1:123456789012:oncall".to_string()],
+        );
+        assert_eq!(form.threshold, "1234.57");
+        assert_eq!(form.comparison, Comparison::Below);
+
+        form.focused = FormField::Topic;
+        form.adjust(true); // past the only topic to "none"
+        assert_eq!(form.topic(), None);
+
+        let alarm = form.to_new_alarm().unwrap();
+        assert_eq!(alarm.name, "orders-db-FreeStorageSpace-low");
+        assert_eq!(alarm.period_secs, 300);
+        assert!(alarm.alarm_actions.is_empty());
+
+        assert_eq!(alarm.treat_missing_data, None);
+
+        form.threshold = "abc".to_string();
+        assert!(form.to_new_alarm().is_err());
+    }
+
+    #[test]
+    fn test_queue_preset_treats_missing_data_as_not_breaching() {
+        // The preset watches the dead-letter queue when there is one
+        let topics = vec!["arn:aws:sns:us-east-1:123456789012:oncall".to_string()];
+        let alarm = AlarmForm::queue_preset("orders", Some("orders-dlq"), false, topics.clone())
+            .to_new_alarm()
+            .unwrap();
+        assert_eq!(
+            alarm.name,
+            "orders-dlq-ApproximateNumberOfMessagesVisible-high"
+        );
+        assert_eq!(
+            (alarm.statistic, alarm.threshold),
+            (StatisticType::Maximum, 1.0)
+        );
+        assert_eq!((alarm.period_secs, alarm.evaluation_periods), (60, 1));
+        assert_eq!(alarm.alarm_actions, topics);
+        // An empty dead-letter queue publishes nothing and must read as OK
+        assert_eq!(alarm.treat_missing_data.as_deref(), Some("notBreaching"));
+
+        let preset = AlarmForm::queue_preset("orders-dlq", None, true, Vec::new());
+        assert_eq!(preset.metric_name, "ApproximateNumberOfMessagesVisible");
+        assert_eq!(preset.treat_missing_data.as_deref(), Some("notBreaching"));
+
+        let preset = AlarmForm::queue_preset("orders", None, false, Vec::new());
+        assert_eq!(preset.metric_name, "ApproximateAgeOfOldestMessage");
+        assert_eq!(preset.threshold, "900");
+        assert_eq!(preset.treat_missing_data.as_deref(), Some("notBreaching"));
+    }
+}
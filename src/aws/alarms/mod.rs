@@ -0,0 +1,249 @@
+// CloudWatch metric alarms attached to the listed resources
+//
+// Alarms are matched to a resource by the dimension CloudWatch uses for it
+// (DBInstanceIdentifier for RDS, QueueName for SQS). Muting an alarm only
+// disables its actions; the alarm keeps evaluating and changing state.
+pub mod client;
+pub mod composite;
+pub mod form;
+
+pub use client::AlarmsManager;
+pub use composite::CompositeAlarm;
+pub use form::AlarmForm;
+
+use crate::aws::eventbridge::rule_id;
+use crate::aws::metrics::types::StatisticType;
+use crate::models::{AwsService, EventRule, ServiceInstance};
+
+/// DisableAlarmActions / EnableAlarmActions accept at most this many names per call
+pub const MAX_ALARMS_PER_CALL: usize = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricAlarm {
+    pub name: String,
+    pub namespace: String,
+    pub metric_name: String,
+    pub dimensions: Vec<(String, String)>,
+    pub threshold: Option<f64>,
+    pub state: String,
+    pub reason: Option<String>, // Why the alarm is in its state, e.g. the datapoints that crossed
+    pub actions_enabled: bool,
+}
+
+impl MetricAlarm {
+    /// Whether the alarm watches a metric of the given resource
+    pub fn targets(&self, instance: &ServiceInstance) -> bool {
+        let (namespace, dimension, value) = resource_dimension(instance);
+        self.namespace == namespace
+            && self
+                .dimensions
+                .iter()
+                .any(|(name, v)| name == dimension && v == value)
+    }
+
+    pub fn is_firing(&self) -> bool {
+        self.state == "ALARM"
+    }
+
+    /// The service and resource ID of the metric the alarm watches, where the TUI shows it
+    pub fn resource(&self) -> Option<(AwsService, String)> {
+        let dimension = |name: &str| {
+            self.dimensions
+                .iter()
+                .find(|(dimension, _)| dimension == name)
+                .map(|(_, value)| value.clone())
+        };
+        match self.namespace.as_str() {
+            "AWS/RDS" => Some((AwsService::Rds, dimension("DBInstanceIdentifier")?)),
+            "AWS/SQS" => Some((AwsService::Sqs, dimension("QueueName")?)),
+            "CloudWatchSynthetics" => Some((AwsService::Synthetics, dimension("CanaryName")?)),
+            "AWS/Lambda" => Some((AwsService::Lambda, dimension("FunctionName")?)),
+            "AWS/Events" => {
+                let bus = dimension("EventBusName");
+                let bus = bus.as_deref().unwrap_or(EventRule::DEFAULT_BUS);
+                Some((
+                    AwsService::EventBridge,
+                    rule_id(bus, &dimension("RuleName")?),
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Namespace, dimension name and dimension value CloudWatch uses for a resource
+pub fn resource_dimension(instance: &ServiceInstance) -> (&'static str, &'static str, &str) {
+    match instance {
+        ServiceInstance::Rds(instance) => ("AWS/RDS", "DBInstanceIdentifier", &instance.identifier),
+        ServiceInstance::Sqs(queue) => ("AWS/SQS", "QueueName", &queue.name),
+        ServiceInstance::Canary(canary) => ("CloudWatchSynthetics", "CanaryName", &canary.name),
+        ServiceInstance::EventRule(rule) => ("AWS/Events", "RuleName", &rule.name),
+        // Batch publishes no metrics itself; alarms watch the primary compute environment's cluster
+        ServiceInstance::BatchQueue(queue) => (
+            "AWS/ECS",
+            "ClusterName",
+            queue
+                .compute_environments
+                .iter()
+                .find_map(|environment| environment.ecs_cluster.as_deref())
+                .unwrap_or(&queue.name),
+        ),
+        ServiceInstance::Function(function) => ("AWS/Lambda", "FunctionName", &function.name),
+    }
+}
+
+/// Direction in which a metric crossing the threshold triggers the alarm
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Above,
+    Below,
+}
+
+impl Comparison {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Comparison::Above => ">=",
+            Comparison::Below => "<=",
+        }
+    }
+}
+
+/// A metric alarm to create with PutMetricAlarm
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewAlarm {
+    pub name: String,
+    pub namespace: String,
+    pub metric_name: String,
+    pub dimension: (String, String),
+    pub statistic: StatisticType,
+    pub comparison: Comparison,
+    pub threshold: f64,
+    pub period_secs: i32,
+    pub evaluation_periods: i32,
+    pub alarm_actions: Vec<String>, // SNS topic ARNs notified on ALARM
+    pub treat_missing_data: Option<String>, // e.g. "notBreaching"; None keeps CloudWatch's "missing"
+}
+
+impl NewAlarm {
+    /// How the alarm will appear in the alarm list once created
+    pub fn to_metric_alarm(&self) -> MetricAlarm {
+        MetricAlarm {
+            name: self.name.clone(),
+            namespace: self.namespace.clone(),
+            metric_name: self.metric_name.clone(),
+            dimensions: vec![self.dimension.clone()],
+            threshold: Some(self.threshold),
+            state: "INSUFFICIENT_DATA".to_string(),
+            reason: None,
+            actions_enabled: true,
+        }
+    }
+}
+
+/// Order alarms are browsed in: firing first, then those without data, then OK
+fn state_rank(state: &str) -> u8 {
+    match state {
+        "ALARM" => 0,
+        "INSUFFICIENT_DATA" => 1,
+        _ => 2,
+    }
+}
+
+/// Namespaces the alarms watch, sorted and each once
+pub fn namespaces(alarms: &[MetricAlarm]) -> Vec<&str> {
+    let mut namespaces: Vec<&str> = alarms
+        .iter()
+        .map(|alarm| alarm.namespace.as_str())
+        .collect();
+    namespaces.sort_unstable();
+    namespaces.dedup();
+    namespaces
+}
+
+/// The alarms watching `namespace`, or all of them; firing first, by name within a state
+pub fn browse<'a>(alarms: &'a [MetricAlarm], namespace: Option<&str>) -> Vec<&'a MetricAlarm> {
+    let mut shown: Vec<&MetricAlarm> = alarms
+        .iter()
+        .filter(|alarm| namespace.is_none_or(|namespace| alarm.namespace == namespace))
+        .collect();
+    shown.sort_by(|a, b| {
+        state_rank(&a.state)
+            .cmp(&state_rank(&b.state))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    shown
+}
+
+/// Alarms whose actions were disabled for planned work, awaiting re-enable
+#[derive(Debug, Clone)]
+pub struct AlarmMaintenance {
+    pub alarm_names: Vec<String>,
+    pub resources: Vec<String>,
+    pub started_at: std::time::Instant,
+    pub reminded_at: std::time::Instant,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SqsQueue;
+
+    #[test]
+    fn test_alarm_matches_resource_by_namespace_and_dimension() {
+        let queue = ServiceInstance::Sqs(SqsQueue {
+            name: "orders".to_string(),
+            url: String::new(),
+            attributes: Vec::new(),
+            error: None,
+        });
+        let alarm = |namespace: &str, value: &str| MetricAlarm {
+            name: "orders-backlog".to_string(),
+            namespace: namespace.to_string(),
+            metric_name: "ApproximateNumberOfMessagesVisible".to_string(),
+            dimensions: vec![("QueueName".to_string(), value.to_string())],
+            threshold: Some(100.0),
+            state: "OK".to_string(),
+            reason: None,
+            actions_enabled: true,
+        };
+
+        assert!(alarm("AWS/SQS", "orders").targets(&queue));
+        assert!(!alarm("AWS/SQS", "orders-dlq").targets(&queue));
+        assert!(!alarm("AWS/RDS", "orders").targets(&queue));
+    }
+
+    #[test]
+    fn test_browse_lists_firing_alarms_first_within_a_namespace() {
+        let alarm = |name: &str, namespace: &str, state: &str| MetricAlarm {
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+            metric_name: String::new(),
+            dimensions: Vec::new(),
+            threshold: None,
+            state: state.to_string(),
+            reason: None,
+            actions_enabled: true,
+        };
+        let alarms = [
+            alarm("orders-backlog", "AWS/SQS", "OK"),
+            alarm("orders-cpu", "AWS/RDS", "ALARM"),
+            alarm("dlq-depth", "AWS/SQS", "INSUFFICIENT_DATA"),
+            alarm("audit-age", "AWS/SQS", "ALARM"),
+        ];
+        assert_eq!(namespaces(&alarms), ["AWS/RDS", "AWS/SQS"]);
+        let names = |namespace| -> Vec<&str> {
+            browse(&alarms, namespace)
+                .iter()
+                .map(|alarm| alarm.name.as_str())
+                .collect()
+        };
+        assert_eq!(
+            names(None),
+            ["audit-age", "orders-cpu", "dlq-depth", "orders-backlog"]
+        );
+        assert_eq!(
+            names(Some("AWS/SQS")),
+            ["audit-age", "dlq-depth", "orders-backlog"]
+        );
+    }
+}
@@ -0,0 +1,169 @@
+// Service Catalog AppRegistry applications and their resources
+//
+// An application (listed under myApplications in the console) gathers the
+// CloudFormation stacks associated with it and the resources tagged with its
+// `awsApplication` tag. AppRegistry keeps a resource group for each of the
+// two, so an application's resources are those groups' members, listed the
+// way a resource group's are.
+//
+// The service has no SDK crate here, so it is called through `JsonApiClient`.
+
+use super::json_api::{escape, JsonApiClient};
+use super::resource_groups::{GroupMembers, ResourceGroupsClient};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// The service as the SDKs name it, for endpoint overrides and the call counts
+const SERVICE_ID: &str = "Service Catalog AppRegistry";
+
+const ENDPOINT_PREFIX: &str = "servicecatalog-appregistry";
+
+/// Name the service signs requests under
+const SIGNING_NAME: &str = "servicecatalog";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListApplicationsResponse {
+    #[serde(default)]
+    applications: Vec<ApplicationSummary>,
+    next_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ApplicationSummary {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct GetApplicationResponse {
+    #[serde(default)]
+    integrations: Integrations,
+}
+
+/// The resource groups AppRegistry keeps for an application
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Integrations {
+    resource_group: Option<IntegratedGroup>, // Associated stacks
+    application_tag_resource_group: Option<IntegratedGroup>, // Resources with the application tag
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntegratedGroup {
+    state: Option<String>,
+    arn: Option<String>,
+    error_message: Option<String>,
+}
+
+impl Integrations {
+    /// ARNs of the groups to list, and why any other could not be used
+    fn groups(self) -> (Vec<String>, Vec<String>) {
+        let mut arns = Vec::new();
+        let mut errors = Vec::new();
+        for group in [self.resource_group, self.application_tag_resource_group]
+            .into_iter()
+            .flatten()
+        {
+            let state = group.state.unwrap_or_default();
+            match group.arn {
+                Some(arn) if !state.ends_with("FAILED") => arns.push(arn),
+                _ => errors.push(match group.error_message {
+                    Some(message) => format!("{state}: {message}"),
+                    None => format!("Resource group {}", state.to_lowercase()),
+                }),
+            }
+        }
+        (arns, errors)
+    }
+}
+
+pub struct AppRegistryClient {
+    api: JsonApiClient,
+}
+
+impl AppRegistryClient {
+    pub async fn new() -> Self {
+        Self {
+            api: JsonApiClient::new(SERVICE_ID, ENDPOINT_PREFIX, SIGNING_NAME).await,
+        }
+    }
+
+    /// Names of the applications in the account and region, in the order the API lists them
+    pub async fn list_applications(&self) -> Result<Vec<String>> {
+        let mut applications = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let path = match &next_token {
+                Some(token) => format!("/applications?nextToken={}", escape(token)),
+                None => "/applications".to_string(),
+            };
+            let page: ListApplicationsResponse = self
+                .api
+                .get("ListApplications", &path)
+                .await
+                .context("Failed to list AppRegistry applications")?;
+            applications.extend(page.applications.into_iter().map(|app| app.name));
+            next_token = page.next_token;
+            if next_token.is_none() {
+                break;
+            }
+        }
+        Ok(applications)
+    }
+
+    /// Every resource of `application`, from its stacks and its tag, across services
+    pub async fn list_members(&self, application: &str) -> Result<GroupMembers> {
+        let found: GetApplicationResponse = self
+            .api
+            .get(
+                "GetApplication",
+                &format!("/applications/{}", escape(application)),
+            )
+            .await
+            .with_context(|| format!("Failed to look up the application {application}"))?;
+        let (groups, errors) = found.integrations.groups();
+
+        let client = ResourceGroupsClient::new().await;
+        let mut members = GroupMembers {
+            query_errors: errors,
+            ..GroupMembers::default()
+        };
+        for group in groups {
+            let listed = client.list_members(&group).await?;
+            members.query_errors.extend(listed.query_errors);
+            // A tagged resource of an associated stack is in both groups
+            for member in listed.members {
+                if !members.members.iter().any(|known| known.arn == member.arn) {
+                    members.members.push(member);
+                }
+            }
+        }
+        members.sort();
+        Ok(members)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integrations_skip_failed_groups() {
+        let found: GetApplicationResponse = serde_json::from_str(
+            r#"{"integrations":{
+                "resourceGroup":{"state":"CREATE_COMPLETE",
+                    "arn":"arn:aws:resource-groups:us-east-1:1:group/AWS_AppRegistry_Application-orders"},
+                "applicationTagResourceGroup":{"state":"CREATE_FAILED",
+                    "errorMessage":"tag:GetResources denied"}}}"#,
+        )
+        .unwrap();
+        let (groups, errors) = found.integrations.groups();
+        assert_eq!(groups.len(), 1);
+        assert!(groups[0].ends_with("Application-orders"));
+        assert_eq!(errors, ["CREATE_FAILED: tag:GetResources denied"]);
+
+        let bare: GetApplicationResponse = serde_json::from_str(r#"{"name":"x"}"#).unwrap();
+        assert_eq!(bare.integrations.groups(), (vec![], vec![]));
+    }
+}
@@ -0,0 +1,261 @@
+use super::{cluster_name_from_arn, JOB_STATUSES, MAX_COUNTED_JOBS};
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::session::AwsSessionManager;
+use crate::models::{BatchJobQueue, ComputeEnvironment};
+use anyhow::Result;
+use aws_sdk_batch::types::{ComputeEnvironmentDetail, JobQueueDetail, JobStatus};
+use aws_sdk_batch::Client as BatchClient;
+use std::collections::HashMap;
+
+/// Batch client operations - job queues, compute environments and job counts
+pub struct BatchClientManager {
+    client: BatchClient,
+}
+
+impl BatchClientManager {
+    /// Create a new Batch client manager using shared AWS session
+    pub async fn new() -> Self {
+        let client = AwsSessionManager::batch_client().await;
+        Self { client }
+    }
+
+    /// Load all job queues in the account/region with their compute environments and job counts
+    pub async fn load_job_queues(&self) -> Result<Vec<BatchJobQueue>> {
+        let details = self.describe_job_queues(None).await?;
+        let environments = self.load_compute_environments().await?;
+
+        let queues = futures::future::join_all(
+            details
+                .iter()
+                .map(|detail| self.queue_from_sdk(detail, &environments)),
+        )
+        .await;
+
+        let mut queues: Vec<BatchJobQueue> = queues.into_iter().collect::<Result<_>>()?;
+        queues.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(queues)
+    }
+
+    /// Reload a single job queue, its compute environments and job counts
+    pub async fn load_job_queue(&self, name: &str) -> Result<BatchJobQueue> {
+        let details = self.describe_job_queues(Some(name)).await?;
+        let detail = details
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("Job queue {name} not found"))?;
+        let environments = self.load_compute_environments().await?;
+        self.queue_from_sdk(detail, &environments).await
+    }
+
+    async fn describe_job_queues(&self, name: Option<&str>) -> Result<Vec<JobQueueDetail>> {
+        let mut queues = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let resp = match self
+                .client
+                .describe_job_queues()
+                .set_job_queues(name.map(|name| vec![name.to_string()]))
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "list Batch job queues",
+                        "Batch DescribeJobQueues permissions",
+                    ));
+                }
+            };
+
+            queues.extend(resp.job_queues().iter().cloned());
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+        Ok(queues)
+    }
+
+    /// Compute environments keyed by ARN, which is how job queues refer to them
+    async fn load_compute_environments(&self) -> Result<HashMap<String, ComputeEnvironment>> {
+        let mut environments = HashMap::new();
+        let mut next_token = None;
+
+        loop {
+            let resp = match self
+                .client
+                .describe_compute_environments()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "list Batch compute environments",
+                        "Batch DescribeComputeEnvironments permissions",
+                    ));
+                }
+            };
+
+            for detail in resp.compute_environments() {
+                if let Some(arn) = detail.compute_environment_arn() {
+                    environments.insert(arn.to_string(), environment_from_sdk(detail));
+                }
+            }
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+        Ok(environments)
+    }
+
+    async fn queue_from_sdk(
+        &self,
+        detail: &JobQueueDetail,
+        environments: &HashMap<String, ComputeEnvironment>,
+    ) -> Result<BatchJobQueue> {
+        let name = detail.job_queue_name().unwrap_or_default().to_string();
+        let counts = futures::future::join_all(
+            JOB_STATUSES
+                .iter()
+                .map(|status| self.count_jobs(&name, status)),
+        )
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?;
+
+        let mut order: Vec<_> = detail.compute_environment_order().iter().collect();
+        order.sort_by_key(|entry| entry.order());
+        let compute_environments = order
+            .iter()
+            .filter_map(|entry| entry.compute_environment())
+            .filter_map(|arn| environments.get(arn).cloned())
+            .collect();
+
+        let state = detail
+            .state()
+            .map(|state| state.as_str().to_string())
+            .unwrap_or_else(|| "UNKNOWN".to_string());
+        let status = detail
+            .status()
+            .map(|status| status.as_str().to_string())
+            .unwrap_or_else(|| "UNKNOWN".to_string());
+        let priority = detail.priority().unwrap_or_default();
+
+        let mut attributes = vec![
+            ("State".to_string(), state.clone()),
+            ("Status".to_string(), status.clone()),
+            ("Priority".to_string(), priority.to_string()),
+        ];
+        for (key, value) in [
+            ("JobQueueArn", detail.job_queue_arn()),
+            ("StatusReason", detail.status_reason()),
+            ("SchedulingPolicyArn", detail.scheduling_policy_arn()),
+        ] {
+            if let Some(value) = value {
+                attributes.push((key.to_string(), value.to_string()));
+            }
+        }
+        for entry in &order {
+            if let (Some(position), Some(arn)) = (entry.order(), entry.compute_environment()) {
+                attributes.push((format!("ComputeEnvironment {position}"), arn.to_string()));
+            }
+        }
+        if let Some(tags) = detail.tags() {
+            attributes.extend(
+                tags.iter()
+                    .map(|(key, value)| (format!("Tag: {key}"), value.clone())),
+            );
+        }
+        attributes.sort();
+
+        Ok(BatchJobQueue {
+            name,
+            state,
+            status,
+            priority,
+            compute_environments,
+            job_counts: JOB_STATUSES
+                .iter()
+                .map(|status| status.to_string())
+                .zip(counts)
+                .collect(),
+            attributes,
+        })
+    }
+
+    /// Jobs in `status` on the queue, paging no further than [`MAX_COUNTED_JOBS`]
+    async fn count_jobs(&self, queue: &str, status: &str) -> Result<usize> {
+        let mut count = 0;
+        let mut next_token = None;
+
+        loop {
+            let resp = match self
+                .client
+                .list_jobs()
+                .job_queue(queue)
+                .job_status(JobStatus::from(status))
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "list Batch jobs",
+                        "Batch ListJobs permissions",
+                    ));
+                }
+            };
+
+            count += resp.job_summary_list().len();
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() || count >= MAX_COUNTED_JOBS {
+                break;
+            }
+        }
+        Ok(count.min(MAX_COUNTED_JOBS))
+    }
+}
+
+fn environment_from_sdk(detail: &ComputeEnvironmentDetail) -> ComputeEnvironment {
+    let resources = detail.compute_resources();
+    let kind = match (
+        detail.r#type(),
+        resources.and_then(|resources| resources.r#type()),
+    ) {
+        (Some(kind), Some(resource_type)) => {
+            format!("{} {}", kind.as_str(), resource_type.as_str())
+        }
+        (Some(kind), None) => kind.as_str().to_string(),
+        _ => "UNKNOWN".to_string(),
+    };
+
+    ComputeEnvironment {
+        name: detail
+            .compute_environment_name()
+            .unwrap_or_default()
+            .to_string(),
+        state: detail
+            .state()
+            .map(|state| state.as_str().to_string())
+            .unwrap_or_else(|| "UNKNOWN".to_string()),
+        status: detail
+            .status()
+            .map(|status| status.as_str().to_string())
+            .unwrap_or_else(|| "UNKNOWN".to_string()),
+        kind,
+        min_vcpus: resources.and_then(|resources| resources.minv_cpus()),
+        desired_vcpus: resources.and_then(|resources| resources.desiredv_cpus()),
+        max_vcpus: resources.and_then(|resources| resources.maxv_cpus()),
+        ecs_cluster: detail
+            .ecs_cluster_arn()
+            .map(|arn| cluster_name_from_arn(arn).to_string()),
+    }
+}
@@ -0,0 +1,52 @@
+// AWS Batch job queues and compute environments
+pub mod client;
+
+pub use client::BatchClientManager;
+
+/// Jobs counted per status before ListJobs paging stops
+///
+/// Busy queues can hold hundreds of thousands of jobs; beyond this the count
+/// is shown as a lower bound instead of paging through all of them.
+pub const MAX_COUNTED_JOBS: usize = 1000;
+
+/// Job statuses in the order a job moves through them
+pub const JOB_STATUSES: [&str; 7] = [
+    "SUBMITTED",
+    "PENDING",
+    "RUNNABLE",
+    "STARTING",
+    "RUNNING",
+    "SUCCEEDED",
+    "FAILED",
+];
+
+/// Job count as shown in the UI, marking counts that hit the paging limit
+pub fn format_job_count(count: usize) -> String {
+    if count >= MAX_COUNTED_JOBS {
+        format!("{MAX_COUNTED_JOBS}+")
+    } else {
+        count.to_string()
+    }
+}
+
+/// ECS cluster name from its ARN (arn:aws:ecs:<region>:<account>:cluster/<name>)
+pub fn cluster_name_from_arn(arn: &str) -> &str {
+    arn.rsplit('/').next().unwrap_or(arn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formats_capped_counts_and_cluster_names() {
+        assert_eq!(format_job_count(42), "42");
+        assert_eq!(format_job_count(MAX_COUNTED_JOBS), "1000+");
+        assert_eq!(
+            cluster_name_from_arn(
+                "arn:aws:ecs:us-east-1:123456789012:cluster/AWSBatch-etl-ce-1a2b3c"
+            ),
+            "AWSBatch-etl-ce-1a2b3c"
+        );
+    }
+}
@@ -0,0 +1,238 @@
+// Fault injection for AWS calls
+//
+// `--chaos` wraps the SDK's HTTP client in a decorator that misbehaves on
+// purpose, so retries, the adaptive limiter, partially loaded pages and error
+// notifications can be exercised and demoed without waiting for AWS to fail:
+//
+// - every call is held back by up to MAX_LATENCY
+// - throttling errors, in each service's own error format, come in bursts
+//   long enough to outlast the SDK's retries now and then
+// - some successful responses are cut short, so they fail to parse
+//
+// Faults come from a generator seeded with the flag's value, so the same seed
+// gives the same faults in the same order of calls. Credential and token
+// endpoints are spared, or the session would not start at all.
+
+use aws_smithy_runtime_api::client::http::{
+    HttpClient, HttpConnector, HttpConnectorFuture, HttpConnectorSettings, SharedHttpClient,
+    SharedHttpConnector,
+};
+use aws_smithy_runtime_api::client::orchestrator::{HttpRequest, HttpResponse};
+use aws_smithy_runtime_api::client::result::ConnectorError;
+use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
+use aws_smithy_runtime_api::http::StatusCode;
+use aws_smithy_types::body::SdkBody;
+use aws_smithy_types::byte_stream::ByteStream;
+use http::Uri;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+const MAX_LATENCY: Duration = Duration::from_millis(1500);
+const THROTTLE_PERCENT: u64 = 8;
+const MAX_THROTTLE_BURST: u64 = 6; // Further calls throttled after the first
+const TRUNCATE_PERCENT: u64 = 5;
+
+const THROTTLE_MESSAGE: &str = "Rate exceeded (injected by --chaos)";
+
+/// Hosts that hand out credentials and tokens rather than data
+const SPARED_HOSTS: [&str; 2] = ["169.254.169.254", "[fd00:ec2::254]"];
+const SPARED_PREFIXES: [&str; 3] = ["sts.", "portal.sso.", "oidc."];
+
+/// The SDK client `inner` with faults injected into its calls
+pub fn wrap(inner: SharedHttpClient, seed: u64) -> SharedHttpClient {
+    SharedHttpClient::new(ChaosClient {
+        inner,
+        faults: Arc::new(Mutex::new(Faults::new(seed))),
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Fault {
+    Throttle,
+    Truncate,
+}
+
+/// What happens to one call
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Plan {
+    latency: Duration,
+    fault: Option<Fault>,
+}
+
+/// Seeded source of plans (SplitMix64, which is plenty for picking faults)
+#[derive(Debug)]
+struct Faults {
+    state: u64,
+    burst: u64, // Calls still to throttle in the current burst
+}
+
+impl Faults {
+    fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            burst: 0,
+        }
+    }
+
+    fn next(&mut self, bound: u64) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        (z ^ (z >> 31)) % bound
+    }
+
+    fn plan(&mut self) -> Plan {
+        let latency = Duration::from_millis(self.next(MAX_LATENCY.as_millis() as u64 + 1));
+        let roll = self.next(100);
+        let fault = if self.burst > 0 {
+            self.burst -= 1;
+            Some(Fault::Throttle)
+        } else if roll < THROTTLE_PERCENT {
+            self.burst = self.next(MAX_THROTTLE_BURST + 1);
+            Some(Fault::Throttle)
+        } else if roll < THROTTLE_PERCENT + TRUNCATE_PERCENT {
+            Some(Fault::Truncate)
+        } else {
+            None
+        };
+        Plan { latency, fault }
+    }
+}
+
+#[derive(Debug)]
+struct ChaosClient {
+    inner: SharedHttpClient,
+    faults: Arc<Mutex<Faults>>,
+}
+
+impl HttpClient for ChaosClient {
+    fn http_connector(
+        &self,
+        settings: &HttpConnectorSettings,
+        components: &RuntimeComponents,
+    ) -> SharedHttpConnector {
+        SharedHttpConnector::new(ChaosConnector {
+            inner: self.inner.http_connector(settings, components),
+            faults: self.faults.clone(),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct ChaosConnector {
+    inner: SharedHttpConnector,
+    faults: Arc<Mutex<Faults>>,
+}
+
+impl HttpConnector for ChaosConnector {
+    fn call(&self, request: HttpRequest) -> HttpConnectorFuture {
+        if spared(request.uri()) {
+            return self.inner.call(request);
+        }
+        let plan = self.faults.lock().unwrap_or_else(|e| e.into_inner()).plan();
+        let query_protocol = request
+            .headers()
+            .get("content-type")
+            .is_some_and(|value| value.starts_with("application/x-www-form-urlencoded"));
+        let inner = self.inner.clone();
+        HttpConnectorFuture::new(async move {
+            tokio::time::sleep(plan.latency).await;
+            match plan.fault {
+                Some(Fault::Throttle) => Ok(throttling_response(query_protocol)),
+                Some(Fault::Truncate) => truncate(inner.call(request).await?).await,
+                None => inner.call(request).await,
+            }
+        })
+    }
+}
+
+fn spared(uri: &str) -> bool {
+    let Some(host) = uri
+        .parse::<Uri>()
+        .ok()
+        .and_then(|uri| uri.host().map(str::to_string))
+    else {
+        return false;
+    };
+    SPARED_HOSTS.contains(&host.as_str())
+        || SPARED_PREFIXES
+            .iter()
+            .any(|prefix| host.starts_with(prefix))
+}
+
+/// A 400 the SDK reads as throttling, whichever protocol the service speaks
+fn throttling_response(query_protocol: bool) -> HttpResponse {
+    let (body, content_type) = if query_protocol {
+        // CloudWatch, RDS, SNS and CloudFormation answer in XML
+        (
+            format!(
+                "<ErrorResponse><Error><Type>Sender</Type><Code>Throttling</Code>\
+                 <Message>{THROTTLE_MESSAGE}</Message></Error></ErrorResponse>"
+            ),
+            "text/xml",
+        )
+    } else {
+        (
+            format!(r#"{{"__type":"ThrottlingException","message":"{THROTTLE_MESSAGE}"}}"#),
+            "application/json",
+        )
+    };
+    let status = StatusCode::try_from(400).expect("400 is a valid status code");
+    let mut response = HttpResponse::new(status, SdkBody::from(body));
+    response.headers_mut().insert("content-type", content_type);
+    // REST services take the error code from this header rather than the body
+    response
+        .headers_mut()
+        .insert("x-amzn-errortype", "ThrottlingException");
+    response
+}
+
+/// The first half of a successful response's body; errors are passed on whole
+async fn truncate(response: HttpResponse) -> Result<HttpResponse, ConnectorError> {
+    if !response.status().is_success() {
+        return Ok(response);
+    }
+    let status = response.status();
+    let mut headers = response.headers().clone();
+    let body = ByteStream::new(response.into_body())
+        .collect()
+        .await
+        .map_err(|e| ConnectorError::io(e.into()))?
+        .into_bytes();
+    let kept = body.slice(..body.len() / 2);
+    headers.insert("content-length", kept.len().to_string());
+    let mut truncated = HttpResponse::new(status, SdkBody::from(kept));
+    *truncated.headers_mut() = headers;
+    Ok(truncated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_faults_repeat_for_a_seed() {
+        let plans = |seed| {
+            let mut faults = Faults::new(seed);
+            (0..500).map(|_| faults.plan()).collect::<Vec<_>>()
+        };
+        let first = plans(1);
+        assert_eq!(first, plans(1));
+        assert_ne!(first, plans(2));
+
+        let count = |fault| {
+            first
+                .iter()
+                .filter(|plan| plan.fault == Some(fault))
+                .count()
+        };
+        assert!(count(Fault::Throttle) > 0 && count(Fault::Truncate) > 0);
+        assert!(first.iter().any(|plan| plan.fault.is_none()));
+        assert!(first.iter().all(|plan| plan.latency <= MAX_LATENCY));
+
+        assert!(spared("https://sts.eu-west-1.amazonaws.com/"));
+        assert!(spared("http://169.254.169.254/latest/api/token"));
+        assert!(!spared("https://monitoring.eu-west-1.amazonaws.com/"));
+    }
+}
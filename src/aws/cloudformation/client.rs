@@ -0,0 +1,102 @@
+use super::{DriftState, StackMembership};
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::session::AwsSessionManager;
+use anyhow::Result;
+use aws_sdk_cloudformation::error::ProvideErrorMetadata;
+use aws_sdk_cloudformation::Client as CloudFormationClient;
+
+/// Error code DescribeStackResources returns for resources outside any stack
+const NOT_IN_A_STACK: &str = "ValidationError";
+
+/// CloudFormation client operations - stack membership and drift lookups
+pub struct CloudFormationClientManager {
+    client: CloudFormationClient,
+}
+
+impl CloudFormationClientManager {
+    /// Create a new CloudFormation client manager using shared AWS session
+    pub async fn new() -> Self {
+        let client = AwsSessionManager::cloudformation_client().await;
+        Self { client }
+    }
+
+    /// Stack the resource with `physical_id` belongs to; None when it is not managed by CloudFormation
+    pub async fn stack_membership(&self, physical_id: &str) -> Result<Option<StackMembership>> {
+        let resp = match self
+            .client
+            .describe_stack_resources()
+            .physical_resource_id(physical_id)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) if e.code() == Some(NOT_IN_A_STACK) => return Ok(None),
+            Err(e) => {
+                return Err(AwsErrorHandler::handle_aws_error(
+                    e,
+                    "fetch CloudFormation stack resources",
+                    "CloudFormation DescribeStackResources permissions",
+                ));
+            }
+        };
+        // The whole stack is returned; pick the resource itself
+        let Some(resource) = resp
+            .stack_resources()
+            .iter()
+            .find(|resource| resource.physical_resource_id() == Some(physical_id))
+        else {
+            return Ok(None);
+        };
+        let stack_name = resource.stack_name().unwrap_or_default().to_string();
+
+        let stacks = match self
+            .client
+            .describe_stacks()
+            .stack_name(&stack_name)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Err(AwsErrorHandler::handle_aws_error(
+                    e,
+                    "fetch CloudFormation stack",
+                    "CloudFormation DescribeStacks permissions",
+                ));
+            }
+        };
+        let stack = stacks.stacks().first();
+
+        Ok(Some(StackMembership {
+            stack_name,
+            stack_status: stack
+                .and_then(|stack| stack.stack_status())
+                .map_or_else(String::new, |status| status.as_str().to_string()),
+            stack_status_reason: stack
+                .and_then(|stack| stack.stack_status_reason())
+                .map(str::to_string),
+            stack_drift: stack
+                .and_then(|stack| stack.drift_information())
+                .map(|drift| DriftState {
+                    status: drift
+                        .stack_drift_status()
+                        .map_or_else(String::new, |status| status.as_str().to_string()),
+                    last_checked: drift.last_check_timestamp().map(|time| time.secs()),
+                }),
+            logical_id: resource
+                .logical_resource_id()
+                .unwrap_or_default()
+                .to_string(),
+            resource_type: resource.resource_type().unwrap_or_default().to_string(),
+            resource_status: resource
+                .resource_status()
+                .map_or_else(String::new, |status| status.as_str().to_string()),
+            resource_drift: resource.drift_information().map(|drift| DriftState {
+                status: drift
+                    .stack_resource_drift_status()
+                    .map_or_else(String::new, |status| status.as_str().to_string()),
+                last_checked: drift.last_check_timestamp().map(|time| time.secs()),
+            }),
+        }))
+    }
+}
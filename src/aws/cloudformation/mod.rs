@@ -0,0 +1,129 @@
+// CloudFormation stack membership and drift of the selected resource
+//
+// A resource changed outside its stack ("someone edited it in the console")
+// is a common root cause found during metric triage. DescribeStackResources
+// finds the stack a resource belongs to by its physical ID; the drift shown
+// is the result of the last drift detection run in CloudFormation.
+pub mod client;
+
+pub use client::CloudFormationClientManager;
+
+use crate::models::ServiceInstance;
+use crate::ui::theme::Severity;
+
+/// Result of the last drift detection on a stack or one of its resources
+#[derive(Debug, Clone, PartialEq)]
+pub struct DriftState {
+    pub status: String,            // IN_SYNC, DRIFTED, NOT_CHECKED, ...
+    pub last_checked: Option<i64>, // Seconds since the Unix epoch
+}
+
+impl DriftState {
+    /// Warning when drifted, ok when in sync; None when never checked
+    pub fn severity(&self) -> Option<Severity> {
+        match self.status.as_str() {
+            "DRIFTED" | "MODIFIED" | "DELETED" => Some(Severity::Warning),
+            "IN_SYNC" => Some(Severity::Ok),
+            _ => None,
+        }
+    }
+}
+
+/// The stack a resource belongs to, with both their statuses and drift
+#[derive(Debug, Clone, PartialEq)]
+pub struct StackMembership {
+    pub stack_name: String,
+    pub stack_status: String,
+    pub stack_status_reason: Option<String>,
+    pub stack_drift: Option<DriftState>,
+    pub logical_id: String,
+    pub resource_type: String,
+    pub resource_status: String,
+    pub resource_drift: Option<DriftState>,
+}
+
+/// Critical for failed or rolled-back stacks, warning while an operation is running
+pub fn stack_status_severity(status: &str) -> Severity {
+    if status.ends_with("_FAILED") || status.contains("ROLLBACK") {
+        Severity::Critical
+    } else if status.ends_with("_IN_PROGRESS") {
+        Severity::Warning
+    } else {
+        Severity::Ok
+    }
+}
+
+/// ID CloudFormation records as the physical resource ID of `instance`
+///
+/// This is what the resource's `Ref` returns: the queue URL for SQS, the job
+/// queue ARN for Batch and `bus|name` for rules on custom event buses.
+pub fn physical_resource_id(instance: &ServiceInstance) -> Option<String> {
+    match instance {
+        ServiceInstance::Rds(instance) => Some(instance.identifier.clone()),
+        ServiceInstance::Sqs(queue) => Some(queue.url.clone()).filter(|url| !url.is_empty()),
+        ServiceInstance::Canary(canary) => Some(canary.name.clone()),
+        ServiceInstance::EventRule(rule) if rule.event_bus == "default" => Some(rule.name.clone()),
+        ServiceInstance::EventRule(rule) => Some(format!("{}|{}", rule.event_bus, rule.name)),
+        ServiceInstance::BatchQueue(queue) => queue
+            .attributes
+            .iter()
+            .find(|(key, _)| key == "JobQueueArn")
+            .map(|(_, arn)| arn.clone()),
+        ServiceInstance::Function(function) => Some(function.name.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AwsService, EventRule};
+
+    #[test]
+    fn test_physical_resource_ids() {
+        let rule = |event_bus: &str| {
+            ServiceInstance::EventRule(EventRule {
+                id: String::new(),
+                name: "nightly-export".to_string(),
+                event_bus: event_bus.to_string(),
+                state: "ENABLED".to_string(),
+                trigger: String::new(),
+                targets: Vec::new(),
+                attributes: Vec::new(),
+            })
+        };
+        assert_eq!(
+            physical_resource_id(&rule("default")).as_deref(),
+            Some("nightly-export")
+        );
+        assert_eq!(
+            physical_resource_id(&rule("orders")).as_deref(),
+            Some("orders|nightly-export")
+        );
+
+        let db = ServiceInstance::departed(&AwsService::Rds, "orders-db");
+        assert_eq!(physical_resource_id(&db).as_deref(), Some("orders-db"));
+        let queue = ServiceInstance::departed(&AwsService::Batch, "jobs");
+        assert_eq!(physical_resource_id(&queue), None);
+    }
+
+    #[test]
+    fn test_status_severities() {
+        assert_eq!(
+            stack_status_severity("UPDATE_ROLLBACK_COMPLETE"),
+            Severity::Critical
+        );
+        assert_eq!(
+            stack_status_severity("UPDATE_IN_PROGRESS"),
+            Severity::Warning
+        );
+        assert_eq!(stack_status_severity("CREATE_COMPLETE"), Severity::Ok);
+
+        let drift = |status: &str| DriftState {
+            status: status.to_string(),
+            last_checked: None,
+        };
+        assert_eq!(drift("MODIFIED").severity(), Some(Severity::Warning));
+        assert_eq!(drift("IN_SYNC").severity(), Some(Severity::Ok));
+        assert_eq!(drift("NOT_CHECKED").severity(), None);
+    }
+}
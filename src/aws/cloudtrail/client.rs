@@ -0,0 +1,86 @@
+use super::{error_code, MAX_TRAIL_EVENTS};
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::session::AwsSessionManager;
+use crate::models::TrailEvent;
+use anyhow::Result;
+use aws_sdk_cloudtrail::primitives::DateTime;
+use aws_sdk_cloudtrail::types::{LookupAttribute, LookupAttributeKey};
+use aws_sdk_cloudtrail::Client as CloudTrailClient;
+use std::time::{Duration, SystemTime};
+
+/// CloudTrail client operations - recent management events per resource
+pub struct CloudTrailClientManager {
+    client: CloudTrailClient,
+}
+
+impl CloudTrailClientManager {
+    /// Create a new CloudTrail client manager using shared AWS session
+    pub async fn new() -> Self {
+        let client = AwsSessionManager::cloudtrail_client().await;
+        Self { client }
+    }
+
+    /// Write API calls recorded against `resource` since `start_time`, newest first
+    ///
+    /// LookupEvents accepts a single lookup attribute, so read-only calls such
+    /// as Describe* are filtered out here rather than in the request.
+    pub async fn lookup_resource_events(
+        &self,
+        resource: &str,
+        start_time: SystemTime,
+    ) -> Result<Vec<TrailEvent>> {
+        let attribute = LookupAttribute::builder()
+            .attribute_key(LookupAttributeKey::ResourceName)
+            .attribute_value(resource)
+            .build()?;
+        let mut events = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let resp = match self
+                .client
+                .lookup_events()
+                .lookup_attributes(attribute.clone())
+                .start_time(DateTime::from(start_time))
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "look up CloudTrail events",
+                        "CloudTrail LookupEvents permissions",
+                    ));
+                }
+            };
+
+            events.extend(
+                resp.events()
+                    .iter()
+                    .filter(|event| event.read_only() != Some("true"))
+                    .map(event_from_sdk),
+            );
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() || events.len() >= MAX_TRAIL_EVENTS {
+                break;
+            }
+        }
+        events.truncate(MAX_TRAIL_EVENTS);
+        Ok(events)
+    }
+}
+
+fn event_from_sdk(event: &aws_sdk_cloudtrail::types::Event) -> TrailEvent {
+    TrailEvent {
+        name: event.event_name().unwrap_or_default().to_string(),
+        time: event.event_time().map(system_time),
+        user: event.username().unwrap_or("-").to_string(),
+        error: event.cloud_trail_event().and_then(error_code),
+    }
+}
+
+fn system_time(ts: &DateTime) -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_secs(ts.secs().max(0) as u64)
+}
@@ -0,0 +1,49 @@
+// CloudTrail management events affecting the selected resource
+pub mod client;
+
+pub use client::CloudTrailClientManager;
+
+use crate::models::ServiceInstance;
+
+/// Events shown per lookup; LookupEvents is limited to two requests a second
+pub const MAX_TRAIL_EVENTS: usize = 100;
+
+/// Name CloudTrail records as the resource of API calls against `instance`
+///
+/// SQS calls are recorded against the queue URL rather than its name.
+pub fn trail_resource_name(instance: &ServiceInstance) -> &str {
+    match instance {
+        ServiceInstance::Rds(instance) => &instance.identifier,
+        ServiceInstance::Sqs(queue) => &queue.url,
+        ServiceInstance::Canary(canary) => &canary.name,
+        ServiceInstance::EventRule(rule) => &rule.name,
+        ServiceInstance::BatchQueue(queue) => &queue.name,
+        ServiceInstance::Function(function) => &function.name,
+    }
+}
+
+/// Error code of a recorded call, from the raw CloudTrail event JSON
+///
+/// Calls that failed (e.g. AccessDenied) are recorded too but changed nothing.
+pub fn error_code(cloud_trail_event: &str) -> Option<String> {
+    serde_json::from_str::<serde_json::Value>(cloud_trail_event)
+        .ok()?
+        .get("errorCode")?
+        .as_str()
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reads_error_codes_from_raw_events() {
+        assert_eq!(
+            error_code(r#"{"eventName":"PurgeQueue","errorCode":"AccessDenied"}"#).as_deref(),
+            Some("AccessDenied")
+        );
+        assert_eq!(error_code(r#"{"eventName":"ModifyDBInstance"}"#), None);
+        assert_eq!(error_code("not json"), None);
+    }
+}
@@ -1,21 +1,48 @@
 use crate::models::MetricData;
 use anyhow::Result;
-use aws_sdk_cloudwatch::Client as CloudWatchClient;
+use std::collections::HashMap;
+use std::sync::Mutex;
 use std::time::SystemTime;
 
 // Import our new modules
 use super::metric_builder::build_metric_data;
-use super::metric_fetcher::fetch_comprehensive_metric;
+use super::metric_fetcher::{
+    custom_instance_metrics, probe_metric_resolution, try_fetch_metric, FailedMetrics, MetricClient,
+};
 use super::metric_types::{AdvancedMetrics, CoreMetrics, MetricFetchParams};
+use super::metrics::types::MetricResolution;
 use super::session::AwsSessionManager;
 use super::time_range::calculate_period_seconds;
 
 // Re-export for backward compatibility
 pub use super::time_range::{TimeRange, TimeUnit};
 
-pub async fn load_metrics(instance_id: &str, time_range: TimeRange) -> Result<MetricData> {
+/// Series kept from the last fetch for metrics that have no new datapoint
+/// yet, keyed by CloudWatch metric name
+pub type ReusedMetrics = HashMap<&'static str, (Vec<f64>, Vec<SystemTime>)>;
+
+/// What the concurrent calls of one load collect: the metrics whose call
+/// failed, and each series' own timestamps by CloudWatch metric name
+#[derive(Default)]
+struct FetchOutcome {
+    failures: Mutex<FailedMetrics>,
+    series_timestamps: Mutex<HashMap<String, Vec<SystemTime>>>,
+}
+
+/// Custom metrics probed for their resolution, at most
+const RESOLUTION_PROBE_METRICS: usize = 3;
+
+/// Fetch every RDS metric except those in `reused`, which are passed through
+///
+/// A metric whose call fails is left without datapoints and listed in the
+/// returned failures, so one throttled call doesn't cost the other metrics.
+pub async fn load_metrics(
+    instance_id: &str,
+    time_range: TimeRange,
+    reused: &ReusedMetrics,
+) -> Result<(MetricData, FailedMetrics)> {
     // Use shared AWS session manager for CloudWatch client
-    let client = AwsSessionManager::cloudwatch_client().await;
+    let client = AwsSessionManager::metric_client().await;
 
     let end_time = SystemTime::now();
     let start_time = end_time - time_range.duration();
@@ -25,9 +52,13 @@ pub async fn load_metrics(instance_id: &str, time_range: TimeRange) -> Result<Me
     // Calculate period based on time range duration and period_days
     let period_seconds = calculate_period_seconds(&time_range);
 
+    let outcome = FetchOutcome::default();
+
     // Fetch core metrics concurrently with error handling
     let core_metrics = fetch_core_metrics(
         &client,
+        reused,
+        &outcome,
         &instance_id_owned,
         start_time,
         end_time,
@@ -37,6 +68,8 @@ pub async fn load_metrics(instance_id: &str, time_range: TimeRange) -> Result<Me
 
     let advanced_metrics = fetch_advanced_metrics(
         &client,
+        reused,
+        &outcome,
         &instance_id_owned,
         start_time,
         end_time,
@@ -44,11 +77,83 @@ pub async fn load_metrics(instance_id: &str, time_range: TimeRange) -> Result<Me
     )
     .await;
 
-    Ok(build_metric_data(core_metrics, advanced_metrics))
+    let mut metrics = build_metric_data(core_metrics, advanced_metrics);
+    metrics.series_timestamps = outcome
+        .series_timestamps
+        .into_inner()
+        .unwrap_or_else(|e| e.into_inner());
+    Ok((
+        metrics,
+        outcome
+            .failures
+            .into_inner()
+            .unwrap_or_else(|e| e.into_inner()),
+    ))
+}
+
+/// Detect whether the instance publishes high-resolution metrics
+///
+/// The AWS/RDS metrics are always stored at one-minute resolution, and
+/// Enhanced Monitoring writes to CloudWatch Logs (RDSOSMetrics) rather than
+/// to CloudWatch metrics. Only custom metrics carrying the instance's
+/// `DBInstanceIdentifier` dimension and published with `StorageResolution=1`
+/// have sub-minute datapoints, so the first few of those are probed.
+pub async fn detect_resolution(instance_id: &str) -> MetricResolution {
+    let client = AwsSessionManager::cloudwatch_client().await;
+
+    for metric in custom_instance_metrics(&client, instance_id)
+        .await
+        .iter()
+        .take(RESOLUTION_PROBE_METRICS)
+    {
+        if probe_metric_resolution(&client, metric).await == MetricResolution::High {
+            return MetricResolution::High;
+        }
+    }
+    MetricResolution::Standard
+}
+
+async fn fetch_or_reuse(
+    client: &MetricClient,
+    reused: &ReusedMetrics,
+    outcome: &FetchOutcome,
+    params: MetricFetchParams,
+    start_time: SystemTime,
+    end_time: SystemTime,
+    period_seconds: i32,
+) -> (f64, Vec<f64>, Vec<SystemTime>) {
+    let metric_name = params.metric_name.clone();
+    let fetched = match reused.get(metric_name.as_str()) {
+        Some((history, timestamps)) => {
+            let latest = history.last().copied().unwrap_or(0.0);
+            Ok((latest, history.clone(), timestamps.clone()))
+        }
+        None => try_fetch_metric(client, params, start_time, end_time, period_seconds).await,
+    };
+    match fetched {
+        Ok(fetched) => {
+            outcome
+                .series_timestamps
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(metric_name, fetched.2.clone());
+            fetched
+        }
+        Err(reason) => {
+            outcome
+                .failures
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(metric_name, reason);
+            (0.0, Vec::new(), Vec::new())
+        }
+    }
 }
 
 async fn fetch_core_metrics(
-    client: &CloudWatchClient,
+    client: &MetricClient,
+    reused: &ReusedMetrics,
+    outcome: &FetchOutcome,
     instance_id: &str,
     start_time: SystemTime,
     end_time: SystemTime,
@@ -70,8 +175,10 @@ async fn fetch_core_metrics(
         (freeable_memory, memory_hist, _),
         (queue_depth, queue_depth_hist, _),
     ) = tokio::join!(
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "CPUUtilization".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -82,8 +189,10 @@ async fn fetch_core_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "DatabaseConnections".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -94,8 +203,10 @@ async fn fetch_core_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "FreeStorageSpace".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -106,8 +217,10 @@ async fn fetch_core_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "ReadIOPS".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -118,8 +231,10 @@ async fn fetch_core_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "WriteIOPS".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -130,8 +245,10 @@ async fn fetch_core_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "ReadLatency".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -142,8 +259,10 @@ async fn fetch_core_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "WriteLatency".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -154,8 +273,10 @@ async fn fetch_core_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "ReadThroughput".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -166,8 +287,10 @@ async fn fetch_core_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "WriteThroughput".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -178,8 +301,10 @@ async fn fetch_core_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "NetworkReceiveThroughput".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -190,8 +315,10 @@ async fn fetch_core_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "NetworkTransmitThroughput".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -202,8 +329,10 @@ async fn fetch_core_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "SwapUsage".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -214,8 +343,10 @@ async fn fetch_core_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "FreeableMemory".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -226,8 +357,10 @@ async fn fetch_core_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "DiskQueueDepth".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -274,7 +407,9 @@ async fn fetch_core_metrics(
 }
 
 async fn fetch_advanced_metrics(
-    client: &CloudWatchClient,
+    client: &MetricClient,
+    reused: &ReusedMetrics,
+    outcome: &FetchOutcome,
     instance_id: &str,
     start_time: SystemTime,
     end_time: SystemTime,
@@ -295,8 +430,10 @@ async fn fetch_advanced_metrics(
         (checkpoint_lag, checkpoint_lag_hist, _),
         (connection_attempts, connection_attempts_hist, _),
     ) = tokio::join!(
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "BurstBalance".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -307,8 +444,10 @@ async fn fetch_advanced_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "CPUCreditUsage".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -319,8 +458,10 @@ async fn fetch_advanced_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "CPUCreditBalance".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -331,8 +472,10 @@ async fn fetch_advanced_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "BinLogDiskUsage".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -343,8 +486,10 @@ async fn fetch_advanced_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "ReplicaLag".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -355,8 +500,10 @@ async fn fetch_advanced_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "MaximumUsedTransactionIDs".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -367,8 +514,10 @@ async fn fetch_advanced_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "OldestReplicationSlotLag".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -379,8 +528,10 @@ async fn fetch_advanced_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "ReplicationSlotDiskUsage".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -391,8 +542,10 @@ async fn fetch_advanced_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "TransactionLogsDiskUsage".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -403,8 +556,10 @@ async fn fetch_advanced_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "TransactionLogsGeneration".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -415,8 +570,10 @@ async fn fetch_advanced_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "FailedSQLServerAgentJobsCount".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -427,8 +584,10 @@ async fn fetch_advanced_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "CheckpointLag".to_string(),
                 namespace: "AWS/RDS".to_string(),
@@ -439,8 +598,10 @@ async fn fetch_advanced_metrics(
             end_time,
             period_seconds
         ),
-        fetch_comprehensive_metric(
+        fetch_or_reuse(
             client,
+            reused,
+            outcome,
             MetricFetchParams {
                 metric_name: "ConnectionAttempts".to_string(),
                 namespace: "AWS/RDS".to_string(),
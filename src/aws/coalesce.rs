@@ -0,0 +1,134 @@
+// Coalescing of identical in-flight fetches
+//
+// Auto-refresh and a manual refresh can ask for the same series at the same
+// moment. The first caller for a key starts the fetch; anyone asking for the
+// same key before it finishes awaits that fetch instead of issuing their own
+// AWS call, and every caller receives a clone of the one result. Nothing is
+// cached: once the fetch completes the key is free and the next request
+// goes to AWS again.
+
+use futures::future::{BoxFuture, FutureExt, Shared};
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::Mutex;
+
+pub struct Coalescer<K, V: Clone> {
+    in_flight: Mutex<HashMap<K, Shared<BoxFuture<'static, V>>>>,
+}
+
+impl<K, V> Default for Coalescer<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Coalescer<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Run `fetch` for `key`, or join the fetch already running for it
+    ///
+    /// `fetch` is only polled when no fetch for `key` is in flight.
+    pub async fn run<F>(&self, key: K, fetch: F) -> V
+    where
+        F: Future<Output = V> + Send + 'static,
+    {
+        let shared = {
+            let mut in_flight = self.lock();
+            in_flight
+                .entry(key.clone())
+                .or_insert_with(|| fetch.boxed().shared())
+                .clone()
+        };
+
+        let value = shared.clone().await;
+
+        // The first waiter to finish frees the key; a newer fetch that has
+        // already replaced this one is left alone
+        let mut in_flight = self.lock();
+        if in_flight
+            .get(&key)
+            .is_some_and(|current| current.ptr_eq(&shared))
+        {
+            in_flight.remove(&key);
+        }
+        value
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<K, Shared<BoxFuture<'static, V>>>> {
+        self.in_flight.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tokio::sync::oneshot;
+
+    #[tokio::test]
+    async fn test_concurrent_requests_share_one_fetch() {
+        let coalescer = Coalescer::<&str, u32>::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counted = |value: u32, gate: Option<oneshot::Receiver<()>>| {
+            let calls = calls.clone();
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                if let Some(gate) = gate {
+                    let _ = gate.await;
+                }
+                value
+            }
+        };
+
+        // The first fetch is held open until both callers have joined it
+        let (release, gate) = oneshot::channel();
+        let (first, second, _) = tokio::join!(
+            coalescer.run("cpu", counted(1, Some(gate))),
+            coalescer.run("cpu", counted(2, None)),
+            async { release.send(()).unwrap() },
+        );
+        assert_eq!((first, second), (1, 1));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // A different key is fetched separately, and a finished key fetches again
+        let (other, again) = tokio::join!(
+            coalescer.run("iops", counted(3, None)),
+            coalescer.run("cpu", counted(4, None)),
+        );
+        assert_eq!((other, again), (3, 4));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(coalescer.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_survives_its_first_caller_being_dropped() {
+        let coalescer = Coalescer::<&str, u32>::new();
+        let (release, gate) = oneshot::channel::<()>();
+
+        // Start the fetch, then abandon it, as when a refresh is superseded
+        let leader = coalescer.run("cpu", async move {
+            let _ = gate.await;
+            1
+        });
+        assert!(leader.now_or_never().is_none());
+
+        release.send(()).unwrap();
+        let joined = coalescer.run("cpu", async { 2 }).await;
+        assert_eq!(joined, 1);
+        assert!(coalescer.lock().is_empty());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::session::AwsSessionManager;
+use crate::models::{RightsizingOption, RightsizingRecommendation, UtilizationMetric};
+use anyhow::Result;
+use aws_sdk_computeoptimizer::types::{
+    RdsdbInstanceRecommendationOption, RdsdbRecommendation, RdsdbUtilizationMetric,
+};
+use aws_sdk_computeoptimizer::Client as ComputeOptimizerClient;
+
+/// Compute Optimizer client operations - RDS instance right-sizing
+pub struct ComputeOptimizerClientManager {
+    client: ComputeOptimizerClient,
+}
+
+impl ComputeOptimizerClientManager {
+    /// Create a new Compute Optimizer client manager using shared AWS session
+    pub async fn new() -> Self {
+        let client = AwsSessionManager::compute_optimizer_client().await;
+        Self { client }
+    }
+
+    /// Right-sizing recommendation for the RDS instance with `arn`
+    ///
+    /// None when Compute Optimizer has not analysed the instance yet, which
+    /// takes about 30 hours of metrics after the account opts in.
+    pub async fn rds_recommendation(&self, arn: &str) -> Result<Option<RightsizingRecommendation>> {
+        let resp = match self
+            .client
+            .get_rds_database_recommendations()
+            .resource_arns(arn)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Err(AwsErrorHandler::handle_aws_error(
+                    e,
+                    "get Compute Optimizer recommendations",
+                    "Compute Optimizer GetRDSDatabaseRecommendations permissions",
+                ));
+            }
+        };
+
+        Ok(resp
+            .rds_db_recommendations()
+            .first()
+            .map(recommendation_from_sdk))
+    }
+}
+
+fn recommendation_from_sdk(recommendation: &RdsdbRecommendation) -> RightsizingRecommendation {
+    let mut options: Vec<RightsizingOption> = recommendation
+        .instance_recommendation_options()
+        .iter()
+        .map(option_from_sdk)
+        .collect();
+    options.sort_by_key(|option| option.rank);
+
+    RightsizingRecommendation {
+        current_class: recommendation
+            .current_db_instance_class()
+            .unwrap_or_default()
+            .to_string(),
+        finding: recommendation
+            .instance_finding()
+            .map(|finding| finding.as_str().to_string())
+            .unwrap_or_else(|| "Unknown".to_string()),
+        reasons: recommendation
+            .instance_finding_reason_codes()
+            .iter()
+            .map(|reason| reason.as_str().to_string())
+            .collect(),
+        lookback_days: recommendation.lookback_period_in_days(),
+        utilization: recommendation
+            .utilization_metrics()
+            .iter()
+            .map(metric_from_sdk)
+            .collect(),
+        options,
+    }
+}
+
+fn option_from_sdk(option: &RdsdbInstanceRecommendationOption) -> RightsizingOption {
+    RightsizingOption {
+        instance_class: option.db_instance_class().unwrap_or_default().to_string(),
+        rank: option.rank(),
+        performance_risk: option.performance_risk(),
+        projected: option
+            .projected_utilization_metrics()
+            .iter()
+            .map(metric_from_sdk)
+            .collect(),
+        monthly_savings: option
+            .savings_opportunity()
+            .and_then(|savings| savings.estimated_monthly_savings())
+            .map(|savings| {
+                (
+                    savings.value(),
+                    savings
+                        .currency()
+                        .map(|currency| currency.as_str().to_string())
+                        .unwrap_or_default(),
+                )
+            }),
+    }
+}
+
+fn metric_from_sdk(metric: &RdsdbUtilizationMetric) -> UtilizationMetric {
+    UtilizationMetric {
+        name: metric
+            .name()
+            .map(|name| name.as_str().to_string())
+            .unwrap_or_default(),
+        statistic: metric
+            .statistic()
+            .map(|statistic| statistic.as_str().to_string())
+            .unwrap_or_default(),
+        value: metric.value(),
+    }
+}
@@ -0,0 +1,71 @@
+// Compute Optimizer right-sizing recommendations for RDS instances
+pub mod client;
+
+pub use client::ComputeOptimizerClientManager;
+
+/// Readable form of a finding or reason code, e.g. `CPUOverprovisioned` -> "CPU overprovisioned"
+///
+/// Acronyms such as CPU, EBS and DB keep their capitals.
+pub fn humanize_code(code: &str) -> String {
+    let chars: Vec<char> = code.chars().collect();
+    let mut words: Vec<String> = Vec::new();
+    let mut word = String::new();
+    for (index, &c) in chars.iter().enumerate() {
+        let previous = index.checked_sub(1).map(|i| chars[i]);
+        let next = chars.get(index + 1);
+        let starts_word = c.is_uppercase()
+            && previous.is_some_and(|p| {
+                p.is_lowercase() || (p.is_uppercase() && next.is_some_and(|n| n.is_lowercase()))
+            });
+        if starts_word && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+        .iter()
+        .enumerate()
+        .map(|(index, word)| {
+            let acronym = word.len() > 1 && word.chars().all(|c| c.is_uppercase());
+            if acronym || index == 0 {
+                word.clone()
+            } else {
+                word.to_lowercase()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Unit of a Compute Optimizer RDS utilization metric, for formatting its value
+pub fn utilization_unit(metric: &str) -> &'static str {
+    match metric {
+        "CPU" | "Memory" | "EBSVolumeStorageSpaceUtilization" => "Percent",
+        "DatabaseConnections" => "Count",
+        name if name.ends_with("Throughput") => "Bytes/Second",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_humanizes_codes_keeping_acronyms() {
+        assert_eq!(humanize_code("Overprovisioned"), "Overprovisioned");
+        assert_eq!(humanize_code("CPUOverprovisioned"), "CPU overprovisioned");
+        assert_eq!(
+            humanize_code("NewGenerationDBInstanceClassAvailable"),
+            "New generation DB instance class available"
+        );
+        assert_eq!(
+            humanize_code("EBSIOPSOverprovisioned"),
+            "EBSIOPS overprovisioned"
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use super::rule_id;
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::session::AwsSessionManager;
+use crate::models::{EventRule, RuleTarget};
+use anyhow::Result;
+use aws_sdk_eventbridge::Client as EventBridgeClient;
+
+/// EventBridge client operations - rules, their buses and targets
+pub struct EventBridgeClientManager {
+    client: EventBridgeClient,
+}
+
+impl EventBridgeClientManager {
+    /// Create a new EventBridge client manager using shared AWS session
+    pub async fn new() -> Self {
+        let client = AwsSessionManager::eventbridge_client().await;
+        Self { client }
+    }
+
+    /// Load the rules of every event bus in the account/region with their targets
+    pub async fn load_rules(&self) -> Result<Vec<EventRule>> {
+        let buses = self.load_event_buses().await?;
+        let rules = futures::future::join_all(buses.iter().map(|bus| self.load_bus_rules(bus)))
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+
+        let rules = futures::future::join_all(rules.into_iter().flatten().map(|rule| async {
+            let targets = self.load_targets(&rule.event_bus, &rule.name).await?;
+            Ok(rule.with_targets(targets))
+        }))
+        .await;
+
+        let mut rules: Vec<EventRule> = rules.into_iter().collect::<Result<_>>()?;
+        rules.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(rules)
+    }
+
+    async fn load_event_buses(&self) -> Result<Vec<String>> {
+        let mut buses = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let resp = match self
+                .client
+                .list_event_buses()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "list EventBridge event buses",
+                        "EventBridge ListEventBuses permissions",
+                    ));
+                }
+            };
+
+            buses.extend(
+                resp.event_buses()
+                    .iter()
+                    .filter_map(|bus| bus.name().map(str::to_string)),
+            );
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+        Ok(buses)
+    }
+
+    async fn load_bus_rules(&self, bus: &str) -> Result<Vec<EventRule>> {
+        let mut rules = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let resp = match self
+                .client
+                .list_rules()
+                .event_bus_name(bus)
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "list EventBridge rules",
+                        "EventBridge ListRules permissions",
+                    ));
+                }
+            };
+
+            rules.extend(resp.rules().iter().map(|rule| rule_from_sdk(bus, rule)));
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+        Ok(rules)
+    }
+
+    /// Reload one rule's targets, e.g. after a dead-letter queue was configured
+    pub async fn load_targets(&self, bus: &str, rule: &str) -> Result<Vec<RuleTarget>> {
+        let mut targets = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let resp = match self
+                .client
+                .list_targets_by_rule()
+                .rule(rule)
+                .event_bus_name(bus)
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "list EventBridge rule targets",
+                        "EventBridge ListTargetsByRule permissions",
+                    ));
+                }
+            };
+
+            targets.extend(resp.targets().iter().map(|target| {
+                RuleTarget {
+                    id: target.id().to_string(),
+                    arn: target.arn().to_string(),
+                    dead_letter_arn: target
+                        .dead_letter_config()
+                        .and_then(|config| config.arn())
+                        .map(str::to_string),
+                }
+            }));
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+        Ok(targets)
+    }
+}
+
+fn rule_from_sdk(bus: &str, rule: &aws_sdk_eventbridge::types::Rule) -> EventRule {
+    let name = rule.name().unwrap_or_default().to_string();
+    let state = rule
+        .state()
+        .map(|state| state.as_str().to_string())
+        .unwrap_or_else(|| "UNKNOWN".to_string());
+    let trigger = rule
+        .schedule_expression()
+        .or(rule.event_pattern())
+        .unwrap_or_default()
+        .to_string();
+
+    let mut attributes = vec![
+        ("EventBusName".to_string(), bus.to_string()),
+        ("State".to_string(), state.clone()),
+    ];
+    for (key, value) in [
+        ("Arn", rule.arn()),
+        ("Description", rule.description()),
+        ("ScheduleExpression", rule.schedule_expression()),
+        ("EventPattern", rule.event_pattern()),
+        ("ManagedBy", rule.managed_by()),
+    ] {
+        if let Some(value) = value {
+            attributes.push((key.to_string(), value.to_string()));
+        }
+    }
+
+    EventRule {
+        id: rule_id(bus, &name),
+        name,
+        event_bus: bus.to_string(),
+        state,
+        trigger,
+        targets: Vec::new(),
+        attributes,
+    }
+}
@@ -0,0 +1,52 @@
+// EventBridge rules and event buses
+pub mod client;
+
+pub use client::EventBridgeClientManager;
+
+/// Metric id for a rule: the bare name on the default bus, `bus/name` elsewhere
+///
+/// Rule names cannot contain `/`, so the last segment is always the rule even
+/// for partner buses such as `aws.partner/vendor/source`.
+pub fn rule_id(event_bus: &str, name: &str) -> String {
+    if event_bus == crate::models::EventRule::DEFAULT_BUS {
+        name.to_string()
+    } else {
+        format!("{event_bus}/{name}")
+    }
+}
+
+/// Event bus and rule name of a rule id built by [`rule_id`]
+pub fn split_rule_id(id: &str) -> (Option<&str>, &str) {
+    match id.rsplit_once('/') {
+        Some((bus, name)) => (Some(bus), name),
+        None => (None, id),
+    }
+}
+
+/// Resource name at the end of an ARN, e.g. the queue of an SQS dead-letter ARN
+pub fn arn_resource(arn: &str) -> &str {
+    arn.rsplit([':', '/']).next().unwrap_or(arn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_ids_round_trip_across_buses() {
+        assert_eq!(rule_id("default", "nightly"), "nightly");
+        assert_eq!(split_rule_id("nightly"), (None, "nightly"));
+
+        let id = rule_id("aws.partner/vendor/source", "orders");
+        assert_eq!(id, "aws.partner/vendor/source/orders");
+        assert_eq!(
+            split_rule_id(&id),
+            (Some("aws.partner/vendor/source"), "orders")
+        );
+
+        assert_eq!(
+            arn_resource("arn:aws:sqs:us-east-1:123456789012:orders-dlq"),
+            "orders-dlq"
+        );
+    }
+}
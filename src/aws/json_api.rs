@@ -0,0 +1,384 @@
+// Signed JSON calls to services without an SDK crate among the dependencies
+//
+// AWS Resource Groups, Service Catalog AppRegistry and Lambda have no SDK
+// client here, so their operations are called directly: a JSON request to the
+// service's endpoint, signed with SigV4 using the session's credentials and
+// sent through the same connector as the SDK clients, proxy included.
+//
+// What the SDK clients do for their calls is done here too: the endpoint
+// follows the region's partition, dual-stack and any endpoint override, the
+// credentials are cached until shortly before they expire, and calls go
+// through a call limiter, are retried as the session's retry settings say and
+// are counted on the internals page.
+
+use super::limiter::{self, is_throttling};
+use super::session::AwsSessionManager;
+use super::usage;
+use anyhow::{anyhow, Result};
+use aws_config::retry::RetryConfig;
+use aws_config::SdkConfig;
+use aws_sdk_sts::config::{Credentials, ProvideCredentials};
+use aws_sigv4::http_request::{sign, SignableBody, SignableRequest, SigningSettings};
+use aws_sigv4::sign::v4;
+use aws_smithy_runtime_api::client::http::{HttpConnector, SharedHttpConnector};
+use aws_smithy_runtime_api::client::identity::{Identity, IdentityCachePartition, ResolveIdentity};
+use aws_smithy_runtime_api::client::orchestrator::HttpRequest;
+use aws_smithy_types::body::SdkBody;
+use aws_smithy_types::byte_stream::ByteStream;
+use aws_smithy_types::error::metadata::{ErrorMetadata, ProvideErrorMetadata};
+use aws_types::service_config::ServiceConfigKey;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+use tokio::sync::Mutex;
+
+/// Cached credentials are resolved again once they are this close to expiring
+const CREDENTIALS_REFRESH_BEFORE: Duration = Duration::from_secs(60);
+
+/// Credentials of each provider, so assume-role and SSO profiles are not
+/// resolved again for every call
+static CREDENTIALS: Mutex<BTreeMap<IdentityCachePartition, Credentials>> =
+    Mutex::const_new(BTreeMap::new());
+
+/// The error body of a failed call
+#[derive(Deserialize)]
+struct ErrorResponse {
+    #[serde(alias = "__type", alias = "Code")]
+    code: Option<String>,
+    #[serde(alias = "message")]
+    #[serde(rename = "Message")]
+    message: Option<String>,
+}
+
+/// Why an attempt failed: the error AWS answered with, or why no answer came
+#[derive(Debug)]
+struct Failure {
+    meta: ErrorMetadata,
+    retryable: bool,
+}
+
+impl ProvideErrorMetadata for Failure {
+    fn meta(&self) -> &ErrorMetadata {
+        &self.meta
+    }
+}
+
+pub struct JsonApiClient {
+    config: Arc<SdkConfig>,
+    connector: SharedHttpConnector,
+    service_id: &'static str, // As the SDKs name the service, e.g. `Resource Groups`
+    endpoint: String,         // e.g. https://resource-groups.eu-west-1.amazonaws.com
+    signing_name: &'static str,
+}
+
+impl JsonApiClient {
+    pub async fn new(
+        service_id: &'static str,
+        endpoint_prefix: &'static str,
+        signing_name: &'static str,
+    ) -> Self {
+        let config = AwsSessionManager::get_config().await;
+        let endpoint = endpoint_override(&config, service_id).unwrap_or_else(|| {
+            let region = config
+                .region()
+                .map(|region| region.as_ref())
+                .unwrap_or_default();
+            regional_endpoint(
+                endpoint_prefix,
+                region,
+                config.use_dual_stack().unwrap_or(false),
+            )
+        });
+        Self {
+            config,
+            connector: AwsSessionManager::http_connector(),
+            service_id,
+            endpoint,
+            signing_name,
+        }
+    }
+
+    /// POST `body` to `path` for `operation` and parse the JSON answer
+    pub async fn post<T: DeserializeOwned>(
+        &self,
+        operation: &str,
+        path: &str,
+        body: serde_json::Value,
+    ) -> Result<T> {
+        self.send(operation, "POST", path, Some(body)).await
+    }
+
+    /// GET `path` for `operation` and parse the JSON answer
+    pub async fn get<T: DeserializeOwned>(&self, operation: &str, path: &str) -> Result<T> {
+        self.send(operation, "GET", path, None).await
+    }
+
+    /// Send the request, retrying failures worth another attempt
+    async fn send<T: DeserializeOwned>(
+        &self,
+        operation: &str,
+        method: &str,
+        path: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<T> {
+        let body = body.map(|body| body.to_string()).unwrap_or_default();
+        let retry = self
+            .config
+            .retry_config()
+            .cloned()
+            .unwrap_or_else(RetryConfig::standard);
+        usage::record(self.service_id, operation);
+        let started = Instant::now();
+
+        let mut attempt = 1;
+        let result = loop {
+            let result = limiter::json_apis()
+                .call(self.service_id, || self.attempt(method, path, &body))
+                .await;
+            match result {
+                Err(failure) if failure.retryable && attempt < retry.max_attempts() => {
+                    let backoff = retry.initial_backoff() * 2u32.saturating_pow(attempt - 1);
+                    tokio::time::sleep(backoff.min(retry.max_backoff())).await;
+                    attempt += 1;
+                }
+                result => break result,
+            }
+        };
+        usage::complete(started.elapsed());
+
+        let bytes = result.map_err(|failure| {
+            anyhow!(failure
+                .meta
+                .message()
+                .or(failure.meta.code())
+                .unwrap_or("Unknown error")
+                .to_string())
+        })?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    /// One signed request; the body of a successful answer
+    async fn attempt(&self, method: &str, path: &str, body: &str) -> Result<Vec<u8>, Failure> {
+        let failure = |message: String| Failure {
+            meta: ErrorMetadata::builder().message(message).build(),
+            retryable: false,
+        };
+        let region = self
+            .config
+            .region()
+            .ok_or_else(|| failure("No AWS region configured".to_string()))?
+            .to_string();
+        let credentials = self
+            .credentials()
+            .await
+            .map_err(|e| failure(format!("{e:#}")))?;
+
+        let url = format!("{}{path}", self.endpoint);
+        let uri: http::Uri = url.parse().map_err(|e| failure(format!("{e}")))?;
+        let host = uri.authority().map(|a| a.to_string()).unwrap_or_default();
+        let headers = [
+            ("host", host.as_str()),
+            ("content-type", "application/json"),
+        ];
+
+        let identity = Identity::from(credentials);
+        let signed = v4::SigningParams::builder()
+            .identity(&identity)
+            .region(&region)
+            .name(self.signing_name)
+            .time(SystemTime::now())
+            .settings(SigningSettings::default())
+            .build()
+            .map_err(|e| failure(e.to_string()))
+            .and_then(|params| {
+                let signable = SignableRequest::new(
+                    method,
+                    url.as_str(),
+                    headers.iter().copied(),
+                    SignableBody::Bytes(body.as_bytes()),
+                )
+                .map_err(|e| failure(e.to_string()))?;
+                sign(signable, &params.into()).map_err(|e| failure(e.to_string()))
+            })?;
+        let (instructions, _) = signed.into_parts();
+
+        let mut request = http::Request::builder().method(method).uri(&url);
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+        let mut request = request
+            .body(SdkBody::from(body))
+            .map_err(|e| failure(e.to_string()))?;
+        instructions.apply_to_request_http1x(&mut request);
+        let request = HttpRequest::try_from(request).map_err(|e| failure(e.to_string()))?;
+
+        let response = self.connector.call(request).await.map_err(|e| Failure {
+            meta: ErrorMetadata::builder()
+                .message(format!("Could not reach {host}: {e}"))
+                .build(),
+            // Failing to reach the endpoint is worth a retry, as it is for the SDK clients
+            retryable: e.is_io() || e.is_timeout(),
+        })?;
+        let status = response.status().as_u16();
+        let error_type = response
+            .headers()
+            .get("x-amzn-errortype")
+            .map(|value| value.to_string());
+        let bytes = ByteStream::new(response.into_body())
+            .collect()
+            .await
+            .map_err(|e| failure(e.to_string()))?
+            .into_bytes();
+        if (200..300).contains(&status) {
+            return Ok(bytes.to_vec());
+        }
+
+        let error = serde_json::from_slice::<ErrorResponse>(&bytes).ok();
+        let code = error_type
+            .or_else(|| error.as_ref().and_then(|error| error.code.clone()))
+            .map(|code| error_code(&code).to_string());
+        let message = error
+            .and_then(|error| error.message)
+            .unwrap_or_else(|| format!("HTTP {status}"));
+        let retryable = status == 429 || status >= 500 || is_throttling(code.as_deref());
+        let mut meta = ErrorMetadata::builder().message(message);
+        if let Some(code) = code {
+            meta = meta.code(code);
+        }
+        Err(Failure {
+            meta: meta.build(),
+            retryable,
+        })
+    }
+
+    /// The session's credentials, resolved again only when they are about to expire
+    async fn credentials(&self) -> Result<Credentials> {
+        let provider = self
+            .config
+            .credentials_provider()
+            .ok_or_else(|| anyhow!("No AWS credentials configured"))?;
+        let mut cache = CREDENTIALS.lock().await;
+        let partition = provider.cache_partition();
+        let fresh = |credentials: &&Credentials| {
+            credentials.expiry().is_none_or(|expiry| {
+                expiry
+                    .duration_since(SystemTime::now())
+                    .is_ok_and(|left| left > CREDENTIALS_REFRESH_BEFORE)
+            })
+        };
+        if let Some(credentials) = partition
+            .and_then(|partition| cache.get(&partition))
+            .filter(fresh)
+        {
+            return Ok(credentials.clone());
+        }
+        let credentials = provider
+            .provide_credentials()
+            .await
+            .map_err(|e| anyhow!("AWS credentials error: {e}"))?;
+        if let Some(partition) = partition {
+            cache.insert(partition, credentials.clone());
+        }
+        Ok(credentials)
+    }
+}
+
+/// The endpoint set for the service, or for every service, in the environment or profile
+fn endpoint_override(config: &SdkConfig, service_id: &str) -> Option<String> {
+    let key = ServiceConfigKey::builder()
+        .service_id(service_id)
+        .env("AWS_ENDPOINT_URL")
+        .profile("endpoint_url")
+        .build()
+        .ok()?;
+    config
+        .service_config()
+        .and_then(|service_config| service_config.load_config(key))
+        .or_else(|| config.endpoint_url().map(str::to_string))
+        .map(|endpoint| endpoint.trim_end_matches('/').to_string())
+}
+
+/// The service's endpoint in `region`, in the region's partition
+fn regional_endpoint(endpoint_prefix: &str, region: &str, dual_stack: bool) -> String {
+    let domain = match (region.starts_with("cn-"), dual_stack) {
+        (false, false) => "amazonaws.com",
+        (false, true) => "api.aws",
+        (true, false) => "amazonaws.com.cn",
+        (true, true) => "api.amazonwebservices.com.cn",
+    };
+    format!("https://{endpoint_prefix}.{region}.{domain}")
+}
+
+/// The error code without the namespace or extra fields some services add, e.g.
+/// `com.amazon.coral#ThrottlingException:http://...` is `ThrottlingException`
+fn error_code(raw: &str) -> &str {
+    let code = raw.split(':').next().unwrap_or(raw);
+    code.rsplit('#').next().unwrap_or(code)
+}
+
+/// Percent-encode a path segment or query value, e.g. a pagination token
+pub fn escape(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_keeps_unreserved_characters() {
+        assert_eq!(escape("orders-app_v2.~"), "orders-app_v2.~");
+        assert_eq!(escape("a+b/c="), "a%2Bb%2Fc%3D");
+    }
+
+    #[test]
+    fn test_endpoints_follow_the_partition_and_dual_stack() {
+        assert_eq!(
+            regional_endpoint("lambda", "eu-west-1", false),
+            "https://lambda.eu-west-1.amazonaws.com"
+        );
+        assert_eq!(
+            regional_endpoint("lambda", "eu-west-1", true),
+            "https://lambda.eu-west-1.api.aws"
+        );
+        assert_eq!(
+            regional_endpoint("resource-groups", "cn-north-1", false),
+            "https://resource-groups.cn-north-1.amazonaws.com.cn"
+        );
+        assert_eq!(
+            regional_endpoint("resource-groups", "cn-north-1", true),
+            "https://resource-groups.cn-north-1.api.amazonwebservices.com.cn"
+        );
+
+        let overridden = SdkConfig::builder()
+            .endpoint_url("http://localhost:4566/")
+            .build();
+        assert_eq!(
+            endpoint_override(&overridden, "Lambda").as_deref(),
+            Some("http://localhost:4566")
+        );
+        assert_eq!(
+            endpoint_override(&SdkConfig::builder().build(), "Lambda"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_error_codes_lose_their_namespace() {
+        assert_eq!(error_code("ThrottlingException"), "ThrottlingException");
+        assert_eq!(
+            error_code("com.amazon.coral#TooManyRequestsException:http://internal"),
+            "TooManyRequestsException"
+        );
+    }
+}
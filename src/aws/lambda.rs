@@ -0,0 +1,423 @@
+// Lambda functions, and those consuming an SQS queue through event source mappings
+//
+// Functions are listed as a service of their own, their `AWS/Lambda` metrics
+// charted by `LambdaMetricProvider`. The queue's metrics show the producer side: messages arriving and the age
+// of the oldest one waiting. Whether they are worked off depends on the
+// functions polling the queue, so for each event source mapping on the queue
+// the consumer's invocations, errors, throttles, duration and concurrency over
+// the last hour are read from CloudWatch, with the error lines its log group
+// received in that time. The queue's ApproximateAgeOfOldestMessage plays the
+// part IteratorAge plays for streams.
+//
+// The service has no SDK crate here, so it is called through `JsonApiClient`.
+
+use super::json_api::{escape, JsonApiClient};
+use super::logs::{lambda_log_group, LogEvent, LogsClientManager};
+use super::metrics::fetcher::fetch_metric_series;
+use super::metrics::providers::{LambdaMetricProvider, MetricProvider};
+use super::metrics::types::StatisticType;
+use super::session::AwsSessionManager;
+use crate::models::LambdaFunction;
+use crate::ui::theme::Severity;
+use anyhow::{Context, Result};
+use aws_sdk_cloudwatch::types::Dimension;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::time::{Duration, SystemTime};
+
+/// How far back the consumers' metrics and error lines are read
+pub const LOOKBACK: Duration = Duration::from_secs(3600);
+
+const PERIOD_SECS: i32 = 300;
+
+/// Log lines counted as errors: the runtime's ERROR level, timeouts and crashes
+const ERROR_PATTERN: &str = r#"?ERROR ?Error ?"Task timed out" ?"Runtime exited""#;
+
+/// Error lines kept per consumer, newest
+const MAX_ERROR_LINES: usize = 20;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct EventSourceMapping {
+    pub function_arn: String,
+    #[serde(default)]
+    pub state: String, // Enabled, Disabled, Creating, ...
+    pub batch_size: Option<i32>,
+    pub scaling_config: Option<ScalingConfig>,
+    pub last_processing_result: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ScalingConfig {
+    pub maximum_concurrency: Option<i32>,
+}
+
+impl EventSourceMapping {
+    pub fn function_name(&self) -> &str {
+        self.function_arn
+            .split(":function:")
+            .nth(1)
+            .map_or(&self.function_arn, |name| {
+                // Drop a qualifier, e.g. :live
+                name.split(':').next().unwrap_or(name)
+            })
+    }
+
+    /// Concurrent invocations the mapping allows itself, below the function's own limit
+    pub fn maximum_concurrency(&self) -> Option<i32> {
+        self.scaling_config.as_ref()?.maximum_concurrency
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.state == "Enabled"
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ListEventSourceMappingsResponse {
+    #[serde(default)]
+    event_source_mappings: Vec<EventSourceMapping>,
+    next_marker: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct FunctionConcurrency {
+    reserved_concurrent_executions: Option<i32>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ListFunctionsResponse {
+    #[serde(default)]
+    functions: Vec<Map<String, Value>>,
+    next_marker: Option<String>,
+}
+
+/// A function from its configuration, every scalar field kept as an attribute
+fn function_from(configuration: Map<String, Value>) -> Option<LambdaFunction> {
+    let text = |name: &str| configuration.get(name).and_then(Value::as_str);
+    let number = |name: &str| configuration.get(name).and_then(Value::as_i64);
+    let mut attributes: Vec<(String, String)> = configuration
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = match value {
+                Value::String(text) => text.clone(),
+                Value::Number(number) => number.to_string(),
+                Value::Bool(flag) => flag.to_string(),
+                // e.g. Architectures
+                Value::Array(items) => items
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                _ => return None,
+            };
+            (!value.is_empty()).then(|| (name.clone(), value))
+        })
+        .collect();
+    attributes.sort();
+    Some(LambdaFunction {
+        name: text("FunctionName")?.to_string(),
+        runtime: text("Runtime").unwrap_or("image").to_string(),
+        memory_mb: number("MemorySize"),
+        timeout_secs: number("Timeout"),
+        state: text("State").map(str::to_string),
+        last_modified: text("LastModified").unwrap_or_default().to_string(),
+        attributes,
+    })
+}
+
+/// One of a consumer's metrics over the lookback
+#[derive(Debug, Clone)]
+pub struct ConsumerMetric {
+    pub name: String,
+    pub unit: String,
+    pub statistic: StatisticType, // Sums the metric up over the lookback
+    pub values: Vec<f64>,
+}
+
+impl ConsumerMetric {
+    /// Total of a count, peak of a maximum, mean of an average; None without datapoints
+    pub fn summary(&self) -> Option<f64> {
+        if self.values.is_empty() {
+            return None;
+        }
+        Some(match self.statistic {
+            StatisticType::Sum => self.values.iter().sum(),
+            StatisticType::Maximum => self.values.iter().copied().fold(f64::MIN, f64::max),
+            StatisticType::Minimum => self.values.iter().copied().fold(f64::MAX, f64::min),
+            StatisticType::Average => self.values.iter().sum::<f64>() / self.values.len() as f64,
+        })
+    }
+}
+
+/// A function polling the queue, with its health over the lookback
+#[derive(Debug, Clone)]
+pub struct LambdaConsumer {
+    pub mapping: EventSourceMapping,
+    pub reserved_concurrency: Option<i32>,
+    pub metrics: Vec<ConsumerMetric>,
+    pub recent_errors: Option<Vec<LogEvent>>, // None when the function's log group cannot be read
+}
+
+impl LambdaConsumer {
+    pub fn metric(&self, name: &str) -> Option<&ConsumerMetric> {
+        self.metrics.iter().find(|metric| metric.name == name)
+    }
+
+    fn total(&self, name: &str) -> f64 {
+        self.metric(name)
+            .and_then(ConsumerMetric::summary)
+            .unwrap_or(0.0)
+    }
+
+    /// Critical when the mapping is not consuming or most invocations fail, warning on any
+    /// error or throttle
+    pub fn severity(&self) -> Severity {
+        let invocations = self.total("Invocations");
+        let errors = self.total("Errors");
+        if !self.mapping.is_enabled() || (invocations > 0.0 && errors / invocations >= 0.5) {
+            Severity::Critical
+        } else if errors > 0.0 || self.total("Throttles") > 0.0 {
+            Severity::Warning
+        } else {
+            Severity::Ok
+        }
+    }
+}
+
+pub struct LambdaClient {
+    api: JsonApiClient,
+}
+
+impl LambdaClient {
+    pub async fn new() -> Self {
+        Self {
+            api: JsonApiClient::new("Lambda", "lambda", "lambda").await,
+        }
+    }
+
+    /// Every function in the account and region, in the order the API lists them
+    pub async fn list_functions(&self) -> Result<Vec<LambdaFunction>> {
+        let mut functions = Vec::new();
+        let mut marker: Option<String> = None;
+        loop {
+            let path = match &marker {
+                Some(marker) => format!("/2015-03-31/functions/?Marker={}", escape(marker)),
+                None => "/2015-03-31/functions/".to_string(),
+            };
+            let page: ListFunctionsResponse = self
+                .api
+                .get("ListFunctions", &path)
+                .await
+                .context("Failed to list Lambda functions")?;
+            functions.extend(page.functions.into_iter().filter_map(function_from));
+            marker = page.next_marker;
+            if marker.is_none() {
+                break;
+            }
+        }
+        Ok(functions)
+    }
+
+    /// The function's full configuration, with the state ListFunctions leaves out
+    pub async fn function(&self, name: &str) -> Result<LambdaFunction> {
+        let configuration: Map<String, Value> = self
+            .api
+            .get(
+                "GetFunctionConfiguration",
+                &format!("/2015-03-31/functions/{}/configuration", escape(name)),
+            )
+            .await
+            .with_context(|| format!("Failed to read the configuration of {name}"))?;
+        function_from(configuration).with_context(|| format!("No function named {name}"))
+    }
+
+    /// Event source mappings reading from the resource with `source_arn`, e.g. a queue
+    pub async fn event_source_mappings(&self, source_arn: &str) -> Result<Vec<EventSourceMapping>> {
+        let mut mappings = Vec::new();
+        let mut marker: Option<String> = None;
+        loop {
+            let mut path = format!(
+                "/2015-03-31/event-source-mappings/?EventSourceArn={}",
+                escape(source_arn)
+            );
+            if let Some(marker) = &marker {
+                path.push_str(&format!("&Marker={}", escape(marker)));
+            }
+            let page: ListEventSourceMappingsResponse = self
+                .api
+                .get("ListEventSourceMappings", &path)
+                .await
+                .context("Failed to list Lambda event source mappings")?;
+            mappings.extend(page.event_source_mappings);
+            marker = page.next_marker;
+            if marker.is_none() {
+                break;
+            }
+        }
+        Ok(mappings)
+    }
+
+    /// The function's reserved concurrency, None when it shares the account's pool
+    pub async fn reserved_concurrency(&self, function: &str) -> Result<Option<i32>> {
+        let concurrency: FunctionConcurrency = self
+            .api
+            .get(
+                "GetFunctionConcurrency",
+                &format!("/2019-09-30/functions/{}/concurrency", escape(function)),
+            )
+            .await
+            .with_context(|| format!("Failed to read the concurrency of {function}"))?;
+        Ok(concurrency.reserved_concurrent_executions)
+    }
+}
+
+/// The functions consuming the queue with `queue_arn`, each with its last hour of health
+///
+/// A metric or log that cannot be read leaves that part empty rather than
+/// failing the others.
+pub async fn load_consumers(queue_arn: &str) -> Result<Vec<LambdaConsumer>> {
+    let lambda = LambdaClient::new().await;
+    let mappings = lambda.event_source_mappings(queue_arn).await?;
+    if mappings.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let cloudwatch = AwsSessionManager::cloudwatch_client().await;
+    let logs = LogsClientManager::new().await;
+    let end = SystemTime::now();
+    let start = end - LOOKBACK;
+    let mut consumers = Vec::new();
+    for mapping in mappings {
+        let function = mapping.function_name().to_string();
+        let reserved_concurrency = lambda
+            .reserved_concurrency(&function)
+            .await
+            .unwrap_or_default();
+
+        let mut metrics = Vec::new();
+        for definition in LambdaMetricProvider::new().get_metrics_config() {
+            let dimension = Dimension::builder()
+                .name("FunctionName")
+                .value(&function)
+                .build();
+            let (values, _) = fetch_metric_series(
+                &cloudwatch,
+                "AWS/Lambda",
+                &definition,
+                vec![dimension],
+                start,
+                end,
+                PERIOD_SECS,
+            )
+            .await
+            .unwrap_or_default();
+            metrics.push(ConsumerMetric {
+                unit: definition.unit.clone().unwrap_or_default(),
+                name: definition.name,
+                statistic: definition.statistic,
+                values,
+            });
+        }
+
+        let recent_errors = logs
+            .matching_events(&lambda_log_group(&function), start, ERROR_PATTERN)
+            .await
+            .unwrap_or_default()
+            .map(|mut events| {
+                let skip = events.len().saturating_sub(MAX_ERROR_LINES);
+                events.drain(..skip);
+                events
+            });
+
+        consumers.push(LambdaConsumer {
+            mapping,
+            reserved_concurrency,
+            metrics,
+            recent_errors,
+        });
+    }
+    Ok(consumers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mappings_name_their_function_and_consumers_rate_their_health() {
+        let page: ListEventSourceMappingsResponse = serde_json::from_str(
+            r#"{"EventSourceMappings":[{"UUID":"a1","State":"Enabled","BatchSize":10,
+                "FunctionArn":"arn:aws:lambda:us-east-1:1:function:orders-worker:live",
+                "ScalingConfig":{"MaximumConcurrency":5}}]}"#,
+        )
+        .unwrap();
+        let mapping = page.event_source_mappings[0].clone();
+        assert_eq!(mapping.function_name(), "orders-worker");
+        assert_eq!(mapping.maximum_concurrency(), Some(5));
+        assert!(page.next_marker.is_none());
+
+        let consumer = |errors: f64, state: &str| LambdaConsumer {
+            mapping: EventSourceMapping {
+                state: state.to_string(),
+                ..mapping.clone()
+            },
+            reserved_concurrency: None,
+            metrics: vec![
+                ConsumerMetric {
+                    name: "Invocations".to_string(),
+                    unit: "Count".to_string(),
+                    statistic: StatisticType::Sum,
+                    values: vec![40.0, 60.0],
+                },
+                ConsumerMetric {
+                    name: "Errors".to_string(),
+                    unit: "Count".to_string(),
+                    statistic: StatisticType::Sum,
+                    values: vec![0.0, errors],
+                },
+            ],
+            recent_errors: None,
+        };
+        assert_eq!(consumer(0.0, "Enabled").severity(), Severity::Ok);
+        assert_eq!(consumer(3.0, "Enabled").severity(), Severity::Warning);
+        assert_eq!(consumer(60.0, "Enabled").severity(), Severity::Critical);
+        assert_eq!(consumer(0.0, "Disabled").severity(), Severity::Critical);
+        assert_eq!(
+            consumer(3.0, "Enabled").metric("Errors").unwrap().summary(),
+            Some(3.0)
+        );
+    }
+
+    #[test]
+    fn test_functions_keep_their_configuration_as_attributes() {
+        let page: ListFunctionsResponse = serde_json::from_str(
+            r#"{"Functions":[{"FunctionName":"orders-worker","Runtime":"python3.12",
+                "MemorySize":512,"Timeout":30,"Architectures":["arm64"],
+                "LastModified":"2026-10-01T12:00:00.000+0000","Environment":{"Variables":{}}},
+                {"FunctionName":"thumbnails","PackageType":"Image"}],"NextMarker":"m1"}"#,
+        )
+        .unwrap();
+        assert_eq!(page.next_marker.as_deref(), Some("m1"));
+        let functions: Vec<LambdaFunction> = page
+            .functions
+            .into_iter()
+            .filter_map(function_from)
+            .collect();
+        assert_eq!(functions[0].memory_mb, Some(512));
+        assert_eq!(functions[0].timeout_secs, Some(30));
+        assert!(functions[0].state.is_none());
+        assert!(functions[0]
+            .attributes
+            .contains(&("Architectures".to_string(), "arm64".to_string())));
+        assert!(!functions[0]
+            .attributes
+            .iter()
+            .any(|(key, _)| key == "Environment"));
+        assert_eq!(functions[1].runtime, "image");
+    }
+}
@@ -0,0 +1,321 @@
+// Shared limit on concurrent CloudWatch API calls
+//
+// Metric pages, replica lag and queue views fetch many series in parallel.
+// Every GetMetricStatistics call goes through one limiter so a large account
+// cannot stampede the API. The calls made without an SDK client (Lambda,
+// Resource Groups, AppRegistry) share a second limiter of their own. The limit adapts: a throttling response halves it
+// and adds a delay before later calls, and runs of successful calls raise it
+// back one step at a time up to the configured maximum.
+//
+// Throttling responses are also logged per namespace. For COOL_DOWN after the
+// latest one the footer warns that data may be delayed and auto-refresh runs
+// REFRESH_STRETCH times less often, giving the account's quota time to recover.
+
+use aws_sdk_cloudwatch::error::ProvideErrorMetadata;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+
+/// Concurrent calls allowed when `[cloudwatch] max_concurrent_calls` is not set
+pub const DEFAULT_MAX_CONCURRENT_CALLS: usize = 10;
+
+const MIN_BACKOFF: Duration = Duration::from_millis(200);
+const MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+/// How long after a throttling response refreshes stay stretched
+pub const COOL_DOWN: Duration = Duration::from_secs(300);
+
+/// Factor auto-refresh intervals are stretched by while cooling down
+const REFRESH_STRETCH: u32 = 3;
+
+/// Throttling responses kept for the debug views, oldest dropped first
+const MAX_THROTTLES: usize = 50;
+
+/// Error codes AWS services use for request throttling
+const THROTTLING_CODES: [&str; 5] = [
+    "Throttling",
+    "ThrottlingException",
+    "ThrottledException",
+    "RequestLimitExceeded",
+    "TooManyRequestsException",
+];
+
+static CLOUDWATCH: OnceLock<CallLimiter> = OnceLock::new();
+static JSON_APIS: OnceLock<CallLimiter> = OnceLock::new();
+
+/// Set the CloudWatch limit; only effective before the first call
+pub fn configure_cloudwatch(max_concurrent_calls: usize) {
+    let _ = CLOUDWATCH.set(CallLimiter::new(max_concurrent_calls));
+}
+
+pub fn cloudwatch() -> &'static CallLimiter {
+    CLOUDWATCH.get_or_init(|| CallLimiter::new(DEFAULT_MAX_CONCURRENT_CALLS))
+}
+
+/// The limiter of the calls made without an SDK client
+pub fn json_apis() -> &'static CallLimiter {
+    JSON_APIS.get_or_init(|| CallLimiter::new(DEFAULT_MAX_CONCURRENT_CALLS))
+}
+
+pub fn is_throttling(code: Option<&str>) -> bool {
+    code.is_some_and(|code| THROTTLING_CODES.contains(&code))
+}
+
+pub struct CallLimiter {
+    semaphore: Semaphore,
+    state: Mutex<LimiterState>,
+    throttles: Mutex<ThrottleLog>,
+}
+
+impl CallLimiter {
+    pub fn new(max_concurrent_calls: usize) -> Self {
+        let max = max_concurrent_calls.max(1);
+        Self {
+            semaphore: Semaphore::new(max),
+            state: Mutex::new(LimiterState::new(max)),
+            throttles: Mutex::new(ThrottleLog::default()),
+        }
+    }
+
+    /// Run `call` once a slot is free, adjusting the limit from its outcome
+    ///
+    /// `namespace` is the CloudWatch namespace the call reads, e.g. "AWS/RDS",
+    /// or the service called without an SDK client, which throttling responses
+    /// are logged under.
+    pub async fn call<T, E, Fut>(&self, namespace: &str, call: impl FnOnce() -> Fut) -> Result<T, E>
+    where
+        Fut: Future<Output = Result<T, E>>,
+        E: ProvideErrorMetadata,
+    {
+        let permit = self
+            .semaphore
+            .acquire()
+            .await
+            .expect("limiter semaphore is never closed");
+
+        let backoff = self.lock_state().backoff;
+        if !backoff.is_zero() {
+            tokio::time::sleep(backoff).await;
+        }
+
+        let result = call().await;
+        let throttled = match &result {
+            Err(e) if is_throttling(e.code()) => {
+                self.lock_throttles().record(
+                    namespace,
+                    e.code().unwrap_or_default(),
+                    Instant::now(),
+                );
+                true
+            }
+            _ => false,
+        };
+
+        let mut state = self.lock_state();
+        let grow = if throttled {
+            state.on_throttle();
+            false
+        } else {
+            state.on_success()
+        };
+
+        // Shrinking retires permits as calls finish; growing cancels a pending
+        // shrink first and only then hands out a new permit
+        if state.pending_shrink > 0 {
+            state.pending_shrink -= 1;
+            permit.forget();
+        } else {
+            drop(permit);
+        }
+        if grow {
+            if state.pending_shrink > 0 {
+                state.pending_shrink -= 1;
+            } else {
+                self.semaphore.add_permits(1);
+            }
+        }
+        result
+    }
+
+    /// Whether a call was throttled within the last COOL_DOWN
+    pub fn cooling_down(&self) -> bool {
+        self.lock_throttles().cooling_down(Instant::now())
+    }
+
+    /// `interval`, stretched while cooling down from throttling
+    pub fn stretched(&self, interval: Duration) -> Duration {
+        if self.cooling_down() {
+            interval * REFRESH_STRETCH
+        } else {
+            interval
+        }
+    }
+
+    /// Logged throttling responses, newest last
+    pub fn throttles(&self) -> Vec<Throttle> {
+        self.lock_throttles().events.iter().cloned().collect()
+    }
+
+    /// Throttling responses per namespace within the last COOL_DOWN
+    pub fn recent_throttles(&self) -> Vec<(String, usize)> {
+        self.lock_throttles().recent(Instant::now())
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, LimiterState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn lock_throttles(&self) -> std::sync::MutexGuard<'_, ThrottleLog> {
+        self.throttles.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A call refused with a throttling error
+#[derive(Debug, Clone, PartialEq)]
+pub struct Throttle {
+    pub namespace: String,
+    pub code: String, // e.g. "Throttling" or "RequestLimitExceeded"
+    pub at: Instant,
+}
+
+#[derive(Debug, Default)]
+struct ThrottleLog {
+    events: VecDeque<Throttle>,
+}
+
+impl ThrottleLog {
+    fn record(&mut self, namespace: &str, code: &str, at: Instant) {
+        if self.events.len() == MAX_THROTTLES {
+            self.events.pop_front();
+        }
+        self.events.push_back(Throttle {
+            namespace: namespace.to_string(),
+            code: code.to_string(),
+            at,
+        });
+    }
+
+    fn cooling_down(&self, now: Instant) -> bool {
+        self.events
+            .back()
+            .is_some_and(|latest| now.saturating_duration_since(latest.at) < COOL_DOWN)
+    }
+
+    fn recent(&self, now: Instant) -> Vec<(String, usize)> {
+        let mut counts: Vec<(String, usize)> = Vec::new();
+        for event in &self.events {
+            if now.saturating_duration_since(event.at) >= COOL_DOWN {
+                continue;
+            }
+            match counts
+                .iter_mut()
+                .find(|(namespace, _)| *namespace == event.namespace)
+            {
+                Some((_, count)) => *count += 1,
+                None => counts.push((event.namespace.clone(), 1)),
+            }
+        }
+        counts
+    }
+}
+
+/// Additive-increase, multiplicative-decrease bookkeeping for the limit
+#[derive(Debug)]
+struct LimiterState {
+    max: usize,
+    limit: usize,
+    pending_shrink: usize, // Permits still to retire after the last decrease
+    successes: usize,      // Successful calls since the limit last changed
+    backoff: Duration,     // Delay before each call while throttling persists
+}
+
+impl LimiterState {
+    fn new(max: usize) -> Self {
+        Self {
+            max,
+            limit: max,
+            pending_shrink: 0,
+            successes: 0,
+            backoff: Duration::ZERO,
+        }
+    }
+
+    fn on_throttle(&mut self) {
+        let reduced = (self.limit / 2).max(1);
+        self.pending_shrink += self.limit - reduced;
+        self.limit = reduced;
+        self.successes = 0;
+        self.backoff = (self.backoff * 2).clamp(MIN_BACKOFF, MAX_BACKOFF);
+    }
+
+    /// Returns true when the limit grew by one
+    fn on_success(&mut self) -> bool {
+        self.backoff /= 2;
+        if self.backoff < MIN_BACKOFF {
+            self.backoff = Duration::ZERO;
+        }
+        self.successes += 1;
+        if self.limit < self.max && self.successes >= self.limit {
+            self.limit += 1;
+            self.successes = 0;
+            return true;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_throttling_halves_limit_and_successes_restore_it() {
+        let mut state = LimiterState::new(8);
+        state.on_throttle();
+        assert_eq!((state.limit, state.pending_shrink), (4, 4));
+        assert_eq!(state.backoff, MIN_BACKOFF);
+
+        state.on_throttle();
+        assert_eq!(state.limit, 2);
+        assert_eq!(state.backoff, MIN_BACKOFF * 2);
+
+        // The backoff decays first, then the limit grows after a full round of successes
+        assert!(!state.on_success());
+        assert!(state.on_success());
+        assert_eq!(state.limit, 3);
+        assert_eq!(state.backoff, Duration::ZERO);
+
+        assert!(is_throttling(Some("Throttling")));
+        assert!(!is_throttling(Some("AccessDenied")));
+    }
+
+    #[test]
+    fn test_throttles_are_counted_per_namespace_until_cooled_down() {
+        let start = Instant::now();
+        let mut log = ThrottleLog::default();
+        assert!(!log.cooling_down(start));
+
+        log.record("AWS/RDS", "Throttling", start);
+        log.record(
+            "AWS/SQS",
+            "RequestLimitExceeded",
+            start + Duration::from_secs(60),
+        );
+        log.record("AWS/RDS", "Throttling", start + Duration::from_secs(120));
+        assert_eq!(
+            log.recent(start + Duration::from_secs(120)),
+            [("AWS/RDS".to_string(), 2), ("AWS/SQS".to_string(), 1)]
+        );
+
+        // The first has aged out; the cool-down runs from the latest
+        let later = start + COOL_DOWN + Duration::from_secs(30);
+        assert_eq!(
+            log.recent(later),
+            [("AWS/SQS".to_string(), 1), ("AWS/RDS".to_string(), 1)]
+        );
+        assert!(log.cooling_down(later));
+        assert!(!log.cooling_down(start + Duration::from_secs(120) + COOL_DOWN));
+    }
+}
@@ -0,0 +1,182 @@
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::session::AwsSessionManager;
+use anyhow::Result;
+use aws_sdk_cloudwatchlogs::Client as LogsClient;
+use std::time::{Duration, SystemTime};
+
+/// Upper bound on events read per request, so a noisy log cannot stall the UI
+const MAX_EVENTS: usize = 1000;
+
+#[derive(Debug, Clone)]
+pub struct LogEvent {
+    pub event_id: String, // Unique within the log group
+    pub timestamp: SystemTime,
+    pub message: String,
+}
+
+/// Which events a read keeps when more match than it holds
+#[derive(Debug, Clone, Copy)]
+enum Window {
+    /// Stop once this many of the oldest have been read
+    Oldest(usize),
+    /// Read through to the newest event, keeping this many of the latest
+    Newest(usize),
+}
+
+/// CloudWatch Logs client operations - reading recent log events
+pub struct LogsClientManager {
+    client: LogsClient,
+}
+
+impl LogsClientManager {
+    /// Create a new CloudWatch Logs client manager using shared AWS session
+    pub async fn new() -> Self {
+        let client = AwsSessionManager::logs_client().await;
+        Self { client }
+    }
+
+    /// Events of a log group since `start_time`, oldest first
+    ///
+    /// Returns None when the log group does not exist, i.e. the log is not
+    /// published to CloudWatch Logs.
+    pub async fn recent_events(
+        &self,
+        log_group: &str,
+        start_time: SystemTime,
+    ) -> Result<Option<Vec<LogEvent>>> {
+        self.filter_events(log_group, start_time, None, Window::Oldest(MAX_EVENTS))
+            .await
+    }
+
+    /// Events since `start_time` that match a CloudWatch Logs filter pattern, oldest first
+    pub async fn matching_events(
+        &self,
+        log_group: &str,
+        start_time: SystemTime,
+        pattern: &str,
+    ) -> Result<Option<Vec<LogEvent>>> {
+        self.filter_events(
+            log_group,
+            start_time,
+            Some(pattern),
+            Window::Oldest(MAX_EVENTS),
+        )
+        .await
+    }
+
+    /// The newest `keep` events since `start_time`, optionally matching a filter pattern
+    ///
+    /// Pages through every event since `start_time`, so a tail always reaches
+    /// the latest event however busy the log group is.
+    pub async fn latest_events(
+        &self,
+        log_group: &str,
+        start_time: SystemTime,
+        pattern: Option<&str>,
+        keep: usize,
+    ) -> Result<Option<Vec<LogEvent>>> {
+        self.filter_events(log_group, start_time, pattern, Window::Newest(keep))
+            .await
+    }
+
+    /// Names of the log groups starting with `prefix`, sorted
+    pub async fn log_groups(&self, prefix: &str) -> Result<Vec<String>> {
+        let mut groups = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let resp = self
+                .client
+                .describe_log_groups()
+                .log_group_name_prefix(prefix)
+                .set_next_token(next_token.take())
+                .send()
+                .await
+                .map_err(|e| {
+                    AwsErrorHandler::handle_aws_error(
+                        e,
+                        &format!("list log groups under {prefix}"),
+                        "CloudWatch Logs DescribeLogGroups permissions",
+                    )
+                })?;
+            groups.extend(
+                resp.log_groups()
+                    .iter()
+                    .filter_map(|group| group.log_group_name().map(str::to_string)),
+            );
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+        groups.sort();
+        Ok(groups)
+    }
+
+    async fn filter_events(
+        &self,
+        log_group: &str,
+        start_time: SystemTime,
+        pattern: Option<&str>,
+        window: Window,
+    ) -> Result<Option<Vec<LogEvent>>> {
+        let start_millis = start_time
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|d| d.as_millis() as i64)
+            .unwrap_or(0);
+        let mut events = Vec::new();
+        let mut next_token: Option<String> = None;
+
+        loop {
+            let resp = match self
+                .client
+                .filter_log_events()
+                .log_group_name(log_group)
+                .start_time(start_millis)
+                .set_filter_pattern(pattern.map(str::to_string))
+                .set_next_token(next_token.clone())
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    if e.as_service_error()
+                        .is_some_and(|e| e.is_resource_not_found_exception())
+                    {
+                        return Ok(None);
+                    }
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        &format!("read log group {log_group}"),
+                        "CloudWatch Logs FilterLogEvents permissions",
+                    ));
+                }
+            };
+
+            events.extend(resp.events().iter().filter_map(|event| {
+                Some(LogEvent {
+                    event_id: event.event_id().unwrap_or_default().to_string(),
+                    timestamp: SystemTime::UNIX_EPOCH
+                        + Duration::from_millis(event.timestamp()?.max(0) as u64),
+                    message: event.message()?.to_string(),
+                })
+            }));
+
+            next_token = resp.next_token().map(str::to_string);
+            match window {
+                Window::Oldest(limit) if events.len() >= limit => break,
+                Window::Newest(keep) if events.len() > keep => {
+                    // Pages come oldest first, so only the latest need keeping
+                    events.sort_by_key(|event| event.timestamp);
+                    events.drain(..events.len() - keep);
+                }
+                _ => {}
+            }
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        events.sort_by_key(|event| event.timestamp);
+        Ok(Some(events))
+    }
+}
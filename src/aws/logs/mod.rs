@@ -0,0 +1,27 @@
+// CloudWatch Logs - log groups that RDS and Lambda publish to
+pub mod client;
+pub mod slow_query;
+pub mod tail;
+
+pub use client::{LogEvent, LogsClientManager};
+
+/// Log group RDS publishes one of an instance's logs to, e.g. `error` or `postgresql`
+pub fn rds_log_group(
+    instance_id: &str,
+    cluster_id: Option<&str>,
+    engine: &str,
+    log: &str,
+) -> String {
+    // Aurora publishes logs per cluster rather than per instance
+    match cluster_id {
+        Some(cluster) if engine.starts_with("aurora") => {
+            format!("/aws/rds/cluster/{cluster}/{log}")
+        }
+        _ => format!("/aws/rds/instance/{instance_id}/{log}"),
+    }
+}
+
+/// Log group Lambda writes a function's output to, unless it is configured otherwise
+pub fn lambda_log_group(function: &str) -> String {
+    format!("/aws/lambda/{function}")
+}
@@ -0,0 +1,268 @@
+// Slow query log parsing
+//
+// MySQL and MariaDB publish the slow query log to a `slowquery` log group, one
+// entry per event with `# Query_time:` and `Rows_examined:` headers. PostgreSQL
+// logs statements above `log_min_duration_statement` to its `postgresql` log
+// group as `duration: ... ms  statement: ...` lines, without row counts.
+// Statements are normalized (literals replaced by `?`) so repeats of one query
+// shape can be recognized in the table.
+
+use super::{rds_log_group, LogEvent};
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlowQuery {
+    pub timestamp: SystemTime,
+    pub duration_secs: f64,
+    pub rows_examined: Option<u64>,
+    pub statement: String, // Normalized
+}
+
+/// Column the slow query table is sorted by, descending
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SlowQuerySort {
+    #[default]
+    Duration,
+    RowsExamined,
+    Time,
+}
+
+impl SlowQuerySort {
+    pub fn next(self) -> Self {
+        match self {
+            SlowQuerySort::Duration => SlowQuerySort::RowsExamined,
+            SlowQuerySort::RowsExamined => SlowQuerySort::Time,
+            SlowQuerySort::Time => SlowQuerySort::Duration,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SlowQuerySort::Duration => "duration",
+            SlowQuerySort::RowsExamined => "rows examined",
+            SlowQuerySort::Time => "time",
+        }
+    }
+
+    pub fn sort(self, queries: &mut [SlowQuery]) {
+        match self {
+            SlowQuerySort::Duration => {
+                queries.sort_by(|a, b| b.duration_secs.total_cmp(&a.duration_secs))
+            }
+            SlowQuerySort::RowsExamined => {
+                queries.sort_by_key(|query| std::cmp::Reverse(query.rows_examined))
+            }
+            SlowQuerySort::Time => queries.sort_by_key(|query| std::cmp::Reverse(query.timestamp)),
+        }
+    }
+}
+
+/// Log group the instance's slow queries are published to, if its engine has one
+pub fn log_group(instance_id: &str, cluster_id: Option<&str>, engine: &str) -> Option<String> {
+    let log = if engine.contains("postgres") {
+        "postgresql"
+    } else if engine.contains("mysql") || engine.contains("mariadb") {
+        "slowquery"
+    } else {
+        return None;
+    };
+    Some(rds_log_group(instance_id, cluster_id, engine, log))
+}
+
+/// Slow queries found in log events; events of other kinds are skipped
+pub fn parse_events(events: &[LogEvent]) -> Vec<SlowQuery> {
+    events
+        .iter()
+        .filter_map(|event| {
+            parse_mysql(&event.message)
+                .or_else(|| parse_postgres(&event.message))
+                .map(|(duration_secs, rows_examined, statement)| SlowQuery {
+                    timestamp: event.timestamp,
+                    duration_secs,
+                    rows_examined,
+                    statement: normalize_statement(&statement),
+                })
+        })
+        .collect()
+}
+
+fn parse_mysql(message: &str) -> Option<(f64, Option<u64>, String)> {
+    let header = message
+        .lines()
+        .find(|line| line.starts_with("# Query_time:"))?;
+    let field = |name: &str| {
+        let mut words = header.split_whitespace();
+        words.find(|word| *word == name)?;
+        words.next()
+    };
+    let duration = field("Query_time:")?.parse().ok()?;
+    let rows_examined = field("Rows_examined:").and_then(|rows| rows.parse().ok());
+
+    // The statement follows the comment headers and the `SET timestamp` / `use` lines
+    let statement: Vec<&str> = message
+        .lines()
+        .filter(|line| {
+            let lower = line.trim_start().to_ascii_lowercase();
+            !line.starts_with('#')
+                && !lower.starts_with("set timestamp=")
+                && !lower.starts_with("use ")
+                && !lower.trim().is_empty()
+        })
+        .collect();
+    Some((duration, rows_examined, statement.join(" ")))
+}
+
+fn parse_postgres(message: &str) -> Option<(f64, Option<u64>, String)> {
+    let (_, rest) = message.split_once("duration: ")?;
+    let (millis, rest) = rest.split_once(" ms")?;
+    let duration = millis.trim().parse::<f64>().ok()? / 1000.0;
+    // `statement:` for simple queries, `execute <name>:` for prepared ones
+    let statement = rest
+        .split_once("statement: ")
+        .or_else(|| {
+            rest.split_once("execute ")
+                .and_then(|(_, s)| s.split_once(": "))
+        })
+        .map(|(_, statement)| statement)?;
+    Some((duration, None, statement.to_string()))
+}
+
+/// Replace literals with `?`, collapse `IN (?, ?, ...)` lists and whitespace
+pub fn normalize_statement(statement: &str) -> String {
+    let mut normalized = String::with_capacity(statement.len());
+    let mut chars = statement.chars().peekable();
+    let mut previous: Option<char> = None;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' => {
+                // Skip to the closing quote, treating a doubled quote as an escape
+                while let Some(next) = chars.next() {
+                    if next == '\\' {
+                        chars.next();
+                    } else if next == c {
+                        if chars.peek() == Some(&c) {
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+                }
+                normalized.push('?');
+            }
+            c if c.is_ascii_digit()
+                && !previous.is_some_and(|p| p.is_alphanumeric() || p == '_') =>
+            {
+                while chars
+                    .peek()
+                    .is_some_and(|next| next.is_ascii_alphanumeric() || *next == '.')
+                {
+                    chars.next();
+                }
+                normalized.push('?');
+            }
+            c if c.is_whitespace() => {
+                if !normalized.ends_with(' ') && !normalized.is_empty() {
+                    normalized.push(' ');
+                }
+            }
+            c => normalized.push(c),
+        }
+        previous = Some(c);
+    }
+
+    let mut normalized = normalized.trim_end().trim_end_matches(';').to_string();
+    while normalized.contains("?, ?") {
+        normalized = normalized.replace("?, ?", "?");
+    }
+    normalized.replace("?,?", "?")
+}
+
+/// Value of a metric series at `timestamp`, from the datapoint covering it
+pub fn value_at(
+    timestamp: SystemTime,
+    timestamps: &[SystemTime],
+    values: &[f64],
+    period: Duration,
+) -> Option<f64> {
+    timestamps
+        .iter()
+        .zip(values)
+        .filter(|(start, _)| {
+            timestamp
+                .duration_since(**start)
+                .is_ok_and(|offset| offset < period)
+        })
+        .map(|(_, value)| *value)
+        .next_back()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slow_queries_parse_and_normalize() {
+        let at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let events = vec![
+            LogEvent {
+                event_id: String::new(),
+                timestamp: at,
+                message: "# Time: 2023-11-14T22:13:20.123456Z\n\
+                    # User@Host: app[app] @  [10.0.0.1]  Id:    12\n\
+                    # Query_time: 2.500123  Lock_time: 0.000100 Rows_sent: 1  Rows_examined: 500000\n\
+                    use orders;\n\
+                    SET timestamp=1700000000;\n\
+                    SELECT * FROM orders\n  WHERE customer_id = 42 AND status IN ('new', 'paid');"
+                    .to_string(),
+            },
+            LogEvent {
+                event_id: String::new(),
+                timestamp: at + Duration::from_secs(60),
+                message: "2023-11-14 22:14:20 UTC:10.0.0.1(5432):app@orders:[123]:LOG:  \
+                    duration: 812.5 ms  statement: UPDATE t1 SET note = 'it''s' WHERE id = 7"
+                    .to_string(),
+            },
+            LogEvent {
+                event_id: String::new(),
+                timestamp: at,
+                message: "2023-11-14 22:14:21 UTC::@:[99]:LOG:  checkpoint starting: time"
+                    .to_string(),
+            },
+        ];
+
+        let mut queries = parse_events(&events);
+        assert_eq!(queries.len(), 2);
+        assert_eq!(queries[0].duration_secs, 2.500123);
+        assert_eq!(queries[0].rows_examined, Some(500_000));
+        assert_eq!(
+            queries[0].statement,
+            "SELECT * FROM orders WHERE customer_id = ? AND status IN (?)"
+        );
+        assert_eq!(queries[1].duration_secs, 0.8125);
+        assert_eq!(queries[1].rows_examined, None);
+        assert_eq!(queries[1].statement, "UPDATE t1 SET note = ? WHERE id = ?");
+
+        SlowQuerySort::Time.sort(&mut queries);
+        assert_eq!(queries[0].duration_secs, 0.8125);
+
+        let timestamps = [at - Duration::from_secs(300), at];
+        let cpu = [35.0, 92.0];
+        let period = Duration::from_secs(300);
+        assert_eq!(
+            value_at(at + Duration::from_secs(60), &timestamps, &cpu, period),
+            Some(92.0)
+        );
+        assert_eq!(
+            value_at(at - Duration::from_secs(1), &timestamps, &cpu, period),
+            Some(35.0)
+        );
+        assert_eq!(value_at(at + period, &timestamps, &cpu, period), None);
+
+        assert_eq!(
+            log_group("orders-1", Some("orders"), "aurora-mysql").as_deref(),
+            Some("/aws/rds/cluster/orders/slowquery")
+        );
+        assert_eq!(log_group("legacy", None, "sqlserver-se"), None);
+    }
+}
@@ -0,0 +1,157 @@
+// Live tail of a log group
+//
+// `l` on an RDS instance's or a Lambda function's metrics lists the log
+// groups the resource publishes to, and Enter on one follows it. The tail
+// polls FilterLogEvents every couple of seconds from shortly before the
+// latest event seen, since events can land in CloudWatch Logs a little after
+// their timestamp, paging through to the newest event and skipping the ones
+// it already has by event ID. The newest lines are kept for scrolling back
+// through.
+
+use super::client::LogEvent;
+use std::collections::{HashSet, VecDeque};
+use std::time::{Duration, SystemTime};
+
+/// How often a followed log group is polled
+pub const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How far back a tail starts
+pub const BACKFILL: Duration = Duration::from_secs(5 * 60);
+
+/// How far before the latest event each poll reaches, for events ingested late
+const OVERLAP: Duration = Duration::from_secs(30);
+
+/// Lines kept for scrolling back; the oldest are dropped first
+pub const SCROLLBACK: usize = 5000;
+
+/// Events of a tailed log group, oldest first
+#[derive(Debug, Default)]
+pub struct TailBuffer {
+    events: VecDeque<LogEvent>,
+    latest: Option<SystemTime>,
+    recent: HashSet<(SystemTime, String)>, // Timestamps and IDs of events within the overlap of the latest, to skip when polled again
+}
+
+impl TailBuffer {
+    /// Where the next poll starts: shortly before the latest event, else `BACKFILL` ago
+    pub fn poll_from(&self, now: SystemTime) -> SystemTime {
+        match self.latest {
+            Some(latest) => latest - OVERLAP,
+            None => now - BACKFILL,
+        }
+    }
+
+    /// Append the events not seen yet; returns how many were appended
+    pub fn push(&mut self, events: Vec<LogEvent>) -> usize {
+        let mut appended = 0;
+        for event in events {
+            if !self
+                .recent
+                .insert((event.timestamp, event.event_id.clone()))
+            {
+                continue;
+            }
+            self.latest = Some(
+                self.latest
+                    .map_or(event.timestamp, |latest| latest.max(event.timestamp)),
+            );
+            self.events.push_back(event);
+            appended += 1;
+        }
+        if let Some(latest) = self.latest {
+            self.recent
+                .retain(|(timestamp, _)| *timestamp + OVERLAP >= latest);
+        }
+        let excess = self.events.len().saturating_sub(SCROLLBACK);
+        self.events.drain(..excess);
+        appended
+    }
+
+    pub fn events(&self) -> &VecDeque<LogEvent> {
+        &self.events
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+/// Whether an event's message contains `query`, ignoring case
+pub fn matches(event: &LogEvent, query: &str) -> bool {
+    event.message.to_lowercase().contains(&query.to_lowercase())
+}
+
+/// The nearest event matching `query` before `from` (`older`) or after it
+pub fn find_match(
+    events: &VecDeque<LogEvent>,
+    query: &str,
+    from: usize,
+    older: bool,
+) -> Option<usize> {
+    if older {
+        (0..from.min(events.len()))
+            .rev()
+            .find(|&index| matches(&events[index], query))
+    } else {
+        (from + 1..events.len()).find(|&index| matches(&events[index], query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(secs: u64, message: &str) -> LogEvent {
+        LogEvent {
+            event_id: format!("{secs}-{message}"),
+            timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(secs),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_polls_overlap_without_repeating_events() {
+        let mut buffer = TailBuffer::default();
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1000);
+        assert_eq!(buffer.poll_from(now), now - BACKFILL);
+
+        assert_eq!(
+            buffer.push(vec![event(900, "start"), event(960, "ERROR a")]),
+            2
+        );
+        assert_eq!(
+            buffer.poll_from(now),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(930)
+        );
+        // The next poll sees the latest event again, and one ingested late
+        assert_eq!(
+            buffer.push(vec![
+                event(950, "late"),
+                event(960, "ERROR a"),
+                event(970, "error b")
+            ]),
+            2
+        );
+        let messages: Vec<&str> = buffer
+            .events()
+            .iter()
+            .map(|event| event.message.as_str())
+            .collect();
+        assert_eq!(messages, vec!["start", "ERROR a", "late", "error b"]);
+
+        // The same line logged twice in one second is two events
+        let mut repeated = event(980, "retrying");
+        repeated.event_id.push_str("-again");
+        assert_eq!(buffer.push(vec![event(980, "retrying"), repeated]), 2);
+        assert_eq!(buffer.len(), 6);
+
+        assert_eq!(find_match(buffer.events(), "error", 5, true), Some(3));
+        assert_eq!(find_match(buffer.events(), "error", 3, true), Some(1));
+        assert_eq!(find_match(buffer.events(), "error", 1, false), Some(3));
+        assert_eq!(find_match(buffer.events(), "error", 1, true), None);
+    }
+}
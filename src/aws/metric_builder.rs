@@ -10,6 +10,7 @@ use crate::aws::metrics::types::ServiceMetrics;
 use crate::models::{AwsService, MetricData};
 use anyhow::{anyhow, Result};
 use std::any::Any;
+use std::collections::HashMap;
 
 /// Dynamic metric builder that constructs MetricData from various AWS service sources
 ///
@@ -20,7 +21,7 @@ use std::any::Any;
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,ignore
 /// let builder = DynamicMetricBuilder::new();
 /// let metric_data = builder.build_from_service_metrics(service_metrics)?;
 /// ```
@@ -121,6 +122,7 @@ impl Default for DynamicMetricBuilder {
 pub fn build_metric_data(core: CoreMetrics, advanced: AdvancedMetrics) -> MetricData {
     MetricData {
         timestamps: core.timestamps,
+        series_timestamps: HashMap::new(),
         cpu_utilization: core.cpu_utilization,
         cpu_history: core.cpu_history,
         database_connections: core.database_connections,
@@ -194,7 +196,7 @@ pub fn build_metric_data(core: CoreMetrics, advanced: AdvancedMetrics) -> Metric
 /// * `Result<MetricData>` - Transformed legacy format or error
 ///
 /// # Examples
-/// ```rust
+/// ```rust,ignore
 /// let metric_data = build_from_service_metrics(rds_metrics)?;
 /// ```
 pub fn build_from_service_metrics(service_metrics: ServiceMetrics) -> Result<MetricData> {
@@ -205,6 +207,7 @@ pub fn build_from_service_metrics(service_metrics: ServiceMetrics) -> Result<Met
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aws::metrics::generator::{Series, ServiceMetricsBuilder};
     use crate::aws::metrics::types::{MetricValue, ServiceMetrics};
 
     use std::time::SystemTime;
@@ -239,6 +242,25 @@ mod tests {
         assert_eq!(metric_data.database_connections, 10.0);
     }
 
+    #[test]
+    fn test_build_from_generated_series() {
+        let builder = DynamicMetricBuilder::new();
+
+        let cpu = Series::diurnal_cpu(180);
+        let service_metrics = ServiceMetricsBuilder::new(AwsService::Rds)
+            .series("CPUUtilization", cpu.clone())
+            .series("DatabaseConnections", Series::step_change(180, 10.0, 25.0))
+            .series("ReadLatency", Series::spiky_latency(180))
+            .build();
+
+        let metric_data = builder.build_from_service_metrics(service_metrics).unwrap();
+        assert_eq!(metric_data.cpu_utilization, cpu.current());
+        assert_eq!(metric_data.cpu_history, cpu.values);
+        assert_eq!(metric_data.timestamps, cpu.timestamps);
+        assert_eq!(metric_data.database_connections, 25.0);
+        assert_eq!(metric_data.read_latency_history.len(), 180);
+    }
+
     #[test]
     fn test_legacy_build_function_compatibility() {
         // Test that the legacy function still works
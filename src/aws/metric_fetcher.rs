@@ -1,14 +1,127 @@
+use super::coalesce::Coalescer;
+use super::limiter;
 use super::metric_types::MetricFetchParams;
+use super::metrics::retention;
+use super::metrics::types::MetricResolution;
+use aws_config::SdkConfig;
+use aws_sdk_cloudwatch::error::ProvideErrorMetadata;
+use aws_sdk_cloudwatch::types::{DimensionFilter, Metric, RecentlyActive};
 use aws_sdk_cloudwatch::Client as CloudWatchClient;
+use aws_smithy_runtime_api::client::identity::{IdentityCachePartition, ResolveIdentity};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::ops::Deref;
+use std::sync::OnceLock;
 use std::time::SystemTime;
 
+/// How far back the resolution probe looks for sub-minute datapoints
+const RESOLUTION_PROBE_WINDOW_SECS: u64 = 5 * 60;
+
+/// Latest value, history and timestamps of one metric
+pub type MetricSeries = (f64, Vec<f64>, Vec<SystemTime>);
+
+/// CloudWatch metric names whose fetch failed, with the reason
+pub type FailedMetrics = BTreeMap<String, String>;
+
+/// A series request: where it is sent, metric and instance, period and window
+/// length in seconds, and how many minutes before now the window ends
+type SeriesKey = (Location, MetricFetchParams, i32, u64, u64);
+
+/// The region a request goes to and the credentials, and so the account, it is signed with
+///
+/// Instance names repeat across regions and accounts, so requests for the same
+/// name only share a call when both match.
+type Location = (Option<String>, Option<IdentityCachePartition>);
+
+/// A CloudWatch client and the region and credentials its calls are sent with
+///
+/// The SDK client does not expose the credentials it signs with, so they are
+/// read from the config the client is built from.
+#[derive(Clone, Debug)]
+pub struct MetricClient {
+    client: CloudWatchClient,
+    location: Location,
+}
+
+impl MetricClient {
+    pub fn new(config: &SdkConfig, client_config: aws_sdk_cloudwatch::Config) -> Self {
+        let client = CloudWatchClient::from_conf(client_config);
+        let region = client.config().region().map(|region| region.to_string());
+        let credentials = config
+            .credentials_provider()
+            .and_then(|provider| provider.cache_partition());
+        Self {
+            client,
+            location: (region, credentials),
+        }
+    }
+}
+
+impl Deref for MetricClient {
+    type Target = CloudWatchClient;
+
+    fn deref(&self) -> &CloudWatchClient {
+        &self.client
+    }
+}
+
+static IN_FLIGHT: OnceLock<Coalescer<SeriesKey, Result<MetricSeries, String>>> = OnceLock::new();
+
+/// Fetch one metric series; a failed call reads as a series without datapoints
 pub async fn fetch_comprehensive_metric(
+    client: &MetricClient,
+    params: MetricFetchParams,
+    start_time: SystemTime,
+    end_time: SystemTime,
+    period_seconds: i32,
+) -> MetricSeries {
+    try_fetch_metric(client, params, start_time, end_time, period_seconds)
+        .await
+        .unwrap_or_else(|_| (0.0, Vec::new(), Vec::new()))
+}
+
+/// Fetch one metric series, sharing the call with an identical request already in flight
+///
+/// The error is the short reason shown on the metric's tile.
+pub async fn try_fetch_metric(
+    client: &MetricClient,
+    params: MetricFetchParams,
+    start_time: SystemTime,
+    end_time: SystemTime,
+    period_seconds: i32,
+) -> Result<MetricSeries, String> {
+    let window_secs = end_time
+        .duration_since(start_time)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    // Windows shifted into the past (compare mode) must not share a call with the current one
+    let ends_ago_mins = SystemTime::now()
+        .duration_since(end_time)
+        .map(|d| d.as_secs() / 60)
+        .unwrap_or(0);
+    let key = (
+        client.location.clone(),
+        params.clone(),
+        period_seconds,
+        window_secs,
+        ends_ago_mins,
+    );
+    let client = client.client.clone();
+    IN_FLIGHT
+        .get_or_init(Coalescer::new)
+        .run(key, async move {
+            request_metric(&client, params, start_time, end_time, period_seconds).await
+        })
+        .await
+}
+
+async fn request_metric(
     client: &CloudWatchClient,
     params: MetricFetchParams,
     start_time: SystemTime,
     end_time: SystemTime,
     period_seconds: i32,
-) -> (f64, Vec<f64>, Vec<SystemTime>) {
+) -> Result<MetricSeries, String> {
     let mut request = client
         .get_metric_statistics()
         .namespace(&params.namespace)
@@ -40,39 +153,160 @@ pub async fn fetch_comprehensive_metric(
         }
     }
 
-    let resp = request.send().await;
+    let data = limiter::cloudwatch()
+        .call(&params.namespace, || request.send())
+        .await
+        .map_err(|e| failure_reason(&e))?;
 
-    match resp {
-        Ok(data) => {
-            if let Some(mut datapoints) = data.datapoints {
-                datapoints.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
-
-                let latest_value = datapoints.last().and_then(|dp| dp.average).unwrap_or(0.0);
-
-                let recent_datapoints: Vec<_> = datapoints.iter().rev().take(36).rev().collect();
-
-                let history: Vec<f64> = recent_datapoints
-                    .iter()
-                    .filter_map(|dp| dp.average)
-                    .collect();
-
-                let timestamps: Vec<SystemTime> = recent_datapoints
-                    .iter()
-                    .map(|dp| {
-                        dp.timestamp
-                            .map(|ts| {
-                                SystemTime::UNIX_EPOCH
-                                    + std::time::Duration::from_secs(ts.secs() as u64)
-                            })
-                            .unwrap_or_else(SystemTime::now)
+    if let Some(mut datapoints) = data.datapoints {
+        datapoints.sort_by_key(|dp| dp.timestamp);
+
+        let latest_value = datapoints.last().and_then(|dp| dp.average).unwrap_or(0.0);
+
+        let history: Vec<f64> = datapoints.iter().filter_map(|dp| dp.average).collect();
+
+        let timestamps: Vec<SystemTime> = datapoints
+            .iter()
+            .map(|dp| {
+                dp.timestamp
+                    .map(|ts| {
+                        SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(ts.secs() as u64)
                     })
-                    .collect();
+                    .unwrap_or_else(SystemTime::now)
+            })
+            .collect();
 
-                (latest_value, history, timestamps)
-            } else {
-                (0.0, Vec::new(), Vec::new())
-            }
+        // Long ranges keep their older datapoints as a coarser min/max envelope
+        let (history, timestamps) = retention::cap_series(history, timestamps);
+        Ok((latest_value, history, timestamps))
+    } else {
+        Ok((0.0, Vec::new(), Vec::new()))
+    }
+}
+
+/// The error to show instead of the metrics when all `attempted` fetches failed
+///
+/// Anything less is shown as the metrics that loaded plus error tiles.
+pub fn every_metric_failed(failures: &FailedMetrics, attempted: usize) -> Option<anyhow::Error> {
+    let (_, reason) = failures.iter().next()?;
+    (failures.len() >= attempted).then(|| anyhow::anyhow!("Every metric failed to load ({reason})"))
+}
+
+/// Short reason a CloudWatch call failed: the error code (e.g. "Throttling") when AWS sent one
+pub fn failure_reason<E: ProvideErrorMetadata + Display>(error: &E) -> String {
+    error
+        .code()
+        .map_or_else(|| error.to_string(), str::to_string)
+}
+
+/// Custom metrics published with an instance's `DBInstanceIdentifier` dimension
+///
+/// Only metrics with datapoints in the last three hours are listed, and AWS
+/// namespaces are skipped: AWS/RDS and the other service namespaces only ever
+/// store one-minute datapoints.
+pub async fn custom_instance_metrics(client: &CloudWatchClient, instance_id: &str) -> Vec<Metric> {
+    let request = client
+        .list_metrics()
+        .dimensions(
+            DimensionFilter::builder()
+                .name("DBInstanceIdentifier")
+                .value(instance_id)
+                .build(),
+        )
+        .recently_active(RecentlyActive::Pt3H);
+    match limiter::cloudwatch()
+        .call("custom", || request.send())
+        .await
+    {
+        Ok(resp) => resp
+            .metrics
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|metric| {
+                metric
+                    .namespace()
+                    .is_some_and(|namespace| !namespace.starts_with("AWS/"))
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Probe whether a metric is published at high resolution
+///
+/// Requests the last few minutes at a 1-second period; standard-resolution
+/// metrics only ever return one datapoint per minute, so any sub-minute
+/// spacing means the metric was published with StorageResolution=1.
+pub async fn probe_metric_resolution(
+    client: &CloudWatchClient,
+    metric: &Metric,
+) -> MetricResolution {
+    let end_time = SystemTime::now();
+    let start_time = end_time - std::time::Duration::from_secs(RESOLUTION_PROBE_WINDOW_SECS);
+    let namespace = metric.namespace().unwrap_or_default();
+
+    let request = client
+        .get_metric_statistics()
+        .namespace(namespace)
+        .set_metric_name(metric.metric_name().map(str::to_string))
+        .set_dimensions(metric.dimensions.clone())
+        .start_time(aws_sdk_cloudwatch::primitives::DateTime::from(start_time))
+        .end_time(aws_sdk_cloudwatch::primitives::DateTime::from(end_time))
+        .period(1)
+        .statistics(aws_sdk_cloudwatch::types::Statistic::SampleCount);
+    let resp = limiter::cloudwatch()
+        .call(namespace, || request.send())
+        .await;
+
+    match resp {
+        Ok(data) => {
+            let mut timestamps: Vec<SystemTime> = data
+                .datapoints
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|dp| dp.timestamp)
+                .map(|ts| SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(ts.secs() as u64))
+                .collect();
+            timestamps.sort();
+            MetricResolution::from_timestamps(&timestamps)
         }
-        Err(_) => (0.0, Vec::new(), Vec::new()),
+        Err(_) => MetricResolution::Standard,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_cloudwatch::config::{
+        BehaviorVersion, Credentials, Region, SharedCredentialsProvider,
+    };
+
+    fn session(access_key: &str, region: &'static str) -> SdkConfig {
+        SdkConfig::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::from_static(region))
+            .credentials_provider(SharedCredentialsProvider::new(Credentials::new(
+                access_key, "secret", None, None, "test",
+            )))
+            .build()
+    }
+
+    fn client(config: &SdkConfig) -> MetricClient {
+        MetricClient::new(
+            config,
+            aws_sdk_cloudwatch::config::Builder::from(config).build(),
+        )
+    }
+
+    #[test]
+    fn test_calls_are_shared_only_within_a_region_and_credentials() {
+        let production = session("AKIAPRODUCTION", "eu-west-1");
+        assert_eq!(client(&production).location, client(&production).location);
+        assert_eq!(client(&production).location.0.as_deref(), Some("eu-west-1"));
+
+        let staging = session("AKIASTAGING", "eu-west-1");
+        assert_ne!(client(&production).location, client(&staging).location);
+        let elsewhere = session("AKIAPRODUCTION", "us-east-1");
+        assert_ne!(client(&production).location, client(&elsewhere).location);
     }
 }
@@ -1,6 +1,7 @@
 use std::time::SystemTime;
 
 // Parameter struct to reduce function argument count
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MetricFetchParams {
     pub metric_name: String,
     pub namespace: String,
@@ -1,6 +1,9 @@
 //! Factory for creating and managing metric service providers
 
-use crate::aws::metrics::providers::{MetricProvider, RdsMetricProvider};
+use crate::aws::metrics::providers::{
+    BatchMetricProvider, EventBridgeMetricProvider, LambdaMetricProvider, MetricProvider,
+    RdsMetricProvider, SqsMetricProvider, SyntheticsMetricProvider,
+};
 use crate::models::AwsService;
 use anyhow::{anyhow, Result};
 use std::collections::HashMap;
@@ -22,6 +25,17 @@ impl MetricServiceFactory {
 
         // Register default providers
         factory.register_provider(AwsService::Rds, Box::new(RdsMetricProvider::new()));
+        factory.register_provider(AwsService::Sqs, Box::new(SqsMetricProvider::new()));
+        factory.register_provider(
+            AwsService::Synthetics,
+            Box::new(SyntheticsMetricProvider::new()),
+        );
+        factory.register_provider(
+            AwsService::EventBridge,
+            Box::new(EventBridgeMetricProvider::new()),
+        );
+        factory.register_provider(AwsService::Batch, Box::new(BatchMetricProvider::new()));
+        factory.register_provider(AwsService::Lambda, Box::new(LambdaMetricProvider::new()));
 
         factory
     }
@@ -73,6 +87,11 @@ mod tests {
     fn test_factory_creation() {
         let factory = MetricServiceFactory::new();
         assert!(factory.has_provider(&AwsService::Rds));
+        assert!(factory.has_provider(&AwsService::Sqs));
+        assert!(factory.has_provider(&AwsService::Synthetics));
+        assert!(factory.has_provider(&AwsService::EventBridge));
+        assert!(factory.has_provider(&AwsService::Batch));
+        assert!(factory.has_provider(&AwsService::Lambda));
     }
 
     #[test]
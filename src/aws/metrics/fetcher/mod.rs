@@ -1,3 +1,182 @@
 //! Universal metric fetching logic
 
-// Re-export the main fetcher
+use crate::aws::limiter;
+use crate::aws::metric_fetcher::{failure_reason, FailedMetrics};
+use crate::aws::metrics::providers::MetricProvider;
+use crate::aws::metrics::retention;
+use crate::aws::metrics::types::{MetricDefinition, MetricValue, ServiceMetrics, StatisticType};
+use crate::aws::session::AwsSessionManager;
+use crate::aws::time_range::{calculate_period_seconds, TimeRange};
+use anyhow::{anyhow, Result};
+use aws_sdk_cloudwatch::types::{Dimension, Statistic};
+use aws_sdk_cloudwatch::Client as CloudWatchClient;
+use std::time::SystemTime;
+
+/// Fetch every metric a provider defines for a single resource
+///
+/// `resource_id` is mapped to the provider's dimension via the generic
+/// `instance_id` key (e.g. `QueueName` for SQS). Metrics without datapoints
+/// are left out of the result; those whose call failed are returned with the
+/// reason instead.
+pub async fn fetch_service_metrics(
+    provider: &dyn MetricProvider,
+    resource_id: &str,
+    time_range: TimeRange,
+) -> Result<(ServiceMetrics, FailedMetrics)> {
+    let definitions = provider.get_metrics_config();
+    let mut service_metrics = ServiceMetrics::new(provider.get_service_type());
+    let failures = fetch_into(
+        &mut service_metrics,
+        provider,
+        &definitions,
+        resource_id,
+        time_range,
+    )
+    .await?;
+    Ok((service_metrics, failures))
+}
+
+/// Refetch only the metrics named in `names` into `service_metrics`
+///
+/// The other series are kept as loaded. Returns the ones that failed.
+pub async fn refetch_service_metrics(
+    service_metrics: &mut ServiceMetrics,
+    provider: &dyn MetricProvider,
+    names: &[String],
+    resource_id: &str,
+    time_range: TimeRange,
+) -> Result<FailedMetrics> {
+    let definitions: Vec<_> = provider
+        .get_metrics_config()
+        .into_iter()
+        .filter(|definition| names.contains(&definition.name))
+        .collect();
+    fetch_into(
+        service_metrics,
+        provider,
+        &definitions,
+        resource_id,
+        time_range,
+    )
+    .await
+}
+
+async fn fetch_into(
+    service_metrics: &mut ServiceMetrics,
+    provider: &dyn MetricProvider,
+    definitions: &[MetricDefinition],
+    resource_id: &str,
+    time_range: TimeRange,
+) -> Result<FailedMetrics> {
+    let client = AwsSessionManager::cloudwatch_client().await;
+    let dimensions = sdk_dimensions(provider, resource_id)?;
+
+    let end_time = SystemTime::now();
+    let start_time = end_time - time_range.duration();
+    let period_seconds = calculate_period_seconds(&time_range);
+    let namespace = provider.get_service_namespace();
+
+    let results = futures::future::join_all(definitions.iter().map(|definition| {
+        fetch_metric_series(
+            &client,
+            namespace,
+            definition,
+            dimensions.clone(),
+            start_time,
+            end_time,
+            period_seconds,
+        )
+    }))
+    .await;
+
+    let mut failures = FailedMetrics::new();
+    for (definition, result) in definitions.iter().zip(results) {
+        let (history, timestamps) = match result {
+            Ok(series) => series,
+            Err(reason) => {
+                failures.insert(definition.name.clone(), reason);
+                continue;
+            }
+        };
+        if history.is_empty() {
+            continue;
+        }
+        // Long ranges keep their older datapoints as a coarser min/max envelope
+        let (history, timestamps) = retention::cap_series(history, timestamps);
+        if service_metrics.timestamps.len() < timestamps.len() {
+            service_metrics.timestamps = timestamps.clone();
+        }
+        service_metrics
+            .series_timestamps
+            .insert(definition.name.clone(), timestamps);
+        let current = history.last().copied().unwrap_or(0.0);
+        service_metrics.add_metric(definition.name.clone(), MetricValue::new(current, history));
+    }
+
+    Ok(failures)
+}
+
+/// The provider's dimensions for `resource_id` as CloudWatch request dimensions
+pub fn sdk_dimensions(provider: &dyn MetricProvider, resource_id: &str) -> Result<Vec<Dimension>> {
+    let dimensions = provider.resource_dimensions(resource_id);
+    if dimensions.is_empty() {
+        return Err(anyhow!("Provider has no instance_id dimension mapping"));
+    }
+    Ok(dimensions
+        .into_iter()
+        .map(|(name, value)| Dimension::builder().name(name).value(value).build())
+        .collect())
+}
+
+/// Fetch one metric's datapoints in chronological order using its configured statistic
+///
+/// The error is the short reason the call failed.
+pub async fn fetch_metric_series(
+    client: &CloudWatchClient,
+    namespace: &str,
+    definition: &MetricDefinition,
+    dimensions: Vec<Dimension>,
+    start_time: SystemTime,
+    end_time: SystemTime,
+    period_seconds: i32,
+) -> Result<(Vec<f64>, Vec<SystemTime>), String> {
+    let statistic = match definition.statistic {
+        StatisticType::Average => Statistic::Average,
+        StatisticType::Sum => Statistic::Sum,
+        StatisticType::Maximum => Statistic::Maximum,
+        StatisticType::Minimum => Statistic::Minimum,
+    };
+
+    let request = client
+        .get_metric_statistics()
+        .namespace(namespace)
+        .metric_name(&definition.name)
+        .set_dimensions(Some(dimensions))
+        .start_time(aws_sdk_cloudwatch::primitives::DateTime::from(start_time))
+        .end_time(aws_sdk_cloudwatch::primitives::DateTime::from(end_time))
+        .period(period_seconds)
+        .statistics(statistic);
+    let data = limiter::cloudwatch()
+        .call(namespace, || request.send())
+        .await
+        .map_err(|e| failure_reason(&e))?;
+
+    let mut datapoints = data.datapoints.unwrap_or_default();
+    datapoints.sort_by_key(|dp| dp.timestamp);
+
+    Ok(datapoints
+        .iter()
+        .filter_map(|dp| {
+            let value = match definition.statistic {
+                StatisticType::Average => dp.average,
+                StatisticType::Sum => dp.sum,
+                StatisticType::Maximum => dp.maximum,
+                StatisticType::Minimum => dp.minimum,
+            }?;
+            let timestamp = dp.timestamp.map(|ts| {
+                SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(ts.secs() as u64)
+            })?;
+            Some((value, timestamp))
+        })
+        .unzip())
+}
@@ -0,0 +1,287 @@
+//! Realistic metric series for tests, benchmarks and demo data
+//!
+//! Hand-written series of three values exercise little of the charts and
+//! analytics. `SeriesBuilder` layers the shapes real metrics have on a
+//! baseline: a daily cycle, noise, occasional spikes, step changes where a
+//! deploy or failover moved the level, and gaps where no datapoint was
+//! published. Noise and spikes come from a seeded generator, so a series is
+//! the same on every run. `ServiceMetricsBuilder` puts series together the
+//! way the fetcher returns them.
+
+use super::types::{MetricValue, ServiceMetrics};
+use crate::models::AwsService;
+use std::f64::consts::TAU;
+use std::ops::Range;
+use std::time::{Duration, SystemTime};
+
+/// 2023-11-14 22:13:20 UTC, a fixed start so series do not depend on the clock
+const DEFAULT_START_SECS: u64 = 1_700_000_000;
+const DAY_SECS: f64 = 86400.0;
+
+/// Datapoints and their times, oldest first
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    pub timestamps: Vec<SystemTime>,
+    pub values: Vec<f64>,
+}
+
+impl Series {
+    /// CPU percent following the working day: ~25% at night, ~65% mid-afternoon
+    pub fn diurnal_cpu(points: usize) -> Self {
+        SeriesBuilder::new(points)
+            .baseline(45.0)
+            .diurnal(20.0)
+            .noise(0.05)
+            .clamp(0.0, 100.0)
+            .build()
+    }
+
+    /// Latency in seconds: ~5 ms with one datapoint in twenty at ten times that
+    pub fn spiky_latency(points: usize) -> Self {
+        SeriesBuilder::new(points)
+            .baseline(0.005)
+            .noise(0.1)
+            .spikes(0.05, 10.0)
+            .clamp(0.0, f64::MAX)
+            .build()
+    }
+
+    /// A level that moves from `before` to `after` half way through
+    pub fn step_change(points: usize, before: f64, after: f64) -> Self {
+        SeriesBuilder::new(points)
+            .baseline(before)
+            .step(points / 2, after - before)
+            .build()
+    }
+
+    pub fn current(&self) -> f64 {
+        self.values.last().copied().unwrap_or(0.0)
+    }
+}
+
+/// Shapes layered onto a baseline, applied in the order below regardless of call order
+#[derive(Debug, Clone)]
+pub struct SeriesBuilder {
+    points: usize,
+    start: SystemTime,
+    period: Duration,
+    seed: u64,
+    baseline: f64,
+    diurnal: f64,               // Amplitude of the daily cycle, peaking at 15:00 UTC
+    noise: f64,                 // Standard deviation as a fraction of the value
+    spikes: Option<(f64, f64)>, // Chance per datapoint and the factor spikes multiply by
+    steps: Vec<(usize, f64)>,   // From datapoint index, added to the level
+    gaps: Vec<Range<usize>>,    // Datapoints dropped, as CloudWatch leaves them out
+    clamp: Option<(f64, f64)>,
+}
+
+impl SeriesBuilder {
+    /// `points` one-minute datapoints of a flat series at zero
+    pub fn new(points: usize) -> Self {
+        Self {
+            points,
+            start: SystemTime::UNIX_EPOCH + Duration::from_secs(DEFAULT_START_SECS),
+            period: Duration::from_secs(60),
+            seed: 1,
+            baseline: 0.0,
+            diurnal: 0.0,
+            noise: 0.0,
+            spikes: None,
+            steps: Vec::new(),
+            gaps: Vec::new(),
+            clamp: None,
+        }
+    }
+
+    pub fn start(mut self, start: SystemTime) -> Self {
+        self.start = start;
+        self
+    }
+
+    pub fn period_secs(mut self, secs: u64) -> Self {
+        self.period = Duration::from_secs(secs);
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn baseline(mut self, value: f64) -> Self {
+        self.baseline = value;
+        self
+    }
+
+    pub fn diurnal(mut self, amplitude: f64) -> Self {
+        self.diurnal = amplitude;
+        self
+    }
+
+    pub fn noise(mut self, fraction: f64) -> Self {
+        self.noise = fraction;
+        self
+    }
+
+    pub fn spikes(mut self, chance: f64, factor: f64) -> Self {
+        self.spikes = Some((chance, factor));
+        self
+    }
+
+    pub fn step(mut self, at: usize, delta: f64) -> Self {
+        self.steps.push((at, delta));
+        self
+    }
+
+    pub fn gap(mut self, range: Range<usize>) -> Self {
+        self.gaps.push(range);
+        self
+    }
+
+    pub fn clamp(mut self, min: f64, max: f64) -> Self {
+        self.clamp = Some((min, max));
+        self
+    }
+
+    pub fn build(&self) -> Series {
+        let mut rng = SplitMix(self.seed);
+        let mut series = Series {
+            timestamps: Vec::with_capacity(self.points),
+            values: Vec::with_capacity(self.points),
+        };
+        for i in 0..self.points {
+            let at = self.start + self.period * i as u32;
+            // Drawn for every datapoint, so a gap leaves the rest of the series as it was
+            let (gaussian, roll) = (rng.gaussian(), rng.unit());
+            if self.gaps.iter().any(|gap| gap.contains(&i)) {
+                continue;
+            }
+
+            let mut value = self.baseline
+                + self
+                    .steps
+                    .iter()
+                    .filter(|(from, _)| i >= *from)
+                    .map(|(_, delta)| delta)
+                    .sum::<f64>();
+            if self.diurnal != 0.0 {
+                value += self.diurnal * day_phase(at);
+            }
+            value *= 1.0 + self.noise * gaussian;
+            if let Some((chance, factor)) = self.spikes {
+                if roll < chance {
+                    value *= factor;
+                }
+            }
+            if let Some((min, max)) = self.clamp {
+                value = value.clamp(min, max);
+            }
+            series.timestamps.push(at);
+            series.values.push(value);
+        }
+        series
+    }
+}
+
+/// -1 at 03:00 UTC rising to 1 at 15:00 UTC
+fn day_phase(at: SystemTime) -> f64 {
+    let secs = at
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0.0, |since| since.as_secs_f64());
+    let hours_from_peak = (secs % DAY_SECS) / 3600.0 - 15.0;
+    (TAU * hours_from_peak / 24.0).cos()
+}
+
+/// Seeded source of noise (SplitMix64)
+struct SplitMix(u64);
+
+impl SplitMix {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1)
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Standard normal, by the Box-Muller transform
+    fn gaussian(&mut self) -> f64 {
+        let u = self.unit().max(f64::MIN_POSITIVE);
+        let v = self.unit();
+        (-2.0 * u.ln()).sqrt() * (TAU * v).cos()
+    }
+}
+
+/// A service's metrics as the fetcher returns them, the timestamps those of the first series
+pub struct ServiceMetricsBuilder {
+    metrics: ServiceMetrics,
+}
+
+impl ServiceMetricsBuilder {
+    pub fn new(service: AwsService) -> Self {
+        Self {
+            metrics: ServiceMetrics::new(service),
+        }
+    }
+
+    pub fn series(mut self, name: &str, series: Series) -> Self {
+        if self.metrics.timestamps.is_empty() {
+            self.metrics.timestamps = series.timestamps.clone();
+        }
+        let value = MetricValue::new(series.current(), series.values);
+        self.metrics.raw_metrics.insert(name.to_string(), value);
+        self.metrics
+            .series_timestamps
+            .insert(name.to_string(), series.timestamps);
+        self
+    }
+
+    pub fn build(self) -> ServiceMetrics {
+        self.metrics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_series_shapes() {
+        // A day of five-minute CPU datapoints, starting at midnight UTC
+        let midnight = SystemTime::UNIX_EPOCH + Duration::from_secs(19_675 * 86400);
+        let day = SeriesBuilder::new(288)
+            .start(midnight)
+            .period_secs(300)
+            .baseline(45.0)
+            .diurnal(20.0)
+            .noise(0.02);
+        let cpu = day.build();
+        let hour = |h: usize| cpu.values[h * 12];
+        assert!(hour(15) > 60.0 && hour(3) < 30.0);
+        // The same seed gives the same noise
+        assert_eq!(cpu, day.build());
+        assert_ne!(cpu, day.clone().seed(2).build());
+
+        let latency = Series::spiky_latency(1000);
+        let spikes = latency.values.iter().filter(|v| **v > 0.025).count();
+        assert!((20..=80).contains(&spikes));
+
+        let step = Series::step_change(10, 100.0, 40.0);
+        assert_eq!(step.values[4], 100.0);
+        assert_eq!(step.current(), 40.0);
+
+        let gapped = SeriesBuilder::new(10).baseline(1.0).gap(3..5).build();
+        assert_eq!(gapped.values.len(), 8);
+        assert_eq!(
+            gapped.timestamps[3]
+                .duration_since(gapped.timestamps[2])
+                .ok(),
+            Some(Duration::from_secs(180))
+        );
+    }
+}
@@ -6,8 +6,14 @@
 
 pub mod factory;
 pub mod fetcher;
+pub mod generator;
+pub mod percentiles;
 pub mod providers;
+pub mod registry;
+pub mod retention;
+pub mod staleness;
 pub mod types;
+pub mod widget_image;
 
 // Re-export commonly used types
 // Types are imported directly where needed
@@ -0,0 +1,186 @@
+//! Percentile statistics of RDS metrics
+//!
+//! Averages hide the slow requests that matter most on latency charts.
+//! Metrics the registry gives percentiles are fetched with every statistic of
+//! every such metric in one GetMetricData call, then lined up by timestamp.
+
+use super::registry::{MetricRegistry, Percentiles};
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::limiter;
+use crate::aws::session::AwsSessionManager;
+use crate::models::MetricType;
+use anyhow::Result;
+use aws_sdk_cloudwatch::primitives::DateTime;
+use aws_sdk_cloudwatch::types::{Dimension, Metric, MetricDataQuery, MetricStat, ScanBy};
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, SystemTime};
+
+/// A metric's percentile statistics over shared timestamps; NaN where one had no datapoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct PercentileSeries {
+    pub percentiles: Percentiles,
+    pub timestamps: Vec<SystemTime>,
+    pub line: Vec<f64>,
+    pub low: Vec<f64>,
+    pub high: Vec<f64>,
+}
+
+/// Percentile series of every metric the registry gives percentiles, by CloudWatch metric name
+pub async fn fetch_percentiles(
+    instance_id: &str,
+    start_time: SystemTime,
+    end_time: SystemTime,
+    period_seconds: i32,
+) -> Result<HashMap<&'static str, PercentileSeries>> {
+    let metrics: Vec<(MetricType, Percentiles)> = MetricType::ALL
+        .into_iter()
+        .filter_map(|metric| MetricRegistry::percentiles(&metric).map(|p| (metric, p)))
+        .collect();
+    let queries: Vec<MetricDataQuery> = metrics
+        .iter()
+        .enumerate()
+        .flat_map(|(index, (metric, percentiles))| {
+            percentiles
+                .statistics()
+                .into_iter()
+                .enumerate()
+                .map(move |(stat_index, stat)| {
+                    MetricDataQuery::builder()
+                        .id(query_id(index, stat_index))
+                        .metric_stat(
+                            MetricStat::builder()
+                                .metric(
+                                    Metric::builder()
+                                        .namespace("AWS/RDS")
+                                        .metric_name(metric.metric_name())
+                                        .dimensions(
+                                            Dimension::builder()
+                                                .name("DBInstanceIdentifier")
+                                                .value(instance_id)
+                                                .build(),
+                                        )
+                                        .build(),
+                                )
+                                .period(period_seconds)
+                                .stat(stat)
+                                .build(),
+                        )
+                        .return_data(true)
+                        .build()
+                })
+        })
+        .collect();
+
+    let client = AwsSessionManager::cloudwatch_client().await;
+    let mut datapoints: HashMap<String, Vec<(i64, f64)>> = HashMap::new();
+    let mut next_token: Option<String> = None;
+    loop {
+        let request = client
+            .get_metric_data()
+            .set_metric_data_queries(Some(queries.clone()))
+            .start_time(DateTime::from(start_time))
+            .end_time(DateTime::from(end_time))
+            .scan_by(ScanBy::TimestampAscending)
+            .set_next_token(next_token.take());
+        let response = limiter::cloudwatch()
+            .call("AWS/RDS", || request.send())
+            .await
+            .map_err(|e| {
+                AwsErrorHandler::handle_aws_error(
+                    e,
+                    "fetch percentile statistics",
+                    "cloudwatch:GetMetricData",
+                )
+            })?;
+        for result in response.metric_data_results() {
+            let Some(id) = result.id() else {
+                continue;
+            };
+            datapoints.entry(id.to_string()).or_default().extend(
+                result
+                    .timestamps()
+                    .iter()
+                    .map(DateTime::secs)
+                    .zip(result.values().iter().copied()),
+            );
+        }
+        next_token = response.next_token().map(str::to_string);
+        if next_token.is_none() {
+            break;
+        }
+    }
+
+    Ok(metrics
+        .iter()
+        .enumerate()
+        .map(|(index, (metric, percentiles))| {
+            let columns = [0, 1, 2].map(|stat_index| {
+                datapoints
+                    .get(&query_id(index, stat_index))
+                    .map(Vec::as_slice)
+                    .unwrap_or_default()
+            });
+            (metric.metric_name(), line_up(*percentiles, columns))
+        })
+        .collect())
+}
+
+/// Query IDs must start with a lowercase letter and be unique within the call
+fn query_id(metric_index: usize, stat_index: usize) -> String {
+    format!("m{metric_index}_{stat_index}")
+}
+
+/// Line, low and high datapoints on the timestamps any of them has
+fn line_up(percentiles: Percentiles, columns: [&[(i64, f64)]; 3]) -> PercentileSeries {
+    let mut rows: BTreeMap<i64, [f64; 3]> = BTreeMap::new();
+    for (column, datapoints) in columns.iter().enumerate() {
+        for &(secs, value) in *datapoints {
+            rows.entry(secs).or_insert([f64::NAN; 3])[column] = value;
+        }
+    }
+    let mut series = PercentileSeries {
+        percentiles,
+        timestamps: Vec::with_capacity(rows.len()),
+        line: Vec::with_capacity(rows.len()),
+        low: Vec::with_capacity(rows.len()),
+        high: Vec::with_capacity(rows.len()),
+    };
+    for (secs, [line, low, high]) in rows {
+        series
+            .timestamps
+            .push(SystemTime::UNIX_EPOCH + Duration::from_secs(secs.max(0) as u64));
+        series.line.push(line);
+        series.low.push(low);
+        series.high.push(high);
+    }
+    series
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_statistics_are_lined_up_by_timestamp() {
+        let percentiles = MetricRegistry::percentiles(&MetricType::ReadLatency).unwrap();
+        assert_eq!(percentiles.statistics(), ["p50", "p90", "p99"]);
+        assert!(MetricRegistry::percentiles(&MetricType::CpuUtilization).is_none());
+
+        let p50 = [(60, 0.002), (120, 0.003)];
+        let p90 = [(60, 0.010), (120, 0.012)];
+        // p99 is missing a period and has one the others do not
+        let p99 = [(60, 0.050), (180, 0.090)];
+        let series = line_up(percentiles, [&p50, &p90, &p99]);
+
+        assert_eq!(series.timestamps.len(), 3);
+        assert_eq!(
+            series.timestamps[0],
+            SystemTime::UNIX_EPOCH + Duration::from_secs(60)
+        );
+        assert_eq!(series.line[..2], [0.002, 0.003]);
+        assert!(series.line[2].is_nan());
+        assert!(series.high[1].is_nan());
+        assert_eq!(series.high[2], 0.090);
+        assert_eq!(query_id(1, 2), "m1_2");
+    }
+}
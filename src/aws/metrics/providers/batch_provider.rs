@@ -0,0 +1,64 @@
+//! Batch-specific metric provider implementation
+//!
+//! AWS Batch publishes no job queue metrics of its own; compute environments
+//! run their jobs on an ECS cluster, so their vCPU usage is read from that
+//! cluster's `AWS/ECS` metrics. The resource id is the cluster name.
+
+use super::MetricProvider;
+use crate::aws::metrics::types::{MetricCategory, MetricDefinition, ServiceMetrics, StatisticType};
+use crate::models::AwsService;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// Batch compute environment metric provider that implements the MetricProvider trait
+pub struct BatchMetricProvider;
+
+impl MetricProvider for BatchMetricProvider {
+    fn get_service_namespace(&self) -> &'static str {
+        "AWS/ECS"
+    }
+
+    fn get_metrics_config(&self) -> Vec<MetricDefinition> {
+        let percent = |name: &str, category| MetricDefinition {
+            name: name.to_string(),
+            unit: Some("Percent".to_string()),
+            statistic: StatisticType::Average,
+            category,
+        };
+        vec![
+            // Share of the registered vCPUs and memory reserved by running jobs
+            percent("CPUReservation", MetricCategory::Core),
+            percent("MemoryReservation", MetricCategory::Core),
+            // What the running jobs actually use
+            percent("CPUUtilization", MetricCategory::Performance),
+            percent("MemoryUtilization", MetricCategory::Performance),
+        ]
+    }
+
+    fn get_dimension_mappings(&self) -> HashMap<String, String> {
+        let mut map = HashMap::new();
+        map.insert("instance_id".to_string(), "ClusterName".to_string());
+        map
+    }
+
+    fn transform_raw_data(&self, data: ServiceMetrics) -> Box<dyn Any> {
+        // Batch has no legacy representation; the raw metrics are used directly
+        Box::new(data)
+    }
+
+    fn get_service_type(&self) -> AwsService {
+        AwsService::Batch
+    }
+}
+
+impl Default for BatchMetricProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BatchMetricProvider {
+    pub fn new() -> Self {
+        Self
+    }
+}
@@ -0,0 +1,78 @@
+//! EventBridge-specific metric provider implementation
+
+use super::MetricProvider;
+use crate::aws::eventbridge::split_rule_id;
+use crate::aws::metrics::types::{MetricCategory, MetricDefinition, ServiceMetrics, StatisticType};
+use crate::models::AwsService;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// EventBridge rule metric provider that implements the MetricProvider trait
+pub struct EventBridgeMetricProvider;
+
+impl MetricProvider for EventBridgeMetricProvider {
+    fn get_service_namespace(&self) -> &'static str {
+        "AWS/Events"
+    }
+
+    fn get_metrics_config(&self) -> Vec<MetricDefinition> {
+        let count = |name: &str, category| MetricDefinition {
+            name: name.to_string(),
+            unit: Some("Count".to_string()),
+            statistic: StatisticType::Sum,
+            category,
+        };
+        vec![
+            // Delivery to targets
+            count("TriggeredRules", MetricCategory::Core),
+            count("Invocations", MetricCategory::Core),
+            count("FailedInvocations", MetricCategory::Core),
+            count("ThrottledRules", MetricCategory::Core),
+            // Dead-letter queue handling of failed deliveries
+            count("InvocationsSentToDlq", MetricCategory::Performance),
+            count(
+                "InvocationsFailedToBeSentToDlq",
+                MetricCategory::Performance,
+            ),
+        ]
+    }
+
+    fn get_dimension_mappings(&self) -> HashMap<String, String> {
+        let mut map = HashMap::new();
+        map.insert("instance_id".to_string(), "RuleName".to_string());
+        map.insert("event_bus".to_string(), "EventBusName".to_string());
+        map
+    }
+
+    /// Rules on custom buses publish with both the bus and rule name
+    fn resource_dimensions(&self, resource_id: &str) -> Vec<(String, String)> {
+        let (bus, rule) = split_rule_id(resource_id);
+        let mut dimensions = Vec::new();
+        if let Some(bus) = bus {
+            dimensions.push(("EventBusName".to_string(), bus.to_string()));
+        }
+        dimensions.push(("RuleName".to_string(), rule.to_string()));
+        dimensions
+    }
+
+    fn transform_raw_data(&self, data: ServiceMetrics) -> Box<dyn Any> {
+        // EventBridge has no legacy representation; the raw metrics are used directly
+        Box::new(data)
+    }
+
+    fn get_service_type(&self) -> AwsService {
+        AwsService::EventBridge
+    }
+}
+
+impl Default for EventBridgeMetricProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBridgeMetricProvider {
+    pub fn new() -> Self {
+        Self
+    }
+}
@@ -0,0 +1,81 @@
+//! Lambda-specific metric provider implementation
+
+use super::MetricProvider;
+use crate::aws::metrics::types::{MetricCategory, MetricDefinition, ServiceMetrics, StatisticType};
+use crate::models::AwsService;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// Lambda function metric provider that implements the MetricProvider trait
+pub struct LambdaMetricProvider;
+
+impl MetricProvider for LambdaMetricProvider {
+    fn get_service_namespace(&self) -> &'static str {
+        "AWS/Lambda"
+    }
+
+    fn get_metrics_config(&self) -> Vec<MetricDefinition> {
+        let metric = |name: &str, unit: &str, statistic, category| MetricDefinition {
+            name: name.to_string(),
+            unit: Some(unit.to_string()),
+            statistic,
+            category,
+        };
+        vec![
+            // Invocations and how they end
+            metric(
+                "Invocations",
+                "Count",
+                StatisticType::Sum,
+                MetricCategory::Core,
+            ),
+            metric("Errors", "Count", StatisticType::Sum, MetricCategory::Core),
+            metric(
+                "Throttles",
+                "Count",
+                StatisticType::Sum,
+                MetricCategory::Core,
+            ),
+            // How long and how many at once
+            metric(
+                "Duration",
+                "Milliseconds",
+                StatisticType::Average,
+                MetricCategory::Performance,
+            ),
+            metric(
+                "ConcurrentExecutions",
+                "Count",
+                StatisticType::Maximum,
+                MetricCategory::Performance,
+            ),
+        ]
+    }
+
+    fn get_dimension_mappings(&self) -> HashMap<String, String> {
+        let mut map = HashMap::new();
+        map.insert("instance_id".to_string(), "FunctionName".to_string());
+        map
+    }
+
+    fn transform_raw_data(&self, data: ServiceMetrics) -> Box<dyn Any> {
+        // Lambda has no legacy representation; the raw metrics are used directly
+        Box::new(data)
+    }
+
+    fn get_service_type(&self) -> AwsService {
+        AwsService::Lambda
+    }
+}
+
+impl Default for LambdaMetricProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LambdaMetricProvider {
+    pub fn new() -> Self {
+        Self
+    }
+}
@@ -5,10 +5,20 @@ use crate::models::AwsService;
 use std::any::Any;
 use std::collections::HashMap;
 
+pub mod batch_provider;
+pub mod eventbridge_provider;
+pub mod lambda_provider;
 pub mod rds_provider;
+pub mod sqs_provider;
+pub mod synthetics_provider;
 
 // Re-export the provider implementations
+pub use batch_provider::BatchMetricProvider;
+pub use eventbridge_provider::EventBridgeMetricProvider;
+pub use lambda_provider::LambdaMetricProvider;
 pub use rds_provider::RdsMetricProvider;
+pub use sqs_provider::SqsMetricProvider;
+pub use synthetics_provider::SyntheticsMetricProvider;
 
 /// Core trait that all AWS service metric providers must implement
 ///
@@ -26,6 +36,17 @@ pub trait MetricProvider: Send + Sync {
     /// (e.g., "instance_id" -> "DBInstanceIdentifier" for RDS)
     fn get_dimension_mappings(&self) -> HashMap<String, String>;
 
+    /// CloudWatch dimensions identifying one resource
+    ///
+    /// Defaults to the single `instance_id` mapping; providers whose metrics
+    /// are keyed on more than one dimension override this.
+    fn resource_dimensions(&self, resource_id: &str) -> Vec<(String, String)> {
+        self.get_dimension_mappings()
+            .get("instance_id")
+            .map(|name| vec![(name.clone(), resource_id.to_string())])
+            .unwrap_or_default()
+    }
+
     /// Transforms raw ServiceMetrics into the legacy format for backward compatibility
     /// Returns a boxed Any that can be downcast to the appropriate service-specific type
     fn transform_raw_data(&self, data: ServiceMetrics) -> Box<dyn Any>;
@@ -270,7 +270,8 @@ impl MetricProvider for RdsMetricProvider {
         };
 
         // Use the existing build_metric_data function
-        let legacy_data = build_metric_data(core_metrics, advanced_metrics);
+        let mut legacy_data = build_metric_data(core_metrics, advanced_metrics);
+        legacy_data.series_timestamps = data.series_timestamps;
         Box::new(legacy_data)
     }
 
@@ -279,8 +280,126 @@ impl MetricProvider for RdsMetricProvider {
     }
 }
 
+impl Default for RdsMetricProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl RdsMetricProvider {
     pub fn new() -> Self {
         Self
     }
+
+    /// Whether CloudWatch publishes `metric` for an instance of this engine and class
+    ///
+    /// Metrics like CPU credits or SQL Server agent jobs only exist for some
+    /// instances; asking for them elsewhere costs a call and returns nothing.
+    /// An engine or class not recognized is assumed to publish everything.
+    pub fn publishes(metric: &str, engine: &str, instance_class: &str) -> bool {
+        let family = EngineFamily::of(engine);
+        match publishers(metric) {
+            Publishers::Any => true,
+            Publishers::Engines(families) => {
+                family == EngineFamily::Unknown || families.contains(&family)
+            }
+            Publishers::NotEngines(families) => !families.contains(&family),
+            Publishers::BurstableClasses => {
+                instance_class.is_empty() || instance_class.starts_with("db.t")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EngineFamily {
+    MySql, // MySQL and MariaDB
+    AuroraMySql,
+    Postgres,
+    AuroraPostgres,
+    SqlServer,
+    Oracle,
+    Db2,
+    Unknown,
+}
+
+impl EngineFamily {
+    fn of(engine: &str) -> Self {
+        match engine {
+            "mysql" | "mariadb" => Self::MySql,
+            "aurora" | "aurora-mysql" => Self::AuroraMySql,
+            "postgres" => Self::Postgres,
+            "aurora-postgresql" => Self::AuroraPostgres,
+            engine if engine.contains("sqlserver") => Self::SqlServer,
+            engine if engine.contains("oracle") => Self::Oracle,
+            engine if engine.starts_with("db2") => Self::Db2,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Which instances a metric is published for
+enum Publishers {
+    Any,
+    Engines(&'static [EngineFamily]),
+    NotEngines(&'static [EngineFamily]),
+    BurstableClasses, // db.t* classes, which run on CPU credits
+}
+
+fn publishers(metric: &str) -> Publishers {
+    use EngineFamily::*;
+    match metric {
+        "CPUCreditUsage" | "CPUCreditBalance" => Publishers::BurstableClasses,
+        // Aurora storage is the cluster volume, reported at the cluster level
+        "FreeStorageSpace" | "BurstBalance" => {
+            Publishers::NotEngines(&[AuroraMySql, AuroraPostgres])
+        }
+        "SwapUsage" => Publishers::NotEngines(&[SqlServer]),
+        "BinLogDiskUsage" => Publishers::Engines(&[MySql, AuroraMySql]),
+        "ConnectionAttempts" => Publishers::Engines(&[MySql]),
+        "MaximumUsedTransactionIDs"
+        | "OldestReplicationSlotLag"
+        | "ReplicationSlotDiskUsage"
+        | "TransactionLogsDiskUsage"
+        | "TransactionLogsGeneration" => Publishers::Engines(&[Postgres, AuroraPostgres]),
+        "FailedSQLServerAgentJobsCount" => Publishers::Engines(&[SqlServer]),
+        _ => Publishers::Any,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics_are_filtered_by_engine_and_class() {
+        let publishes = RdsMetricProvider::publishes;
+        assert!(publishes("CPUCreditBalance", "mysql", "db.t4g.medium"));
+        assert!(!publishes("CPUCreditBalance", "mysql", "db.r6g.large"));
+        assert!(!publishes(
+            "FailedSQLServerAgentJobsCount",
+            "postgres",
+            "db.r6g.large"
+        ));
+        assert!(publishes(
+            "FailedSQLServerAgentJobsCount",
+            "sqlserver-se",
+            "db.m5.xlarge"
+        ));
+        assert!(!publishes(
+            "FreeStorageSpace",
+            "aurora-postgresql",
+            "db.r6g.large"
+        ));
+        assert!(publishes(
+            "TransactionLogsDiskUsage",
+            "aurora-postgresql",
+            "db.r6g.large"
+        ));
+        assert!(!publishes("BinLogDiskUsage", "postgres", "db.m5.large"));
+        assert!(publishes("CPUUtilization", "oracle-ee", "db.m5.large"));
+        assert!(!publishes("BinLogDiskUsage", "oracle-ee", "db.m5.large"));
+        // Nothing is left out while the instance is not known
+        assert!(publishes("BinLogDiskUsage", "", ""));
+    }
 }
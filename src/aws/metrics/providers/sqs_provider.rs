@@ -0,0 +1,117 @@
+//! SQS-specific metric provider implementation
+
+use super::MetricProvider;
+use crate::aws::metrics::types::{MetricCategory, MetricDefinition, ServiceMetrics, StatisticType};
+use crate::models::AwsService;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// SQS metric provider that implements the MetricProvider trait
+pub struct SqsMetricProvider;
+
+impl MetricProvider for SqsMetricProvider {
+    fn get_service_namespace(&self) -> &'static str {
+        "AWS/SQS"
+    }
+
+    fn get_metrics_config(&self) -> Vec<MetricDefinition> {
+        vec![
+            // Queue depth
+            MetricDefinition {
+                name: "ApproximateNumberOfMessagesVisible".to_string(),
+                unit: Some("Count".to_string()),
+                statistic: StatisticType::Average,
+                category: MetricCategory::Core,
+            },
+            MetricDefinition {
+                name: "ApproximateNumberOfMessagesNotVisible".to_string(),
+                unit: Some("Count".to_string()),
+                statistic: StatisticType::Average,
+                category: MetricCategory::Core,
+            },
+            MetricDefinition {
+                name: "ApproximateAgeOfOldestMessage".to_string(),
+                unit: Some("Seconds".to_string()),
+                statistic: StatisticType::Maximum,
+                category: MetricCategory::Core,
+            },
+            // Throughput counters
+            MetricDefinition {
+                name: "NumberOfMessagesSent".to_string(),
+                unit: Some("Count".to_string()),
+                statistic: StatisticType::Sum,
+                category: MetricCategory::Performance,
+            },
+            MetricDefinition {
+                name: "NumberOfMessagesReceived".to_string(),
+                unit: Some("Count".to_string()),
+                statistic: StatisticType::Sum,
+                category: MetricCategory::Performance,
+            },
+            MetricDefinition {
+                name: "NumberOfMessagesDeleted".to_string(),
+                unit: Some("Count".to_string()),
+                statistic: StatisticType::Sum,
+                category: MetricCategory::Performance,
+            },
+            MetricDefinition {
+                name: "NumberOfEmptyReceives".to_string(),
+                unit: Some("Count".to_string()),
+                statistic: StatisticType::Sum,
+                category: MetricCategory::Advanced,
+            },
+            MetricDefinition {
+                name: "ApproximateNumberOfMessagesDelayed".to_string(),
+                unit: Some("Count".to_string()),
+                statistic: StatisticType::Average,
+                category: MetricCategory::Advanced,
+            },
+            MetricDefinition {
+                name: "SentMessageSize".to_string(),
+                unit: Some("Bytes".to_string()),
+                statistic: StatisticType::Average,
+                category: MetricCategory::Advanced,
+            },
+            // FIFO queues only; standard queues return no datapoints for these
+            MetricDefinition {
+                name: "ApproximateNumberOfGroupsWithInflightMessages".to_string(),
+                unit: Some("Count".to_string()),
+                statistic: StatisticType::Average,
+                category: MetricCategory::Advanced,
+            },
+            MetricDefinition {
+                name: "NumberOfDeduplicatedSentMessages".to_string(),
+                unit: Some("Count".to_string()),
+                statistic: StatisticType::Sum,
+                category: MetricCategory::Advanced,
+            },
+        ]
+    }
+
+    fn get_dimension_mappings(&self) -> HashMap<String, String> {
+        let mut map = HashMap::new();
+        map.insert("instance_id".to_string(), "QueueName".to_string());
+        map
+    }
+
+    fn transform_raw_data(&self, data: ServiceMetrics) -> Box<dyn Any> {
+        // SQS has no legacy representation; the raw metrics are used directly
+        Box::new(data)
+    }
+
+    fn get_service_type(&self) -> AwsService {
+        AwsService::Sqs
+    }
+}
+
+impl Default for SqsMetricProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SqsMetricProvider {
+    pub fn new() -> Self {
+        Self
+    }
+}
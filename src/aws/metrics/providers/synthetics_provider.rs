@@ -0,0 +1,66 @@
+//! Synthetics-specific metric provider implementation
+
+use super::MetricProvider;
+use crate::aws::metrics::types::{MetricCategory, MetricDefinition, ServiceMetrics, StatisticType};
+use crate::models::AwsService;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// Synthetics canary metric provider that implements the MetricProvider trait
+pub struct SyntheticsMetricProvider;
+
+impl MetricProvider for SyntheticsMetricProvider {
+    fn get_service_namespace(&self) -> &'static str {
+        "CloudWatchSynthetics"
+    }
+
+    fn get_metrics_config(&self) -> Vec<MetricDefinition> {
+        vec![
+            MetricDefinition {
+                name: "SuccessPercent".to_string(),
+                unit: Some("Percent".to_string()),
+                statistic: StatisticType::Average,
+                category: MetricCategory::Core,
+            },
+            MetricDefinition {
+                name: "Duration".to_string(),
+                unit: Some("Milliseconds".to_string()),
+                statistic: StatisticType::Average,
+                category: MetricCategory::Core,
+            },
+            MetricDefinition {
+                name: "Failed".to_string(),
+                unit: Some("Count".to_string()),
+                statistic: StatisticType::Sum,
+                category: MetricCategory::Performance,
+            },
+        ]
+    }
+
+    fn get_dimension_mappings(&self) -> HashMap<String, String> {
+        let mut map = HashMap::new();
+        map.insert("instance_id".to_string(), "CanaryName".to_string());
+        map
+    }
+
+    fn transform_raw_data(&self, data: ServiceMetrics) -> Box<dyn Any> {
+        // Synthetics has no legacy representation; the raw metrics are used directly
+        Box::new(data)
+    }
+
+    fn get_service_type(&self) -> AwsService {
+        AwsService::Synthetics
+    }
+}
+
+impl Default for SyntheticsMetricProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SyntheticsMetricProvider {
+    pub fn new() -> Self {
+        Self
+    }
+}
@@ -0,0 +1,158 @@
+//! Per-metric fetch settings of RDS metrics
+//!
+//! RDS publishes most metrics every minute, but storage, credit and some
+//! engine-specific metrics only every five minutes. Auto-refresh asks the
+//! schedule which metrics can have a new datapoint and reuses the rest.
+//!
+//! Metrics whose tail matters more than their average also name the
+//! percentile statistics their chart is drawn from.
+
+use crate::models::MetricType;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+const ONE_MINUTE: Duration = Duration::from_secs(60);
+const FIVE_MINUTES: Duration = Duration::from_secs(5 * 60);
+
+/// Percentile statistics charted for a metric: a line and a band around the tail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Percentiles {
+    pub line: &'static str,
+    pub band: (&'static str, &'static str), // Lower and upper edge
+}
+
+impl Percentiles {
+    /// Every statistic to fetch, line first
+    pub fn statistics(&self) -> [&'static str; 3] {
+        [self.line, self.band.0, self.band.1]
+    }
+}
+
+/// Median with the p90 to p99 tail shaded around it
+const LATENCY_PERCENTILES: Percentiles = Percentiles {
+    line: "p50",
+    band: ("p90", "p99"),
+};
+
+pub struct MetricRegistry;
+
+impl MetricRegistry {
+    /// How often RDS publishes a new datapoint for `metric`
+    pub fn refresh_interval(metric: &MetricType) -> Duration {
+        match metric {
+            MetricType::FreeStorageSpace
+            | MetricType::BinLogDiskUsage
+            | MetricType::TransactionLogsDiskUsage
+            | MetricType::ReplicationSlotDiskUsage
+            | MetricType::MaximumUsedTransactionIds
+            | MetricType::BurstBalance
+            | MetricType::CpuCreditUsage
+            | MetricType::CpuCreditBalance
+            | MetricType::FailedSqlServerAgentJobsCount => FIVE_MINUTES,
+            _ => ONE_MINUTE,
+        }
+    }
+
+    /// Percentiles drawn in place of the average on `metric`'s chart, if any
+    pub fn percentiles(metric: &MetricType) -> Option<Percentiles> {
+        match metric {
+            MetricType::ReadLatency | MetricType::WriteLatency => Some(LATENCY_PERCENTILES),
+            _ => None,
+        }
+    }
+}
+
+/// When each metric of one instance was last fetched
+#[derive(Debug, Default)]
+pub struct RefreshSchedule {
+    instance_id: Option<String>,
+    fetched_at: HashMap<&'static str, Instant>,
+}
+
+impl RefreshSchedule {
+    /// Whether the schedule describes the metrics currently loaded for `instance_id`
+    pub fn tracks(&self, instance_id: &str) -> bool {
+        self.instance_id.as_deref() == Some(instance_id)
+    }
+
+    /// Start over after a full fetch of every metric
+    pub fn reset(&mut self, instance_id: &str, now: Instant) {
+        self.instance_id = Some(instance_id.to_string());
+        self.fetched_at = MetricType::ALL
+            .iter()
+            .map(|metric| (metric.metric_name(), now))
+            .collect();
+    }
+
+    pub fn clear(&mut self) {
+        self.instance_id = None;
+        self.fetched_at.clear();
+    }
+
+    /// Metrics that cannot have a new datapoint since they were last fetched
+    pub fn not_due(&self, now: Instant) -> Vec<MetricType> {
+        MetricType::ALL
+            .into_iter()
+            .filter(|metric| {
+                self.fetched_at
+                    .get(metric.metric_name())
+                    .is_some_and(|fetched| {
+                        now.duration_since(*fetched) < MetricRegistry::refresh_interval(metric)
+                    })
+            })
+            .collect()
+    }
+
+    /// Make metrics whose fetch failed due on the next refresh
+    pub fn mark_failed<'a>(&mut self, metric_names: impl IntoIterator<Item = &'a String>) {
+        for name in metric_names {
+            self.fetched_at.remove(name.as_str());
+        }
+    }
+
+    pub fn mark_fetched(&mut self, metrics: impl IntoIterator<Item = MetricType>, now: Instant) {
+        for metric in metrics {
+            self.fetched_at.insert(metric.metric_name(), now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slow_metrics_are_skipped_between_publishes() {
+        let start = Instant::now();
+        let mut schedule = RefreshSchedule::default();
+        schedule.reset("orders-db", start);
+        assert!(schedule.tracks("orders-db"));
+
+        // Half a minute later nothing has been republished
+        assert_eq!(schedule.not_due(start + Duration::from_secs(30)).len(), 27);
+
+        // After a minute only the five-minute metrics are still current
+        let later = start + Duration::from_secs(90);
+        let not_due = schedule.not_due(later);
+        assert_eq!(not_due.len(), 9);
+        assert!(not_due.contains(&MetricType::FreeStorageSpace));
+        assert!(!not_due.contains(&MetricType::CpuUtilization));
+
+        schedule.mark_fetched([MetricType::CpuUtilization], later);
+        assert!(schedule
+            .not_due(later + Duration::from_secs(30))
+            .contains(&MetricType::CpuUtilization));
+    }
+
+    #[test]
+    fn test_failed_metrics_are_due_right_away() {
+        let start = Instant::now();
+        let mut schedule = RefreshSchedule::default();
+        schedule.reset("orders-db", start);
+        schedule.mark_failed(&["FreeStorageSpace".to_string()]);
+
+        let not_due = schedule.not_due(start + Duration::from_secs(30));
+        assert_eq!(not_due.len(), 26);
+        assert!(!not_due.contains(&MetricType::FreeStorageSpace));
+    }
+}
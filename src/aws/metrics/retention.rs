@@ -0,0 +1,145 @@
+//! Cap on the datapoints kept in memory per series
+//!
+//! A month of one-minute data across every metric of several tabs adds up, so
+//! each fetched series is capped at `[history] max_points`. The newest half of
+//! the cap is kept as fetched; older datapoints are merged into buckets of
+//! equal size, each kept as its minimum and maximum in the order they
+//! occurred. The shape of the older part, including its spikes and dips,
+//! survives at a coarser time resolution.
+
+use std::sync::OnceLock;
+use std::time::SystemTime;
+
+/// Datapoints per series when `[history] max_points` is not set
+pub const DEFAULT_MAX_POINTS: usize = 720;
+
+/// Smallest cap accepted; lower values would leave too little to chart
+const MIN_POINTS: usize = 60;
+
+static MAX_POINTS: OnceLock<usize> = OnceLock::new();
+
+/// Set the cap; only effective before the first series is fetched
+pub fn configure(max_points: usize) {
+    let _ = MAX_POINTS.set(max_points.max(MIN_POINTS));
+}
+
+pub fn max_points() -> usize {
+    *MAX_POINTS.get_or_init(|| DEFAULT_MAX_POINTS)
+}
+
+/// Cap a fetched series at the configured number of datapoints
+pub fn cap_series(values: Vec<f64>, timestamps: Vec<SystemTime>) -> (Vec<f64>, Vec<SystemTime>) {
+    downsample(values, timestamps, max_points())
+}
+
+/// Keep the newest `max_points / 2` datapoints and fold the older ones into
+/// min/max pairs so the series has at most `max_points`
+///
+/// Each pair is placed at its bucket's first and last timestamp, so series
+/// fetched over the same grid still share their timestamps afterwards.
+pub fn downsample(
+    mut values: Vec<f64>,
+    mut timestamps: Vec<SystemTime>,
+    max_points: usize,
+) -> (Vec<f64>, Vec<SystemTime>) {
+    let len = values.len().min(timestamps.len());
+    values.truncate(len);
+    timestamps.truncate(len);
+    if len <= max_points || max_points < 4 {
+        return (values, timestamps);
+    }
+
+    let recent = max_points / 2;
+    let older = len - recent;
+    let buckets = (max_points - recent) / 2;
+    let bucket_size = older.div_ceil(buckets);
+
+    let mut kept_values = Vec::with_capacity(max_points);
+    let mut kept_timestamps = Vec::with_capacity(max_points);
+    for start in (0..older).step_by(bucket_size) {
+        let end = (start + bucket_size).min(older);
+        let bucket = &values[start..end];
+        let (min_at, max_at) = extremes(bucket);
+        if end - start == 1 {
+            kept_values.push(bucket[0]);
+            kept_timestamps.push(timestamps[start]);
+            continue;
+        }
+        let (first, second) = if min_at <= max_at {
+            (bucket[min_at], bucket[max_at])
+        } else {
+            (bucket[max_at], bucket[min_at])
+        };
+        kept_values.extend([first, second]);
+        kept_timestamps.extend([timestamps[start], timestamps[end - 1]]);
+    }
+    kept_values.extend_from_slice(&values[older..]);
+    kept_timestamps.extend_from_slice(&timestamps[older..]);
+    (kept_values, kept_timestamps)
+}
+
+/// Positions of the minimum and maximum of a non-empty slice
+fn extremes(values: &[f64]) -> (usize, usize) {
+    let mut min_at = 0;
+    let mut max_at = 0;
+    for (i, &value) in values.iter().enumerate() {
+        if value < values[min_at] {
+            min_at = i;
+        }
+        if value > values[max_at] {
+            max_at = i;
+        }
+    }
+    (min_at, max_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn series(values: &[f64]) -> (Vec<f64>, Vec<SystemTime>) {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let timestamps = (0..values.len())
+            .map(|i| start + Duration::from_secs(60 * i as u64))
+            .collect();
+        (values.to_vec(), timestamps)
+    }
+
+    #[test]
+    fn test_downsample_keeps_recent_points_and_older_envelope() {
+        // 12 older points fold into 2 buckets of 6, the newest 4 stay as fetched
+        let (values, timestamps) = series(&[
+            5.0, 9.0, 1.0, 5.0, 5.0, 5.0, // max before min
+            5.0, 5.0, 0.5, 5.0, 20.0, 5.0, // min before max
+            7.0, 8.0, 6.0, 7.0,
+        ]);
+        let (kept, kept_timestamps) = downsample(values, timestamps.clone(), 8);
+        assert_eq!(kept, vec![9.0, 1.0, 0.5, 20.0, 7.0, 8.0, 6.0, 7.0]);
+        assert_eq!(
+            kept_timestamps,
+            vec![
+                timestamps[0],
+                timestamps[5],
+                timestamps[6],
+                timestamps[11],
+                timestamps[12],
+                timestamps[13],
+                timestamps[14],
+                timestamps[15],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_short_series_are_untouched() {
+        let (values, timestamps) = series(&[1.0, 2.0, 3.0]);
+        assert_eq!(
+            downsample(values.clone(), timestamps.clone(), 8),
+            (values, timestamps)
+        );
+
+        let (values, timestamps) = series(&[1.0; 1000]);
+        assert!(downsample(values, timestamps, 100).0.len() <= 100);
+    }
+}
@@ -0,0 +1,106 @@
+//! Detecting metrics that stopped arriving because the resource is not active
+//!
+//! A stopped RDS instance, an idle SQS queue or a stopped canary publishes no datapoints, which
+//! otherwise shows up as empty or flat charts that look like a fetch problem.
+
+use std::time::{Duration, SystemTime};
+
+/// Datapoints within this many periods of now count as recent
+const RECENT_PERIODS: u64 = 3;
+
+/// Lower bound on the recency window, so 1-minute periods tolerate CloudWatch delay
+const MIN_RECENT_WINDOW: Duration = Duration::from_secs(15 * 60);
+
+/// Whether the newest timestamp falls within the recency window for `period_secs`
+pub fn has_recent_datapoints(timestamps: &[SystemTime], period_secs: i32, now: SystemTime) -> bool {
+    let window =
+        Duration::from_secs(period_secs.max(0) as u64 * RECENT_PERIODS).max(MIN_RECENT_WINDOW);
+    match timestamps.iter().max() {
+        // A timestamp ahead of the local clock is as recent as it gets
+        Some(last) => now.duration_since(*last).map_or(true, |age| age <= window),
+        None => false,
+    }
+}
+
+/// Explanation for missing RDS datapoints when the instance is not running
+pub fn rds_staleness(status: &str, has_recent_data: bool) -> Option<String> {
+    (!has_recent_data && status != "available")
+        .then(|| format!("No recent data (instance {status})"))
+}
+
+/// Explanation for missing SQS datapoints when the queue holds no messages
+pub fn sqs_staleness(queue_is_empty: bool, has_recent_data: bool) -> Option<String> {
+    (!has_recent_data && queue_is_empty).then(|| "No recent data (queue idle)".to_string())
+}
+
+/// Explanation for missing Synthetics datapoints when the canary is not running
+pub fn canary_staleness(state: &str, has_recent_data: bool) -> Option<String> {
+    (!has_recent_data && state != "RUNNING")
+        .then(|| format!("No recent data (canary {})", state.to_lowercase()))
+}
+
+/// Explanation for missing EventBridge datapoints when the rule is disabled
+pub fn rule_staleness(state: &str, has_recent_data: bool) -> Option<String> {
+    (!has_recent_data && state != "ENABLED")
+        .then(|| format!("No recent data (rule {})", state.to_lowercase()))
+}
+
+/// Explanation for missing ECS datapoints when the compute environment has no instances
+pub fn compute_environment_staleness(
+    desired_vcpus: Option<i32>,
+    has_recent_data: bool,
+) -> Option<String> {
+    (!has_recent_data && desired_vcpus == Some(0))
+        .then(|| "No recent data (compute environment scaled to zero)".to_string())
+}
+
+/// Explanation for missing Lambda datapoints, which a function only publishes when invoked
+pub fn function_staleness(state: Option<&str>, has_recent_data: bool) -> Option<String> {
+    (!has_recent_data).then(|| match state {
+        Some(state) if state != "Active" => {
+            format!("No recent data (function {})", state.to_lowercase())
+        }
+        _ => "No recent data (function not invoked)".to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stopped_instance_without_recent_datapoints_is_stale() {
+        let now = SystemTime::now();
+        let hour_ago = now - Duration::from_secs(3600);
+        let minute_ago = now - Duration::from_secs(60);
+
+        assert!(has_recent_datapoints(&[hour_ago, minute_ago], 60, now));
+        assert!(!has_recent_datapoints(&[hour_ago], 60, now));
+        assert!(has_recent_datapoints(&[hour_ago], 3600, now));
+        assert!(!has_recent_datapoints(&[], 60, now));
+
+        assert_eq!(
+            rds_staleness("stopped", false).as_deref(),
+            Some("No recent data (instance stopped)")
+        );
+        assert_eq!(rds_staleness("available", false), None);
+        assert_eq!(rds_staleness("stopped", true), None);
+        assert!(sqs_staleness(true, false).is_some());
+        assert!(sqs_staleness(false, false).is_none());
+        assert_eq!(
+            canary_staleness("STOPPED", false).as_deref(),
+            Some("No recent data (canary stopped)")
+        );
+        assert_eq!(canary_staleness("RUNNING", false), None);
+        assert!(rule_staleness("DISABLED", false).is_some());
+        assert!(rule_staleness("ENABLED", false).is_none());
+        assert!(compute_environment_staleness(Some(0), false).is_some());
+        assert!(compute_environment_staleness(Some(4), false).is_none());
+        assert_eq!(
+            function_staleness(Some("Inactive"), false).as_deref(),
+            Some("No recent data (function inactive)")
+        );
+        assert!(function_staleness(None, false).is_some());
+        assert!(function_staleness(Some("Active"), true).is_none());
+    }
+}
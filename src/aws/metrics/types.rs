@@ -37,6 +37,8 @@ pub enum MetricCategory {
 pub struct ServiceMetrics {
     pub raw_metrics: HashMap<String, MetricValue>,
     pub timestamps: Vec<SystemTime>,
+    /// Each series' own timestamps, which skip the periods it has no datapoint for
+    pub series_timestamps: HashMap<String, Vec<SystemTime>>,
     pub service_type: AwsService,
 }
 
@@ -65,6 +67,7 @@ impl ServiceMetrics {
         Self {
             raw_metrics: HashMap::new(),
             timestamps: Vec::new(),
+            series_timestamps: HashMap::new(),
             service_type,
         }
     }
@@ -73,3 +76,80 @@ impl ServiceMetrics {
         self.raw_metrics.insert(name, value);
     }
 }
+
+/// Storage resolution of a CloudWatch metric
+///
+/// Standard-resolution metrics are stored at one-minute granularity, while
+/// high-resolution custom metrics (published with `StorageResolution=1`) keep
+/// per-second datapoints for the first three hours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MetricResolution {
+    #[default]
+    Standard,
+    High,
+}
+
+impl MetricResolution {
+    /// How long CloudWatch keeps sub-minute datapoints for high-resolution metrics
+    pub const HIGH_RESOLUTION_RETENTION_SECS: u64 = 3 * 3600;
+
+    /// Infer the resolution from the spacing of datapoints returned by a
+    /// fine-grained probe request. Any gap shorter than a minute means the
+    /// metric was published at high resolution.
+    pub fn from_timestamps(timestamps: &[SystemTime]) -> Self {
+        let has_sub_minute_gap = timestamps.windows(2).any(|pair| {
+            pair[1]
+                .duration_since(pair[0])
+                .map(|gap| gap.as_secs() < 60)
+                .unwrap_or(false)
+        });
+
+        if has_sub_minute_gap {
+            MetricResolution::High
+        } else {
+            MetricResolution::Standard
+        }
+    }
+
+    /// Short label for status lines and panel titles
+    pub fn label(&self) -> &'static str {
+        match self {
+            MetricResolution::Standard => "std",
+            MetricResolution::High => "hi-res",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_resolution_from_minute_spacing() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let timestamps: Vec<SystemTime> = (0..5)
+            .map(|i| start + Duration::from_secs(i * 60))
+            .collect();
+        assert_eq!(
+            MetricResolution::from_timestamps(&timestamps),
+            MetricResolution::Standard
+        );
+    }
+
+    #[test]
+    fn test_resolution_from_second_spacing() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let timestamps: Vec<SystemTime> = (0..5)
+            .map(|i| start + Duration::from_secs(i * 10))
+            .collect();
+        assert_eq!(
+            MetricResolution::from_timestamps(&timestamps),
+            MetricResolution::High
+        );
+        assert_eq!(
+            MetricResolution::from_timestamps(&[]),
+            MetricResolution::Standard
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! Charts rendered by CloudWatch itself
+//!
+//! GetMetricWidgetImage draws a metric the way the console does and returns a
+//! PNG, which terminals with a graphics protocol can show in place of the
+//! Braille charts.
+
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::limiter;
+use crate::aws::session::AwsSessionManager;
+use anyhow::{anyhow, Result};
+use serde_json::json;
+
+/// Largest image side CloudWatch renders, in pixels
+const MAX_SIDE_PX: u32 = 2000;
+
+/// One metric of one resource, drawn over the last `range_secs`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WidgetRequest {
+    pub namespace: String,
+    pub metric_name: String,
+    pub dimension: (String, String),
+    pub statistic: String, // e.g. "Average"
+    pub period_secs: i32,
+    pub range_secs: u64,
+    pub width_px: u32,
+    pub height_px: u32,
+    pub annotations: Vec<(String, f64)>, // Labeled horizontal lines
+}
+
+impl WidgetRequest {
+    /// Identifies the image, so it is only fetched again when something it shows changes
+    pub fn key(&self) -> String {
+        format!(
+            "{}/{}/{}/{}/{}/{}/{}x{}/{:?}",
+            self.namespace,
+            self.dimension.1,
+            self.metric_name,
+            self.statistic,
+            self.period_secs,
+            self.range_secs,
+            self.width_px,
+            self.height_px,
+            self.annotations
+        )
+    }
+
+    /// MetricWidget JSON describing the chart
+    fn widget_json(&self) -> String {
+        let horizontal: Vec<_> = self
+            .annotations
+            .iter()
+            .map(|(label, value)| json!({ "label": label, "value": value }))
+            .collect();
+        json!({
+            "metrics": [[
+                self.namespace,
+                self.metric_name,
+                self.dimension.0,
+                self.dimension.1,
+                { "stat": self.statistic }
+            ]],
+            "view": "timeSeries",
+            "period": self.period_secs,
+            "start": format!("-PT{}S", self.range_secs),
+            "end": "P0D",
+            "width": self.width_px.clamp(1, MAX_SIDE_PX),
+            "height": self.height_px.clamp(1, MAX_SIDE_PX),
+            "legend": { "position": "hidden" },
+            "annotations": { "horizontal": horizontal },
+        })
+        .to_string()
+    }
+}
+
+/// PNG of the requested chart
+pub async fn fetch_widget_image(request: &WidgetRequest) -> Result<Vec<u8>> {
+    let client = AwsSessionManager::cloudwatch_client().await;
+    let call = client
+        .get_metric_widget_image()
+        .metric_widget(request.widget_json())
+        .output_format("png");
+    let response = limiter::cloudwatch()
+        .call(&request.namespace, || call.send())
+        .await
+        .map_err(|e| {
+            AwsErrorHandler::handle_aws_error(
+                e,
+                "render the chart image",
+                "cloudwatch:GetMetricWidgetImage",
+            )
+        })?;
+    response
+        .metric_widget_image
+        .map(|image| image.into_inner())
+        .ok_or_else(|| anyhow!("CloudWatch returned no chart image"))
+}
@@ -8,6 +8,30 @@ pub mod error_utils;
 // AWS Session Management - centralized config and client management
 pub mod session;
 
+// Named profiles of the shared config files, for switching profile at runtime
+pub mod profiles;
+
+// Regions to list resources in, one at a time or all at once
+pub mod regions;
+
+// HTTP client for networks that only reach AWS through a proxy
+pub mod http_client;
+
+// Signed JSON calls to services without an SDK crate
+pub mod json_api;
+
+// Fault injection for exercising failure handling (--chaos)
+pub mod chaos;
+
+// Adaptive limit on concurrent CloudWatch calls
+pub mod limiter;
+
+// Sharing of identical in-flight CloudWatch fetches
+pub mod coalesce;
+
+// API calls made this session and their estimated cost
+pub mod usage;
+
 // New refactored modules
 pub mod metric_builder;
 pub mod metric_fetcher;
@@ -20,5 +44,53 @@ pub mod metrics;
 // RDS-focused service organization
 pub mod rds;
 
+// SQS queues
+pub mod sqs;
+
+// Lambda functions consuming SQS queues
+pub mod lambda;
+
+// CloudWatch Synthetics canaries
+pub mod synthetics;
+
+// EventBridge rules and their dead-letter queues
+pub mod eventbridge;
+
+// AWS Batch job queues and compute environments
+pub mod batch;
+
+// CloudTrail API activity of the selected resource
+pub mod cloudtrail;
+
+// CloudFormation stack membership and drift of the selected resource
+pub mod cloudformation;
+
+// AWS Resource Groups and their members across services
+pub mod resource_groups;
+
+// Service Catalog AppRegistry applications, resolved through their resource groups
+pub mod appregistry;
+
+// Compute Optimizer right-sizing recommendations for RDS instances
+pub mod compute_optimizer;
+
+// SSM Parameter Store
+pub mod ssm;
+
+// Secrets Manager rotation of database credentials
+pub mod secrets;
+
+// Service Quotas with static fallbacks
+pub mod quotas;
+
+// CloudWatch metric alarms
+pub mod alarms;
+
+// SNS topics used as alarm actions
+pub mod sns;
+
+// CloudWatch Logs published by RDS
+pub mod logs;
+
 // Keep existing exports for backward compatibility
 pub use rds_service::load_rds_instances;
@@ -0,0 +1,121 @@
+// Named profiles from the shared AWS config files
+//
+// `p` on the service list offers the profiles of ~/.aws/config and
+// ~/.aws/credentials, or of the files AWS_CONFIG_FILE and
+// AWS_SHARED_CREDENTIALS_FILE point to, and switches the session to the one
+// picked without restarting.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// A profile as the shared files define it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    pub name: String,
+    pub region: Option<String>, // From ~/.aws/config; None resolves as usual
+}
+
+/// Profiles overlay opened from the service list
+#[derive(Debug, Default)]
+pub struct ProfilesPanel {
+    pub profiles: Vec<Profile>,
+    pub selected_index: usize,
+}
+
+/// Profiles defined in the shared config and credentials files, by name
+pub fn list_profiles() -> Vec<Profile> {
+    let read = |variable: &str, file: &str| {
+        std::env::var_os(variable)
+            .map(PathBuf::from)
+            .or_else(|| dirs::home_dir().map(|home| home.join(".aws").join(file)))
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .unwrap_or_default()
+    };
+    parse(
+        &read("AWS_CONFIG_FILE", "config"),
+        &read("AWS_SHARED_CREDENTIALS_FILE", "credentials"),
+    )
+}
+
+/// Profiles of the two files' sections, sorted by name
+///
+/// The config file names them `[profile <name>]`, except `[default]`; the
+/// credentials file names them plainly. Sections that are not profiles, such
+/// as `[sso-session <name>]`, are skipped.
+pub fn parse(config: &str, credentials: &str) -> Vec<Profile> {
+    let mut profiles: BTreeMap<String, Option<String>> = BTreeMap::new();
+    for (contents, in_config) in [(config, true), (credentials, false)] {
+        let mut current: Option<String> = None;
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with(['#', ';']) {
+                continue;
+            }
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let section = section.trim();
+                current = match section.split_once(char::is_whitespace) {
+                    Some(("profile", name)) if in_config => Some(name.trim().to_string()),
+                    Some(_) => None,
+                    None if !in_config || section == "default" => Some(section.to_string()),
+                    None => None,
+                };
+                if let Some(name) = &current {
+                    profiles.entry(name.clone()).or_default();
+                }
+                continue;
+            }
+            let (Some(name), Some((key, value))) = (&current, line.split_once('=')) else {
+                continue;
+            };
+            if in_config && key.trim() == "region" {
+                profiles.insert(name.clone(), Some(value.trim().to_string()));
+            }
+        }
+    }
+    profiles
+        .into_iter()
+        .map(|(name, region)| Profile { name, region })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profiles_from_config_and_credentials() {
+        let config = "\
+[default]
+region = us-east-1
+
+[profile staging]
+region = eu-west-1
+# region = us-west-2
+
+[sso-session corp]
+sso_region = us-east-1
+
+[profile prod-admin]
+sso_session = corp
+";
+        let credentials = "\
+[default]
+aws_access_key_id = AKIA
+
+[ci]
+aws_access_key_id = AKIB
+";
+        let profile = |name: &str, region: Option<&str>| Profile {
+            name: name.to_string(),
+            region: region.map(str::to_string),
+        };
+        assert_eq!(
+            parse(config, credentials),
+            vec![
+                profile("ci", None),
+                profile("default", Some("us-east-1")),
+                profile("prod-admin", None),
+                profile("staging", Some("eu-west-1")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::session::AwsSessionManager;
+use anyhow::Result;
+use aws_sdk_servicequotas::Client as ServiceQuotasClient;
+
+/// Service Quotas client operations
+pub struct ServiceQuotasManager {
+    client: ServiceQuotasClient,
+}
+
+impl ServiceQuotasManager {
+    /// Create a new Service Quotas client manager using shared AWS session
+    pub async fn new() -> Self {
+        let client = AwsSessionManager::service_quotas_client().await;
+        Self { client }
+    }
+
+    /// Quota names and values for a service, applied values taking precedence over AWS defaults
+    pub async fn load_quotas(&self, service_code: &str) -> Result<Vec<(String, f64)>> {
+        let mut quotas = self.list_applied(service_code).await?;
+        for (name, value) in self.list_defaults(service_code).await? {
+            if !quotas.iter().any(|(applied, _)| *applied == name) {
+                quotas.push((name, value));
+            }
+        }
+        Ok(quotas)
+    }
+
+    async fn list_applied(&self, service_code: &str) -> Result<Vec<(String, f64)>> {
+        let mut quotas = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let resp = match self
+                .client
+                .list_service_quotas()
+                .service_code(service_code)
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "list service quotas",
+                        "servicequotas:ListServiceQuotas permissions",
+                    ));
+                }
+            };
+
+            quotas.extend(
+                resp.quotas()
+                    .iter()
+                    .filter_map(|quota| Some((quota.quota_name()?.to_string(), quota.value()?))),
+            );
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(quotas)
+    }
+
+    async fn list_defaults(&self, service_code: &str) -> Result<Vec<(String, f64)>> {
+        let mut quotas = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let resp = match self
+                .client
+                .list_aws_default_service_quotas()
+                .service_code(service_code)
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "list default service quotas",
+                        "servicequotas:ListAWSDefaultServiceQuotas permissions",
+                    ));
+                }
+            };
+
+            quotas.extend(
+                resp.quotas()
+                    .iter()
+                    .filter_map(|quota| Some((quota.quota_name()?.to_string(), quota.value()?))),
+            );
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(quotas)
+    }
+}
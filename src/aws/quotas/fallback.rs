@@ -0,0 +1,94 @@
+// Static quota values used when the Service Quotas API has no answer
+//
+// RDS max_connections is not a Service Quota at all: it is an engine parameter
+// whose default is derived from instance memory, so it is always computed here.
+
+const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// SQS in-flight message limits per queue
+pub const SQS_STANDARD_IN_FLIGHT: f64 = 120_000.0;
+pub const SQS_FIFO_IN_FLIGHT: f64 = 20_000.0;
+
+/// Default GetMetricStatistics request rate per account and region
+pub const CLOUDWATCH_GET_METRIC_STATISTICS_TPS: f64 = 400.0;
+
+/// Burstable classes do not follow the per-vCPU memory ratio
+const BURSTABLE_MEMORY_GIB: &[(&str, f64)] = &[
+    ("micro", 1.0),
+    ("small", 2.0),
+    ("medium", 4.0),
+    ("large", 8.0),
+    ("xlarge", 16.0),
+    ("2xlarge", 32.0),
+];
+
+/// Approximate memory of an instance class such as `db.r6g.large`
+pub fn instance_class_memory_gib(instance_class: &str) -> Option<f64> {
+    let mut parts = instance_class.strip_prefix("db.")?.split('.');
+    let family = parts.next()?;
+    let size = parts.next()?;
+
+    if family.starts_with('t') {
+        return BURSTABLE_MEMORY_GIB
+            .iter()
+            .find(|(name, _)| *name == size)
+            .map(|(_, gib)| *gib);
+    }
+
+    let gib_per_vcpu = match family.chars().next()? {
+        'm' => 4.0,
+        'r' => 8.0,
+        'x' => 16.0,
+        'z' => 8.0,
+        _ => return None,
+    };
+    let vcpus = match size {
+        "large" => 2.0,
+        "xlarge" => 4.0,
+        _ => size.strip_suffix("xlarge")?.parse::<f64>().ok()? * 4.0,
+    };
+    Some(gib_per_vcpu * vcpus)
+}
+
+/// Default max_connections from the engine's parameter group formula
+pub fn rds_default_max_connections(engine: &str, instance_class: &str) -> Option<f64> {
+    let memory_bytes = instance_class_memory_gib(instance_class)? * GIB;
+    if engine.contains("postgres") {
+        // LEAST({DBInstanceClassMemory/9531392}, 5000)
+        Some((memory_bytes / 9_531_392.0).floor().min(5000.0))
+    } else if engine.contains("mysql") || engine.contains("mariadb") {
+        // {DBInstanceClassMemory/12582880}
+        Some((memory_bytes / 12_582_880.0).floor())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instance_class_memory() {
+        assert_eq!(instance_class_memory_gib("db.t3.micro"), Some(1.0));
+        assert_eq!(instance_class_memory_gib("db.r6g.large"), Some(16.0));
+        assert_eq!(instance_class_memory_gib("db.m5.4xlarge"), Some(64.0));
+        assert_eq!(instance_class_memory_gib("db.serverless"), None);
+    }
+
+    #[test]
+    fn test_default_max_connections() {
+        assert_eq!(
+            rds_default_max_connections("mysql", "db.t3.micro"),
+            Some(85.0)
+        );
+        assert_eq!(
+            rds_default_max_connections("postgres", "db.r6g.16xlarge"),
+            Some(5000.0)
+        );
+        assert_eq!(
+            rds_default_max_connections("sqlserver-ee", "db.m5.large"),
+            None
+        );
+    }
+}
@@ -0,0 +1,125 @@
+// Service quotas shown next to the metrics they limit
+pub mod client;
+pub mod fallback;
+
+use crate::models::{RdsInstance, SqsQueue};
+use client::ServiceQuotasManager;
+use std::collections::HashMap;
+
+/// Service codes used by the Service Quotas API
+pub const SQS_SERVICE_CODE: &str = "sqs";
+pub const CLOUDWATCH_SERVICE_CODE: &str = "monitoring";
+
+/// Fraction of a quota at which a metric is flagged as approaching it
+pub const APPROACHING_QUOTA: f64 = 0.8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuotaSource {
+    ServiceQuotas, // Applied or AWS default value from the Service Quotas API
+    Fallback,      // Static table or engine default formula
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quota {
+    pub name: &'static str,
+    pub limit: f64,
+    pub source: QuotaSource,
+}
+
+impl Quota {
+    /// Fraction of the quota used by `value`
+    pub fn usage(&self, value: f64) -> f64 {
+        if self.limit > 0.0 {
+            value / self.limit
+        } else {
+            0.0
+        }
+    }
+
+    /// Short label such as "max 1365 (default)"
+    pub fn label(&self) -> String {
+        let source = match self.source {
+            QuotaSource::ServiceQuotas => "quota",
+            QuotaSource::Fallback => "default",
+        };
+        format!("max {:.0} ({source})", self.limit)
+    }
+}
+
+/// Quota values from the Service Quotas API, fetched once per service code
+///
+/// A failed or empty lookup is cached too, so the static fallbacks are used
+/// for the rest of the session instead of retrying on every refresh.
+#[derive(Debug, Default)]
+pub struct QuotaCache {
+    by_service: HashMap<String, Vec<(String, f64)>>,
+}
+
+impl QuotaCache {
+    pub async fn ensure_loaded(&mut self, service_code: &str) {
+        if self.by_service.contains_key(service_code) {
+            return;
+        }
+        let quotas = ServiceQuotasManager::new()
+            .await
+            .load_quotas(service_code)
+            .await
+            .unwrap_or_default();
+        self.by_service.insert(service_code.to_string(), quotas);
+    }
+
+    fn lookup(&self, service_code: &str, matches: impl Fn(&str) -> bool) -> Option<f64> {
+        self.by_service
+            .get(service_code)?
+            .iter()
+            .find(|(name, _)| matches(name))
+            .map(|(_, value)| *value)
+    }
+
+    fn resolve(&self, name: &'static str, api_value: Option<f64>, fallback: f64) -> Quota {
+        match api_value {
+            Some(limit) => Quota {
+                name,
+                limit,
+                source: QuotaSource::ServiceQuotas,
+            },
+            None => Quota {
+                name,
+                limit: fallback,
+                source: QuotaSource::Fallback,
+            },
+        }
+    }
+
+    /// Engine default max_connections for the instance class, when the engine has a formula
+    pub fn rds_max_connections(&self, instance: &RdsInstance) -> Option<Quota> {
+        let limit =
+            fallback::rds_default_max_connections(&instance.engine, &instance.instance_class)?;
+        Some(self.resolve("Max connections", None, limit))
+    }
+
+    pub fn sqs_in_flight_messages(&self, queue: &SqsQueue) -> Quota {
+        let fifo = queue.is_fifo();
+        let api_value = self.lookup(SQS_SERVICE_CODE, |name| {
+            let name = name.to_ascii_lowercase();
+            name.contains("in flight") && name.contains("fifo") == fifo
+        });
+        let fallback = if fifo {
+            fallback::SQS_FIFO_IN_FLIGHT
+        } else {
+            fallback::SQS_STANDARD_IN_FLIGHT
+        };
+        self.resolve("In-flight messages", api_value, fallback)
+    }
+
+    pub fn cloudwatch_get_metric_statistics_tps(&self) -> Quota {
+        let api_value = self.lookup(CLOUDWATCH_SERVICE_CODE, |name| {
+            name.contains("GetMetricStatistics")
+        });
+        self.resolve(
+            "CloudWatch GetMetricStatistics TPS",
+            api_value,
+            fallback::CLOUDWATCH_GET_METRIC_STATISTICS_TPS,
+        )
+    }
+}
@@ -0,0 +1,301 @@
+use aws_sdk_rds::types::DbInstance;
+
+/// Attribute holding the ARN of the master password secret RDS manages
+pub const MASTER_USER_SECRET: &str = "Master User Secret";
+
+/// Flatten a DescribeDBInstances record into ordered key-value rows
+///
+/// Only attributes that are present on the instance are returned, so the
+/// details pane never shows rows of empty placeholders.
+pub fn collect_attributes(instance: &DbInstance) -> Vec<(String, String)> {
+    let mut rows = Vec::new();
+    let mut push = |key: &str, value: Option<String>| {
+        if let Some(value) = value.filter(|v| !v.is_empty()) {
+            rows.push((key.to_string(), value));
+        }
+    };
+
+    push("Identifier", instance.db_instance_identifier.clone());
+    push("ARN", instance.db_instance_arn.clone());
+    push("Resource ID", instance.dbi_resource_id.clone());
+    push("Engine", instance.engine.clone());
+    push("Engine Version", instance.engine_version.clone());
+    push("Status", instance.db_instance_status.clone());
+    push("Class", instance.db_instance_class.clone());
+    push(
+        "Endpoint",
+        instance.endpoint.as_ref().and_then(|e| e.address.clone()),
+    );
+    push(
+        "Port",
+        instance
+            .endpoint
+            .as_ref()
+            .and_then(|e| e.port)
+            .map(|p| p.to_string()),
+    );
+    push(
+        "Listener Endpoint",
+        instance
+            .listener_endpoint
+            .as_ref()
+            .and_then(|e| e.address.clone()),
+    );
+    push("Cluster", instance.db_cluster_identifier.clone());
+    push("Database Name", instance.db_name.clone());
+    push("Master Username", instance.master_username.clone());
+    push("Availability Zone", instance.availability_zone.clone());
+    push("Secondary AZ", instance.secondary_availability_zone.clone());
+    push("Multi-AZ", instance.multi_az.map(yes_no));
+    push(
+        "Publicly Accessible",
+        instance.publicly_accessible.map(yes_no),
+    );
+    push(
+        "VPC",
+        instance
+            .db_subnet_group
+            .as_ref()
+            .and_then(|g| g.vpc_id.clone()),
+    );
+    push(
+        "Subnet Group",
+        instance
+            .db_subnet_group
+            .as_ref()
+            .and_then(|g| g.db_subnet_group_name.clone()),
+    );
+    push(
+        "Security Groups",
+        join(
+            instance
+                .vpc_security_groups()
+                .iter()
+                .filter_map(|g| g.vpc_security_group_id.clone()),
+        ),
+    );
+    push("Storage Type", instance.storage_type.clone());
+    push(
+        "Allocated Storage",
+        instance.allocated_storage.map(|gb| format!("{gb} GiB")),
+    );
+    push(
+        "Max Allocated Storage",
+        instance.max_allocated_storage.map(|gb| format!("{gb} GiB")),
+    );
+    push("Provisioned IOPS", instance.iops.map(|v| v.to_string()));
+    push(
+        "Storage Throughput",
+        instance.storage_throughput.map(|v| format!("{v} MiB/s")),
+    );
+    push("Storage Encrypted", instance.storage_encrypted.map(yes_no));
+    push("KMS Key", instance.kms_key_id.clone());
+    push(
+        MASTER_USER_SECRET,
+        instance
+            .master_user_secret
+            .as_ref()
+            .and_then(|secret| secret.secret_arn.clone()),
+    );
+    push(
+        "Parameter Groups",
+        join(instance.db_parameter_groups().iter().filter_map(|g| {
+            g.db_parameter_group_name.as_ref().map(|name| {
+                match g.parameter_apply_status.as_deref() {
+                    Some(status) => format!("{name} ({status})"),
+                    None => name.clone(),
+                }
+            })
+        })),
+    );
+    push(
+        "Option Groups",
+        join(
+            instance
+                .option_group_memberships()
+                .iter()
+                .filter_map(|g| g.option_group_name.clone()),
+        ),
+    );
+    push(
+        "Backup Retention",
+        instance
+            .backup_retention_period
+            .map(|days| format!("{days} days")),
+    );
+    push("Backup Window", instance.preferred_backup_window.clone());
+    push(
+        "Maintenance Window",
+        instance.preferred_maintenance_window.clone(),
+    );
+    push(
+        "Auto Minor Upgrade",
+        instance.auto_minor_version_upgrade.map(yes_no),
+    );
+    push(
+        "Deletion Protection",
+        instance.deletion_protection.map(yes_no),
+    );
+    push(
+        "IAM Auth",
+        instance.iam_database_authentication_enabled.map(yes_no),
+    );
+    push(
+        "Performance Insights",
+        instance.performance_insights_enabled.map(yes_no),
+    );
+    push(
+        "Enhanced Monitoring",
+        instance
+            .monitoring_interval
+            .filter(|&secs| secs > 0)
+            .map(|secs| format!("{secs}s")),
+    );
+    push("CA Certificate", instance.ca_certificate_identifier.clone());
+    push(
+        "Read Replica Source",
+        instance.read_replica_source_db_instance_identifier.clone(),
+    );
+    push(
+        "Read Replicas",
+        join(
+            instance
+                .read_replica_db_instance_identifiers()
+                .iter()
+                .cloned(),
+        ),
+    );
+    push(
+        "Log Exports",
+        join(instance.enabled_cloudwatch_logs_exports().iter().cloned()),
+    );
+    push("License Model", instance.license_model.clone());
+    push("Network Type", instance.network_type.clone());
+    push(
+        "Created",
+        instance
+            .instance_create_time
+            .as_ref()
+            .map(|t| t.to_string()),
+    );
+    for (field, value) in pending_modifications(instance) {
+        push(&format!("Pending: {field}"), Some(value));
+    }
+    for tag in instance.tag_list() {
+        if let Some(key) = &tag.key {
+            push(&format!("Tag: {key}"), tag.value.clone());
+        }
+    }
+
+    rows
+}
+
+/// Changes queued for the next maintenance window (or currently being applied)
+pub fn pending_modifications(instance: &DbInstance) -> Vec<(String, String)> {
+    let Some(pending) = instance.pending_modified_values.as_ref() else {
+        return Vec::new();
+    };
+
+    let mut rows = Vec::new();
+    let mut push = |field: &str, value: Option<String>| {
+        if let Some(value) = value {
+            rows.push((field.to_string(), value));
+        }
+    };
+
+    push("Class", pending.db_instance_class().map(str::to_string));
+    push(
+        "Allocated Storage",
+        pending.allocated_storage().map(|gb| format!("{gb} GiB")),
+    );
+    push("Storage Type", pending.storage_type().map(str::to_string));
+    push("Provisioned IOPS", pending.iops().map(|v| v.to_string()));
+    push(
+        "Storage Throughput",
+        pending.storage_throughput().map(|v| format!("{v} MiB/s")),
+    );
+    push("Engine", pending.engine().map(str::to_string));
+    push(
+        "Engine Version",
+        pending.engine_version().map(str::to_string),
+    );
+    push("Multi-AZ", pending.multi_az().map(yes_no));
+    push("Port", pending.port().map(|p| p.to_string()));
+    push(
+        "Backup Retention",
+        pending
+            .backup_retention_period()
+            .map(|days| format!("{days} days")),
+    );
+    push(
+        "CA Certificate",
+        pending.ca_certificate_identifier().map(str::to_string),
+    );
+    push(
+        "Subnet Group",
+        pending.db_subnet_group_name().map(str::to_string),
+    );
+    push("License Model", pending.license_model().map(str::to_string));
+    push(
+        "IAM Auth",
+        pending.iam_database_authentication_enabled().map(yes_no),
+    );
+    push(
+        "Identifier",
+        pending.db_instance_identifier().map(str::to_string),
+    );
+    push(
+        "Master Password",
+        pending
+            .master_user_password()
+            .map(|_| "changing".to_string()),
+    );
+
+    rows
+}
+
+fn yes_no(flag: bool) -> String {
+    if flag { "Yes" } else { "No" }.to_string()
+}
+
+fn join(values: impl Iterator<Item = String>) -> Option<String> {
+    let joined = values.collect::<Vec<_>>().join(", ");
+    if joined.is_empty() {
+        None
+    } else {
+        Some(joined)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_rds::types::PendingModifiedValues;
+
+    #[test]
+    fn test_pending_modifications_are_listed() {
+        let instance = DbInstance::builder()
+            .db_instance_identifier("orders-db")
+            .pending_modified_values(
+                PendingModifiedValues::builder()
+                    .db_instance_class("db.r6g.xlarge")
+                    .allocated_storage(500)
+                    .master_user_password("secret")
+                    .build(),
+            )
+            .build();
+
+        let pending = pending_modifications(&instance);
+        assert_eq!(
+            pending,
+            vec![
+                ("Class".to_string(), "db.r6g.xlarge".to_string()),
+                ("Allocated Storage".to_string(), "500 GiB".to_string()),
+                ("Master Password".to_string(), "changing".to_string()),
+            ]
+        );
+        assert!(collect_attributes(&instance)
+            .iter()
+            .any(|(key, _)| key == "Pending: Class"));
+    }
+}
@@ -0,0 +1,123 @@
+use aws_sdk_rds::types::BlueGreenDeployment as SdkBlueGreenDeployment;
+
+/// Deployment status while traffic is being moved from blue to green
+const SWITCHOVER_IN_PROGRESS: &str = "SWITCHOVER_IN_PROGRESS";
+
+/// Deployment statuses that no longer need watching
+const FINISHED_STATUSES: [&str; 3] = ["SWITCHOVER_COMPLETED", "DELETING", "INVALID_CONFIGURATION"];
+
+/// One blue (source) / green (target) instance pair of a deployment
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwitchoverMember {
+    pub source_arn: String,
+    pub target_arn: String,
+    pub status: String,
+}
+
+/// An RDS blue/green deployment as returned by DescribeBlueGreenDeployments
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlueGreenDeployment {
+    pub identifier: String,
+    pub name: String,
+    pub status: String,
+    pub status_details: Option<String>,
+    pub source_arn: String,
+    pub target_arn: String,
+    pub members: Vec<SwitchoverMember>,
+    pub tasks: Vec<(String, String)>, // (task name, status)
+}
+
+impl BlueGreenDeployment {
+    pub fn from_sdk(deployment: &SdkBlueGreenDeployment) -> Self {
+        let text = |value: Option<&str>| value.unwrap_or_default().to_string();
+        Self {
+            identifier: text(deployment.blue_green_deployment_identifier()),
+            name: text(deployment.blue_green_deployment_name()),
+            status: text(deployment.status()),
+            status_details: deployment.status_details().map(str::to_string),
+            source_arn: text(deployment.source()),
+            target_arn: text(deployment.target()),
+            members: deployment
+                .switchover_details()
+                .iter()
+                .map(|detail| SwitchoverMember {
+                    source_arn: text(detail.source_member()),
+                    target_arn: text(detail.target_member()),
+                    status: text(detail.status()),
+                })
+                .collect(),
+            tasks: deployment
+                .tasks()
+                .iter()
+                .map(|task| (text(task.name()), text(task.status())))
+                .collect(),
+        }
+    }
+
+    pub fn is_switching_over(&self) -> bool {
+        self.status == SWITCHOVER_IN_PROGRESS
+    }
+
+    /// Still provisioning, available for switchover, or switching over
+    pub fn is_active(&self) -> bool {
+        !FINISHED_STATUSES.contains(&self.status.as_str())
+    }
+
+    /// Whether an instance (by identifier) is on either side of the deployment
+    pub fn involves(&self, instance_id: &str) -> bool {
+        [&self.source_arn, &self.target_arn]
+            .into_iter()
+            .chain(
+                self.members
+                    .iter()
+                    .flat_map(|member| [&member.source_arn, &member.target_arn]),
+            )
+            .any(|arn| identifier_from_arn(arn) == instance_id)
+    }
+}
+
+/// Resource name from an RDS ARN (arn:aws:rds:<region>:<account>:db:<identifier>)
+pub fn identifier_from_arn(arn: &str) -> &str {
+    arn.rsplit(':').next().unwrap_or(arn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_rds::types::{BlueGreenDeploymentTask, SwitchoverDetail};
+
+    #[test]
+    fn test_deployment_from_describe_output() {
+        let sdk = SdkBlueGreenDeployment::builder()
+            .blue_green_deployment_identifier("bgd-123")
+            .blue_green_deployment_name("orders-upgrade")
+            .status("SWITCHOVER_IN_PROGRESS")
+            .source("arn:aws:rds:us-east-1:123456789012:db:orders-db")
+            .target("arn:aws:rds:us-east-1:123456789012:db:orders-db-green-abc")
+            .switchover_details(
+                SwitchoverDetail::builder()
+                    .source_member("arn:aws:rds:us-east-1:123456789012:db:orders-db")
+                    .target_member("arn:aws:rds:us-east-1:123456789012:db:orders-db-green-abc")
+                    .status("SWITCHOVER_IN_PROGRESS")
+                    .build(),
+            )
+            .tasks(
+                BlueGreenDeploymentTask::builder()
+                    .name("CREATING_READ_REPLICA_OF_SOURCE")
+                    .status("COMPLETED")
+                    .build(),
+            )
+            .build();
+
+        let deployment = BlueGreenDeployment::from_sdk(&sdk);
+        assert!(deployment.is_switching_over());
+        assert!(deployment.is_active());
+        assert!(deployment.involves("orders-db"));
+        assert!(deployment.involves("orders-db-green-abc"));
+        assert!(!deployment.involves("payments-db"));
+        assert_eq!(
+            identifier_from_arn(&deployment.members[0].target_arn),
+            "orders-db-green-abc"
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::ui::theme::Severity;
+use anyhow::Result;
+use aws_sdk_rds::types::DbInstance;
+use aws_sdk_rds::Client as RdsClient;
+use chrono::{TimeZone, Utc};
+use std::collections::HashMap;
+
+/// Rotation is due once the certificate expires within this many days
+const ROTATION_WARNING_DAYS: i64 = 60;
+
+/// Within this many days (or past expiry) clients start failing to connect soon
+const ROTATION_CRITICAL_DAYS: i64 = 7;
+
+const SECS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// The CA an instance's TLS certificate is signed by, and when it expires
+#[derive(Debug, Clone, PartialEq)]
+pub struct CertificateStatus {
+    pub ca_identifier: String,
+    pub expires_at: Option<i64>, // Seconds since the Unix epoch
+}
+
+impl CertificateStatus {
+    /// CA and server certificate expiry as reported by DescribeDBInstances
+    pub fn from_instance(instance: &DbInstance) -> Option<Self> {
+        let details = instance.certificate_details();
+        let ca_identifier = details
+            .and_then(|details| details.ca_identifier())
+            .or(instance.ca_certificate_identifier())?;
+        Some(Self {
+            ca_identifier: ca_identifier.to_string(),
+            expires_at: details
+                .and_then(|details| details.valid_till())
+                .map(|time| time.secs()),
+        })
+    }
+
+    /// Bound the expiry by the CA's own, which DescribeDBInstances omits on older instances
+    pub fn apply_ca_expiry(&mut self, ca_expiry: &HashMap<String, i64>) {
+        if let Some(&ca_expires_at) = ca_expiry.get(&self.ca_identifier) {
+            self.expires_at = Some(
+                self.expires_at
+                    .map_or(ca_expires_at, |expires_at| expires_at.min(ca_expires_at)),
+            );
+        }
+    }
+
+    /// Whole days until expiry at `now`; negative once expired
+    pub fn days_left(&self, now: i64) -> Option<i64> {
+        self.expires_at
+            .map(|expires_at| (expires_at - now).div_euclid(SECS_PER_DAY))
+    }
+
+    /// Warning when rotation is due, critical when expiry is imminent or past
+    pub fn severity(&self, now: i64) -> Option<Severity> {
+        self.days_left(now).and_then(rotation_severity)
+    }
+
+    /// Expiry date and how far away it is, e.g. "2024-08-22 (in 45 days)"
+    pub fn expiry_label(&self, now: i64) -> Option<String> {
+        let expires_at = self.expires_at?;
+        let date = Utc
+            .timestamp_opt(expires_at, 0)
+            .single()?
+            .format("%Y-%m-%d");
+        Some(format!(
+            "{date} ({})",
+            days_left_label(self.days_left(now)?)
+        ))
+    }
+}
+
+/// Severity of a certificate expiring in `days_left` days
+pub fn rotation_severity(days_left: i64) -> Option<Severity> {
+    if days_left < ROTATION_CRITICAL_DAYS {
+        Some(Severity::Critical)
+    } else if days_left < ROTATION_WARNING_DAYS {
+        Some(Severity::Warning)
+    } else {
+        None
+    }
+}
+
+/// "in 45 days", "today" or "expired 3 days ago"
+pub fn days_left_label(days_left: i64) -> String {
+    match days_left {
+        0 => "today".to_string(),
+        1 => "in 1 day".to_string(),
+        days if days > 0 => format!("in {days} days"),
+        -1 => "expired 1 day ago".to_string(),
+        days => format!("expired {} days ago", -days),
+    }
+}
+
+/// Expiry of every RDS certificate authority, by identifier
+///
+/// A customer override of a CA's validity takes precedence.
+pub async fn load_ca_expiry(client: &RdsClient) -> Result<HashMap<String, i64>> {
+    let mut expiry = HashMap::new();
+    let mut marker = None;
+
+    loop {
+        let resp = match client
+            .describe_certificates()
+            .set_marker(marker)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Err(AwsErrorHandler::handle_aws_error(
+                    e,
+                    "fetch RDS certificate authorities",
+                    "RDS DescribeCertificates permissions",
+                ));
+            }
+        };
+
+        for certificate in resp.certificates() {
+            let valid_till = certificate
+                .customer_override_valid_till()
+                .or(certificate.valid_till());
+            if let (Some(identifier), Some(valid_till)) =
+                (certificate.certificate_identifier(), valid_till)
+            {
+                expiry.insert(identifier.to_string(), valid_till.secs());
+            }
+        }
+        marker = resp.marker().map(str::to_string);
+        if marker.is_none() {
+            break;
+        }
+    }
+
+    Ok(expiry)
+}
+
+/// Fill in each instance's certificate from DescribeDBInstances and the CA list
+///
+/// Without DescribeCertificates permissions only the expiry reported on the
+/// instance itself is used.
+pub async fn certificate_statuses(
+    client: &RdsClient,
+    instances: &[DbInstance],
+) -> Vec<Option<CertificateStatus>> {
+    let ca_expiry = load_ca_expiry(client).await.unwrap_or_default();
+    instances
+        .iter()
+        .map(|instance| {
+            let mut status = CertificateStatus::from_instance(instance)?;
+            status.apply_ca_expiry(&ca_expiry);
+            Some(status)
+        })
+        .collect()
+}
+
+/// Add the expiry next to the "CA Certificate" row of the details pane
+pub fn insert_expiry_attribute(
+    attributes: &mut Vec<(String, String)>,
+    status: &CertificateStatus,
+    now: i64,
+) {
+    let Some(label) = status.expiry_label(now) else {
+        return;
+    };
+    let position = attributes
+        .iter()
+        .position(|(key, _)| key == "CA Certificate")
+        .map_or(attributes.len(), |index| index + 1);
+    attributes.insert(position, ("CA Expires".to_string(), label));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_rds::primitives::DateTime;
+    use aws_sdk_rds::types::CertificateDetails;
+
+    #[test]
+    fn test_certificate_expiry_and_rotation_severity() {
+        let now = 1_700_000_000;
+        let instance = DbInstance::builder()
+            .ca_certificate_identifier("rds-ca-rsa2048-g1")
+            .certificate_details(
+                CertificateDetails::builder()
+                    .ca_identifier("rds-ca-rsa2048-g1")
+                    .valid_till(DateTime::from_secs(now + 400 * SECS_PER_DAY))
+                    .build(),
+            )
+            .build();
+        let mut status = CertificateStatus::from_instance(&instance).unwrap();
+        assert_eq!(status.days_left(now), Some(400));
+        assert_eq!(status.severity(now), None);
+
+        // The CA expires before the server certificate does
+        let ca_expiry = HashMap::from([(
+            "rds-ca-rsa2048-g1".to_string(),
+            now + 30 * SECS_PER_DAY + 60,
+        )]);
+        status.apply_ca_expiry(&ca_expiry);
+        assert_eq!(status.days_left(now), Some(30));
+        assert_eq!(status.severity(now), Some(Severity::Warning));
+        assert_eq!(status.expiry_label(now).unwrap(), "2023-12-14 (in 30 days)");
+
+        assert_eq!(
+            status.severity(now + 30 * SECS_PER_DAY),
+            Some(Severity::Critical)
+        );
+        assert_eq!(days_left_label(-3), "expired 3 days ago");
+
+        // Only the CA identifier: the expiry comes from the CA list alone
+        let legacy = DbInstance::builder()
+            .ca_certificate_identifier("rds-ca-2019")
+            .build();
+        let mut status = CertificateStatus::from_instance(&legacy).unwrap();
+        assert_eq!(status.expires_at, None);
+        status.apply_ca_expiry(&HashMap::from([("rds-ca-2019".to_string(), now)]));
+        assert_eq!(status.expires_at, Some(now));
+        assert!(CertificateStatus::from_instance(&DbInstance::builder().build()).is_none());
+    }
+}
@@ -1,7 +1,14 @@
 use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::rds::attributes::{collect_attributes, pending_modifications};
+use crate::aws::rds::blue_green::BlueGreenDeployment;
+use crate::aws::rds::certificates::{certificate_statuses, insert_expiry_attribute};
+use crate::aws::rds::global::GlobalDatabase;
+use crate::aws::rds::parameters::{self, ParameterDrift};
+use crate::aws::rds::replicas::ReplicaTopology;
 use crate::aws::session::AwsSessionManager;
+use crate::journal::unix_now;
 use crate::models::RdsInstance;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use aws_sdk_rds::Client as RdsClient;
 
 /// RDS client operations - centralized AWS RDS API calls
@@ -32,14 +39,34 @@ impl RdsClientManager {
 
         let mut instances = Vec::new();
 
-        if let Some(db_instances) = resp.db_instances {
-            for instance in db_instances {
+        let db_instances = resp.db_instances.unwrap_or_default();
+        let certificates = certificate_statuses(&self.client, &db_instances).await;
+        let now = unix_now() as i64;
+        for (instance, certificate) in db_instances.into_iter().zip(certificates) {
+            {
+                let mut attributes = collect_attributes(&instance);
+                if let Some(certificate) = &certificate {
+                    insert_expiry_attribute(&mut attributes, certificate, now);
+                }
+                let pending_modifications = pending_modifications(&instance);
+                let topology = ReplicaTopology::from_instance(&instance);
                 let rds_instance = RdsInstance {
                     identifier: instance.db_instance_identifier.unwrap_or_default(),
                     engine: instance.engine.unwrap_or_default(),
                     status: instance.db_instance_status.unwrap_or_default(),
                     instance_class: instance.db_instance_class.unwrap_or_default(),
+                    port: instance
+                        .endpoint
+                        .as_ref()
+                        .and_then(|e| e.port)
+                        .and_then(|p| u16::try_from(p).ok()),
                     endpoint: instance.endpoint.and_then(|e| e.address),
+                    allocated_storage_gib: instance.allocated_storage,
+                    max_allocated_storage_gib: instance.max_allocated_storage,
+                    pending_modifications,
+                    topology,
+                    attributes,
+                    certificate,
                 };
                 instances.push(rds_instance);
             }
@@ -47,4 +74,129 @@ impl RdsClientManager {
 
         Ok(instances)
     }
+
+    /// Load all blue/green deployments in the account/region
+    pub async fn load_blue_green_deployments(&self) -> Result<Vec<BlueGreenDeployment>> {
+        let mut deployments = Vec::new();
+        let mut marker = None;
+
+        loop {
+            let resp = match self
+                .client
+                .describe_blue_green_deployments()
+                .set_marker(marker)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "fetch RDS blue/green deployments",
+                        "RDS DescribeBlueGreenDeployments permissions",
+                    ));
+                }
+            };
+
+            deployments.extend(
+                resp.blue_green_deployments()
+                    .iter()
+                    .map(BlueGreenDeployment::from_sdk),
+            );
+            marker = resp.marker().map(str::to_string);
+            if marker.is_none() {
+                break;
+            }
+        }
+
+        Ok(deployments)
+    }
+
+    /// Load all Aurora global databases in the account
+    pub async fn load_global_databases(&self) -> Result<Vec<GlobalDatabase>> {
+        let mut databases = Vec::new();
+        let mut marker = None;
+
+        loop {
+            let resp = match self
+                .client
+                .describe_global_clusters()
+                .set_marker(marker)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "fetch Aurora global databases",
+                        "RDS DescribeGlobalClusters permissions",
+                    ));
+                }
+            };
+
+            databases.extend(resp.global_clusters().iter().map(GlobalDatabase::from_sdk));
+            marker = resp.marker().map(str::to_string);
+            if marker.is_none() {
+                break;
+            }
+        }
+
+        Ok(databases)
+    }
+
+    /// The instance's parameter group compared with its engine's defaults
+    pub async fn parameter_drift(&self, instance_id: &str) -> Result<ParameterDrift> {
+        parameters::load_drift(&self.client, instance_id).await
+    }
+
+    /// Start a manual snapshot of an instance
+    ///
+    /// This is a write call; callers are responsible for checking the write gate
+    /// before invoking it.
+    pub async fn create_snapshot(&self, instance_id: &str, snapshot_id: &str) -> Result<()> {
+        match self
+            .client
+            .create_db_snapshot()
+            .db_instance_identifier(instance_id)
+            .db_snapshot_identifier(snapshot_id)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(AwsErrorHandler::handle_aws_error(
+                e,
+                "create RDS snapshot",
+                "RDS CreateDBSnapshot permissions",
+            )),
+        }
+    }
+
+    /// Status and percent complete of a snapshot
+    pub async fn snapshot_progress(&self, snapshot_id: &str) -> Result<(String, Option<i32>)> {
+        let resp = match self
+            .client
+            .describe_db_snapshots()
+            .db_snapshot_identifier(snapshot_id)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Err(AwsErrorHandler::handle_aws_error(
+                    e,
+                    "fetch RDS snapshot status",
+                    "RDS DescribeDBSnapshots permissions",
+                ))
+            }
+        };
+        let snapshot = resp
+            .db_snapshots()
+            .first()
+            .ok_or_else(|| anyhow!("Snapshot {snapshot_id} not found"))?;
+        Ok((
+            snapshot.status().unwrap_or_default().to_string(),
+            snapshot.percent_progress(),
+        ))
+    }
 }
@@ -0,0 +1,78 @@
+use crate::analytics::compare::{diff_windows, CompareOffset, WindowDiff};
+use crate::aws::metric_fetcher::fetch_comprehensive_metric;
+use crate::aws::metric_types::MetricFetchParams;
+use crate::aws::session::AwsSessionManager;
+use crate::aws::time_range::{calculate_period_seconds, TimeRange};
+use crate::models::MetricType;
+use std::time::SystemTime;
+
+/// One metric over the current window and the same window `offset` earlier
+#[derive(Debug, Clone)]
+pub struct MetricComparison {
+    pub metric: MetricType,
+    pub current: Vec<f64>,
+    pub current_timestamps: Vec<SystemTime>,
+    pub previous: Vec<f64>,
+    pub previous_timestamps: Vec<SystemTime>, // As recorded, not shifted by the offset
+    pub diff: Option<WindowDiff>,
+}
+
+/// Fetch every RDS metric for `time_range` ending now and ending `offset` earlier
+///
+/// Metrics without data in either window are left out.
+pub async fn load_window_comparison(
+    instance_id: &str,
+    time_range: TimeRange,
+    offset: CompareOffset,
+) -> Vec<MetricComparison> {
+    let client = AwsSessionManager::metric_client().await;
+    let end_time = SystemTime::now();
+    let start_time = end_time - time_range.duration();
+    let period_seconds = calculate_period_seconds(&time_range);
+
+    let fetch = |metric: &MetricType, start_time: SystemTime, end_time: SystemTime| {
+        fetch_comprehensive_metric(
+            &client,
+            MetricFetchParams {
+                metric_name: metric.metric_name().to_string(),
+                namespace: "AWS/RDS".to_string(),
+                instance_id: instance_id.to_string(),
+                unit: None,
+            },
+            start_time,
+            end_time,
+            period_seconds,
+        )
+    };
+
+    let results = futures::future::join_all(MetricType::ALL.iter().map(|metric| async move {
+        let (current, previous) = futures::future::join(
+            fetch(metric, start_time, end_time),
+            fetch(
+                metric,
+                start_time - offset.duration(),
+                end_time - offset.duration(),
+            ),
+        )
+        .await;
+        (metric.clone(), current, previous)
+    }))
+    .await;
+
+    results
+        .into_iter()
+        .filter_map(
+            |(metric, (_, current, current_timestamps), (_, previous, previous_timestamps))| {
+                let diff = diff_windows(&current, &previous);
+                (!current.is_empty() || !previous.is_empty()).then_some(MetricComparison {
+                    metric,
+                    current,
+                    current_timestamps,
+                    previous,
+                    previous_timestamps,
+                    diff,
+                })
+            },
+        )
+        .collect()
+}
@@ -0,0 +1,286 @@
+// Aurora global databases
+//
+// DescribeGlobalClusters lists the regional clusters of each global database:
+// one primary that takes writes and up to ten read-only secondaries. Each
+// secondary publishes AuroraGlobalDBReplicationLag and AuroraGlobalDBRPOLag to
+// CloudWatch in its own region, so lag is fetched with a config for that
+// region.
+
+use crate::aws::metric_fetcher::FailedMetrics;
+use crate::aws::metrics::fetcher::fetch_metric_series;
+use crate::aws::metrics::types::{MetricCategory, MetricDefinition, StatisticType};
+use crate::aws::rds::blue_green::identifier_from_arn;
+use crate::aws::session::AwsSessionManager;
+use crate::aws::time_range::{calculate_period_seconds, TimeRange};
+use aws_sdk_cloudwatch::types::Dimension;
+use aws_sdk_rds::types::{
+    GlobalCluster as SdkGlobalCluster, GlobalClusterMemberSynchronizationStatus,
+};
+use std::time::SystemTime;
+
+/// Lag metrics charted for each secondary, both in milliseconds
+pub const LAG_METRICS: [&str; 2] = ["AuroraGlobalDBReplicationLag", "AuroraGlobalDBRPOLag"];
+
+/// RPO lag above which a planned failover would wait noticeably for the secondary to catch up
+const RPO_LAG_READY_MS: f64 = 60_000.0;
+
+/// One regional cluster of a global database
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobalMember {
+    pub cluster_arn: String,
+    pub is_writer: bool,
+    pub synchronization: Option<String>, // "connected" or "pending-resync"
+    pub write_forwarding: Option<String>,
+}
+
+impl GlobalMember {
+    pub fn region(&self) -> &str {
+        region_from_arn(&self.cluster_arn)
+    }
+
+    pub fn cluster_id(&self) -> &str {
+        identifier_from_arn(&self.cluster_arn)
+    }
+}
+
+/// A failover or switchover the global database is going through
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobalFailover {
+    pub status: String,
+    pub from_arn: String,
+    pub to_arn: String,
+    pub data_loss_allowed: bool, // true for an unplanned failover
+}
+
+/// An Aurora global database as returned by DescribeGlobalClusters
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobalDatabase {
+    pub identifier: String,
+    pub status: String,
+    pub engine: String,
+    pub engine_version: String,
+    pub members: Vec<GlobalMember>, // Primary first
+    pub failover: Option<GlobalFailover>,
+}
+
+impl GlobalDatabase {
+    pub fn from_sdk(cluster: &SdkGlobalCluster) -> Self {
+        let text = |value: Option<&str>| value.unwrap_or_default().to_string();
+        let mut members: Vec<GlobalMember> = cluster
+            .global_cluster_members()
+            .iter()
+            .map(|member| GlobalMember {
+                cluster_arn: text(member.db_cluster_arn()),
+                is_writer: member.is_writer().unwrap_or(false),
+                synchronization: member
+                    .synchronization_status()
+                    .map(|status| status.as_str().to_string()),
+                write_forwarding: member
+                    .global_write_forwarding_status()
+                    .map(|status| status.as_str().to_string()),
+            })
+            .collect();
+        members.sort_by_key(|member| !member.is_writer);
+
+        Self {
+            identifier: text(cluster.global_cluster_identifier()),
+            status: text(cluster.status()),
+            engine: text(cluster.engine()),
+            engine_version: text(cluster.engine_version()),
+            members,
+            failover: cluster.failover_state().map(|state| GlobalFailover {
+                status: state
+                    .status()
+                    .map(|status| status.as_str().to_string())
+                    .unwrap_or_default(),
+                from_arn: text(state.from_db_cluster_arn()),
+                to_arn: text(state.to_db_cluster_arn()),
+                data_loss_allowed: state.is_data_loss_allowed().unwrap_or(false),
+            }),
+        }
+    }
+
+    pub fn primary(&self) -> Option<&GlobalMember> {
+        self.members.iter().find(|member| member.is_writer)
+    }
+
+    pub fn secondaries(&self) -> impl Iterator<Item = &GlobalMember> {
+        self.members.iter().filter(|member| !member.is_writer)
+    }
+
+    /// Whether a regional cluster (by identifier) belongs to this global database
+    pub fn involves(&self, cluster_id: &str) -> bool {
+        self.members
+            .iter()
+            .any(|member| member.cluster_id() == cluster_id)
+    }
+
+    /// Whether a planned failover to `secondary` could start now, else why not
+    ///
+    /// `rpo_lag_ms` is the secondary's latest AuroraGlobalDBRPOLag, if any.
+    pub fn failover_readiness(
+        &self,
+        secondary: &GlobalMember,
+        rpo_lag_ms: Option<f64>,
+    ) -> Result<(), String> {
+        if let Some(failover) = &self.failover {
+            return Err(format!("{} in progress", failover.status));
+        }
+        if self.status != "available" {
+            return Err(format!("global database {}", self.status));
+        }
+        if secondary.synchronization.as_deref()
+            == Some(GlobalClusterMemberSynchronizationStatus::PendingResync.as_str())
+        {
+            return Err("pending resync".to_string());
+        }
+        match rpo_lag_ms {
+            None => Err("no RPO lag data".to_string()),
+            Some(lag) if lag > RPO_LAG_READY_MS => Err(format!("RPO lag {:.0} s", lag / 1000.0)),
+            Some(_) => Ok(()),
+        }
+    }
+}
+
+/// Region of an RDS ARN (arn:aws:rds:<region>:<account>:cluster:<identifier>)
+pub fn region_from_arn(arn: &str) -> &str {
+    arn.split(':').nth(3).unwrap_or_default()
+}
+
+/// Lag series of one secondary
+#[derive(Debug, Clone, Default)]
+pub struct SecondaryLag {
+    pub cluster_arn: String,
+    pub replication: Vec<f64>,
+    pub replication_timestamps: Vec<SystemTime>,
+    pub rpo: Vec<f64>,
+    pub rpo_timestamps: Vec<SystemTime>,
+    pub failures: FailedMetrics,
+}
+
+impl SecondaryLag {
+    pub fn current_replication(&self) -> Option<f64> {
+        self.replication.last().copied()
+    }
+
+    pub fn current_rpo(&self) -> Option<f64> {
+        self.rpo.last().copied()
+    }
+}
+
+/// Fetch both lag metrics for every secondary concurrently, each from its own region
+pub async fn load_secondary_lag(
+    secondaries: &[GlobalMember],
+    time_range: TimeRange,
+) -> Vec<SecondaryLag> {
+    let end_time = SystemTime::now();
+    let start_time = end_time - time_range.duration();
+    let period_seconds = calculate_period_seconds(&time_range);
+
+    futures::future::join_all(secondaries.iter().map(|secondary| async move {
+        let config = AwsSessionManager::in_region(secondary.region()).await;
+        AwsSessionManager::scoped(config, async {
+            let client = AwsSessionManager::cloudwatch_client().await;
+            let dimension = Dimension::builder()
+                .name("DBClusterIdentifier")
+                .value(secondary.cluster_id())
+                .build();
+            let mut lag = SecondaryLag {
+                cluster_arn: secondary.cluster_arn.clone(),
+                ..SecondaryLag::default()
+            };
+            for name in LAG_METRICS {
+                let definition = MetricDefinition {
+                    name: name.to_string(),
+                    unit: Some("Milliseconds".to_string()),
+                    statistic: StatisticType::Average,
+                    category: MetricCategory::Advanced,
+                };
+                match fetch_metric_series(
+                    &client,
+                    "AWS/RDS",
+                    &definition,
+                    vec![dimension.clone()],
+                    start_time,
+                    end_time,
+                    period_seconds,
+                )
+                .await
+                {
+                    Ok((values, timestamps)) if name == LAG_METRICS[0] => {
+                        lag.replication = values;
+                        lag.replication_timestamps = timestamps;
+                    }
+                    Ok((values, timestamps)) => {
+                        lag.rpo = values;
+                        lag.rpo_timestamps = timestamps;
+                    }
+                    Err(reason) => {
+                        lag.failures.insert(name.to_string(), reason);
+                    }
+                }
+            }
+            lag
+        })
+        .await
+    }))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_rds::types::GlobalClusterMember;
+
+    #[test]
+    fn test_global_database_from_describe_output() {
+        let sdk = SdkGlobalCluster::builder()
+            .global_cluster_identifier("orders-global")
+            .status("available")
+            .engine("aurora-postgresql")
+            .engine_version("15.4")
+            .global_cluster_members(
+                GlobalClusterMember::builder()
+                    .db_cluster_arn("arn:aws:rds:eu-west-1:123456789012:cluster:orders-eu")
+                    .is_writer(false)
+                    .synchronization_status(GlobalClusterMemberSynchronizationStatus::Connected)
+                    .build(),
+            )
+            .global_cluster_members(
+                GlobalClusterMember::builder()
+                    .db_cluster_arn("arn:aws:rds:us-east-1:123456789012:cluster:orders-us")
+                    .is_writer(true)
+                    .readers("arn:aws:rds:eu-west-1:123456789012:cluster:orders-eu")
+                    .build(),
+            )
+            .build();
+
+        let database = GlobalDatabase::from_sdk(&sdk);
+        let primary = database.primary().unwrap();
+        assert_eq!(primary.cluster_id(), "orders-us");
+        assert_eq!(primary.region(), "us-east-1");
+        assert!(database.involves("orders-eu"));
+        assert!(!database.involves("payments"));
+
+        let secondary = database.secondaries().next().unwrap().clone();
+        assert_eq!(secondary.region(), "eu-west-1");
+        assert_eq!(database.failover_readiness(&secondary, Some(800.0)), Ok(()));
+        assert_eq!(
+            database.failover_readiness(&secondary, Some(120_000.0)),
+            Err("RPO lag 120 s".to_string())
+        );
+        assert!(database.failover_readiness(&secondary, None).is_err());
+
+        let mut switching = database.clone();
+        switching.failover = Some(GlobalFailover {
+            status: "failing-over".to_string(),
+            from_arn: primary.cluster_arn.clone(),
+            to_arn: secondary.cluster_arn.clone(),
+            data_loss_allowed: false,
+        });
+        assert_eq!(
+            switching.failover_readiness(&secondary, Some(800.0)),
+            Err("failing-over in progress".to_string())
+        );
+    }
+}
@@ -16,7 +16,8 @@ impl RdsMetricsManager {
         let time_range = TimeRange::new(3, crate::aws::time_range::TimeUnit::Hours, 1).unwrap();
 
         // Use existing cloudwatch_service but with RDS-specific context
-        let metric_data = cloudwatch_service::load_metrics(instance_id, time_range).await?;
+        let (metric_data, _) =
+            cloudwatch_service::load_metrics(instance_id, time_range, &HashMap::new()).await?;
 
         // Convert single MetricData to HashMap for consistency
         let mut metrics_map = HashMap::new();
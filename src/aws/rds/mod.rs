@@ -1,6 +1,15 @@
 // RDS service module - centralized RDS operations
+pub mod attributes;
+pub mod blue_green;
+pub mod certificates;
 pub mod client;
+pub mod compare;
+pub mod global;
 pub mod instances;
 pub mod metrics;
+pub mod parameters;
+pub mod probe;
+pub mod replicas;
+pub mod snapshots;
 
 pub use instances::*;
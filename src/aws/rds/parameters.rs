@@ -0,0 +1,243 @@
+// Parameters an instance's parameter group changes from the engine defaults
+//
+// A custom parameter group lists hundreds of parameters, of which a handful
+// differ from the engine's defaults. DescribeDBParameters with source "user"
+// returns only those set on the group, and DescribeEngineDefaultParameters for
+// the group's family gives the values they replace. Static parameters only
+// take effect once the instance reboots; until then the instance reports the
+// group as "pending-reboot".
+
+use crate::aws::error_utils::AwsErrorHandler;
+use anyhow::{anyhow, Result};
+use aws_sdk_rds::types::Parameter;
+use aws_sdk_rds::Client as RdsClient;
+use std::collections::HashMap;
+
+/// A parameter the group sets to something other than the engine default
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterOverride {
+    pub name: String,
+    pub value: String,
+    pub default: Option<String>, // None when the engine leaves it unset
+    pub is_static: bool,         // Takes effect only after a reboot
+}
+
+/// The instance's parameter group and what it overrides
+#[derive(Debug, Clone, Default)]
+pub struct ParameterDrift {
+    pub group: String,
+    pub family: String,
+    pub apply_status: Option<String>, // e.g. "in-sync" or "pending-reboot"
+    pub overrides: Vec<ParameterOverride>,
+}
+
+impl ParameterDrift {
+    pub fn pending_reboot(&self) -> bool {
+        self.apply_status.as_deref() == Some("pending-reboot")
+    }
+
+    /// Default groups carry the engine defaults and cannot be changed
+    pub fn is_default_group(&self) -> bool {
+        self.group.starts_with("default.")
+    }
+}
+
+/// The parameters set on a group that differ from the defaults, by name
+pub fn compare(set: &[Parameter], defaults: &[Parameter]) -> Vec<ParameterOverride> {
+    let defaults: HashMap<&str, Option<&str>> = defaults
+        .iter()
+        .filter_map(|parameter| Some((parameter.parameter_name()?, parameter.parameter_value())))
+        .collect();
+    let mut overrides: Vec<ParameterOverride> = set
+        .iter()
+        .filter_map(|parameter| {
+            let name = parameter.parameter_name()?;
+            let value = parameter.parameter_value()?;
+            let default = defaults.get(name).copied().flatten();
+            (default != Some(value)).then(|| ParameterOverride {
+                name: name.to_string(),
+                value: value.to_string(),
+                default: default.map(str::to_string),
+                is_static: parameter.apply_type() == Some("static"),
+            })
+        })
+        .collect();
+    overrides.sort_by(|a, b| a.name.cmp(&b.name));
+    overrides
+}
+
+/// Look up the instance's parameter group and compare it with the engine defaults
+pub async fn load_drift(client: &RdsClient, instance_id: &str) -> Result<ParameterDrift> {
+    let instance = match client
+        .describe_db_instances()
+        .db_instance_identifier(instance_id)
+        .send()
+        .await
+    {
+        Ok(resp) => resp.db_instances().first().cloned(),
+        Err(e) => {
+            return Err(AwsErrorHandler::handle_aws_error(
+                e,
+                "fetch RDS instance",
+                "RDS describe permissions",
+            ))
+        }
+    };
+    let membership = instance
+        .as_ref()
+        .and_then(|instance| instance.db_parameter_groups().first())
+        .ok_or_else(|| anyhow!("{instance_id} has no DB parameter group"))?;
+    let group = membership
+        .db_parameter_group_name()
+        .unwrap_or_default()
+        .to_string();
+
+    let family = match client
+        .describe_db_parameter_groups()
+        .db_parameter_group_name(&group)
+        .send()
+        .await
+    {
+        Ok(resp) => resp
+            .db_parameter_groups()
+            .first()
+            .and_then(|group| group.db_parameter_group_family())
+            .unwrap_or_default()
+            .to_string(),
+        Err(e) => {
+            return Err(AwsErrorHandler::handle_aws_error(
+                e,
+                "fetch RDS parameter group",
+                "RDS DescribeDBParameterGroups permissions",
+            ))
+        }
+    };
+
+    let set = group_parameters(client, &group).await?;
+    let defaults = engine_defaults(client, &family).await?;
+    Ok(ParameterDrift {
+        overrides: compare(&set, &defaults),
+        apply_status: membership.parameter_apply_status().map(str::to_string),
+        group,
+        family,
+    })
+}
+
+/// Parameters set on the group itself, rather than inherited from the engine
+async fn group_parameters(client: &RdsClient, group: &str) -> Result<Vec<Parameter>> {
+    let mut parameters = Vec::new();
+    let mut marker = None;
+
+    loop {
+        let resp = match client
+            .describe_db_parameters()
+            .db_parameter_group_name(group)
+            .source("user")
+            .set_marker(marker)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Err(AwsErrorHandler::handle_aws_error(
+                    e,
+                    "fetch RDS parameters",
+                    "RDS DescribeDBParameters permissions",
+                ));
+            }
+        };
+
+        parameters.extend_from_slice(resp.parameters());
+        marker = resp.marker().map(str::to_string);
+        if marker.is_none() {
+            break;
+        }
+    }
+
+    Ok(parameters)
+}
+
+async fn engine_defaults(client: &RdsClient, family: &str) -> Result<Vec<Parameter>> {
+    let mut parameters = Vec::new();
+    let mut marker = None;
+
+    loop {
+        let resp = match client
+            .describe_engine_default_parameters()
+            .db_parameter_group_family(family)
+            .set_marker(marker)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Err(AwsErrorHandler::handle_aws_error(
+                    e,
+                    "fetch RDS engine default parameters",
+                    "RDS DescribeEngineDefaultParameters permissions",
+                ));
+            }
+        };
+
+        let Some(defaults) = resp.engine_defaults() else {
+            break;
+        };
+        parameters.extend_from_slice(defaults.parameters());
+        marker = defaults.marker().map(str::to_string);
+        if marker.is_none() {
+            break;
+        }
+    }
+
+    Ok(parameters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameter(name: &str, value: Option<&str>, apply_type: &str) -> Parameter {
+        Parameter::builder()
+            .parameter_name(name)
+            .set_parameter_value(value.map(str::to_string))
+            .apply_type(apply_type)
+            .build()
+    }
+
+    #[test]
+    fn test_only_values_differing_from_defaults_are_listed() {
+        let set = [
+            parameter("max_connections", Some("500"), "dynamic"),
+            parameter("innodb_buffer_pool_size", Some("2147483648"), "static"),
+            parameter("time_zone", Some("UTC"), "dynamic"),
+            parameter("slow_query_log", Some("1"), "dynamic"),
+        ];
+        let defaults = [
+            parameter(
+                "max_connections",
+                Some("{DBInstanceClassMemory/12582880}"),
+                "dynamic",
+            ),
+            parameter(
+                "innodb_buffer_pool_size",
+                Some("{DBInstanceClassMemory*3/4}"),
+                "static",
+            ),
+            parameter("time_zone", None, "dynamic"),
+            parameter("slow_query_log", Some("1"), "dynamic"),
+        ];
+
+        let overrides = compare(&set, &defaults);
+        let names: Vec<&str> = overrides.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["innodb_buffer_pool_size", "max_connections", "time_zone"]
+        );
+        assert!(overrides[0].is_static);
+        assert_eq!(
+            overrides[1].default.as_deref(),
+            Some("{DBInstanceClassMemory/12582880}")
+        );
+        assert_eq!(overrides[2].default, None);
+    }
+}
@@ -0,0 +1,210 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_rustls::rustls::client::danger::{
+    HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier,
+};
+use tokio_rustls::rustls::crypto::{
+    verify_tls12_signature, verify_tls13_signature, CryptoProvider,
+};
+use tokio_rustls::rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use tokio_rustls::rustls::{ClientConfig, DigitallySignedStruct, SignatureScheme};
+use tokio_rustls::TlsConnector;
+
+/// Give up on each probe step after this long
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// PostgreSQL SSLRequest message: length 8, request code 80877103
+const POSTGRES_SSL_REQUEST: [u8; 8] = [0, 0, 0, 8, 4, 210, 22, 47];
+
+/// Outcome of probing an RDS endpoint from the local machine
+#[derive(Debug, Clone)]
+pub struct ProbeResult {
+    pub target: String,
+    pub tcp: Result<Duration, String>,
+    pub tls: Option<Result<Duration, String>>,
+}
+
+impl ProbeResult {
+    /// One-line report suitable for the status line
+    pub fn summary(&self) -> String {
+        let mut summary = match &self.tcp {
+            Ok(latency) => format!("{}: TCP ok in {} ms", self.target, latency.as_millis()),
+            Err(e) => format!("{}: TCP failed ({e})", self.target),
+        };
+        match &self.tls {
+            Some(Ok(latency)) => summary.push_str(&format!(
+                ", TLS handshake ok in {} ms (certificate not verified)",
+                latency.as_millis()
+            )),
+            Some(Err(e)) => summary.push_str(&format!(", TLS failed ({e})")),
+            None => {}
+        }
+        summary
+    }
+}
+
+/// TCP connect to the endpoint and, optionally, complete a TLS handshake
+///
+/// TLS is negotiated the way the engine expects it; PostgreSQL-compatible
+/// engines use the in-protocol SSLRequest. Other engines negotiate TLS inside
+/// their own handshake, so only the TCP check is performed for them.
+pub async fn probe_endpoint(host: &str, port: u16, engine: &str, with_tls: bool) -> ProbeResult {
+    let target = format!("{host}:{port}");
+
+    let started = Instant::now();
+    let stream = match tokio::time::timeout(PROBE_TIMEOUT, TcpStream::connect(&target)).await {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(e)) => {
+            return ProbeResult {
+                target,
+                tcp: Err(e.to_string()),
+                tls: None,
+            }
+        }
+        Err(_) => {
+            return ProbeResult {
+                target,
+                tcp: Err(format!("timed out after {}s", PROBE_TIMEOUT.as_secs())),
+                tls: None,
+            }
+        }
+    };
+    let tcp_latency = started.elapsed();
+
+    let tls = if !with_tls {
+        None
+    } else if engine.contains("postgres") {
+        Some(
+            tokio::time::timeout(PROBE_TIMEOUT, postgres_tls_handshake(stream, host))
+                .await
+                .unwrap_or_else(|_| Err("timed out".to_string())),
+        )
+    } else {
+        Some(Err(format!("TLS probe is not supported for {engine}")))
+    };
+
+    ProbeResult {
+        target,
+        tcp: Ok(tcp_latency),
+        tls,
+    }
+}
+
+async fn postgres_tls_handshake(mut stream: TcpStream, host: &str) -> Result<Duration, String> {
+    let started = Instant::now();
+
+    stream
+        .write_all(&POSTGRES_SSL_REQUEST)
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut response = [0u8; 1];
+    stream
+        .read_exact(&mut response)
+        .await
+        .map_err(|e| e.to_string())?;
+    if response[0] != b'S' {
+        return Err("server does not accept TLS".to_string());
+    }
+
+    let server_name = ServerName::try_from(host.to_string()).map_err(|e| e.to_string())?;
+    let connector = TlsConnector::from(Arc::new(probe_tls_config()));
+    connector
+        .connect(server_name, stream)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(started.elapsed())
+}
+
+/// TLS config that completes the handshake without validating the chain
+///
+/// RDS certificates are issued by the RDS CA, which is not in system trust
+/// stores; the probe measures reachability and handshake latency only.
+fn probe_tls_config() -> ClientConfig {
+    let provider = tokio_rustls::rustls::crypto::aws_lc_rs::default_provider();
+    ClientConfig::builder()
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(AcceptAnyCertificate(Arc::new(provider))))
+        .with_no_client_auth()
+}
+
+#[derive(Debug)]
+struct AcceptAnyCertificate(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptAnyCertificate {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, tokio_rustls::rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, tokio_rustls::rustls::Error> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, tokio_rustls::rustls::Error> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_probe_reports_tcp_latency() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let result = probe_endpoint("127.0.0.1", port, "mysql", false).await;
+        assert!(result.tcp.is_ok());
+        assert!(result.tls.is_none());
+        assert!(result
+            .summary()
+            .starts_with(&format!("127.0.0.1:{port}: TCP ok")));
+    }
+
+    #[tokio::test]
+    async fn test_probe_reports_refused_connection() {
+        // Bind then drop to get a port that is very likely closed
+        let port = {
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            listener.local_addr().unwrap().port()
+        };
+
+        let result = probe_endpoint("127.0.0.1", port, "postgres", true).await;
+        assert!(result.tcp.is_err());
+        assert!(result.tls.is_none());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::aws::metric_fetcher::fetch_comprehensive_metric;
+use crate::aws::metric_types::MetricFetchParams;
+use crate::aws::session::AwsSessionManager;
+use crate::aws::time_range::{calculate_period_seconds, TimeRange};
+use aws_sdk_rds::types::DbInstance;
+use std::time::SystemTime;
+
+/// Read replica relationships of an instance
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReplicaTopology {
+    pub replicas: Vec<String>, // Identifiers (or ARNs for cross-region replicas)
+    pub source: Option<String>, // Primary this instance replicates from
+}
+
+impl ReplicaTopology {
+    pub fn from_instance(instance: &DbInstance) -> Self {
+        Self {
+            replicas: instance.read_replica_db_instance_identifiers().to_vec(),
+            source: instance.read_replica_source_db_instance_identifier.clone(),
+        }
+    }
+}
+
+/// Cross-region replicas are referenced by ARN and publish metrics in their own region
+pub fn is_cross_region(identifier: &str) -> bool {
+    identifier.starts_with("arn:")
+}
+
+/// ReplicaLag series for one read replica
+#[derive(Debug, Clone)]
+pub struct ReplicaLag {
+    pub identifier: String,
+    pub current: Option<f64>, // None when the replica returned no datapoints
+    pub history: Vec<f64>,
+    pub timestamps: Vec<SystemTime>,
+}
+
+/// Fetch ReplicaLag for every replica concurrently
+///
+/// Cross-region replicas are listed without data since their metrics are not
+/// available from this region's CloudWatch endpoint.
+pub async fn load_replica_lag(replicas: &[String], time_range: TimeRange) -> Vec<ReplicaLag> {
+    let client = AwsSessionManager::metric_client().await;
+    let end_time = SystemTime::now();
+    let start_time = end_time - time_range.duration();
+    let period_seconds = calculate_period_seconds(&time_range);
+
+    let results = futures::future::join_all(replicas.iter().map(|identifier| {
+        let client = &client;
+        async move {
+            if is_cross_region(identifier) {
+                return (Vec::new(), Vec::new());
+            }
+            let (_, history, timestamps) = fetch_comprehensive_metric(
+                client,
+                MetricFetchParams {
+                    metric_name: "ReplicaLag".to_string(),
+                    namespace: "AWS/RDS".to_string(),
+                    instance_id: identifier.clone(),
+                    unit: Some("Seconds".to_string()),
+                },
+                start_time,
+                end_time,
+                period_seconds,
+            )
+            .await;
+            (history, timestamps)
+        }
+    }))
+    .await;
+
+    replicas
+        .iter()
+        .zip(results)
+        .map(|(identifier, (history, timestamps))| ReplicaLag {
+            identifier: identifier.clone(),
+            current: history.last().copied(),
+            history,
+            timestamps,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topology_from_describe_output() {
+        let primary = DbInstance::builder()
+            .db_instance_identifier("orders-db")
+            .read_replica_db_instance_identifiers("orders-db-replica-1")
+            .read_replica_db_instance_identifiers(
+                "arn:aws:rds:eu-west-1:123456789012:db:orders-db-eu",
+            )
+            .build();
+        let topology = ReplicaTopology::from_instance(&primary);
+        assert_eq!(topology.replicas.len(), 2);
+        assert_eq!(topology.source, None);
+        assert!(is_cross_region(&topology.replicas[1]));
+
+        let replica = DbInstance::builder()
+            .db_instance_identifier("orders-db-replica-1")
+            .read_replica_source_db_instance_identifier("orders-db")
+            .build();
+        assert_eq!(
+            ReplicaTopology::from_instance(&replica).source.as_deref(),
+            Some("orders-db")
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use crate::aws::rds::client::RdsClientManager;
+use crate::jobs::JobReporter;
+use anyhow::{anyhow, Result};
+use chrono::Local;
+use std::time::Duration;
+
+/// How often a snapshot being created is checked on
+const SNAPSHOT_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Create a manual snapshot of an instance and report its progress until it is available
+///
+/// Runs as a background job; the snapshot is named after the instance and the
+/// local time it was taken, e.g. `orders-db-awscw-20240612-093015`.
+pub async fn create_snapshot(instance_id: String, reporter: JobReporter) -> Result<String> {
+    let client = RdsClientManager::new().await;
+    let snapshot_id = format!(
+        "{instance_id}-awscw-{}",
+        Local::now().format("%Y%m%d-%H%M%S")
+    );
+    client.create_snapshot(&instance_id, &snapshot_id).await?;
+    reporter.progress(Some(0.0), format!("Creating {snapshot_id}"));
+
+    loop {
+        tokio::time::sleep(SNAPSHOT_POLL_INTERVAL).await;
+        let (status, percent) = client.snapshot_progress(&snapshot_id).await?;
+        match status.as_str() {
+            "available" => return Ok(format!("Snapshot {snapshot_id} is available")),
+            "failed" | "deleting" => return Err(anyhow!("Snapshot {snapshot_id} is {status}")),
+            _ => reporter.progress(
+                percent.map(|percent| f64::from(percent) / 100.0),
+                format!("{snapshot_id} {status}"),
+            ),
+        }
+    }
+}
@@ -1,5 +1,9 @@
 use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::rds::attributes::{collect_attributes, pending_modifications};
+use crate::aws::rds::certificates::{certificate_statuses, insert_expiry_attribute};
+use crate::aws::rds::replicas::ReplicaTopology;
 use crate::aws::session::AwsSessionManager;
+use crate::journal::unix_now;
 use crate::models::RdsInstance;
 use anyhow::Result;
 
@@ -20,14 +24,34 @@ pub async fn load_rds_instances() -> Result<Vec<RdsInstance>> {
 
     let mut instances = Vec::new();
 
-    if let Some(db_instances) = resp.db_instances {
-        for instance in db_instances {
+    let db_instances = resp.db_instances.unwrap_or_default();
+    let certificates = certificate_statuses(&client, &db_instances).await;
+    let now = unix_now() as i64;
+    for (instance, certificate) in db_instances.into_iter().zip(certificates) {
+        {
+            let mut attributes = collect_attributes(&instance);
+            if let Some(certificate) = &certificate {
+                insert_expiry_attribute(&mut attributes, certificate, now);
+            }
+            let pending_modifications = pending_modifications(&instance);
+            let topology = ReplicaTopology::from_instance(&instance);
             let rds_instance = RdsInstance {
                 identifier: instance.db_instance_identifier.unwrap_or_default(),
                 engine: instance.engine.unwrap_or_default(),
                 status: instance.db_instance_status.unwrap_or_default(),
                 instance_class: instance.db_instance_class.unwrap_or_default(),
+                port: instance
+                    .endpoint
+                    .as_ref()
+                    .and_then(|e| e.port)
+                    .and_then(|p| u16::try_from(p).ok()),
                 endpoint: instance.endpoint.and_then(|e| e.address),
+                allocated_storage_gib: instance.allocated_storage,
+                max_allocated_storage_gib: instance.max_allocated_storage,
+                pending_modifications,
+                topology,
+                attributes,
+                certificate,
             };
             instances.push(rds_instance);
         }
@@ -0,0 +1,129 @@
+// Regions to list resources in
+//
+// `R` on the service list offers the regions of `regions` in config.toml, or
+// the ones every account has enabled by default when it is not set:
+//
+//   regions = ["eu-west-1", "eu-central-1", "us-east-1"]
+//
+// Picking one switches the session to it, keeping the profile. "All regions"
+// lists the chosen service in every one of them at once instead, each row
+// tagged with its region, and opening a row switches the session to the row's
+// region. `--region all` starts in that mode.
+
+use crate::aws::session::AwsSessionManager;
+use crate::listing::load_instances;
+use crate::models::{AwsService, ServiceInstance};
+use futures::future::join_all;
+
+/// `--region` value that lists every region at once
+pub const ALL_REGIONS: &str = "all";
+
+/// Regions enabled in every account unless disabled; opt-in regions are added through `regions`
+const DEFAULT_REGIONS: &[&str] = &[
+    "us-east-1",
+    "us-east-2",
+    "us-west-1",
+    "us-west-2",
+    "ca-central-1",
+    "sa-east-1",
+    "eu-west-1",
+    "eu-west-2",
+    "eu-west-3",
+    "eu-central-1",
+    "eu-north-1",
+    "ap-south-1",
+    "ap-northeast-1",
+    "ap-northeast-2",
+    "ap-northeast-3",
+    "ap-southeast-1",
+    "ap-southeast-2",
+];
+
+/// Regions overlay opened from the service list; the first row is "All regions"
+#[derive(Debug, Default)]
+pub struct RegionsPanel {
+    pub regions: Vec<String>,
+    pub selected_index: usize,
+}
+
+impl RegionsPanel {
+    /// The region under the cursor; None on the "All regions" row
+    pub fn selected_region(&self) -> Option<&str> {
+        let index = self.selected_index.checked_sub(1)?;
+        self.regions.get(index).map(String::as_str)
+    }
+}
+
+/// The configured regions in order without repeats, or the default ones
+pub fn choices(configured: &[String]) -> Vec<String> {
+    if configured.is_empty() {
+        return DEFAULT_REGIONS
+            .iter()
+            .map(|region| region.to_string())
+            .collect();
+    }
+    let mut regions: Vec<String> = Vec::new();
+    for region in configured {
+        let region = region.trim();
+        if !region.is_empty() && !regions.iter().any(|listed| listed == region) {
+            regions.push(region.to_string());
+        }
+    }
+    regions
+}
+
+/// Resources listed across regions, each with its region, and why some regions did not list
+#[derive(Debug, Default)]
+pub struct RegionListing {
+    pub rows: Vec<(String, ServiceInstance)>,
+    pub failures: Vec<String>,
+}
+
+/// List `service` in every region at once with the session's credentials
+///
+/// Rows come in the order the regions are given; a region that fails is
+/// reported, not fatal.
+pub async fn list_in_regions(service: &AwsService, regions: &[String]) -> RegionListing {
+    let listings = join_all(regions.iter().map(|region| async move {
+        let config = AwsSessionManager::in_region(region).await;
+        let instances = AwsSessionManager::scoped(config, load_instances(service.clone())).await;
+        (region, instances)
+    }))
+    .await;
+
+    let mut listing = RegionListing::default();
+    for (region, instances) in listings {
+        match instances {
+            Ok(instances) => listing.rows.extend(
+                instances
+                    .into_iter()
+                    .map(|instance| (region.clone(), instance)),
+            ),
+            Err(e) => listing.failures.push(format!("{region}: {e:#}")),
+        }
+    }
+    listing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_configured_regions_replace_the_defaults() {
+        assert_eq!(choices(&[]).len(), DEFAULT_REGIONS.len());
+        let configured = ["eu-west-1", " us-east-1", "eu-west-1", ""].map(str::to_string);
+        assert_eq!(choices(&configured), vec!["eu-west-1", "us-east-1"]);
+
+        let panel = RegionsPanel {
+            regions: choices(&configured),
+            selected_index: 0,
+        };
+        assert_eq!(panel.selected_region(), None);
+        let panel = RegionsPanel {
+            selected_index: 2,
+            ..panel
+        };
+        assert_eq!(panel.selected_region(), Some("us-east-1"));
+    }
+}
@@ -0,0 +1,259 @@
+// AWS Resource Groups and their members across services
+//
+// Teams often keep a resource group per application, by tag query or by
+// CloudFormation stack. ListGroups lists the groups of the account and
+// region, and ListGroupResources resolves a group to the ARNs of its
+// members, whichever kind of group it is. Members of the services the TUI
+// monitors open their metrics; the others, e.g. EC2 instances, are listed so
+// the group reads as the team knows it.
+//
+// The service has no SDK crate here, so it is called through `JsonApiClient`.
+
+use super::eventbridge::arn_resource;
+use super::json_api::{escape, JsonApiClient};
+use crate::models::AwsService;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::json;
+
+/// Name the service signs requests under
+const SIGNING_NAME: &str = "resource-groups";
+
+/// Members asked for per ListGroupResources page, the most the API returns
+const MEMBERS_PER_PAGE: u32 = 50;
+
+/// A resource in a group
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupMember {
+    pub arn: String,
+    pub resource_type: String, // e.g. AWS::RDS::DBInstance
+}
+
+impl GroupMember {
+    /// The service whose metrics the TUI shows for this member, if it shows any
+    pub fn service(&self) -> Option<AwsService> {
+        match self.resource_type.as_str() {
+            "AWS::RDS::DBInstance" => Some(AwsService::Rds),
+            "AWS::SQS::Queue" => Some(AwsService::Sqs),
+            "AWS::Synthetics::Canary" => Some(AwsService::Synthetics),
+            "AWS::Events::Rule" => Some(AwsService::EventBridge),
+            "AWS::Batch::JobQueue" => Some(AwsService::Batch),
+            "AWS::Lambda::Function" => Some(AwsService::Lambda),
+            _ => None,
+        }
+    }
+
+    /// The ID the instance list knows the member by, e.g. the queue name of an SQS ARN
+    pub fn id(&self) -> &str {
+        // Rules on a custom bus are `rule/bus/name`, as EventBridge rule IDs are
+        if self.resource_type == "AWS::Events::Rule" {
+            if let Some((_, id)) = self.arn.split_once(":rule/") {
+                return id;
+            }
+        }
+        arn_resource(&self.arn)
+    }
+
+    /// Service and kind of resource, e.g. "EC2 Instance"
+    pub fn kind(&self) -> String {
+        let mut parts = self.resource_type.split("::").skip(1);
+        match (parts.next(), parts.next()) {
+            (Some(service), Some(kind)) => format!("{service} {kind}"),
+            _ => self.resource_type.clone(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ListGroupsResponse {
+    #[serde(default)]
+    group_identifiers: Vec<GroupIdentifier>,
+    next_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GroupIdentifier {
+    group_name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ListGroupResourcesResponse {
+    #[serde(default)]
+    resources: Vec<ListedResource>,
+    next_token: Option<String>,
+    #[serde(default)]
+    query_errors: Vec<QueryError>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ListedResource {
+    identifier: ResourceIdentifier,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ResourceIdentifier {
+    resource_arn: String,
+    resource_type: String,
+}
+
+/// Why a group's query could not be resolved in full, e.g. a deleted stack
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct QueryError {
+    error_code: Option<String>,
+    message: Option<String>,
+}
+
+/// A group's members, with what kept its query from resolving in full
+#[derive(Debug, Clone, Default)]
+pub struct GroupMembers {
+    pub members: Vec<GroupMember>,
+    pub query_errors: Vec<String>,
+}
+
+impl GroupMembers {
+    /// Monitored members first, then by kind, so each service's members sit together
+    pub fn sort(&mut self) {
+        self.members.sort_by(|a, b| {
+            (a.service().is_none(), &a.resource_type, a.id()).cmp(&(
+                b.service().is_none(),
+                &b.resource_type,
+                b.id(),
+            ))
+        });
+    }
+}
+
+pub struct ResourceGroupsClient {
+    api: JsonApiClient,
+}
+
+impl ResourceGroupsClient {
+    pub async fn new() -> Self {
+        Self {
+            api: JsonApiClient::new("Resource Groups", SIGNING_NAME, SIGNING_NAME).await,
+        }
+    }
+
+    /// Names of the groups in the account and region, in the order the API lists them
+    pub async fn list_groups(&self) -> Result<Vec<String>> {
+        let mut groups = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let path = match &next_token {
+                Some(token) => format!("/groups-list?nextToken={}", escape(token)),
+                None => "/groups-list".to_string(),
+            };
+            let page: ListGroupsResponse = self
+                .api
+                .post("ListGroups", &path, json!({}))
+                .await
+                .context("Failed to list resource groups")?;
+            groups.extend(page.group_identifiers.into_iter().map(|g| g.group_name));
+            next_token = page.next_token;
+            if next_token.is_none() {
+                break;
+            }
+        }
+        Ok(groups)
+    }
+
+    /// Every member of `group`, by name or ARN, across services
+    pub async fn list_members(&self, group: &str) -> Result<GroupMembers> {
+        let mut members = GroupMembers::default();
+        let mut next_token: Option<String> = None;
+        loop {
+            let mut body = json!({ "Group": group, "MaxResults": MEMBERS_PER_PAGE });
+            if let Some(token) = &next_token {
+                body["NextToken"] = json!(token);
+            }
+            let page: ListGroupResourcesResponse = self
+                .api
+                .post("ListGroupResources", "/list-group-resources", body)
+                .await
+                .with_context(|| format!("Failed to list the resources of {group}"))?;
+            members
+                .members
+                .extend(page.resources.into_iter().map(|resource| GroupMember {
+                    arn: resource.identifier.resource_arn,
+                    resource_type: resource.identifier.resource_type,
+                }));
+            members
+                .query_errors
+                .extend(page.query_errors.into_iter().map(|error| {
+                    let code = error.error_code.unwrap_or_default();
+                    match error.message {
+                        Some(message) => format!("{code}: {message}"),
+                        None => code,
+                    }
+                }));
+            next_token = page.next_token;
+            if next_token.is_none() {
+                break;
+            }
+        }
+        members.sort();
+        Ok(members)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(resource_type: &str, arn: &str) -> GroupMember {
+        GroupMember {
+            arn: arn.to_string(),
+            resource_type: resource_type.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_members_map_to_services_and_ids() {
+        let db = member(
+            "AWS::RDS::DBInstance",
+            "arn:aws:rds:us-east-1:123456789012:db:orders-db",
+        );
+        assert_eq!(
+            (db.service(), db.id()),
+            (Some(AwsService::Rds), "orders-db")
+        );
+        let queue = member(
+            "AWS::SQS::Queue",
+            "arn:aws:sqs:us-east-1:123456789012:orders",
+        );
+        assert_eq!(queue.id(), "orders");
+        let rule = member(
+            "AWS::Events::Rule",
+            "arn:aws:events:us-east-1:123456789012:rule/orders-bus/shipped",
+        );
+        assert_eq!(rule.id(), "orders-bus/shipped");
+
+        let ec2 = member(
+            "AWS::EC2::Instance",
+            "arn:aws:ec2:us-east-1:123456789012:instance/i-0abc",
+        );
+        assert_eq!(ec2.service(), None);
+        assert_eq!(
+            (ec2.id(), ec2.kind()),
+            ("i-0abc", "EC2 Instance".to_string())
+        );
+
+        let page: ListGroupResourcesResponse = serde_json::from_str(
+            r#"{"Resources":[{"Identifier":{"ResourceArn":"arn:aws:sqs:us-east-1:1:a",
+                "ResourceType":"AWS::SQS::Queue"}}],
+                "QueryErrors":[{"ErrorCode":"CLOUDFORMATION_STACK_INACTIVE","Message":"gone"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            page.resources[0].identifier.resource_type,
+            "AWS::SQS::Queue"
+        );
+        assert!(page.next_token.is_none());
+    }
+}
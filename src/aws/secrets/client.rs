@@ -0,0 +1,51 @@
+use super::DbSecret;
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::session::AwsSessionManager;
+use anyhow::Result;
+use aws_sdk_secretsmanager::Client as SecretsManagerClient;
+
+/// Secrets Manager client operations - secret metadata, never secret values
+pub struct SecretsClientManager {
+    client: SecretsManagerClient,
+}
+
+impl SecretsClientManager {
+    /// Create a new Secrets Manager client manager using shared AWS session
+    pub async fn new() -> Self {
+        let client = AwsSessionManager::secretsmanager_client().await;
+        Self { client }
+    }
+
+    /// Load every secret in the account/region with its rotation state
+    pub async fn load_secrets(&self) -> Result<Vec<DbSecret>> {
+        let mut secrets = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let resp = match self
+                .client
+                .list_secrets()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "fetch Secrets Manager secrets",
+                        "Secrets Manager ListSecrets permissions",
+                    ));
+                }
+            };
+
+            secrets.extend(resp.secret_list().iter().map(DbSecret::from_sdk));
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(secrets)
+    }
+}
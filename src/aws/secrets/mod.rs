@@ -0,0 +1,214 @@
+// Secrets Manager service module - rotation state of database credentials
+pub mod client;
+
+pub use client::SecretsClientManager;
+
+use crate::aws::rds::attributes::MASTER_USER_SECRET;
+use crate::config::SecretsConfig;
+use crate::models::RdsInstance;
+use crate::ui::theme::Severity;
+use aws_sdk_secretsmanager::types::SecretListEntry;
+
+const SECS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// A scheduled rotation this long past due has most likely failed
+const ROTATION_GRACE_SECS: i64 = SECS_PER_DAY;
+
+/// A secret's metadata as returned by ListSecrets; the secret value is never read
+#[derive(Debug, Clone, PartialEq)]
+pub struct DbSecret {
+    pub name: String,
+    pub arn: String,
+    pub rotation_enabled: bool,
+    pub last_rotated: Option<i64>, // Seconds since the Unix epoch
+    pub last_changed: Option<i64>,
+    pub next_rotation: Option<i64>,
+    pub tags: Vec<(String, String)>,
+}
+
+/// How a secret's rotation is doing; `severity` is None when it is fine
+#[derive(Debug, Clone, PartialEq)]
+pub struct RotationHealth {
+    pub severity: Option<Severity>,
+    pub summary: String,
+}
+
+impl DbSecret {
+    pub fn from_sdk(entry: &SecretListEntry) -> Self {
+        let text = |value: Option<&str>| value.unwrap_or_default().to_string();
+        Self {
+            name: text(entry.name()),
+            arn: text(entry.arn()),
+            rotation_enabled: entry.rotation_enabled().unwrap_or(false),
+            last_rotated: entry.last_rotated_date().map(|time| time.secs()),
+            last_changed: entry.last_changed_date().map(|time| time.secs()),
+            next_rotation: entry.next_rotation_date().map(|time| time.secs()),
+            tags: entry
+                .tags()
+                .iter()
+                .filter_map(|tag| Some((tag.key()?.to_string(), text(tag.value()))))
+                .collect(),
+        }
+    }
+
+    /// Whether the secret is named, by name or ARN, in `reference`
+    fn matches(&self, reference: &str) -> bool {
+        reference == self.name || reference == self.arn
+    }
+
+    /// Rotation health at `now`, given the age past which credentials are stale
+    ///
+    /// A scheduled rotation more than a day overdue is critical, since it
+    /// usually means the rotation function is failing. Credentials unchanged
+    /// for longer than `max_age_days`, rotated or not, are a warning.
+    pub fn rotation_health(&self, now: i64, max_age_days: i64) -> RotationHealth {
+        let days_since = |time: i64| (now - time).div_euclid(SECS_PER_DAY);
+
+        if let Some(next_rotation) = self.next_rotation.filter(|_| self.rotation_enabled) {
+            if now - next_rotation > ROTATION_GRACE_SECS {
+                return RotationHealth {
+                    severity: Some(Severity::Critical),
+                    summary: format!("rotation overdue by {} days", days_since(next_rotation)),
+                };
+            }
+        }
+
+        // Credentials change with any update to the secret, rotation or not
+        let updated = self.last_rotated.max(self.last_changed);
+        let rotation = if self.rotation_enabled {
+            ""
+        } else {
+            ", rotation off"
+        };
+        match updated.map(days_since) {
+            Some(age) if age > max_age_days => RotationHealth {
+                severity: Some(Severity::Warning),
+                summary: format!("unchanged for {age} days{rotation}"),
+            },
+            Some(age) => RotationHealth {
+                severity: None,
+                summary: format!("changed {age} days ago{rotation}"),
+            },
+            None => RotationHealth {
+                severity: (!self.rotation_enabled).then_some(Severity::Warning),
+                summary: format!("never rotated{rotation}"),
+            },
+        }
+    }
+}
+
+/// Secrets holding the credentials of `instance`
+///
+/// A secret belongs to an instance when it is the master password secret RDS
+/// manages for it, when `[secrets.instances]` names it for the instance, or
+/// when its `[secrets] tag` has the instance identifier as its value.
+pub fn secrets_for_instance<'a>(
+    secrets: &'a [DbSecret],
+    config: &SecretsConfig,
+    instance: &RdsInstance,
+) -> Vec<&'a DbSecret> {
+    let managed = instance.attribute(MASTER_USER_SECRET);
+    let configured = config.instances.get(&instance.identifier);
+    secrets
+        .iter()
+        .filter(|secret| {
+            managed.is_some_and(|arn| secret.matches(arn))
+                || configured.is_some_and(|reference| secret.matches(reference))
+                || secret
+                    .tags
+                    .iter()
+                    .any(|(key, value)| key == &config.tag && value == &instance.identifier)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aws::rds::replicas::ReplicaTopology;
+    use std::collections::BTreeMap;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn secret(name: &str, rotation_enabled: bool, changed_days_ago: i64) -> DbSecret {
+        DbSecret {
+            name: name.to_string(),
+            arn: format!("arn:aws:secretsmanager:eu-west-1:123456789012:secret:{name}-AbCdEf"),
+            rotation_enabled,
+            last_rotated: None,
+            last_changed: Some(NOW - changed_days_ago * SECS_PER_DAY),
+            next_rotation: None,
+            tags: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_rotation_health() {
+        let fresh = secret("orders-db-app", true, 10);
+        assert_eq!(
+            fresh.rotation_health(NOW, 90),
+            RotationHealth {
+                severity: None,
+                summary: "changed 10 days ago".to_string(),
+            }
+        );
+
+        let stale = secret("legacy-db-admin", false, 200);
+        assert_eq!(
+            stale.rotation_health(NOW, 90),
+            RotationHealth {
+                severity: Some(Severity::Warning),
+                summary: "unchanged for 200 days, rotation off".to_string(),
+            }
+        );
+
+        let mut failing = secret("orders-db-admin", true, 40);
+        failing.next_rotation = Some(NOW - 3 * SECS_PER_DAY);
+        assert_eq!(
+            failing.rotation_health(NOW, 90).severity,
+            Some(Severity::Critical)
+        );
+
+        // Due today: the rotation may simply not have run yet
+        failing.next_rotation = Some(NOW - 60 * 60);
+        assert_eq!(failing.rotation_health(NOW, 90).severity, None);
+    }
+
+    #[test]
+    fn test_secrets_are_matched_by_managed_arn_config_and_tag() {
+        let managed = secret("rds!db-1234", true, 1);
+        let configured = secret("orders/reporting", false, 1);
+        let mut tagged = secret("orders-db-app", true, 1);
+        tagged.tags = vec![("awscw:db-instance".to_string(), "orders-db".to_string())];
+        let unrelated = secret("billing-db-app", true, 1);
+        let secrets = vec![managed.clone(), configured, tagged, unrelated];
+
+        let instance = RdsInstance {
+            identifier: "orders-db".to_string(),
+            engine: "postgres".to_string(),
+            status: "available".to_string(),
+            instance_class: "db.r6g.large".to_string(),
+            endpoint: None,
+            port: None,
+            allocated_storage_gib: None,
+            max_allocated_storage_gib: None,
+            pending_modifications: Vec::new(),
+            topology: ReplicaTopology::default(),
+            attributes: vec![(MASTER_USER_SECRET.to_string(), managed.arn)],
+            certificate: None,
+        };
+        let config = SecretsConfig {
+            instances: BTreeMap::from([("orders-db".to_string(), "orders/reporting".to_string())]),
+            ..SecretsConfig::default()
+        };
+
+        let names: Vec<&str> = secrets_for_instance(&secrets, &config, &instance)
+            .iter()
+            .map(|secret| secret.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["rds!db-1234", "orders/reporting", "orders-db-app"]
+        );
+    }
+}
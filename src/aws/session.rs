@@ -1,13 +1,63 @@
+use super::chaos;
+use super::http_client::{self, http_client, proxy_client};
+use super::metric_fetcher::MetricClient;
+use super::usage::CallCounter;
+use crate::config::NetworkConfig;
+use aws_config::retry::RetryConfig;
+use aws_config::sts::AssumeRoleProvider;
+use aws_config::timeout::TimeoutConfig;
+use aws_config::ConfigLoader;
 use aws_config::{BehaviorVersion, SdkConfig};
+use aws_sdk_batch::Client as BatchClient;
+use aws_sdk_cloudformation::Client as CloudFormationClient;
+use aws_sdk_cloudtrail::Client as CloudTrailClient;
 use aws_sdk_cloudwatch::Client as CloudWatchClient;
+use aws_sdk_cloudwatchlogs::Client as LogsClient;
+use aws_sdk_computeoptimizer::Client as ComputeOptimizerClient;
+use aws_sdk_eventbridge::Client as EventBridgeClient;
 use aws_sdk_rds::Client as RdsClient;
+use aws_sdk_secretsmanager::Client as SecretsManagerClient;
+use aws_sdk_servicequotas::Client as ServiceQuotasClient;
+use aws_sdk_sns::Client as SnsClient;
+use aws_sdk_sqs::Client as SqsClient;
+use aws_sdk_ssm::Client as SsmClient;
 use aws_sdk_sts::Client as StsClient;
-use std::sync::Arc;
+use aws_sdk_synthetics::Client as SyntheticsClient;
+use aws_smithy_runtime_api::client::http::{HttpConnectorSettings, SharedHttpConnector};
+use std::future::Future;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 use tokio::sync::RwLock;
 
 /// Global AWS configuration - loaded once and reused throughout the application
 static AWS_CONFIG: RwLock<Option<Arc<SdkConfig>>> = RwLock::const_new(None);
 
+/// Profile and region given on the command line, taking precedence over the environment
+static PROFILE_OVERRIDE: OnceLock<String> = OnceLock::new();
+static REGION_OVERRIDE: OnceLock<String> = OnceLock::new();
+
+/// `[network]` settings, applied to every config loaded
+static NETWORK: OnceLock<NetworkConfig> = OnceLock::new();
+
+/// Seed of the faults injected into AWS calls, when `--chaos` is given
+static CHAOS: OnceLock<u64> = OnceLock::new();
+
+/// The SDK's connect timeout, kept when only the read timeout is configured
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_millis(3100);
+
+tokio::task_local! {
+    /// Config that clients created within `AwsSessionManager::scoped` use instead of the shared one
+    static SCOPED_CONFIG: Arc<SdkConfig>;
+}
+
+/// Where to reach an account other than the default one; unset fields resolve as usual
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionContext {
+    pub profile: Option<String>,
+    pub role_arn: Option<String>, // Assumed with the profile's credentials
+    pub region: Option<String>,
+}
+
 /// AWS Session Manager - handles centralized AWS config and client creation
 ///
 /// This ensures AWS config is loaded only once using standard credential chain:
@@ -16,9 +66,80 @@ static AWS_CONFIG: RwLock<Option<Arc<SdkConfig>>> = RwLock::const_new(None);
 /// - SSO sessions
 /// - Instance roles (when running on EC2)
 /// - Container roles (when running on ECS/Fargate)
+///
+/// Every client it creates counts its calls in the session's API usage.
 pub struct AwsSessionManager;
 
 impl AwsSessionManager {
+    /// Use `profile` instead of AWS_PROFILE; only effective before the config is loaded
+    pub fn set_profile(profile: String) {
+        let _ = PROFILE_OVERRIDE.set(profile);
+    }
+
+    /// Use `region` instead of the resolved default; only effective before the config is loaded
+    pub fn set_region(region: String) {
+        let _ = REGION_OVERRIDE.set(region);
+    }
+
+    /// Use `network` for the HTTP client, timeouts and retries; only effective before the config is loaded
+    pub fn set_network(network: NetworkConfig) {
+        let _ = NETWORK.set(network);
+    }
+
+    /// Inject faults seeded with `seed` into every AWS call from now on
+    pub fn set_chaos(seed: u64) {
+        let _ = CHAOS.set(seed);
+    }
+
+    /// A connector with the `[network]` settings, for calls made without an SDK client
+    pub fn http_connector() -> SharedHttpConnector {
+        let unset = NetworkConfig::default();
+        let network = NETWORK.get().unwrap_or(&unset);
+        let seconds =
+            |secs: Option<f64>| secs.and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+        let mut settings = HttpConnectorSettings::builder();
+        settings
+            .set_connect_timeout(Some(
+                seconds(network.connect_timeout_secs).unwrap_or(DEFAULT_CONNECT_TIMEOUT),
+            ))
+            .set_read_timeout(seconds(network.read_timeout_secs));
+        http_client::connector(network, &settings.build())
+    }
+
+    /// A config loader with the `[network]` settings applied
+    fn loader() -> ConfigLoader {
+        let mut loader = aws_config::defaults(BehaviorVersion::latest());
+        let unset = NetworkConfig::default();
+        let network = NETWORK.get().unwrap_or(&unset);
+        let client = http_client(network);
+        if let Some(&seed) = CHAOS.get() {
+            let client = client.unwrap_or_else(|| proxy_client(network));
+            loader = loader.http_client(chaos::wrap(client, seed));
+        } else if let Some(client) = client {
+            loader = loader.http_client(client);
+        }
+        let seconds =
+            |secs: Option<f64>| secs.and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+        let read_timeout = seconds(network.read_timeout_secs);
+        let connect_timeout = seconds(network.connect_timeout_secs);
+        if connect_timeout.is_some() || read_timeout.is_some() {
+            let mut timeouts = TimeoutConfig::builder()
+                .connect_timeout(connect_timeout.unwrap_or(DEFAULT_CONNECT_TIMEOUT));
+            if let Some(read_timeout) = read_timeout {
+                timeouts = timeouts.read_timeout(read_timeout);
+            }
+            loader = loader.timeout_config(timeouts.build());
+        }
+        if let Some(attempts) = network.max_attempts {
+            loader =
+                loader.retry_config(RetryConfig::standard().with_max_attempts(attempts.max(1)));
+        }
+        if network.dual_stack {
+            loader = loader.use_dual_stack(true);
+        }
+        loader
+    }
+
     /// Get the shared AWS configuration, loading it once if needed
     ///
     /// This uses AWS standard credential chain and respects all AWS SDK conventions
@@ -31,6 +152,9 @@ impl AwsSessionManager {
     /// 4. Amazon ECS/EKS container credentials
     /// 5. Amazon EC2 Instance Metadata Service (IMDSv2)
     pub async fn get_config() -> Arc<SdkConfig> {
+        if let Ok(config) = SCOPED_CONFIG.try_with(Arc::clone) {
+            return config;
+        }
         let read_guard = AWS_CONFIG.read().await;
         if let Some(config) = read_guard.as_ref() {
             return config.clone();
@@ -43,28 +167,255 @@ impl AwsSessionManager {
             return config.clone();
         }
 
-        let config = aws_config::defaults(BehaviorVersion::latest()).load().await;
+        let mut loader = Self::loader();
+        if let Some(profile) = PROFILE_OVERRIDE.get() {
+            loader = loader.profile_name(profile);
+        }
+        if let Some(region) = REGION_OVERRIDE.get() {
+            loader = loader.region(aws_config::Region::new(region.clone()));
+        }
+        let config = loader.load().await;
         let new_config = Arc::new(config);
         *write_guard = Some(new_config.clone());
         new_config
     }
 
+    /// Load the config of another account or region, without touching the shared one
+    pub async fn load_context(context: &SessionContext) -> Arc<SdkConfig> {
+        let loader = || {
+            let mut loader = Self::loader();
+            if let Some(profile) = &context.profile {
+                loader = loader.profile_name(profile);
+            }
+            if let Some(region) = &context.region {
+                loader = loader.region(aws_config::Region::new(region.clone()));
+            }
+            loader
+        };
+        let config = loader().load().await;
+        let Some(role_arn) = &context.role_arn else {
+            return Arc::new(config);
+        };
+        let role = AssumeRoleProvider::builder(role_arn)
+            .session_name("awscw")
+            .configure(&config)
+            .build()
+            .await;
+        Arc::new(loader().credentials_provider(role).load().await)
+    }
+
+    /// Run `future` with every client it creates using `config` rather than the shared config
+    ///
+    /// The scope does not reach tasks spawned from within `future`.
+    pub async fn scoped<F: Future>(config: Arc<SdkConfig>, future: F) -> F::Output {
+        SCOPED_CONFIG.scope(config, future).await
+    }
+
+    /// Make `config` the shared config, e.g. after switching to another account
+    pub async fn switch_to(config: Arc<SdkConfig>) {
+        *AWS_CONFIG.write().await = Some(config);
+    }
+
+    /// Make `profile` the shared config's profile once its credentials check out
+    ///
+    /// `region`, else a `--region` given on the command line, still applies.
+    /// Returns the account the profile signs in to; on failure the session is
+    /// unchanged.
+    pub async fn switch_profile(profile: &str, region: Option<&str>) -> anyhow::Result<String> {
+        let context = SessionContext {
+            profile: Some(profile.to_string()),
+            role_arn: None,
+            region: region
+                .map(str::to_string)
+                .or_else(|| REGION_OVERRIDE.get().cloned()),
+        };
+        let config = Self::load_context(&context).await;
+        let identity = Self::scoped(config.clone(), async {
+            Self::sts_client().await.get_caller_identity().send().await
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("Could not sign in with profile {profile}: {e}"))?;
+        Self::switch_to(config).await;
+        Ok(identity.account().unwrap_or("unknown account").to_string())
+    }
+
+    /// The shared config, or the scoped one, pointed at `region` with the same credentials
+    pub async fn in_region(region: &str) -> Arc<SdkConfig> {
+        let config = Self::get_config().await;
+        Arc::new(
+            config
+                .to_builder()
+                .region(aws_config::Region::new(region.to_string()))
+                .build(),
+        )
+    }
+
+    /// Make `region` the shared config's region, keeping the credentials
+    pub async fn switch_region(region: &str) {
+        let config = Self::in_region(region).await;
+        Self::switch_to(config).await;
+    }
+
     /// Create a new RDS client using the shared config
     pub async fn rds_client() -> RdsClient {
         let config = Self::get_config().await;
-        RdsClient::new(&config)
+        RdsClient::from_conf(
+            aws_sdk_rds::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
     }
 
     /// Create a new CloudWatch client using the shared config
     pub async fn cloudwatch_client() -> CloudWatchClient {
         let config = Self::get_config().await;
-        CloudWatchClient::new(&config)
+        CloudWatchClient::from_conf(
+            aws_sdk_cloudwatch::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
+    }
+
+    /// Create a new CloudWatch client for metric fetches, which knows the
+    /// region and credentials of its calls
+    pub async fn metric_client() -> MetricClient {
+        let config = Self::get_config().await;
+        MetricClient::new(
+            &config,
+            aws_sdk_cloudwatch::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
+    }
+
+    /// Create a new CloudWatch Logs client using the shared config
+    pub async fn logs_client() -> LogsClient {
+        let config = Self::get_config().await;
+        LogsClient::from_conf(
+            aws_sdk_cloudwatchlogs::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
+    }
+
+    /// Create a new SQS client using the shared config
+    pub async fn sqs_client() -> SqsClient {
+        let config = Self::get_config().await;
+        SqsClient::from_conf(
+            aws_sdk_sqs::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
+    }
+
+    /// Create a new SNS client using the shared config
+    pub async fn sns_client() -> SnsClient {
+        let config = Self::get_config().await;
+        SnsClient::from_conf(
+            aws_sdk_sns::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
+    }
+
+    /// Create a new SSM client using the shared config
+    pub async fn ssm_client() -> SsmClient {
+        let config = Self::get_config().await;
+        SsmClient::from_conf(
+            aws_sdk_ssm::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
+    }
+
+    /// Create a new CloudFormation client using the shared config
+    pub async fn cloudformation_client() -> CloudFormationClient {
+        let config = Self::get_config().await;
+        CloudFormationClient::from_conf(
+            aws_sdk_cloudformation::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
+    }
+
+    /// Create a new Secrets Manager client using the shared config
+    pub async fn secretsmanager_client() -> SecretsManagerClient {
+        let config = Self::get_config().await;
+        SecretsManagerClient::from_conf(
+            aws_sdk_secretsmanager::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
+    }
+
+    /// Create a new Batch client using the shared config
+    pub async fn batch_client() -> BatchClient {
+        let config = Self::get_config().await;
+        BatchClient::from_conf(
+            aws_sdk_batch::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
+    }
+
+    /// Create a new CloudTrail client using the shared config
+    pub async fn cloudtrail_client() -> CloudTrailClient {
+        let config = Self::get_config().await;
+        CloudTrailClient::from_conf(
+            aws_sdk_cloudtrail::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
+    }
+
+    /// Create a new Compute Optimizer client using the shared config
+    pub async fn compute_optimizer_client() -> ComputeOptimizerClient {
+        let config = Self::get_config().await;
+        ComputeOptimizerClient::from_conf(
+            aws_sdk_computeoptimizer::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
+    }
+
+    /// Create a new EventBridge client using the shared config
+    pub async fn eventbridge_client() -> EventBridgeClient {
+        let config = Self::get_config().await;
+        EventBridgeClient::from_conf(
+            aws_sdk_eventbridge::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
+    }
+
+    /// Create a new Synthetics client using the shared config
+    pub async fn synthetics_client() -> SyntheticsClient {
+        let config = Self::get_config().await;
+        SyntheticsClient::from_conf(
+            aws_sdk_synthetics::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
+    }
+
+    /// Create a new Service Quotas client using the shared config
+    pub async fn service_quotas_client() -> ServiceQuotasClient {
+        let config = Self::get_config().await;
+        ServiceQuotasClient::from_conf(
+            aws_sdk_servicequotas::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
     }
 
     /// Create a new STS client using the shared config (for credential validation)
     pub async fn sts_client() -> StsClient {
         let config = Self::get_config().await;
-        StsClient::new(&config)
+        StsClient::from_conf(
+            aws_sdk_sts::config::Builder::from(&*config)
+                .interceptor(CallCounter)
+                .build(),
+        )
     }
 
     /// Validate credentials using AWS STS GetCallerIdentity
@@ -78,8 +429,11 @@ impl AwsSessionManager {
         status_messages.push("Checking AWS credentials...".to_string());
 
         // Get current profile info for display (AWS SDK handles actual profile resolution)
-        let profile =
-            std::env::var("AWS_PROFILE").unwrap_or_else(|_| "default (implicit)".to_string());
+        let profile = PROFILE_OVERRIDE
+            .get()
+            .cloned()
+            .or_else(|| std::env::var("AWS_PROFILE").ok())
+            .unwrap_or_else(|| "default (implicit)".to_string());
 
         // Load config once - AWS SDK handles the credential provider chain
         let config = Self::get_config().await;
@@ -179,7 +533,7 @@ impl AwsSessionManager {
     /// Note: This is generally not needed as AWS SDK handles credential refresh automatically
     pub async fn reload_config() -> Arc<SdkConfig> {
         // Force reload by creating new config
-        let config = aws_config::defaults(BehaviorVersion::latest()).load().await;
+        let config = Self::loader().load().await;
         let new_config = Arc::new(config);
 
         // Update the global config
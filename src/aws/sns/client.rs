@@ -0,0 +1,56 @@
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::session::AwsSessionManager;
+use anyhow::Result;
+use aws_sdk_sns::Client as SnsClient;
+
+/// SNS client operations - topic discovery
+pub struct SnsClientManager {
+    client: SnsClient,
+}
+
+impl SnsClientManager {
+    /// Create a new SNS client manager using shared AWS session
+    pub async fn new() -> Self {
+        let client = AwsSessionManager::sns_client().await;
+        Self { client }
+    }
+
+    /// ARNs of all topics in the account/region, sorted
+    pub async fn list_topics(&self) -> Result<Vec<String>> {
+        let mut topics = Vec::new();
+        let mut next_token: Option<String> = None;
+
+        loop {
+            let resp = match self
+                .client
+                .list_topics()
+                .set_next_token(next_token.clone())
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "list SNS topics",
+                        "SNS ListTopics permissions",
+                    ));
+                }
+            };
+
+            topics.extend(
+                resp.topics()
+                    .iter()
+                    .filter_map(|topic| topic.topic_arn().map(str::to_string)),
+            );
+
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        topics.sort();
+        Ok(topics)
+    }
+}
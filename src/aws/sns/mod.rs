@@ -0,0 +1,4 @@
+// SNS service module - topics offered as alarm notification targets
+pub mod client;
+
+pub use client::SnsClientManager;
@@ -0,0 +1,127 @@
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::session::AwsSessionManager;
+use crate::jobs::JobReporter;
+use anyhow::{anyhow, Result};
+use aws_sdk_sqs::types::MessageAttributeValue;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Send a single message to a queue, returning the new message ID
+///
+/// This is a write call; callers are responsible for checking the write gate
+/// before invoking it.
+pub async fn send_test_message(
+    queue_url: &str,
+    body: String,
+    attributes: BTreeMap<String, String>,
+    fifo: bool,
+) -> Result<String> {
+    let client = AwsSessionManager::sqs_client().await;
+
+    let mut request = client
+        .send_message()
+        .queue_url(queue_url)
+        .message_body(body);
+
+    for (name, value) in attributes {
+        let attribute = MessageAttributeValue::builder()
+            .data_type("String")
+            .string_value(value)
+            .build()?;
+        request = request.message_attributes(name, attribute);
+    }
+
+    // FIFO queues require a group ID; a unique dedup ID keeps repeated tests distinct
+    if fifo {
+        let dedup_id = format!(
+            "awscw-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        );
+        request = request
+            .message_group_id("awscw-test")
+            .message_deduplication_id(dedup_id);
+    }
+
+    match request.send().await {
+        Ok(resp) => resp
+            .message_id()
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("SQS did not return a message ID")),
+        Err(e) => Err(AwsErrorHandler::handle_aws_error(
+            e,
+            "send SQS test message",
+            "SQS SendMessage permissions",
+        )),
+    }
+}
+
+/// How often a running redrive is checked on
+const REDRIVE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Move every message in a dead-letter queue back to the queues it came from,
+/// reporting progress until the move task ends
+///
+/// Runs as a background job. This is a write call; callers are responsible for
+/// checking the write gate before invoking it.
+pub async fn redrive_dead_letter_queue(queue_arn: String, reporter: JobReporter) -> Result<String> {
+    let client = AwsSessionManager::sqs_client().await;
+    if let Err(e) = client
+        .start_message_move_task()
+        .source_arn(&queue_arn)
+        .send()
+        .await
+    {
+        return Err(AwsErrorHandler::handle_aws_error(
+            e,
+            "start SQS redrive",
+            "SQS StartMessageMoveTask permissions",
+        ));
+    }
+
+    loop {
+        tokio::time::sleep(REDRIVE_POLL_INTERVAL).await;
+        let resp = match client
+            .list_message_move_tasks()
+            .source_arn(&queue_arn)
+            .max_results(1)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Err(AwsErrorHandler::handle_aws_error(
+                    e,
+                    "fetch SQS redrive status",
+                    "SQS ListMessageMoveTasks permissions",
+                ))
+            }
+        };
+        // The most recent task is listed first
+        let Some(task) = resp.results().first() else {
+            continue;
+        };
+        let moved = task.approximate_number_of_messages_moved();
+        match task.status().unwrap_or_default() {
+            "COMPLETED" => return Ok(format!("Moved {moved} messages")),
+            "FAILED" => {
+                return Err(anyhow!(
+                    "Redrive failed after {moved} messages: {}",
+                    task.failure_reason().unwrap_or("no reason given")
+                ))
+            }
+            "CANCELLED" => return Err(anyhow!("Redrive cancelled after {moved} messages")),
+            _ => {
+                let total = task.approximate_number_of_messages_to_move();
+                reporter.progress(
+                    total
+                        .filter(|&total| total > 0)
+                        .map(|total| moved as f64 / total as f64),
+                    match total {
+                        Some(total) => format!("Moved {moved} of {total} messages"),
+                        None => format!("Moved {moved} messages"),
+                    },
+                );
+            }
+        }
+    }
+}
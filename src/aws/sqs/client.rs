@@ -0,0 +1,137 @@
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::session::AwsSessionManager;
+use crate::models::SqsQueue;
+use anyhow::Result;
+use aws_sdk_sqs::types::QueueAttributeName;
+use aws_sdk_sqs::Client as SqsClient;
+use futures::stream::{self, StreamExt};
+
+/// GetQueueAttributes calls in flight at once while listing queues
+const MAX_CONCURRENT_QUEUE_LOADS: usize = 10;
+
+/// SQS client operations - centralized AWS SQS API calls
+pub struct SqsClientManager {
+    client: SqsClient,
+}
+
+impl SqsClientManager {
+    /// Create a new SQS client manager using shared AWS session
+    pub async fn new() -> Self {
+        let client = AwsSessionManager::sqs_client().await;
+        Self { client }
+    }
+
+    /// Load all queues in the account/region along with their attributes
+    pub async fn load_queues(&self) -> Result<Vec<SqsQueue>> {
+        let mut queue_urls = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let resp = match self
+                .client
+                .list_queues()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "list SQS queues",
+                        "SQS list permissions",
+                    ));
+                }
+            };
+
+            queue_urls.extend(resp.queue_urls().iter().cloned());
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        // A queue whose attributes fail to load is listed with the error instead
+        // of failing the whole list
+        let mut queues: Vec<SqsQueue> = stream::iter(queue_urls)
+            .map(|url| async move {
+                match self.load_queue(url.clone()).await {
+                    Ok(queue) => queue,
+                    Err(e) => SqsQueue {
+                        name: queue_name_from_url(&url),
+                        url,
+                        attributes: Vec::new(),
+                        error: Some(format!("{e:#}")),
+                    },
+                }
+            })
+            .buffer_unordered(MAX_CONCURRENT_QUEUE_LOADS)
+            .collect()
+            .await;
+        queues.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(queues)
+    }
+
+    /// Load a single queue's attributes
+    pub async fn load_queue(&self, url: String) -> Result<SqsQueue> {
+        let resp = match self
+            .client
+            .get_queue_attributes()
+            .queue_url(&url)
+            .attribute_names(QueueAttributeName::All)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Err(AwsErrorHandler::handle_aws_error(
+                    e,
+                    "fetch SQS queue attributes",
+                    "SQS GetQueueAttributes permissions",
+                ));
+            }
+        };
+
+        let mut attributes: Vec<(String, String)> = resp
+            .attributes()
+            .map(|attrs| {
+                attrs
+                    .iter()
+                    .map(|(key, value)| (key.as_str().to_string(), value.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        attributes.extend(self.load_queue_tags(&url).await);
+        attributes.sort();
+
+        Ok(SqsQueue {
+            name: queue_name_from_url(&url),
+            url,
+            attributes,
+            error: None,
+        })
+    }
+
+    /// Queue tags as `Tag: <key>` rows, matching RDS attributes
+    ///
+    /// Tags are optional detail, so a missing ListQueueTags permission
+    /// leaves them out instead of failing the queue.
+    async fn load_queue_tags(&self, url: &str) -> Vec<(String, String)> {
+        match self.client.list_queue_tags().queue_url(url).send().await {
+            Ok(resp) => resp
+                .tags()
+                .map(|tags| {
+                    tags.iter()
+                        .map(|(key, value)| (format!("Tag: {key}"), value.clone()))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+/// Queue URLs end with the queue name (https://sqs.<region>.amazonaws.com/<account>/<name>)
+fn queue_name_from_url(url: &str) -> String {
+    url.rsplit('/').next().unwrap_or(url).to_string()
+}
@@ -0,0 +1,266 @@
+// Queue folders
+//
+// A flat list of hundreds of queues is hard to find anything in. 'f' on the
+// SQS list groups the queues into collapsible folders, each headed by the
+// messages waiting and in flight across its queues and the oldest message
+// among them. A queue's folder is the value of a tag, when one is configured
+// and the queue has it, else its name up to the first separator:
+//
+//   [sqs.folders]
+//   tag = "team"
+//   separator = "-"
+//
+// so orders-created and orders-created-dlq share the "orders" folder. A
+// queue whose name has no separator heads a folder of its own name.
+
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::limiter;
+use crate::aws::session::AwsSessionManager;
+use crate::models::{ServiceInstance, SqsQueue};
+use anyhow::Result;
+use aws_sdk_cloudwatch::primitives::DateTime;
+use aws_sdk_cloudwatch::types::{Dimension, Metric, MetricDataQuery, MetricStat, ScanBy};
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::time::{Duration, SystemTime};
+
+/// GetMetricData takes at most this many queries per call
+const QUERIES_PER_CALL: usize = 500;
+
+/// How far back the latest oldest-message age is looked for
+const AGE_LOOKBACK: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct FolderConfig {
+    pub tag: Option<String>, // Tag naming the folder; queues without it fall back to the name
+    pub separator: String,
+}
+
+impl Default for FolderConfig {
+    fn default() -> Self {
+        Self {
+            tag: None,
+            separator: "-".to_string(),
+        }
+    }
+}
+
+impl FolderConfig {
+    pub fn folder_of(&self, queue: &SqsQueue) -> String {
+        let tagged = self
+            .tag
+            .as_deref()
+            .and_then(|tag| queue.attribute(&format!("Tag: {tag}")));
+        if let Some(folder) = tagged {
+            return folder.to_string();
+        }
+        match queue.name.split_once(self.separator.as_str()) {
+            Some((prefix, _)) if !self.separator.is_empty() => prefix.to_string(),
+            _ => queue.name.clone(),
+        }
+    }
+}
+
+/// A folder of queues with the totals shown on its row
+#[derive(Debug, Clone, PartialEq)]
+pub struct Folder {
+    pub name: String,
+    pub queues: Vec<usize>, // Indices into the instance list, in list order
+    pub visible: u64,       // ApproximateNumberOfMessages across the queues
+    pub in_flight: u64,     // ApproximateNumberOfMessagesNotVisible across the queues
+    pub oldest_age: Option<f64>, // Oldest message among the queues, seconds; None before ages load
+}
+
+/// A row of the folder tree
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FolderRow {
+    Folder(usize), // Index into the folders
+    Queue(usize),  // Index into the instance list
+}
+
+/// The listed queues' folders, sorted by name
+pub fn group(
+    config: &FolderConfig,
+    instances: &[ServiceInstance],
+    oldest_ages: &HashMap<String, f64>,
+) -> Vec<Folder> {
+    let mut folders: BTreeMap<String, Folder> = BTreeMap::new();
+    for (index, instance) in instances.iter().enumerate() {
+        let ServiceInstance::Sqs(queue) = instance else {
+            continue;
+        };
+        let name = config.folder_of(queue);
+        let folder = folders.entry(name.clone()).or_insert_with(|| Folder {
+            name,
+            queues: Vec::new(),
+            visible: 0,
+            in_flight: 0,
+            oldest_age: None,
+        });
+        let count = |attribute: &str| {
+            queue
+                .attribute(attribute)
+                .and_then(|value| value.parse::<u64>().ok())
+                .unwrap_or(0)
+        };
+        folder.queues.push(index);
+        folder.visible += count("ApproximateNumberOfMessages");
+        folder.in_flight += count("ApproximateNumberOfMessagesNotVisible");
+        if let Some(&age) = oldest_ages.get(&queue.name) {
+            folder.oldest_age = Some(folder.oldest_age.map_or(age, |oldest| oldest.max(age)));
+        }
+    }
+    folders.into_values().collect()
+}
+
+/// Each folder followed by its queues, unless it is collapsed
+pub fn rows(folders: &[Folder], collapsed: &BTreeSet<String>) -> Vec<FolderRow> {
+    let mut rows = Vec::new();
+    for (index, folder) in folders.iter().enumerate() {
+        rows.push(FolderRow::Folder(index));
+        if !collapsed.contains(&folder.name) {
+            rows.extend(folder.queues.iter().map(|&queue| FolderRow::Queue(queue)));
+        }
+    }
+    rows
+}
+
+/// Latest ApproximateAgeOfOldestMessage of each queue, in seconds
+///
+/// Queues without a recent datapoint, e.g. ones that have been empty, are
+/// left out.
+pub async fn load_oldest_ages(queue_names: &[String]) -> Result<HashMap<String, f64>> {
+    let client = AwsSessionManager::cloudwatch_client().await;
+    let end_time = SystemTime::now();
+    let start_time = end_time - AGE_LOOKBACK;
+    let mut ages = HashMap::new();
+
+    for (chunk_index, chunk) in queue_names.chunks(QUERIES_PER_CALL).enumerate() {
+        let query_id = |index: usize| format!("q{}", chunk_index * QUERIES_PER_CALL + index);
+        let queries: Vec<MetricDataQuery> = chunk
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                MetricDataQuery::builder()
+                    .id(query_id(index))
+                    .metric_stat(
+                        MetricStat::builder()
+                            .metric(
+                                Metric::builder()
+                                    .namespace("AWS/SQS")
+                                    .metric_name("ApproximateAgeOfOldestMessage")
+                                    .dimensions(
+                                        Dimension::builder().name("QueueName").value(name).build(),
+                                    )
+                                    .build(),
+                            )
+                            .period(300)
+                            .stat("Maximum")
+                            .build(),
+                    )
+                    .return_data(true)
+                    .build()
+            })
+            .collect();
+
+        let mut next_token: Option<String> = None;
+        loop {
+            let request = client
+                .get_metric_data()
+                .set_metric_data_queries(Some(queries.clone()))
+                .start_time(DateTime::from(start_time))
+                .end_time(DateTime::from(end_time))
+                .scan_by(ScanBy::TimestampDescending)
+                .set_next_token(next_token.take());
+            let response = limiter::cloudwatch()
+                .call("AWS/SQS", || request.send())
+                .await
+                .map_err(|e| {
+                    AwsErrorHandler::handle_aws_error(
+                        e,
+                        "fetch queue message ages",
+                        "cloudwatch:GetMetricData",
+                    )
+                })?;
+            for result in response.metric_data_results() {
+                let index = result
+                    .id()
+                    .and_then(|id| id.strip_prefix('q'))
+                    .and_then(|index| index.parse::<usize>().ok());
+                let (Some(index), Some(&latest)) = (index, result.values().first()) else {
+                    continue;
+                };
+                if let Some(name) = queue_names.get(index) {
+                    ages.entry(name.clone()).or_insert(latest);
+                }
+            }
+            next_token = response.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+    }
+    Ok(ages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue(name: &str, visible: &str, tags: &[(&str, &str)]) -> ServiceInstance {
+        let mut attributes = vec![(
+            "ApproximateNumberOfMessages".to_string(),
+            visible.to_string(),
+        )];
+        attributes.extend(
+            tags.iter()
+                .map(|(key, value)| (format!("Tag: {key}"), value.to_string())),
+        );
+        ServiceInstance::Sqs(SqsQueue {
+            name: name.to_string(),
+            url: String::new(),
+            attributes,
+            error: None,
+        })
+    }
+
+    #[test]
+    fn test_queues_grouped_by_tag_then_name_prefix() {
+        let config = FolderConfig {
+            tag: Some("team".to_string()),
+            ..FolderConfig::default()
+        };
+        let instances = vec![
+            queue("billing", "1", &[]),
+            queue("orders-created", "4", &[]),
+            queue("orders-created-dlq", "2", &[]),
+            queue("refunds", "7", &[("team", "orders")]),
+        ];
+        let ages = HashMap::from([
+            ("orders-created".to_string(), 30.0),
+            ("refunds".to_string(), 600.0),
+        ]);
+
+        let folders = group(&config, &instances, &ages);
+        assert_eq!(
+            folders
+                .iter()
+                .map(|folder| (folder.name.as_str(), folder.queues.clone(), folder.visible))
+                .collect::<Vec<_>>(),
+            vec![("billing", vec![0], 1), ("orders", vec![1, 2, 3], 13)]
+        );
+        assert_eq!(folders[0].oldest_age, None);
+        assert_eq!(folders[1].oldest_age, Some(600.0));
+
+        let collapsed = BTreeSet::from(["orders".to_string()]);
+        assert_eq!(
+            rows(&folders, &collapsed),
+            vec![
+                FolderRow::Folder(0),
+                FolderRow::Queue(0),
+                FolderRow::Folder(1)
+            ]
+        );
+    }
+}
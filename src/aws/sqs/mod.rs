@@ -0,0 +1,7 @@
+// SQS service module - centralized SQS operations
+pub mod actions;
+pub mod client;
+pub mod folders;
+pub mod policy;
+
+pub use client::SqsClientManager;
@@ -0,0 +1,354 @@
+// Access and encryption of an SQS queue
+//
+// Messages that never arrive are usually refused on the way in: the queue
+// policy does not let the SNS topic, EventBridge rule or S3 bucket send, a
+// Deny statement catches the sender, or the queue is encrypted with the AWS
+// managed key, which those services cannot use. Everything here is read from
+// the queue's attributes, so it needs no permission beyond listing queues.
+
+use crate::models::SqsQueue;
+use crate::ui::theme::Severity;
+use serde_json::Value;
+
+/// The key SQS manages for SSE-KMS when no customer managed key is chosen
+const AWS_MANAGED_KEY: &str = "alias/aws/sqs";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Encryption {
+    None,
+    SqsManaged, // SSE-SQS
+    Kms {
+        key_id: String,
+        data_key_reuse_secs: Option<u64>,
+    },
+}
+
+impl Encryption {
+    pub fn label(&self) -> String {
+        match self {
+            Self::None => "not encrypted".to_string(),
+            Self::SqsManaged => "SSE-SQS (keys managed by SQS)".to_string(),
+            Self::Kms {
+                key_id,
+                data_key_reuse_secs,
+            } => match data_key_reuse_secs {
+                Some(secs) => format!("SSE-KMS with {key_id}, data keys reused for {secs}s"),
+                None => format!("SSE-KMS with {key_id}"),
+            },
+        }
+    }
+
+    pub fn kms_key(&self) -> Option<&str> {
+        match self {
+            Self::Kms { key_id, .. } => Some(key_id),
+            _ => None,
+        }
+    }
+}
+
+/// A line of the pretty-printed policy
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyLine {
+    pub text: String,
+    pub principal: bool, // Part of a Principal or NotPrincipal element
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub text: String,
+    pub severity: Option<Severity>, // None for information
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct QueueAccess {
+    pub policy: Option<String>, // Pretty-printed, or as stored when it is not valid JSON
+    pub lines: Vec<PolicyLine>,
+    pub encryption: Option<Encryption>, // None until the queue's attributes are loaded
+    pub findings: Vec<Finding>,
+}
+
+pub fn inspect(queue: &SqsQueue) -> QueueAccess {
+    let encryption = (!queue.attributes.is_empty()).then(|| encryption(queue));
+    let Some(raw) = queue.attribute("Policy").filter(|raw| !raw.is_empty()) else {
+        return QueueAccess {
+            findings: vec![Finding {
+                text: "No queue policy: only IAM principals of the queue's own account can use \
+                       it, so SNS, EventBridge, S3 and other accounts cannot send"
+                    .to_string(),
+                severity: None,
+            }],
+            encryption,
+            ..QueueAccess::default()
+        };
+    };
+    let Ok(document) = serde_json::from_str::<Value>(raw) else {
+        return QueueAccess {
+            policy: Some(raw.to_string()),
+            lines: raw.lines().map(plain_line).collect(),
+            findings: vec![Finding {
+                text: "The policy is not valid JSON".to_string(),
+                severity: Some(Severity::Warning),
+            }],
+            encryption,
+        };
+    };
+
+    let pretty = serde_json::to_string_pretty(&document).unwrap_or_else(|_| raw.to_string());
+    let lines = highlight_principals(&pretty);
+    let findings = findings(&document, encryption.as_ref());
+    QueueAccess {
+        policy: Some(pretty),
+        lines,
+        encryption,
+        findings,
+    }
+}
+
+fn encryption(queue: &SqsQueue) -> Encryption {
+    match queue
+        .attribute("KmsMasterKeyId")
+        .filter(|key| !key.is_empty())
+    {
+        Some(key_id) => Encryption::Kms {
+            key_id: key_id.to_string(),
+            data_key_reuse_secs: queue
+                .attribute("KmsDataKeyReusePeriodSeconds")
+                .and_then(|secs| secs.parse().ok()),
+        },
+        None if queue.attribute("SqsManagedSseEnabled") == Some("true") => Encryption::SqsManaged,
+        None => Encryption::None,
+    }
+}
+
+fn plain_line(text: &str) -> PolicyLine {
+    PolicyLine {
+        text: text.to_string(),
+        principal: false,
+    }
+}
+
+/// Mark the lines of each Principal element, nested values and closing bracket included
+fn highlight_principals(pretty: &str) -> Vec<PolicyLine> {
+    let indent = |line: &str| line.len() - line.trim_start().len();
+    let mut open: Option<usize> = None; // Indent of the Principal key being marked
+    pretty
+        .lines()
+        .map(|text| {
+            let trimmed = text.trim_start();
+            let principal = match open {
+                Some(depth) if indent(text) > depth => true,
+                Some(depth) if indent(text) == depth && trimmed.starts_with(['}', ']']) => {
+                    open = None;
+                    true
+                }
+                _ => {
+                    open = None;
+                    let key = trimmed.starts_with("\"Principal\":")
+                        || trimmed.starts_with("\"NotPrincipal\":");
+                    if key && (trimmed.ends_with('{') || trimmed.ends_with('[')) {
+                        open = Some(indent(text));
+                    }
+                    key
+                }
+            };
+            PolicyLine {
+                text: text.to_string(),
+                principal,
+            }
+        })
+        .collect()
+}
+
+/// Strings of a value that may be one string or a list of them
+fn strings(value: Option<&Value>) -> Vec<String> {
+    match value {
+        Some(Value::String(text)) => vec![text.clone()],
+        Some(Value::Array(items)) => items
+            .iter()
+            .filter_map(|item| item.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Principals of a statement as "AWS arn:...", "Service sns.amazonaws.com" or "*"
+fn principals(statement: &Value) -> Vec<String> {
+    match statement.get("Principal") {
+        Some(Value::String(any)) => vec![any.clone()],
+        Some(Value::Object(kinds)) => kinds
+            .iter()
+            .flat_map(|(kind, value)| {
+                strings(Some(value))
+                    .into_iter()
+                    .map(move |principal| match principal.as_str() {
+                        "*" => "*".to_string(),
+                        _ => format!("{kind} {principal}"),
+                    })
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Conditions as "StringEquals aws:SourceArn=arn:...", one per key
+fn conditions(statement: &Value) -> Vec<String> {
+    let Some(Value::Object(operators)) = statement.get("Condition") else {
+        return Vec::new();
+    };
+    operators
+        .iter()
+        .flat_map(|(operator, keys)| {
+            keys.as_object()
+                .into_iter()
+                .flatten()
+                .map(move |(key, value)| {
+                    let values = match value {
+                        Value::String(text) => text.clone(),
+                        other => strings(Some(other)).join(", "),
+                    };
+                    format!("{operator} {key}={values}")
+                })
+        })
+        .collect()
+}
+
+fn findings(document: &Value, encryption: Option<&Encryption>) -> Vec<Finding> {
+    let statements: Vec<&Value> = match document.get("Statement") {
+        Some(Value::Array(statements)) => statements.iter().collect(),
+        Some(statement @ Value::Object(_)) => vec![statement],
+        _ => Vec::new(),
+    };
+
+    let mut findings = Vec::new();
+    let mut services = Vec::new();
+    for statement in statements {
+        let allow = statement.get("Effect").and_then(Value::as_str) == Some("Allow");
+        let actions = strings(statement.get("Action")).join(", ");
+        let principals = principals(statement);
+        let conditions = conditions(statement);
+        let when = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" when {}", conditions.join(" and "))
+        };
+
+        if !allow {
+            findings.push(Finding {
+                text: format!(
+                    "Denies {actions} to {}{when}: a sender caught by it is refused even when \
+                     allowed elsewhere",
+                    principal_list(&principals)
+                ),
+                severity: Some(Severity::Warning),
+            });
+            continue;
+        }
+        if principals.iter().any(|principal| principal == "*") && conditions.is_empty() {
+            findings.push(Finding {
+                text: format!("Anyone can {actions}: the queue is public"),
+                severity: Some(Severity::Critical),
+            });
+            continue;
+        }
+        for principal in &principals {
+            if let Some(service) = principal.strip_prefix("Service ") {
+                services.push(service.to_string());
+                findings.push(Finding {
+                    text: format!("{service} may {actions}{when}"),
+                    severity: None,
+                });
+            }
+        }
+    }
+
+    if let (Some(key), false) = (
+        encryption.and_then(Encryption::kms_key),
+        services.is_empty(),
+    ) {
+        let services = services.join(", ");
+        findings.push(if key == AWS_MANAGED_KEY {
+            Finding {
+                text: format!(
+                    "Encrypted with the AWS managed key, which {services} cannot use: their \
+                     messages are dropped. Use a customer managed key that grants them \
+                     kms:GenerateDataKey and kms:Decrypt"
+                ),
+                severity: Some(Severity::Critical),
+            }
+        } else {
+            Finding {
+                text: format!(
+                    "The policy of {key} must grant {services} kms:GenerateDataKey and \
+                     kms:Decrypt, or their messages are dropped"
+                ),
+                severity: None,
+            }
+        });
+    }
+    findings
+}
+
+fn principal_list(principals: &[String]) -> String {
+    if principals.is_empty() {
+        "everyone not excluded".to_string()
+    } else {
+        principals.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_principals_and_managed_key_are_flagged() {
+        let policy = r#"{"Version":"2012-10-17","Statement":[
+            {"Effect":"Allow","Principal":{"Service":"sns.amazonaws.com"},"Action":"sqs:SendMessage",
+             "Resource":"*","Condition":{"ArnEquals":{"aws:SourceArn":"arn:aws:sns:eu-west-1:123456789012:orders"}}},
+            {"Effect":"Allow","Principal":"*","Action":["sqs:ReceiveMessage"],"Resource":"*"}]}"#;
+        let queue = SqsQueue {
+            name: "orders".to_string(),
+            url: String::new(),
+            attributes: vec![
+                ("KmsMasterKeyId".to_string(), AWS_MANAGED_KEY.to_string()),
+                ("Policy".to_string(), policy.to_string()),
+            ],
+            error: None,
+        };
+        let access = inspect(&queue);
+        assert_eq!(
+            access.encryption.as_ref().unwrap().kms_key(),
+            Some(AWS_MANAGED_KEY)
+        );
+
+        let principal_lines: Vec<&str> = access
+            .lines
+            .iter()
+            .filter(|line| line.principal)
+            .map(|line| line.text.trim())
+            .collect();
+        assert_eq!(
+            principal_lines,
+            [
+                "\"Principal\": {",
+                "\"Service\": \"sns.amazonaws.com\"",
+                "},",
+                "\"Principal\": \"*\","
+            ]
+        );
+
+        let texts: Vec<&str> = access.findings.iter().map(|f| f.text.as_str()).collect();
+        assert!(texts[0].starts_with("sns.amazonaws.com may sqs:SendMessage when ArnEquals"));
+        assert_eq!(access.findings[1].severity, Some(Severity::Critical));
+        assert!(texts[2].starts_with("Encrypted with the AWS managed key"));
+
+        let unencrypted = SqsQueue {
+            attributes: vec![("SqsManagedSseEnabled".to_string(), "false".to_string())],
+            ..queue
+        };
+        let access = inspect(&unencrypted);
+        assert_eq!(access.encryption, Some(Encryption::None));
+        assert!(access.policy.is_none());
+        assert_eq!(access.findings.len(), 1);
+    }
+}
@@ -0,0 +1,43 @@
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::session::AwsSessionManager;
+use anyhow::{anyhow, Result};
+use aws_sdk_ssm::Client as SsmClient;
+
+/// SSM client operations - Parameter Store access
+pub struct SsmClientManager {
+    client: SsmClient,
+}
+
+impl SsmClientManager {
+    /// Create a new SSM client manager using shared AWS session
+    pub async fn new() -> Self {
+        let client = AwsSessionManager::ssm_client().await;
+        Self { client }
+    }
+
+    /// Read a parameter's value, decrypting SecureString parameters
+    pub async fn get_parameter(&self, name: &str) -> Result<String> {
+        let resp = match self
+            .client
+            .get_parameter()
+            .name(name)
+            .with_decryption(true)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Err(AwsErrorHandler::handle_aws_error(
+                    e,
+                    &format!("read SSM parameter {name}"),
+                    "SSM GetParameter permissions",
+                ));
+            }
+        };
+
+        resp.parameter()
+            .and_then(|parameter| parameter.value())
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("SSM parameter {name} has no value"))
+    }
+}
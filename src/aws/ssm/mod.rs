@@ -0,0 +1,4 @@
+// SSM service module - Parameter Store reads for shared configuration
+pub mod client;
+
+pub use client::SsmClientManager;
@@ -0,0 +1,188 @@
+use crate::aws::error_utils::AwsErrorHandler;
+use crate::aws::session::AwsSessionManager;
+use crate::models::{Canary, CanaryRun};
+use anyhow::Result;
+use aws_sdk_synthetics::primitives::DateTime;
+use aws_sdk_synthetics::Client as SyntheticsClient;
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+/// Synthetics client operations - canaries and their last runs
+pub struct SyntheticsClientManager {
+    client: SyntheticsClient,
+}
+
+impl SyntheticsClientManager {
+    /// Create a new Synthetics client manager using shared AWS session
+    pub async fn new() -> Self {
+        let client = AwsSessionManager::synthetics_client().await;
+        Self { client }
+    }
+
+    /// Load all canaries in the account/region with their most recent run
+    pub async fn load_canaries(&self) -> Result<Vec<Canary>> {
+        let mut canaries = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let resp = match self
+                .client
+                .describe_canaries()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "list Synthetics canaries",
+                        "Synthetics DescribeCanaries permissions",
+                    ));
+                }
+            };
+
+            canaries.extend(resp.canaries().iter().map(canary_from_sdk));
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        let mut last_runs = self.load_last_runs(None).await?;
+        for canary in &mut canaries {
+            canary.last_run = last_runs.remove(&canary.name);
+        }
+        canaries.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(canaries)
+    }
+
+    /// Reload a single canary and its most recent run
+    pub async fn load_canary(&self, name: &str) -> Result<Canary> {
+        let resp = match self.client.describe_canaries().names(name).send().await {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Err(AwsErrorHandler::handle_aws_error(
+                    e,
+                    "describe Synthetics canary",
+                    "Synthetics DescribeCanaries permissions",
+                ));
+            }
+        };
+        let mut canary = resp
+            .canaries()
+            .first()
+            .map(canary_from_sdk)
+            .ok_or_else(|| anyhow::anyhow!("Canary {name} not found"))?;
+        canary.last_run = self.load_last_runs(Some(name)).await?.remove(&canary.name);
+        Ok(canary)
+    }
+
+    /// Most recent run per canary name, optionally limited to one canary
+    async fn load_last_runs(&self, name: Option<&str>) -> Result<HashMap<String, CanaryRun>> {
+        let mut runs = HashMap::new();
+        let mut next_token = None;
+
+        loop {
+            let resp = match self
+                .client
+                .describe_canaries_last_run()
+                .set_names(name.map(|name| vec![name.to_string()]))
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    return Err(AwsErrorHandler::handle_aws_error(
+                        e,
+                        "fetch Synthetics canary runs",
+                        "Synthetics DescribeCanariesLastRun permissions",
+                    ));
+                }
+            };
+
+            for last_run in resp.canaries_last_run() {
+                if let (Some(name), Some(run)) = (last_run.canary_name(), last_run.last_run()) {
+                    runs.insert(name.to_string(), run_from_sdk(run));
+                }
+            }
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+        Ok(runs)
+    }
+}
+
+fn canary_from_sdk(canary: &aws_sdk_synthetics::types::Canary) -> Canary {
+    let state = canary
+        .status()
+        .and_then(|status| status.state())
+        .map(|state| state.as_str().to_string())
+        .unwrap_or_else(|| "UNKNOWN".to_string());
+    let schedule = canary
+        .schedule()
+        .and_then(|schedule| schedule.expression())
+        .unwrap_or_default()
+        .to_string();
+    let runtime = canary.runtime_version().unwrap_or_default().to_string();
+    let artifact_location = canary
+        .artifact_s3_location()
+        .unwrap_or_default()
+        .to_string();
+
+    let mut attributes = vec![
+        ("State".to_string(), state.clone()),
+        ("Schedule".to_string(), schedule.clone()),
+        ("RuntimeVersion".to_string(), runtime.clone()),
+        ("ArtifactS3Location".to_string(), artifact_location.clone()),
+    ];
+    if let Some(reason) = canary.status().and_then(|status| status.state_reason()) {
+        attributes.push(("StateReason".to_string(), reason.to_string()));
+    }
+    if let Some(arn) = canary.execution_role_arn() {
+        attributes.push(("ExecutionRoleArn".to_string(), arn.to_string()));
+    }
+    if let Some(tags) = canary.tags() {
+        attributes.extend(
+            tags.iter()
+                .map(|(key, value)| (format!("Tag: {key}"), value.clone())),
+        );
+    }
+    attributes.sort();
+
+    Canary {
+        name: canary.name().unwrap_or_default().to_string(),
+        state,
+        schedule,
+        runtime,
+        artifact_location,
+        last_run: None,
+        attributes,
+    }
+}
+
+fn run_from_sdk(run: &aws_sdk_synthetics::types::CanaryRun) -> CanaryRun {
+    let status = run.status();
+    CanaryRun {
+        state: status
+            .and_then(|status| status.state())
+            .map(|state| state.as_str().to_string())
+            .unwrap_or_else(|| "UNKNOWN".to_string()),
+        failure_reason: status
+            .and_then(|status| status.state_reason())
+            .filter(|reason| !reason.is_empty())
+            .map(str::to_string),
+        completed: run
+            .timeline()
+            .and_then(|timeline| timeline.completed())
+            .map(system_time),
+        artifact_location: run.artifact_s3_location().unwrap_or_default().to_string(),
+    }
+}
+
+fn system_time(ts: &DateTime) -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_secs(ts.secs().max(0) as u64)
+}
@@ -0,0 +1,39 @@
+// CloudWatch Synthetics canaries
+pub mod client;
+
+pub use client::SyntheticsClientManager;
+
+/// S3 console link for a canary's artifacts
+///
+/// Synthetics reports locations as `bucket/prefix`, sometimes with an
+/// `s3://` scheme; the console expects the prefix to end with a slash.
+pub fn artifacts_console_url(location: &str) -> Option<String> {
+    let location = location.trim_start_matches("s3://").trim_matches('/');
+    let (bucket, prefix) = location.split_once('/').unwrap_or((location, ""));
+    if bucket.is_empty() {
+        return None;
+    }
+    let mut url = format!("https://s3.console.aws.amazon.com/s3/buckets/{bucket}");
+    if !prefix.is_empty() {
+        url.push_str(&format!("?prefix={prefix}/"));
+    }
+    Some(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builds_console_links_from_artifact_locations() {
+        assert_eq!(
+            artifacts_console_url("cw-syn-results-123/canary/us-east-1/checkout").as_deref(),
+            Some("https://s3.console.aws.amazon.com/s3/buckets/cw-syn-results-123?prefix=canary/us-east-1/checkout/")
+        );
+        assert_eq!(
+            artifacts_console_url("s3://artifacts/").as_deref(),
+            Some("https://s3.console.aws.amazon.com/s3/buckets/artifacts")
+        );
+        assert_eq!(artifacts_console_url(""), None);
+    }
+}
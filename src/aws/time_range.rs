@@ -1,7 +1,17 @@
+use super::metrics::types::MetricResolution;
 use anyhow::Result;
 use std::time::Duration;
 
-#[derive(Debug, Clone, Copy)]
+/// Sub-minute periods CloudWatch accepts for high-resolution metrics
+const HIGH_RESOLUTION_PERIODS: [i32; 4] = [1, 5, 10, 30];
+
+/// Standard periods offered when cycling through granularities
+const STANDARD_PERIODS: [i32; 6] = [60, 300, 900, 3600, 21600, 86400];
+
+/// Maximum datapoints returned by a single GetMetricStatistics call
+pub const MAX_DATAPOINTS_PER_REQUEST: u64 = 1440;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TimeUnit {
     Minutes,
     Hours,
@@ -10,11 +20,12 @@ pub enum TimeUnit {
     Months,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct TimeRange {
     pub value: u32,
     pub unit: TimeUnit,
     pub period_days: u32,
+    pub period_override: Option<i32>, // Explicit period chosen by the user, in seconds
 }
 
 impl TimeRange {
@@ -38,9 +49,33 @@ impl TimeRange {
             value,
             unit,
             period_days,
+            period_override: None,
         })
     }
 
+    /// Parse a command-line range such as `30m`, `3h`, `7d` or `2w`
+    pub fn parse(text: &str) -> Result<Self> {
+        let split = text
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(|| anyhow::anyhow!("Range '{text}' needs a unit: m, h, d or w"))?;
+        let (value, unit) = text.split_at(split);
+        let value: u32 = value
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Range '{text}' must start with a number"))?;
+        let unit = match unit {
+            "m" => TimeUnit::Minutes,
+            "h" => TimeUnit::Hours,
+            "d" => TimeUnit::Days,
+            "w" => TimeUnit::Weeks,
+            other => {
+                return Err(anyhow::anyhow!(
+                    "Unknown range unit '{other}', expected m, h, d or w"
+                ))
+            }
+        };
+        Self::new(value, unit, 1)
+    }
+
     pub fn duration(self) -> Duration {
         let seconds = match self.unit {
             TimeUnit::Minutes => self.value as u64 * 60,
@@ -51,9 +86,38 @@ impl TimeRange {
         };
         Duration::from_secs(seconds)
     }
+
+    /// Periods that are valid for this range at the given metric resolution
+    ///
+    /// Sub-minute periods are only offered for high-resolution metrics and only
+    /// while the whole range is still inside CloudWatch's 3-hour high-resolution
+    /// retention window. Periods that would exceed the per-request datapoint
+    /// limit are filtered out.
+    pub fn valid_periods(&self, resolution: MetricResolution) -> Vec<i32> {
+        let duration_seconds = self.duration().as_secs();
+        let within_high_res_retention =
+            duration_seconds <= MetricResolution::HIGH_RESOLUTION_RETENTION_SECS;
+
+        let fine_periods = if resolution == MetricResolution::High && within_high_res_retention {
+            HIGH_RESOLUTION_PERIODS.to_vec()
+        } else {
+            Vec::new()
+        };
+
+        fine_periods
+            .into_iter()
+            .chain(STANDARD_PERIODS)
+            .filter(|&period| duration_seconds / period as u64 <= MAX_DATAPOINTS_PER_REQUEST)
+            .filter(|&period| (period as u64) < duration_seconds.max(60))
+            .collect()
+    }
 }
 
 pub fn calculate_period_seconds(time_range: &TimeRange) -> i32 {
+    if let Some(period) = time_range.period_override {
+        return period;
+    }
+
     // Calculate appropriate period based on time range duration and period_days
     let duration_seconds = time_range.duration().as_secs();
 
@@ -93,3 +157,50 @@ pub fn calculate_period_seconds(time_range: &TimeRange) -> i32 {
     // Ensure the period doesn't exceed CloudWatch limits (max 1 day = 86400 seconds)
     adjusted_period.min(86400)
 }
+
+/// Human readable label for a period in seconds (e.g. "10s", "5m", "1h")
+pub fn format_period(period_seconds: i32) -> String {
+    if period_seconds < 60 {
+        format!("{period_seconds}s")
+    } else if period_seconds < 3600 {
+        format!("{}m", period_seconds / 60)
+    } else if period_seconds < 86400 {
+        format!("{}h", period_seconds / 3600)
+    } else {
+        format!("{}d", period_seconds / 86400)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_high_resolution_periods_only_within_retention() {
+        let short = TimeRange::new(1, TimeUnit::Hours, 1).unwrap();
+        let periods = short.valid_periods(MetricResolution::High);
+        assert!(periods.contains(&5));
+        assert!(!periods.contains(&1)); // 3600 points would exceed the request limit
+
+        let standard = short.valid_periods(MetricResolution::Standard);
+        assert!(standard.iter().all(|&p| p >= 60));
+
+        let long = TimeRange::new(1, TimeUnit::Days, 1).unwrap();
+        let periods = long.valid_periods(MetricResolution::High);
+        assert!(periods.iter().all(|&p| p >= 60));
+    }
+
+    #[test]
+    fn test_period_override_takes_precedence() {
+        let mut range = TimeRange::new(5, TimeUnit::Minutes, 1).unwrap();
+        assert_eq!(calculate_period_seconds(&range), 60);
+        range.period_override = Some(10);
+        assert_eq!(calculate_period_seconds(&range), 10);
+        assert_eq!(format_period(10), "10s");
+        assert_eq!(format_period(300), "5m");
+
+        assert_eq!(TimeRange::parse("90m").unwrap().duration().as_secs(), 5400);
+        assert!(TimeRange::parse("3").is_err());
+        assert!(TimeRange::parse("3y").is_err());
+    }
+}
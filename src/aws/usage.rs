@@ -0,0 +1,324 @@
+// API calls made this session, per operation and per page
+//
+// Every client AwsSessionManager creates counts its calls through CallCounter,
+// an SDK interceptor, so the call sites need not know about it. Calls made
+// since the last frame are put down to the page shown in it: the calls an
+// input makes to open a page count against that page, as do its refreshes.
+// Background work, e.g. warming favorites, counts against whatever is shown.
+//
+// Of the services used, only CloudWatch charges for these calls. The estimate
+// uses us-east-1 list prices without the free tier: GetMetricData per metric
+// requested, GetMetricWidgetImage per metric drawn and the rest per request.
+//
+// Calls are timed as well, from the counter to the response or error, for the
+// fetch latency on the internals page.
+
+use aws_sdk_cloudwatch::operation::get_metric_data::GetMetricDataInput;
+use aws_sdk_cloudwatch::operation::get_metric_widget_image::GetMetricWidgetImageInput;
+use aws_smithy_runtime_api::box_error::BoxError;
+use aws_smithy_runtime_api::client::interceptors::context::{
+    BeforeSerializationInterceptorContextRef, FinalizerInterceptorContextRef, Input,
+};
+use aws_smithy_runtime_api::client::interceptors::Intercept;
+use aws_smithy_runtime_api::client::orchestrator::Metadata;
+use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
+use aws_smithy_types::config_bag::{ConfigBag, Storable, StoreReplace};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Dollars per CloudWatch request, or per metric for the operations charged by metric
+const REQUEST_PRICE: f64 = 0.01 / 1000.0;
+const METRIC_DATA_PRICE: f64 = 0.01 / 1000.0;
+const WIDGET_IMAGE_PRICE: f64 = 0.02 / 1000.0;
+
+static USAGE: Mutex<Usage> = Mutex::new(Usage::new());
+
+/// Calls of one operation, or of everything shown on one page
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Tally {
+    pub calls: u64,
+    pub metrics: u64, // Metrics requested by the operations charged per metric
+    pub cost: f64,    // Estimated dollars
+}
+
+impl Tally {
+    fn add(&mut self, other: Tally) {
+        self.calls += other.calls;
+        self.metrics += other.metrics;
+        self.cost += other.cost;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationUsage {
+    pub service: String,
+    pub operation: String,
+    pub tally: Tally,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Usage {
+    operations: BTreeMap<(String, String), Tally>,
+    pages: BTreeMap<String, Tally>,
+    unattributed: Tally, // Calls since the last frame, not yet put down to a page
+    completed: u64,      // Calls that got a response or error, and their summed time
+    call_time: Duration,
+}
+
+impl Usage {
+    const fn new() -> Self {
+        Self {
+            operations: BTreeMap::new(),
+            pages: BTreeMap::new(),
+            unattributed: Tally {
+                calls: 0,
+                metrics: 0,
+                cost: 0.0,
+            },
+            completed: 0,
+            call_time: Duration::ZERO,
+        }
+    }
+
+    /// Count a call; `metrics` is how many it requested, for the operations charged per metric
+    pub fn record(&mut self, service: &str, operation: &str, metrics: Option<u64>) {
+        let tally = Tally {
+            calls: 1,
+            metrics: metrics.unwrap_or(0),
+            cost: cost(service, operation, metrics),
+        };
+        self.operations
+            .entry((service.to_string(), operation.to_string()))
+            .or_default()
+            .add(tally);
+        self.unattributed.add(tally);
+    }
+
+    /// A call completed, successfully or not, after `elapsed`
+    pub fn complete(&mut self, elapsed: Duration) {
+        self.completed += 1;
+        self.call_time += elapsed;
+    }
+
+    /// Put the calls made since the last time down to `page`
+    pub fn attribute(&mut self, page: &str) {
+        let tally = std::mem::take(&mut self.unattributed);
+        if tally.calls > 0 {
+            self.pages.entry(page.to_string()).or_default().add(tally);
+        }
+    }
+
+    /// Operations, most expensive first, then most called
+    pub fn operations(&self) -> Vec<OperationUsage> {
+        let mut operations: Vec<OperationUsage> = self
+            .operations
+            .iter()
+            .map(|((service, operation), tally)| OperationUsage {
+                service: service.clone(),
+                operation: operation.clone(),
+                tally: *tally,
+            })
+            .collect();
+        operations.sort_by(|a, b| {
+            b.tally
+                .cost
+                .total_cmp(&a.tally.cost)
+                .then(b.tally.calls.cmp(&a.tally.calls))
+        });
+        operations
+    }
+
+    /// Pages, most expensive first, then most called
+    pub fn pages(&self) -> Vec<(String, Tally)> {
+        let mut pages: Vec<(String, Tally)> = self
+            .pages
+            .iter()
+            .map(|(page, tally)| (page.clone(), *tally))
+            .collect();
+        pages.sort_by(|(_, a), (_, b)| b.cost.total_cmp(&a.cost).then(b.calls.cmp(&a.calls)));
+        pages
+    }
+
+    pub fn total(&self) -> Tally {
+        let mut total = Tally::default();
+        for tally in self.operations.values() {
+            total.add(*tally);
+        }
+        total
+    }
+}
+
+/// Estimated dollars for one call
+fn cost(service: &str, operation: &str, metrics: Option<u64>) -> f64 {
+    if service != "CloudWatch" {
+        return 0.0;
+    }
+    let metrics = metrics.unwrap_or(1) as f64;
+    match operation {
+        "GetMetricData" => metrics * METRIC_DATA_PRICE,
+        "GetMetricWidgetImage" => metrics * WIDGET_IMAGE_PRICE,
+        _ => REQUEST_PRICE,
+    }
+}
+
+/// Dollars to a hundredth of a cent, as a session's calls rarely add up to a cent
+pub fn format_cost(cost: f64) -> String {
+    format!("${cost:.4}")
+}
+
+/// Operations listed in the report printed on exit
+const REPORT_OPERATIONS: usize = 5;
+
+/// What the session's calls came to, printed on exit; empty when nothing was called
+pub fn session_report() -> String {
+    let usage = snapshot();
+    let total = usage.total();
+    if total.calls == 0 {
+        return String::new();
+    }
+    let mut report = format!(
+        "API calls this session: {} (estimated CloudWatch cost {})\n",
+        total.calls,
+        format_cost(total.cost)
+    );
+    for operation in usage.operations().iter().take(REPORT_OPERATIONS) {
+        let name = format!("{} {}", operation.service, operation.operation);
+        report.push_str(&format!(
+            "  {name:<44} {:>7} calls  {}\n",
+            operation.tally.calls,
+            format_cost(operation.tally.cost)
+        ));
+    }
+    if let Some((page, tally)) = usage.pages().first() {
+        report.push_str(&format!(
+            "Most expensive page: {page} ({} calls, {})\n",
+            tally.calls,
+            format_cost(tally.cost)
+        ));
+    }
+    report
+}
+
+/// Count a call made without an SDK client, which CallCounter never sees
+pub fn record(service: &str, operation: &str) {
+    lock().record(service, operation, None);
+}
+
+/// A call counted with `record` got its answer or error after `elapsed`, retries included
+pub fn complete(elapsed: Duration) {
+    lock().complete(elapsed);
+}
+
+/// The calls counted so far
+pub fn snapshot() -> Usage {
+    lock().clone()
+}
+
+/// Put the calls made since the last frame down to `page`
+pub fn attribute(page: &str) {
+    lock().attribute(page);
+}
+
+/// Calls completed so far and the time they took between them
+pub fn call_time() -> (u64, Duration) {
+    let usage = lock();
+    (usage.completed, usage.call_time)
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Usage> {
+    USAGE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Counts and times each call a client makes; added to every client AwsSessionManager creates
+#[derive(Debug)]
+pub struct CallCounter;
+
+/// When the call being counted started, kept in its config bag until it completes
+#[derive(Debug)]
+struct CallStarted(Instant);
+
+impl Storable for CallStarted {
+    type Storer = StoreReplace<Self>;
+}
+
+impl Intercept for CallCounter {
+    fn name(&self) -> &'static str {
+        "CallCounter"
+    }
+
+    // Called once per call, retries aside, once the operation's metadata is known
+    fn read_before_serialization(
+        &self,
+        context: &BeforeSerializationInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        if let Some(metadata) = cfg.load::<Metadata>() {
+            lock().record(
+                metadata.service(),
+                metadata.name(),
+                metrics_requested(context.input()),
+            );
+        }
+        cfg.interceptor_state()
+            .store_put(CallStarted(Instant::now()));
+        Ok(())
+    }
+
+    // Called once the call has its response or error, retries included
+    fn read_after_execution(
+        &self,
+        _context: &FinalizerInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        if let Some(CallStarted(started)) = cfg.load::<CallStarted>() {
+            lock().complete(started.elapsed());
+        }
+        Ok(())
+    }
+}
+
+/// Metrics a call requests, for the operations charged per metric
+fn metrics_requested(input: &Input) -> Option<u64> {
+    if let Some(request) = input.downcast_ref::<GetMetricDataInput>() {
+        return Some(request.metric_data_queries().len() as u64);
+    }
+    let request = input.downcast_ref::<GetMetricWidgetImageInput>()?;
+    let widget: serde_json::Value = serde_json::from_str(request.metric_widget()?).ok()?;
+    Some(widget.get("metrics")?.as_array()?.len() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calls_are_priced_and_put_down_to_pages() {
+        let mut usage = Usage::new();
+        for _ in 0..3 {
+            usage.record("CloudWatch", "GetMetricStatistics", None);
+        }
+        usage.record("RDS", "DescribeDBInstances", None);
+        usage.attribute("RDS list");
+        usage.record("CloudWatch", "GetMetricData", Some(500));
+        usage.attribute("RDS metrics");
+        usage.attribute("Services"); // Nothing called since
+
+        let operations = usage.operations();
+        assert_eq!(operations[0].operation, "GetMetricData");
+        assert_eq!(operations[0].tally.metrics, 500);
+        assert_eq!(operations[1].tally.calls, 3);
+        assert_eq!(operations[2].tally.cost, 0.0);
+
+        let pages = usage.pages();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].0, "RDS metrics");
+        assert_eq!(pages[1].1.calls, 4);
+
+        let total = usage.total();
+        assert_eq!(total.calls, 5);
+        assert!((total.cost - 0.005 - 0.00003).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,201 @@
+// Bookmarked chart views
+//
+// A bookmark names a view to come back to: the resource, the charted metric,
+// whether the chart page was open, the time range and the period. Bookmarks
+// live in config.toml next to the other settings:
+//
+//   [[bookmarks]]
+//   name = "orders-db CPU last week"
+//   service = "rds"
+//   resource = "orders-db"
+//   metric = "CPU Utilization"
+//   range = "1w"
+//   period_secs = 3600
+//   chart = true
+//
+// The same fields as JSON are the share format: 'y' in the bookmarks panel
+// copies one, and `awscw bookmarks --import` adds the ones a teammate sent.
+
+use crate::aws::time_range::{calculate_period_seconds, TimeRange, TimeUnit};
+use crate::config;
+use crate::listing::parse_service;
+use crate::models::AwsService;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use toml_edit::{ArrayOfTables, DocumentMut, Item, Table};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub name: String,
+    pub service: String, // As given to --service, e.g. rds or sqs
+    pub resource: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metric: Option<String>, // Display name of the charted RDS metric
+    pub range: String, // e.g. 3h or 7d, as given to --range
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub period_secs: Option<i32>,
+    #[serde(default)]
+    pub chart: bool, // The metric's chart page rather than the metrics summary
+}
+
+impl Bookmark {
+    /// A bookmark of `resource` as shown with `time_range`, charting `metric` if given
+    pub fn new(
+        name: String,
+        service: &AwsService,
+        resource: String,
+        metric: Option<String>,
+        time_range: TimeRange,
+        chart: bool,
+    ) -> Self {
+        Self {
+            name,
+            service: service.short_name().to_ascii_lowercase(),
+            resource,
+            metric,
+            range: range_arg(time_range),
+            period_secs: Some(calculate_period_seconds(&time_range)),
+            chart,
+        }
+    }
+
+    pub fn service(&self) -> Result<AwsService> {
+        parse_service(&self.service)
+    }
+
+    /// The time range to restore, with the period the view had
+    pub fn time_range(&self) -> Result<TimeRange> {
+        let mut time_range = TimeRange::parse(&self.range)?;
+        time_range.period_override = self.period_secs;
+        Ok(time_range)
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Bookmarks from a shared JSON snippet: one bookmark or an array of them
+    pub fn parse_json(json: &str) -> Result<Vec<Self>> {
+        let bookmarks = match serde_json::from_str::<Vec<Self>>(json) {
+            Ok(bookmarks) => bookmarks,
+            Err(_) => vec![serde_json::from_str::<Self>(json).context("Invalid bookmark JSON")?],
+        };
+        for bookmark in &bookmarks {
+            bookmark.service()?;
+            bookmark.time_range()?;
+        }
+        Ok(bookmarks)
+    }
+}
+
+/// Bookmarks overlay: the saved views, and the name of one being added
+#[derive(Debug, Default)]
+pub struct BookmarksPanel {
+    pub selected_index: usize,
+    pub name_input: Option<String>, // Typed name of the view being bookmarked
+}
+
+/// A time range as `--range` takes it; months are given in days
+fn range_arg(time_range: TimeRange) -> String {
+    match time_range.unit {
+        TimeUnit::Minutes => format!("{}m", time_range.value),
+        TimeUnit::Hours => format!("{}h", time_range.value),
+        TimeUnit::Days => format!("{}d", time_range.value),
+        TimeUnit::Weeks => format!("{}w", time_range.value),
+        TimeUnit::Months => format!("{}d", time_range.value * 30),
+    }
+}
+
+/// Replace the bookmarks in the config file, leaving the rest of it as it was
+pub fn save(bookmarks: &[Bookmark]) -> Result<PathBuf> {
+    config::update_config_file(|document| write_to(bookmarks, document))
+}
+
+/// Add shared bookmarks to `existing` and save them; shared ones replace
+/// bookmarks of the same name
+pub fn import(existing: &[Bookmark], json: &str) -> Result<(PathBuf, usize)> {
+    let shared = Bookmark::parse_json(json)?;
+    let mut bookmarks: Vec<Bookmark> = existing
+        .iter()
+        .filter(|bookmark| !shared.iter().any(|new| new.name == bookmark.name))
+        .cloned()
+        .collect();
+    let count = shared.len();
+    bookmarks.extend(shared);
+    Ok((save(&bookmarks)?, count))
+}
+
+fn write_to(bookmarks: &[Bookmark], document: &mut DocumentMut) {
+    if bookmarks.is_empty() {
+        document.remove("bookmarks");
+        return;
+    }
+
+    let mut tables = ArrayOfTables::new();
+    for bookmark in bookmarks {
+        let mut table = Table::new();
+        table.insert("name", toml_edit::value(&bookmark.name));
+        table.insert("service", toml_edit::value(&bookmark.service));
+        table.insert("resource", toml_edit::value(&bookmark.resource));
+        if let Some(metric) = &bookmark.metric {
+            table.insert("metric", toml_edit::value(metric));
+        }
+        table.insert("range", toml_edit::value(&bookmark.range));
+        if let Some(period) = bookmark.period_secs {
+            table.insert("period_secs", toml_edit::value(i64::from(period)));
+        }
+        table.insert("chart", toml_edit::value(bookmark.chart));
+        tables.push(table);
+    }
+    document.insert("bookmarks", Item::ArrayOfTables(tables));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bookmark() -> Bookmark {
+        Bookmark::new(
+            "orders-db CPU last week".to_string(),
+            &AwsService::Rds,
+            "orders-db".to_string(),
+            Some("CPU Utilization".to_string()),
+            TimeRange::new(1, TimeUnit::Weeks, 7).unwrap(),
+            true,
+        )
+    }
+
+    #[test]
+    fn test_json_round_trip_and_validation() {
+        let bookmark = bookmark();
+        assert_eq!(bookmark.range, "1w");
+        assert_eq!(bookmark.service().unwrap(), AwsService::Rds);
+
+        let json = bookmark.to_json().unwrap();
+        assert_eq!(Bookmark::parse_json(&json).unwrap(), vec![bookmark.clone()]);
+        assert_eq!(
+            Bookmark::parse_json(&format!("[{json},{json}]"))
+                .unwrap()
+                .len(),
+            2
+        );
+        assert!(Bookmark::parse_json(&json.replace("\"rds\"", "\"ec2\"")).is_err());
+        assert!(Bookmark::parse_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_write_keeps_the_rest_of_the_config() {
+        let mut document: DocumentMut = "refresh_interval_secs = 15\n".parse().unwrap();
+        write_to(&[bookmark()], &mut document);
+        let written = document.to_string();
+        assert!(written.starts_with("refresh_interval_secs = 15\n"));
+        assert!(written.contains("[[bookmarks]]"), "{written}");
+
+        let config = config::Config::parse(&written).unwrap();
+        assert_eq!(config.bookmarks, vec![bookmark()]);
+
+        write_to(&[], &mut document);
+        assert!(!document.to_string().contains("bookmarks"));
+    }
+}
@@ -0,0 +1,358 @@
+// Command-line interface
+//
+// Without a subcommand (or with `tui`) the terminal UI starts. The other
+// subcommands run headless for scripts and scheduled jobs, and `completions`
+// prints a completion script for bash, zsh or fish generated from the same
+// definition.
+
+use clap::{value_parser, Arg, ArgAction, ArgGroup, Command};
+use clap_complete::Shell;
+
+/// Palette names accepted by `--theme`, as in `[theme] palette`
+pub const THEMES: [&str; 4] = ["default", "red-green", "blue-yellow", "monochrome"];
+
+pub fn command() -> Command {
+    Command::new("awscw")
+        .version(env!("CARGO_PKG_VERSION"))
+        .about("AWS CloudWatch TUI")
+        .arg(
+            Arg::new("profile")
+                .long("profile")
+                .global(true)
+                .help("AWS profile to use instead of AWS_PROFILE or the default"),
+        )
+        .arg(
+            Arg::new("region")
+                .long("region")
+                .global(true)
+                .help(
+                    "AWS region to use instead of the profile or environment default; \"all\" lists every region at once",
+                ),
+        )
+        .arg(
+            Arg::new("theme")
+                .long("theme")
+                .global(true)
+                .value_parser(THEMES)
+                .help("Color palette, overriding [theme] palette in the config file"),
+        )
+        .arg(
+            Arg::new("read-only")
+                .long("read-only")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help("Disable every action that modifies AWS resources, whatever the config says"),
+        )
+        .arg(
+            Arg::new("screen-reader")
+                .long("screen-reader")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help("Render pages as plain text for screen readers and braille displays"),
+        )
+        .arg(
+            Arg::new("ascii")
+                .long("ascii")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help("Draw borders and charts with ASCII characters only"),
+        )
+        .arg(
+            Arg::new("chaos")
+                .long("chaos")
+                .value_name("SEED")
+                .global(true)
+                .num_args(0..=1)
+                .default_missing_value("1")
+                .value_parser(value_parser!(u64))
+                .help("Developer aid: inject latency, throttling and truncated responses into AWS calls, repeatably for a seed"),
+        )
+        .arg(
+            Arg::new("panel")
+                .long("panel")
+                .value_name("WIDGET")
+                .help("Show one widget full-screen: sparkline:[service:]resource:metric[@range] or alarms"),
+        )
+        .arg(
+            Arg::new("control")
+                .long("control")
+                .value_name("SOCKET")
+                .help("Accept commands such as 'select rds orders-db; range 6h' on a Unix socket, or stdin for -"),
+        )
+        .subcommand(Command::new("tui").about("Start the terminal UI (the default)"))
+        .subcommand(
+            Command::new("list")
+                .about("Print the resources of a service, e.g. for jq or fzf")
+                .arg(service_arg().required(true))
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .value_parser(["text", "json"])
+                        .default_value("text")
+                        .help("text (one identifier per line) or json"),
+                ),
+        )
+        .subcommand(
+            Command::new("query")
+                .about("Print the datapoints of one metric")
+                .arg(
+                    Arg::new("resource")
+                        .required(true)
+                        .help("Instance identifier or queue name"),
+                )
+                .arg(
+                    Arg::new("metric")
+                        .required(true)
+                        .help("CloudWatch metric name, e.g. CPUUtilization"),
+                )
+                .arg(service_arg().default_value("rds"))
+                .arg(
+                    Arg::new("range").long("range").default_value("3h").help(
+                        "How far back to look: minutes, hours, days or weeks (30m, 3h, 7d, 2w)",
+                    ),
+                )
+                .arg(
+                    Arg::new("stat")
+                        .long("stat")
+                        .value_parser(["average", "sum", "maximum", "minimum"])
+                        .help("Statistic; defaults to the one the metric pages use"),
+                )
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .value_parser(["text", "json"])
+                        .default_value("text")
+                        .help("text (timestamp and value per line) or json"),
+                ),
+        )
+        .subcommand(
+            Command::new("backtest")
+                .about("Show when a threshold rule would have fired over a metric's history")
+                .arg(
+                    Arg::new("resource")
+                        .required(true)
+                        .help("Instance identifier or queue name"),
+                )
+                .arg(
+                    Arg::new("metric")
+                        .required(true)
+                        .help("CloudWatch metric name, e.g. CPUUtilization"),
+                )
+                .arg(service_arg().default_value("rds"))
+                .arg(
+                    Arg::new("above")
+                        .long("above")
+                        .value_parser(value_parser!(f64))
+                        .help("Fire when the metric is at or above this value"),
+                )
+                .arg(
+                    Arg::new("below")
+                        .long("below")
+                        .value_parser(value_parser!(f64))
+                        .help("Fire when the metric is at or below this value"),
+                )
+                .group(
+                    ArgGroup::new("threshold")
+                        .args(["above", "below"])
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("period")
+                        .long("period")
+                        .value_parser(value_parser!(u64).range(60..))
+                        .default_value("300")
+                        .help("Seconds per evaluated datapoint"),
+                )
+                .arg(
+                    Arg::new("evaluation-periods")
+                        .long("evaluation-periods")
+                        .value_parser(value_parser!(usize))
+                        .default_value("3")
+                        .help("Datapoints the rule looks back over"),
+                )
+                .arg(
+                    Arg::new("datapoints")
+                        .long("datapoints")
+                        .value_parser(value_parser!(usize))
+                        .help("Breaching datapoints among them needed to fire; defaults to all"),
+                )
+                .arg(Arg::new("range").long("range").default_value("7d").help(
+                    "How far back to replay: minutes, hours, days or weeks (30m, 3h, 7d, 2w)",
+                ))
+                .arg(
+                    Arg::new("stat")
+                        .long("stat")
+                        .value_parser(["average", "sum", "maximum", "minimum"])
+                        .help("Statistic; defaults to the one the metric pages use"),
+                ),
+        )
+        .subcommand(
+            Command::new("gaps")
+                .about("List periods a heartbeat metric was missing or zero, with availability")
+                .arg(
+                    Arg::new("resource")
+                        .required(true)
+                        .help("Instance identifier or queue name"),
+                )
+                .arg(Arg::new("metric").long("metric").help(
+                    "Metric to scan; defaults to DatabaseConnections (rds) or NumberOfMessagesSent (sqs)",
+                ))
+                .arg(service_arg().default_value("rds"))
+                .arg(
+                    Arg::new("range").long("range").default_value("7d").help(
+                        "How far back to scan: minutes, hours, days or weeks (30m, 3h, 7d, 2w)",
+                    ),
+                )
+                .arg(
+                    Arg::new("period")
+                        .long("period")
+                        .value_parser(value_parser!(u64).range(60..))
+                        .default_value("300")
+                        .help("Seconds per period; shorter gaps are not detected"),
+                )
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .value_parser(["text", "json"])
+                        .default_value("text")
+                        .help("text (one gap per line and a summary) or json"),
+                ),
+        )
+        .subcommand(
+            Command::new("watch")
+                .about("Print the latest key metrics of an RDS instance until interrupted")
+                .arg(
+                    Arg::new("instance")
+                        .required(true)
+                        .help("RDS instance identifier"),
+                )
+                .arg(
+                    Arg::new("interval")
+                        .long("interval")
+                        .value_parser(value_parser!(u64).range(10..))
+                        .default_value("60")
+                        .help("Seconds between samples"),
+                ),
+        )
+        .subcommand(
+            Command::new("export")
+                .about("Print a summary of every RDS instance for an ops report")
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .value_parser(["markdown", "md", "html"])
+                        .default_value("markdown")
+                        .help("markdown or html"),
+                )
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .help("Write the report to this file instead of stdout"),
+                ),
+        )
+        .subcommand(
+            Command::new("report")
+                .about("Write a Markdown report with SVG charts, e.g. from cron")
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .required(true)
+                        .help("Report file; charts go to a <name>-charts directory beside it"),
+                )
+                .arg(
+                    Arg::new("watchlist")
+                        .long("watchlist")
+                        .action(ArgAction::SetTrue)
+                        .help("Only include RDS instances on the watchlist"),
+                ),
+        )
+        .subcommand(
+            Command::new("bookmarks")
+                .about("Print the bookmarked views as JSON, or add shared ones")
+                .arg(
+                    Arg::new("import")
+                        .long("import")
+                        .value_name("JSON")
+                        .help("Bookmark JSON copied from the bookmarks panel (one or an array)"),
+                ),
+        )
+        .subcommand(
+            Command::new("completions")
+                .about("Print a shell completion script")
+                .arg(
+                    Arg::new("shell")
+                        .required(true)
+                        .value_parser(value_parser!(Shell))
+                        .help("bash, zsh, fish, elvish or powershell"),
+                ),
+        )
+}
+
+fn service_arg() -> Arg {
+    Arg::new("service")
+        .long("service")
+        .value_parser(["rds", "sqs", "synthetics", "eventbridge", "batch", "lambda"])
+        .help("rds, sqs, synthetics, eventbridge, batch or lambda")
+}
+
+/// Write the completion script for `shell` to stdout
+pub fn print_completions(shell: Shell) {
+    let mut command = command();
+    clap_complete::generate(shell, &mut command, "awscw", &mut std::io::stdout());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_definition_and_global_flags() {
+        command().debug_assert();
+
+        let matches = command()
+            .try_get_matches_from([
+                "awscw",
+                "query",
+                "orders-db",
+                "CPUUtilization",
+                "--region",
+                "eu-west-1",
+                "--read-only",
+            ])
+            .unwrap();
+        assert_eq!(
+            matches.get_one::<String>("region").map(String::as_str),
+            Some("eu-west-1")
+        );
+        assert!(matches.get_flag("read-only"));
+        let (name, query) = matches.subcommand().unwrap();
+        assert_eq!(name, "query");
+        assert_eq!(query.get_one::<String>("range").unwrap(), "3h");
+
+        assert!(command()
+            .try_get_matches_from(["awscw", "--theme", "sepia"])
+            .is_err());
+
+        // A backtest needs exactly one of --above and --below
+        assert!(command()
+            .try_get_matches_from(["awscw", "backtest", "orders-db", "CPUUtilization"])
+            .is_err());
+        assert!(command()
+            .try_get_matches_from([
+                "awscw",
+                "backtest",
+                "orders-db",
+                "CPUUtilization",
+                "--above",
+                "80",
+                "--below",
+                "10",
+            ])
+            .is_err());
+    }
+}
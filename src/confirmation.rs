@@ -0,0 +1,199 @@
+// Typed confirmation of write actions
+//
+// Every write action is held as a `Confirmation` until the phrase it shows
+// has been typed: a random four-character code, or the name of the resource
+// it acts on. The action can only be taken back out of the confirmation once
+// the phrase matches, so no action runs on a stray key press.
+//
+// How strict the phrase is follows the environment of the resources acted on,
+// read from a tag, with the strictest environment winning:
+//
+//   [actions]
+//   allow_writes = true
+//   confirm = "code"
+//   environment_tag = "Environment"
+//
+//   [actions.confirm_by_environment]
+//   production = "name"
+//   staging = "code"
+//
+// Resources without the tag, or with a label not listed, use `confirm`. A
+// resource that is not in the loaded lists has tags that can't be read, so it
+// gets the strictest phrase configured.
+
+use crate::config::ActionsConfig;
+use crate::models::PendingAction;
+use serde::Deserialize;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::SystemTime;
+
+const CODE_LENGTH: usize = 4;
+
+/// Characters of the random code, without look-alikes such as 0/o and 1/l
+const CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+/// What has to be typed to confirm a write action, least strict first
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Strictness {
+    #[default]
+    Code,
+    Name,
+}
+
+/// A resource's environment, from its environment tag
+#[derive(Debug, Clone, PartialEq)]
+pub enum Environment {
+    Tagged(String),
+    Untagged,
+    Unknown, // Not in the loaded lists, so its tags can't be read
+}
+
+impl Environment {
+    /// What the confirmation prompt shows; nothing for untagged resources
+    pub fn describe(&self) -> Option<String> {
+        match self {
+            Environment::Tagged(label) => Some(label.clone()),
+            Environment::Untagged => None,
+            Environment::Unknown => Some("unknown (not in the loaded list)".to_string()),
+        }
+    }
+}
+
+/// Strictness for resources in the given environments; the strictest one wins
+pub fn strictness<'a>(
+    config: &ActionsConfig,
+    environments: impl IntoIterator<Item = &'a Environment>,
+) -> Strictness {
+    environments
+        .into_iter()
+        .map(|environment| match environment {
+            Environment::Tagged(label) => config
+                .confirm_by_environment
+                .iter()
+                .find(|(listed, _)| listed.eq_ignore_ascii_case(label))
+                .map_or(config.confirm, |(_, strictness)| *strictness),
+            Environment::Untagged => config.confirm,
+            Environment::Unknown => config
+                .confirm_by_environment
+                .values()
+                .copied()
+                .fold(config.confirm, Strictness::max),
+        })
+        .max()
+        .unwrap_or(config.confirm)
+}
+
+/// A short code that differs between confirmations
+pub fn random_code() -> String {
+    let mut hasher = RandomState::new().build_hasher();
+    if let Ok(elapsed) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+        hasher.write_u128(elapsed.as_nanos());
+    }
+    let mut bits = hasher.finish();
+    (0..CODE_LENGTH)
+        .map(|_| {
+            let c = CODE_ALPHABET[(bits % CODE_ALPHABET.len() as u64) as usize];
+            bits /= CODE_ALPHABET.len() as u64;
+            c as char
+        })
+        .collect()
+}
+
+/// A write action waiting for its phrase to be typed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Confirmation {
+    action: PendingAction,
+    pub question: String, // e.g. "Create a manual snapshot of orders-db?"
+    pub phrase: String,   // What has to be typed
+    pub environment: Option<String>, // Strictest environment among the resources, if tagged or unknown
+    pub typed: String,
+}
+
+impl Confirmation {
+    pub fn new(
+        action: PendingAction,
+        question: String,
+        strictness: Strictness,
+        environment: Option<String>,
+    ) -> Self {
+        let phrase = match strictness {
+            Strictness::Code => random_code(),
+            Strictness::Name => action.target(),
+        };
+        Self {
+            action,
+            question,
+            phrase,
+            environment,
+            typed: String::new(),
+        }
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        self.typed.push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.typed.pop();
+    }
+
+    pub fn is_typed(&self) -> bool {
+        self.typed == self.phrase
+    }
+
+    /// The action, once its phrase has been typed
+    pub fn into_action(self) -> Option<PendingAction> {
+        self.is_typed().then_some(self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_strictest_environment_picks_the_phrase() {
+        let config = ActionsConfig {
+            confirm: Strictness::Code,
+            confirm_by_environment: BTreeMap::from([
+                ("production".to_string(), Strictness::Name),
+                ("dev".to_string(), Strictness::Code),
+            ]),
+            ..ActionsConfig::default()
+        };
+        let tagged = |label: &str| Environment::Tagged(label.to_string());
+        assert_eq!(
+            strictness(&config, [&Environment::Untagged]),
+            Strictness::Code
+        );
+        assert_eq!(strictness(&config, [&tagged("dev")]), Strictness::Code);
+        assert_eq!(
+            strictness(&config, [&tagged("dev"), &tagged("Production")]),
+            Strictness::Name
+        );
+        assert_eq!(strictness(&config, []), Strictness::Code);
+        // A resource whose tags can't be read gets the strictest phrase configured
+        assert_eq!(
+            strictness(&config, [&tagged("dev"), &Environment::Unknown]),
+            Strictness::Name
+        );
+
+        let action = PendingAction::CreateSnapshot {
+            instance_id: "orders-db".to_string(),
+        };
+        let mut confirmation =
+            Confirmation::new(action.clone(), String::new(), Strictness::Name, None);
+        assert_eq!(confirmation.phrase, "orders-db");
+        "orders-d".chars().for_each(|c| confirmation.input_char(c));
+        assert_eq!(confirmation.clone().into_action(), None);
+        confirmation.input_char('b');
+        assert_eq!(confirmation.into_action(), Some(action.clone()));
+
+        let code = Confirmation::new(action, String::new(), Strictness::Code, None).phrase;
+        assert_eq!(code.len(), CODE_LENGTH);
+        assert!(code.bytes().all(|c| CODE_ALPHABET.contains(&c)));
+    }
+}
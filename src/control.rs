@@ -0,0 +1,299 @@
+// Scripted control of the running terminal UI
+//
+// `awscw --control <socket>` listens on a Unix socket for commands, one line
+// at a time with `;` between commands, so scripts, tests and window-manager
+// keybindings can drive the UI:
+//
+//   echo 'select rds orders-db; range 6h; metric CPUUtilization; chart' | nc -U /tmp/awscw.sock
+//   echo 'export csv /tmp/orders-db.csv' | nc -U /tmp/awscw.sock
+//
+// Each line is answered with `ok` or `error: <reason>` for the first command
+// that failed; the commands after it are skipped. `--control -` reads
+// commands from stdin instead (keys still come from the terminal), without
+// replies. Commands run between frames, in the tab being shown.
+
+use crate::aws::time_range::TimeRange;
+use crate::listing::parse_service;
+use crate::models::{App, AppState, AwsService};
+use crate::utils::export::series_csv;
+use anyhow::{anyhow, Context, Result};
+use std::os::unix::fs::FileTypeExt;
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::UnixListener;
+use tokio::sync::{mpsc, oneshot};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ControlCommand {
+    Select {
+        service: AwsService,
+        resource: String,
+    },
+    Range(TimeRange),
+    Metric(String),
+    Chart,
+    Back,
+    Refresh,
+    ExportCsv(PathBuf),
+    Timings,
+    Calls,
+    Internals,
+    Quit,
+}
+
+impl ControlCommand {
+    pub fn parse(command: &str) -> Result<Self> {
+        let words: Vec<&str> = command.split_whitespace().collect();
+        match words.as_slice() {
+            ["select", service, resource] => Ok(Self::Select {
+                service: parse_service(service)?,
+                resource: resource.to_string(),
+            }),
+            ["range", range] => Ok(Self::Range(TimeRange::parse(range)?)),
+            ["metric", name] => Ok(Self::Metric(name.to_string())),
+            ["chart"] => Ok(Self::Chart),
+            ["back"] => Ok(Self::Back),
+            ["refresh"] => Ok(Self::Refresh),
+            ["export", "csv", path] => Ok(Self::ExportCsv(PathBuf::from(path))),
+            ["timings"] => Ok(Self::Timings),
+            ["calls"] => Ok(Self::Calls),
+            ["internals"] => Ok(Self::Internals),
+            ["quit"] => Ok(Self::Quit),
+            _ => Err(anyhow!(
+                "Unknown command '{command}', expected select, range, metric, chart, back, \
+                 refresh, export csv, timings, calls, internals or quit"
+            )),
+        }
+    }
+}
+
+/// A line of commands and where to send the answer, if anywhere
+pub struct ControlRequest {
+    pub line: String,
+    pub reply: Option<oneshot::Sender<String>>,
+}
+
+/// Commands arriving on the socket or stdin, and the socket file to remove on exit
+pub struct ControlChannel {
+    pub requests: mpsc::UnboundedReceiver<ControlRequest>,
+    socket: Option<PathBuf>,
+}
+
+impl Drop for ControlChannel {
+    fn drop(&mut self) {
+        if let Some(socket) = &self.socket {
+            let _ = std::fs::remove_file(socket);
+        }
+    }
+}
+
+/// Start accepting commands on the Unix socket at `target`, or on stdin for `-`
+pub fn listen(target: &str) -> Result<ControlChannel> {
+    let (sender, requests) = mpsc::unbounded_channel();
+    if target == "-" {
+        tokio::spawn(read_stdin(sender));
+        return Ok(ControlChannel {
+            requests,
+            socket: None,
+        });
+    }
+
+    let path = Path::new(target);
+    // A socket left behind by a previous run would make the bind fail; anything
+    // else at the path is left alone
+    if let Ok(metadata) = std::fs::symlink_metadata(path) {
+        if !metadata.file_type().is_socket() {
+            return Err(anyhow!(
+                "{} already exists and is not a socket",
+                path.display()
+            ));
+        }
+        std::fs::remove_file(path)
+            .with_context(|| format!("Failed to remove old socket {}", path.display()))?;
+    }
+    let listener = UnixListener::bind(path)
+        .with_context(|| format!("Failed to listen on {}", path.display()))?;
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(serve_connection(stream, sender.clone()));
+        }
+    });
+    Ok(ControlChannel {
+        requests,
+        socket: Some(path.to_path_buf()),
+    })
+}
+
+async fn read_stdin(sender: mpsc::UnboundedSender<ControlRequest>) {
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if sender.send(ControlRequest { line, reply: None }).is_err() {
+            break;
+        }
+    }
+}
+
+async fn serve_connection(
+    stream: tokio::net::UnixStream,
+    sender: mpsc::UnboundedSender<ControlRequest>,
+) {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        let (reply, answer) = oneshot::channel();
+        let request = ControlRequest {
+            line,
+            reply: Some(reply),
+        };
+        if sender.send(request).is_err() {
+            break;
+        }
+        let Ok(answer) = answer.await else {
+            break;
+        };
+        if writer
+            .write_all(format!("{answer}\n").as_bytes())
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+}
+
+/// Run a line of commands; true when one of them asked to quit
+pub async fn execute(app: &mut App, request: ControlRequest) -> bool {
+    let (quit, answer) = run_line(app, &request.line).await;
+    match request.reply {
+        Some(reply) => {
+            let _ = reply.send(answer);
+        }
+        // Without anyone to answer, failures show where key errors do
+        None if answer != "ok" => app.status_message = Some(format!("Control {answer}")),
+        None => {}
+    }
+    quit
+}
+
+/// Run the commands of one line: whether one asked to quit, and `ok` or the first error
+pub async fn run_line(app: &mut App, line: &str) -> (bool, String) {
+    let mut quit = false;
+    let mut answer = "ok".to_string();
+    for command in line.split(';').map(str::trim) {
+        if command.is_empty() {
+            continue;
+        }
+        match ControlCommand::parse(command) {
+            Ok(ControlCommand::Quit) => {
+                quit = true;
+                break;
+            }
+            Ok(command) => {
+                if let Err(e) = run(app, command).await {
+                    answer = format!("error: {e:#}");
+                    break;
+                }
+            }
+            Err(e) => {
+                answer = format!("error: {e:#}");
+                break;
+            }
+        }
+    }
+    (quit, answer)
+}
+
+async fn run(app: &mut App, command: ControlCommand) -> Result<()> {
+    match command {
+        ControlCommand::Select { service, resource } => {
+            let time_range = app.view.time_range;
+            if !app
+                .open_resource(service, &resource, time_range, false)
+                .await?
+            {
+                return Err(anyhow!("{resource} not found"));
+            }
+        }
+        ControlCommand::Range(time_range) => app.set_time_range(time_range).await?,
+        ControlCommand::Metric(name) => {
+            if !app.select_metric(&name) {
+                return Err(anyhow!("No data for metric {name}"));
+            }
+        }
+        ControlCommand::Chart => {
+            if app.view.state != AppState::MetricsSummary
+                || app.view.selected_service != Some(AwsService::Rds)
+            {
+                return Err(anyhow!("Charts open from an RDS instance's metrics"));
+            }
+            app.enter_instance_details();
+        }
+        ControlCommand::Back => match app.view.state {
+            AppState::InstanceDetails => app.back_to_metrics_summary(),
+            AppState::InstanceList => app.back_to_service_list(),
+            AppState::ServiceList => {}
+            AppState::Timings => app.back_from_timings(),
+            AppState::ApiCalls => app.back_from_api_calls(),
+            AppState::Internals => app.back_from_internals(),
+            _ => app.back_to_list(),
+        },
+        ControlCommand::Refresh => app.reload_open_resource().await?,
+        ControlCommand::ExportCsv(path) => {
+            let columns = app
+                .loaded_series()
+                .ok_or_else(|| anyhow!("No resource's metrics are open"))?;
+            std::fs::write(&path, series_csv(&columns))
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+        ControlCommand::Timings => app.enter_timings(),
+        ControlCommand::Calls => app.enter_api_calls(),
+        ControlCommand::Internals => app.enter_internals(),
+        ControlCommand::Quit => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            ControlCommand::parse("select rds orders-db").unwrap(),
+            ControlCommand::Select {
+                service: AwsService::Rds,
+                resource: "orders-db".to_string(),
+            }
+        );
+        assert_eq!(
+            ControlCommand::parse("range 6h").unwrap(),
+            ControlCommand::Range(TimeRange::parse("6h").unwrap())
+        );
+        assert_eq!(
+            ControlCommand::parse("export csv /tmp/x.csv").unwrap(),
+            ControlCommand::ExportCsv(PathBuf::from("/tmp/x.csv"))
+        );
+        assert_eq!(
+            ControlCommand::parse("timings").unwrap(),
+            ControlCommand::Timings
+        );
+        assert_eq!(
+            ControlCommand::parse("internals").unwrap(),
+            ControlCommand::Internals
+        );
+        assert!(ControlCommand::parse("select ec2 i-123").is_err());
+        assert!(ControlCommand::parse("range soon").is_err());
+        assert!(ControlCommand::parse("export pdf /tmp/x.pdf").is_err());
+    }
+
+    #[test]
+    fn test_listen_keeps_files_that_are_not_sockets() {
+        let path = std::env::temp_dir().join(format!("awscw-control-{}.txt", std::process::id()));
+        std::fs::write(&path, "notes").unwrap();
+        let error = listen(path.to_str().unwrap()).err().unwrap();
+        assert!(error.to_string().contains("is not a socket"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "notes");
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,18 +1,223 @@
 use crate::aws::cloudwatch_service::TimeUnit;
-use crate::models::{App, AppState};
+use crate::models::{App, AppState, AwsService, GroupSource};
 use anyhow::Result;
 use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
 
+/// Lines PgUp/PgDn scroll the log tail by
+const LOG_PAGE_LINES: isize = 20;
+
+/// Keys pass through three stages, and the first to consume one stops it:
+/// global keys (tabs), then whatever overlay is open (release notes, the
+/// alarm form, a y/n confirmation, the details pane), then the page. New
+/// popups or input modes add a capture to `capture_overlay_key` instead of
+/// another arm in every page handler.
 pub async fn handle_event(app: &mut App, event: Event) -> Result<bool> {
-    if let Event::Key(key) = event {
-        match app.state {
-            AppState::ServiceList => handle_service_list_event(app, key.code).await,
-            AppState::InstanceList => handle_rds_list_event(app, key.code).await,
-            AppState::MetricsSummary => handle_metrics_summary_event(app, key).await,
-            AppState::InstanceDetails => handle_instance_details_event(app, key.code).await,
+    let Event::Key(key) = event else {
+        // Resize needs no state change: every draw re-derives grid columns,
+        // sparkline widths and chart buckets from the new terminal size
+        return Ok(false);
+    };
+    if let Some(quit) = handle_global_key(app, key).await? {
+        return Ok(quit);
+    }
+    if let Some(quit) = capture_overlay_key(app, key.code).await? {
+        return Ok(quit);
+    }
+    handle_page_event(app, key).await
+}
+
+/// Keys available on every page unless an overlay takes typed keys.
+/// 'u'/Ctrl+r undo and redo view changes; Ctrl+o/Ctrl+i (or Alt+Left/Right)
+/// go back and forward through the resources opened. F1-F9 show that tab; 'g' starts
+/// gt/gT (next/previous), gn (new tab), gc (close tab) and, on RDS metrics,
+/// gb (blue/green) and gd (Aurora global database). 'a' opens the CloudTrail activity of the resource being viewed,
+/// 'K' its CloudFormation stack, 'B' the bookmarked views, 'J' the background jobs and F12 the performance HUD.
+/// 'e' on a resource's metrics summary exports its loaded series to a CSV or JSON file.
+/// ':' opens the command line, which takes the commands of `--control` (e.g. `timings`).
+/// 'I' starts incident mode on an RDS instance's metrics, and ends it from anywhere.
+async fn handle_global_key(app: &mut App, key: KeyEvent) -> Result<Option<bool>> {
+    if overlay_open(app) {
+        return Ok(None);
+    }
+    if std::mem::take(&mut app.pending_g) {
+        match key.code {
+            KeyCode::Char('t') => app.next_tab(),
+            KeyCode::Char('T') => app.previous_tab(),
+            KeyCode::Char('n') => app.open_tab(),
+            KeyCode::Char('c') => app.close_tab(),
+            KeyCode::Char('b')
+                if app.view.state == AppState::MetricsSummary
+                    && app.view.selected_service == Some(AwsService::Rds) =>
+            {
+                app.enter_blue_green().await
+            }
+            KeyCode::Char('d')
+                if app.view.state == AppState::MetricsSummary
+                    && app.view.selected_service == Some(AwsService::Rds) =>
+            {
+                app.enter_global_database().await
+            }
+            _ => app.status_message = None,
+        }
+        return Ok(Some(false));
+    }
+    match (key.code, key.modifiers) {
+        (KeyCode::Char('u'), KeyModifiers::NONE) => app.undo_view().await?,
+        (KeyCode::Char('r'), KeyModifiers::CONTROL) => app.redo_view().await?,
+        (KeyCode::Char('o'), KeyModifiers::CONTROL) | (KeyCode::Left, KeyModifiers::ALT) => {
+            app.jump_back().await?
+        }
+        // Most terminals send Ctrl+i as Tab, which keeps switching panels; Alt+Right always works
+        (KeyCode::Char('i'), KeyModifiers::CONTROL) | (KeyCode::Right, KeyModifiers::ALT) => {
+            app.jump_forward().await?
+        }
+        (KeyCode::Char('g'), _) => {
+            app.pending_g = true;
+            app.status_message =
+                Some("g-  t/T: Next/Previous Tab • n: New Tab • c: Close Tab".to_string());
+        }
+        (KeyCode::F(number @ 1..=9), _) => app.switch_tab(usize::from(number) - 1),
+        (KeyCode::Char('a'), KeyModifiers::NONE) if in_metrics_view(app) => {
+            app.toggle_activity_panel().await
+        }
+        (KeyCode::Char('K'), _) if in_metrics_view(app) => app.toggle_stack_panel().await,
+        (KeyCode::Char('e'), KeyModifiers::NONE) if app.view.state == AppState::MetricsSummary => {
+            app.open_export_prompt()
+        }
+        (KeyCode::Char('B'), _) => app.toggle_bookmarks_panel(),
+        (KeyCode::Char('J'), _) => app.toggle_jobs_panel(),
+        (KeyCode::F(12), _) => app.toggle_perf_hud(),
+        (KeyCode::Char(':'), _) => app.open_command_line(),
+        (KeyCode::Char('I'), _) if app.incident.is_some() || incident_available(app) => {
+            app.toggle_incident().await?
         }
+        _ => return Ok(None),
+    }
+    Ok(Some(false))
+}
+
+/// Whether incident mode can start here: on an RDS instance's metrics
+fn incident_available(app: &App) -> bool {
+    app.view.state == AppState::MetricsSummary && app.view.selected_service == Some(AwsService::Rds)
+}
+
+/// Whether a single resource's metrics are shown, on any service
+fn in_metrics_view(app: &App) -> bool {
+    matches!(
+        app.view.state,
+        AppState::MetricsSummary | AppState::InstanceDetails
+    )
+}
+
+/// Whether an overlay is taking every key
+fn overlay_open(app: &App) -> bool {
+    app.release_notes_open
+        || app.alarm_form.is_some()
+        || app.threshold_editor.is_some()
+        || app.pin_prompt.is_some()
+        || app.export_prompt.is_some()
+        || app.command_line.is_some()
+        || app.pending_action.is_some()
+        || app.view.details_pane.open
+        || app.view.activity.open
+        || app.view.rightsizing.open
+        || app.view.stack.open
+        || app.view.queue_access.open
+        || app.view.consumers.open
+        || app.view.compare.open
+        || app.bookmarks_panel.is_some()
+        || app.jobs_panel.is_some()
+        || app.profiles_panel.is_some()
+        || app.regions_panel.is_some()
+        || app.view.log_tail.input.is_some()
+}
+
+/// Keys for the open overlay, topmost first; None when no overlay is open
+async fn capture_overlay_key(app: &mut App, key: KeyCode) -> Result<Option<bool>> {
+    // The release notes overlay takes every key until it is closed
+    if app.release_notes_open {
+        if matches!(key, KeyCode::Esc | KeyCode::Char('U') | KeyCode::Char('q')) {
+            app.toggle_release_notes();
+        }
+        return Ok(Some(false));
+    }
+    if !overlay_open(app) {
+        return Ok(None);
+    }
+
+    // Any key press dismisses the previous status message
+    app.status_message = None;
+    if app.alarm_form.is_some() {
+        handle_alarm_form_event(app, key);
+        Ok(Some(false))
+    } else if app.threshold_editor.is_some() {
+        handle_threshold_editor_event(app, key).await;
+        Ok(Some(false))
+    } else if app.pin_prompt.is_some() {
+        handle_pin_prompt_event(app, key);
+        Ok(Some(false))
+    } else if app.export_prompt.is_some() {
+        handle_export_prompt_event(app, key);
+        Ok(Some(false))
+    } else if app.view.log_tail.input.is_some() {
+        handle_log_input_event(app, key).await;
+        Ok(Some(false))
+    } else if app.command_line.is_some() {
+        handle_command_line_event(app, key).await.map(Some)
+    } else if app.bookmarks_panel.is_some() {
+        handle_bookmarks_panel_event(app, key).await.map(Some)
+    } else if app.jobs_panel.is_some() {
+        Ok(Some(handle_jobs_panel_event(app, key)))
+    } else if app.profiles_panel.is_some() {
+        handle_profiles_panel_event(app, key).await.map(Some)
+    } else if app.regions_panel.is_some() {
+        handle_regions_panel_event(app, key).await.map(Some)
+    } else if app.pending_action.is_some() {
+        handle_confirmation_event(app, key).await?;
+        Ok(Some(false))
+    } else if app.view.activity.open {
+        handle_activity_panel_event(app, key).await.map(Some)
+    } else if app.view.rightsizing.open {
+        handle_rightsizing_panel_event(app, key).await.map(Some)
+    } else if app.view.stack.open {
+        handle_stack_panel_event(app, key).await.map(Some)
+    } else if app.view.queue_access.open {
+        Ok(Some(handle_queue_access_panel_event(app, key)))
+    } else if app.view.consumers.open {
+        handle_consumers_panel_event(app, key).await.map(Some)
+    } else if app.view.compare.open {
+        handle_compare_panel_event(app, key).await.map(Some)
     } else {
-        Ok(false)
+        handle_details_pane_event(app, key).map(Some)
+    }
+}
+
+async fn handle_page_event(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match app.view.state {
+        AppState::ServiceList => handle_service_list_event(app, key.code).await,
+        AppState::InstanceList => handle_rds_list_event(app, key.code).await,
+        AppState::MetricsSummary => match app.view.selected_service {
+            Some(AwsService::Sqs) => handle_queue_summary_event(app, key.code).await,
+            Some(AwsService::Synthetics) => handle_canary_summary_event(app, key.code).await,
+            Some(AwsService::EventBridge) => handle_event_rule_summary_event(app, key.code).await,
+            Some(AwsService::Batch) => handle_batch_queue_summary_event(app, key.code).await,
+            Some(AwsService::Lambda) => handle_function_summary_event(app, key.code).await,
+            _ => handle_metrics_summary_event(app, key).await,
+        },
+        AppState::InstanceDetails => handle_instance_details_event(app, key.code).await,
+        AppState::ReplicaTopology => handle_replica_topology_event(app, key.code).await,
+        AppState::BlueGreen => handle_blue_green_event(app, key.code).await,
+        AppState::GlobalDatabase => handle_global_database_event(app, key.code).await,
+        AppState::SlowQueries => handle_slow_queries_event(app, key.code).await,
+        AppState::LogTail => handle_log_tail_event(app, key.code).await,
+        AppState::Parameters => handle_parameters_event(app, key.code).await,
+        AppState::Fleet => handle_fleet_event(app, key.code).await,
+        AppState::ResourceGroups => handle_resource_groups_event(app, key.code).await,
+        AppState::CompositeAlarms => handle_composite_alarms_event(app, key.code).await,
+        AppState::Alarms => handle_alarm_browser_event(app, key.code).await,
+        AppState::Timings => Ok(handle_timings_event(app, key.code)),
+        AppState::ApiCalls => Ok(handle_api_calls_event(app, key.code)),
+        AppState::Internals => Ok(handle_internals_event(app, key.code)),
     }
 }
 
@@ -25,6 +230,14 @@ async fn handle_service_list_event(app: &mut App, key: KeyCode) -> Result<bool>
         KeyCode::Up | KeyCode::Char('k') => {
             app.service_previous();
         }
+        KeyCode::Char('U') => app.toggle_release_notes(),
+        KeyCode::Char('F') => app.enter_fleet().await,
+        KeyCode::Char('G') => app.enter_resource_groups(GroupSource::ResourceGroups).await,
+        KeyCode::Char('A') => app.enter_resource_groups(GroupSource::Applications).await,
+        KeyCode::Char('C') => app.enter_composite_alarms().await,
+        KeyCode::Char('M') => app.enter_alarm_browser().await,
+        KeyCode::Char('p') => app.toggle_profiles_panel(),
+        KeyCode::Char('R') => app.toggle_regions_panel(),
         KeyCode::Enter => {
             let selected_service = app.select_service().cloned();
             if let Some(service) = selected_service {
@@ -51,6 +264,8 @@ async fn handle_rds_list_event(app: &mut App, key_code: KeyCode) -> Result<bool>
         }
     }
 
+    app.status_message = None;
+
     match key_code {
         KeyCode::Char('q') => Ok(true), // Signal to quit
         KeyCode::Esc => {
@@ -65,16 +280,79 @@ async fn handle_rds_list_event(app: &mut App, key_code: KeyCode) -> Result<bool>
             app.previous();
             Ok(false)
         }
+        KeyCode::Enter if app.folder_selected() => {
+            app.toggle_selected_folder();
+            Ok(false)
+        }
+        KeyCode::Left if app.folders_shown() => {
+            app.collapse_folder();
+            Ok(false)
+        }
+        KeyCode::Right if app.folders_shown() => {
+            app.expand_folder();
+            Ok(false)
+        }
+        KeyCode::Char('f') if app.view.selected_service == Some(AwsService::Sqs) => {
+            app.toggle_queue_folders().await;
+            Ok(false)
+        }
         KeyCode::Enter => {
+            app.follow_selected_region().await;
             app.enter_metrics_summary();
             if let Some(instance_id) = app.get_selected_instance_id() {
-                app.load_metrics(&instance_id).await?;
+                match app.view.selected_service {
+                    Some(
+                        AwsService::Sqs
+                        | AwsService::Synthetics
+                        | AwsService::EventBridge
+                        | AwsService::Lambda,
+                    ) => app.load_service_metrics(&instance_id).await?,
+                    Some(AwsService::Batch) => {
+                        app.view.compute_environment_index = 0;
+                        app.load_compute_environment_metrics().await?
+                    }
+                    _ => {
+                        app.detect_metric_resolution(&instance_id).await;
+                        app.load_metrics(&instance_id).await?;
+                    }
+                }
             }
             Ok(false)
         }
         KeyCode::Char('r') => {
             app.loading = true;
-            let selected_service = app.selected_service.clone();
+            let selected_service = app.view.selected_service.clone();
+            if let Some(service) = selected_service {
+                app.load_service_instances(&service).await?;
+            }
+            Ok(false)
+        }
+        KeyCode::Char(' ') => {
+            app.toggle_mark_selected();
+            Ok(false)
+        }
+        KeyCode::Char('m') => {
+            // Maintenance: disable alarm actions for the marked (or selected) resources
+            app.request_alarm_actions(false);
+            Ok(false)
+        }
+        KeyCode::Char('M') => {
+            app.request_alarm_actions(true);
+            Ok(false)
+        }
+        KeyCode::Char('T') if app.view.selected_service == Some(AwsService::Rds) => {
+            app.open_threshold_editor().await;
+            Ok(false)
+        }
+        KeyCode::Char('E') if app.view.selected_service == Some(AwsService::Rds) => {
+            app.start_fleet_export();
+            Ok(false)
+        }
+        KeyCode::Char('w') => {
+            // Reload so the list is filtered (or unfiltered) from scratch
+            app.toggle_watchlist_only();
+            app.loading = true;
+            let selected_service = app.view.selected_service.clone();
             if let Some(service) = selected_service {
                 app.load_service_instances(&service).await?;
             }
@@ -85,6 +363,9 @@ async fn handle_rds_list_event(app: &mut App, key_code: KeyCode) -> Result<bool>
 }
 
 async fn handle_metrics_summary_event(app: &mut App, key: KeyEvent) -> Result<bool> {
+    // Any key press dismisses the previous status message
+    app.status_message = None;
+
     match (key.code, key.modifiers) {
         (KeyCode::Char('q'), _) => Ok(true), // Signal to quit
         (KeyCode::Down, _) => {
@@ -106,8 +387,11 @@ async fn handle_metrics_summary_event(app: &mut App, key: KeyEvent) -> Result<bo
                     }
                 }
                 crate::models::FocusedPanel::SparklineGrid => {
-                    // Navigate to Instance Details when Enter is pressed on SparklineGrid
-                    app.enter_instance_details();
+                    // A failed tile has no chart to open, so Enter retries it instead
+                    match app.selected_failed_metric() {
+                        Some(metric_name) => app.retry_failed_metrics(Some(&metric_name)).await?,
+                        None => app.enter_instance_details(),
+                    }
                 }
             }
             Ok(false)
@@ -187,11 +471,660 @@ async fn handle_metrics_summary_event(app: &mut App, key: KeyEvent) -> Result<bo
             }
             Ok(false)
         }
+        (KeyCode::Char('i'), _) => {
+            app.toggle_details_pane();
+            Ok(false)
+        }
+        (KeyCode::Char('T'), _) => {
+            app.open_threshold_editor().await;
+            Ok(false)
+        }
+        (KeyCode::Char('c'), _) => {
+            // Connectivity probe: 'c' for TCP only, 'C' to also complete a TLS handshake
+            app.probe_selected_instance(false).await;
+            Ok(false)
+        }
+        (KeyCode::Char('C'), _) => {
+            app.probe_selected_instance(true).await;
+            Ok(false)
+        }
+        (KeyCode::Char('t'), _) => {
+            app.enter_replica_topology().await;
+            Ok(false)
+        }
+        (KeyCode::Char('s'), _) => {
+            app.enter_slow_queries().await;
+            Ok(false)
+        }
+        (KeyCode::Char('l'), _) => {
+            app.enter_log_tail().await;
+            Ok(false)
+        }
+        (KeyCode::Char('P'), _) => {
+            app.enter_parameters().await;
+            Ok(false)
+        }
+        (KeyCode::Char('o'), _) => {
+            app.toggle_rightsizing_panel().await;
+            Ok(false)
+        }
+        (KeyCode::Char('v'), _) => {
+            app.toggle_compare_panel().await;
+            Ok(false)
+        }
+        (KeyCode::Char('S'), _) => {
+            app.request_snapshot();
+            Ok(false)
+        }
+        (KeyCode::Char('x'), _) => {
+            app.retry_failed_metrics(None).await?;
+            Ok(false)
+        }
+        (KeyCode::Char('R'), _) => {
+            app.refresh_focused_metric().await?;
+            Ok(false)
+        }
+        // RDS copies from the metric's chart screen
+        (KeyCode::Char('y'), _) if app.view.selected_service != Some(AwsService::Rds) => {
+            app.copy_chart_as_text();
+            Ok(false)
+        }
+        (KeyCode::Char('p'), _) => {
+            // Cycle the aggregation period (sub-minute for high-resolution metrics)
+            app.cycle_period();
+            if let Some(instance_id) = app.get_selected_rds_instance_id() {
+                app.load_metrics(&instance_id).await?
+            }
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
+
+async fn handle_queue_summary_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    app.status_message = None;
+
+    match key_code {
+        KeyCode::Char('q') => Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => {
+            app.back_to_list();
+            app.reset_scroll();
+            app.view.service_metrics = None;
+            Ok(false)
+        }
+        KeyCode::Char('r') => {
+            app.refresh_selected_queue().await?;
+            Ok(false)
+        }
+        KeyCode::Char('x') => {
+            app.retry_failed_metrics(None).await?;
+            Ok(false)
+        }
+        KeyCode::Char('s') => {
+            app.request_send_test_message();
+            Ok(false)
+        }
+        KeyCode::Char('R') => {
+            app.request_redrive();
+            Ok(false)
+        }
+        KeyCode::Char('P') => {
+            app.toggle_queue_access_panel();
+            Ok(false)
+        }
+        KeyCode::Char('L') => {
+            app.toggle_consumers_panel().await;
+            Ok(false)
+        }
+        KeyCode::Char('A') => {
+            app.open_queue_alarm_preset().await;
+            Ok(false)
+        }
+        KeyCode::Char('y') => {
+            app.copy_chart_as_text();
+            Ok(false)
+        }
+        KeyCode::Char('i') => {
+            app.toggle_details_pane();
+            Ok(false)
+        }
+        KeyCode::Char('f') => {
+            app.cycle_stacked_family();
+            Ok(false)
+        }
+        KeyCode::Char(digit @ '1'..='9') => {
+            app.toggle_stack_layer(digit as usize - '1' as usize);
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
+
+async fn handle_canary_summary_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    app.status_message = None;
+
+    match key_code {
+        KeyCode::Char('q') => Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => {
+            app.back_to_list();
+            app.reset_scroll();
+            app.view.service_metrics = None;
+            Ok(false)
+        }
+        KeyCode::Char('r') => {
+            app.refresh_selected_canary().await?;
+            Ok(false)
+        }
+        KeyCode::Char('x') => {
+            app.retry_failed_metrics(None).await?;
+            Ok(false)
+        }
+        KeyCode::Char('y') => {
+            app.copy_canary_artifacts_link();
+            Ok(false)
+        }
+        KeyCode::Char('Y') => {
+            app.copy_chart_as_text();
+            Ok(false)
+        }
+        KeyCode::Char('i') => {
+            app.toggle_details_pane();
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
+
+async fn handle_event_rule_summary_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    app.status_message = None;
+
+    match key_code {
+        KeyCode::Char('q') => Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => {
+            app.back_to_list();
+            app.reset_scroll();
+            app.view.service_metrics = None;
+            Ok(false)
+        }
+        KeyCode::Char('r') => {
+            app.refresh_selected_event_rule().await?;
+            Ok(false)
+        }
+        KeyCode::Char('x') => {
+            app.retry_failed_metrics(None).await?;
+            Ok(false)
+        }
+        KeyCode::Char('y') => {
+            app.copy_chart_as_text();
+            Ok(false)
+        }
+        KeyCode::Char('i') => {
+            app.toggle_details_pane();
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
+
+async fn handle_batch_queue_summary_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    app.status_message = None;
+
+    match key_code {
+        KeyCode::Char('q') => Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => {
+            app.back_to_list();
+            app.reset_scroll();
+            app.view.service_metrics = None;
+            Ok(false)
+        }
+        KeyCode::Char('r') => {
+            app.refresh_selected_batch_queue().await?;
+            Ok(false)
+        }
+        KeyCode::Char('x') => {
+            app.retry_failed_metrics(None).await?;
+            Ok(false)
+        }
+        KeyCode::Tab | KeyCode::Right => {
+            app.cycle_compute_environment(true).await?;
+            Ok(false)
+        }
+        KeyCode::BackTab | KeyCode::Left => {
+            app.cycle_compute_environment(false).await?;
+            Ok(false)
+        }
+        KeyCode::Char('y') => {
+            app.copy_chart_as_text();
+            Ok(false)
+        }
+        KeyCode::Char('i') => {
+            app.toggle_details_pane();
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
+
+async fn handle_function_summary_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    app.status_message = None;
+
+    match key_code {
+        KeyCode::Char('q') => Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => {
+            app.back_to_list();
+            app.reset_scroll();
+            app.view.service_metrics = None;
+            Ok(false)
+        }
+        KeyCode::Char('r') => {
+            app.refresh_selected_function().await?;
+            Ok(false)
+        }
+        KeyCode::Char('x') => {
+            app.retry_failed_metrics(None).await?;
+            Ok(false)
+        }
+        KeyCode::Char('y') => {
+            app.copy_chart_as_text();
+            Ok(false)
+        }
+        KeyCode::Char('i') => {
+            app.toggle_details_pane();
+            Ok(false)
+        }
+        KeyCode::Char('l') => {
+            app.enter_log_tail().await;
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
+
+async fn handle_replica_topology_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    app.status_message = None;
+
+    match key_code {
+        KeyCode::Char('q') => Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => {
+            app.back_from_replica_topology();
+            Ok(false)
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.replica_next();
+            Ok(false)
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.replica_previous();
+            Ok(false)
+        }
+        KeyCode::Enter => {
+            app.open_selected_replica().await?;
+            Ok(false)
+        }
+        KeyCode::Char('r') => {
+            app.load_replica_topology().await;
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
+
+async fn handle_blue_green_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => {
+            app.back_from_blue_green();
+            Ok(false)
+        }
+        KeyCode::Char('r') => {
+            app.refresh_blue_green().await;
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
+
+async fn handle_global_database_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => {
+            app.back_from_global_database();
+            Ok(false)
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.global_secondary_next();
+            Ok(false)
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.global_secondary_previous();
+            Ok(false)
+        }
+        KeyCode::Char('r') => {
+            app.refresh_global_database().await;
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
+
+async fn handle_slow_queries_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => {
+            app.back_from_slow_queries();
+            Ok(false)
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.slow_query_next();
+            Ok(false)
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.slow_query_previous();
+            Ok(false)
+        }
+        KeyCode::Char('s') => {
+            app.cycle_slow_query_sort();
+            Ok(false)
+        }
+        KeyCode::Char('r') => {
+            app.load_slow_queries().await;
+            Ok(false)
+        }
         _ => Ok(false),
     }
 }
 
+async fn handle_log_tail_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    app.status_message = None;
+
+    if app.view.log_tail.group.is_none() {
+        match key_code {
+            KeyCode::Char('q') => return Ok(true),
+            KeyCode::Char('b') | KeyCode::Esc => app.back_from_log_tail(),
+            KeyCode::Down | KeyCode::Char('j') => app.log_groups_next(),
+            KeyCode::Up | KeyCode::Char('k') => app.log_groups_previous(),
+            KeyCode::Enter => app.start_log_tail().await,
+            _ => {}
+        }
+        return Ok(false);
+    }
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => app.back_from_log_tail(),
+        KeyCode::Up | KeyCode::Char('k') => app.scroll_log_tail(1),
+        KeyCode::Down | KeyCode::Char('j') => app.scroll_log_tail(-1),
+        KeyCode::PageUp => app.scroll_log_tail(LOG_PAGE_LINES),
+        KeyCode::PageDown => app.scroll_log_tail(-LOG_PAGE_LINES),
+        KeyCode::End | KeyCode::Char('G') => app.follow_log_tail(),
+        KeyCode::Char(' ') => app.toggle_log_tail_pause(),
+        KeyCode::Char('/') => app.start_log_input(false),
+        KeyCode::Char('f') => app.start_log_input(true),
+        KeyCode::Char('n') => app.jump_to_log_match(true),
+        KeyCode::Char('N') => app.jump_to_log_match(false),
+        _ => {}
+    }
+    Ok(false)
+}
+
+/// Search or filter pattern typed on the log tail page
+async fn handle_log_input_event(app: &mut App, key_code: KeyCode) {
+    match key_code {
+        KeyCode::Esc => app.cancel_log_input(),
+        KeyCode::Enter => app.submit_log_input().await,
+        KeyCode::Backspace => app.log_input_backspace(),
+        KeyCode::Char(c) => app.log_input_char(c),
+        _ => {}
+    }
+}
+
+async fn handle_parameters_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => app.back_from_parameters(),
+        KeyCode::Down | KeyCode::Char('j') => app.parameters_next(),
+        KeyCode::Up | KeyCode::Char('k') => app.parameters_previous(),
+        KeyCode::Char('r') => app.load_parameters().await,
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_fleet_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => app.back_from_fleet(),
+        KeyCode::Down | KeyCode::Char('j') => app.fleet_next(),
+        KeyCode::Up | KeyCode::Char('k') => app.fleet_previous(),
+        KeyCode::Char('s') => app.cycle_fleet_service().await,
+        KeyCode::Char('r') => app.load_fleet().await,
+        KeyCode::Enter => app.open_fleet_row().await?,
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_composite_alarms_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => app.back_from_composite_alarms(),
+        KeyCode::Down | KeyCode::Char('j') => app.composite_alarms_next(),
+        KeyCode::Up | KeyCode::Char('k') => app.composite_alarms_previous(),
+        KeyCode::Char('r') => app.load_composite_alarms().await,
+        KeyCode::Enter => app.open_composite_row().await?,
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_alarm_browser_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => app.view.state = AppState::ServiceList,
+        KeyCode::Down | KeyCode::Char('j') => app.alarm_browser_next(),
+        KeyCode::Up | KeyCode::Char('k') => app.alarm_browser_previous(),
+        KeyCode::Char('n') => app.cycle_alarm_namespace(),
+        KeyCode::Char('r') => app.load_alarm_browser().await,
+        KeyCode::Enter => app.open_browsed_alarm().await?,
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_resource_groups_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('b') | KeyCode::Esc => app.back_from_resource_groups(),
+        KeyCode::Down | KeyCode::Char('j') => app.resource_groups_next(),
+        KeyCode::Up | KeyCode::Char('k') => app.resource_groups_previous(),
+        KeyCode::Char('r') => app.load_resource_groups().await,
+        KeyCode::Enter => app.open_resource_group_row().await?,
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_timings_event(app: &mut App, key_code: KeyCode) -> bool {
+    match key_code {
+        KeyCode::Char('q') => return true,
+        KeyCode::Char('b') | KeyCode::Esc => app.back_from_timings(),
+        KeyCode::Char('c') => app.clear_timings(),
+        _ => {}
+    }
+    false
+}
+
+fn handle_api_calls_event(app: &mut App, key_code: KeyCode) -> bool {
+    match key_code {
+        KeyCode::Char('q') => return true,
+        KeyCode::Char('b') | KeyCode::Esc => app.back_from_api_calls(),
+        _ => {}
+    }
+    false
+}
+
+fn handle_internals_event(app: &mut App, key_code: KeyCode) -> bool {
+    match key_code {
+        KeyCode::Char('q') => return true,
+        KeyCode::Char('b') | KeyCode::Esc => app.back_from_internals(),
+        KeyCode::Char('y') => app.copy_internals(),
+        _ => {}
+    }
+    false
+}
+
+async fn handle_activity_panel_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('a') | KeyCode::Esc => app.toggle_activity_panel().await,
+        KeyCode::Down | KeyCode::Char('j') => app.activity_scroll_down(),
+        KeyCode::Up | KeyCode::Char('k') => app.activity_scroll_up(),
+        KeyCode::Char('r') => app.load_activity().await,
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_rightsizing_panel_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('o') | KeyCode::Esc => app.toggle_rightsizing_panel().await,
+        KeyCode::Char('r') => app.load_rightsizing().await,
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_stack_panel_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('K') | KeyCode::Esc => app.toggle_stack_panel().await,
+        KeyCode::Char('r') => app.load_stack().await,
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_consumers_panel_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('L') | KeyCode::Esc => app.toggle_consumers_panel().await,
+        KeyCode::Char('r') => app.load_consumers().await,
+        KeyCode::Down | KeyCode::Char('j') => app.consumers_scroll_down(),
+        KeyCode::Up | KeyCode::Char('k') => app.consumers_scroll_up(),
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_queue_access_panel_event(app: &mut App, key_code: KeyCode) -> bool {
+    match key_code {
+        KeyCode::Char('q') => return true,
+        KeyCode::Char('P') | KeyCode::Esc => app.toggle_queue_access_panel(),
+        KeyCode::Down | KeyCode::Char('j') => app.queue_access_scroll_down(),
+        KeyCode::Up | KeyCode::Char('k') => app.queue_access_scroll_up(),
+        KeyCode::Char('y') => app.copy_queue_policy(),
+        KeyCode::Char('Y') => app.copy_queue_kms_key(),
+        _ => {}
+    }
+    false
+}
+
+async fn handle_compare_panel_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('v') | KeyCode::Esc => app.toggle_compare_panel().await,
+        KeyCode::Down | KeyCode::Char('j') => app.compare_scroll_down(),
+        KeyCode::Up | KeyCode::Char('k') => app.compare_scroll_up(),
+        KeyCode::Char('w') => app.cycle_compare_offset().await,
+        KeyCode::Char('r') => app.load_comparison().await,
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_jobs_panel_event(app: &mut App, key_code: KeyCode) -> bool {
+    match key_code {
+        KeyCode::Char('q') => return true,
+        KeyCode::Char('J') | KeyCode::Esc => app.toggle_jobs_panel(),
+        KeyCode::Down | KeyCode::Char('j') => app.jobs_scroll_down(),
+        KeyCode::Up | KeyCode::Char('k') => app.jobs_scroll_up(),
+        KeyCode::Char('x') => app.cancel_selected_job(),
+        KeyCode::Char('c') => app.clear_finished_jobs(),
+        _ => {}
+    }
+    false
+}
+
+async fn handle_profiles_panel_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('p') | KeyCode::Esc => app.toggle_profiles_panel(),
+        KeyCode::Down | KeyCode::Char('j') => app.profiles_scroll_down(),
+        KeyCode::Up | KeyCode::Char('k') => app.profiles_scroll_up(),
+        KeyCode::Enter => app.switch_to_selected_profile().await,
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_regions_panel_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('R') | KeyCode::Esc => app.toggle_regions_panel(),
+        KeyCode::Down | KeyCode::Char('j') => app.regions_scroll_down(),
+        KeyCode::Up | KeyCode::Char('k') => app.regions_scroll_up(),
+        KeyCode::Enter => app.switch_to_selected_region().await,
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_bookmarks_panel_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    // While a name is typed every character goes into it
+    if let Some(name) = app.bookmark_name_input() {
+        match key_code {
+            KeyCode::Char(c) => name.push(c),
+            KeyCode::Backspace => {
+                name.pop();
+            }
+            KeyCode::Enter => app.save_bookmark(),
+            KeyCode::Esc => app.cancel_bookmark(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('B') | KeyCode::Esc => app.toggle_bookmarks_panel(),
+        KeyCode::Down | KeyCode::Char('j') => app.bookmarks_scroll_down(),
+        KeyCode::Up | KeyCode::Char('k') => app.bookmarks_scroll_up(),
+        KeyCode::Enter => app.open_selected_bookmark().await?,
+        KeyCode::Char('n') => app.start_bookmark(),
+        KeyCode::Char('d') => app.delete_selected_bookmark(),
+        KeyCode::Char('y') => app.copy_selected_bookmark(),
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_details_pane_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('i') | KeyCode::Esc => app.toggle_details_pane(),
+        KeyCode::Down | KeyCode::Char('j') => app.details_scroll_down(),
+        KeyCode::Up | KeyCode::Char('k') => app.details_scroll_up(),
+        KeyCode::Left | KeyCode::Char('h') => app.details_scroll_left(),
+        KeyCode::Right | KeyCode::Char('l') => app.details_scroll_right(),
+        KeyCode::Char('y') => app.copy_selected_attribute(false),
+        KeyCode::Char('Y') => app.copy_selected_attribute(true),
+        _ => {}
+    }
+    Ok(false)
+}
+
 async fn handle_instance_details_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    app.status_message = None;
+
     match key_code {
         KeyCode::Char('q') => Ok(true), // Signal to quit
         KeyCode::Char('b') | KeyCode::Esc => {
@@ -205,6 +1138,10 @@ async fn handle_instance_details_event(app: &mut App, key_code: KeyCode) -> Resu
             }
             Ok(false)
         }
+        KeyCode::Char('R') => {
+            app.refresh_focused_metric().await?;
+            Ok(false)
+        }
         KeyCode::Up | KeyCode::Char('k') => {
             app.scroll_up();
             Ok(false)
@@ -217,6 +1154,153 @@ async fn handle_instance_details_event(app: &mut App, key_code: KeyCode) -> Resu
             app.reset_scroll();
             Ok(false)
         }
+        KeyCode::Char('A') => {
+            app.open_alarm_form().await;
+            Ok(false)
+        }
+        KeyCode::Char('h') => {
+            app.toggle_heat_strip();
+            Ok(false)
+        }
+        KeyCode::Char('d') => {
+            app.toggle_derivative();
+            Ok(false)
+        }
+        KeyCode::Char('P') => {
+            app.open_pin_prompt();
+            Ok(false)
+        }
+        KeyCode::Char('y') => {
+            app.copy_chart_as_text();
+            Ok(false)
+        }
+        KeyCode::Char('f') => {
+            app.cycle_stacked_family();
+            Ok(false)
+        }
+        KeyCode::Char(digit @ '1'..='9') => {
+            app.toggle_stack_layer(digit as usize - '1' as usize);
+            Ok(false)
+        }
         _ => Ok(false),
     }
 }
+
+async fn handle_threshold_editor_event(app: &mut App, key_code: KeyCode) {
+    match key_code {
+        KeyCode::Esc => app.close_threshold_editor(),
+        KeyCode::Enter => app.save_thresholds(),
+        _ => {
+            let Some(editor) = app.threshold_editor.as_mut() else {
+                return;
+            };
+            match key_code {
+                KeyCode::Down => editor.next_row(),
+                KeyCode::Up => editor.previous_row(),
+                KeyCode::Left | KeyCode::Right | KeyCode::Tab => editor.switch_field(),
+                KeyCode::Backspace => editor.backspace(),
+                KeyCode::Char('d') => editor.reset_row(),
+                KeyCode::Char(c) => editor.input_char(c),
+                _ => {}
+            }
+            // A metric that just got a threshold needs values to preview
+            app.load_threshold_previews().await;
+        }
+    }
+}
+
+/// A pending write action takes typed keys until its phrase is confirmed
+async fn handle_confirmation_event(app: &mut App, key_code: KeyCode) -> Result<()> {
+    match key_code {
+        KeyCode::Esc => app.cancel_pending_action(),
+        KeyCode::Enter => app.confirm_pending_action().await?,
+        KeyCode::Backspace => {
+            if let Some(confirmation) = app.pending_action.as_mut() {
+                confirmation.backspace();
+            }
+        }
+        KeyCode::Char(c) => {
+            if let Some(confirmation) = app.pending_action.as_mut() {
+                confirmation.input_char(c);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn handle_pin_prompt_event(app: &mut App, key_code: KeyCode) {
+    match key_code {
+        KeyCode::Esc => app.cancel_pin_prompt(),
+        KeyCode::Enter => app.submit_pin_prompt(),
+        KeyCode::Backspace => {
+            if let Some(prompt) = app.pin_prompt.as_mut() {
+                prompt.backspace();
+            }
+        }
+        KeyCode::Char(c) => {
+            if let Some(prompt) = app.pin_prompt.as_mut() {
+                prompt.input_char(c);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn handle_export_prompt_event(app: &mut App, key_code: KeyCode) {
+    match key_code {
+        KeyCode::Esc => app.cancel_export_prompt(),
+        KeyCode::Enter => app.submit_export_prompt(),
+        KeyCode::Backspace => {
+            if let Some(prompt) = app.export_prompt.as_mut() {
+                prompt.backspace();
+            }
+        }
+        KeyCode::Char(c) => {
+            if let Some(prompt) = app.export_prompt.as_mut() {
+                prompt.input_char(c);
+            }
+        }
+        _ => {}
+    }
+}
+
+async fn handle_command_line_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    match key_code {
+        KeyCode::Esc => app.cancel_command_line(),
+        KeyCode::Enter => return Ok(app.submit_command_line().await),
+        KeyCode::Backspace => {
+            if let Some(line) = app.command_line.as_mut() {
+                line.pop();
+            }
+        }
+        KeyCode::Char(c) => {
+            if let Some(line) = app.command_line.as_mut() {
+                line.push(c);
+            }
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_alarm_form_event(app: &mut App, key_code: KeyCode) {
+    match key_code {
+        KeyCode::Esc => app.close_alarm_form(),
+        KeyCode::Enter => app.submit_alarm_form(),
+        _ => {
+            let Some(form) = app.alarm_form.as_mut() else {
+                return;
+            };
+            match key_code {
+                KeyCode::Down | KeyCode::Tab => form.next_field(),
+                KeyCode::Up | KeyCode::BackTab => form.previous_field(),
+                KeyCode::Right => form.adjust(true),
+                KeyCode::Left => form.adjust(false),
+                KeyCode::Backspace => form.backspace(),
+                KeyCode::Char(c) => form.input_char(c),
+                _ => {}
+            }
+        }
+    }
+}
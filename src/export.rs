@@ -0,0 +1,374 @@
+// Fleet summary export for ops reports
+//
+// `awscw export` lists every RDS instance with its status, the latest value of
+// a few key metrics over the last hour and a health score, as a Markdown or
+// HTML table. Metric health uses the same thresholds as the metric pages; a
+// CA certificate due for rotation counts against it like a metric would. A
+// value past a reference line pinned on its metric names the line.
+
+use crate::aws::load_rds_instances;
+use crate::aws::metric_fetcher::{fetch_comprehensive_metric, MetricSeries};
+use crate::aws::metric_types::MetricFetchParams;
+use crate::aws::rds::certificates::{days_left_label, rotation_severity};
+use crate::aws::session::AwsSessionManager;
+use crate::jobs::JobReporter;
+use crate::journal::unix_now;
+use crate::models::RdsInstance;
+use crate::reference_lines::ReferenceLines;
+use crate::thresholds::Thresholds;
+use crate::ui::components::metric_utils::{format_value, get_metric_severity};
+use crate::ui::theme::Severity;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Local};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime};
+
+/// Window and period of the metric snapshot
+const SNAPSHOT_WINDOW: Duration = Duration::from_secs(60 * 60);
+const SNAPSHOT_PERIOD_SECS: i32 = 300;
+
+/// (CloudWatch name, display name, unit) of the metrics in the report
+pub const SNAPSHOT_METRICS: [(&str, &str, &str); 5] = [
+    ("CPUUtilization", "CPU Utilization", "Percent"),
+    ("DatabaseConnections", "Database Connections", "Count"),
+    ("FreeStorageSpace", "Free Storage Space", "Bytes"),
+    ("ReadLatency", "Read Latency", "Seconds"),
+    ("WriteLatency", "Write Latency", "Seconds"),
+];
+
+/// Health score deductions
+const NOT_AVAILABLE_PENALTY: u32 = 50;
+const WARNING_PENALTY: u32 = 10;
+const CRITICAL_PENALTY: u32 = 25;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Markdown,
+    Html,
+}
+
+impl ExportFormat {
+    pub fn parse(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "markdown" | "md" => Ok(Self::Markdown),
+            "html" => Ok(Self::Html),
+            other => Err(anyhow!(
+                "Unknown export format '{other}', expected markdown or html"
+            )),
+        }
+    }
+}
+
+/// One instance in the report
+pub struct FleetRow {
+    pub identifier: String,
+    pub engine: String,
+    pub status: String,
+    pub instance_class: String,
+    /// Latest value of each of `SNAPSHOT_METRICS`; None without recent data
+    pub snapshot: Vec<Option<f64>>,
+    /// Days until the CA certificate expires; None when unknown
+    pub certificate_days_left: Option<i64>,
+}
+
+impl FleetRow {
+    /// Row for an instance from its `SNAPSHOT_METRICS` series
+    pub fn new(instance: &RdsInstance, series: &[MetricSeries]) -> Self {
+        Self {
+            identifier: instance.identifier.clone(),
+            engine: instance.engine.clone(),
+            status: instance.status.clone(),
+            instance_class: instance.instance_class.clone(),
+            snapshot: series
+                .iter()
+                .map(|(latest, history, _)| (!history.is_empty()).then_some(*latest))
+                .collect(),
+            certificate_days_left: instance
+                .certificate
+                .as_ref()
+                .and_then(|certificate| certificate.days_left(unix_now() as i64)),
+        }
+    }
+
+    /// 100 for an available instance with every metric healthy
+    ///
+    /// An instance that is not available loses half its score, and each metric
+    /// in warning or critical state deducts a further amount, as does a CA
+    /// certificate due for rotation.
+    pub fn health_score(&self, thresholds: &Thresholds) -> u32 {
+        let mut penalty = if self.status == "available" {
+            0
+        } else {
+            NOT_AVAILABLE_PENALTY
+        };
+        for ((_, name, _), value) in SNAPSHOT_METRICS.iter().zip(&self.snapshot) {
+            penalty += match value.and_then(|value| get_metric_severity(thresholds, name, value)) {
+                Some(Severity::Critical) => CRITICAL_PENALTY,
+                Some(Severity::Warning) => WARNING_PENALTY,
+                _ => 0,
+            };
+        }
+        penalty += match self.certificate_days_left.and_then(rotation_severity) {
+            Some(Severity::Critical) => CRITICAL_PENALTY,
+            Some(Severity::Warning) => WARNING_PENALTY,
+            _ => 0,
+        };
+        100u32.saturating_sub(penalty)
+    }
+
+    fn cells(&self, thresholds: &Thresholds, reference_lines: &ReferenceLines) -> Vec<String> {
+        let mut cells = vec![
+            self.identifier.clone(),
+            self.engine.clone(),
+            self.instance_class.clone(),
+            self.status.clone(),
+        ];
+        cells.extend(SNAPSHOT_METRICS.iter().zip(&self.snapshot).map(
+            |((metric, _, unit), value)| match value {
+                Some(value) => {
+                    let crossed: Vec<&str> = reference_lines
+                        .get(*metric)
+                        .into_iter()
+                        .flatten()
+                        .filter(|line| line.crossed_by(*value))
+                        .map(|line| line.label.as_str())
+                        .collect();
+                    if crossed.is_empty() {
+                        format_value(*value, unit)
+                    } else {
+                        format!(
+                            "{} (past {})",
+                            format_value(*value, unit),
+                            crossed.join(", ")
+                        )
+                    }
+                }
+                None => "-".to_string(),
+            },
+        ));
+        cells.push(
+            self.certificate_days_left
+                .map_or_else(|| "-".to_string(), days_left_label),
+        );
+        cells.push(self.health_score(thresholds).to_string());
+        cells
+    }
+}
+
+/// Load every RDS instance with a metric snapshot and render the report
+pub async fn export_fleet(
+    format: ExportFormat,
+    thresholds: &Thresholds,
+    reference_lines: &ReferenceLines,
+) -> Result<String> {
+    let rows = load_fleet_rows(|_, _| {}).await?;
+    Ok(render(
+        format,
+        &rows,
+        thresholds,
+        reference_lines,
+        &Local::now(),
+    ))
+}
+
+/// Write the report to `path` as a background job, reporting the instances loaded so far
+pub async fn export_fleet_to_file(
+    format: ExportFormat,
+    thresholds: Thresholds,
+    reference_lines: ReferenceLines,
+    path: PathBuf,
+    reporter: JobReporter,
+) -> Result<String> {
+    let rows = load_fleet_rows(|done, total| {
+        reporter.progress(
+            Some(done as f64 / total as f64),
+            format!("Loaded {done} of {total} instances"),
+        )
+    })
+    .await?;
+    let report = render(format, &rows, &thresholds, &reference_lines, &Local::now());
+    std::fs::write(&path, report).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(format!(
+        "Wrote {} instances to {}",
+        rows.len(),
+        path.display()
+    ))
+}
+
+/// Every RDS instance with its metric snapshot; `on_row(done, total)` follows each one
+async fn load_fleet_rows(on_row: impl Fn(usize, usize)) -> Result<Vec<FleetRow>> {
+    let instances = load_rds_instances().await?;
+    let (done, total) = (AtomicUsize::new(0), instances.len());
+    let rows = futures::future::join_all(instances.iter().map(|instance| {
+        let (done, on_row) = (&done, &on_row);
+        async move {
+            let series =
+                fetch_key_metrics(&instance.identifier, SNAPSHOT_WINDOW, SNAPSHOT_PERIOD_SECS)
+                    .await;
+            on_row(done.fetch_add(1, Ordering::Relaxed) + 1, total);
+            FleetRow::new(instance, &series)
+        }
+    }))
+    .await;
+    Ok(rows)
+}
+
+/// Series of each of `SNAPSHOT_METRICS` for one instance, in the same order
+pub async fn fetch_key_metrics(
+    identifier: &str,
+    window: Duration,
+    period_seconds: i32,
+) -> Vec<MetricSeries> {
+    let client = AwsSessionManager::metric_client().await;
+    let end_time = SystemTime::now();
+    let start_time = end_time - window;
+
+    futures::future::join_all(SNAPSHOT_METRICS.iter().map(|(metric, _, unit)| {
+        fetch_comprehensive_metric(
+            &client,
+            MetricFetchParams {
+                metric_name: metric.to_string(),
+                namespace: "AWS/RDS".to_string(),
+                instance_id: identifier.to_string(),
+                unit: Some(unit.to_string()),
+            },
+            start_time,
+            end_time,
+            period_seconds,
+        )
+    }))
+    .await
+}
+
+fn headers() -> Vec<&'static str> {
+    let mut headers = vec!["Instance", "Engine", "Class", "Status"];
+    headers.extend(SNAPSHOT_METRICS.iter().map(|(_, name, _)| *name));
+    headers.extend(["CA Expires", "Health"]);
+    headers
+}
+
+pub fn render(
+    format: ExportFormat,
+    rows: &[FleetRow],
+    thresholds: &Thresholds,
+    reference_lines: &ReferenceLines,
+    generated_at: &DateTime<Local>,
+) -> String {
+    let generated = generated_at.format("%Y-%m-%d %H:%M %Z");
+    let headers = headers();
+    match format {
+        ExportFormat::Markdown => {
+            let row = |cells: Vec<String>| {
+                let cells: Vec<String> = cells.iter().map(|c| c.replace('|', "\\|")).collect();
+                format!("| {} |\n", cells.join(" | "))
+            };
+            let mut out = format!("# RDS Fleet Summary\n\nGenerated {generated}\n\n");
+            out += &row(headers.iter().map(|h| h.to_string()).collect());
+            out += &row(vec!["---".to_string(); headers.len()]);
+            for fleet_row in rows {
+                out += &row(fleet_row.cells(thresholds, reference_lines));
+            }
+            out
+        }
+        ExportFormat::Html => {
+            let row = |tag: &str, cells: Vec<String>| {
+                let cells: String = cells
+                    .iter()
+                    .map(|c| format!("<{tag}>{}</{tag}>", escape_html(c)))
+                    .collect();
+                format!("    <tr>{cells}</tr>\n")
+            };
+            let mut out = format!(
+                "<h1>RDS Fleet Summary</h1>\n<p>Generated {generated}</p>\n<table>\n  <thead>\n"
+            );
+            out += &row("th", headers.iter().map(|h| h.to_string()).collect());
+            out += "  </thead>\n  <tbody>\n";
+            for fleet_row in rows {
+                out += &row("td", fleet_row.cells(thresholds, reference_lines));
+            }
+            out += "  </tbody>\n</table>\n";
+            out
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reference_lines::parse_line;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_fleet_report_tables_and_health_score() {
+        let gib = 1024.0 * 1024.0 * 1024.0;
+        let rows = vec![
+            FleetRow {
+                identifier: "orders-db".to_string(),
+                engine: "postgres".to_string(),
+                status: "available".to_string(),
+                instance_class: "db.r6g.large".to_string(),
+                snapshot: vec![Some(85.0), Some(12.0), Some(20.0 * gib), None, None],
+                certificate_days_left: Some(30),
+            },
+            FleetRow {
+                identifier: "<legacy>".to_string(),
+                engine: "mysql".to_string(),
+                status: "stopped".to_string(),
+                instance_class: "db.t3.micro".to_string(),
+                snapshot: vec![None; 5],
+                certificate_days_left: None,
+            },
+        ];
+        let thresholds = Thresholds::default();
+        assert_eq!(rows[0].health_score(&thresholds), 65);
+        assert_eq!(rows[1].health_score(&thresholds), 50);
+
+        let generated = Local.with_ymd_and_hms(2024, 3, 1, 7, 30, 0).unwrap();
+        let lines = ReferenceLines::new();
+        let markdown = render(
+            ExportFormat::Markdown,
+            &rows,
+            &thresholds,
+            &lines,
+            &generated,
+        );
+        assert!(markdown.contains(
+            "| orders-db | postgres | db.r6g.large | available | 85.0% | 12.0 | 20.0 GB | - | - | in 30 days | 65 |"
+        ));
+
+        // A value past a pinned line names it; one on the right side does not
+        let lines = ReferenceLines::from([
+            (
+                "CPUUtilization".to_string(),
+                vec![parse_line("SLO=80%", "Percent").unwrap()],
+            ),
+            (
+                "DatabaseConnections".to_string(),
+                vec![parse_line("max_connections=500", "Count").unwrap()],
+            ),
+        ]);
+        let markdown = render(
+            ExportFormat::Markdown,
+            &rows,
+            &thresholds,
+            &lines,
+            &generated,
+        );
+        assert!(markdown.contains("| 85.0% (past SLO) | 12.0 |"));
+
+        let html = render(ExportFormat::Html, &rows, &thresholds, &lines, &generated);
+        assert!(html.contains("<td>&lt;legacy&gt;</td>"));
+        assert_eq!(html.matches("<tr>").count(), 3);
+
+        assert_eq!(ExportFormat::parse("MD").unwrap(), ExportFormat::Markdown);
+        assert!(ExportFormat::parse("csv").is_err());
+    }
+}
@@ -0,0 +1,255 @@
+// Inventory of one service across several accounts
+//
+// Accounts are listed in config.toml, each reached through a profile, a role
+// assumed with the profile's (or the default) credentials, or both, in the
+// regions given (the profile's own region when none are):
+//
+//   [[accounts]]
+//   name = "prod"
+//   profile = "prod-sso"
+//   role_arn = "arn:aws:iam::123456789012:role/ReadOnly"
+//   regions = ["eu-west-1", "us-east-1"]
+//
+// `F` on the service list opens the fleet page, which lists the chosen
+// service in every account and region at once. Enter on a row switches the
+// session to the row's account and region and opens the resource there.
+
+use crate::aws::resource_groups::GroupMember;
+use crate::aws::session::{AwsSessionManager, SessionContext};
+use crate::listing::load_instances;
+use crate::models::{AwsService, ServiceInstance};
+use crate::ui::theme::Severity;
+use futures::future::join_all;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Account {
+    pub name: String,
+    #[serde(default)]
+    pub profile: Option<String>,
+    #[serde(default)]
+    pub role_arn: Option<String>,
+    #[serde(default)]
+    pub regions: Vec<String>, // Empty: the region the profile resolves to
+}
+
+/// One account and region the fleet page enumerates
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    pub account: String,
+    pub context: SessionContext,
+}
+
+/// Every account in each of its regions, in the order they are configured
+pub fn locations(accounts: &[Account]) -> Vec<Location> {
+    accounts
+        .iter()
+        .flat_map(|account| {
+            let regions: Vec<Option<String>> = if account.regions.is_empty() {
+                vec![None]
+            } else {
+                account.regions.iter().cloned().map(Some).collect()
+            };
+            regions.into_iter().map(|region| Location {
+                account: account.name.clone(),
+                context: SessionContext {
+                    profile: account.profile.clone(),
+                    role_arn: account.role_arn.clone(),
+                    region,
+                },
+            })
+        })
+        .collect()
+}
+
+/// A resource as listed in one account and region
+#[derive(Debug, Clone)]
+pub struct FleetRow {
+    pub account: String,
+    pub region: String,
+    pub context: SessionContext, // Region filled in, so opening the row lands where it was listed
+    pub instance: ServiceInstance,
+}
+
+impl FleetRow {
+    /// "prod eu-west-1"
+    pub fn location_label(&self) -> String {
+        format!("{} {}", self.account, self.region)
+    }
+}
+
+/// Rows of every location that listed, and why the others did not
+#[derive(Debug, Default)]
+pub struct Inventory {
+    pub rows: Vec<FleetRow>,
+    pub failures: Vec<String>,
+}
+
+/// List `service` in every location at once; a location that fails is reported, not fatal
+pub async fn enumerate(service: &AwsService, locations: &[Location]) -> Inventory {
+    let listings = join_all(locations.iter().map(|location| async move {
+        let config = AwsSessionManager::load_context(&location.context).await;
+        let region = config
+            .region()
+            .map_or_else(|| "-".to_string(), |region| region.to_string());
+        let instances = AwsSessionManager::scoped(config, load_instances(service.clone())).await;
+        (location, region, instances)
+    }))
+    .await;
+
+    let mut inventory = Inventory::default();
+    for (location, region, instances) in listings {
+        match instances {
+            Ok(instances) => {
+                inventory
+                    .rows
+                    .extend(instances.into_iter().map(|instance| FleetRow {
+                        account: location.account.clone(),
+                        region: region.clone(),
+                        context: SessionContext {
+                            region: Some(region.clone()),
+                            ..location.context.clone()
+                        },
+                        instance,
+                    }))
+            }
+            Err(e) => inventory
+                .failures
+                .push(format!("{} {region}: {e:#}", location.account)),
+        }
+    }
+    inventory
+}
+
+/// Badge summing up a resource's health, e.g. an instance's status or a canary's last run
+pub fn health(instance: &ServiceInstance) -> (String, Option<Severity>) {
+    match instance {
+        ServiceInstance::Rds(instance) => {
+            let severity = match instance.status.as_str() {
+                "available" => Some(Severity::Ok),
+                "stopped" | "failed" | "storage-full" | "inaccessible-encryption-credentials" => {
+                    Some(Severity::Critical)
+                }
+                "" => None,
+                _ => Some(Severity::Warning),
+            };
+            (instance.status.clone(), severity)
+        }
+        ServiceInstance::Sqs(queue) => (
+            format!(
+                "{} visible",
+                queue
+                    .attribute("ApproximateNumberOfMessages")
+                    .unwrap_or("-")
+            ),
+            None,
+        ),
+        ServiceInstance::Canary(canary) => match &canary.last_run {
+            Some(run) if run.failed() => ("last run failed".to_string(), Some(Severity::Critical)),
+            Some(_) => ("last run passed".to_string(), Some(Severity::Ok)),
+            None => (canary.state.to_lowercase(), None),
+        },
+        ServiceInstance::EventRule(rule) => {
+            let without_dlq = rule.targets_without_dlq().count();
+            if !rule.is_enabled() {
+                (rule.state.to_lowercase(), None)
+            } else if without_dlq > 0 {
+                (
+                    format!("{without_dlq} without DLQ"),
+                    Some(Severity::Warning),
+                )
+            } else {
+                ("enabled".to_string(), Some(Severity::Ok))
+            }
+        }
+        ServiceInstance::BatchQueue(queue) => {
+            let failed = queue.job_count("FAILED");
+            if failed > 0 {
+                (format!("{failed} failed"), Some(Severity::Critical))
+            } else if queue.is_enabled() {
+                ("enabled".to_string(), Some(Severity::Ok))
+            } else {
+                (queue.state.to_lowercase(), None)
+            }
+        }
+        ServiceInstance::Function(function) => match function.state.as_deref() {
+            Some(state) if function.is_failed() => (state.to_lowercase(), Some(Severity::Critical)),
+            Some("Active") => ("active".to_string(), Some(Severity::Ok)),
+            Some(state) => (state.to_lowercase(), Some(Severity::Warning)),
+            None => (function.runtime.clone(), None),
+        },
+    }
+}
+
+/// Health of each monitored member of a resource group, by ARN
+///
+/// Each service among the members is listed once in the session's account and
+/// region; a service that cannot be listed leaves its members without health.
+pub async fn member_health(members: &[GroupMember]) -> HashMap<String, (String, Option<Severity>)> {
+    let mut services: Vec<AwsService> = Vec::new();
+    for service in members.iter().filter_map(GroupMember::service) {
+        if !services.contains(&service) {
+            services.push(service);
+        }
+    }
+    let listings = join_all(services.into_iter().map(|service| async move {
+        let instances = load_instances(service.clone()).await.unwrap_or_default();
+        (service, instances)
+    }))
+    .await;
+
+    members
+        .iter()
+        .filter_map(|member| {
+            let service = member.service()?;
+            let (_, instances) = listings.iter().find(|(listed, _)| *listed == service)?;
+            let instance = instances
+                .iter()
+                .find(|instance| instance.as_aws_instance().id() == member.id())?;
+            Some((member.arn.clone(), health(instance)))
+        })
+        .collect()
+}
+
+/// Identifier of a row's resource, as the instance list shows it
+pub fn resource_id(row: &FleetRow) -> &str {
+    row.instance.as_aws_instance().id()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accounts_expand_to_one_location_per_region() {
+        #[derive(Deserialize)]
+        struct File {
+            accounts: Vec<Account>,
+        }
+        let file: File = toml::from_str(
+            r#"
+            [[accounts]]
+            name = "prod"
+            profile = "prod-sso"
+            regions = ["eu-west-1", "us-east-1"]
+
+            [[accounts]]
+            name = "staging"
+            role_arn = "arn:aws:iam::210987654321:role/ReadOnly"
+            "#,
+        )
+        .unwrap();
+
+        let locations = locations(&file.accounts);
+        assert_eq!(locations.len(), 3);
+        assert_eq!(locations[0].account, "prod");
+        assert_eq!(locations[1].context.region.as_deref(), Some("us-east-1"));
+        assert_eq!(locations[1].context.profile.as_deref(), Some("prod-sso"));
+        assert_eq!(locations[2].context.region, None);
+        assert_eq!(
+            locations[2].context.role_arn.as_deref(),
+            Some("arn:aws:iam::210987654321:role/ReadOnly")
+        );
+    }
+}
@@ -0,0 +1,204 @@
+// Undo/redo of navigation and view settings, and the jump list
+//
+// Before anything changes what a tab shows (the page, the resource, the time
+// range, the watchlist filter or the charted metric) a snapshot of the view
+// is recorded. Undo restores the last snapshot and keeps the current view for
+// redo; any new change clears the redo side. Snapshots hold identifiers
+// rather than data, so restoring one reloads whatever it points at.
+//
+// The jump list only remembers which resources were opened, in order, like a
+// browser's back/forward history, whichever way each one was reached.
+
+use crate::aws::cloudwatch_service::TimeRange;
+use crate::models::{AppState, AwsService, FocusedPanel};
+use std::collections::VecDeque;
+
+/// Snapshots kept for undo, and resources kept in the jump list; the oldest
+/// is dropped beyond this
+const HISTORY_LIMIT: usize = 50;
+
+/// Where a tab was and how its view was set up
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewSnapshot {
+    pub state: AppState,
+    pub service: Option<AwsService>,
+    pub instance_id: Option<String>, // Resource open on the metric pages
+    pub list_selected: Option<usize>,
+    pub time_range: TimeRange,
+    pub time_range_scroll: usize,
+    pub watchlist_only: bool,
+    pub focused_panel: FocusedPanel,
+    pub selected_metric_index: usize, // Metric selected in the sparkline grid
+    pub scroll_offset: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct ViewHistory {
+    undo: VecDeque<ViewSnapshot>,
+    redo: Vec<ViewSnapshot>,
+}
+
+impl ViewHistory {
+    /// Remember the view as it is before a change
+    pub fn record(&mut self, snapshot: ViewSnapshot) {
+        // Repeating a change (e.g. cycling periods) records one step
+        if self.undo.back() != Some(&snapshot) {
+            self.undo.push_back(snapshot);
+            if self.undo.len() > HISTORY_LIMIT {
+                self.undo.pop_front();
+            }
+        }
+        self.redo.clear();
+    }
+
+    /// The view to go back to, keeping `current` for redo
+    pub fn undo(&mut self, current: ViewSnapshot) -> Option<ViewSnapshot> {
+        let previous = self.undo.pop_back()?;
+        self.redo.push(current);
+        Some(previous)
+    }
+
+    /// The view last undone, keeping `current` for undo
+    pub fn redo(&mut self, current: ViewSnapshot) -> Option<ViewSnapshot> {
+        let next = self.redo.pop()?;
+        self.undo.push_back(current);
+        Some(next)
+    }
+}
+
+/// Resources opened in a tab, with the one being looked at
+#[derive(Debug, Default)]
+pub struct JumpList {
+    entries: Vec<ViewSnapshot>,
+    position: usize,
+}
+
+impl JumpList {
+    /// A resource was opened; anything ahead of the current entry is forgotten
+    pub fn visit(&mut self, snapshot: ViewSnapshot) {
+        if let Some(entry) = self.entries.get_mut(self.position) {
+            if same_resource(entry, &snapshot) {
+                *entry = snapshot;
+                return;
+            }
+            self.entries.truncate(self.position + 1);
+        }
+        self.entries.push(snapshot);
+        if self.entries.len() > HISTORY_LIMIT {
+            self.entries.remove(0);
+        }
+        self.position = self.entries.len() - 1;
+    }
+
+    /// The resource opened before the one in `current`. Away from any
+    /// resource (e.g. back on the list), that is the last one opened.
+    pub fn back(&mut self, current: ViewSnapshot) -> Option<ViewSnapshot> {
+        let entry = self.entries.get(self.position)?;
+        if !same_resource(entry, &current) {
+            return Some(entry.clone());
+        }
+        if self.position == 0 {
+            return None;
+        }
+        self.entries[self.position] = current;
+        self.position -= 1;
+        Some(self.entries[self.position].clone())
+    }
+
+    /// The resource opened after the current entry, before going back
+    pub fn forward(&mut self, current: ViewSnapshot) -> Option<ViewSnapshot> {
+        if self.position + 1 >= self.entries.len() {
+            return None;
+        }
+        if same_resource(&self.entries[self.position], &current) {
+            self.entries[self.position] = current;
+        }
+        self.position += 1;
+        Some(self.entries[self.position].clone())
+    }
+}
+
+fn same_resource(a: &ViewSnapshot, b: &ViewSnapshot) -> bool {
+    a.service == b.service && a.instance_id == b.instance_id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aws::cloudwatch_service::TimeUnit;
+
+    fn resource(service: AwsService, id: &str) -> ViewSnapshot {
+        ViewSnapshot {
+            service: Some(service),
+            instance_id: Some(id.to_string()),
+            ..snapshot(3)
+        }
+    }
+
+    fn snapshot(hours: u32) -> ViewSnapshot {
+        ViewSnapshot {
+            state: AppState::MetricsSummary,
+            service: Some(AwsService::Rds),
+            instance_id: Some("orders-db".to_string()),
+            list_selected: Some(0),
+            time_range: TimeRange::new(hours, TimeUnit::Hours, 1).unwrap(),
+            time_range_scroll: 0,
+            watchlist_only: false,
+            focused_panel: FocusedPanel::TimeRanges,
+            selected_metric_index: 0,
+            scroll_offset: 0,
+        }
+    }
+
+    #[test]
+    fn test_undo_redo_and_new_changes_clear_redo() {
+        let mut history = ViewHistory::default();
+        history.record(snapshot(1));
+        history.record(snapshot(3));
+        history.record(snapshot(3)); // Same view twice is one step
+
+        // Viewing 6h: undo goes to 3h, then 1h, then nothing
+        assert_eq!(history.undo(snapshot(6)), Some(snapshot(3)));
+        assert_eq!(history.undo(snapshot(3)), Some(snapshot(1)));
+        assert_eq!(history.undo(snapshot(1)), None);
+
+        assert_eq!(history.redo(snapshot(1)), Some(snapshot(3)));
+        history.record(snapshot(3));
+        assert_eq!(history.redo(snapshot(12)), None);
+
+        for hours in 1..=HISTORY_LIMIT as u32 + 5 {
+            history.record(snapshot(hours));
+        }
+        assert_eq!(history.undo.len(), HISTORY_LIMIT);
+    }
+
+    #[test]
+    fn test_jump_list_back_and_forward_across_services() {
+        let queue = resource(AwsService::Sqs, "orders");
+        let dlq = resource(AwsService::Sqs, "orders-dlq");
+        let db = resource(AwsService::Rds, "orders-db");
+        let mut jumps = JumpList::default();
+        jumps.visit(queue.clone());
+        jumps.visit(dlq.clone());
+        jumps.visit(db.clone());
+
+        assert_eq!(jumps.back(db.clone()), Some(dlq.clone()));
+        assert_eq!(jumps.back(dlq.clone()), Some(queue.clone()));
+        assert_eq!(jumps.back(queue.clone()), None);
+        assert_eq!(jumps.forward(queue.clone()), Some(dlq.clone()));
+
+        // Back on the instance list, back returns to the resource left
+        let list = ViewSnapshot {
+            state: AppState::InstanceList,
+            instance_id: None,
+            ..dlq.clone()
+        };
+        assert_eq!(jumps.back(list), Some(dlq.clone()));
+
+        // Opening another resource drops the forward entries
+        let other = resource(AwsService::Rds, "payments-db");
+        jumps.visit(other.clone());
+        assert_eq!(jumps.forward(other.clone()), None);
+        assert_eq!(jumps.back(other), Some(dlq));
+    }
+}
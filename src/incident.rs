@@ -0,0 +1,264 @@
+// Incident mode
+//
+// 'I' on an RDS instance's metrics sets up everything an incident calls for
+// in one go: the last hour, refreshed every REFRESH_INTERVAL; the tail of the
+// instance's error log in a pane under the metrics; its firing alarms above
+// the log; and a recording of the session for the postmortem. 'I' again ends
+// it and puts the time range and auto-refresh back as they were.
+//
+// The recording is a Markdown timeline in the data directory, e.g.
+// ~/.local/share/awscw/incidents/orders-db-20260314T020512Z.md: when incident
+// mode started and ended, every alarm that started or stopped firing, and
+// every error log line, each with its time. Lines are written as they happen,
+// so a crash or a lost SSH session keeps what was recorded.
+
+use crate::aws::logs::{rds_log_group, LogEvent};
+use crate::aws::time_range::{TimeRange, TimeUnit};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeSet, VecDeque};
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
+
+/// How often metrics, alarms and the log are refreshed during an incident
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How far back the log tail starts
+const TAIL_SINCE: Duration = Duration::from_secs(10 * 60);
+
+/// Log lines kept for the pane, oldest dropped first
+const TAIL_LINES: usize = 200;
+
+const INCIDENTS_DIR: &str = "incidents";
+
+/// The window incident mode pins
+pub fn time_range() -> TimeRange {
+    TimeRange::new(1, TimeUnit::Hours, 1).expect("one hour is a valid time range")
+}
+
+/// Log group an engine writes its errors to, if it publishes one
+pub fn error_log_group(
+    instance_id: &str,
+    cluster_id: Option<&str>,
+    engine: &str,
+) -> Option<String> {
+    let log = if engine.contains("postgres") {
+        "postgresql"
+    } else if engine.contains("mysql")
+        || engine.contains("mariadb")
+        || engine.starts_with("sqlserver")
+    {
+        "error"
+    } else if engine.starts_with("oracle") {
+        "alert"
+    } else {
+        return None;
+    };
+    Some(rds_log_group(instance_id, cluster_id, engine, log))
+}
+
+/// What incident mode changed, to put back when it ends
+#[derive(Debug, Clone, Copy)]
+pub struct Restore {
+    pub time_range: TimeRange,
+    pub time_range_scroll: usize, // Time range highlighted in the metrics page's panel
+    pub auto_refresh: bool,
+}
+
+#[derive(Debug)]
+pub struct Incident {
+    pub resource: String,
+    pub started_at: SystemTime,
+    pub log_group: Option<String>,
+    pub log: VecDeque<LogEvent>,
+    pub log_notice: Option<String>, // Why there is no log to tail, or why reading it failed
+    pub firing: BTreeSet<String>,   // Names of the resource's alarms in ALARM
+    pub recording: Option<Recording>,
+    pub last_refresh: Option<Instant>,
+    pub restore: Restore,
+}
+
+impl Incident {
+    pub fn new(
+        resource: String,
+        log_group: Option<String>,
+        recording: Option<Recording>,
+        restore: Restore,
+    ) -> Self {
+        Self {
+            resource,
+            started_at: SystemTime::now(),
+            log_notice: log_group
+                .is_none()
+                .then(|| "This engine publishes no error log to tail".to_string()),
+            log_group,
+            log: VecDeque::new(),
+            firing: BTreeSet::new(),
+            recording,
+            last_refresh: None,
+            restore,
+        }
+    }
+
+    pub fn needs_refresh(&self) -> bool {
+        self.last_refresh
+            .is_none_or(|last| last.elapsed() > REFRESH_INTERVAL)
+    }
+
+    /// Where the next read of the log starts: the newest line tailed, else TAIL_SINCE ago
+    pub fn log_since(&self) -> SystemTime {
+        self.log
+            .back()
+            .map_or_else(|| SystemTime::now() - TAIL_SINCE, |event| event.timestamp)
+    }
+
+    /// Take in the resource's firing alarms, recording the ones that changed
+    pub fn update_alarms(&mut self, firing: BTreeSet<String>) {
+        let changes: Vec<String> = firing
+            .difference(&self.firing)
+            .map(|name| format!("Alarm firing: {name}"))
+            .chain(
+                self.firing
+                    .difference(&firing)
+                    .map(|name| format!("Alarm cleared: {name}")),
+            )
+            .collect();
+        for change in changes {
+            self.note(&change);
+        }
+        self.firing = firing;
+    }
+
+    /// Append the events read since the last refresh, skipping those already tailed
+    pub fn append_log(&mut self, events: Vec<LogEvent>) {
+        let fresh: Vec<LogEvent> = events
+            .into_iter()
+            .filter(|event| !self.log.iter().rev().any(|seen| same_event(seen, event)))
+            .collect();
+        for event in fresh {
+            if let Some(recording) = self.recording.as_mut() {
+                recording.write(
+                    event.timestamp,
+                    &format!("log: {}", event.message.trim_end()),
+                );
+            }
+            if self.log.len() == TAIL_LINES {
+                self.log.pop_front();
+            }
+            self.log.push_back(event);
+        }
+    }
+
+    /// Add a line to the recording, timed now
+    pub fn note(&mut self, text: &str) {
+        if let Some(recording) = self.recording.as_mut() {
+            recording.write(SystemTime::now(), text);
+        }
+    }
+}
+
+/// Whether two events are the same log line, read twice
+fn same_event(a: &LogEvent, b: &LogEvent) -> bool {
+    a.timestamp == b.timestamp && a.message == b.message
+}
+
+/// The postmortem timeline being written
+#[derive(Debug)]
+pub struct Recording {
+    pub path: PathBuf,
+    file: File,
+}
+
+impl Recording {
+    pub fn start(resource: &str, started_at: SystemTime) -> Result<Self> {
+        let dir = dirs::data_local_dir()
+            .map(|dir| dir.join("awscw").join(INCIDENTS_DIR))
+            .context("No data directory to record the incident in")?;
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+        let path = dir.join(file_name(resource, started_at));
+        let mut file =
+            File::create(&path).with_context(|| format!("Failed to create {}", path.display()))?;
+        writeln!(
+            file,
+            "# Incident on {resource}\n\nStarted {}\n",
+            DateTime::<Utc>::from(started_at).format("%Y-%m-%d %H:%M:%S UTC")
+        )
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(Self { path, file })
+    }
+
+    /// A timeline entry; a failed write is not worth interrupting the incident for
+    fn write(&mut self, at: SystemTime, text: &str) {
+        let _ = writeln!(
+            self.file,
+            "- {} {text}",
+            DateTime::<Utc>::from(at).format("%H:%M:%SZ")
+        );
+    }
+}
+
+fn file_name(resource: &str, started_at: SystemTime) -> String {
+    let safe: String = resource
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!(
+        "{safe}-{}.md",
+        DateTime::<Utc>::from(started_at).format("%Y%m%dT%H%M%SZ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_incident_tracks_alarm_changes_and_new_log_lines() {
+        assert_eq!(
+            error_log_group("orders-db", None, "mysql").as_deref(),
+            Some("/aws/rds/instance/orders-db/error")
+        );
+        assert_eq!(
+            error_log_group("orders-1", Some("orders"), "aurora-postgresql").as_deref(),
+            Some("/aws/rds/cluster/orders/postgresql")
+        );
+        assert_eq!(error_log_group("legacy", None, "db2-se"), None);
+
+        let restore = Restore {
+            time_range: time_range(),
+            time_range_scroll: 0,
+            auto_refresh: false,
+        };
+        let mut incident = Incident::new("orders-db".to_string(), None, None, restore);
+        assert!(incident.log_notice.is_some());
+        assert!(incident.needs_refresh());
+
+        incident.update_alarms(BTreeSet::from(["cpu-high".to_string()]));
+        incident.update_alarms(BTreeSet::from(["disk-low".to_string()]));
+        assert_eq!(incident.firing, BTreeSet::from(["disk-low".to_string()]));
+
+        let at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let event = |secs, message: &str| LogEvent {
+            event_id: String::new(),
+            timestamp: at + Duration::from_secs(secs),
+            message: message.to_string(),
+        };
+        incident.append_log(vec![event(0, "deadlock"), event(1, "timeout")]);
+        // The next read starts at the newest line, which comes back with the new ones
+        assert_eq!(incident.log_since(), at + Duration::from_secs(1));
+        incident.append_log(vec![event(1, "timeout"), event(1, "retry"), event(2, "ok")]);
+        let messages: Vec<&str> = incident.log.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages, ["deadlock", "timeout", "retry", "ok"]);
+
+        assert_eq!(file_name("orders/db", at), "orders_db-20231114T221320Z.md");
+    }
+}
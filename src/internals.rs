@@ -0,0 +1,200 @@
+// The app's own figures, sampled once a second and charted like a resource's metrics
+//
+// Frame time is the slowest frame drawn since the previous sample. Fetch
+// latency is the mean time of the AWS calls completed since then, timed by
+// the interceptor that counts them. The cache hit rate is that of the warm
+// cache of favorite instances over the session, and pending fetches are the
+// instances it has still to warm plus the background jobs running. Memory is
+// the resident size of the process. `:internals` charts the last ten minutes;
+// `y` there copies the latest values for a bug report.
+
+use crate::aws::metrics::types::{MetricCategory, MetricDefinition, MetricValue, StatisticType};
+use crate::ui::components::metric_utils::format_value;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant, SystemTime};
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Samples kept per figure, oldest dropped first
+const MAX_SAMPLES: usize = 600;
+
+/// The figures the app samples about itself, with units `format_value` takes
+const INTERNALS: [(&str, &str); 5] = [
+    ("Frame time", "Milliseconds"),
+    ("Fetch latency", "Milliseconds"),
+    ("Cache hit rate", "Percent"),
+    ("Pending fetches", "Count"),
+    ("Memory", "Bytes"),
+];
+
+/// The figures as metric definitions, in the order they are charted
+pub fn definitions() -> Vec<MetricDefinition> {
+    INTERNALS
+        .iter()
+        .map(|(name, unit)| MetricDefinition {
+            name: name.to_string(),
+            unit: Some(unit.to_string()),
+            statistic: StatisticType::Maximum,
+            category: MetricCategory::Performance,
+        })
+        .collect()
+}
+
+/// Figures read from the rest of the app when a sample is taken
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Readings {
+    // AWS calls completed this session and the time they took between them
+    pub calls: (u64, Duration),
+    pub cache_hit_rate: Option<f64>, // None before the first lookup
+    pub pending_fetches: usize,
+    pub memory: Option<u64>,
+}
+
+/// The last ten minutes of the app's own figures
+#[derive(Debug, Default)]
+pub struct Internals {
+    timestamps: VecDeque<SystemTime>,
+    series: [VecDeque<f64>; INTERNALS.len()],
+    slowest_frame: Duration, // Since the last sample
+    calls: (u64, Duration),  // As read at the last sample
+    sampled_at: Option<Instant>,
+}
+
+impl Internals {
+    pub fn record_frame(&mut self, elapsed: Duration) {
+        self.slowest_frame = self.slowest_frame.max(elapsed);
+    }
+
+    pub fn is_due(&self) -> bool {
+        self.sampled_at
+            .is_none_or(|sampled_at| sampled_at.elapsed() >= SAMPLE_INTERVAL)
+    }
+
+    pub fn sample(&mut self, readings: Readings) {
+        let (calls, time) = readings.calls;
+        let (last_calls, last_time) = self.calls;
+        // Without a call completed since the last sample, latency reads as zero
+        let latency = match calls.saturating_sub(last_calls) {
+            0 => Duration::ZERO,
+            completed => time.saturating_sub(last_time) / completed as u32,
+        };
+        let values = [
+            millis(std::mem::take(&mut self.slowest_frame)),
+            millis(latency),
+            readings
+                .cache_hit_rate
+                .map_or(f64::NAN, |rate| rate * 100.0),
+            readings.pending_fetches as f64,
+            readings.memory.map_or(f64::NAN, |bytes| bytes as f64),
+        ];
+
+        if self.timestamps.len() == MAX_SAMPLES {
+            self.timestamps.pop_front();
+            for series in &mut self.series {
+                series.pop_front();
+            }
+        }
+        self.timestamps.push_back(SystemTime::now());
+        for (series, value) in self.series.iter_mut().zip(values) {
+            series.push_back(value);
+        }
+        self.calls = readings.calls;
+        self.sampled_at = Some(Instant::now());
+    }
+
+    pub fn timestamps(&self) -> &VecDeque<SystemTime> {
+        &self.timestamps
+    }
+
+    /// The samples of each figure that has been read, keyed by name like a resource's metrics
+    ///
+    /// A figure that could not be read is NaN for that sample; the latest value
+    /// is the latest reading, and a figure never read is left out.
+    pub fn metrics(&self) -> HashMap<String, MetricValue> {
+        INTERNALS
+            .iter()
+            .zip(&self.series)
+            .filter_map(|((name, _), series)| {
+                let current = series.iter().rev().copied().find(|v| !v.is_nan())?;
+                let history = series.iter().copied().collect();
+                Some((name.to_string(), MetricValue::new(current, history)))
+            })
+            .collect()
+    }
+
+    /// The latest value and the peak of each figure, one per line, for a bug report
+    pub fn report(&self) -> String {
+        let mut report = format!(
+            "awscw {} internals over the last {} samples\n",
+            env!("CARGO_PKG_VERSION"),
+            self.timestamps.len()
+        );
+        let metrics = self.metrics();
+        for (name, unit) in INTERNALS {
+            let Some(value) = metrics.get(name) else {
+                report.push_str(&format!("{name}: not available\n"));
+                continue;
+            };
+            let peak = value.history.iter().copied().fold(0.0, f64::max);
+            report.push_str(&format!(
+                "{name}: {} (peak {})\n",
+                format_value(value.current, unit),
+                format_value(peak, unit)
+            ));
+        }
+        report
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples_take_the_slowest_frame_and_mean_latency() {
+        let ms = Duration::from_millis;
+        let mut internals = Internals::default();
+        assert!(internals.is_due());
+
+        internals.record_frame(ms(4));
+        internals.record_frame(ms(30));
+        internals.sample(Readings {
+            calls: (2, ms(300)),
+            cache_hit_rate: Some(0.5),
+            pending_fetches: 3,
+            memory: Some(64 << 20),
+        });
+        assert!(!internals.is_due());
+        // Nothing drawn or called in between
+        internals.sample(Readings {
+            calls: (2, ms(300)),
+            ..Readings::default()
+        });
+
+        let metrics = internals.metrics();
+        assert_eq!(metrics["Frame time"].history, [30.0, 0.0]);
+        assert_eq!(metrics["Fetch latency"].history, [150.0, 0.0]);
+        assert_eq!(metrics["Pending fetches"].history, [3.0, 0.0]);
+        // Unread figures are gaps rather than zeros, and keep their last reading
+        assert!(metrics["Cache hit rate"].history[1].is_nan());
+        assert_eq!(metrics["Memory"].current, (64 << 20) as f64);
+        assert_eq!(internals.timestamps().len(), 2);
+        assert!(internals
+            .report()
+            .contains("Memory: 64.0 MB (peak 64.0 MB)"));
+    }
+
+    #[test]
+    fn test_figures_never_read_are_left_out() {
+        let mut internals = Internals::default();
+        internals.sample(Readings::default());
+        let metrics = internals.metrics();
+        assert!(metrics.contains_key("Frame time"));
+        assert!(!metrics.contains_key("Memory"));
+        assert!(internals.report().contains("Memory: not available"));
+    }
+}
@@ -0,0 +1,269 @@
+// Background jobs for long-running operations
+//
+// Creating a snapshot, redriving a dead-letter queue or exporting the fleet
+// summary can take minutes. Each runs as a tokio task that reports progress
+// over a channel, so the UI keeps drawing and taking keys while several of
+// them run. The jobs panel ('J') lists them with their progress; when one
+// finishes, a toast in the corner says how it went.
+
+use anyhow::Result;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// How long a completion toast stays on screen
+const TOAST_DURATION: Duration = Duration::from_secs(6);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JobStatus {
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+impl JobStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            JobStatus::Running => "running",
+            JobStatus::Succeeded => "done",
+            JobStatus::Failed => "failed",
+            JobStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+enum JobUpdate {
+    Progress {
+        fraction: Option<f64>,
+        detail: String,
+    },
+    Finished(Result<String, String>),
+}
+
+/// Handed to a job's task to report how far it has got
+#[derive(Clone)]
+pub struct JobReporter {
+    sender: mpsc::UnboundedSender<JobUpdate>,
+}
+
+impl JobReporter {
+    /// Report progress: the share done (0-1) when known, and what is happening
+    pub fn progress(&self, fraction: Option<f64>, detail: impl Into<String>) {
+        let _ = self.sender.send(JobUpdate::Progress {
+            fraction: fraction.map(|f| f.clamp(0.0, 1.0)),
+            detail: detail.into(),
+        });
+    }
+}
+
+pub struct Job {
+    pub label: String,
+    pub status: JobStatus,
+    pub progress: Option<f64>, // Share done, 0-1, when the operation reports it
+    pub detail: String,        // Latest progress, or the outcome once finished
+    pub started_at: Instant,
+    pub finished_at: Option<Instant>,
+    updates: mpsc::UnboundedReceiver<JobUpdate>,
+    task: JoinHandle<()>,
+}
+
+impl Job {
+    pub fn elapsed(&self) -> Duration {
+        self.finished_at
+            .unwrap_or_else(Instant::now)
+            .duration_since(self.started_at)
+    }
+}
+
+/// A job's outcome, shown briefly in the corner of the screen
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub text: String,
+    pub failed: bool,
+    pub shown_at: Instant,
+}
+
+#[derive(Default)]
+pub struct JobManager {
+    jobs: Vec<Job>, // Oldest first
+    toasts: Vec<Toast>,
+}
+
+impl JobManager {
+    /// Run `work` in the background; its Ok message or error becomes the job's outcome
+    pub fn start<F, Fut>(&mut self, label: String, work: F)
+    where
+        F: FnOnce(JobReporter) -> Fut,
+        Fut: Future<Output = Result<String>> + Send + 'static,
+    {
+        let (sender, updates) = mpsc::unbounded_channel();
+        let reporter = JobReporter {
+            sender: sender.clone(),
+        };
+        let work = work(reporter);
+        let task = tokio::spawn(async move {
+            let outcome = work.await.map_err(|e| format!("{e:#}"));
+            let _ = sender.send(JobUpdate::Finished(outcome));
+        });
+        self.jobs.push(Job {
+            label,
+            status: JobStatus::Running,
+            progress: None,
+            detail: "Starting".to_string(),
+            started_at: Instant::now(),
+            finished_at: None,
+            updates,
+            task,
+        });
+    }
+
+    pub fn jobs(&self) -> &[Job] {
+        &self.jobs
+    }
+
+    pub fn running(&self) -> usize {
+        self.jobs
+            .iter()
+            .filter(|job| job.status == JobStatus::Running)
+            .count()
+    }
+
+    /// Toasts still on screen, oldest first
+    pub fn toasts(&self) -> &[Toast] {
+        &self.toasts
+    }
+
+    /// Remove the toasts on screen, to be logged rather than shown
+    pub fn take_toasts(&mut self) -> Vec<Toast> {
+        std::mem::take(&mut self.toasts)
+    }
+
+    /// Apply the progress reported since the last call and toast finished jobs; never waits
+    pub fn poll(&mut self) {
+        // A cancelled job may still have sent updates before it was stopped
+        for job in self
+            .jobs
+            .iter_mut()
+            .filter(|job| job.status == JobStatus::Running)
+        {
+            while let Ok(update) = job.updates.try_recv() {
+                match update {
+                    JobUpdate::Progress { fraction, detail } => {
+                        job.progress = fraction.or(job.progress);
+                        job.detail = detail;
+                    }
+                    JobUpdate::Finished(outcome) => {
+                        let failed = outcome.is_err();
+                        job.status = if failed {
+                            JobStatus::Failed
+                        } else {
+                            job.progress = Some(1.0);
+                            JobStatus::Succeeded
+                        };
+                        job.detail = outcome.unwrap_or_else(|e| e);
+                        job.finished_at = Some(Instant::now());
+                        self.toasts.push(Toast {
+                            text: format!("{}: {}", job.label, job.detail),
+                            failed,
+                            shown_at: Instant::now(),
+                        });
+                    }
+                }
+            }
+        }
+        self.toasts
+            .retain(|toast| toast.shown_at.elapsed() < TOAST_DURATION);
+    }
+
+    /// Stop waiting for a running job; whatever AWS already started carries on
+    pub fn cancel(&mut self, index: usize) {
+        if let Some(job) = self
+            .jobs
+            .get_mut(index)
+            .filter(|job| job.status == JobStatus::Running)
+        {
+            job.task.abort();
+            job.status = JobStatus::Cancelled;
+            job.detail = "Stopped watching; the operation may still complete in AWS".to_string();
+            job.finished_at = Some(Instant::now());
+        }
+    }
+
+    /// Forget finished jobs
+    pub fn clear_finished(&mut self) {
+        self.jobs.retain(|job| job.status == JobStatus::Running);
+    }
+}
+
+/// Jobs overlay: the selected job
+#[derive(Debug, Default)]
+pub struct JobsPanel {
+    pub selected_index: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    async fn wait_for_jobs(manager: &mut JobManager) {
+        while manager.running() > 0 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            manager.poll();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_jobs_report_progress_and_toast_outcomes() {
+        let mut manager = JobManager::default();
+        manager.start("Export".to_string(), |reporter| async move {
+            reporter.progress(Some(0.5), "1 of 2 instances");
+            Ok("Wrote fleet.md".to_string())
+        });
+        manager.start("Redrive".to_string(), |_| async move {
+            Err(anyhow!("Source queue is not a DLQ"))
+        });
+        assert_eq!(manager.running(), 2);
+
+        wait_for_jobs(&mut manager).await;
+        let jobs = manager.jobs();
+        assert_eq!(jobs[0].status, JobStatus::Succeeded);
+        assert_eq!(jobs[0].progress, Some(1.0));
+        assert_eq!(jobs[0].detail, "Wrote fleet.md");
+        assert_eq!(jobs[1].status, JobStatus::Failed);
+
+        // Whichever finished first was toasted first
+        let mut toasts: Vec<_> = manager
+            .toasts()
+            .iter()
+            .map(|toast| (toast.text.as_str(), toast.failed))
+            .collect();
+        toasts.sort();
+        assert_eq!(
+            toasts,
+            vec![
+                ("Export: Wrote fleet.md", false),
+                ("Redrive: Source queue is not a DLQ", true),
+            ]
+        );
+
+        manager.clear_finished();
+        assert!(manager.jobs().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_stops_a_running_job() {
+        let mut manager = JobManager::default();
+        manager.start("Snapshot".to_string(), |_| async move {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok("available".to_string())
+        });
+        manager.cancel(0);
+        manager.poll();
+        assert_eq!(manager.jobs()[0].status, JobStatus::Cancelled);
+        assert_eq!(manager.running(), 0);
+        assert!(manager.toasts().is_empty());
+    }
+}
@@ -0,0 +1,327 @@
+// Crash-safe journal of session state
+//
+// Some state only lives in memory but matters after a crash or an SSH
+// disconnect: which alarms were muted for planned work (and so still need
+// re-enabling), and which resources were marked for a bulk action. Each
+// change is appended to a JSON-lines journal and flushed immediately; on the
+// next start the journal is replayed. Once it grows past `COMPACT_AFTER`
+// entries, and on a clean exit, it is rewritten as a single snapshot.
+
+use crate::models::ResourceKey;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+const JOURNAL_FILE: &str = "session.journal";
+
+/// Entries appended before the journal is compacted into a snapshot
+const COMPACT_AFTER: usize = 200;
+
+/// Alarms muted for planned work, as stored in the journal
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MutedAlarms {
+    pub alarm_names: Vec<String>,
+    pub resources: Vec<String>,
+    pub started_at: u64, // Seconds since the Unix epoch
+}
+
+impl MutedAlarms {
+    /// How long ago the alarms were muted
+    pub fn elapsed(&self) -> Duration {
+        let started = SystemTime::UNIX_EPOCH + Duration::from_secs(self.started_at);
+        SystemTime::now()
+            .duration_since(started)
+            .unwrap_or_default()
+    }
+}
+
+/// Current time in seconds since the Unix epoch
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum JournalEntry {
+    Marked {
+        id: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        region: Option<String>, // Set when marked in a list spanning all regions
+    },
+    Unmarked {
+        id: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        region: Option<String>,
+    },
+    MarksCleared,
+    AlarmsMuted {
+        alarm_names: Vec<String>,
+        resources: Vec<String>,
+        at: u64,
+    },
+    AlarmsUnmuted {
+        alarm_names: Vec<String>,
+        resources: Vec<String>,
+    },
+    Snapshot {
+        #[serde(deserialize_with = "marks_from_snapshot")]
+        marked: BTreeSet<ResourceKey>,
+        muted: Option<MutedAlarms>,
+    },
+}
+
+/// A mark in a snapshot; journals written before marks carried a region hold bare IDs
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredMark {
+    Id(String),
+    Key(ResourceKey),
+}
+
+fn marks_from_snapshot<'de, D>(deserializer: D) -> Result<BTreeSet<ResourceKey>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let marks = Vec::<StoredMark>::deserialize(deserializer)?;
+    Ok(marks
+        .into_iter()
+        .map(|mark| match mark {
+            StoredMark::Id(id) => (None, id),
+            StoredMark::Key(key) => key,
+        })
+        .collect())
+}
+
+/// State rebuilt by replaying the journal
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JournalState {
+    pub marked: BTreeSet<ResourceKey>,
+    pub muted: Option<MutedAlarms>,
+}
+
+impl JournalState {
+    pub fn apply(&mut self, entry: JournalEntry) {
+        match entry {
+            JournalEntry::Marked { id, region } => {
+                self.marked.insert((region, id));
+            }
+            JournalEntry::Unmarked { id, region } => {
+                self.marked.remove(&(region, id));
+            }
+            JournalEntry::MarksCleared => self.marked.clear(),
+            JournalEntry::AlarmsMuted {
+                alarm_names,
+                resources,
+                at,
+            } => {
+                let muted = self.muted.get_or_insert_with(|| MutedAlarms {
+                    started_at: at,
+                    ..MutedAlarms::default()
+                });
+                muted.alarm_names.extend(alarm_names);
+                muted.resources.extend(resources);
+            }
+            JournalEntry::AlarmsUnmuted {
+                alarm_names,
+                resources,
+            } => {
+                if let Some(muted) = &mut self.muted {
+                    muted.alarm_names.retain(|name| !alarm_names.contains(name));
+                    muted.resources.retain(|id| !resources.contains(id));
+                    if muted.alarm_names.is_empty() {
+                        self.muted = None;
+                    }
+                }
+            }
+            JournalEntry::Snapshot { marked, muted } => {
+                self.marked = marked;
+                self.muted = muted;
+            }
+        }
+    }
+
+    fn snapshot(&self) -> JournalEntry {
+        JournalEntry::Snapshot {
+            marked: self.marked.clone(),
+            muted: self.muted.clone(),
+        }
+    }
+}
+
+/// Append-only journal; without a state directory every call is a no-op
+#[derive(Debug, Default)]
+pub struct Journal {
+    path: Option<PathBuf>,
+    state: JournalState,
+    entries: usize,
+}
+
+impl Journal {
+    /// Open the journal in the user's state directory and replay it
+    pub fn open() -> Self {
+        match journal_path() {
+            Some(path) => Self::open_at(path),
+            None => Self::default(),
+        }
+    }
+
+    fn open_at(path: PathBuf) -> Self {
+        let mut journal = Self {
+            path: Some(path),
+            ..Self::default()
+        };
+        let contents = journal
+            .path
+            .as_deref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .unwrap_or_default();
+        // A crash mid-write can leave a torn last line; it is skipped
+        for entry in contents
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+        {
+            journal.state.apply(entry);
+            journal.entries += 1;
+        }
+        journal
+    }
+
+    pub fn state(&self) -> &JournalState {
+        &self.state
+    }
+
+    /// Apply and persist one change, compacting when the journal gets long
+    ///
+    /// Write failures are returned but the in-memory state is updated regardless.
+    pub fn record(&mut self, entry: JournalEntry) -> Result<()> {
+        self.state.apply(entry.clone());
+        let Some(path) = self.path.clone() else {
+            return Ok(());
+        };
+        if self.entries >= COMPACT_AFTER {
+            return self.compact();
+        }
+        append(&path, &entry)?;
+        self.entries += 1;
+        Ok(())
+    }
+
+    /// Rewrite the journal as one snapshot entry
+    pub fn compact(&mut self) -> Result<()> {
+        let Some(path) = self.path.clone() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        // Write beside the journal and rename, so a crash leaves either the old or the new file
+        let temp = path.with_extension("journal.tmp");
+        let mut file =
+            File::create(&temp).with_context(|| format!("Failed to write {}", temp.display()))?;
+        writeln!(file, "{}", serde_json::to_string(&self.state.snapshot())?)?;
+        file.sync_all()?;
+        std::fs::rename(&temp, &path)
+            .with_context(|| format!("Failed to replace {}", path.display()))?;
+        self.entries = 1;
+        Ok(())
+    }
+}
+
+fn append(path: &Path, entry: &JournalEntry) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)?;
+    file.sync_data()?;
+    Ok(())
+}
+
+/// Location of the journal (e.g. `~/.local/state/awscw/session.journal`)
+fn journal_path() -> Option<PathBuf> {
+    dirs::state_dir()
+        .or_else(dirs::data_local_dir)
+        .map(|dir| dir.join("awscw").join(JOURNAL_FILE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_journal_replays_after_crash_and_compacts() {
+        let dir = std::env::temp_dir().join(format!("awscw-journal-{}", std::process::id()));
+        let path = dir.join(JOURNAL_FILE);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let mut journal = Journal::open_at(path.clone());
+        journal
+            .record(JournalEntry::Marked {
+                id: "orders-db".to_string(),
+                region: None,
+            })
+            .unwrap();
+        journal
+            .record(JournalEntry::Marked {
+                id: "orders-db".to_string(),
+                region: Some("eu-west-1".to_string()),
+            })
+            .unwrap();
+        journal
+            .record(JournalEntry::AlarmsMuted {
+                alarm_names: vec!["orders-db-cpu".to_string(), "orders-db-storage".to_string()],
+                resources: vec!["orders-db".to_string()],
+                at: 1_700_000_000,
+            })
+            .unwrap();
+        journal
+            .record(JournalEntry::AlarmsUnmuted {
+                alarm_names: vec!["orders-db-cpu".to_string()],
+                resources: Vec::new(),
+            })
+            .unwrap();
+
+        // Simulate a crash that tore the last write
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        write!(file, "{{\"event\":\"marked\",\"id\":\"pay").unwrap();
+        drop(file);
+
+        let replayed = Journal::open_at(path.clone());
+        assert_eq!(replayed.state(), journal.state());
+        let muted = replayed.state().muted.clone().unwrap();
+        assert_eq!(muted.alarm_names, vec!["orders-db-storage".to_string()]);
+        assert_eq!(muted.started_at, 1_700_000_000);
+
+        let mut compacted = replayed;
+        compacted.compact().unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.lines().count(), 1);
+        assert_eq!(Journal::open_at(path.clone()).state(), journal.state());
+
+        // Snapshots from before marks carried a region hold bare IDs
+        std::fs::write(
+            &path,
+            "{\"event\":\"snapshot\",\"marked\":[\"orders-db\"],\"muted\":null}\n",
+        )
+        .unwrap();
+        assert_eq!(
+            Journal::open_at(path).state().marked,
+            BTreeSet::from([(None, "orders-db".to_string())])
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,36 @@
+// The terminal UI's modules, shared by the binary and the benchmarks
+
+pub mod analytics;
+pub mod app;
+pub mod aws;
+pub mod bookmarks;
+pub mod cli;
+pub mod config;
+pub mod confirmation;
+pub mod control;
+pub mod event_handler;
+pub mod export;
+pub mod fleet;
+pub mod history;
+pub mod incident;
+pub mod internals;
+pub mod jobs;
+pub mod journal;
+pub mod listing;
+pub mod models;
+pub mod panel;
+pub mod perf;
+pub mod policy;
+pub mod prefetch;
+pub mod query;
+pub mod quiet_hours;
+pub mod reference_lines;
+pub mod report;
+pub mod resource_history;
+pub mod scripting;
+pub mod terminal;
+pub mod thresholds;
+pub mod ui;
+pub mod update_check;
+pub mod utils;
+pub mod watchlist;
@@ -0,0 +1,189 @@
+// Resource listings for scripts
+//
+// `awscw list --service sqs --output json` prints the resources the instance
+// list would show, with their attributes and tags, so they can be piped into
+// jq or fzf. The text format prints one identifier per line.
+
+use crate::aws::batch::BatchClientManager;
+use crate::aws::eventbridge::EventBridgeClientManager;
+use crate::aws::lambda::LambdaClient;
+use crate::aws::load_rds_instances;
+use crate::aws::sqs::client::SqsClientManager;
+use crate::aws::synthetics::SyntheticsClientManager;
+use crate::models::{AwsService, ServiceInstance};
+use crate::watchlist::Watchlist;
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Prefix that marks tags among the attribute rows
+const TAG_PREFIX: &str = "Tag: ";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListFormat {
+    Text,
+    Json,
+}
+
+impl ListFormat {
+    pub fn parse(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            other => Err(anyhow!(
+                "Unknown output format '{other}', expected text or json"
+            )),
+        }
+    }
+}
+
+pub fn parse_service(name: &str) -> Result<AwsService> {
+    match name.to_ascii_lowercase().as_str() {
+        "rds" => Ok(AwsService::Rds),
+        "sqs" => Ok(AwsService::Sqs),
+        "synthetics" | "canaries" => Ok(AwsService::Synthetics),
+        "eventbridge" | "events" => Ok(AwsService::EventBridge),
+        "batch" => Ok(AwsService::Batch),
+        "lambda" | "functions" => Ok(AwsService::Lambda),
+        other => Err(anyhow!(
+            "Unknown service '{other}', expected rds, sqs, synthetics, eventbridge, batch or lambda"
+        )),
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListedResource {
+    pub service: &'static str,
+    pub id: String,
+    pub watched: bool,
+    pub tags: BTreeMap<String, String>,
+    pub attributes: BTreeMap<String, String>,
+}
+
+impl ListedResource {
+    pub fn new(instance: &ServiceInstance, watchlist: &Watchlist) -> Self {
+        let (service, id, rows) = match instance {
+            ServiceInstance::Rds(instance) => ("rds", &instance.identifier, &instance.attributes),
+            ServiceInstance::Sqs(queue) => ("sqs", &queue.name, &queue.attributes),
+            ServiceInstance::Canary(canary) => ("synthetics", &canary.name, &canary.attributes),
+            ServiceInstance::EventRule(rule) => ("eventbridge", &rule.id, &rule.attributes),
+            ServiceInstance::BatchQueue(queue) => ("batch", &queue.name, &queue.attributes),
+            ServiceInstance::Function(function) => ("lambda", &function.name, &function.attributes),
+        };
+        let (tags, attributes) = rows
+            .iter()
+            .cloned()
+            .partition::<Vec<_>, _>(|(key, _)| key.starts_with(TAG_PREFIX));
+        Self {
+            service,
+            id: id.clone(),
+            watched: watchlist.contains(instance, None),
+            tags: tags
+                .into_iter()
+                .map(|(key, value)| (key[TAG_PREFIX.len()..].to_string(), value))
+                .collect(),
+            attributes: attributes.into_iter().collect(),
+        }
+    }
+}
+
+/// Load the resources of `service` and format them
+pub async fn list_resources(
+    service: AwsService,
+    format: ListFormat,
+    watchlist: &Watchlist,
+) -> Result<String> {
+    let instances = load_instances(service).await?;
+    let resources: Vec<ListedResource> = instances
+        .iter()
+        .map(|instance| ListedResource::new(instance, watchlist))
+        .collect();
+    render(format, &resources)
+}
+
+/// Every resource of `service` in the account and region of the session
+pub async fn load_instances(service: AwsService) -> Result<Vec<ServiceInstance>> {
+    Ok(match service {
+        AwsService::Rds => load_rds_instances()
+            .await?
+            .into_iter()
+            .map(ServiceInstance::Rds)
+            .collect(),
+        AwsService::Sqs => SqsClientManager::new()
+            .await
+            .load_queues()
+            .await?
+            .into_iter()
+            .map(ServiceInstance::Sqs)
+            .collect(),
+        AwsService::Synthetics => SyntheticsClientManager::new()
+            .await
+            .load_canaries()
+            .await?
+            .into_iter()
+            .map(ServiceInstance::Canary)
+            .collect(),
+        AwsService::EventBridge => EventBridgeClientManager::new()
+            .await
+            .load_rules()
+            .await?
+            .into_iter()
+            .map(ServiceInstance::EventRule)
+            .collect(),
+        AwsService::Batch => BatchClientManager::new()
+            .await
+            .load_job_queues()
+            .await?
+            .into_iter()
+            .map(ServiceInstance::BatchQueue)
+            .collect(),
+        AwsService::Lambda => LambdaClient::new()
+            .await
+            .list_functions()
+            .await?
+            .into_iter()
+            .map(ServiceInstance::Function)
+            .collect(),
+    })
+}
+
+pub fn render(format: ListFormat, resources: &[ListedResource]) -> Result<String> {
+    Ok(match format {
+        ListFormat::Json => serde_json::to_string_pretty(resources)? + "\n",
+        ListFormat::Text => resources
+            .iter()
+            .map(|resource| format!("{}\n", resource.id))
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SqsQueue;
+
+    #[test]
+    fn test_listing_splits_tags_from_attributes() {
+        let queue = ServiceInstance::Sqs(SqsQueue {
+            name: "orders".to_string(),
+            url: "https://sqs.eu-west-1.amazonaws.com/123456789012/orders".to_string(),
+            attributes: vec![
+                ("ApproximateNumberOfMessages".to_string(), "4".to_string()),
+                ("Tag: team".to_string(), "payments".to_string()),
+            ],
+            error: None,
+        });
+        let watchlist = Watchlist::parse(r#"sqs = ["orders"]"#).unwrap();
+        let resources = vec![ListedResource::new(&queue, &watchlist)];
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render(ListFormat::Json, &resources).unwrap()).unwrap();
+        assert_eq!(json[0]["service"], "sqs");
+        assert_eq!(json[0]["watched"], true);
+        assert_eq!(json[0]["tags"]["team"], "payments");
+        assert_eq!(json[0]["attributes"]["ApproximateNumberOfMessages"], "4");
+        assert!(json[0]["attributes"].get("Tag: team").is_none());
+
+        assert_eq!(render(ListFormat::Text, &resources).unwrap(), "orders\n");
+    }
+}
@@ -1,20 +1,24 @@
-mod app;
-mod aws;
-mod config;
-mod event_handler;
-mod models;
-mod terminal;
-mod ui;
+use awscw::{
+    analytics, aws, bookmarks, cli, config, control, event_handler, export, listing, models, panel,
+    policy, query, report, terminal, ui, utils, watchlist,
+};
 
 use anyhow::Result;
-use clap::Command;
 use crossterm::event;
 
 use aws::session::AwsSessionManager;
+use config::Config;
 use event_handler::handle_event;
 use models::{App, AppState};
+use policy::Policy;
+use std::time::{Duration, Instant};
 use terminal::TerminalManager;
 use ui::render_app;
+use ui::theme::Palette;
+
+/// How long to wait for input before running background refresh checks
+const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
 async fn validate_aws_credentials() -> Result<()> {
     // Use the new centralized session manager for credential validation
     let validation_result = AwsSessionManager::validate_credentials().await?;
@@ -45,50 +49,373 @@ async fn validate_aws_credentials() -> Result<()> {
     }
 }
 
-async fn run_app(mut terminal: TerminalManager, mut app: App) -> Result<()> {
+async fn run_app(
+    mut terminal: TerminalManager,
+    mut app: App,
+    mut control: Option<control::ControlChannel>,
+) -> Result<()> {
     // App starts with ServiceList state; instance loading happens via event handler
 
-    loop {
+    'frames: loop {
+        if let Some(protocol) = app.graphics {
+            let next = app
+                .chart_image()
+                .map(|(image, area)| (image.key.clone(), area));
+            terminal.release_image(
+                protocol,
+                next.as_ref().map(|(key, area)| (key.as_str(), *area)),
+            )?;
+        }
+        // Calls made since the last frame count against the page now shown
+        app.attribute_api_calls();
+        let drawing = Instant::now();
         terminal.draw(|f| render_app(f, &mut app))?;
+        app.perf.record_frame(drawing.elapsed());
+        app.internals.record_frame(drawing.elapsed());
+        app.timings.frame_drawn(drawing.elapsed());
+        if let Some(protocol) = app.graphics {
+            terminal.show_image(protocol, app.chart_image())?;
+            // Drawn after the frame so the image is sized to the area just rendered
+            app.load_chart_image().await;
+        }
 
         // Check for loading timeout
         if app.loading {
             app.check_loading_timeout();
         }
 
-        if let Ok(event) = event::read() {
-            let should_quit = handle_event(&mut app, event).await?;
-            if should_quit {
-                break;
+        // Poll so background refreshes (e.g. watching a queue) run without key presses
+        if event::poll(EVENT_POLL_INTERVAL)? {
+            if let Ok(event) = event::read() {
+                let (before, started) = (app.page_position(), Instant::now());
+                let should_quit = handle_event(&mut app, event).await?;
+                app.note_page_change(before, started);
+                if should_quit {
+                    break;
+                }
             }
         }
 
+        // Commands from scripts run between frames, like key presses
+        if let Some(channel) = control.as_mut() {
+            while let Ok(request) = channel.requests.try_recv() {
+                let (before, started) = (app.page_position(), Instant::now());
+                if control::execute(&mut app, request).await {
+                    break 'frames;
+                }
+                app.note_page_change(before, started);
+            }
+        }
+
+        // Show a newer release once the background check has answered
+        app.poll_update_check();
+
+        // Progress and outcomes of snapshots, redrives and exports running in the background
+        app.poll_jobs();
+
+        // Metrics of favorite instances warmed since startup
+        app.prefetch.poll();
+
+        // The app's own figures, charted on the internals page
+        app.sample_internals();
+
+        // Keep the shared watchlist in step with Parameter Store
+        if app.needs_watchlist_refresh() {
+            app.refresh_watchlist().await;
+        }
+
+        // Say what was held back once quiet hours end
+        app.poll_quiet_hours();
+
+        // Nudge to re-enable alarm actions muted for planned work
+        if app.needs_alarm_reminder() {
+            app.remind_alarm_maintenance();
+        }
+
+        // Alarms and error log of the instance under incident, on their own schedule
+        if app.needs_incident_refresh() {
+            app.refresh_incident().await;
+        }
+
+        // Poll queue counters while a test message is being watched
+        if app.view.state == AppState::MetricsSummary && app.needs_watch_refresh() {
+            app.refresh_selected_queue().await?;
+        }
+
+        // Replica lag is refreshed on its own; the instance list is not reloaded underneath it
+        if app.view.state == AppState::ReplicaTopology && app.needs_refresh() {
+            app.load_replica_topology().await;
+        }
+
+        // Follow a blue/green deployment, polling faster during switchover
+        if app.view.state == AppState::BlueGreen && app.needs_blue_green_refresh() {
+            app.refresh_blue_green().await;
+        }
+
+        // Secondary lag of a global database, reloaded with its members
+        if app.view.state == AppState::GlobalDatabase && app.needs_refresh() {
+            app.refresh_global_database().await;
+        }
+
+        // A followed log group is polled on its own, faster than auto-refresh
+        if app.needs_log_tail_poll() {
+            app.poll_log_tail().await;
+        }
+
         // Auto-refresh logic - only refresh if we're in a state that needs data
         if app.needs_refresh()
             && matches!(
-                app.state,
+                app.view.state,
                 AppState::InstanceList | AppState::MetricsSummary | AppState::InstanceDetails
             )
         {
-            if let Some(service) = &app.selected_service {
+            if let Some(service) = &app.view.selected_service {
                 match service {
-                    crate::models::AwsService::Rds => {
+                    models::AwsService::Rds => {
                         app.load_rds_instances().await?;
+                        // Metric pages also pick up new datapoints, skipping slow metrics
+                        if app.view.state != AppState::InstanceList {
+                            app.refresh_metrics().await?;
+                        }
+                    }
+                    models::AwsService::Sqs => {
+                        app.load_service_instances(&models::AwsService::Sqs).await?;
+                    }
+                    models::AwsService::Synthetics => {
+                        app.load_service_instances(&models::AwsService::Synthetics)
+                            .await?;
+                    }
+                    models::AwsService::EventBridge => {
+                        app.load_service_instances(&models::AwsService::EventBridge)
+                            .await?;
+                    }
+                    models::AwsService::Batch => {
+                        app.load_service_instances(&models::AwsService::Batch)
+                            .await?;
+                    }
+                    models::AwsService::Lambda => {
+                        app.load_service_instances(&models::AwsService::Lambda)
+                            .await?;
                     }
                 }
             }
         }
     }
 
+    // A clean exit leaves a compact journal for the next session
+    app.compact_journal();
+
+    Ok(())
+}
+
+/// Run a headless subcommand; errors go to stderr so stdout stays clean for pipes
+async fn run_command(name: &str, matches: &clap::ArgMatches, config: &Config) -> Result<()> {
+    let watchlist = || {
+        watchlist::Watchlist::load_cached()
+            .with_overrides(&config.watchlist.include, &config.watchlist.exclude)
+    };
+    let arg = |id: &str| matches.get_one::<String>(id).map(String::as_str);
+
+    match name {
+        "list" => {
+            let service = listing::parse_service(arg("service").unwrap_or_default())?;
+            let format = listing::ListFormat::parse(arg("output").unwrap_or("text"))?;
+            print!(
+                "{}",
+                listing::list_resources(service, format, &watchlist()).await?
+            );
+        }
+        "query" => {
+            let service = listing::parse_service(arg("service").unwrap_or("rds"))?;
+            let range = aws::time_range::TimeRange::parse(arg("range").unwrap_or("3h"))?;
+            let statistic = arg("stat").map(query::parse_statistic).transpose()?;
+            let datapoints = query::query_metric(
+                &service,
+                arg("resource").unwrap_or_default(),
+                arg("metric").unwrap_or_default(),
+                range,
+                statistic,
+            )
+            .await?;
+            print!(
+                "{}",
+                query::render_datapoints(&datapoints, arg("output") == Some("json"))?
+            );
+        }
+        "backtest" => {
+            let service = listing::parse_service(arg("service").unwrap_or("rds"))?;
+            let range = aws::time_range::TimeRange::parse(arg("range").unwrap_or("7d"))?;
+            let statistic = arg("stat").map(query::parse_statistic).transpose()?;
+            let (comparison, threshold) = match matches.get_one::<f64>("above") {
+                Some(above) => (aws::alarms::Comparison::Above, *above),
+                None => (
+                    aws::alarms::Comparison::Below,
+                    matches.get_one::<f64>("below").copied().unwrap_or_default(),
+                ),
+            };
+            let evaluation_periods = matches
+                .get_one::<usize>("evaluation-periods")
+                .copied()
+                .unwrap_or(3);
+            let rule = analytics::backtest::AlertRule {
+                comparison,
+                threshold,
+                period_secs: matches.get_one::<u64>("period").copied().unwrap_or(300),
+                evaluation_periods,
+                datapoints_to_alarm: matches
+                    .get_one::<usize>("datapoints")
+                    .copied()
+                    .unwrap_or(evaluation_periods),
+            };
+            let backtest = query::backtest_metric(
+                &service,
+                arg("resource").unwrap_or_default(),
+                arg("metric").unwrap_or_default(),
+                range,
+                statistic,
+                &rule,
+            )
+            .await?;
+            print!("{}", query::render_backtest(backtest.as_ref()));
+        }
+        "gaps" => {
+            let service = listing::parse_service(arg("service").unwrap_or("rds"))?;
+            let range = aws::time_range::TimeRange::parse(arg("range").unwrap_or("7d"))?;
+            let report = query::gap_report(
+                &service,
+                arg("resource").unwrap_or_default(),
+                arg("metric").unwrap_or(query::heartbeat_metric(&service)),
+                range,
+                matches.get_one::<u64>("period").copied().unwrap_or(300),
+            )
+            .await?;
+            print!(
+                "{}",
+                query::render_gap_report(&report, arg("output") == Some("json"))?
+            );
+        }
+        "watch" => {
+            let interval = matches.get_one::<u64>("interval").copied().unwrap_or(60);
+            query::watch_instance(
+                arg("instance").unwrap_or_default(),
+                Duration::from_secs(interval),
+            )
+            .await?;
+        }
+        "export" => {
+            let format = export::ExportFormat::parse(arg("format").unwrap_or("markdown"))?;
+            let report =
+                export::export_fleet(format, &config.thresholds, &config.reference_lines).await?;
+            match arg("output") {
+                Some(path) => std::fs::write(path, report)?,
+                None => print!("{report}"),
+            }
+        }
+        "report" => {
+            let output = std::path::Path::new(arg("output").unwrap_or_default());
+            let watchlist = matches.get_flag("watchlist").then(watchlist);
+            let count = report::write_report(output, watchlist.as_ref(), config).await?;
+            println!("Wrote {} ({count} instances)", output.display());
+        }
+        "bookmarks" => match arg("import") {
+            Some(json) => {
+                let (path, count) = bookmarks::import(&config.bookmarks, json)?;
+                println!("Added {count} bookmarks to {}", path.display());
+            }
+            None => println!("{}", serde_json::to_string_pretty(&config.bookmarks)?),
+        },
+        other => unreachable!("unhandled subcommand {other}"),
+    }
     Ok(())
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let _matches = Command::new("awscw")
-        .version("0.1.0")
-        .about("AWS CloudWatch TUI")
-        .get_matches();
+    let matches = cli::command().get_matches();
+
+    if let Some(("completions", completion)) = matches.subcommand() {
+        if let Some(shell) = completion.get_one::<clap_complete::Shell>("shell") {
+            cli::print_completions(*shell);
+        }
+        return Ok(());
+    }
+
+    let mut config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Cannot start AWS CloudWatch TUI: {e:#}");
+            std::process::exit(1);
+        }
+    };
+    // A policy that does not parse must not leave its features enabled
+    config.policy = match Policy::load() {
+        Ok(policy) => policy,
+        Err(e) => {
+            eprintln!("Cannot start AWS CloudWatch TUI: {e:#}");
+            std::process::exit(1);
+        }
+    };
+
+    if matches.get_flag("screen-reader") {
+        config.accessibility.screen_reader = true;
+    }
+    if matches.get_flag("read-only") {
+        config.actions.read_only = true;
+    }
+    if let Some(palette) = matches
+        .get_one::<String>("theme")
+        .and_then(|name| Palette::from_name(name))
+    {
+        config.theme.palette = palette;
+    }
+    utils::formatting::set_locale(config.locale);
+    aws::limiter::configure_cloudwatch(config.cloudwatch.max_concurrent_calls);
+    aws::metrics::retention::configure(config.history.max_points);
+    if matches.get_flag("ascii") {
+        config.theme.ascii = Some(true);
+    } else if config.theme.ascii.is_none() {
+        config.theme.ascii = Some(ui::ascii::unicode_unsupported());
+    }
+
+    AwsSessionManager::set_network(config.network.clone());
+    if let Some(&seed) = matches.get_one::<u64>("chaos") {
+        AwsSessionManager::set_chaos(seed);
+    }
+    if let Some(profile) = matches.get_one::<String>("profile") {
+        AwsSessionManager::set_profile(profile.clone());
+    }
+    let region = matches.get_one::<String>("region");
+    let all_regions = region.is_some_and(|region| region == aws::regions::ALL_REGIONS);
+    if let Some(region) = region.filter(|_| !all_regions) {
+        AwsSessionManager::set_region(region.clone());
+    }
+    if all_regions && matches.subcommand().is_some_and(|(name, _)| name != "tui") {
+        eprintln!("awscw: --region all is only supported by the terminal UI");
+        std::process::exit(1);
+    }
+
+    match matches.subcommand() {
+        None | Some(("tui", _)) => {}
+        Some((name, command_matches)) => {
+            if let Err(e) = run_command(name, command_matches, &config).await {
+                eprintln!("awscw {name}: {e:#}");
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+    }
+
+    let panel = match matches
+        .get_one::<String>("panel")
+        .map(|spec| panel::PanelWidget::parse(spec))
+    {
+        Some(Ok(widget)) => Some(widget),
+        Some(Err(e)) => {
+            eprintln!("awscw --panel: {e:#}");
+            std::process::exit(1);
+        }
+        None => None,
+    };
 
     // Validate AWS credentials before starting the terminal UI
     if let Err(e) = validate_aws_credentials().await {
@@ -96,6 +423,22 @@ async fn main() -> Result<()> {
         std::process::exit(1);
     }
 
+    // One widget full-screen, for composing layouts in tmux or zellij panes
+    if let Some(widget) = panel {
+        return panel::run_panel(TerminalManager::new()?, widget, &config).await;
+    }
+
+    let control = match matches.get_one::<String>("control") {
+        Some(target) => match control::listen(target) {
+            Ok(channel) => Some(channel),
+            Err(e) => {
+                eprintln!("awscw --control: {e:#}");
+                std::process::exit(1);
+            }
+        },
+        None => None,
+    };
+
     println!("Starting AWS CloudWatch TUI...");
     println!("Press 'q' to quit, 'r' to refresh data");
     println!();
@@ -104,12 +447,16 @@ async fn main() -> Result<()> {
     let terminal = TerminalManager::new()?;
 
     // Create app and run - starts with service selection
-    let app = App::new();
-    let res = run_app(terminal, app).await;
+    let mut app = App::new(config);
+    app.all_regions = all_regions;
+    app.start_update_check();
+    app.start_prefetch();
+    let res = run_app(terminal, app, control).await;
 
     if let Err(err) = res {
         println!("{err:?}");
     }
+    print!("{}", aws::usage::session_report());
 
     Ok(())
 }
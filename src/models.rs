@@ -1,6 +1,54 @@
-use crate::aws::cloudwatch_service::TimeRange;
-use ratatui::widgets::ListState;
+use crate::analytics::baseline::BaselineStore;
+use crate::analytics::compare::CompareOffset;
+use crate::aws::alarms::composite::RuleLine;
+use crate::aws::alarms::{AlarmForm, AlarmMaintenance, CompositeAlarm, MetricAlarm, NewAlarm};
+use crate::aws::cloudformation::StackMembership;
+use crate::aws::cloudwatch_service::{TimeRange, TimeUnit};
+use crate::aws::lambda::LambdaConsumer;
+use crate::aws::logs::slow_query::{SlowQuery, SlowQuerySort};
+use crate::aws::logs::tail::TailBuffer;
+use crate::aws::metric_fetcher::FailedMetrics;
+use crate::aws::metrics::percentiles::PercentileSeries;
+use crate::aws::metrics::registry::RefreshSchedule;
+use crate::aws::metrics::types::{MetricResolution, ServiceMetrics};
+use crate::aws::profiles::ProfilesPanel;
+use crate::aws::quotas::QuotaCache;
+use crate::aws::rds::blue_green::BlueGreenDeployment;
+use crate::aws::rds::certificates::CertificateStatus;
+use crate::aws::rds::compare::MetricComparison;
+use crate::aws::rds::global::{GlobalDatabase, SecondaryLag};
+use crate::aws::rds::parameters::ParameterDrift;
+use crate::aws::rds::replicas::{ReplicaLag, ReplicaTopology};
+use crate::aws::regions::RegionsPanel;
+use crate::aws::resource_groups::GroupMembers;
+use crate::aws::secrets::DbSecret;
+use crate::bookmarks::BookmarksPanel;
+use crate::config::Config;
+use crate::confirmation::Confirmation;
+use crate::fleet::Inventory;
+use crate::history::{JumpList, ViewHistory};
+use crate::incident::Incident;
+use crate::internals::Internals;
+use crate::jobs::{JobManager, JobsPanel};
+use crate::journal::Journal;
+use crate::perf::{PageTimings, PerfStats};
+use crate::prefetch::Prefetch;
+use crate::quiet_hours::Held;
+use crate::reference_lines::PinPrompt;
+use crate::resource_history::ResourceHistory;
+use crate::scripting::{ScriptHost, ScriptOutput};
+use crate::thresholds::ThresholdEditor;
+use crate::ui::accessibility::Announcer;
+use crate::ui::graphics::{GraphicsProtocol, RasterImage};
+use crate::ui::theme::Severity;
+use crate::update_check::Release;
+use crate::utils::export::ExportPrompt;
+use crate::watchlist::Watchlist;
+use ratatui::layout::Rect;
+use ratatui::widgets::{ListState, TableState};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::time::{Instant, SystemTime};
+use tokio::task::JoinHandle;
 
 #[derive(Debug, Clone)]
 pub struct RdsInstance {
@@ -9,6 +57,34 @@ pub struct RdsInstance {
     pub status: String,
     pub instance_class: String,
     pub endpoint: Option<String>,
+    pub port: Option<u16>,
+    pub allocated_storage_gib: Option<i32>,
+    pub max_allocated_storage_gib: Option<i32>, // Storage autoscaling ceiling; None when disabled
+    pub pending_modifications: Vec<(String, String)>,
+    pub topology: ReplicaTopology,
+    pub attributes: Vec<(String, String)>, // Full attribute list for the details pane
+    pub certificate: Option<CertificateStatus>,
+}
+
+const BYTES_PER_GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+impl RdsInstance {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Free space the instance would have once storage autoscaling reaches its ceiling
+    ///
+    /// This is the current free space plus the storage autoscaling may still add.
+    pub fn autoscaling_free_storage_ceiling(&self, free_storage_bytes: f64) -> Option<f64> {
+        let allocated = self.allocated_storage_gib?;
+        let max = self.max_allocated_storage_gib?;
+        let growth = f64::from(max.saturating_sub(allocated).max(0)) * BYTES_PER_GIB;
+        Some(free_storage_bytes + growth)
+    }
 }
 
 impl AwsInstance for RdsInstance {
@@ -28,6 +104,324 @@ impl AwsInstance for RdsInstance {
         AwsService::Rds
     }
 }
+#[derive(Debug, Clone)]
+pub struct SqsQueue {
+    pub name: String,
+    pub url: String,
+    pub attributes: Vec<(String, String)>, // GetQueueAttributes results, sorted by name
+    pub error: Option<String>,             // Why the attributes could not be loaded
+}
+
+impl SqsQueue {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn is_fifo(&self) -> bool {
+        self.attribute("FifoQueue") == Some("true")
+    }
+
+    pub fn queue_type(&self) -> &'static str {
+        if self.is_fifo() {
+            "FIFO"
+        } else {
+            "Standard"
+        }
+    }
+
+    /// Name of the queue failed messages are moved to, from the RedrivePolicy
+    pub fn dead_letter_queue(&self) -> Option<String> {
+        let policy: serde_json::Value =
+            serde_json::from_str(self.attribute("RedrivePolicy")?).ok()?;
+        let arn = policy.get("deadLetterTargetArn")?.as_str()?;
+        arn.rsplit(':').next().map(str::to_string)
+    }
+
+    /// Whether this queue is some other queue's dead-letter queue
+    ///
+    /// SQS only records this on the source queues, so it is read from a
+    /// RedriveAllowPolicy, else guessed from the name.
+    pub fn is_dead_letter_queue(&self) -> bool {
+        let name = self.name.to_lowercase();
+        let name = name.trim_end_matches(".fifo");
+        self.attribute("RedriveAllowPolicy").is_some()
+            || name.ends_with("dlq")
+            || name.ends_with("dead-letter")
+            || name.ends_with("deadletter")
+    }
+}
+
+impl AwsInstance for SqsQueue {
+    fn id(&self) -> &str {
+        &self.name
+    }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn status(&self) -> &str {
+        "active"
+    }
+
+    fn service_type(&self) -> AwsService {
+        AwsService::Sqs
+    }
+}
+
+/// A CloudWatch Synthetics canary with its most recent run
+#[derive(Debug, Clone)]
+pub struct Canary {
+    pub name: String,
+    pub state: String,
+    pub schedule: String,
+    pub runtime: String,
+    pub artifact_location: String,
+    pub last_run: Option<CanaryRun>,
+    pub attributes: Vec<(String, String)>, // DescribeCanaries fields and tags, sorted by name
+}
+
+#[derive(Debug, Clone)]
+pub struct CanaryRun {
+    pub state: String,
+    pub failure_reason: Option<String>,
+    pub completed: Option<SystemTime>,
+    pub artifact_location: String,
+}
+
+impl CanaryRun {
+    pub fn failed(&self) -> bool {
+        self.state == "FAILED"
+    }
+}
+
+impl AwsInstance for Canary {
+    fn id(&self) -> &str {
+        &self.name
+    }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn status(&self) -> &str {
+        &self.state
+    }
+
+    fn service_type(&self) -> AwsService {
+        AwsService::Synthetics
+    }
+}
+
+/// An EventBridge rule on the default or a custom event bus
+#[derive(Debug, Clone)]
+pub struct EventRule {
+    pub id: String, // `name` on the default bus, `bus/name` on custom buses
+    pub name: String,
+    pub event_bus: String,
+    pub state: String,
+    pub trigger: String, // schedule expression or event pattern
+    pub targets: Vec<RuleTarget>,
+    pub attributes: Vec<(String, String)>, // ListRules fields and targets, sorted by name
+}
+
+#[derive(Debug, Clone)]
+pub struct RuleTarget {
+    pub id: String,
+    pub arn: String,
+    pub dead_letter_arn: Option<String>,
+}
+
+impl EventRule {
+    pub const DEFAULT_BUS: &'static str = "default";
+
+    pub fn is_enabled(&self) -> bool {
+        self.state == "ENABLED"
+    }
+
+    /// Replace the rule's targets, listing each with its dead-letter queue in the details pane
+    pub fn with_targets(mut self, targets: Vec<RuleTarget>) -> Self {
+        self.attributes
+            .retain(|(key, _)| !key.starts_with("Target"));
+        for target in &targets {
+            self.attributes
+                .push((format!("Target: {}", target.id), target.arn.clone()));
+            if let Some(dlq) = &target.dead_letter_arn {
+                self.attributes
+                    .push((format!("Target DLQ: {}", target.id), dlq.clone()));
+            }
+        }
+        self.attributes.sort();
+        self.targets = targets;
+        self
+    }
+
+    /// Targets whose failed deliveries are dropped instead of kept in a queue
+    pub fn targets_without_dlq(&self) -> impl Iterator<Item = &RuleTarget> {
+        self.targets
+            .iter()
+            .filter(|target| target.dead_letter_arn.is_none())
+    }
+}
+
+impl AwsInstance for EventRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn status(&self) -> &str {
+        &self.state
+    }
+
+    fn service_type(&self) -> AwsService {
+        AwsService::EventBridge
+    }
+}
+
+/// An AWS Batch job queue with the compute environments it schedules onto
+#[derive(Debug, Clone)]
+pub struct BatchJobQueue {
+    pub name: String,
+    pub state: String,  // ENABLED or DISABLED
+    pub status: String, // VALID, INVALID, UPDATING, ...
+    pub priority: i32,
+    pub compute_environments: Vec<ComputeEnvironment>, // In the queue's scheduling order
+    pub job_counts: Vec<(String, usize)>, // ListJobs count per job status, in lifecycle order
+    pub attributes: Vec<(String, String)>, // DescribeJobQueues fields and tags, sorted by name
+}
+
+#[derive(Debug, Clone)]
+pub struct ComputeEnvironment {
+    pub name: String,
+    pub state: String,
+    pub status: String,
+    pub kind: String, // MANAGED or UNMANAGED, with the EC2/SPOT/FARGATE resource type
+    pub min_vcpus: Option<i32>,
+    pub desired_vcpus: Option<i32>,
+    pub max_vcpus: Option<i32>,
+    pub ecs_cluster: Option<String>, // Name of the ECS cluster the jobs run on
+}
+
+impl BatchJobQueue {
+    pub fn job_count(&self, status: &str) -> usize {
+        self.job_counts
+            .iter()
+            .find(|(name, _)| name == status)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// Jobs waiting to be placed on a compute environment
+    pub fn queued(&self) -> usize {
+        ["SUBMITTED", "PENDING", "RUNNABLE"]
+            .iter()
+            .map(|status| self.job_count(status))
+            .sum()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.state == "ENABLED"
+    }
+}
+
+impl AwsInstance for BatchJobQueue {
+    fn id(&self) -> &str {
+        &self.name
+    }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn status(&self) -> &str {
+        &self.status
+    }
+
+    fn service_type(&self) -> AwsService {
+        AwsService::Batch
+    }
+}
+
+/// A Lambda function, as ListFunctions or GetFunctionConfiguration describe it
+#[derive(Debug, Clone)]
+pub struct LambdaFunction {
+    pub name: String,
+    pub runtime: String, // e.g. python3.12, or "image" for container images
+    pub memory_mb: Option<i64>,
+    pub timeout_secs: Option<i64>,
+    pub state: Option<String>, // Active, Pending, Inactive or Failed; not in ListFunctions
+    pub last_modified: String,
+    pub attributes: Vec<(String, String)>, // Configuration fields, sorted by name
+}
+
+impl LambdaFunction {
+    /// Whether the function cannot run invocations; unknown until its configuration is read
+    pub fn is_failed(&self) -> bool {
+        matches!(self.state.as_deref(), Some("Failed" | "Inactive"))
+    }
+}
+
+impl AwsInstance for LambdaFunction {
+    fn id(&self) -> &str {
+        &self.name
+    }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn status(&self) -> &str {
+        self.state.as_deref().unwrap_or(&self.runtime)
+    }
+
+    fn service_type(&self) -> AwsService {
+        AwsService::Lambda
+    }
+}
+
+/// A write API call CloudTrail recorded against a resource
+#[derive(Debug, Clone)]
+pub struct TrailEvent {
+    pub name: String, // e.g. ModifyDBInstance, PurgeQueue
+    pub time: Option<SystemTime>,
+    pub user: String,
+    pub error: Option<String>, // Error code of a call that failed and changed nothing
+}
+
+/// Compute Optimizer's right-sizing recommendation for an RDS instance
+#[derive(Debug, Clone)]
+pub struct RightsizingRecommendation {
+    pub current_class: String,
+    pub finding: String,      // Optimized, Overprovisioned or Underprovisioned
+    pub reasons: Vec<String>, // e.g. CPUOverprovisioned
+    pub lookback_days: f64,
+    pub utilization: Vec<UtilizationMetric>, // Of the current class over the lookback period
+    pub options: Vec<RightsizingOption>,     // Best ranked first
+}
+
+/// An instance class Compute Optimizer suggests instead of the current one
+#[derive(Debug, Clone)]
+pub struct RightsizingOption {
+    pub instance_class: String,
+    pub rank: i32,
+    pub performance_risk: f64, // 0 (none) to 4 (very high)
+    pub projected: Vec<UtilizationMetric>,
+    pub monthly_savings: Option<(f64, String)>, // Amount and currency
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UtilizationMetric {
+    pub name: String,      // e.g. CPU, Memory, DatabaseConnections
+    pub statistic: String, // Maximum or Average
+    pub value: f64,
+}
+
 #[derive(Debug)]
 pub struct MetricData {
     // Core Performance Metrics
@@ -63,8 +457,10 @@ pub struct MetricData {
     pub checkpoint_lag: f64,     // Seconds - checkpoint lag
     pub connection_attempts: f64, // Count - MySQL connection attempts
 
-    // Historical data for 3 hours (36 data points at 5min intervals)
+    // History over the selected range, capped at `[history] max_points` per series
     pub timestamps: Vec<SystemTime>,
+    // Each series' own timestamps, keyed by CloudWatch metric name
+    pub series_timestamps: HashMap<String, Vec<SystemTime>>,
     pub cpu_history: Vec<f64>,
     pub connections_history: Vec<f64>,
     pub read_iops_history: Vec<f64>,
@@ -100,6 +496,7 @@ impl Default for MetricData {
     fn default() -> Self {
         Self {
             timestamps: Vec::new(),
+            series_timestamps: HashMap::new(),
             cpu_utilization: 0.0,
             database_connections: 0.0,
             free_storage_space: 0.0,
@@ -378,21 +775,33 @@ impl MetricData {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AwsService {
     Rds,
-    // Future services can be added here when needed
-    // Sqs,
+    Sqs,
+    Synthetics,
+    EventBridge,
+    Batch,
+    Lambda,
 }
 
 impl AwsService {
     pub fn display_name(&self) -> &'static str {
         match self {
             AwsService::Rds => "RDS (Relational Database Service)",
+            AwsService::Sqs => "SQS (Simple Queue Service)",
+            AwsService::Synthetics => "Synthetics (CloudWatch Canaries)",
+            AwsService::EventBridge => "EventBridge (Rules and Event Buses)",
+            AwsService::Batch => "Batch (Job Queues and Compute Environments)",
+            AwsService::Lambda => "Lambda (Functions)",
         }
     }
 
-    #[allow(dead_code)]
     pub fn short_name(&self) -> &'static str {
         match self {
             AwsService::Rds => "RDS",
+            AwsService::Sqs => "SQS",
+            AwsService::Synthetics => "Synthetics",
+            AwsService::EventBridge => "EventBridge",
+            AwsService::Batch => "Batch",
+            AwsService::Lambda => "Lambda",
         }
     }
 }
@@ -402,15 +811,104 @@ impl AwsService {
 #[derive(Debug, Clone)]
 pub enum ServiceInstance {
     Rds(RdsInstance),
+    Sqs(SqsQueue),
+    Canary(Canary),
+    EventRule(EventRule),
+    BatchQueue(BatchJobQueue),
+    Function(LambdaFunction),
     // Future services will be added here when needed
-    // Sqs(SqsQueue),
     // Ec2(Ec2Instance),
 }
 
 impl ServiceInstance {
+    /// Stand-in for a resource that no longer exists, so its metrics can still be opened
+    ///
+    /// Only the ID is known; everything else is left empty.
+    pub fn departed(service: &AwsService, id: &str) -> Self {
+        const GONE: &str = "deleted";
+        match service {
+            AwsService::Rds => ServiceInstance::Rds(RdsInstance {
+                identifier: id.to_string(),
+                engine: String::new(),
+                status: GONE.to_string(),
+                instance_class: String::new(),
+                endpoint: None,
+                port: None,
+                allocated_storage_gib: None,
+                max_allocated_storage_gib: None,
+                pending_modifications: Vec::new(),
+                topology: ReplicaTopology::default(),
+                attributes: Vec::new(),
+                certificate: None,
+            }),
+            AwsService::Sqs => ServiceInstance::Sqs(SqsQueue {
+                name: id.to_string(),
+                url: String::new(),
+                attributes: Vec::new(),
+                error: None,
+            }),
+            AwsService::Synthetics => ServiceInstance::Canary(Canary {
+                name: id.to_string(),
+                state: GONE.to_string(),
+                schedule: String::new(),
+                runtime: String::new(),
+                artifact_location: String::new(),
+                last_run: None,
+                attributes: Vec::new(),
+            }),
+            AwsService::EventBridge => {
+                let (event_bus, name) = id.rsplit_once('/').unwrap_or((EventRule::DEFAULT_BUS, id));
+                ServiceInstance::EventRule(EventRule {
+                    id: id.to_string(),
+                    name: name.to_string(),
+                    event_bus: event_bus.to_string(),
+                    state: GONE.to_string(),
+                    trigger: String::new(),
+                    targets: Vec::new(),
+                    attributes: Vec::new(),
+                })
+            }
+            AwsService::Batch => ServiceInstance::BatchQueue(BatchJobQueue {
+                name: id.to_string(),
+                state: GONE.to_string(),
+                status: GONE.to_string(),
+                priority: 0,
+                compute_environments: Vec::new(),
+                job_counts: Vec::new(),
+                attributes: Vec::new(),
+            }),
+            AwsService::Lambda => ServiceInstance::Function(LambdaFunction {
+                name: id.to_string(),
+                runtime: String::new(),
+                memory_mb: None,
+                timeout_secs: None,
+                state: Some(GONE.to_string()),
+                last_modified: String::new(),
+                attributes: Vec::new(),
+            }),
+        }
+    }
+
+    /// Attribute rows, tags included as `Tag: <key>`
+    pub fn attributes(&self) -> &[(String, String)] {
+        match self {
+            ServiceInstance::Rds(instance) => &instance.attributes,
+            ServiceInstance::Sqs(queue) => &queue.attributes,
+            ServiceInstance::Canary(canary) => &canary.attributes,
+            ServiceInstance::EventRule(rule) => &rule.attributes,
+            ServiceInstance::BatchQueue(queue) => &queue.attributes,
+            ServiceInstance::Function(function) => &function.attributes,
+        }
+    }
+
     pub fn as_aws_instance(&self) -> &dyn AwsInstance {
         match self {
             ServiceInstance::Rds(instance) => instance,
+            ServiceInstance::Sqs(queue) => queue,
+            ServiceInstance::Canary(canary) => canary,
+            ServiceInstance::EventRule(rule) => rule,
+            ServiceInstance::BatchQueue(queue) => queue,
+            ServiceInstance::Function(function) => function,
         }
     }
 }
@@ -424,12 +922,25 @@ pub trait AwsInstance {
     fn service_type(&self) -> AwsService;
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AppState {
     ServiceList,     // NEW: Show list of available AWS services
     InstanceList,    // RENAMED: Show instances for selected service (was RdsList)
     MetricsSummary,  // Show metrics summary for selected instance
     InstanceDetails, // Show detailed metrics for selected instance
+    ReplicaTopology, // Show read replicas of a primary with their lag
+    BlueGreen,       // Show a blue/green deployment and its switchover progress
+    GlobalDatabase,  // Show an Aurora global database's secondary regions and their lag
+    SlowQueries,     // Show recent slow query log entries of an RDS instance
+    Parameters,      // Show the parameters an RDS instance's parameter group overrides
+    LogTail,         // Pick a log group of an RDS instance or Lambda function and follow it
+    Fleet,           // Show one service's resources across the configured accounts
+    Timings,         // Show how long recent page loads took, per page
+    ApiCalls,        // Show the API calls made this session and their estimated cost
+    Internals,       // Chart the app's own frame time, fetch latency, cache and memory
+    ResourceGroups,  // List resource groups or applications, or one's members across services
+    CompositeAlarms, // List composite alarms, or one's rule as a tree of its children's states
+    Alarms,          // Browse the metric alarms by state, optionally within one namespace
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -470,6 +981,37 @@ pub enum MetricType {
 }
 
 impl MetricType {
+    /// Every RDS metric the app fetches
+    pub const ALL: [MetricType; 27] = [
+        MetricType::CpuUtilization,
+        MetricType::DatabaseConnections,
+        MetricType::FreeStorageSpace,
+        MetricType::ReadIops,
+        MetricType::WriteIops,
+        MetricType::ReadLatency,
+        MetricType::WriteLatency,
+        MetricType::ReadThroughput,
+        MetricType::WriteThroughput,
+        MetricType::NetworkReceiveThroughput,
+        MetricType::NetworkTransmitThroughput,
+        MetricType::SwapUsage,
+        MetricType::FreeableMemory,
+        MetricType::QueueDepth,
+        MetricType::BurstBalance,
+        MetricType::CpuCreditUsage,
+        MetricType::CpuCreditBalance,
+        MetricType::BinLogDiskUsage,
+        MetricType::ReplicaLag,
+        MetricType::MaximumUsedTransactionIds,
+        MetricType::OldestReplicationSlotLag,
+        MetricType::ReplicationSlotDiskUsage,
+        MetricType::TransactionLogsDiskUsage,
+        MetricType::TransactionLogsGeneration,
+        MetricType::FailedSqlServerAgentJobsCount,
+        MetricType::CheckpointLag,
+        MetricType::ConnectionAttempts,
+    ];
+
     pub fn display_name(&self) -> &'static str {
         match self {
             MetricType::CpuUtilization => "CPU Utilization",
@@ -501,32 +1043,259 @@ impl MetricType {
             MetricType::ConnectionAttempts => "Connection Attempts",
         }
     }
+
+    /// Spiky enough that a heat strip reads better than a line
+    pub fn is_latency(&self) -> bool {
+        matches!(self, MetricType::ReadLatency | MetricType::WriteLatency)
+    }
+
+    /// Only climbs until a reset, so its per-interval change says more than its value
+    pub fn is_counter(&self) -> bool {
+        matches!(
+            self,
+            MetricType::MaximumUsedTransactionIds
+                | MetricType::BinLogDiskUsage
+                | MetricType::TransactionLogsDiskUsage
+                | MetricType::ReplicationSlotDiskUsage
+        )
+    }
+
+    /// CloudWatch metric name as published in the AWS/RDS namespace
+    pub fn metric_name(&self) -> &'static str {
+        match self {
+            MetricType::CpuUtilization => "CPUUtilization",
+            MetricType::DatabaseConnections => "DatabaseConnections",
+            MetricType::FreeStorageSpace => "FreeStorageSpace",
+            MetricType::ReadIops => "ReadIOPS",
+            MetricType::WriteIops => "WriteIOPS",
+            MetricType::ReadLatency => "ReadLatency",
+            MetricType::WriteLatency => "WriteLatency",
+            MetricType::ReadThroughput => "ReadThroughput",
+            MetricType::WriteThroughput => "WriteThroughput",
+            MetricType::NetworkReceiveThroughput => "NetworkReceiveThroughput",
+            MetricType::NetworkTransmitThroughput => "NetworkTransmitThroughput",
+            MetricType::SwapUsage => "SwapUsage",
+            MetricType::FreeableMemory => "FreeableMemory",
+            MetricType::QueueDepth => "DiskQueueDepth",
+            MetricType::BurstBalance => "BurstBalance",
+            MetricType::CpuCreditUsage => "CPUCreditUsage",
+            MetricType::CpuCreditBalance => "CPUCreditBalance",
+            MetricType::BinLogDiskUsage => "BinLogDiskUsage",
+            MetricType::ReplicaLag => "ReplicaLag",
+            MetricType::MaximumUsedTransactionIds => "MaximumUsedTransactionIDs",
+            MetricType::OldestReplicationSlotLag => "OldestReplicationSlotLag",
+            MetricType::ReplicationSlotDiskUsage => "ReplicationSlotDiskUsage",
+            MetricType::TransactionLogsDiskUsage => "TransactionLogsDiskUsage",
+            MetricType::TransactionLogsGeneration => "TransactionLogsGeneration",
+            MetricType::FailedSqlServerAgentJobsCount => "FailedSQLServerAgentJobsCount",
+            MetricType::CheckpointLag => "CheckpointLag",
+            MetricType::ConnectionAttempts => "ConnectionAttempts",
+        }
+    }
 }
 
+/// Session-wide core: configuration, caches and background work shared by
+/// every tab. What a page shows and where it is scrolled lives in `ViewState`.
 pub struct App {
+    // Navigation state of the active tab; the others wait in `tabs`
+    pub view: ViewState,
+    pub tabs: Vec<ViewState>, // One per tab, the active one's slot left empty
+    pub active_tab: usize,
+    pub pending_g: bool, // 'g' pressed, waiting for the rest of gt/gT/gn/gc/gb
+
     // Service selection state (focused on RDS for now)
     pub available_services: Vec<AwsService>,
+
+    pub loading: bool,
+    pub metrics_loading: bool,
+    pub auto_refresh_enabled: bool,
+    pub metrics_per_screen: usize,
+    pub metric_grid_columns: usize, // Metric list columns for the current terminal width
+
+    // Error handling
+    pub error_message: Option<String>, // Store user-friendly error messages
+
+    // Loading timeout management
+    pub loading_start_time: Option<Instant>, // Track when loading started
+
+    pub status_message: Option<String>, // Transient feedback shown in the controls line
+
+    // User scripting hooks
+    pub script_host: ScriptHost,
+    pub script_output: ScriptOutput, // Results of the last script run over the loaded metrics
+
+    // User configuration
+    pub config: Config,
+
+    // Write actions
+    pub pending_action: Option<Confirmation>, // Write action awaiting its typed phrase
+    pub sent_test_message: Option<SentTestMessage>, // Last test message, watched for consumer activity
+
+    // Shared watchlist (Parameter Store + local overrides)
+    pub watchlist: Watchlist,
+    pub watchlist_only: bool, // Only list watched resources
+    pub watchlist_refreshed_at: Option<Instant>,
+    pub watchlist_error: Option<String>, // Last fetch failure; the cached list stays in use
+
+    // Service quotas shown next to the metrics they limit
+    pub quotas: QuotaCache,
+
+    // CloudWatch alarms and bulk maintenance
+    pub alarms: Vec<MetricAlarm>,
+    pub marked_instances: BTreeSet<ResourceKey>, // Resources selected for bulk alarm actions
+    pub alarm_maintenance: Option<AlarmMaintenance>, // Alarms muted for planned work
+    pub alarm_form: Option<AlarmForm>,           // Open "create alarm" form on the chart view
+
+    // Incident mode on one RDS instance ('I')
+    pub incident: Option<Incident>,
+
+    // Metric health thresholds being edited
+    pub threshold_editor: Option<ThresholdEditor>,
+    pub pin_prompt: Option<PinPrompt>, // Reference line being typed on the chart view
+    pub export_prompt: Option<ExportPrompt>, // Path the open resource's metrics are exported to
+
+    // Saved chart views (`[[bookmarks]]` in the config)
+    pub bookmarks_panel: Option<BookmarksPanel>,
+
+    // Profiles of the shared AWS config files, to switch the session to
+    pub profiles_panel: Option<ProfilesPanel>,
+
+    // Notifications logged instead of shown during quiet hours
+    pub held_notifications: Held,
+
+    // Long-running operations (snapshots, redrives, exports) and their toasts
+    pub jobs: JobManager,
+    pub jobs_panel: Option<JobsPanel>,
+
+    // Favorite instances' metrics fetched ahead of a visit
+    pub prefetch: Prefetch,
+
+    // Account opened from the fleet page or profile switcher; None while on the default session
+    pub active_account: Option<String>,
+
+    // Regions to switch the session to, and whether lists span all of them
+    pub regions_panel: Option<RegionsPanel>,
+    pub active_region: Option<String>, // Picked at runtime; None while on the resolved default
+    pub all_regions: bool,
+
+    // Performance HUD (F12)
+    pub perf_hud_open: bool,
+    pub perf: PerfStats,
+    pub timings: PageTimings,
+    pub internals: Internals,
+
+    // Command line opened with ':', taking control commands such as `timings`
+    pub command_line: Option<String>,
+
+    // Screen-reader mode status line
+    pub announcer: Announcer,
+
+    // Release update check
+    pub update_check: Option<JoinHandle<Option<Release>>>, // Background lookup still running
+    pub available_update: Option<Release>,                 // Newer release than this build
+    pub release_notes_open: bool,
+
+    // Crash-safe record of marks and muted alarms
+    pub journal: Journal,
+
+    // Hour-of-week baselines drawn behind the detail charts
+    pub baselines: BaselineStore,
+
+    // When each resource was last listed, to keep listing ones that have gone
+    pub resource_history: ResourceHistory,
+
+    // Raster charts shown instead of Braille where the terminal has graphics (`[charts]`)
+    pub graphics: Option<GraphicsProtocol>,
+    pub loaded_chart_image: Option<ChartImage>,
+    pub chart_image_area: Option<Rect>, // Cells reserved for the image by the last render
+}
+
+/// A listed resource: its region when the list spans all regions, and its ID
+///
+/// IDs are only unique within a region, so a list spanning regions can hold
+/// two resources with the same ID.
+pub type ResourceKey = (Option<String>, String);
+
+/// The list's highlighted resource and the one whose metrics are open, by key
+///
+/// Taken before a refresh replaces the instance list, so both stay on the same
+/// resources when the new list is ordered differently, rather than on whatever
+/// now sits at their old index.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StickySelection {
+    pub highlighted: Option<(usize, ResourceKey)>, // Index and key before the refresh
+    pub open: Option<ResourceKey>,
+    pub offset: usize, // First row shown in the list
+}
+
+impl StickySelection {
+    /// Where the highlighted and open resources are in the new list, with the
+    /// offset keeping the highlighted row where it was on screen
+    ///
+    /// A highlighted resource that is gone leaves the highlight at its old
+    /// position; an open one that is gone is no longer open.
+    pub fn resolve(&self, keys: &[ResourceKey]) -> (Option<usize>, Option<usize>, usize) {
+        let position = |key: &ResourceKey| keys.iter().position(|listed| listed == key);
+        let open = self.open.as_ref().and_then(position);
+        let Some((index, key)) = &self.highlighted else {
+            return (None, open, 0);
+        };
+        if keys.is_empty() {
+            return (None, open, 0);
+        }
+        match position(key) {
+            Some(moved) => (
+                Some(moved),
+                open,
+                (self.offset + moved).saturating_sub(*index),
+            ),
+            None => {
+                let kept = (*index).min(keys.len() - 1);
+                (Some(kept), open, self.offset.min(kept))
+            }
+        }
+    }
+}
+
+/// What one tab is looking at: its page, selections, scroll positions, time
+/// range and the resources and metrics loaded for them
+#[derive(Debug)]
+pub struct ViewState {
+    pub state: AppState,
+
+    // Service selection
     pub service_list_state: ListState,
     pub selected_service: Option<AwsService>,
 
-    // Instance list state (generic for all services, but RDS-focused)
+    // Instance list (generic for all services, but RDS-focused)
     pub instances: Vec<ServiceInstance>,
     pub rds_instances: Vec<RdsInstance>, // Keep for backward compatibility during transition
     pub list_state: ListState,
-    pub loading: bool,
-    pub state: AppState,
     pub selected_instance: Option<usize>,
+    pub departed: BTreeMap<String, u64>, // Listed in earlier sessions but gone now, with when last seen
+    pub instance_regions: Vec<String>, // Region of each listed resource when listing all regions, else empty
+    pub db_secrets: Vec<DbSecret>,     // Secrets Manager secrets, when `[secrets]` is enabled
+
+    // Metrics of the selected instance
     pub metrics: MetricData,
-    pub metrics_loading: bool,
+    pub service_metrics: Option<ServiceMetrics>, // Provider-based metrics for non-RDS services
+    pub failed_metrics: FailedMetrics, // Metrics whose last fetch failed, shown as error tiles
+    pub percentiles: HashMap<&'static str, PercentileSeries>, // Latency percentiles by metric name
     pub last_refresh: Option<Instant>,
-    pub auto_refresh_enabled: bool,
     pub scroll_offset: usize,
-    pub metrics_per_screen: usize,
     pub metrics_summary_scroll: usize, // Track metrics summary scroll position separately
     pub time_range_scroll: usize,      // Track time range selection scroll position
     pub focused_panel: FocusedPanel,   // Track which panel has focus (metrics or time ranges)
     pub saved_focused_panel: FocusedPanel, // Save focused panel state when transitioning to details
     pub time_range: TimeRange,
+    pub metric_resolution: MetricResolution, // Detected storage resolution of the selected instance's metrics
+    pub metric_schedule: RefreshSchedule,    // When each metric was last fetched, for auto-refresh
+    pub compute_environment_index: usize,    // Compute environment charted in the Batch queue view
+
+    // How the charts draw their series
+    pub heat_strip: bool, // Latency charts drawn as a heat strip instead of a line
+    pub stacked: StackedView, // Metric family drawn as stacked layers
+    pub derivative: bool, // Counter charts drawn as their change per interval
 
     // Sparkline grid state
     pub selected_metric: Option<MetricType>, // Currently selected metric in sparkline grid
@@ -534,9 +1303,479 @@ pub struct App {
     pub sparkline_grid_selected_index: usize, // Track currently selected metric index in grid
     pub saved_sparkline_grid_selected_index: usize, // Save selected metric index when transitioning to details
 
-    // Error handling
-    pub error_message: Option<String>, // Store user-friendly error messages
+    // Earlier views of this tab, for undo/redo, and the resources opened in it
+    pub history: ViewHistory,
+    pub jumps: JumpList,
 
-    // Loading timeout management
-    pub loading_start_time: Option<Instant>, // Track when loading started
+    // Pages reached from the metrics view
+    pub details_pane: DetailsPane,
+    pub activity: ActivityPanel,
+    pub rightsizing: RightsizingPanel,
+    pub stack: StackPanel,
+    pub queue_access: QueueAccessPanel,
+    pub consumers: ConsumersPanel,
+    pub compare: ComparePanel,
+    pub topology: TopologyPage,
+    pub blue_green: BlueGreenPage,
+    pub global_database: GlobalDatabasePage,
+    pub slow_query: SlowQueryPage,
+    pub log_tail: LogTailPage,
+    pub parameters: ParameterPage,
+    pub fleet: FleetPage,
+    pub resource_groups: ResourceGroupPage,
+    pub composite_alarms: CompositeAlarmPage,
+    pub alarm_browser: AlarmBrowserPage,
+    pub queue_folders: QueueFolders,
+    pub timings_from: Option<AppState>, // Page to return to from the timings page
+    pub api_calls_from: Option<AppState>, // Page to return to from the API calls page
+    pub internals_from: Option<AppState>, // Page to return to from the internals page
+}
+
+/// Instance details pane over the metrics view
+#[derive(Debug, Default)]
+pub struct DetailsPane {
+    pub open: bool,
+    pub selected_index: usize, // Selected attribute row
+    pub hscroll: usize,        // Horizontal scroll offset for long values
+}
+
+/// Recent CloudTrail API activity over the metrics view
+#[derive(Debug, Default)]
+pub struct ActivityPanel {
+    pub open: bool,
+    pub events: Vec<TrailEvent>,
+    pub selected_index: usize,
+    pub resource: Option<String>, // Resource the events were looked up for
+}
+
+/// Compute Optimizer right-sizing recommendation over the RDS metrics view
+#[derive(Debug, Default)]
+pub struct RightsizingPanel {
+    pub open: bool,
+    pub recommendation: Option<RightsizingRecommendation>, // None when Compute Optimizer has none
+    pub instance: Option<String>, // Instance the recommendation was fetched for
+}
+
+/// CloudFormation stack of the resource being viewed, over any metrics view
+#[derive(Debug, Default)]
+pub struct StackPanel {
+    pub open: bool,
+    pub membership: Option<StackMembership>, // None when the resource is not in a stack
+    pub resource: Option<String>,            // Physical ID the stack was looked up for
+}
+
+/// Resource policy and encryption of the queue being viewed, over the SQS metrics view
+#[derive(Debug, Default)]
+pub struct QueueAccessPanel {
+    pub open: bool,
+    pub scroll: usize, // First policy line shown
+}
+
+/// Lambda functions consuming the queue being viewed, over the SQS metrics view
+#[derive(Debug, Default)]
+pub struct ConsumersPanel {
+    pub open: bool,
+    pub consumers: Vec<LambdaConsumer>,
+    pub queue: Option<String>, // Queue the consumers were looked up for
+    pub scroll: usize,         // First line shown
+}
+
+/// Current window against an earlier one, over the RDS metrics view
+#[derive(Debug, Default)]
+pub struct ComparePanel {
+    pub open: bool,
+    pub offset: CompareOffset,
+    pub rows: Vec<MetricComparison>, // Metrics with data in either window
+    pub selected_index: usize,       // Row whose windows are charted
+    pub instance: Option<String>,    // Instance the windows were fetched for
+}
+
+/// Metric family drawn as stacked layers instead of one series per chart
+#[derive(Debug, Default)]
+pub struct StackedView {
+    pub family: Option<&'static str>, // Family name; None draws single series
+    pub hidden: BTreeSet<usize>,      // Layers toggled off, by index
+}
+
+/// Read replica topology view
+#[derive(Debug, Default)]
+pub struct TopologyPage {
+    pub primary: Option<String>, // Primary whose replicas are shown
+    pub lags: Vec<ReplicaLag>,
+    pub list_state: ListState,
+}
+
+/// RDS blue/green deployments
+#[derive(Debug, Default)]
+pub struct BlueGreenPage {
+    pub deployments: Vec<BlueGreenDeployment>,
+    pub selected: Option<String>, // Deployment identifier shown on the page
+    pub lags: Vec<ReplicaLag>,    // ReplicaLag of each green member
+}
+
+/// Aurora global database the selected instance's cluster belongs to
+#[derive(Debug, Default)]
+pub struct GlobalDatabasePage {
+    pub database: Option<GlobalDatabase>,
+    pub lags: Vec<SecondaryLag>, // One per secondary, in member order
+    pub list_state: ListState,   // Selected secondary, whose lag is charted
+}
+
+/// Slow query log of the selected RDS instance
+#[derive(Debug, Default)]
+pub struct SlowQueryPage {
+    pub queries: Vec<SlowQuery>,
+    pub sort: SlowQuerySort,
+    pub table_state: TableState,
+    pub log: Option<String>,    // Log group read for the page
+    pub notice: Option<String>, // Why the table is empty, e.g. log not published
+}
+
+/// Log groups of the selected RDS instance or Lambda function, and the live tail of one
+#[derive(Debug, Default)]
+pub struct LogTailPage {
+    pub groups: Vec<String>,
+    pub group_state: ListState,
+    pub group: Option<String>, // Group being tailed; None while picking one
+    pub buffer: TailBuffer,
+    pub filter: Option<String>, // CloudWatch Logs filter pattern the tail is read with
+    pub search: Option<String>, // Highlighted in the lines, and jumped between with n/N
+    pub input: Option<LogInput>,
+    pub scroll: usize, // Lines scrolled back from the newest; 0 follows new lines
+    pub paused: bool,
+    pub polled_at: Option<Instant>,
+    pub notice: Option<String>, // Why nothing is shown, e.g. no log groups or a failed read
+}
+
+/// Text being typed on the log tail page
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogInput {
+    Search(String),
+    Filter(String),
+}
+
+/// Parameters of the selected RDS instance that differ from the engine defaults
+#[derive(Debug, Default)]
+pub struct ParameterPage {
+    pub drift: Option<ParameterDrift>,
+    pub table_state: TableState,
+    pub notice: Option<String>, // Why there is nothing to list, e.g. a failed lookup
+}
+
+/// What the resource group page lists
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GroupSource {
+    #[default]
+    ResourceGroups,
+    Applications, // Service Catalog AppRegistry applications
+}
+
+impl GroupSource {
+    pub fn title(&self) -> &'static str {
+        match self {
+            GroupSource::ResourceGroups => "Resource groups",
+            GroupSource::Applications => "Applications",
+        }
+    }
+}
+
+/// The account's resource groups or applications, or the members of the one opened
+#[derive(Debug, Default)]
+pub struct ResourceGroupPage {
+    pub source: GroupSource,
+    pub groups: Vec<String>,
+    pub group: Option<String>, // Group whose members are listed; the groups are listed while None
+    pub members: GroupMembers,
+    pub health: HashMap<String, (String, Option<Severity>)>, // By member ARN, monitored members only
+    pub table_state: TableState,
+    pub notice: Option<String>, // Why there is nothing to list, e.g. a failed lookup
+}
+
+impl ResourceGroupPage {
+    /// Each monitored service among the members, with how many there are and the worst health
+    pub fn service_health(&self) -> Vec<(AwsService, usize, Option<Severity>)> {
+        let mut services: Vec<(AwsService, usize, Option<Severity>)> = Vec::new();
+        for member in &self.members.members {
+            let Some(service) = member.service() else {
+                continue;
+            };
+            let severity = self
+                .health
+                .get(&member.arn)
+                .and_then(|(_, severity)| *severity);
+            match services.iter_mut().find(|(known, ..)| *known == service) {
+                Some((_, count, worst)) => {
+                    *count += 1;
+                    *worst = (*worst).max(severity);
+                }
+                None => services.push((service, 1, severity)),
+            }
+        }
+        services
+    }
+
+    /// Rows of the list shown: members of the open group, else the groups
+    pub fn rows(&self) -> usize {
+        match self.group {
+            Some(_) => self.members.members.len(),
+            None => self.groups.len(),
+        }
+    }
+}
+
+/// The account's composite alarms, or the rule tree of the one opened
+#[derive(Debug, Default)]
+pub struct CompositeAlarmPage {
+    pub alarms: Vec<CompositeAlarm>,
+    pub open: Option<String>, // Composite whose tree is shown; the composites are listed while None
+    pub lines: Vec<RuleLine>,
+    pub table_state: TableState,
+    pub notice: Option<String>, // Why there is nothing to show, e.g. a rule that did not parse
+}
+
+impl CompositeAlarmPage {
+    /// Rows of the list shown: lines of the open tree, else the composites
+    pub fn rows(&self) -> usize {
+        match self.open {
+            Some(_) => self.lines.len(),
+            None => self.alarms.len(),
+        }
+    }
+
+    pub fn open_alarm(&self) -> Option<&CompositeAlarm> {
+        let name = self.open.as_deref()?;
+        self.alarms.iter().find(|alarm| alarm.name == name)
+    }
+}
+
+/// The account's metric alarms, listed from `App::alarms`
+#[derive(Debug, Default)]
+pub struct AlarmBrowserPage {
+    pub namespace: Option<String>, // Only alarms on this namespace are listed; all while None
+    pub table_state: TableState,   // Row of `aws::alarms::browse` for the namespace
+    pub notice: Option<String>,    // Why there is nothing to show, e.g. DescribeAlarms failing
+}
+
+/// The SQS list grouped into folders ('f'); see `aws::sqs::folders`
+///
+/// A queue row under the cursor is the list's selection as usual; a folder
+/// row is held here by name, with no queue selected.
+#[derive(Debug, Default)]
+pub struct QueueFolders {
+    pub shown: bool,
+    pub collapsed: BTreeSet<String>,
+    pub selected: Option<String>,          // Folder under the cursor
+    pub list_state: ListState,             // Scroll position of the tree
+    pub oldest_ages: HashMap<String, f64>, // Latest age of the oldest message, by queue
+}
+
+/// One service's resources across the configured accounts
+#[derive(Debug, Default)]
+pub struct FleetPage {
+    pub service: Option<AwsService>, // Service listed; RDS until another is chosen
+    pub inventory: Inventory,
+    pub table_state: TableState,
+}
+
+impl Default for ViewState {
+    fn default() -> Self {
+        let mut service_list_state = ListState::default();
+        service_list_state.select(Some(0));
+        ViewState {
+            state: AppState::ServiceList, // Start with service selection
+            service_list_state,
+            selected_service: None,
+            instances: Vec::new(),
+            rds_instances: Vec::new(),
+            list_state: ListState::default(),
+            selected_instance: None,
+            departed: BTreeMap::new(),
+            instance_regions: Vec::new(),
+            db_secrets: Vec::new(),
+            metrics: MetricData::default(),
+            service_metrics: None,
+            failed_metrics: FailedMetrics::new(),
+            percentiles: HashMap::new(),
+            last_refresh: None,
+            scroll_offset: 0,
+            metrics_summary_scroll: 0,
+            time_range_scroll: 2,
+            focused_panel: FocusedPanel::TimeRanges,
+            saved_focused_panel: FocusedPanel::TimeRanges,
+            time_range: TimeRange::new(3, TimeUnit::Hours, 1).unwrap(),
+            metric_resolution: MetricResolution::Standard,
+            metric_schedule: RefreshSchedule::default(),
+            compute_environment_index: 0,
+            heat_strip: false,
+            stacked: StackedView::default(),
+            derivative: false,
+            selected_metric: None,
+            sparkline_grid_scroll: 0,
+            sparkline_grid_selected_index: 0,
+            saved_sparkline_grid_selected_index: 0,
+            history: ViewHistory::default(),
+            jumps: JumpList::default(),
+            details_pane: DetailsPane::default(),
+            activity: ActivityPanel::default(),
+            rightsizing: RightsizingPanel::default(),
+            stack: StackPanel::default(),
+            queue_access: QueueAccessPanel::default(),
+            consumers: ConsumersPanel::default(),
+            compare: ComparePanel::default(),
+            topology: TopologyPage::default(),
+            blue_green: BlueGreenPage::default(),
+            global_database: GlobalDatabasePage::default(),
+            slow_query: SlowQueryPage::default(),
+            log_tail: LogTailPage::default(),
+            parameters: ParameterPage::default(),
+            fleet: FleetPage::default(),
+            resource_groups: ResourceGroupPage::default(),
+            composite_alarms: CompositeAlarmPage::default(),
+            alarm_browser: AlarmBrowserPage::default(),
+            queue_folders: QueueFolders::default(),
+            timings_from: None,
+            api_calls_from: None,
+            internals_from: None,
+        }
+    }
+}
+
+/// Detail chart plotted locally or fetched with GetMetricWidgetImage
+#[derive(Debug, Clone)]
+pub struct ChartImage {
+    pub key: String,                // Identifies what it shows and at which size
+    pub image: Option<RasterImage>, // None when it could not be drawn
+}
+
+/// A write action that has been requested but not yet confirmed
+#[derive(Debug, Clone, PartialEq)]
+pub enum PendingAction {
+    SendTestMessage {
+        queue_name: String,
+    },
+    SetAlarmActions {
+        alarm_names: Vec<String>,
+        resources: Vec<String>,
+        enabled: bool,
+    },
+    CreateAlarm {
+        alarm: NewAlarm,
+    },
+    CreateSnapshot {
+        instance_id: String,
+    },
+    RedriveQueue {
+        queue_name: String,
+        queue_arn: String,
+    },
+}
+
+impl PendingAction {
+    /// IDs of the resources the action changes or acts for
+    pub fn resources(&self) -> Vec<&str> {
+        match self {
+            PendingAction::SendTestMessage { queue_name }
+            | PendingAction::RedriveQueue { queue_name, .. } => vec![queue_name],
+            PendingAction::SetAlarmActions { resources, .. } => {
+                resources.iter().map(String::as_str).collect()
+            }
+            PendingAction::CreateAlarm { alarm } => vec![&alarm.dimension.1],
+            PendingAction::CreateSnapshot { instance_id } => vec![instance_id],
+        }
+    }
+
+    /// Name typed to confirm the action; the alarm count when it spans several
+    pub fn target(&self) -> String {
+        match self {
+            PendingAction::SendTestMessage { queue_name }
+            | PendingAction::RedriveQueue { queue_name, .. } => queue_name.clone(),
+            PendingAction::SetAlarmActions { alarm_names, .. } => match alarm_names.as_slice() {
+                [name] => name.clone(),
+                names => format!("{} alarms", names.len()),
+            },
+            PendingAction::CreateAlarm { alarm } => alarm.name.clone(),
+            PendingAction::CreateSnapshot { instance_id } => instance_id.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SentTestMessage {
+    pub queue_name: String,
+    pub message_id: String,
+    pub sent_at: Instant,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_autoscaling_free_storage_ceiling() {
+        let mut instance = RdsInstance {
+            identifier: "orders-db".to_string(),
+            engine: "postgres".to_string(),
+            status: "available".to_string(),
+            instance_class: "db.r6g.large".to_string(),
+            endpoint: None,
+            port: None,
+            allocated_storage_gib: Some(100),
+            max_allocated_storage_gib: Some(150),
+            pending_modifications: Vec::new(),
+            topology: ReplicaTopology::default(),
+            attributes: Vec::new(),
+            certificate: None,
+        };
+
+        let free = 10.0 * BYTES_PER_GIB;
+        assert_eq!(
+            instance.autoscaling_free_storage_ceiling(free),
+            Some(60.0 * BYTES_PER_GIB)
+        );
+
+        instance.max_allocated_storage_gib = None;
+        assert_eq!(instance.autoscaling_free_storage_ceiling(free), None);
+    }
+
+    #[test]
+    fn test_sticky_selection_follows_resources_by_id() {
+        let key = |region: Option<&str>, id: &str| (region.map(str::to_string), id.to_string());
+        let sticky = StickySelection {
+            highlighted: Some((3, key(None, "orders-db"))),
+            open: Some(key(None, "payments-db")),
+            offset: 2,
+        };
+        // A new instance sorted in ahead of both moves them down a row
+        let keys: Vec<ResourceKey> = [
+            "audit-db",
+            "billing-db",
+            "cache-db",
+            "ledger-db",
+            "orders-db",
+            "payments-db",
+        ]
+        .into_iter()
+        .map(|id| key(None, id))
+        .collect();
+        assert_eq!(sticky.resolve(&keys), (Some(4), Some(5), 3));
+
+        // Gone: the highlight stays put and the open resource is dropped
+        let keys = [key(None, "audit-db"), key(None, "billing-db")];
+        assert_eq!(sticky.resolve(&keys), (Some(1), None, 1));
+        assert_eq!(sticky.resolve(&[]), (None, None, 0));
+        assert_eq!(StickySelection::default().resolve(&keys), (None, None, 0));
+
+        // Across regions the same ID is told apart by its region
+        let sticky = StickySelection {
+            highlighted: Some((1, key(Some("eu-west-1"), "orders-db"))),
+            open: None,
+            offset: 0,
+        };
+        let keys = [
+            key(Some("eu-west-1"), "audit-db"),
+            key(Some("us-east-1"), "orders-db"),
+            key(Some("eu-west-1"), "orders-db"),
+        ];
+        assert_eq!(sticky.resolve(&keys), (Some(2), None, 1));
+    }
 }
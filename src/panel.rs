@@ -0,0 +1,221 @@
+// Single-panel mode for composing layouts out of several awscw processes
+//
+// `awscw --panel <widget>` draws one widget over the whole terminal with a
+// one-line title and no borders, so tmux or zellij panes can each show one:
+//
+//   awscw --panel sparkline:orders-db:CPUUtilization
+//   awscw --panel sparkline:sqs:orders:ApproximateAgeOfOldestMessage@6h
+//   awscw --panel alarms
+//
+// A sparkline names the resource and CloudWatch metric, optionally preceded
+// by the service (RDS by default) and followed by `@<range>` (3h by default).
+// The widget reloads on the configured refresh interval; 'r' reloads it now
+// and 'q', Esc or Ctrl+c quits.
+
+use crate::aws::alarms::{AlarmsManager, MetricAlarm};
+use crate::aws::metrics::factory::MetricServiceFactory;
+use crate::aws::time_range::TimeRange;
+use crate::config::Config;
+use crate::listing::parse_service;
+use crate::models::AwsService;
+use crate::query::query_metric;
+use crate::terminal::TerminalManager;
+use crate::ui::components::single_panel::render_single_panel;
+use anyhow::{anyhow, Result};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use std::time::{Duration, Instant};
+
+const DEFAULT_RANGE: &str = "3h";
+
+/// The one widget a panel process shows
+#[derive(Debug, Clone, PartialEq)]
+pub enum PanelWidget {
+    Sparkline {
+        service: AwsService,
+        resource: String,
+        metric: String,
+        range: String, // As given to --range, e.g. 3h
+    },
+    Alarms,
+}
+
+impl PanelWidget {
+    pub fn parse(spec: &str) -> Result<Self> {
+        let (kind, rest) = spec.split_once(':').unwrap_or((spec, ""));
+        match kind {
+            "alarms" if rest.is_empty() => Ok(Self::Alarms),
+            "sparkline" => {
+                let (target, range) = rest.split_once('@').unwrap_or((rest, DEFAULT_RANGE));
+                TimeRange::parse(range)?;
+                let parts: Vec<&str> = target.split(':').collect();
+                let (service, resource, metric) = match parts.as_slice() {
+                    [resource, metric] => (AwsService::Rds, *resource, *metric),
+                    [service, resource, metric] => (parse_service(service)?, *resource, *metric),
+                    _ => {
+                        return Err(anyhow!(
+                            "Sparkline panels are sparkline:[service:]resource:metric[@range]"
+                        ))
+                    }
+                };
+                if resource.is_empty() || metric.is_empty() {
+                    return Err(anyhow!("Sparkline panel '{spec}' needs a resource and metric"));
+                }
+                Ok(Self::Sparkline {
+                    service,
+                    resource: resource.to_string(),
+                    metric: metric.to_string(),
+                    range: range.to_string(),
+                })
+            }
+            _ => Err(anyhow!(
+                "Unknown panel '{spec}', expected sparkline:[service:]resource:metric[@range] or alarms"
+            )),
+        }
+    }
+}
+
+/// What the widget last loaded
+#[derive(Debug)]
+pub enum PanelData {
+    Series { values: Vec<f64>, unit: String },
+    Alarms(Vec<MetricAlarm>),
+}
+
+#[derive(Debug)]
+pub struct PanelState {
+    pub widget: PanelWidget,
+    pub data: Option<PanelData>,
+    pub error: Option<String>, // Last load failure; the previous data stays shown
+    pub loaded_at: Option<Instant>,
+}
+
+impl PanelState {
+    async fn load(&mut self) {
+        match load_widget(&self.widget).await {
+            Ok(data) => {
+                self.data = Some(data);
+                self.error = None;
+            }
+            Err(e) => self.error = Some(format!("{e:#}")),
+        }
+        self.loaded_at = Some(Instant::now());
+    }
+}
+
+async fn load_widget(widget: &PanelWidget) -> Result<PanelData> {
+    match widget {
+        PanelWidget::Sparkline {
+            service,
+            resource,
+            metric,
+            range,
+        } => {
+            let datapoints =
+                query_metric(service, resource, metric, TimeRange::parse(range)?, None).await?;
+            Ok(PanelData::Series {
+                values: datapoints.iter().map(|datapoint| datapoint.value).collect(),
+                unit: metric_unit(service, metric),
+            })
+        }
+        PanelWidget::Alarms => {
+            let mut alarms = AlarmsManager::new().await.load_alarms().await?;
+            // Firing alarms first, then those without data
+            alarms.sort_by_key(|alarm| {
+                let rank = match alarm.state.as_str() {
+                    "ALARM" => 0,
+                    "INSUFFICIENT_DATA" => 1,
+                    _ => 2,
+                };
+                (rank, alarm.name.clone())
+            });
+            Ok(PanelData::Alarms(alarms))
+        }
+    }
+}
+
+/// Unit the service's metric pages use for `metric`; empty for metrics they do not show
+fn metric_unit(service: &AwsService, metric: &str) -> String {
+    MetricServiceFactory::new()
+        .get_provider(service)
+        .ok()
+        .and_then(|provider| {
+            provider
+                .get_metrics_config()
+                .into_iter()
+                .find(|definition| definition.name == metric)
+        })
+        .and_then(|definition| definition.unit)
+        .unwrap_or_default()
+}
+
+/// Show `widget` until the user quits, reloading it every refresh interval
+pub async fn run_panel(
+    mut terminal: TerminalManager,
+    widget: PanelWidget,
+    config: &Config,
+) -> Result<()> {
+    let mut state = PanelState {
+        widget,
+        data: None,
+        error: None,
+        loaded_at: None,
+    };
+
+    loop {
+        if state
+            .loaded_at
+            .is_none_or(|at| at.elapsed() >= config.refresh_interval)
+        {
+            state.load().await;
+        }
+        terminal.draw(|f| render_single_panel(f, &state, &config.theme))?;
+
+        if event::poll(Duration::from_millis(250))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match (key.code, key.modifiers) {
+                    (KeyCode::Char('q'), _) | (KeyCode::Esc, _) => break,
+                    (KeyCode::Char('c'), KeyModifiers::CONTROL) => break,
+                    (KeyCode::Char('r'), _) => state.loaded_at = None,
+                    _ => {}
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_panel_widgets() {
+        assert_eq!(PanelWidget::parse("alarms").unwrap(), PanelWidget::Alarms);
+        assert_eq!(
+            PanelWidget::parse("sparkline:orders-db:CPUUtilization").unwrap(),
+            PanelWidget::Sparkline {
+                service: AwsService::Rds,
+                resource: "orders-db".to_string(),
+                metric: "CPUUtilization".to_string(),
+                range: "3h".to_string(),
+            }
+        );
+        assert_eq!(
+            PanelWidget::parse("sparkline:sqs:orders:ApproximateAgeOfOldestMessage@6h").unwrap(),
+            PanelWidget::Sparkline {
+                service: AwsService::Sqs,
+                resource: "orders".to_string(),
+                metric: "ApproximateAgeOfOldestMessage".to_string(),
+                range: "6h".to_string(),
+            }
+        );
+        assert!(PanelWidget::parse("sparkline:orders-db").is_err());
+        assert!(PanelWidget::parse("sparkline:orders-db:CPUUtilization@soon").is_err());
+        assert!(PanelWidget::parse("sparkline:ec2:i-123:CPUUtilization").is_err());
+        assert!(PanelWidget::parse("alarms:orders-db").is_err());
+        assert!(PanelWidget::parse("dashboard").is_err());
+    }
+}
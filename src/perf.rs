@@ -0,0 +1,213 @@
+// Performance HUD figures: frame times, process memory and metric history held
+//
+// F12 shows them over any page. The history figure counts every datapoint the
+// open tabs keep (values and timestamps), which is what `[history] max_points`
+// bounds; the resident size is the whole process as the OS sees it.
+//
+// Page loads are timed as well, end to end: from the key press (or control
+// command) that changed the page, through the handler that fetched its data,
+// to the first frame drawn of it. `:timings` lists them per page.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Page loads kept for `:timings`, oldest dropped first
+const MAX_PAGE_LOADS: usize = 200;
+
+/// Render times of the frames drawn while the HUD is open
+#[derive(Debug, Default)]
+pub struct PerfStats {
+    pub last_frame: Duration,
+    pub slowest_frame: Duration,
+}
+
+impl PerfStats {
+    pub fn record_frame(&mut self, elapsed: Duration) {
+        self.last_frame = elapsed;
+        self.slowest_frame = self.slowest_frame.max(elapsed);
+    }
+}
+
+/// How long one page took to show, from the input that opened it
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageLoad {
+    pub page: String,
+    pub fetch: Duration,  // Input to its handler returning, data fetched
+    pub render: Duration, // Drawing the first frame of the page
+    pub total: Duration,  // Input to that frame drawn, including work between the two
+}
+
+/// A page whose data is in, waiting for its first frame
+#[derive(Debug)]
+struct PendingLoad {
+    page: String,
+    started: Instant,
+    fetched: Instant,
+}
+
+/// Loads of one page, summed up
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageStats {
+    pub page: String,
+    pub loads: usize,
+    pub median_fetch: Duration,
+    pub median_render: Duration,
+    pub median_total: Duration,
+    pub slowest_total: Duration,
+}
+
+/// Recent page loads, newest last
+#[derive(Debug, Default)]
+pub struct PageTimings {
+    pending: Option<PendingLoad>,
+    loads: VecDeque<PageLoad>,
+}
+
+impl PageTimings {
+    /// The input that started at `started` changed the page to `page`, its data now fetched
+    pub fn fetched(&mut self, page: String, started: Instant) {
+        self.pending = Some(PendingLoad {
+            page,
+            started,
+            fetched: Instant::now(),
+        });
+    }
+
+    /// A frame that took `drawing` was just drawn; completes the page load waiting for it
+    pub fn frame_drawn(&mut self, drawing: Duration) {
+        let Some(pending) = self.pending.take() else {
+            return;
+        };
+        self.record(PageLoad {
+            page: pending.page,
+            fetch: pending.fetched - pending.started,
+            render: drawing,
+            total: pending.started.elapsed(),
+        });
+    }
+
+    fn record(&mut self, load: PageLoad) {
+        if self.loads.len() == MAX_PAGE_LOADS {
+            self.loads.pop_front();
+        }
+        self.loads.push_back(load);
+    }
+
+    pub fn loads(&self) -> &VecDeque<PageLoad> {
+        &self.loads
+    }
+
+    pub fn clear(&mut self) {
+        self.loads.clear();
+    }
+
+    /// Each page's loads summed up, slowest median first
+    pub fn per_page(&self) -> Vec<PageStats> {
+        let mut pages: Vec<&str> = self.loads.iter().map(|load| load.page.as_str()).collect();
+        pages.sort_unstable();
+        pages.dedup();
+        let mut stats: Vec<PageStats> = pages
+            .into_iter()
+            .map(|page| {
+                let loads: Vec<&PageLoad> =
+                    self.loads.iter().filter(|load| load.page == page).collect();
+                let median = |phase: fn(&PageLoad) -> Duration| {
+                    let mut durations: Vec<Duration> =
+                        loads.iter().map(|load| phase(load)).collect();
+                    durations.sort_unstable();
+                    durations[durations.len() / 2]
+                };
+                PageStats {
+                    page: page.to_string(),
+                    loads: loads.len(),
+                    median_fetch: median(|load| load.fetch),
+                    median_render: median(|load| load.render),
+                    median_total: median(|load| load.total),
+                    slowest_total: loads
+                        .iter()
+                        .map(|load| load.total)
+                        .max()
+                        .unwrap_or_default(),
+                }
+            })
+            .collect();
+        stats.sort_by_key(|page| std::cmp::Reverse(page.median_total));
+        stats
+    }
+}
+
+/// Datapoints held in memory
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct HeldHistory {
+    pub series: usize,
+    pub points: usize,     // Values across every series
+    pub timestamps: usize, // Timestamps, shared by the series of one resource
+}
+
+impl HeldHistory {
+    pub fn add_series(&mut self, points: usize) {
+        self.series += 1;
+        self.points += points;
+    }
+
+    /// Approximate bytes the values and timestamps take
+    pub fn bytes(&self) -> usize {
+        self.points * std::mem::size_of::<f64>()
+            + self.timestamps * std::mem::size_of::<std::time::SystemTime>()
+    }
+}
+
+/// Resident memory of this process in bytes; None where /proc is not available
+pub fn resident_memory() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    parse_vm_rss(&status)
+}
+
+fn parse_vm_rss(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kib: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kib * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vm_rss() {
+        let status = "Name:\tawscw\nVmPeak:\t  90000 kB\nVmRSS:\t   54321 kB\nThreads:\t12\n";
+        assert_eq!(parse_vm_rss(status), Some(54321 * 1024));
+        assert_eq!(parse_vm_rss("Name:\tawscw\n"), None);
+    }
+
+    #[test]
+    fn test_page_loads_sum_up_per_page() {
+        let ms = Duration::from_millis;
+        let mut timings = PageTimings::default();
+        // A frame with no page load waiting is not one
+        timings.frame_drawn(ms(5));
+        assert!(timings.loads().is_empty());
+
+        for (page, fetch, render) in [
+            ("RDS metrics", 900, 20),
+            ("Services", 1, 5),
+            ("RDS metrics", 300, 10),
+            ("RDS metrics", 600, 30),
+        ] {
+            timings.record(PageLoad {
+                page: page.to_string(),
+                fetch: ms(fetch),
+                render: ms(render),
+                total: ms(fetch + render),
+            });
+        }
+        let stats = timings.per_page();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].page, "RDS metrics");
+        assert_eq!(stats[0].loads, 3);
+        assert_eq!(stats[0].median_fetch, ms(600));
+        assert_eq!(stats[0].median_render, ms(20));
+        assert_eq!(stats[0].slowest_total, ms(920));
+        assert_eq!(stats[1].median_total, ms(6));
+    }
+}
@@ -0,0 +1,191 @@
+// Feature policy
+//
+// A team can check a `.awscw-policy.toml` into its repositories to turn
+// features off for resources in some environments. awscw reads the first one
+// found in the working directory or one of its parents:
+//
+//   [environments.production]
+//   disable = ["writes", "slow_queries"]
+//
+//   [environments.dev]
+//   disable = []
+//
+//   [default]
+//   disable = ["writes"]
+//
+// A resource's environment is the value of the tag named by
+// `environment_tag` under `[actions]`, matched ignoring case. `[default]`
+// covers resources without the tag or with a label not listed. A resource
+// that is not in the loaded lists has tags that can't be read, so a feature
+// any section disables is disabled for it. A feature touching several
+// resources is disabled if any of their environments disables it. The config
+// file cannot loosen the policy.
+
+use crate::confirmation::Environment;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+const POLICY_FILE: &str = ".awscw-policy.toml";
+
+/// Features a policy can disable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Feature {
+    Writes,      // Every action that modifies AWS resources
+    SlowQueries, // Statements read from the slow query log
+    QueuePolicy, // The queue policy and its principals
+    Activity,    // CloudTrail calls against the resource
+}
+
+impl Feature {
+    pub fn describe(self) -> &'static str {
+        match self {
+            Feature::Writes => "Write actions are",
+            Feature::SlowQueries => "Slow query logs are",
+            Feature::QueuePolicy => "Queue policies are",
+            Feature::Activity => "API activity is",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Rules {
+    disable: Vec<Feature>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Policy {
+    default: Rules,
+    environments: BTreeMap<String, Rules>,
+    #[serde(skip)]
+    pub path: Option<PathBuf>, // File the policy was read from; None when there is none
+}
+
+impl Policy {
+    /// The policy nearest the working directory; an empty one when there is none
+    pub fn load() -> Result<Self> {
+        let Some(path) = std::env::current_dir().ok().and_then(|dir| find(&dir)) else {
+            return Ok(Self::default());
+        };
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let mut policy = Self::parse(&contents)
+            .with_context(|| format!("Invalid policy in {}", path.display()))?;
+        policy.path = Some(path);
+        Ok(policy)
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        Ok(toml::from_str(contents)?)
+    }
+
+    /// Environment disabling `feature` for resources in `environments`; None when allowed
+    ///
+    /// Resources without the environment tag are named "untagged". Those
+    /// whose environment is unknown are held to the strictest section.
+    pub fn denies<'a>(
+        &self,
+        feature: Feature,
+        environments: impl IntoIterator<Item = &'a Environment>,
+    ) -> Option<String> {
+        environments.into_iter().find_map(|environment| {
+            let label = match environment {
+                Environment::Tagged(label) => label.as_str(),
+                Environment::Untagged => "untagged",
+                Environment::Unknown => {
+                    let strictest = std::iter::once(&self.default)
+                        .chain(self.environments.values())
+                        .any(|rules| rules.disable.contains(&feature));
+                    return strictest.then(|| "an unknown environment".to_string());
+                }
+            };
+            let (label, rules) = self
+                .environments
+                .iter()
+                .find(|(listed, _)| listed.eq_ignore_ascii_case(label))
+                .map_or((label, &self.default), |(listed, rules)| {
+                    (listed.as_str(), rules)
+                });
+            rules.disable.contains(&feature).then(|| label.to_string())
+        })
+    }
+
+    /// "Write actions are disabled by policy for production (<path>)"
+    pub fn denial(&self, feature: Feature, environment: &str) -> String {
+        let source = self.path.as_deref().map_or_else(
+            || POLICY_FILE.to_string(),
+            |path| path.display().to_string(),
+        );
+        format!(
+            "{} disabled by policy for {environment} ({source})",
+            feature.describe()
+        )
+    }
+}
+
+/// The policy file in `dir` or the nearest parent holding one
+fn find(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join(POLICY_FILE))
+        .find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_disables_features_per_environment() {
+        let policy = Policy::parse(
+            r#"
+[environments.production]
+disable = ["writes", "slow_queries"]
+
+[environments.dev]
+disable = []
+
+[default]
+disable = ["writes"]
+"#,
+        )
+        .unwrap();
+
+        let tagged = |label: &str| Environment::Tagged(label.to_string());
+        assert_eq!(policy.denies(Feature::Writes, [&tagged("dev")]), None);
+        assert_eq!(
+            policy.denies(Feature::Writes, [&tagged("dev"), &tagged("Production")]),
+            Some("production".to_string())
+        );
+        assert_eq!(
+            policy.denies(Feature::SlowQueries, [&tagged("staging")]),
+            None
+        );
+        assert_eq!(
+            policy.denies(Feature::Writes, [&tagged("staging")]),
+            Some("staging".to_string())
+        );
+        assert_eq!(
+            policy.denies(Feature::Writes, [&Environment::Untagged]),
+            Some("untagged".to_string())
+        );
+        assert_eq!(
+            Policy::default().denies(Feature::Writes, [&Environment::Untagged]),
+            None
+        );
+        // Resources whose tags can't be read get the strictest section
+        assert_eq!(
+            policy.denies(Feature::SlowQueries, [&Environment::Unknown]),
+            Some("an unknown environment".to_string())
+        );
+        assert_eq!(
+            policy.denies(Feature::Activity, [&Environment::Unknown]),
+            None
+        );
+
+        assert!(Policy::parse("[default]\ndisable = [\"peeking\"]").is_err());
+    }
+}
@@ -0,0 +1,211 @@
+// Warm metrics cache for favorite resources
+//
+// Opening an instance's metrics waits on a couple of dozen CloudWatch calls.
+// Right after startup, once the credentials are known to work, the RDS
+// instances on the watchlist and in bookmarks are fetched one at a time in
+// the background, over the range a metrics page opens with, so the first
+// visit to each renders straight away. A warmed series is handed out once,
+// and only while it is fresh; after that pages fetch from CloudWatch as usual.
+
+use crate::aws::cloudwatch_service::{load_metrics, ReusedMetrics, TimeRange};
+use crate::aws::metric_fetcher::{every_metric_failed, FailedMetrics};
+use crate::bookmarks::Bookmark;
+use crate::listing::parse_service;
+use crate::models::{AwsService, MetricData, MetricType};
+use crate::watchlist::Watchlist;
+use std::collections::{BTreeSet, HashMap};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{self, error::TryRecvError};
+use tokio::task::JoinHandle;
+
+/// Warmed series older than this are dropped in favor of a fresh fetch
+const WARM_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// Metrics of one instance as fetched in the background
+pub struct Warmed {
+    pub metrics: MetricData,
+    pub failures: FailedMetrics,
+    pub fetched_at: Instant,
+}
+
+/// Background fetch of favorite instances and the series it has warmed so far
+#[derive(Default)]
+pub struct Prefetch {
+    time_range: Option<TimeRange>, // Range the series were fetched over
+    total: usize,
+    done: usize,
+    warmed: HashMap<String, Warmed>,
+    lookups: usize, // Pages opened since the fetch started, and those it had warmed
+    hits: usize,
+    updates: Option<mpsc::UnboundedReceiver<(String, Option<Warmed>)>>,
+    task: Option<JoinHandle<()>>,
+}
+
+/// RDS instances worth warming: the watchlist's, then bookmarked ones, at most `limit`
+pub fn targets(watchlist: &Watchlist, bookmarks: &[Bookmark], limit: usize) -> Vec<String> {
+    let bookmarked = bookmarks
+        .iter()
+        .filter(|bookmark| matches!(parse_service(&bookmark.service), Ok(AwsService::Rds)))
+        .map(|bookmark| bookmark.resource.clone());
+    let mut seen = BTreeSet::new();
+    watchlist
+        .rds
+        .iter()
+        .cloned()
+        .chain(bookmarked)
+        .filter(|identifier| seen.insert(identifier.clone()))
+        .take(limit)
+        .collect()
+}
+
+impl Prefetch {
+    /// Fetch each of `instances` over `time_range` in the background, one after another
+    pub fn start(instances: Vec<String>, time_range: TimeRange) -> Self {
+        let (sender, updates) = mpsc::unbounded_channel();
+        let total = instances.len();
+        let task = tokio::spawn(async move {
+            for instance_id in instances {
+                let warmed = load_metrics(&instance_id, time_range, &ReusedMetrics::new())
+                    .await
+                    .ok()
+                    // An instance whose every metric failed is left for the page to report
+                    .filter(|(_, failures)| {
+                        every_metric_failed(failures, MetricType::ALL.len()).is_none()
+                    })
+                    .map(|(metrics, failures)| Warmed {
+                        metrics,
+                        failures,
+                        fetched_at: Instant::now(),
+                    });
+                if sender.send((instance_id, warmed)).is_err() {
+                    return;
+                }
+            }
+        });
+        Self {
+            time_range: Some(time_range),
+            total,
+            done: 0,
+            warmed: HashMap::new(),
+            lookups: 0,
+            hits: 0,
+            updates: Some(updates),
+            task: Some(task),
+        }
+    }
+
+    /// Keep the instances fetched since the last call; never waits
+    pub fn poll(&mut self) {
+        let Some(updates) = self.updates.as_mut() else {
+            return;
+        };
+        let finished = loop {
+            match updates.try_recv() {
+                Ok((instance_id, warmed)) => {
+                    self.done += 1;
+                    if let Some(warmed) = warmed {
+                        self.warmed.insert(instance_id, warmed);
+                    }
+                }
+                Err(TryRecvError::Empty) => break false,
+                // The task has ended, whether or not every instance was fetched
+                Err(TryRecvError::Disconnected) => break true,
+            }
+        };
+        if finished {
+            self.updates = None;
+            self.task = None;
+        }
+    }
+
+    /// (fetched, total) while the background fetch is still running
+    pub fn progress(&self) -> Option<(usize, usize)> {
+        self.task.is_some().then_some((self.done, self.total))
+    }
+
+    /// The warmed metrics of `instance_id`, if fetched over `time_range` and still fresh
+    pub fn take(&mut self, instance_id: &str, time_range: TimeRange) -> Option<Warmed> {
+        // Pages opened without a background fetch started don't count as misses
+        self.time_range?;
+        self.lookups += 1;
+        let warmed = self.warmed.remove(instance_id)?;
+        let warmed = (self.time_range == Some(time_range)
+            && warmed.fetched_at.elapsed() < WARM_TTL)
+            .then_some(warmed)?;
+        self.hits += 1;
+        Some(warmed)
+    }
+
+    /// Share of the pages opened that were served warmed series; None before the first
+    pub fn hit_rate(&self) -> Option<f64> {
+        (self.lookups > 0).then(|| self.hits as f64 / self.lookups as f64)
+    }
+}
+
+impl Drop for Prefetch {
+    fn drop(&mut self) {
+        if let Some(task) = &self.task {
+            task.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aws::cloudwatch_service::TimeUnit;
+
+    fn bookmark(service: &str, resource: &str) -> Bookmark {
+        Bookmark {
+            name: resource.to_string(),
+            service: service.to_string(),
+            resource: resource.to_string(),
+            metric: None,
+            range: "3h".to_string(),
+            period_secs: None,
+            chart: false,
+        }
+    }
+
+    #[test]
+    fn test_targets_and_fresh_series_are_taken_once() {
+        let watchlist = Watchlist::parse(r#"rds = ["payments-db", "orders-db"]"#).unwrap();
+        let bookmarks = [
+            bookmark("rds", "orders-db"),
+            bookmark("sqs", "orders"),
+            bookmark("RDS", "reporting-db"),
+        ];
+        assert_eq!(
+            targets(&watchlist, &bookmarks, 10),
+            vec!["orders-db", "payments-db", "reporting-db"]
+        );
+        assert_eq!(targets(&watchlist, &bookmarks, 1), vec!["orders-db"]);
+
+        let three_hours = TimeRange::new(3, TimeUnit::Hours, 1).unwrap();
+        let mut prefetch = Prefetch::default();
+        prefetch.time_range = Some(three_hours);
+        let warmed = |age: Duration| Warmed {
+            metrics: MetricData::default(),
+            failures: FailedMetrics::new(),
+            fetched_at: Instant::now() - age,
+        };
+        prefetch
+            .warmed
+            .insert("orders-db".to_string(), warmed(Duration::ZERO));
+        prefetch
+            .warmed
+            .insert("payments-db".to_string(), warmed(WARM_TTL * 2));
+        prefetch
+            .warmed
+            .insert("reporting-db".to_string(), warmed(Duration::ZERO));
+
+        assert!(prefetch.take("orders-db", three_hours).is_some());
+        assert!(prefetch.take("orders-db", three_hours).is_none());
+        assert!(prefetch.take("payments-db", three_hours).is_none());
+        let one_hour = TimeRange::new(1, TimeUnit::Hours, 1).unwrap();
+        assert!(prefetch.take("reporting-db", one_hour).is_none());
+        assert_eq!(prefetch.hit_rate(), Some(0.25));
+        assert_eq!(prefetch.progress(), None);
+        assert_eq!(Prefetch::default().hit_rate(), None);
+    }
+}
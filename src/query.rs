@@ -0,0 +1,315 @@
+// One-off metric queries from the command line
+//
+// `awscw query orders-db CPUUtilization --range 6h` prints the datapoints of
+// one metric; `awscw watch orders-db` prints the latest key metrics of an RDS
+// instance every interval until interrupted; `awscw backtest` replays a
+// threshold rule over a metric's history and prints when it would have fired;
+// `awscw gaps` lists the periods a heartbeat metric was missing or zero.
+
+use crate::analytics::backtest::{AlertRule, Backtest};
+use crate::analytics::gaps::{find_gaps, GapKind, GapReport};
+use crate::aws::metrics::factory::MetricServiceFactory;
+use crate::aws::metrics::fetcher::{fetch_metric_series, sdk_dimensions};
+use crate::aws::metrics::types::{MetricCategory, MetricDefinition, StatisticType};
+use crate::aws::session::AwsSessionManager;
+use crate::aws::time_range::{calculate_period_seconds, TimeRange, MAX_DATAPOINTS_PER_REQUEST};
+use crate::export::{fetch_key_metrics, SNAPSHOT_METRICS};
+use crate::models::AwsService;
+use crate::ui::components::metric_utils::format_value;
+use crate::ui::components::queue_summary::format_age;
+use crate::utils::formatting::locale;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local, SecondsFormat, Utc};
+use serde::Serialize;
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Serialize)]
+pub struct Datapoint {
+    pub timestamp: String, // RFC 3339, UTC
+    pub value: f64,
+}
+
+pub fn parse_statistic(name: &str) -> Result<StatisticType> {
+    match name {
+        "average" => Ok(StatisticType::Average),
+        "sum" => Ok(StatisticType::Sum),
+        "maximum" => Ok(StatisticType::Maximum),
+        "minimum" => Ok(StatisticType::Minimum),
+        other => Err(anyhow!("Unknown statistic '{other}'")),
+    }
+}
+
+/// Datapoints of `metric` for one resource, oldest first
+///
+/// The statistic defaults to the one the service's metric pages use, or
+/// Average for metrics they do not show.
+pub async fn query_metric(
+    service: &AwsService,
+    resource: &str,
+    metric: &str,
+    range: TimeRange,
+    statistic: Option<StatisticType>,
+) -> Result<Vec<Datapoint>> {
+    let (timestamps, values) = fetch_history(service, resource, metric, range, statistic).await?;
+    Ok(timestamps
+        .into_iter()
+        .zip(values)
+        .map(|(timestamp, value)| Datapoint {
+            timestamp: DateTime::<Utc>::from(timestamp).to_rfc3339_opts(SecondsFormat::Secs, true),
+            value,
+        })
+        .collect())
+}
+
+/// Replay a threshold rule over the metric's history at the rule's period
+///
+/// Ranges too long for one request at that period fall back to the range's
+/// default granularity, which the result flags as coarser than the rule.
+pub async fn backtest_metric(
+    service: &AwsService,
+    resource: &str,
+    metric: &str,
+    mut range: TimeRange,
+    statistic: Option<StatisticType>,
+    rule: &AlertRule,
+) -> Result<Option<Backtest>> {
+    if range.duration().as_secs() / rule.period_secs.max(1) <= MAX_DATAPOINTS_PER_REQUEST {
+        range.period_override = Some(rule.period_secs as i32);
+    }
+    let (timestamps, values) = fetch_history(service, resource, metric, range, statistic).await?;
+    Ok(Backtest::run(rule, &timestamps, &values))
+}
+
+/// Firing periods as tab-separated start, end, duration and worst value lines
+pub fn render_backtest(backtest: Option<&Backtest>) -> String {
+    let Some(backtest) = backtest else {
+        return "No datapoints in the range\n".to_string();
+    };
+    let timestamp =
+        |at: SystemTime| DateTime::<Utc>::from(at).to_rfc3339_opts(SecondsFormat::Secs, true);
+    let mut output = String::new();
+    for firing in &backtest.firings {
+        output.push_str(&format!(
+            "{}\t{}\t{}s\t{}\n",
+            timestamp(firing.start),
+            firing
+                .end
+                .map(timestamp)
+                .unwrap_or_else(|| "firing".to_string()),
+            firing.duration(backtest.to).as_secs(),
+            firing.worst
+        ));
+    }
+    output.push_str(&format!(
+        "# {} firing(s) between {} and {}, {}s in ALARM\n",
+        backtest.firings.len(),
+        timestamp(backtest.from),
+        timestamp(backtest.to),
+        backtest.time_firing().as_secs()
+    ));
+    if backtest.coarser_history {
+        output.push_str("# Datapoints are coarser than the rule period; use a shorter --range\n");
+    }
+    output
+}
+
+/// Metric the gap report watches when none is given: one that is non-zero whenever the resource is in use
+pub fn heartbeat_metric(service: &AwsService) -> &'static str {
+    match service {
+        AwsService::Rds => "DatabaseConnections",
+        AwsService::Sqs => "NumberOfMessagesSent",
+        AwsService::Synthetics => "SuccessPercent",
+        AwsService::EventBridge => "TriggeredRules",
+        AwsService::Batch => "CPUReservation",
+        AwsService::Lambda => "Invocations",
+    }
+}
+
+/// Missing and zero periods of a heartbeat-style metric over `range`
+pub async fn gap_report(
+    service: &AwsService,
+    resource: &str,
+    metric: &str,
+    mut range: TimeRange,
+    period_secs: u64,
+) -> Result<GapReport> {
+    range.period_override = Some(period_secs as i32);
+    let (timestamps, values) = fetch_history(service, resource, metric, range, None).await?;
+    if timestamps.is_empty() {
+        return Err(anyhow!(
+            "No {metric} datapoints for {resource} in the range; check the name and permissions"
+        ));
+    }
+    // The newest period is usually still being aggregated, so it is left out
+    let to = SystemTime::now() - Duration::from_secs(period_secs);
+    Ok(find_gaps(
+        &timestamps,
+        &values,
+        to - range.duration(),
+        to,
+        period_secs,
+    ))
+}
+
+#[derive(Debug, Serialize)]
+struct GapRow {
+    start: String, // RFC 3339, UTC
+    end: String,
+    duration_secs: u64,
+    kind: GapKind,
+}
+
+/// Gaps as JSON, or as a table with an availability summary
+pub fn render_gap_report(report: &GapReport, json: bool) -> Result<String> {
+    let timestamp =
+        |at: SystemTime| DateTime::<Utc>::from(at).to_rfc3339_opts(SecondsFormat::Secs, true);
+    if json {
+        let rows: Vec<GapRow> = report
+            .gaps
+            .iter()
+            .map(|gap| GapRow {
+                start: timestamp(gap.start),
+                end: timestamp(gap.end),
+                duration_secs: gap.duration().as_secs(),
+                kind: gap.kind,
+            })
+            .collect();
+        return Ok(serde_json::to_string_pretty(&rows)? + "\n");
+    }
+
+    let local = |at: SystemTime| {
+        let time = DateTime::<Local>::from(at);
+        format!(
+            "{} {}",
+            locale().short_date(&time),
+            locale().time(&time, false)
+        )
+    };
+    let mut output = String::new();
+    for gap in &report.gaps {
+        output.push_str(&format!(
+            "{}  →  {}\t{}\t{}\n",
+            local(gap.start),
+            local(gap.end),
+            format_age(gap.duration().as_secs_f64()),
+            gap.kind.label()
+        ));
+    }
+    output.push_str(&format!(
+        "{} gap(s), {} in total; {:.2}% of the time with data between {} and {}\n",
+        report.gaps.len(),
+        format_age(report.total_gap().as_secs_f64()),
+        report.availability(),
+        local(report.from),
+        local(report.to)
+    ));
+    Ok(output)
+}
+
+async fn fetch_history(
+    service: &AwsService,
+    resource: &str,
+    metric: &str,
+    range: TimeRange,
+    statistic: Option<StatisticType>,
+) -> Result<(Vec<SystemTime>, Vec<f64>)> {
+    let factory = MetricServiceFactory::new();
+    let provider = factory.get_provider(service)?;
+    let dimensions = sdk_dimensions(provider, resource)?;
+    let known = provider
+        .get_metrics_config()
+        .into_iter()
+        .find(|definition| definition.name == metric);
+    let definition = MetricDefinition {
+        name: metric.to_string(),
+        unit: None,
+        statistic: statistic
+            .or(known.map(|definition| definition.statistic))
+            .unwrap_or(StatisticType::Average),
+        category: MetricCategory::Core,
+    };
+
+    let client = AwsSessionManager::cloudwatch_client().await;
+    let end_time = SystemTime::now();
+    let period = calculate_period_seconds(&range);
+
+    // One request returns at most 1440 datapoints; longer ranges are fetched in windows
+    let window = Duration::from_secs(period.max(1) as u64 * MAX_DATAPOINTS_PER_REQUEST);
+    let mut start_time = end_time - range.duration();
+    let (mut timestamps, mut values) = (Vec::new(), Vec::new());
+    while start_time < end_time {
+        let window_end = (start_time + window).min(end_time);
+        let (window_values, window_timestamps) = fetch_metric_series(
+            &client,
+            provider.get_service_namespace(),
+            &definition,
+            dimensions.clone(),
+            start_time,
+            window_end,
+            period,
+        )
+        .await
+        .map_err(|reason| anyhow!("Failed to fetch {}: {reason}", definition.name))?;
+        values.extend(window_values);
+        timestamps.extend(window_timestamps);
+        start_time = window_end;
+    }
+    Ok((timestamps, values))
+}
+
+/// Datapoints as JSON, or as an RFC 3339 timestamp and value per line
+pub fn render_datapoints(datapoints: &[Datapoint], json: bool) -> Result<String> {
+    if json {
+        return Ok(serde_json::to_string_pretty(datapoints)? + "\n");
+    }
+    Ok(datapoints
+        .iter()
+        .map(|point| format!("{}\t{}\n", point.timestamp, point.value))
+        .collect())
+}
+
+/// Print one line of key metrics every `interval` until the process is interrupted
+pub async fn watch_instance(identifier: &str, interval: Duration) -> Result<()> {
+    loop {
+        let series = fetch_key_metrics(identifier, Duration::from_secs(15 * 60), 60).await;
+        let values: Vec<String> = SNAPSHOT_METRICS
+            .iter()
+            .zip(&series)
+            .map(|((_, name, unit), (latest, history, _))| {
+                let value = if history.is_empty() {
+                    "-".to_string()
+                } else {
+                    format_value(*latest, unit)
+                };
+                format!("{name}: {value}")
+            })
+            .collect();
+        println!(
+            "{}  {identifier}  {}",
+            locale().time(&Local::now(), true),
+            values.join("  ")
+        );
+        tokio::time::sleep(interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_datapoints_render_as_text_and_json() {
+        let points = vec![Datapoint {
+            timestamp: "2023-11-14T22:13:20Z".to_string(),
+            value: 42.5,
+        }];
+        assert_eq!(
+            render_datapoints(&points, false).unwrap(),
+            "2023-11-14T22:13:20Z\t42.5\n"
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&render_datapoints(&points, true).unwrap()).unwrap();
+        assert_eq!(json[0]["value"], 42.5);
+        assert!(parse_statistic("p99").is_err());
+    }
+}
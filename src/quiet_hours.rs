@@ -0,0 +1,166 @@
+// Quiet hours
+//
+// A terminal left open overnight keeps polling CloudWatch and keeps putting
+// up reminders nobody is there to read. Between `start` and `end`, in local
+// time, auto-refresh and the watchlist run no more often than
+// `refresh_interval_secs`, and the alarm maintenance reminder and job toasts
+// are written to a log instead of shown:
+//
+//   [quiet_hours]
+//   start = "22:00"
+//   end = "07:00"
+//   refresh_interval_secs = 600
+//
+// The log is notifications.log in the state directory, e.g.
+// ~/.local/state/awscw/notifications.log. When quiet hours end, the status
+// line says how many notifications were held back there.
+
+use crate::config::duration_from_secs;
+use anyhow::{Context, Result};
+use chrono::{Local, NaiveTime, Utc};
+use serde::{Deserialize, Deserializer};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const LOG_FILE: &str = "notifications.log";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct QuietHoursConfig {
+    #[serde(deserialize_with = "time_of_day")]
+    pub start: Option<NaiveTime>,
+    #[serde(deserialize_with = "time_of_day")]
+    pub end: Option<NaiveTime>, // Before `start` for hours that run past midnight
+    #[serde(
+        rename = "refresh_interval_secs",
+        deserialize_with = "duration_from_secs"
+    )]
+    pub refresh_interval: Duration,
+}
+
+impl Default for QuietHoursConfig {
+    fn default() -> Self {
+        Self {
+            start: None,
+            end: None,
+            refresh_interval: Duration::from_secs(600),
+        }
+    }
+}
+
+impl QuietHoursConfig {
+    /// Whether `time` falls in quiet hours; never when either end is unset
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        let (Some(start), Some(end)) = (self.start, self.end) else {
+            return false;
+        };
+        if start <= end {
+            start <= time && time < end
+        } else {
+            time >= start || time < end
+        }
+    }
+
+    pub fn is_now(&self) -> bool {
+        self.contains(Local::now().time())
+    }
+}
+
+/// Notifications kept off the screen during the current quiet hours
+#[derive(Debug, Default)]
+pub struct Held {
+    pub count: usize,
+    pub quiet: bool, // Whether quiet hours were on at the last check
+}
+
+impl Held {
+    /// Log a notification instead of showing it; a failed write only loses the line
+    pub fn hold(&mut self, text: &str) {
+        let _ = append(text);
+        self.count += 1;
+    }
+
+    /// Note whether quiet hours are on, returning how many were held once they end
+    pub fn update(&mut self, quiet: bool) -> Option<usize> {
+        let ended = self.quiet && !quiet;
+        self.quiet = quiet;
+        if !ended || self.count == 0 {
+            return None;
+        }
+        Some(std::mem::take(&mut self.count))
+    }
+}
+
+fn append(text: &str) -> Result<()> {
+    let path = log_path().context("No state directory for the notification log")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    writeln!(file, "{} {text}", Utc::now().format("%Y-%m-%dT%H:%M:%SZ"))?;
+    Ok(())
+}
+
+/// Location of the log of held notifications
+pub fn log_path() -> Option<PathBuf> {
+    dirs::state_dir()
+        .or_else(dirs::data_local_dir)
+        .map(|dir| dir.join("awscw").join(LOG_FILE))
+}
+
+fn time_of_day<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<NaiveTime>, D::Error> {
+    let Some(text) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    NaiveTime::parse_from_str(&text, "%H:%M")
+        .map(Some)
+        .map_err(|_| {
+            serde::de::Error::custom(format!("expected a time like \"22:00\", got {text:?}"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quiet_hours_wrap_past_midnight() {
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let overnight: QuietHoursConfig = toml::from_str(
+            r#"
+            start = "22:00"
+            end = "07:00"
+            "#,
+        )
+        .unwrap();
+        assert!(overnight.contains(at(23, 30)));
+        assert!(overnight.contains(at(2, 0)));
+        assert!(!overnight.contains(at(7, 0)));
+        assert!(!overnight.contains(at(12, 0)));
+
+        let lunch = QuietHoursConfig {
+            start: Some(at(12, 0)),
+            end: Some(at(13, 0)),
+            ..QuietHoursConfig::default()
+        };
+        assert!(lunch.contains(at(12, 30)));
+        assert!(!lunch.contains(at(23, 0)));
+        assert!(!QuietHoursConfig::default().contains(at(2, 0)));
+        assert!(toml::from_str::<QuietHoursConfig>(r#"start = "10pm""#).is_err());
+
+        let mut held = Held {
+            count: 2,
+            quiet: true,
+        };
+        assert_eq!(held.update(true), None);
+        assert_eq!(held.update(false), Some(2));
+        assert_eq!(held.update(false), None);
+    }
+}
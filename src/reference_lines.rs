@@ -0,0 +1,211 @@
+// Reference lines pinned on the detail charts
+//
+// A pinned line marks a value worth reading a series against, such as a
+// connection limit or a latency SLA. Lines are kept per metric, keyed by the
+// CloudWatch metric name, with values in the metric's CloudWatch unit
+// (latency in seconds, storage in bytes):
+//
+//   [reference_lines]
+//   DatabaseConnections = [{ label = "max_connections", value = 500 }]
+//   FreeStorageSpace = [{ label = "headroom", value = 10737418240, bound = "min" }]
+//
+// A "max" line (the default) is crossed by values above it, a "min" line by
+// values below it. `P` on a chart pins a line typed as `label=value`, where
+// the value may carry a unit such as 200ms or 10GB, and writes the section
+// back to config.toml, leaving the rest of the file as it was.
+
+use crate::config;
+use crate::models::MetricType;
+use crate::ui::components::metric_utils::{format_value, get_metric_unit};
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use toml_edit::{Array, DocumentMut, InlineTable, Item};
+
+const KIB: f64 = 1024.0;
+
+/// Lines pinned on each metric's chart, by CloudWatch metric name
+pub type ReferenceLines = BTreeMap<String, Vec<PinnedLine>>;
+
+/// Which side of a line values should stay on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Bound {
+    #[default]
+    Max,
+    Min,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PinnedLine {
+    pub label: String,
+    pub value: f64,
+    #[serde(default)]
+    pub bound: Bound,
+}
+
+impl PinnedLine {
+    /// Whether `value` is on the wrong side of the line
+    pub fn crossed_by(&self, value: f64) -> bool {
+        match self.bound {
+            Bound::Max => value > self.value,
+            Bound::Min => value < self.value,
+        }
+    }
+
+    /// Label and value in the metric's unit, e.g. "SLA 200.00 ms"
+    pub fn describe(&self, unit: &str) -> String {
+        format!("{} {}", self.label, format_value(self.value, unit))
+    }
+}
+
+/// Parse a line typed as `label=value`, or `min label=value` for a minimum
+///
+/// The value may carry a unit that fits the metric: s, ms or us for seconds,
+/// KB, MB or GB for bytes and % for percentages.
+pub fn parse_line(input: &str, unit: &str) -> Result<PinnedLine> {
+    let input = input.trim();
+    let (bound, rest) = match input.strip_prefix("min ") {
+        Some(rest) => (Bound::Min, rest),
+        None => (Bound::Max, input.strip_prefix("max ").unwrap_or(input)),
+    };
+    let (label, value) = rest
+        .rsplit_once('=')
+        .ok_or_else(|| anyhow!("type label=value, e.g. max_connections=500"))?;
+    let label = label.trim();
+    if label.is_empty() {
+        return Err(anyhow!("the line needs a label"));
+    }
+    Ok(PinnedLine {
+        label: label.to_string(),
+        value: parse_value(value.trim(), unit)?,
+        bound,
+    })
+}
+
+/// A number with an optional unit suffix, converted to the metric's CloudWatch unit
+fn parse_value(text: &str, unit: &str) -> Result<f64> {
+    let lower = text.to_ascii_lowercase();
+    let split = lower
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))
+        .unwrap_or(lower.len());
+    let (number, suffix) = lower.split_at(split);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| anyhow!("'{text}' is not a number"))?;
+    let scale = match (unit, suffix.trim()) {
+        (_, "") => 1.0,
+        ("Seconds", "s") | ("Percent", "%") => 1.0,
+        ("Seconds", "ms") => 1e-3,
+        ("Seconds", "us") => 1e-6,
+        ("Bytes" | "Bytes/Second", "kb") => KIB,
+        ("Bytes" | "Bytes/Second", "mb") => KIB * KIB,
+        ("Bytes" | "Bytes/Second", "gb") => KIB * KIB * KIB,
+        (_, suffix) => return Err(anyhow!("'{suffix}' is not a unit of this metric")),
+    };
+    Ok(number * scale)
+}
+
+/// One-line prompt pinning a line on the charted metric
+#[derive(Debug, Clone)]
+pub struct PinPrompt {
+    pub metric: MetricType,
+    pub text: String,
+}
+
+impl PinPrompt {
+    pub fn new(metric: MetricType) -> Self {
+        Self {
+            metric,
+            text: String::new(),
+        }
+    }
+
+    pub fn unit(&self) -> &'static str {
+        get_metric_unit(&self.metric)
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        self.text.push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.text.pop();
+    }
+}
+
+/// Write every metric's lines to `[reference_lines]` in config.toml
+pub fn save(lines: &ReferenceLines) -> Result<PathBuf> {
+    config::update_config_file(|document| write_to(lines, document))
+}
+
+fn write_to(lines: &ReferenceLines, document: &mut DocumentMut) {
+    let section = document
+        .entry("reference_lines")
+        .or_insert_with(toml_edit::table);
+    let Some(section) = section.as_table_mut() else {
+        return;
+    };
+    section.clear();
+    for (metric, metric_lines) in lines.iter().filter(|(_, lines)| !lines.is_empty()) {
+        let mut array = Array::new();
+        for line in metric_lines {
+            let mut entry = InlineTable::new();
+            entry.insert("label", line.label.as_str().into());
+            entry.insert("value", line.value.into());
+            if line.bound == Bound::Min {
+                entry.insert("bound", "min".into());
+            }
+            array.push(entry);
+        }
+        section.insert(metric, Item::Value(array.into()));
+    }
+    if section.is_empty() {
+        document.remove("reference_lines");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_are_parsed_with_units_and_written_back() {
+        let sla = parse_line("SLA 200ms=200ms", "Seconds").unwrap();
+        assert_eq!(sla.label, "SLA 200ms");
+        assert!((sla.value - 0.2).abs() < 1e-12);
+        assert!(sla.crossed_by(0.25) && !sla.crossed_by(0.1));
+
+        let headroom = parse_line("min headroom = 10GB", "Bytes").unwrap();
+        assert_eq!(headroom.bound, Bound::Min);
+        assert_eq!(headroom.value, 10.0 * KIB * KIB * KIB);
+        assert!(headroom.crossed_by(5.0 * KIB * KIB * KIB));
+
+        assert!(parse_line("max_connections", "Count").is_err());
+        assert!(parse_line("=500", "Count").is_err());
+        assert!(parse_line("limit=5GB", "Seconds").is_err());
+
+        let lines = ReferenceLines::from([
+            (
+                "DatabaseConnections".to_string(),
+                vec![parse_line("max_connections=500", "Count").unwrap()],
+            ),
+            ("FreeStorageSpace".to_string(), vec![headroom]),
+        ]);
+        let mut document: DocumentMut = "[theme]\npalette = \"default\"\n".parse().unwrap();
+        write_to(&lines, &mut document);
+        let text = document.to_string();
+        assert!(text.contains("palette = \"default\""));
+
+        #[derive(Deserialize)]
+        struct File {
+            reference_lines: ReferenceLines,
+        }
+        let file: File = toml::from_str(&text).unwrap();
+        assert_eq!(file.reference_lines, lines);
+
+        write_to(&ReferenceLines::new(), &mut document);
+        assert!(!document.to_string().contains("reference_lines"));
+    }
+}
@@ -0,0 +1,224 @@
+// Headless reports for scheduled runs
+//
+// `awscw report --output report.md` writes the fleet summary table followed by
+// a section per instance with the last three hours of the key metrics. Each
+// chart is written as an SVG file into a `<report>-charts` directory next to
+// the report and linked from it, so the report renders in any Markdown viewer
+// and can be produced from cron without a terminal. Reference lines pinned on
+// a metric are drawn across its chart.
+
+use crate::aws::load_rds_instances;
+use crate::config::Config;
+use crate::export::{self, ExportFormat, FleetRow, SNAPSHOT_METRICS};
+use crate::reference_lines::PinnedLine;
+use crate::ui::components::metric_utils::format_value;
+use crate::utils::formatting::locale;
+use crate::watchlist::Watchlist;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+const REPORT_WINDOW: Duration = Duration::from_secs(3 * 60 * 60);
+const REPORT_PERIOD_SECS: i32 = 300;
+
+/// Chart size in pixels and the margin kept free for labels
+const CHART_WIDTH: f64 = 640.0;
+const CHART_HEIGHT: f64 = 180.0;
+const CHART_MARGIN: f64 = 24.0;
+
+/// Write the report and its charts; returns the number of instances covered
+///
+/// With a watchlist only its RDS instances are included.
+pub async fn write_report(
+    output: &Path,
+    watchlist: Option<&Watchlist>,
+    config: &Config,
+) -> Result<usize> {
+    let mut instances = load_rds_instances().await?;
+    if let Some(watchlist) = watchlist {
+        instances.retain(|instance| watchlist.rds.contains(&instance.identifier));
+    }
+
+    let series = futures::future::join_all(instances.iter().map(|instance| {
+        export::fetch_key_metrics(&instance.identifier, REPORT_WINDOW, REPORT_PERIOD_SECS)
+    }))
+    .await;
+
+    let stem = output
+        .file_stem()
+        .map_or("report".into(), |stem| stem.to_string_lossy());
+    let charts_dir_name = format!("{stem}-charts");
+    let charts_dir = output.with_file_name(&charts_dir_name);
+    std::fs::create_dir_all(&charts_dir)
+        .with_context(|| format!("Failed to create {}", charts_dir.display()))?;
+
+    let rows: Vec<FleetRow> = instances
+        .iter()
+        .zip(&series)
+        .map(|(instance, series)| FleetRow::new(instance, series))
+        .collect();
+    let mut report = export::render(
+        ExportFormat::Markdown,
+        &rows,
+        &config.thresholds,
+        &config.reference_lines,
+        &Local::now(),
+    );
+
+    for (instance, series) in instances.iter().zip(&series) {
+        report += &format!("\n## {}\n\n", instance.identifier);
+        for ((metric, name, unit), (_, history, timestamps)) in SNAPSHOT_METRICS.iter().zip(series)
+        {
+            if history.is_empty() {
+                report += &format!("{name}: no data in the last 3 hours\n\n");
+                continue;
+            }
+            let file_name = format!("{}-{metric}.svg", instance.identifier);
+            let path = charts_dir.join(&file_name);
+            let pinned = config
+                .reference_lines
+                .get(*metric)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            std::fs::write(&path, svg_chart(name, unit, history, timestamps, pinned))
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            report += &format!("![{name}](<{charts_dir_name}/{file_name}>)\n\n");
+        }
+    }
+
+    std::fs::write(output, report)
+        .with_context(|| format!("Failed to write {}", output.display()))?;
+    Ok(instances.len())
+}
+
+/// Line chart of one series as a standalone SVG document, with its pinned lines
+fn svg_chart(
+    title: &str,
+    unit: &str,
+    values: &[f64],
+    timestamps: &[SystemTime],
+    pinned: &[PinnedLine],
+) -> String {
+    let max = values.iter().copied().fold(0.0_f64, f64::max);
+    // Pinned lines above the series stretch the scale so they stay on the chart
+    let scale = pinned
+        .iter()
+        .fold(max, |scale, line| scale.max(line.value))
+        .max(f64::EPSILON);
+    let plot_width = CHART_WIDTH - 2.0 * CHART_MARGIN;
+    let plot_height = CHART_HEIGHT - 2.0 * CHART_MARGIN;
+    let step = plot_width / (values.len().max(2) - 1) as f64;
+
+    let points: Vec<String> = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let x = CHART_MARGIN + i as f64 * step;
+            let y = CHART_MARGIN + plot_height * (1.0 - value / scale);
+            format!("{x:.1},{y:.1}")
+        })
+        .collect();
+    let right = CHART_WIDTH - CHART_MARGIN;
+    let lines: String = pinned
+        .iter()
+        .filter(|line| line.value >= 0.0)
+        .map(|line| {
+            let y = CHART_MARGIN + plot_height * (1.0 - line.value / scale);
+            format!(
+                concat!(
+                    r#"<line x1="{m}" y1="{y:.1}" x2="{right}" y2="{y:.1}" stroke="gray" stroke-dasharray="4 3"/>"#,
+                    "\n",
+                    r#"<text x="{right}" y="{label_y:.1}" text-anchor="end" fill="gray">{label}</text>"#,
+                    "\n"
+                ),
+                m = CHART_MARGIN,
+                right = right,
+                y = y,
+                label_y = y - 3.0,
+                label = escape_xml(&line.describe(unit)),
+            )
+        })
+        .collect();
+
+    let time = |timestamp: Option<&SystemTime>| {
+        timestamp
+            .map(|t| locale().time(&DateTime::<Local>::from(*t), false))
+            .unwrap_or_default()
+    };
+    let bottom = CHART_HEIGHT - 6.0;
+
+    format!(
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" font-family="sans-serif" font-size="11">"#,
+            "\n",
+            r#"<rect width="{w}" height="{h}" fill="white"/>"#,
+            "\n",
+            r#"<text x="{m}" y="15" font-size="13">{title}</text>"#,
+            "\n",
+            r#"<text x="{right}" y="15" text-anchor="end">max {max}</text>"#,
+            "\n",
+            "{lines}",
+            r#"<polyline fill="none" stroke="steelblue" stroke-width="1.5" points="{points}"/>"#,
+            "\n",
+            r#"<text x="{m}" y="{bottom}">{start}</text>"#,
+            "\n",
+            r#"<text x="{right}" y="{bottom}" text-anchor="end">{end}</text>"#,
+            "\n</svg>\n"
+        ),
+        w = CHART_WIDTH,
+        h = CHART_HEIGHT,
+        m = CHART_MARGIN,
+        right = right,
+        bottom = bottom,
+        title = escape_xml(title),
+        max = escape_xml(&format_value(max, unit)),
+        lines = lines,
+        points = points.join(" "),
+        start = time(timestamps.first()),
+        end = time(timestamps.last()),
+    )
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reference_lines::parse_line;
+
+    #[test]
+    fn test_svg_chart_scales_points_to_plot_area() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let timestamps = [start, start + Duration::from_secs(300)];
+        let svg = svg_chart("CPU Utilization", "Percent", &[0.0, 50.0], &timestamps, &[]);
+
+        // The first point sits on the baseline and the maximum at the top of the plot
+        assert!(svg.contains(r#"points="24.0,156.0 616.0,24.0""#));
+        assert!(svg.contains("max 50.0%"));
+        assert!(svg.starts_with("<svg"));
+
+        // An all-zero series with one point stays on the baseline without dividing by zero
+        let flat = svg_chart("Read & Write", "Count", &[0.0], &[], &[]);
+        assert!(flat.contains(r#"points="24.0,156.0""#));
+        assert!(flat.contains("Read &amp; Write"));
+
+        // A pinned line above the series raises the scale and is labeled
+        let pinned = [parse_line("SLO=100%", "Percent").unwrap()];
+        let svg = svg_chart(
+            "CPU Utilization",
+            "Percent",
+            &[0.0, 50.0],
+            &timestamps,
+            &pinned,
+        );
+        assert!(svg.contains(r#"points="24.0,156.0 616.0,90.0""#));
+        assert!(svg.contains(r#"y1="24.0""#));
+        assert!(svg.contains(">SLO 100.0%</text>"));
+        assert!(svg.contains("max 50.0%"));
+    }
+}
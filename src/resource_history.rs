@@ -0,0 +1,117 @@
+// Resources seen in earlier sessions
+//
+// Autoscaled replicas, temporary queues and torn-down stacks drop out of the
+// instance list once deleted, but CloudWatch keeps their metrics for months.
+// Every listing records the resources it returned and when; resources seen
+// before but missing now are listed after the live ones, greyed out with
+// when they were last seen, and open onto their metrics like any other.
+// Resources not seen for `RETENTION_SECS` are forgotten.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+const HISTORY_FILE: &str = "seen_resources.json";
+
+/// How long a resource that has gone stays listed
+const RETENTION_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// When each resource was last listed, by service and resource ID
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResourceHistory {
+    services: BTreeMap<String, BTreeMap<String, u64>>, // Seconds since the Unix epoch
+}
+
+impl ResourceHistory {
+    /// Stored history; missing or unreadable files start empty
+    pub fn load() -> Self {
+        history_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = history_path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        std::fs::write(&path, serde_json::to_string(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Record the resources a listing returned at `now`
+    ///
+    /// Returns those seen before but not listed now, with when they were last
+    /// seen, most recently seen first.
+    pub fn record<'a>(
+        &mut self,
+        service: &str,
+        listed: impl IntoIterator<Item = &'a str>,
+        now: u64,
+    ) -> Vec<(String, u64)> {
+        let seen = self.services.entry(service.to_string()).or_default();
+        seen.retain(|_, last_seen| now.saturating_sub(*last_seen) < RETENTION_SECS);
+        for id in listed {
+            seen.insert(id.to_string(), now);
+        }
+
+        let mut departed: Vec<_> = seen
+            .iter()
+            .filter(|(_, last_seen)| **last_seen < now)
+            .map(|(id, last_seen)| (id.clone(), *last_seen))
+            .collect();
+        departed.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        departed
+    }
+}
+
+/// Location of the history (e.g. `~/.local/share/awscw/seen_resources.json`)
+fn history_path() -> Option<PathBuf> {
+    dirs::data_local_dir().map(|dir| dir.join("awscw").join(HISTORY_FILE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_departed_resources_are_listed_until_forgotten() {
+        const DAY: u64 = 24 * 60 * 60;
+        let start = 1_700_000_000;
+        let mut history = ResourceHistory::default();
+
+        assert!(history
+            .record(
+                "RDS",
+                ["orders-db", "orders-db-replica-1", "batch-db"],
+                start
+            )
+            .is_empty());
+        history.record("RDS", ["orders-db", "orders-db-replica-1"], start + DAY);
+
+        // Both replicas are gone now; the more recently seen one comes first
+        let departed = history.record("RDS", ["orders-db"], start + 2 * DAY);
+        assert_eq!(
+            departed,
+            vec![
+                ("orders-db-replica-1".to_string(), start + DAY),
+                ("batch-db".to_string(), start),
+            ]
+        );
+
+        // Other services are tracked separately
+        assert!(history.record("SQS", ["jobs"], start + 2 * DAY).is_empty());
+
+        // A month after it was last seen, a resource is forgotten
+        let departed = history.record("RDS", ["orders-db"], start + 30 * DAY + 1);
+        assert_eq!(
+            departed,
+            vec![("orders-db-replica-1".to_string(), start + DAY)]
+        );
+    }
+}
@@ -0,0 +1,330 @@
+// User scripting hooks - Rhai scripts loaded from the config directory
+//
+// Scripts can define any of the following functions:
+//   fn derive(latest, history)  -> map of derived metric name => number
+//   fn health(latest, history)  -> array of strings or #{ level, message } maps
+//   fn summary(latest, history) -> string (or array of strings) for the header
+//
+// `latest` maps CloudWatch metric names (e.g. "CPUUtilization") to the most
+// recent value, including anything produced by `derive`. `history` maps the
+// same names to arrays of datapoints.
+//
+// Scripts only ever see copies of metric values: no AWS clients or file/module
+// access are registered with the engine, and runaway scripts are cut off by
+// operation and size limits.
+
+use crate::config::config_dir;
+use crate::models::MetricData;
+use rhai::{Array, Dynamic, Engine, Map, AST};
+use std::path::Path;
+
+const SCRIPTS_DIR: &str = "scripts";
+const SCRIPT_EXTENSION: &str = "rhai";
+
+// Sandbox limits - generous for metric arithmetic, small enough to stay responsive
+const MAX_OPERATIONS: u64 = 100_000;
+const MAX_CALL_LEVELS: usize = 32;
+const MAX_STRING_SIZE: usize = 4_096;
+const MAX_COLLECTION_SIZE: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthLevel {
+    Ok,
+    Warning,
+    Critical,
+}
+
+impl HealthLevel {
+    fn parse(level: &str) -> Self {
+        match level.to_ascii_lowercase().as_str() {
+            "ok" | "info" => HealthLevel::Ok,
+            "critical" | "crit" | "error" => HealthLevel::Critical,
+            _ => HealthLevel::Warning,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthFinding {
+    pub level: HealthLevel,
+    pub message: String,
+}
+
+/// Combined result of running every loaded script against one metrics snapshot
+#[derive(Debug, Clone, Default)]
+pub struct ScriptOutput {
+    pub derived: Vec<(String, f64)>,
+    pub health: Vec<HealthFinding>,
+    pub summary_lines: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+struct LoadedScript {
+    name: String,
+    ast: AST,
+}
+
+pub struct ScriptHost {
+    engine: Engine,
+    scripts: Vec<LoadedScript>,
+    pub load_errors: Vec<String>,
+}
+
+impl ScriptHost {
+    /// Load all `*.rhai` files from `<config dir>/scripts`
+    pub fn load() -> Self {
+        let sources = config_dir()
+            .map(|dir| read_script_sources(&dir.join(SCRIPTS_DIR)))
+            .unwrap_or_default();
+        Self::from_sources(sources)
+    }
+
+    /// Compile scripts from (name, source) pairs; failures are kept as load errors
+    pub fn from_sources(sources: Vec<(String, String)>) -> Self {
+        let engine = sandboxed_engine();
+        let mut scripts = Vec::new();
+        let mut load_errors = Vec::new();
+
+        for (name, source) in sources {
+            match engine.compile(&source) {
+                Ok(ast) => scripts.push(LoadedScript { name, ast }),
+                Err(e) => load_errors.push(format!("{name}: {e}")),
+            }
+        }
+
+        Self {
+            engine,
+            scripts,
+            load_errors,
+        }
+    }
+
+    /// Run the derive, health and summary hooks of every script
+    pub fn evaluate(&self, metrics: &MetricData) -> ScriptOutput {
+        let mut output = ScriptOutput {
+            errors: self.load_errors.clone(),
+            ..Default::default()
+        };
+        if self.scripts.is_empty() {
+            return output;
+        }
+
+        let (mut latest, history) = metric_maps(metrics);
+
+        // Derivations run first so health rules and summaries can use them
+        for script in &self.scripts {
+            match self.call_hook(script, "derive", &latest, &history) {
+                Ok(Some(value)) => match value.try_cast::<Map>() {
+                    Some(map) => {
+                        for (key, value) in map {
+                            if let Some(number) = as_number(&value) {
+                                output.derived.push((key.to_string(), number));
+                                latest.insert(key, Dynamic::from_float(number));
+                            }
+                        }
+                    }
+                    None => output
+                        .errors
+                        .push(format!("{}: derive() must return a map", script.name)),
+                },
+                Ok(None) => {}
+                Err(e) => output.errors.push(e),
+            }
+        }
+
+        for script in &self.scripts {
+            match self.call_hook(script, "health", &latest, &history) {
+                Ok(Some(value)) => output.health.extend(health_findings(value)),
+                Ok(None) => {}
+                Err(e) => output.errors.push(e),
+            }
+
+            match self.call_hook(script, "summary", &latest, &history) {
+                Ok(Some(value)) => output.summary_lines.extend(string_lines(value)),
+                Ok(None) => {}
+                Err(e) => output.errors.push(e),
+            }
+        }
+
+        output
+    }
+
+    /// Call a hook if the script defines it; returns `Ok(None)` when absent
+    fn call_hook(
+        &self,
+        script: &LoadedScript,
+        hook: &str,
+        latest: &Map,
+        history: &Map,
+    ) -> Result<Option<Dynamic>, String> {
+        let Some(arity) = script
+            .ast
+            .iter_functions()
+            .find(|f| f.name == hook)
+            .map(|f| f.params.len())
+        else {
+            return Ok(None);
+        };
+
+        let mut scope = rhai::Scope::new();
+        let result = match arity {
+            1 => self
+                .engine
+                .call_fn::<Dynamic>(&mut scope, &script.ast, hook, (latest.clone(),)),
+            _ => self.engine.call_fn::<Dynamic>(
+                &mut scope,
+                &script.ast,
+                hook,
+                (latest.clone(), history.clone()),
+            ),
+        };
+
+        result
+            .map(Some)
+            .map_err(|e| format!("{}: {hook}() failed: {e}", script.name))
+    }
+}
+
+/// Engine with no I/O, no module imports and bounded execution
+fn sandboxed_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.on_print(|_| {});
+    engine.on_debug(|_, _, _| {});
+    engine.set_module_resolver(rhai::module_resolvers::DummyModuleResolver::new());
+    engine.disable_symbol("eval");
+    engine.set_max_operations(MAX_OPERATIONS);
+    engine.set_max_call_levels(MAX_CALL_LEVELS);
+    engine.set_max_string_size(MAX_STRING_SIZE);
+    engine.set_max_array_size(MAX_COLLECTION_SIZE);
+    engine.set_max_map_size(MAX_COLLECTION_SIZE);
+    engine
+}
+
+fn read_script_sources(dir: &Path) -> Vec<(String, String)> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == SCRIPT_EXTENSION))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let name = path.file_name()?.to_string_lossy().to_string();
+            std::fs::read_to_string(&path)
+                .ok()
+                .map(|source| (name, source))
+        })
+        .collect()
+}
+
+/// Build the `latest` and `history` maps handed to script hooks
+fn metric_maps(metrics: &MetricData) -> (Map, Map) {
+    let mut latest = Map::new();
+    let mut history = Map::new();
+
+    for metric in metrics.get_available_metrics() {
+        let values = metrics.get_metric_history(&metric);
+        if let Some(&last) = values.last() {
+            latest.insert(metric.metric_name().into(), Dynamic::from_float(last));
+        }
+        let array: Array = values.iter().map(|&v| Dynamic::from_float(v)).collect();
+        history.insert(metric.metric_name().into(), array.into());
+    }
+
+    (latest, history)
+}
+
+fn as_number(value: &Dynamic) -> Option<f64> {
+    value
+        .as_float()
+        .ok()
+        .or_else(|| value.as_int().ok().map(|i| i as f64))
+}
+
+fn health_findings(value: Dynamic) -> Vec<HealthFinding> {
+    let items = match value.clone().try_cast::<Array>() {
+        Some(array) => array,
+        None => vec![value],
+    };
+
+    items
+        .into_iter()
+        .filter_map(|item| {
+            if let Some(map) = item.clone().try_cast::<Map>() {
+                let message = map.get("message")?.to_string();
+                let level = map
+                    .get("level")
+                    .map(|l| HealthLevel::parse(&l.to_string()))
+                    .unwrap_or(HealthLevel::Warning);
+                Some(HealthFinding { level, message })
+            } else if item.is_string() {
+                Some(HealthFinding {
+                    level: HealthLevel::Warning,
+                    message: item.to_string(),
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn string_lines(value: Dynamic) -> Vec<String> {
+    match value.clone().try_cast::<Array>() {
+        Some(array) => array.into_iter().map(|v| v.to_string()).collect(),
+        None if value.is_unit() => Vec::new(),
+        None => vec![value.to_string()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_metrics() -> MetricData {
+        MetricData {
+            read_iops_history: vec![100.0, 120.0],
+            write_iops_history: vec![40.0, 60.0],
+            cpu_history: vec![50.0, 95.0],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_hooks_derive_health_and_summary() {
+        let host = ScriptHost::from_sources(vec![(
+            "iops.rhai".to_string(),
+            r#"
+                fn derive(m) { #{ TotalIOPS: m.ReadIOPS + m.WriteIOPS } }
+                fn health(m, h) {
+                    if m.CPUUtilization > 90.0 { [#{ level: "critical", message: "CPU hot" }] } else { [] }
+                }
+                fn summary(m) { `IOPS ${m.TotalIOPS}` }
+            "#
+            .to_string(),
+        )]);
+
+        let output = host.evaluate(&sample_metrics());
+        assert!(output.errors.is_empty(), "{:?}", output.errors);
+        assert_eq!(output.derived, vec![("TotalIOPS".to_string(), 180.0)]);
+        assert_eq!(output.health[0].level, HealthLevel::Critical);
+        assert_eq!(output.summary_lines, vec!["IOPS 180.0".to_string()]);
+    }
+
+    #[test]
+    fn test_runaway_script_is_stopped() {
+        let host = ScriptHost::from_sources(vec![(
+            "loop.rhai".to_string(),
+            "fn summary(m) { loop {} }".to_string(),
+        )]);
+
+        let output = host.evaluate(&sample_metrics());
+        assert_eq!(output.errors.len(), 1);
+        assert!(output.summary_lines.is_empty());
+    }
+}
@@ -1,14 +1,18 @@
+use crate::models::ChartImage;
+use crate::ui::graphics::GraphicsProtocol;
 use anyhow::Result;
 use crossterm::{
+    cursor::{MoveTo, RestorePosition, SavePosition},
     event::{DisableMouseCapture, EnableMouseCapture},
-    execute,
+    execute, queue,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::{backend::CrosstermBackend, Terminal};
-use std::io;
+use ratatui::{backend::CrosstermBackend, layout::Rect, Terminal};
+use std::io::{self, Write};
 
 pub struct TerminalManager {
     terminal: Terminal<CrosstermBackend<std::io::Stdout>>,
+    shown_image: Option<(String, Rect)>, // Chart image on screen and the cells it covers
 }
 
 impl TerminalManager {
@@ -19,7 +23,10 @@ impl TerminalManager {
         let backend = CrosstermBackend::new(stdout);
         let terminal = Terminal::new(backend)?;
 
-        Ok(Self { terminal })
+        Ok(Self {
+            terminal,
+            shown_image: None,
+        })
     }
 
     pub fn draw<F>(&mut self, f: F) -> Result<()>
@@ -30,6 +37,62 @@ impl TerminalManager {
         Ok(())
     }
 
+    /// Remove the chart image on screen unless the next frame shows the same one in the same place
+    ///
+    /// Call before drawing: for sixel the screen is cleared so the frame is drawn in full.
+    pub fn release_image(
+        &mut self,
+        protocol: GraphicsProtocol,
+        next: Option<(&str, Rect)>,
+    ) -> Result<()> {
+        let Some(shown) = &self.shown_image else {
+            return Ok(());
+        };
+        if next.is_some_and(|(key, area)| shown.0 == key && shown.1 == area) {
+            return Ok(());
+        }
+        match protocol.delete_sequence() {
+            Some(delete) => {
+                let backend = self.terminal.backend_mut();
+                backend.write_all(delete.as_bytes())?;
+                backend.flush()?;
+            }
+            None => self.terminal.clear()?,
+        }
+        self.shown_image = None;
+        Ok(())
+    }
+
+    /// Draw the chart image into its cells after the frame, unless it is already there
+    pub fn show_image(
+        &mut self,
+        protocol: GraphicsProtocol,
+        image: Option<(&ChartImage, Rect)>,
+    ) -> Result<()> {
+        let Some((image, area)) = image else {
+            return Ok(());
+        };
+        let Some(raster) = &image.image else {
+            return Ok(());
+        };
+        if self
+            .shown_image
+            .as_ref()
+            .is_some_and(|(key, shown)| *key == image.key && *shown == area)
+        {
+            return Ok(());
+        }
+
+        let sequence = protocol.encode(raster, area.width, area.height)?;
+        let backend = self.terminal.backend_mut();
+        queue!(backend, SavePosition, MoveTo(area.x, area.y))?;
+        backend.write_all(sequence.as_bytes())?;
+        queue!(backend, RestorePosition)?;
+        backend.flush()?;
+        self.shown_image = Some((image.key.clone(), area));
+        Ok(())
+    }
+
     pub fn restore(&mut self) -> Result<()> {
         disable_raw_mode()?;
         execute!(
@@ -0,0 +1,474 @@
+// Health thresholds for RDS metrics
+//
+// A metric with a threshold is shown green, yellow or red by its latest
+// value. Which side is bad follows from the order of the two values: a
+// critical value below the warning value (e.g. Free Storage Space) means
+// lower is worse. Built-in thresholds can be overridden per metric, keyed by
+// the metric's display name:
+//
+//   [thresholds.rds]
+//   "CPU Utilization" = { warning = 70, critical = 90 }
+//
+// The threshold editor (`T`) edits the same section and writes it back to
+// config.toml, leaving the rest of the file as it was.
+
+use crate::aws::metric_fetcher::fetch_comprehensive_metric;
+use crate::aws::metric_types::MetricFetchParams;
+use crate::aws::session::AwsSessionManager;
+use crate::config;
+use crate::models::MetricType;
+use crate::ui::components::metric_utils::get_metric_unit;
+use crate::ui::theme::Severity;
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+use toml_edit::{DocumentMut, InlineTable, Item, Table};
+
+/// Window and period of the latest values shown in the editor preview
+const PREVIEW_WINDOW: Duration = Duration::from_secs(15 * 60);
+const PREVIEW_PERIOD_SECS: i32 = 60;
+
+const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Threshold {
+    pub warning: f64,
+    pub critical: f64,
+}
+
+impl Threshold {
+    pub fn lower_is_worse(&self) -> bool {
+        self.critical < self.warning
+    }
+
+    pub fn severity(&self, value: f64) -> Severity {
+        let beyond = |threshold: f64| {
+            if self.lower_is_worse() {
+                value < threshold
+            } else {
+                value > threshold
+            }
+        };
+        if beyond(self.critical) {
+            Severity::Critical
+        } else if beyond(self.warning) {
+            Severity::Warning
+        } else {
+            Severity::Ok
+        }
+    }
+
+    fn validate(&self, unit: &str) -> Result<()> {
+        for value in [self.warning, self.critical] {
+            if !value.is_finite() || value < 0.0 {
+                return Err(anyhow!("values must be zero or more"));
+            }
+            if unit == "Percent" && value > 100.0 {
+                return Err(anyhow!("values are percentages, at most 100"));
+            }
+        }
+        if self.warning == self.critical {
+            return Err(anyhow!("warning and critical must differ"));
+        }
+        Ok(())
+    }
+}
+
+/// Threshold used when the config does not set one
+pub fn builtin_threshold(metric_name: &str) -> Option<Threshold> {
+    let (warning, critical) = match metric_name {
+        "CPU Utilization" => (60.0, 80.0),
+        // Assume > 1000 is high, > 500 is moderate
+        "Database Connections" => (500.0, 1000.0),
+        // Latency in seconds - > 0.1s is bad, > 0.05s is moderate
+        "Read Latency" | "Write Latency" => (0.05, 0.1),
+        // For storage/memory, lower is worse (< 1GB critical, < 5GB warning)
+        "Free Storage Space" | "Freeable Memory" => (5.0 * GIB, GIB),
+        "Burst Balance" => (50.0, 20.0),
+        // > 5 minutes critical, > 1 minute warning
+        "Replica Lag" => (60.0, 300.0),
+        _ => return None,
+    };
+    Some(Threshold { warning, critical })
+}
+
+/// Thresholds from the config file, on top of the built-in ones
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Thresholds {
+    pub rds: BTreeMap<String, Threshold>,
+}
+
+impl Thresholds {
+    pub fn get(&self, metric_name: &str) -> Option<Threshold> {
+        self.rds
+            .get(metric_name)
+            .copied()
+            .or_else(|| builtin_threshold(metric_name))
+    }
+
+    /// None for metrics without a threshold
+    pub fn severity(&self, metric_name: &str, value: f64) -> Option<Severity> {
+        self.get(metric_name)
+            .map(|threshold| threshold.severity(value))
+    }
+
+    /// Replace the `[thresholds]` section of config.toml with these
+    pub fn save(&self) -> Result<PathBuf> {
+        config::update_config_file(|document| self.write_to(document))
+    }
+
+    fn write_to(&self, document: &mut DocumentMut) {
+        if self.rds.is_empty() {
+            if let Some(section) = document.get_mut("thresholds").and_then(Item::as_table_mut) {
+                section.remove("rds");
+                if section.is_empty() {
+                    document.remove("thresholds");
+                }
+            }
+            return;
+        }
+
+        let mut rds = Table::new();
+        for (name, threshold) in &self.rds {
+            let mut entry = InlineTable::new();
+            entry.insert("warning", threshold.warning.into());
+            entry.insert("critical", threshold.critical.into());
+            rds.insert(name, toml_edit::value(entry));
+        }
+        let section = document
+            .entry("thresholds")
+            .or_insert_with(toml_edit::table);
+        if let Some(section) = section.as_table_mut() {
+            section.set_implicit(true);
+            section.insert("rds", Item::Table(rds));
+        } else {
+            let mut section = Table::new();
+            section.set_implicit(true);
+            section.insert("rds", Item::Table(rds));
+            document.insert("thresholds", Item::Table(section));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorField {
+    Warning,
+    Critical,
+}
+
+/// One metric in the threshold editor
+#[derive(Debug, Clone)]
+pub struct EditorRow {
+    pub metric: MetricType,
+    pub warning: String, // Edited as text, validated on save
+    pub critical: String,
+    /// Latest value of each listed instance; None until fetched
+    pub latest: Option<Vec<(String, f64)>>,
+}
+
+impl EditorRow {
+    fn new(metric: MetricType, threshold: Option<Threshold>) -> Self {
+        let (warning, critical) = threshold.map_or_else(Default::default, |threshold| {
+            (
+                format_threshold(threshold.warning),
+                format_threshold(threshold.critical),
+            )
+        });
+        Self {
+            metric,
+            warning,
+            critical,
+            latest: None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.metric.display_name()
+    }
+
+    /// The threshold as entered; None when both values are empty, which
+    /// only metrics without a built-in threshold allow
+    pub fn threshold(&self) -> Result<Option<Threshold>> {
+        let parse = |text: &str, label: &str| {
+            text.trim()
+                .parse::<f64>()
+                .map_err(|_| anyhow!("{label} is not a number"))
+        };
+        match (self.warning.trim(), self.critical.trim()) {
+            ("", "") if builtin_threshold(self.name()).is_none() => Ok(None),
+            ("", _) | (_, "") => Err(anyhow!(
+                "set both warning and critical (d restores the default)"
+            )),
+            (warning, critical) => {
+                let threshold = Threshold {
+                    warning: parse(warning, "warning")?,
+                    critical: parse(critical, "critical")?,
+                };
+                threshold.validate(get_metric_unit(&self.metric))?;
+                Ok(Some(threshold))
+            }
+        }
+    }
+
+    /// Instances that would be red and yellow with the threshold as entered
+    pub fn preview(&self) -> Option<Preview<'_>> {
+        let threshold = self.threshold().ok().flatten()?;
+        let latest = self.latest.as_ref()?;
+        let with = |severity| {
+            latest
+                .iter()
+                .filter(|(_, value)| threshold.severity(*value) == severity)
+                .collect()
+        };
+        Some(Preview {
+            red: with(Severity::Critical),
+            yellow: with(Severity::Warning),
+        })
+    }
+}
+
+/// Instances with their latest value, by the state a threshold puts them in
+pub struct Preview<'a> {
+    pub red: Vec<&'a (String, f64)>,
+    pub yellow: Vec<&'a (String, f64)>,
+}
+
+/// In-app editor for the RDS thresholds, listing every RDS metric
+#[derive(Debug, Clone)]
+pub struct ThresholdEditor {
+    pub rows: Vec<EditorRow>,
+    pub selected: usize,
+    pub field: EditorField,
+    /// Instances previewed against the thresholds
+    pub instances: Vec<String>,
+}
+
+impl ThresholdEditor {
+    pub fn new(thresholds: &Thresholds, instances: Vec<String>) -> Self {
+        Self {
+            rows: MetricType::ALL
+                .iter()
+                .map(|metric| EditorRow::new(metric.clone(), thresholds.get(metric.display_name())))
+                .collect(),
+            selected: 0,
+            field: EditorField::Warning,
+            instances,
+        }
+    }
+
+    pub fn selected_row(&self) -> &EditorRow {
+        &self.rows[self.selected]
+    }
+
+    pub fn next_row(&mut self) {
+        self.selected = (self.selected + 1) % self.rows.len();
+    }
+
+    pub fn previous_row(&mut self) {
+        self.selected = (self.selected + self.rows.len() - 1) % self.rows.len();
+    }
+
+    pub fn switch_field(&mut self) {
+        self.field = match self.field {
+            EditorField::Warning => EditorField::Critical,
+            EditorField::Critical => EditorField::Warning,
+        };
+    }
+
+    fn focused_text(&mut self) -> &mut String {
+        let row = &mut self.rows[self.selected];
+        match self.field {
+            EditorField::Warning => &mut row.warning,
+            EditorField::Critical => &mut row.critical,
+        }
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        if c.is_ascii_digit() || c == '.' {
+            self.focused_text().push(c);
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        self.focused_text().pop();
+    }
+
+    /// Put the selected metric back to its built-in threshold, or none
+    pub fn reset_row(&mut self) {
+        let row = &mut self.rows[self.selected];
+        let latest = row.latest.take();
+        *row = EditorRow::new(row.metric.clone(), builtin_threshold(row.name()));
+        row.latest = latest;
+    }
+
+    /// Metrics with a threshold whose preview values are not loaded yet
+    pub fn missing_previews(&self) -> Vec<MetricType> {
+        self.rows
+            .iter()
+            .filter(|row| row.latest.is_none() && matches!(row.threshold(), Ok(Some(_))))
+            .map(|row| row.metric.clone())
+            .collect()
+    }
+
+    pub fn set_latest(&mut self, metric: &MetricType, latest: Vec<(String, f64)>) {
+        if let Some(row) = self.rows.iter_mut().find(|row| row.metric == *metric) {
+            row.latest = Some(latest);
+        }
+    }
+
+    /// Validated thresholds; only those differing from the built-in ones are kept
+    pub fn thresholds(&self) -> Result<Thresholds> {
+        let mut rds = BTreeMap::new();
+        for row in &self.rows {
+            let threshold = row
+                .threshold()
+                .map_err(|e| anyhow!("{}: {e}", row.name()))?;
+            if let Some(threshold) = threshold {
+                if Some(threshold) != builtin_threshold(row.name()) {
+                    rds.insert(row.name().to_string(), threshold);
+                }
+            }
+        }
+        Ok(Thresholds { rds })
+    }
+}
+
+/// Latest value of a metric for each instance that reported it recently
+pub async fn fetch_latest(metric: &MetricType, instances: &[String]) -> Vec<(String, f64)> {
+    let client = AwsSessionManager::metric_client().await;
+    let end_time = SystemTime::now();
+    let start_time = end_time - PREVIEW_WINDOW;
+
+    let series = futures::future::join_all(instances.iter().map(|identifier| {
+        fetch_comprehensive_metric(
+            &client,
+            MetricFetchParams {
+                metric_name: metric.metric_name().to_string(),
+                namespace: "AWS/RDS".to_string(),
+                instance_id: identifier.clone(),
+                unit: None,
+            },
+            start_time,
+            end_time,
+            PREVIEW_PERIOD_SECS,
+        )
+    }))
+    .await;
+    instances
+        .iter()
+        .zip(series)
+        .filter(|(_, (_, history, _))| !history.is_empty())
+        .map(|(identifier, (latest, _, _))| (identifier.clone(), latest))
+        .collect()
+}
+
+fn format_threshold(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor_row(name: &str) -> usize {
+        MetricType::ALL
+            .iter()
+            .position(|metric| metric.display_name() == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_overrides_validation_and_preview() {
+        let thresholds = Thresholds {
+            rds: BTreeMap::from([(
+                "CPU Utilization".to_string(),
+                Threshold {
+                    warning: 70.0,
+                    critical: 90.0,
+                },
+            )]),
+        };
+        assert_eq!(
+            thresholds.severity("CPU Utilization", 75.0),
+            Some(Severity::Warning)
+        );
+        assert_eq!(
+            thresholds.severity("Free Storage Space", 0.5 * GIB),
+            Some(Severity::Critical)
+        );
+        assert_eq!(thresholds.severity("Read IOPS", 1e6), None);
+
+        let mut editor = ThresholdEditor::new(&thresholds, vec![]);
+        editor.selected = editor_row("CPU Utilization");
+        editor.set_latest(
+            &MetricType::CpuUtilization,
+            vec![("a".to_string(), 95.0), ("b".to_string(), 75.0)],
+        );
+        let preview = editor.selected_row().preview().unwrap();
+        assert_eq!((preview.red.len(), preview.yellow.len()), (1, 1));
+
+        // Back to the built-in threshold, nothing is written for it
+        editor.reset_row();
+        assert!(editor.thresholds().unwrap().rds.is_empty());
+
+        editor.selected = editor_row("Read IOPS");
+        editor.input_char('5');
+        assert!(editor.thresholds().is_err()); // Critical is missing
+        editor.switch_field();
+        editor.input_char('5');
+        assert!(editor.thresholds().is_err()); // Same as warning
+        editor.input_char('0');
+        assert_eq!(
+            editor.thresholds().unwrap().rds["Read IOPS"],
+            Threshold {
+                warning: 5.0,
+                critical: 50.0
+            }
+        );
+        let missing = editor.missing_previews();
+        assert!(missing.contains(&MetricType::ReadIops));
+        assert!(!missing.contains(&MetricType::CpuUtilization)); // Already loaded
+
+        editor.selected = editor_row("CPU Utilization");
+        editor.backspace();
+        editor.input_char('1');
+        editor.input_char('0');
+        editor.input_char('1');
+        let error = editor.thresholds().unwrap_err().to_string();
+        assert!(error.starts_with("CPU Utilization:"), "{error}");
+    }
+
+    #[test]
+    fn test_write_keeps_the_rest_of_the_config() {
+        let mut document: DocumentMut = "# Refresh often\nrefresh_interval_secs = 15\n"
+            .parse()
+            .unwrap();
+        let thresholds = Thresholds {
+            rds: BTreeMap::from([(
+                "CPU Utilization".to_string(),
+                Threshold {
+                    warning: 70.0,
+                    critical: 90.0,
+                },
+            )]),
+        };
+        thresholds.write_to(&mut document);
+        let written = document.to_string();
+        assert!(written.starts_with("# Refresh often\nrefresh_interval_secs = 15\n"));
+        assert!(written.contains("[thresholds.rds]"), "{written}");
+        assert!(!written.contains("[thresholds]\n"), "{written}");
+
+        let config = config::Config::parse(&written).unwrap();
+        assert_eq!(config.thresholds, thresholds);
+
+        Thresholds::default().write_to(&mut document);
+        assert!(!document.to_string().contains("thresholds"));
+    }
+}
@@ -0,0 +1,1187 @@
+// Linearized page summaries for screen readers and braille displays
+//
+// With `[accessibility] screen_reader = true` (or `--screen-reader`) every
+// page is rendered as plain lines of text instead of bordered widgets, e.g.
+// "CPU Utilization 72.0%, rising, warning". Changes between frames (page
+// switches, loads finishing, errors, metrics changing severity) are
+// collected into a single announcement shown in the status line.
+
+use crate::analytics::fifo_groups::FIFO_METRICS;
+use crate::aws::batch::{format_job_count, JOB_STATUSES};
+use crate::aws::eventbridge::arn_resource;
+use crate::aws::metrics::providers::{
+    BatchMetricProvider, EventBridgeMetricProvider, LambdaMetricProvider, MetricProvider,
+    SqsMetricProvider, SyntheticsMetricProvider,
+};
+use crate::aws::rds::blue_green::identifier_from_arn;
+use crate::aws::usage;
+use crate::fleet;
+use crate::internals;
+use crate::models::{App, AppState, AwsService, ServiceInstance, SqsQueue};
+use crate::ui::components::batch_queue_summary::job_count_severity;
+use crate::ui::components::composite_alarms;
+use crate::ui::components::function_summary::{format_setting, FAILURE_METRICS};
+use crate::ui::components::global_database::format_lag;
+use crate::ui::components::metric_utils::{
+    format_value, get_available_metrics_with_history, get_metric_severity, get_metric_unit,
+};
+use crate::ui::components::queue_summary::format_age;
+use crate::ui::components::replica_topology::lag_severity;
+use crate::ui::theme::Severity;
+use crate::utils::formatting::locale;
+use chrono::{DateTime, Local};
+
+/// Relative change between the first and second half of a series that counts as movement
+const TREND_THRESHOLD: f64 = 0.1;
+
+/// Error log lines of an incident read out, newest first
+const INCIDENT_LOG_LINES: usize = 5;
+
+/// Lines of a log tail read out, from the bottom of the view
+const SUMMARY_LOG_LINES: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Rising,
+    Falling,
+    Stable,
+}
+
+impl Trend {
+    /// Compare the mean of the newer half of `history` against the older half
+    pub fn of(history: &[f64]) -> Self {
+        if history.len() < 2 {
+            return Trend::Stable;
+        }
+        let mean = |values: &[f64]| values.iter().sum::<f64>() / values.len() as f64;
+        let (older, newer) = history.split_at(history.len() / 2);
+        let (older, newer) = (mean(older), mean(newer));
+
+        let scale = older.abs().max(newer.abs());
+        if scale == 0.0 {
+            return Trend::Stable;
+        }
+        let change = (newer - older) / scale;
+        if change > TREND_THRESHOLD {
+            Trend::Rising
+        } else if change < -TREND_THRESHOLD {
+            Trend::Falling
+        } else {
+            Trend::Stable
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Trend::Rising => "rising",
+            Trend::Falling => "falling",
+            Trend::Stable => "stable",
+        }
+    }
+}
+
+/// One line of a page: a name, its value in words, and an optional state
+#[derive(Debug, Clone, PartialEq)]
+pub struct SummaryItem {
+    pub label: String,
+    pub text: String,
+    pub severity: Option<Severity>,
+}
+
+impl SummaryItem {
+    fn new(label: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            text: text.into(),
+            severity: None,
+        }
+    }
+
+    fn with_severity(mut self, severity: Option<Severity>) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// e.g. "CPU Utilization 72.0%, rising, warning"
+    pub fn line(&self) -> String {
+        let mut line = if self.text.is_empty() {
+            self.label.clone()
+        } else {
+            format!("{} {}", self.label, self.text)
+        };
+        if let Some(severity) = self.severity.filter(|s| *s != Severity::Ok) {
+            line.push_str(", ");
+            line.push_str(severity.label());
+        }
+        line
+    }
+}
+
+/// Everything a page shows, as text
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageSummary {
+    pub title: String,
+    pub busy: bool,
+    pub error: Option<String>,
+    pub items: Vec<SummaryItem>,
+    pub selected: Option<usize>,
+    pub controls: &'static str,
+}
+
+impl PageSummary {
+    fn new(title: impl Into<String>, controls: &'static str) -> Self {
+        Self {
+            title: title.into(),
+            busy: false,
+            error: None,
+            items: Vec::new(),
+            selected: None,
+            controls,
+        }
+    }
+
+    pub fn selected_item(&self) -> Option<&SummaryItem> {
+        self.items.get(self.selected?)
+    }
+}
+
+/// Build the linear summary of the current page
+pub fn page_summary(app: &App) -> PageSummary {
+    let mut summary = match app.view.state {
+        AppState::ServiceList => service_list_summary(app),
+        AppState::InstanceList => instance_list_summary(app),
+        AppState::MetricsSummary => match app.view.selected_service {
+            Some(AwsService::Sqs) => queue_summary(app),
+            Some(AwsService::Synthetics) => canary_summary(app),
+            Some(AwsService::EventBridge) => event_rule_summary(app),
+            Some(AwsService::Batch) => batch_queue_summary(app),
+            Some(AwsService::Lambda) => function_summary(app),
+            _ => metrics_summary(app),
+        },
+        AppState::InstanceDetails => instance_details_summary(app),
+        AppState::ReplicaTopology => replica_summary(app),
+        AppState::BlueGreen => blue_green_summary(app),
+        AppState::GlobalDatabase => global_database_summary(app),
+        AppState::SlowQueries => slow_queries_summary(app),
+        AppState::LogTail => log_tail_summary(app),
+        AppState::Parameters => parameters_summary(app),
+        AppState::Fleet => fleet_summary(app),
+        AppState::ResourceGroups => resource_groups_summary(app),
+        AppState::CompositeAlarms => composite_alarms_summary(app),
+        AppState::Alarms => alarm_browser_summary(app),
+        AppState::Timings => timings_summary(app),
+        AppState::ApiCalls => api_calls_summary(),
+        AppState::Internals => internals_summary(app),
+    };
+    summary.error = app.error_message.clone();
+    if matches!(
+        app.view.state,
+        AppState::MetricsSummary | AppState::InstanceDetails
+    ) {
+        if let Some(reason) = app.stale_data_reason() {
+            summary.items.insert(0, SummaryItem::new(reason, ""));
+        }
+    }
+    summary
+}
+
+fn service_list_summary(app: &App) -> PageSummary {
+    let mut summary = PageSummary::new("Services", "Up/Down: move, Enter: open, q: quit");
+    summary.items = app
+        .available_services
+        .iter()
+        .map(|service| SummaryItem::new(service.display_name(), ""))
+        .collect();
+    summary.selected = app.view.service_list_state.selected();
+    summary
+}
+
+fn instance_list_summary(app: &App) -> PageSummary {
+    let service = app
+        .view
+        .selected_service
+        .as_ref()
+        .map(|service| service.short_name())
+        .unwrap_or("Service");
+    let instances = app.get_current_instances();
+    let mut summary = PageSummary::new(
+        format!("{service} resources, {} listed", instances.len()),
+        "Up/Down: move, Enter: metrics, Space: mark, m/M: mute/unmute alarms, w: watchlist, r: refresh, Esc: back, q: quit",
+    );
+    summary.busy = app.loading;
+    summary.items = instances
+        .iter()
+        .enumerate()
+        .map(|(index, instance)| {
+            let mut text = match instance {
+                ServiceInstance::Rds(rds) => {
+                    format!("{}, {}, {}", rds.engine, rds.status, rds.instance_class)
+                }
+                ServiceInstance::Sqs(SqsQueue {
+                    error: Some(error), ..
+                }) => format!("attributes failed to load: {error}"),
+                ServiceInstance::Sqs(queue) => format!(
+                    "{}, {} visible, {} in flight",
+                    queue.queue_type(),
+                    queue
+                        .attribute("ApproximateNumberOfMessages")
+                        .unwrap_or("-"),
+                    queue
+                        .attribute("ApproximateNumberOfMessagesNotVisible")
+                        .unwrap_or("-")
+                ),
+                ServiceInstance::Canary(canary) => match &canary.last_run {
+                    Some(run) => format!(
+                        "{}, last run {}",
+                        canary.state.to_lowercase(),
+                        run.state.to_lowercase()
+                    ),
+                    None => format!("{}, never run", canary.state.to_lowercase()),
+                },
+                ServiceInstance::EventRule(rule) => format!(
+                    "{}, {} targets, {} without dead-letter queue",
+                    rule.state.to_lowercase(),
+                    rule.targets.len(),
+                    rule.targets_without_dlq().count()
+                ),
+                ServiceInstance::BatchQueue(queue) => format!(
+                    "{}, {} queued, {} running, {} failed",
+                    queue.state.to_lowercase(),
+                    format_job_count(queue.queued()),
+                    format_job_count(queue.job_count("RUNNING")),
+                    format_job_count(queue.job_count("FAILED"))
+                ),
+                ServiceInstance::Function(function) => format!(
+                    "{}, {} memory, {} timeout",
+                    function.runtime,
+                    format_setting(function.memory_mb, "MB"),
+                    format_setting(function.timeout_secs, "s")
+                ),
+            };
+            let alarms = app.alarms_for(instance);
+            let firing = alarms.iter().filter(|alarm| alarm.is_firing()).count();
+            if !alarms.is_empty() {
+                text.push_str(&format!(", {} alarms, {firing} firing", alarms.len()));
+            }
+            if app.is_marked(index) {
+                text.push_str(", marked");
+            }
+            let severity = (firing > 0).then_some(Severity::Critical);
+            SummaryItem::new(instance.as_aws_instance().id(), text).with_severity(severity)
+        })
+        .collect();
+    summary.selected = app.view.list_state.selected();
+    summary
+}
+
+fn metrics_summary(app: &App) -> PageSummary {
+    let id = app.get_selected_instance_id().unwrap_or_default();
+    let time_range = App::get_time_range_options()
+        .get(app.get_current_time_range_index())
+        .map(|(label, _, _, _)| *label)
+        .unwrap_or("");
+    let mut summary = PageSummary::new(
+        format!("Metrics for {id}, last {time_range}"),
+        "Up/Down: move, Tab: switch to time ranges, Enter: chart, r: refresh, Esc: back, q: quit",
+    );
+    summary.busy = app.metrics_loading;
+    summary.items = get_available_metrics_with_history(&app.view.metrics)
+        .into_iter()
+        .map(|(name, value, history, unit)| {
+            SummaryItem::new(
+                name,
+                format!(
+                    "{}, {}",
+                    format_value(value, unit),
+                    Trend::of(history).label()
+                ),
+            )
+            .with_severity(get_metric_severity(&app.config.thresholds, name, value))
+        })
+        .collect();
+    summary.selected = Some(app.view.sparkline_grid_selected_index);
+    // The incident's alarms and newest error log lines follow the metrics
+    if let Some(incident) = app.incident_on_selected() {
+        summary.items.extend(incident.firing.iter().map(|name| {
+            SummaryItem::new("Alarm firing", name.clone()).with_severity(Some(Severity::Critical))
+        }));
+        summary.items.extend(
+            incident
+                .log
+                .iter()
+                .rev()
+                .take(INCIDENT_LOG_LINES)
+                .map(|event| SummaryItem::new("Error log", event.message.trim_end().to_string())),
+        );
+    }
+    summary
+}
+
+fn queue_summary(app: &App) -> PageSummary {
+    let Some(queue) = app.get_selected_sqs_queue() else {
+        return PageSummary::new("Queue", "Esc: back, q: quit");
+    };
+    let mut summary = PageSummary::new(
+        format!("Queue {}, {}", queue.name, queue.queue_type()),
+        "s: send test message, r: refresh, Esc: back, q: quit",
+    );
+    summary.busy = app.metrics_loading;
+    for (label, attribute) in [
+        ("Visible", "ApproximateNumberOfMessages"),
+        ("In flight", "ApproximateNumberOfMessagesNotVisible"),
+        ("Delayed", "ApproximateNumberOfMessagesDelayed"),
+    ] {
+        summary.items.push(SummaryItem::new(
+            label,
+            queue.attribute(attribute).unwrap_or("unknown"),
+        ));
+    }
+    if let Some(metrics) = &app.view.service_metrics {
+        for definition in SqsMetricProvider::new().get_metrics_config() {
+            if FIFO_METRICS.contains(&definition.name.as_str()) {
+                continue;
+            }
+            if let Some(value) = metrics.raw_metrics.get(&definition.name) {
+                summary.items.push(SummaryItem::new(
+                    definition.name.clone(),
+                    format!(
+                        "{:.0}, {}",
+                        value.current,
+                        Trend::of(&value.history).label()
+                    ),
+                ));
+            }
+        }
+    }
+    if let Some(activity) = app.fifo_group_activity() {
+        let groups = activity
+            .groups_in_flight
+            .map_or_else(|| "unknown".to_string(), |groups| format!("{groups:.0}"));
+        summary
+            .items
+            .push(SummaryItem::new("Groups in flight", groups));
+        for hint in activity.hints {
+            summary.items.push(
+                SummaryItem::new("FIFO groups", hint.text)
+                    .with_severity(hint.warning.then_some(Severity::Warning)),
+            );
+        }
+    }
+    if let Some(aging) = app.queue_age_estimate() {
+        let mut text = format!("oldest {}", format_age(aging.oldest_age_secs));
+        if aging.at_risk {
+            text += ", messages risk expiring at the retention limit";
+        }
+        summary.items.push(
+            SummaryItem::new("Backlog age", text)
+                .with_severity(aging.at_risk.then_some(Severity::Critical)),
+        );
+    }
+    summary
+}
+
+fn canary_summary(app: &App) -> PageSummary {
+    let Some(canary) = app.get_selected_canary() else {
+        return PageSummary::new("Canary", "Esc: back, q: quit");
+    };
+    let mut summary = PageSummary::new(
+        format!("Canary {}, {}", canary.name, canary.state.to_lowercase()),
+        "y: copy artifacts link, r: refresh, Esc: back, q: quit",
+    );
+    summary.busy = app.metrics_loading;
+    if let Some(run) = &canary.last_run {
+        let mut text = run.state.to_lowercase();
+        if let Some(reason) = run.failure_reason.as_ref().filter(|_| run.failed()) {
+            text += &format!(", {reason}");
+        }
+        summary.items.push(
+            SummaryItem::new("Last run", text)
+                .with_severity(run.failed().then_some(Severity::Critical)),
+        );
+    }
+    if let Some(metrics) = &app.view.service_metrics {
+        for definition in SyntheticsMetricProvider::new().get_metrics_config() {
+            if let Some(value) = metrics.raw_metrics.get(&definition.name) {
+                summary.items.push(SummaryItem::new(
+                    definition.name.clone(),
+                    format!(
+                        "{}, {}",
+                        format_value(value.current, definition.unit.as_deref().unwrap_or("")),
+                        Trend::of(&value.history).label()
+                    ),
+                ));
+            }
+        }
+    }
+    summary
+}
+
+fn event_rule_summary(app: &App) -> PageSummary {
+    let Some(rule) = app.get_selected_event_rule() else {
+        return PageSummary::new("Rule", "Esc: back, q: quit");
+    };
+    let mut summary = PageSummary::new(
+        format!("Rule {}, {}", rule.id, rule.state.to_lowercase()),
+        "i: details, r: refresh, Esc: back, q: quit",
+    );
+    summary.busy = app.metrics_loading;
+    for target in &rule.targets {
+        let dlq = target
+            .dead_letter_arn
+            .as_deref()
+            .map(|arn| format!("dead-letter queue {}", arn_resource(arn)))
+            .unwrap_or_else(|| "no dead-letter queue".to_string());
+        summary.items.push(
+            SummaryItem::new(
+                format!("Target {}", target.id),
+                format!("{}, {dlq}", arn_resource(&target.arn)),
+            )
+            .with_severity(
+                target
+                    .dead_letter_arn
+                    .is_none()
+                    .then_some(Severity::Warning),
+            ),
+        );
+    }
+    if let Some(metrics) = &app.view.service_metrics {
+        for definition in EventBridgeMetricProvider::new().get_metrics_config() {
+            if let Some(value) = metrics.raw_metrics.get(&definition.name) {
+                summary.items.push(SummaryItem::new(
+                    definition.name.clone(),
+                    format!(
+                        "{}, {}",
+                        format_value(value.current, definition.unit.as_deref().unwrap_or("")),
+                        Trend::of(&value.history).label()
+                    ),
+                ));
+            }
+        }
+    }
+    summary
+}
+
+fn function_summary(app: &App) -> PageSummary {
+    let Some(function) = app.get_selected_lambda_function() else {
+        return PageSummary::new("Function", "Esc: back, q: quit");
+    };
+    let mut summary = PageSummary::new(
+        format!(
+            "Function {}, {}, {}",
+            function.name,
+            function.runtime,
+            function
+                .state
+                .as_deref()
+                .unwrap_or("state unknown")
+                .to_lowercase()
+        ),
+        "i: details, r: refresh, Esc: back, q: quit",
+    );
+    summary.busy = app.metrics_loading;
+    summary.items.push(SummaryItem::new(
+        "Memory",
+        format_setting(function.memory_mb, "MB"),
+    ));
+    summary.items.push(SummaryItem::new(
+        "Timeout",
+        format_setting(function.timeout_secs, "s"),
+    ));
+    if let Some(metrics) = &app.view.service_metrics {
+        for definition in LambdaMetricProvider::new().get_metrics_config() {
+            if let Some(value) = metrics.raw_metrics.get(&definition.name) {
+                let failing = FAILURE_METRICS.contains(&definition.name.as_str())
+                    && value.history.iter().sum::<f64>() > 0.0;
+                summary.items.push(
+                    SummaryItem::new(
+                        definition.name.clone(),
+                        format!(
+                            "{}, {}",
+                            format_value(value.current, definition.unit.as_deref().unwrap_or("")),
+                            Trend::of(&value.history).label()
+                        ),
+                    )
+                    .with_severity(failing.then_some(Severity::Critical)),
+                );
+            }
+        }
+    }
+    summary
+}
+
+fn batch_queue_summary(app: &App) -> PageSummary {
+    let Some(queue) = app.get_selected_batch_queue() else {
+        return PageSummary::new("Job queue", "Esc: back, q: quit");
+    };
+    let mut summary = PageSummary::new(
+        format!("Job queue {}, {}", queue.name, queue.state.to_lowercase()),
+        "Tab: next compute environment, r: refresh, Esc: back, q: quit",
+    );
+    summary.busy = app.metrics_loading;
+    for status in JOB_STATUSES {
+        summary.items.push(
+            SummaryItem::new(
+                format!("{} jobs", status.to_lowercase()),
+                format_job_count(queue.job_count(status)),
+            )
+            .with_severity(job_count_severity(queue, status)),
+        );
+    }
+    if let Some(environment) = app.get_selected_compute_environment() {
+        summary.items.push(SummaryItem::new(
+            "Compute environment",
+            format!(
+                "{}, {} desired vCPUs",
+                environment.name,
+                environment
+                    .desired_vcpus
+                    .map_or_else(|| "unknown".to_string(), |vcpus| vcpus.to_string())
+            ),
+        ));
+    }
+    if let Some(metrics) = &app.view.service_metrics {
+        for definition in BatchMetricProvider::new().get_metrics_config() {
+            if let Some(value) = metrics.raw_metrics.get(&definition.name) {
+                summary.items.push(SummaryItem::new(
+                    definition.name.clone(),
+                    format!(
+                        "{}, {}",
+                        format_value(value.current, definition.unit.as_deref().unwrap_or("")),
+                        Trend::of(&value.history).label()
+                    ),
+                ));
+            }
+        }
+    }
+    summary
+}
+
+fn instance_details_summary(app: &App) -> PageSummary {
+    let id = app.get_selected_instance_id().unwrap_or_default();
+    let Some(metric) = app.detail_chart_metric() else {
+        return PageSummary::new(format!("Chart for {id}"), "Esc: back, q: quit");
+    };
+    let name = metric.display_name();
+    let mut summary = PageSummary::new(
+        format!("{name} for {id}"),
+        "Up/Down: previous/next metric, A: create alarm, r: refresh, Esc: back, q: quit",
+    );
+    summary.busy = app.metrics_loading;
+
+    let history = app.view.metrics.get_metric_history(&metric);
+    let unit = get_metric_unit(&metric);
+    if let Some(&current) = history.last() {
+        let min = history.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = history.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let average = history.iter().sum::<f64>() / history.len() as f64;
+        summary.items = vec![
+            SummaryItem::new("Latest", format_value(current, unit))
+                .with_severity(get_metric_severity(&app.config.thresholds, name, current)),
+            SummaryItem::new("Trend", Trend::of(history).label()),
+            SummaryItem::new("Minimum", format_value(min, unit)),
+            SummaryItem::new("Maximum", format_value(max, unit)),
+            SummaryItem::new("Average", format_value(average, unit)),
+            SummaryItem::new("Datapoints", history.len().to_string()),
+        ];
+    }
+    if let Some(thresholds) = app.alarm_thresholds().get(name) {
+        for threshold in thresholds {
+            summary.items.push(SummaryItem::new(
+                "Alarm threshold",
+                format_value(*threshold, unit),
+            ));
+        }
+    }
+    summary
+}
+
+fn replica_summary(app: &App) -> PageSummary {
+    let primary = app.view.topology.primary.clone().unwrap_or_default();
+    let mut summary = PageSummary::new(
+        format!(
+            "Replicas of {primary}, {} read replicas",
+            app.view.topology.lags.len()
+        ),
+        "Up/Down: move, Enter: open replica, r: refresh, Esc: back, q: quit",
+    );
+    summary.busy = app.metrics_loading;
+    summary.items = app
+        .view
+        .topology
+        .lags
+        .iter()
+        .map(|lag| match lag.current {
+            Some(secs) => SummaryItem::new(
+                lag.identifier.clone(),
+                format!("lag {secs:.1} s, {}", Trend::of(&lag.history).label()),
+            )
+            .with_severity(Some(lag_severity(secs))),
+            None => SummaryItem::new(lag.identifier.clone(), "no lag data"),
+        })
+        .collect();
+    summary.selected = app.view.topology.list_state.selected();
+    summary
+}
+
+fn blue_green_summary(app: &App) -> PageSummary {
+    let Some(deployment) = app.get_selected_blue_green_deployment() else {
+        return PageSummary::new("Blue/green deployment is no longer listed", "Esc: back");
+    };
+    let mut summary = PageSummary::new(
+        format!("Blue/green deployment {}", deployment.name),
+        "r: refresh, Esc: back, q: quit",
+    );
+    summary.busy = app.metrics_loading;
+    let status = match &deployment.status_details {
+        Some(details) => format!("{}, {details}", deployment.status),
+        None => deployment.status.clone(),
+    };
+    summary.items.push(SummaryItem::new("Status", status));
+    for member in &deployment.members {
+        summary.items.push(SummaryItem::new(
+            format!(
+                "{} to {}",
+                identifier_from_arn(&member.source_arn),
+                identifier_from_arn(&member.target_arn)
+            ),
+            member.status.clone(),
+        ));
+    }
+    for (task, status) in &deployment.tasks {
+        summary
+            .items
+            .push(SummaryItem::new(format!("Task {task}"), status.clone()));
+    }
+    summary
+}
+
+fn global_database_summary(app: &App) -> PageSummary {
+    let page = &app.view.global_database;
+    let Some(database) = &page.database else {
+        return PageSummary::new("Global database is no longer listed", "Esc: back");
+    };
+    let mut summary = PageSummary::new(
+        format!(
+            "Global database {}, {}",
+            database.identifier, database.status
+        ),
+        "Up/Down: select secondary, r: refresh, Esc: back, q: quit",
+    );
+    summary.busy = app.metrics_loading;
+    let lag_text = |lag: Option<f64>| lag.map_or("no data".to_string(), format_lag);
+    for secondary in database.secondaries() {
+        let lag = page
+            .lags
+            .iter()
+            .find(|lag| lag.cluster_arn == secondary.cluster_arn);
+        let (replication, rpo) = lag.map_or((None, None), |lag| {
+            (lag.current_replication(), lag.current_rpo())
+        });
+        let readiness = match database.failover_readiness(secondary, rpo) {
+            Ok(()) => "ready for planned failover".to_string(),
+            Err(reason) => format!("not ready for planned failover, {reason}"),
+        };
+        summary.items.push(SummaryItem::new(
+            format!("{} in {}", secondary.cluster_id(), secondary.region()),
+            format!(
+                "replication lag {}, RPO lag {}, {readiness}",
+                lag_text(replication),
+                lag_text(rpo)
+            ),
+        ));
+    }
+    if let Some(primary) = database.primary() {
+        summary.items.push(SummaryItem::new(
+            format!("Primary {} in {}", primary.cluster_id(), primary.region()),
+            "",
+        ));
+    }
+    summary.selected = page.list_state.selected();
+    summary
+}
+
+fn fleet_summary(app: &App) -> PageSummary {
+    let service = app.view.fleet.service.as_ref().unwrap_or(&AwsService::Rds);
+    let mut summary = PageSummary::new(
+        format!(
+            "{} fleet, {} resources listed",
+            service.short_name(),
+            app.view.fleet.inventory.rows.len()
+        ),
+        "Up/Down: select, Enter: open in its account, s: service, r: refresh, Esc: back, q: quit",
+    );
+    summary.items = app
+        .view
+        .fleet
+        .inventory
+        .failures
+        .iter()
+        .map(|failure| SummaryItem::new("Not listed", failure.clone()))
+        .collect();
+    let listed_from = summary.items.len();
+    summary
+        .items
+        .extend(app.view.fleet.inventory.rows.iter().map(|row| {
+            let (label, _) = fleet::health(&row.instance);
+            SummaryItem::new(
+                fleet::resource_id(row),
+                format!("{}, {label}", row.location_label()),
+            )
+        }));
+    summary.selected = app
+        .view
+        .fleet
+        .table_state
+        .selected()
+        .map(|index| listed_from + index);
+    summary
+}
+
+fn resource_groups_summary(app: &App) -> PageSummary {
+    let page = &app.view.resource_groups;
+    let mut summary = match &page.group {
+        Some(group) => {
+            let services: Vec<String> = page
+                .service_health()
+                .iter()
+                .map(|(service, count, worst)| {
+                    let worst = worst.map_or("unknown", |severity| severity.label());
+                    format!("{} {count} {worst}", service.short_name())
+                })
+                .collect();
+            let mut summary = PageSummary::new(
+                format!(
+                    "{} {group}, {} members: {}",
+                    page.source.title(),
+                    page.members.members.len(),
+                    services.join(", ")
+                ),
+                "Up/Down: select, Enter: view metrics, r: refresh, Esc: list, q: quit",
+            );
+            summary.items = page
+                .members
+                .members
+                .iter()
+                .map(|member| {
+                    let health = match (member.service(), page.health.get(&member.arn)) {
+                        (Some(_), Some((label, severity))) => {
+                            return SummaryItem::new(
+                                member.id(),
+                                format!("{}, {label}", member.kind()),
+                            )
+                            .with_severity(*severity)
+                        }
+                        (Some(_), None) => "not listed",
+                        (None, _) => "not monitored",
+                    };
+                    SummaryItem::new(member.id(), format!("{}, {health}", member.kind()))
+                })
+                .collect();
+            summary
+        }
+        None => {
+            let mut summary = PageSummary::new(
+                format!("{}, {} listed", page.source.title(), page.groups.len()),
+                "Up/Down: select, Enter: list members, r: refresh, Esc: back, q: quit",
+            );
+            summary.items = page
+                .groups
+                .iter()
+                .map(|group| SummaryItem::new(group.clone(), ""))
+                .collect();
+            summary
+        }
+    };
+    summary.selected = page.table_state.selected();
+    if let Some(notice) = &page.notice {
+        summary
+            .items
+            .push(SummaryItem::new("Notice", notice.clone()));
+    }
+    summary.items.extend(
+        page.members
+            .query_errors
+            .iter()
+            .map(|error| SummaryItem::new("Not resolved", error.clone())),
+    );
+    summary
+}
+
+fn composite_alarms_summary(app: &App) -> PageSummary {
+    let page = &app.view.composite_alarms;
+    let alarm_severity = |state: &str| match state {
+        "ALARM" => Some(Severity::Critical),
+        "OK" => Some(Severity::Ok),
+        _ => None,
+    };
+    let mut summary = match page.open_alarm() {
+        Some(alarm) => {
+            let mut summary = PageSummary::new(
+                format!("Composite alarm {}, {}", alarm.name, alarm.state),
+                "Up/Down: select, Enter: view the child's metric, r: refresh, Esc: list, q: quit",
+            );
+            summary.items = page
+                .lines
+                .iter()
+                .map(|line| {
+                    let mut text = composite_alarms::holds_label(line).to_string();
+                    if let Some(state) = &line.state {
+                        text = format!("{text}, child {state}");
+                    }
+                    let label = format!("{}{}", "  ".repeat(line.depth), line.label);
+                    SummaryItem::new(label, text)
+                        .with_severity(line.driving.then_some(Severity::Critical))
+                })
+                .collect();
+            summary
+        }
+        None => {
+            let firing = page.alarms.iter().filter(|alarm| alarm.is_firing()).count();
+            let mut summary = PageSummary::new(
+                format!("Composite alarms, {firing} firing of {}", page.alarms.len()),
+                "Up/Down: select, Enter: show rule, r: refresh, Esc: back, q: quit",
+            );
+            summary.items = page
+                .alarms
+                .iter()
+                .map(|alarm| {
+                    SummaryItem::new(alarm.name.clone(), alarm.state.clone())
+                        .with_severity(alarm_severity(&alarm.state))
+                })
+                .collect();
+            summary
+        }
+    };
+    summary.selected = page.table_state.selected();
+    if let Some(notice) = &page.notice {
+        summary
+            .items
+            .push(SummaryItem::new("Notice", notice.clone()));
+    }
+    summary
+}
+
+fn alarm_browser_summary(app: &App) -> PageSummary {
+    let page = &app.view.alarm_browser;
+    let shown = app.browsed_alarms();
+    let firing = shown.iter().filter(|alarm| alarm.is_firing()).count();
+    let mut summary = PageSummary::new(
+        format!(
+            "Alarms in {}, {firing} firing of {}",
+            page.namespace.as_deref().unwrap_or("every namespace"),
+            shown.len()
+        ),
+        "Up/Down: select, Enter: view metric, n: next namespace, r: refresh, Esc: back, q: quit",
+    );
+    summary.items = shown
+        .iter()
+        .map(|alarm| {
+            SummaryItem::new(
+                alarm.name.clone(),
+                format!("{}, {} {}", alarm.state, alarm.namespace, alarm.metric_name),
+            )
+            .with_severity(composite_alarms::state_severity(&alarm.state))
+        })
+        .collect();
+    summary.selected = page.table_state.selected();
+    if let Some(notice) = &page.notice {
+        summary
+            .items
+            .push(SummaryItem::new("Notice", notice.clone()));
+    }
+    summary
+}
+
+fn timings_summary(app: &App) -> PageSummary {
+    let ms = |elapsed: std::time::Duration| format!("{} ms", elapsed.as_millis());
+    let mut summary = PageSummary::new(
+        format!("Page load timings, {} loads", app.timings.loads().len()),
+        "c: clear, Esc: back, q: quit",
+    );
+    summary.items = app
+        .timings
+        .per_page()
+        .into_iter()
+        .map(|page| {
+            SummaryItem::new(
+                page.page,
+                format!(
+                    "{} loads, median {} (fetch {}, render {}), slowest {}",
+                    page.loads,
+                    ms(page.median_total),
+                    ms(page.median_fetch),
+                    ms(page.median_render),
+                    ms(page.slowest_total)
+                ),
+            )
+        })
+        .collect();
+    summary
+}
+
+fn internals_summary(app: &App) -> PageSummary {
+    let mut summary = PageSummary::new(
+        format!("Internals, {} samples", app.internals.timestamps().len()),
+        "y: copy for a bug report, Esc: back, q: quit",
+    );
+    let metrics = app.internals.metrics();
+    summary.items = internals::definitions()
+        .into_iter()
+        .filter_map(|definition| {
+            let value = metrics.get(&definition.name)?;
+            let unit = definition.unit.as_deref().unwrap_or("");
+            Some(SummaryItem::new(
+                definition.name.as_str(),
+                format_value(value.current, unit),
+            ))
+        })
+        .collect();
+    summary
+}
+
+fn api_calls_summary() -> PageSummary {
+    let usage = usage::snapshot();
+    let total = usage.total();
+    let mut summary = PageSummary::new(
+        format!(
+            "API calls this session, {} calls, estimated CloudWatch cost {}",
+            total.calls,
+            usage::format_cost(total.cost)
+        ),
+        "Esc: back, q: quit",
+    );
+    summary.items = usage
+        .operations()
+        .into_iter()
+        .map(|operation| {
+            SummaryItem::new(
+                format!("{} {}", operation.service, operation.operation),
+                format!(
+                    "{} calls, {}",
+                    operation.tally.calls,
+                    usage::format_cost(operation.tally.cost)
+                ),
+            )
+        })
+        .collect();
+    summary
+}
+
+fn parameters_summary(app: &App) -> PageSummary {
+    let page = &app.view.parameters;
+    let title = match &page.drift {
+        Some(drift) => format!(
+            "Parameters {} overrides, {} family",
+            drift.group, drift.family
+        ),
+        None => "Parameters".to_string(),
+    };
+    let mut summary = PageSummary::new(title, "Up/Down: select, r: refresh, Esc: back, q: quit");
+    summary.busy = app.loading;
+    if let Some(notice) = &page.notice {
+        summary.items.push(SummaryItem::new(notice.clone(), ""));
+    }
+    let Some(drift) = &page.drift else {
+        return summary;
+    };
+    let pending = drift.pending_reboot();
+    summary
+        .items
+        .extend(drift.overrides.iter().map(|parameter| {
+            let mut text = format!(
+                "{}, default {}",
+                parameter.value,
+                parameter.default.as_deref().unwrap_or("unset")
+            );
+            if parameter.is_static {
+                text += if pending {
+                    ", static, waiting for a reboot"
+                } else {
+                    ", static"
+                };
+            }
+            SummaryItem::new(parameter.name.clone(), text)
+                .with_severity((parameter.is_static && pending).then_some(Severity::Warning))
+        }));
+    summary.selected = page.table_state.selected();
+    summary
+}
+
+fn slow_queries_summary(app: &App) -> PageSummary {
+    let mut summary = PageSummary::new(
+        format!(
+            "Slow queries of {}, sorted by {}",
+            app.get_selected_rds_instance_id().unwrap_or_default(),
+            app.view.slow_query.sort.label()
+        ),
+        "Up/Down: select, s: sort, r: refresh, Esc: back, q: quit",
+    );
+    if let Some(notice) = &app.view.slow_query.notice {
+        summary.items.push(SummaryItem::new(notice.clone(), ""));
+        return summary;
+    }
+    summary.items = app
+        .view
+        .slow_query
+        .queries
+        .iter()
+        .map(|query| {
+            let mut text = format!("{:.2} seconds", query.duration_secs);
+            if let Some(rows) = query.rows_examined {
+                text += &format!(", {rows} rows examined");
+            }
+            let cpu = app.cpu_during(query);
+            if let Some(cpu) = cpu {
+                text += &format!(", CPU {cpu:.0} percent");
+            }
+            SummaryItem::new(query.statement.clone(), text).with_severity(cpu.and_then(|cpu| {
+                get_metric_severity(&app.config.thresholds, "CPU Utilization", cpu)
+            }))
+        })
+        .collect();
+    summary.selected = app.view.slow_query.table_state.selected();
+    summary
+}
+
+/// The log groups to pick from, or the newest lines of the followed group
+fn log_tail_summary(app: &App) -> PageSummary {
+    let page = &app.view.log_tail;
+    let Some(group) = &page.group else {
+        let mut summary = PageSummary::new(
+            "Log groups",
+            "Up/Down: select, Enter: follow, Esc: back, q: quit",
+        );
+        if let Some(notice) = &page.notice {
+            summary.items.push(SummaryItem::new(notice.clone(), ""));
+        }
+        summary.items.extend(
+            page.groups
+                .iter()
+                .map(|group| SummaryItem::new(group.clone(), "")),
+        );
+        summary.selected = page.group_state.selected();
+        return summary;
+    };
+
+    let state = if page.paused { "paused" } else { "following" };
+    let mut summary = PageSummary::new(
+        format!("Log tail of {group}, {state}"),
+        "Up/Down: scroll, Space: pause, /: search, n/N: older/newer match, f: filter, Esc: back, q: quit",
+    );
+    if let Some(notice) = &page.notice {
+        summary.items.push(SummaryItem::new(notice.clone(), ""));
+    }
+    // The screen reader hears the lines at the bottom of the view, newest last
+    let events = page.buffer.events();
+    let bottom = events.len().saturating_sub(page.scroll);
+    summary.items.extend(
+        events
+            .range(bottom.saturating_sub(SUMMARY_LOG_LINES)..bottom)
+            .map(|event| {
+                let time = locale().time(&DateTime::<Local>::from(event.timestamp), true);
+                SummaryItem::new(time, event.message.trim_end().to_string())
+            }),
+    );
+    summary
+}
+
+/// Turns differences between consecutive page summaries into one status line
+#[derive(Debug, Default)]
+pub struct Announcer {
+    previous: Option<PageSummary>,
+    pub announcement: Option<String>,
+}
+
+impl Announcer {
+    /// Compare `summary` with the last one seen; keep the last announcement when nothing changed
+    pub fn observe(&mut self, summary: &PageSummary) {
+        let changes = match &self.previous {
+            None => vec![format!("Showing {}", summary.title)],
+            Some(previous) => changes_between(previous, summary),
+        };
+        if !changes.is_empty() {
+            self.announcement = Some(changes.join(". "));
+        }
+        self.previous = Some(summary.clone());
+    }
+}
+
+fn changes_between(previous: &PageSummary, current: &PageSummary) -> Vec<String> {
+    let mut changes = Vec::new();
+    if previous.title != current.title {
+        changes.push(format!("Showing {}", current.title));
+    }
+    if current.busy && !previous.busy {
+        changes.push("Loading".to_string());
+    } else if previous.busy && !current.busy {
+        changes.push(format!("Loaded {} items", current.items.len()));
+    }
+    if let Some(error) = current
+        .error
+        .as_ref()
+        .filter(|e| previous.error.as_ref() != Some(*e))
+    {
+        changes.push(format!("Error: {error}"));
+    }
+
+    // Severity changes are only meaningful on the same page
+    if previous.title == current.title {
+        for item in &current.items {
+            let before = previous
+                .items
+                .iter()
+                .find(|old| old.label == item.label)
+                .and_then(|old| old.severity);
+            if let Some(severity) = item.severity.filter(|s| before.is_some_and(|b| b != *s)) {
+                changes.push(format!("{} is now {}", item.label, severity.label()));
+            }
+        }
+    }
+
+    let selected_label = |summary: &PageSummary| summary.selected_item().map(|i| i.label.clone());
+    if selected_label(previous) != selected_label(current) {
+        if let Some(item) = current.selected_item() {
+            changes.push(format!("Selected {}", item.line()));
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trend_compares_halves_of_history() {
+        assert_eq!(Trend::of(&[10.0, 11.0, 30.0, 32.0]), Trend::Rising);
+        assert_eq!(Trend::of(&[50.0, 48.0, 20.0, 10.0]), Trend::Falling);
+        assert_eq!(Trend::of(&[143.0, 140.0, 145.0, 143.0]), Trend::Stable);
+        assert_eq!(Trend::of(&[5.0]), Trend::Stable);
+    }
+
+    #[test]
+    fn test_announces_severity_changes_and_selection() {
+        let mut page = PageSummary::new("Metrics for orders-db", "");
+        page.items = vec![
+            SummaryItem::new("CPU Utilization", "55.0%, rising").with_severity(Some(Severity::Ok)),
+            SummaryItem::new("Database Connections", "143, stable"),
+        ];
+        page.selected = Some(0);
+
+        let mut announcer = Announcer::default();
+        announcer.observe(&page);
+        assert_eq!(
+            announcer.announcement.as_deref(),
+            Some("Showing Metrics for orders-db")
+        );
+
+        page.items[0] = SummaryItem::new("CPU Utilization", "72.0%, rising")
+            .with_severity(Some(Severity::Warning));
+        page.selected = Some(1);
+        announcer.observe(&page);
+        assert_eq!(
+            announcer.announcement.as_deref(),
+            Some("CPU Utilization is now warning. Selected Database Connections 143, stable")
+        );
+    }
+}
@@ -0,0 +1,131 @@
+// ASCII-only rendering for terminals without Braille or box-drawing glyphs
+//
+// Instead of threading a glyph set through every widget, the finished frame
+// is rewritten cell by cell: borders become + - |, Braille chart and
+// sparkline cells become dot or bar characters, and symbols such as arrows
+// and state markers get ASCII stand-ins. Charts also switch to one point per
+// cell (see `Theme::chart_marker`) so the result reads as a dot plot.
+
+use ratatui::buffer::Buffer;
+use std::env;
+
+/// Bar heights for Braille cells filled from the bottom, one to four dot rows
+const BAR_RAMP: [&str; 4] = ["_", "-", "=", "#"];
+
+/// Terminals known not to draw Braille and box-drawing characters
+const LIMITED_TERMS: [&str; 4] = ["linux", "vt100", "vt220", "dumb"];
+
+/// Best-effort guess that the terminal cannot draw the default glyphs
+///
+/// True for the Linux console and other limited `TERM`s, and when the locale
+/// is set but is not UTF-8.
+pub fn unicode_unsupported() -> bool {
+    let term = env::var("TERM").unwrap_or_default();
+    if LIMITED_TERMS.contains(&term.as_str()) {
+        return true;
+    }
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .find_map(|name| env::var(name).ok().filter(|value| !value.is_empty()));
+    match locale {
+        Some(locale) => {
+            let locale = locale.to_ascii_uppercase();
+            !(locale.contains("UTF-8") || locale.contains("UTF8"))
+        }
+        None => false,
+    }
+}
+
+/// Replace every non-ASCII glyph in the frame that has an ASCII stand-in
+pub fn asciify(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        let mut chars = cell.symbol().chars();
+        let (Some(glyph), None) = (chars.next(), chars.next()) else {
+            continue;
+        };
+        if let Some(replacement) = to_ascii(glyph) {
+            cell.set_symbol(replacement);
+        }
+    }
+}
+
+fn to_ascii(glyph: char) -> Option<&'static str> {
+    if glyph.is_ascii() {
+        return None;
+    }
+    let replacement = match glyph {
+        // Box drawing
+        '─' | '━' | '┄' | '┅' | '┈' | '┉' | '╌' | '╍' | '═' | '╴' | '╶' | '╸' | '╺' => {
+            "-"
+        }
+        '│' | '┃' | '┆' | '┇' | '┊' | '┋' | '╎' | '╏' | '║' | '╵' | '╷' | '╹' | '╻' => {
+            "|"
+        }
+        '\u{2500}'..='\u{257F}' => "+",
+        // Sparkline bars and blocks
+        '▁' | '▂' => "_",
+        '▃' | '▄' => "-",
+        '▅' | '▆' => "=",
+        '▇' | '█' | '■' => "#",
+        '░' => ".",
+        '▒' => ":",
+        '▓' => "%",
+        '\u{2800}'..='\u{28FF}' => braille_to_ascii(glyph as u32 - 0x2800),
+        // Symbols used in labels, markers and key hints
+        '•' | '●' | '★' => "*",
+        '✓' => "+",
+        '▲' => "!",
+        '✖' => "x",
+        '→' | '▶' => ">",
+        '←' => "<",
+        '↑' => "^",
+        '↓' | '▼' => "v",
+        '↗' => "/",
+        '↘' => "\\",
+        '…' => ".",
+        'μ' => "u",
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+/// Braille cells drawn up from the bottom row are bars; anything else is a plotted point
+fn braille_to_ascii(dots: u32) -> &'static str {
+    // Dot bits of each row, top to bottom
+    const ROWS: [u32; 4] = [0x01 | 0x08, 0x02 | 0x10, 0x04 | 0x20, 0x40 | 0x80];
+    if dots == 0 {
+        return " ";
+    }
+    let height = ROWS
+        .iter()
+        .rev()
+        .take_while(|row| dots & **row != 0)
+        .count();
+    match height {
+        0 => "*",
+        height => BAR_RAMP[height - 1],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::{Block, Borders, Widget};
+
+    #[test]
+    fn test_frame_is_rewritten_to_ascii() {
+        let area = Rect::new(0, 0, 6, 3);
+        let mut buffer = Buffer::empty(area);
+        Block::default()
+            .borders(Borders::ALL)
+            .render(area, &mut buffer);
+        buffer.set_string(1, 1, "⣀⣤⣿⠁", ratatui::style::Style::default());
+        asciify(&mut buffer);
+
+        let rows: Vec<String> = (0..3)
+            .map(|y| (0..6).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        assert_eq!(rows, vec!["+----+", "|_-#*|", "+----+"]);
+    }
+}
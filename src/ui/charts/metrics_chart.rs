@@ -1,4 +1,14 @@
+use super::rendering::heat_strip::render_heat_strip;
+use super::rendering::stacked_area::{render_stacked_area, StackedChart};
+use crate::analytics::rate::per_interval_change;
+use crate::aws::metrics::percentiles::PercentileSeries;
+use crate::config::ChartsConfig;
 use crate::models::MetricData;
+use crate::reference_lines::{PinnedLine, ReferenceLines};
+use crate::ui::components::metric_utils::{format_value, get_metric_unit};
+use crate::ui::components::sparkline_utils::downsample_buckets;
+use crate::ui::theme::{Severity, Theme};
+use crate::utils::formatting::{format_number, locale};
 // Type alias to simplify complex types
 type MetricTuple<'a> = (&'a str, String, &'a Vec<f64>, Color, f64, bool);
 
@@ -10,14 +20,92 @@ use ratatui::{
     widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph},
     Frame,
 };
+use std::collections::HashMap;
 use std::time::SystemTime;
 
+/// Severity the storage autoscaling ceiling overlay is drawn with
+const CEILING_SEVERITY: Severity = Severity::Warning;
+
+/// Severity alarm threshold overlays are drawn with
+const ALARM_SEVERITY: Severity = Severity::Critical;
+
+/// Reference lines drawn over the detail charts
+#[derive(Debug, Default)]
+pub struct ChartOverlays {
+    pub free_storage_ceiling: Option<f64>,
+    pub alarm_thresholds: HashMap<String, Vec<f64>>, // Keyed by CloudWatch metric name
+    pub baseline_bands: HashMap<String, Band>,       // Keyed by CloudWatch metric name
+    pub change_markers: Vec<SystemTime>,             // CloudTrail changes to the resource
+    pub heat_strip: bool,                            // Latency charts drawn as a heat strip
+    pub derivative: bool,                            // Counter charts drawn as their change
+    pub percentiles: HashMap<&'static str, PercentileSeries>, // Keyed by CloudWatch metric name
+    pub stacked: Option<StackedChart>,               // Family drawn in place of its members' charts
+    pub reference_lines: ReferenceLines,             // Lines pinned from the config or the chart
+    pub theme: Theme,
+    pub charts: ChartsConfig,
+}
+
+/// Typical (low, high) values per timestamp; NaN where no baseline is known yet
+pub type Band = (Vec<f64>, Vec<f64>);
+
+/// Color of the typical-value band drawn behind the series
+const BAND_COLOR: Color = Color::DarkGray;
+
+/// Color of the vertical lines marking API changes to the resource
+const CHANGE_COLOR: Color = Color::Magenta;
+
+/// Color of the lines pinned from the config or the chart
+const PINNED_COLOR: Color = Color::White;
+
+/// Color of the shaded band between the upper percentiles
+const TAIL_COLOR: Color = Color::DarkGray;
+
+/// Horizontal line drawn across a chart at a fixed value
+struct ReferenceLine {
+    value: f64,
+    color: Color,
+    marker: symbols::Marker,
+    label: Option<String>, // Written at the right end of the line
+}
+
+/// Overlays of one metric's chart
+struct MetricOverlays<'a> {
+    ceiling: Option<f64>,
+    thresholds: &'a [f64],
+    band: Option<&'a Band>,
+    changes: &'a [SystemTime],
+    heat_strip: Option<&'static str>, // Unit of the values, when drawn as a heat strip
+    derivative: bool,                 // Change per interval charted in place of the values
+    percentiles: Option<&'a PercentileSeries>, // Charted in place of the average
+    stacked: Option<&'a StackedChart>, // Family the metric is drawn with
+    pinned: &'a [PinnedLine],
+    unit: &'static str,
+}
+
+/// Everything drawn behind or across the series
+struct Backdrop<'a> {
+    reference_lines: &'a [ReferenceLine],
+    band: Option<&'a Band>,
+    tail: Option<(&'a [f64], &'a [f64])>, // Upper percentiles shaded between
+    changes: &'a [SystemTime],
+}
+
+/// How the metric series itself is plotted
+struct SeriesStyle {
+    color: Color,
+    marker: symbols::Marker,
+    graph_type: GraphType,
+    points: usize, // Buckets the series is averaged into across the chart
+}
+
 pub fn render_metrics(
     f: &mut Frame,
     area: ratatui::layout::Rect,
     metrics: &MetricData,
     scroll_offset: usize,
     metrics_per_screen: usize,
+    overlays: &ChartOverlays,
+    hints: &str,
 ) {
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -33,13 +121,21 @@ pub fn render_metrics(
     render_scrollable_individual_metrics(
         f,
         main_chunks[0],
-        &metrics.timestamps,
+        metrics,
         &individual_metrics,
         scroll_offset,
         metrics_per_screen,
+        overlays,
     );
 
-    render_instructions(f, main_chunks[1], available_count, scroll_offset);
+    render_instructions(f, main_chunks[1], available_count, scroll_offset, hints);
+}
+
+/// Series color of the `index`th available metric, as on its Braille chart
+pub fn metric_color(metrics: &MetricData, index: usize) -> Option<Color> {
+    collect_available_metrics(metrics)
+        .get(index)
+        .map(|(_, _, _, color, _, _)| *color)
 }
 
 fn collect_available_metrics(metrics: &MetricData) -> Vec<MetricTuple<'_>> {
@@ -281,10 +377,11 @@ fn collect_available_metrics(metrics: &MetricData) -> Vec<MetricTuple<'_>> {
 fn render_scrollable_individual_metrics(
     f: &mut Frame,
     area: ratatui::layout::Rect,
-    timestamps: &[SystemTime],
+    metrics: &MetricData,
     individual_metrics: &[MetricTuple],
     scroll_offset: usize,
     metrics_per_screen: usize,
+    overlays: &ChartOverlays,
 ) {
     let metrics_to_show = metrics_per_screen;
     let start_idx = scroll_offset;
@@ -316,13 +413,60 @@ fn render_scrollable_individual_metrics(
         .constraints(constraints)
         .split(area);
 
+    // Same order as individual_metrics, used to look up alarm thresholds
+    let metric_types = metrics.get_available_metrics();
+
     for (i, &metric) in visible_metrics.iter().enumerate() {
         let (name, value, history, color, max_val, available) = metric;
+        // The autoscaling ceiling only applies to the free storage chart
+        let ceiling = overlays
+            .free_storage_ceiling
+            .filter(|_| *name == "Free Storage");
+        let metric_name = metric_types
+            .get(start_idx + i)
+            .map(|metric_type| metric_type.metric_name())
+            .unwrap_or_default();
+        let thresholds = overlays
+            .alarm_thresholds
+            .get(metric_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let band = overlays.baseline_bands.get(metric_name);
+        let stacked = overlays
+            .stacked
+            .as_ref()
+            .filter(|chart| chart.family.contains(metric_name));
+        let metric_type = metric_types.get(start_idx + i);
+        let heat_strip = metric_type
+            .filter(|metric_type| overlays.heat_strip && metric_type.is_latency())
+            .map(get_metric_unit);
+        let derivative =
+            overlays.derivative && metric_type.is_some_and(|metric_type| metric_type.is_counter());
+        let percentiles = overlays.percentiles.get(metric_name);
+        let pinned = overlays
+            .reference_lines
+            .get(metric_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
         render_large_metric_chart(
             f,
             metric_chunks[i],
-            timestamps,
+            &metrics.timestamps,
             (name, value.clone(), history, *color, *max_val, *available),
+            MetricOverlays {
+                ceiling,
+                thresholds,
+                band,
+                changes: &overlays.change_markers,
+                heat_strip,
+                derivative,
+                percentiles,
+                stacked,
+                pinned,
+                unit: metric_type.map_or("", get_metric_unit),
+            },
+            &overlays.theme,
+            &overlays.charts,
         );
     }
 }
@@ -332,9 +476,10 @@ fn render_instructions(
     area: ratatui::layout::Rect,
     available_count: usize,
     scroll_offset: usize,
+    hints: &str,
 ) {
     let instructions = Paragraph::new(format!(
-        "↑/↓ scroll ({} metrics with data, showing {}/{}) • r refresh • b back • q quit",
+        "↑/↓ scroll ({} metrics with data, showing {}/{}) • {hints}",
         available_count,
         scroll_offset + 1,
         available_count
@@ -354,8 +499,38 @@ fn render_large_metric_chart(
     area: ratatui::layout::Rect,
     timestamps: &[SystemTime],
     metric: MetricTuple,
+    overlays: MetricOverlays,
+    theme: &Theme,
+    charts: &ChartsConfig,
 ) {
     let (name, value, history, color, _max_val, available) = metric;
+    let MetricOverlays {
+        ceiling,
+        thresholds,
+        band,
+        changes,
+        heat_strip,
+        derivative,
+        percentiles,
+        stacked,
+        pinned,
+        unit,
+    } = overlays;
+    // The typical-value band is learned from averages, so it is left off percentile charts
+    let band = band.filter(|_| percentiles.is_none());
+    // Thresholds, bands and pinned lines are in the values' terms, not their change
+    let (thresholds, band, pinned) = if derivative {
+        (&[][..], None, &[][..])
+    } else {
+        (thresholds, band, pinned)
+    };
+    let change;
+    let history = if derivative {
+        change = per_interval_change(history);
+        &change
+    } else {
+        history
+    };
 
     if area.width < 20 || area.height < 6 {
         let simple_widget = Paragraph::new(format!("{name}: {value}"))
@@ -382,13 +557,152 @@ fn render_large_metric_chart(
             .add_modifier(Modifier::DIM)
     };
 
-    let title_widget = Paragraph::new(format!("{name}: {value}"))
-        .style(title_style)
-        .alignment(ratatui::layout::Alignment::Left);
+    let mut title_spans = vec![Span::styled(format!("{name}: {value}"), title_style)];
+    if let Some(series) = percentiles {
+        let latest = |values: &[f64]| {
+            values
+                .iter()
+                .rev()
+                .find(|value| value.is_finite())
+                .map_or_else(|| "-".to_string(), |value| format_value(*value, unit))
+        };
+        let (low, high) = series.percentiles.band;
+        title_spans.push(Span::styled(
+            format!(
+                "  ({} {} line, {low}-{high} {}-{} shaded)",
+                series.percentiles.line,
+                latest(&series.line),
+                latest(&series.low),
+                latest(&series.high)
+            ),
+            Style::default().fg(Color::Gray),
+        ));
+    }
+    if derivative {
+        let latest = history
+            .iter()
+            .rev()
+            .find(|change| change.is_finite())
+            .map_or_else(|| "-".to_string(), |change| format_value(*change, unit));
+        title_spans.push(Span::styled(
+            format!("  (change per interval, latest {latest})"),
+            Style::default().fg(Color::Gray),
+        ));
+    }
+    if let Some(ceiling) = ceiling {
+        title_spans.push(Span::styled(
+            format!(
+                "  (autoscaling ceiling {:.1} GB)",
+                ceiling / 1024.0 / 1024.0 / 1024.0
+            ),
+            theme.style(CEILING_SEVERITY),
+        ));
+    }
+    if !thresholds.is_empty() {
+        let values: Vec<String> = thresholds.iter().map(|t| format!("{t}")).collect();
+        title_spans.push(Span::styled(
+            format!(
+                "  ({}alarm at {})",
+                theme.marker(ALARM_SEVERITY),
+                values.join(", ")
+            ),
+            theme.style(ALARM_SEVERITY),
+        ));
+    }
+    // A pinned line the latest value is past is flagged like a breached threshold
+    let latest = history.iter().rev().find(|value| value.is_finite());
+    for line in pinned {
+        let crossed = latest.is_some_and(|value| line.crossed_by(*value));
+        let (marker, style) = if crossed {
+            (
+                theme.marker(Severity::Warning),
+                theme.style(Severity::Warning),
+            )
+        } else {
+            ("", Style::default().fg(PINNED_COLOR))
+        };
+        title_spans.push(Span::styled(
+            format!("  ({marker}{})", line.describe(unit)),
+            style,
+        ));
+    }
+    if band.is_some() {
+        title_spans.push(Span::styled(
+            "  (dotted: typical for this hour)",
+            Style::default().fg(BAND_COLOR),
+        ));
+    }
+    let shown_changes: Vec<SystemTime> = match (timestamps.first(), timestamps.last()) {
+        (Some(first), Some(last)) => changes
+            .iter()
+            .copied()
+            .filter(|time| first <= time && time <= last)
+            .collect(),
+        _ => Vec::new(),
+    };
+    if !shown_changes.is_empty() {
+        title_spans.push(Span::styled(
+            format!("  (│ {} API changes, a: activity)", shown_changes.len()),
+            Style::default().fg(CHANGE_COLOR),
+        ));
+    }
+    let reference_line = |value: f64, severity: Severity| ReferenceLine {
+        value,
+        color: theme.color(severity),
+        marker: theme.line_marker(severity),
+        label: None,
+    };
+    let mut reference_lines: Vec<ReferenceLine> = thresholds
+        .iter()
+        .map(|&threshold| reference_line(threshold, ALARM_SEVERITY))
+        .collect();
+    if let Some(ceiling) = ceiling {
+        reference_lines.push(reference_line(ceiling, CEILING_SEVERITY));
+    }
+    reference_lines.extend(pinned.iter().map(|line| ReferenceLine {
+        value: line.value,
+        color: PINNED_COLOR,
+        marker: theme.chart_marker(),
+        label: Some(line.label.clone()),
+    }));
+    let title_widget =
+        Paragraph::new(Line::from(title_spans)).alignment(ratatui::layout::Alignment::Left);
     f.render_widget(title_widget, widget_chunks[0]);
 
-    if available && !history.is_empty() && widget_chunks[1].height >= 5 {
-        render_high_resolution_chart(f, widget_chunks[1], timestamps, history, color, name);
+    let marker = theme.series_marker(charts.marker);
+    if let Some(chart) = stacked {
+        render_stacked_area(f, widget_chunks[1], chart);
+    } else if let Some(unit) = heat_strip.filter(|_| available && !history.is_empty()) {
+        render_heat_strip(f, widget_chunks[1], timestamps, history, unit);
+    } else if available && !history.is_empty() && widget_chunks[1].height >= 5 {
+        // Percentiles are charted on their own timestamps, the median as the line
+        let (timestamps, history, tail) = match percentiles.filter(|_| !derivative) {
+            Some(series) => (
+                series.timestamps.as_slice(),
+                series.line.as_slice(),
+                Some((series.low.as_slice(), series.high.as_slice())),
+            ),
+            None => (timestamps, history.as_slice(), None),
+        };
+        render_high_resolution_chart(
+            f,
+            widget_chunks[1],
+            timestamps,
+            history,
+            SeriesStyle {
+                color,
+                marker,
+                graph_type: charts.graph_type(),
+                points: charts.plotted_points(widget_chunks[1].width.saturating_sub(2), marker),
+            },
+            name,
+            Backdrop {
+                reference_lines: &reference_lines,
+                band,
+                tail,
+                changes: &shown_changes,
+            },
+        );
     } else {
         let status_msg = if !available {
             "Metric not available for this DB engine/instance type".to_string()
@@ -421,10 +735,17 @@ fn render_high_resolution_chart(
     area: ratatui::layout::Rect,
     timestamps: &[SystemTime],
     history: &[f64],
-    color: Color,
+    series: SeriesStyle,
     metric_name: &str,
+    backdrop: Backdrop,
 ) {
     use chrono::{DateTime, Utc};
+    let Backdrop {
+        reference_lines,
+        band,
+        tail,
+        changes,
+    } = backdrop;
 
     if history.is_empty() || timestamps.is_empty() {
         let no_data_chart = Chart::new(vec![])
@@ -454,23 +775,76 @@ fn render_high_resolution_chart(
     let start_time: DateTime<Utc> = timestamps[0].into();
     let start_epoch = start_time.timestamp() as f64;
 
-    let data_points: Vec<(f64, f64)> = timestamps
+    let epochs: Vec<f64> = timestamps
         .iter()
-        .zip(history.iter())
-        .map(|(timestamp, &value)| {
+        .map(|timestamp| {
             let dt: DateTime<Utc> = (*timestamp).into();
-            let epoch_seconds = dt.timestamp() as f64;
-            (epoch_seconds, value)
+            dt.timestamp() as f64
         })
         .collect();
 
+    // Bucket the series to the plot width so the line is rebuilt for the
+    // current terminal size instead of being clipped
+    let buckets = series.points;
+    // Periods without a datapoint are left out rather than plotted
+    let data_points: Vec<(f64, f64)> = downsample_buckets(&epochs, buckets)
+        .into_iter()
+        .zip(downsample_buckets(history, buckets))
+        .filter(|(_, y)| y.is_finite())
+        .collect();
+
     let end_epoch = data_points
         .last()
         .map(|(x, _)| *x)
         .unwrap_or(start_epoch + 3600.0 * 3.0);
     let time_bounds = [start_epoch, end_epoch];
 
-    let (y_min, y_max) = calculate_y_bounds(history);
+    // Band edges are bucketed like the series; hours without a baseline leave gaps
+    let band_points: Vec<Vec<(f64, f64)>> = band
+        .filter(|(low, _)| low.len() == epochs.len())
+        .map(|(low, high)| {
+            let x = downsample_buckets(&epochs, buckets);
+            [low, high]
+                .into_iter()
+                .map(|edge| {
+                    x.iter()
+                        .copied()
+                        .zip(downsample_buckets(edge, buckets))
+                        .filter(|(_, y)| y.is_finite())
+                        .collect()
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // The tail is shaded with one vertical segment per bucket
+    let tail_segments: Vec<[(f64, f64); 2]> = tail
+        .filter(|(low, high)| low.len() == epochs.len() && high.len() == epochs.len())
+        .map(|(low, high)| {
+            downsample_buckets(&epochs, buckets)
+                .into_iter()
+                .zip(downsample_buckets(low, buckets))
+                .zip(downsample_buckets(high, buckets))
+                .filter(|((_, low), high)| low.is_finite() && high.is_finite())
+                .map(|((x, low), high)| [(x, low), (x, high)])
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let (mut y_min, mut y_max) = calculate_y_bounds(history);
+    for &(_, value) in band_points
+        .iter()
+        .flatten()
+        .chain(tail_segments.iter().flatten())
+    {
+        y_max = y_max.max(value);
+        y_min = y_min.min(value);
+    }
+    // Keep reference lines inside the chart with a little headroom around them
+    for &ReferenceLine { value, .. } in reference_lines {
+        y_max = y_max.max(value * 1.05);
+        y_min = y_min.min(value * 0.95);
+    }
     let y_bounds = if y_max <= y_min {
         [y_min, y_min + 1.0]
     } else {
@@ -479,15 +853,68 @@ fn render_high_resolution_chart(
 
     let dataset = Dataset::default()
         .name("")
-        .marker(symbols::Marker::Braille)
-        .graph_type(GraphType::Line)
-        .style(Style::default().fg(color))
+        .marker(series.marker)
+        .graph_type(series.graph_type)
+        .style(Style::default().fg(series.color))
         .data(&data_points);
 
+    let line_points: Vec<[(f64, f64); 2]> = reference_lines
+        .iter()
+        .map(|line| [(start_epoch, line.value), (end_epoch, line.value)])
+        .collect();
+    // The bands go first so the series is drawn over them
+    let mut datasets: Vec<Dataset> = band_points
+        .iter()
+        .map(|points| {
+            Dataset::default()
+                .name("")
+                .marker(symbols::Marker::Dot)
+                .graph_type(GraphType::Scatter)
+                .style(Style::default().fg(BAND_COLOR))
+                .data(points)
+        })
+        .collect();
+    datasets.extend(tail_segments.iter().map(|segment| {
+        Dataset::default()
+            .name("")
+            .marker(series.marker)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(TAIL_COLOR))
+            .data(segment)
+    }));
+    datasets.push(dataset);
+    let change_points: Vec<[(f64, f64); 2]> = changes
+        .iter()
+        .map(|time| {
+            let x = DateTime::<Utc>::from(*time).timestamp() as f64;
+            [(x, y_bounds[0]), (x, y_bounds[1])]
+        })
+        .collect();
+    for points in &change_points {
+        datasets.push(
+            Dataset::default()
+                .name("")
+                .marker(series.marker)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(CHANGE_COLOR))
+                .data(points),
+        );
+    }
+    for (line, points) in reference_lines.iter().zip(&line_points) {
+        datasets.push(
+            Dataset::default()
+                .name("")
+                .marker(line.marker)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(line.color))
+                .data(points),
+        );
+    }
+
     let x_labels = create_x_labels(timestamps);
     let y_labels = create_y_labels(y_bounds, metric_name);
 
-    let chart = Chart::new(vec![dataset])
+    let chart = Chart::new(datasets)
         .block(
             Block::default()
                 .borders(Borders::ALL)
@@ -507,9 +934,45 @@ fn render_high_resolution_chart(
         );
 
     f.render_widget(chart, area);
+    label_reference_lines(f, area, reference_lines, y_bounds);
 }
 
-fn calculate_y_bounds(history: &[f64]) -> (f64, f64) {
+/// Write each labeled line's label over its right end
+///
+/// The rows match where the chart plots the line: the graph fills the chart's
+/// inner area above the x-axis and its labels.
+fn label_reference_lines(
+    f: &mut Frame,
+    area: ratatui::layout::Rect,
+    reference_lines: &[ReferenceLine],
+    [y_min, y_max]: [f64; 2],
+) {
+    let inner = Block::default().borders(Borders::ALL).inner(area);
+    let rows = inner.height.saturating_sub(2);
+    if rows == 0 || y_max <= y_min {
+        return;
+    }
+    for line in reference_lines {
+        let Some(label) = &line.label else {
+            continue;
+        };
+        let from_top = ((y_max - line.value) / (y_max - y_min) * f64::from(rows - 1)).round();
+        if !(0.0..f64::from(rows)).contains(&from_top) {
+            continue;
+        }
+        let width = label.chars().count() as u16 + 1;
+        let x = inner.right().saturating_sub(width).max(inner.left());
+        f.buffer_mut().set_stringn(
+            x,
+            inner.top() + from_top as u16,
+            format!(" {label}"),
+            usize::from(inner.width),
+            Style::default().fg(line.color),
+        );
+    }
+}
+
+pub fn calculate_y_bounds(history: &[f64]) -> (f64, f64) {
     if history.len() == 1 {
         let val = history[0];
         let margin = if val.abs() > 1.0 {
@@ -536,18 +999,34 @@ fn calculate_y_bounds(history: &[f64]) -> (f64, f64) {
     }
 }
 
-fn create_x_labels(timestamps: &[SystemTime]) -> Vec<Line<'_>> {
-    use chrono::{DateTime, Utc};
+pub fn create_x_labels(timestamps: &[SystemTime]) -> Vec<Line<'_>> {
+    use chrono::{DateTime, Local};
 
     let num_x_labels = 8.min(timestamps.len());
 
+    // Show seconds when the chart spans only a few minutes (high-resolution
+    // periods), and the date when it spans more than a day
+    let span = match (timestamps.first(), timestamps.last()) {
+        (Some(first), Some(last)) => last.duration_since(*first).unwrap_or_default(),
+        _ => std::time::Duration::ZERO,
+    };
+    let locale = locale();
+    let label = |timestamp: SystemTime| {
+        let local_time: DateTime<Local> = timestamp.into();
+        if span.as_secs() > 24 * 3600 {
+            format!(
+                "{} {}",
+                locale.short_date(&local_time),
+                locale.time(&local_time, false)
+            )
+        } else {
+            locale.time(&local_time, span.as_secs() < 10 * 60)
+        }
+    };
+
     if timestamps.len() <= 1 {
         vec![Line::from(Span::styled(
-            {
-                let dt: DateTime<Utc> = timestamps[0].into();
-                let local_time: chrono::DateTime<chrono::Local> = dt.into();
-                format!("{}", local_time.format("%H:%M"))
-            },
+            label(timestamps[0]),
             Style::default().fg(Color::DarkGray),
         ))]
     } else {
@@ -559,12 +1038,8 @@ fn create_x_labels(timestamps: &[SystemTime]) -> Vec<Line<'_>> {
                     (i * (timestamps.len() - 1)) / (num_x_labels - 1)
                 };
 
-                let timestamp = timestamps[idx];
-                let dt: DateTime<Utc> = timestamp.into();
-                let local_time: chrono::DateTime<chrono::Local> = dt.into();
-
                 Line::from(Span::styled(
-                    format!("{}", local_time.format("%H:%M")),
+                    label(timestamps[idx]),
                     Style::default().fg(Color::DarkGray),
                 ))
             })
@@ -572,34 +1047,37 @@ fn create_x_labels(timestamps: &[SystemTime]) -> Vec<Line<'_>> {
     }
 }
 
-fn create_y_labels(y_bounds: [f64; 2], metric_name: &str) -> Vec<Line<'_>> {
-    let format_value = |v: f64| -> String {
-        if metric_name.contains("Memory") || metric_name.contains("Storage") {
-            let gb_value = v / (1024.0 * 1024.0 * 1024.0);
-            if gb_value >= 1.0 {
-                format!("{gb_value:.1}G")
-            } else {
-                let mb_value = v / (1024.0 * 1024.0);
-                format!("{mb_value:.0}M")
-            }
-        } else if metric_name.contains("Throughput") || metric_name.contains("Network") {
+/// Axis label for a value of the named metric, scaled to K/M or bytes as fits
+pub fn format_axis_value(v: f64, metric_name: &str) -> String {
+    if metric_name.contains("Memory") || metric_name.contains("Storage") {
+        let gb_value = v / (1024.0 * 1024.0 * 1024.0);
+        if gb_value >= 1.0 {
+            format!("{}G", format_number(gb_value, 1))
+        } else {
             let mb_value = v / (1024.0 * 1024.0);
-            if mb_value >= 1.0 {
-                format!("{mb_value:.1}M")
-            } else {
-                let kb_value = v / 1024.0;
-                format!("{kb_value:.0}K")
-            }
-        } else if v.abs() >= 1000000.0 {
-            format!("{:.1}M", v / 1000000.0)
-        } else if v.abs() >= 1000.0 {
-            format!("{:.1}K", v / 1000.0)
-        } else if v.abs() >= 1.0 {
-            format!("{v:.1}")
+            format!("{}M", format_number(mb_value, 0))
+        }
+    } else if metric_name.contains("Throughput") || metric_name.contains("Network") {
+        let mb_value = v / (1024.0 * 1024.0);
+        if mb_value >= 1.0 {
+            format!("{}M", format_number(mb_value, 1))
         } else {
-            format!("{v:.2}")
+            let kb_value = v / 1024.0;
+            format!("{}K", format_number(kb_value, 0))
         }
-    };
+    } else if v.abs() >= 1000000.0 {
+        format!("{}M", format_number(v / 1000000.0, 1))
+    } else if v.abs() >= 1000.0 {
+        format!("{}K", format_number(v / 1000.0, 1))
+    } else if v.abs() >= 1.0 {
+        format_number(v, 1)
+    } else {
+        format_number(v, 2)
+    }
+}
+
+fn create_y_labels(y_bounds: [f64; 2], metric_name: &str) -> Vec<Line<'_>> {
+    let format_value = |v: f64| format_axis_value(v, metric_name);
 
     let y_range = y_bounds[1] - y_bounds[0];
     let num_y_labels = if y_range <= 1.0 {
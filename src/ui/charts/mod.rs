@@ -1,2 +1,5 @@
 pub mod metrics_chart;
+pub mod raster;
+pub mod rendering;
 pub mod sparkline_chart;
+pub mod text_art;
@@ -0,0 +1,218 @@
+// Raster charts for terminals with a graphics protocol
+//
+// The detail chart is plotted into a pixel buffer sized to its cells, with
+// the same series, typical-value band and reference lines as the Braille
+// chart. Axis labels stay terminal text around the image, so no font is
+// needed; the background is left transparent to blend with the terminal.
+
+use super::metrics_chart::{calculate_y_bounds, Band};
+use crate::ui::graphics::RasterImage;
+use anyhow::{anyhow, Result};
+use plotters::prelude::*;
+use plotters::style::Color as _;
+use ratatui::style::Color;
+use std::time::SystemTime;
+
+/// Key color marking background pixels, made transparent after plotting
+const BACKGROUND: RGBColor = RGBColor(1, 2, 3);
+
+const GRID: RGBColor = RGBColor(70, 70, 70);
+const BAND: RGBColor = RGBColor(55, 55, 55);
+
+/// Fractions of the height at which horizontal grid lines are drawn
+pub const GRID_LINES: [f64; 3] = [0.25, 0.5, 0.75];
+
+/// One metric's series and overlays, as drawn on the detail chart
+pub struct RasterChart<'a> {
+    pub timestamps: &'a [SystemTime],
+    pub history: &'a [f64],
+    pub color: Color,
+    pub band: Option<&'a Band>,
+    pub reference_lines: Vec<(f64, Color)>,
+}
+
+impl RasterChart<'_> {
+    /// Value range of the plot, covering the series, band and reference lines
+    pub fn y_bounds(&self) -> [f64; 2] {
+        let (mut low, mut high) = calculate_y_bounds(self.history);
+        if let Some((band_low, band_high)) = self.band {
+            for value in band_low.iter().chain(band_high).filter(|v| v.is_finite()) {
+                low = low.min(*value);
+                high = high.max(*value);
+            }
+        }
+        for (value, _) in &self.reference_lines {
+            low = low.min(value * 0.95);
+            high = high.max(value * 1.05);
+        }
+        if high <= low {
+            [low, low + 1.0]
+        } else {
+            [low, high]
+        }
+    }
+
+    pub fn plot(&self, width: u32, height: u32) -> Result<RasterImage> {
+        let epochs: Vec<f64> = self.timestamps.iter().map(|t| epoch_secs(*t)).collect();
+        let (Some(&start), Some(&end)) = (epochs.first(), epochs.last()) else {
+            return Err(anyhow!("No datapoints to plot"));
+        };
+        let end = if end > start { end } else { start + 1.0 };
+        let [y_min, y_max] = self.y_bounds();
+        let plot_error = |e: &dyn std::fmt::Display| anyhow!("Failed to plot chart: {e}");
+
+        let mut rgb = vec![0u8; width as usize * height as usize * 3];
+        {
+            let root = BitMapBackend::with_buffer(&mut rgb, (width, height)).into_drawing_area();
+            root.fill(&BACKGROUND).map_err(|e| plot_error(&e))?;
+            let mut chart = ChartBuilder::on(&root)
+                .build_cartesian_2d(start..end, y_min..y_max)
+                .map_err(|e| plot_error(&e))?;
+
+            let grid = GRID_LINES.map(|fraction| {
+                let value = y_min + (y_max - y_min) * fraction;
+                PathElement::new(vec![(start, value), (end, value)], GRID)
+            });
+            chart.draw_series(grid).map_err(|e| plot_error(&e))?;
+
+            // Band first so the series is drawn over it; hours without a baseline leave gaps
+            if let Some((low, high)) = self.band.filter(|(low, _)| low.len() == epochs.len()) {
+                let mut segment: Vec<(f64, f64, f64)> = Vec::new();
+                let mut polygons = Vec::new();
+                for ((x, low), high) in epochs.iter().zip(low).zip(high) {
+                    if low.is_finite() && high.is_finite() {
+                        segment.push((*x, *low, *high));
+                    } else if !segment.is_empty() {
+                        polygons.push(band_polygon(&std::mem::take(&mut segment)));
+                    }
+                }
+                if !segment.is_empty() {
+                    polygons.push(band_polygon(&segment));
+                }
+                chart.draw_series(polygons).map_err(|e| plot_error(&e))?;
+            }
+
+            let color = rgb_color(self.color);
+            let points: Vec<(f64, f64)> = epochs
+                .iter()
+                .copied()
+                .zip(self.history.iter().copied())
+                .filter(|(_, value)| value.is_finite())
+                .collect();
+            chart
+                .draw_series(LineSeries::new(points, color.stroke_width(2)))
+                .map_err(|e| plot_error(&e))?;
+
+            for (value, line_color) in &self.reference_lines {
+                chart
+                    .draw_series(DashedLineSeries::new(
+                        [(start, *value), (end, *value)],
+                        8,
+                        6,
+                        rgb_color(*line_color).stroke_width(1),
+                    ))
+                    .map_err(|e| plot_error(&e))?;
+            }
+            root.present().map_err(|e| plot_error(&e))?;
+        }
+
+        let pixels = rgb
+            .chunks_exact(3)
+            .flat_map(|p| {
+                let alpha = if (p[0], p[1], p[2]) == (BACKGROUND.0, BACKGROUND.1, BACKGROUND.2) {
+                    0
+                } else {
+                    255
+                };
+                [p[0], p[1], p[2], alpha]
+            })
+            .collect();
+        Ok(RasterImage::Rgba {
+            width,
+            height,
+            pixels,
+        })
+    }
+}
+
+/// Filled area between a run of (x, low, high) points
+fn band_polygon(segment: &[(f64, f64, f64)]) -> Polygon<(f64, f64)> {
+    let outline: Vec<(f64, f64)> = segment
+        .iter()
+        .map(|(x, low, _)| (*x, *low))
+        .chain(segment.iter().rev().map(|(x, _, high)| (*x, *high)))
+        .collect();
+    Polygon::new(outline, BAND.filled())
+}
+
+fn epoch_secs(timestamp: SystemTime) -> f64 {
+    timestamp
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64()
+}
+
+/// Pixel color for a terminal color, using the common xterm values for named colors
+fn rgb_color(color: Color) -> RGBColor {
+    match color {
+        Color::Rgb(r, g, b) => RGBColor(r, g, b),
+        Color::Black => RGBColor(0, 0, 0),
+        Color::Red => RGBColor(205, 49, 49),
+        Color::Green => RGBColor(13, 188, 121),
+        Color::Yellow => RGBColor(229, 229, 16),
+        Color::Blue => RGBColor(36, 114, 200),
+        Color::Magenta => RGBColor(188, 63, 188),
+        Color::Cyan => RGBColor(17, 168, 205),
+        Color::Gray => RGBColor(204, 204, 204),
+        Color::DarkGray => RGBColor(118, 118, 118),
+        Color::LightRed => RGBColor(241, 76, 76),
+        Color::LightGreen => RGBColor(35, 209, 139),
+        Color::LightYellow => RGBColor(245, 245, 67),
+        Color::LightBlue => RGBColor(59, 142, 234),
+        Color::LightMagenta => RGBColor(214, 112, 214),
+        Color::LightCyan => RGBColor(41, 184, 219),
+        _ => RGBColor(229, 229, 229),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_plot_draws_series_on_transparent_background() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let timestamps: Vec<SystemTime> = (0..4)
+            .map(|i| start + Duration::from_secs(i * 300))
+            .collect();
+        let history = [10.0, 40.0, 20.0, 30.0];
+        let chart = RasterChart {
+            timestamps: &timestamps,
+            history: &history,
+            color: Color::Red,
+            band: None,
+            reference_lines: vec![(50.0, Color::Yellow)],
+        };
+
+        // The alarm threshold at 50 is kept inside the plot
+        let [low, high] = chart.y_bounds();
+        assert!(low <= 10.0 && high >= 50.0 * 1.05);
+
+        let RasterImage::Rgba {
+            width,
+            height,
+            pixels,
+        } = chart.plot(40, 20).unwrap()
+        else {
+            panic!("expected raw pixels");
+        };
+        assert_eq!((width, height, pixels.len()), (40, 20, 40 * 20 * 4));
+        let opaque: Vec<&[u8]> = pixels.chunks_exact(4).filter(|p| p[3] == 255).collect();
+        assert!(opaque.iter().any(|p| p[..3] == [205, 49, 49]));
+        assert!(
+            opaque.len() < 40 * 20 / 2,
+            "background must stay transparent"
+        );
+    }
+}
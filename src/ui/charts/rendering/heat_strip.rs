@@ -0,0 +1,184 @@
+use super::{column_stats, time_axis, ColumnStats};
+use crate::ui::components::metric_utils::format_value;
+use crate::utils::formatting::pad_to_width;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::time::SystemTime;
+
+/// Shade and color of each intensity step, lowest first
+const RAMP: [(char, Color); 5] = [
+    ('░', Color::Blue),
+    ('▒', Color::Cyan),
+    ('▓', Color::Green),
+    ('█', Color::Yellow),
+    ('█', Color::Red),
+];
+
+/// Width of the row labels left of the strips
+const GUTTER: u16 = 5;
+
+/// Draw a series as rows of shaded cells, one column per time bucket
+///
+/// The top strip shows each bucket's maximum and the bottom one its mean, so
+/// a short spike stays visible without flattening the baseline around it.
+/// Both strips share one log scale, given in the legend below them.
+pub fn render_heat_strip(
+    f: &mut Frame,
+    area: Rect,
+    timestamps: &[SystemTime],
+    history: &[f64],
+    unit: &str,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Heat strip (log scale)")
+        .border_style(Style::default().fg(Color::White));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    if inner.width <= GUTTER || inner.height < 4 {
+        return;
+    }
+
+    let columns = usize::from(inner.width - GUTTER);
+    let stats = column_stats(history, columns);
+    let Some(bounds) = log_bounds(&stats) else {
+        let empty =
+            Paragraph::new("No datapoints above zero").style(Style::default().fg(Color::Gray));
+        f.render_widget(empty, inner);
+        return;
+    };
+
+    // Two rows go to the legend and the time axis; the max strip gets any odd row
+    let strip_rows = inner.height - 2;
+    let max_rows = strip_rows.div_ceil(2);
+    let mut lines: Vec<Line> = (0..strip_rows)
+        .map(|row| {
+            let use_max = row < max_rows;
+            let label = match row {
+                0 => "max",
+                row if row == max_rows => "mean",
+                _ => "",
+            };
+            let mut spans = vec![Span::styled(
+                pad_to_width(label, usize::from(GUTTER)),
+                Style::default().fg(Color::Gray),
+            )];
+            spans.extend(stats.iter().map(|column| match column {
+                Some(column) => {
+                    let value = if use_max { column.max } else { column.mean };
+                    let (glyph, color) = step(intensity(value, bounds));
+                    Span::styled(glyph.to_string(), Style::default().fg(color))
+                }
+                None => Span::raw(" "),
+            }));
+            Line::from(spans)
+        })
+        .collect();
+
+    let mut legend = vec![Span::styled(
+        format!(
+            "{}{} ",
+            " ".repeat(usize::from(GUTTER)),
+            format_value(bounds.0, unit)
+        ),
+        Style::default().fg(Color::Gray),
+    )];
+    legend.extend(
+        RAMP.iter()
+            .map(|(glyph, color)| Span::styled(glyph.to_string(), Style::default().fg(*color))),
+    );
+    legend.push(Span::styled(
+        format!(" {}", format_value(bounds.1, unit)),
+        Style::default().fg(Color::Gray),
+    ));
+    lines.push(Line::from(legend));
+    lines.push(Line::from(Span::styled(
+        format!(
+            "{}{}",
+            " ".repeat(usize::from(GUTTER)),
+            time_axis(timestamps, columns)
+        ),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Smallest positive mean and largest maximum, the ends of the log scale
+fn log_bounds(stats: &[Option<ColumnStats>]) -> Option<(f64, f64)> {
+    let columns = stats.iter().flatten();
+    let low = columns
+        .clone()
+        .map(|column| column.mean)
+        .filter(|mean| *mean > 0.0)
+        .fold(f64::INFINITY, f64::min);
+    let high = columns
+        .map(|column| column.max)
+        .fold(f64::NEG_INFINITY, f64::max);
+    (low.is_finite() && high >= low).then_some((low, high))
+}
+
+/// Position of `value` between `low` and `high` on a log scale, from 0 to 1
+///
+/// Latency spikes are often orders of magnitude above the baseline; on a
+/// linear scale everything but the spikes would share the lowest step.
+pub fn intensity(value: f64, (low, high): (f64, f64)) -> f64 {
+    if value <= low {
+        0.0
+    } else if value >= high {
+        1.0
+    } else {
+        (value.ln() - low.ln()) / (high.ln() - low.ln())
+    }
+}
+
+fn step(intensity: f64) -> (char, Color) {
+    let index = (intensity * RAMP.len() as f64) as usize;
+    RAMP[index.min(RAMP.len() - 1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_columns_keep_spikes_and_scale_is_logarithmic() {
+        let history = [0.002, 0.002, 0.002, 0.2, 0.002, 0.002, f64::NAN, f64::NAN];
+        let stats = column_stats(&history, 4);
+        assert_eq!(
+            stats,
+            vec![
+                Some(ColumnStats {
+                    mean: 0.002,
+                    max: 0.002
+                }),
+                Some(ColumnStats {
+                    mean: 0.101,
+                    max: 0.2
+                }),
+                Some(ColumnStats {
+                    mean: 0.002,
+                    max: 0.002
+                }),
+                None,
+            ]
+        );
+
+        // Two orders of magnitude: a tenfold rise is halfway up the scale
+        let bounds = log_bounds(&stats).unwrap();
+        assert_eq!(bounds, (0.002, 0.2));
+        assert!((intensity(0.02, bounds) - 0.5).abs() < 1e-9);
+        assert_eq!(step(intensity(0.002, bounds)).1, Color::Blue);
+        assert_eq!(step(intensity(0.2, bounds)).1, Color::Red);
+
+        // Fewer points than columns stretches each one
+        let stretched = column_stats(&[1.0, 3.0], 4);
+        assert_eq!(stretched[1], stretched[0]);
+        assert_eq!(stretched[3].unwrap().max, 3.0);
+    }
+}
@@ -0,0 +1,69 @@
+// Alternative renderers for the detail chart
+pub mod heat_strip;
+pub mod stacked_area;
+
+use super::metrics_chart::create_x_labels;
+use std::time::SystemTime;
+
+/// Mean and maximum of each bucket of a series
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColumnStats {
+    pub mean: f64,
+    pub max: f64,
+}
+
+/// Mean and maximum of the finite points falling in each of `columns` columns
+///
+/// Every point lands in exactly one column; with fewer points than columns
+/// each point is stretched over several. Columns without points are None.
+pub fn column_stats(history: &[f64], columns: usize) -> Vec<Option<ColumnStats>> {
+    if history.is_empty() {
+        return Vec::new();
+    }
+    (0..columns)
+        .map(|column| {
+            let start = column * history.len() / columns;
+            let end = ((column + 1) * history.len() / columns).max(start + 1);
+            let values: Vec<f64> = history[start..end]
+                .iter()
+                .copied()
+                .filter(|value| value.is_finite())
+                .collect();
+            if values.is_empty() {
+                return None;
+            }
+            Some(ColumnStats {
+                mean: values.iter().sum::<f64>() / values.len() as f64,
+                max: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            })
+        })
+        .collect()
+}
+
+/// Time labels spread across `width` columns, dropping any that would overlap
+fn time_axis(timestamps: &[SystemTime], width: usize) -> String {
+    if timestamps.is_empty() {
+        return String::new();
+    }
+    let labels: Vec<String> = create_x_labels(timestamps)
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    let mut axis = vec![' '; width];
+    let mut next_free = 0;
+    for (i, label) in labels.iter().enumerate() {
+        let len = label.chars().count();
+        let position = match labels.len() {
+            1 => 0,
+            count => i * width.saturating_sub(len) / (count - 1),
+        };
+        if position < next_free || position + len > width {
+            continue;
+        }
+        for (offset, c) in label.chars().enumerate() {
+            axis[position + offset] = c;
+        }
+        next_free = position + len + 1;
+    }
+    axis.into_iter().collect()
+}
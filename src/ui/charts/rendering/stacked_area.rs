@@ -0,0 +1,295 @@
+use super::{column_stats, time_axis};
+use crate::models::AwsService;
+use crate::ui::components::metric_utils::format_value;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::time::SystemTime;
+
+/// Metrics whose values add up to a meaningful total
+#[derive(Debug)]
+pub struct MetricFamily {
+    pub name: &'static str,
+    pub service: AwsService,
+    pub unit: &'static str,
+    pub layers: &'static [(&'static str, &'static str)], // CloudWatch metric name and label, bottom first
+}
+
+/// Every family that can be stacked, in the order the queue page cycles through them
+pub const FAMILIES: &[MetricFamily] = &[
+    MetricFamily {
+        name: "IOPS",
+        service: AwsService::Rds,
+        unit: "Count",
+        layers: &[("ReadIOPS", "Read"), ("WriteIOPS", "Write")],
+    },
+    MetricFamily {
+        name: "Throughput",
+        service: AwsService::Rds,
+        unit: "Bytes/Second",
+        layers: &[("ReadThroughput", "Read"), ("WriteThroughput", "Write")],
+    },
+    MetricFamily {
+        name: "Network",
+        service: AwsService::Rds,
+        unit: "Bytes/Second",
+        layers: &[
+            ("NetworkReceiveThroughput", "Receive"),
+            ("NetworkTransmitThroughput", "Transmit"),
+        ],
+    },
+    MetricFamily {
+        name: "Backlog",
+        service: AwsService::Sqs,
+        unit: "Count",
+        layers: &[
+            ("ApproximateNumberOfMessagesVisible", "Visible"),
+            ("ApproximateNumberOfMessagesNotVisible", "In Flight"),
+        ],
+    },
+    MetricFamily {
+        name: "Message Operations",
+        service: AwsService::Sqs,
+        unit: "Count",
+        layers: &[
+            ("NumberOfMessagesSent", "Sent"),
+            ("NumberOfMessagesReceived", "Received"),
+            ("NumberOfMessagesDeleted", "Deleted"),
+        ],
+    },
+];
+
+/// Layer colors, bottom first
+const LAYER_COLORS: [Color; 4] = [Color::Cyan, Color::Yellow, Color::Magenta, Color::Green];
+
+/// Width of the value labels left of the chart
+const GUTTER: usize = 11;
+
+/// Partial cells at the top of the stack, in eighths
+const EIGHTHS: [char; 7] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇'];
+
+impl MetricFamily {
+    pub fn contains(&self, metric_name: &str) -> bool {
+        self.layers.iter().any(|(name, _)| *name == metric_name)
+    }
+}
+
+/// The family `metric_name` belongs to on `service`
+pub fn family_of(service: &AwsService, metric_name: &str) -> Option<&'static MetricFamily> {
+    FAMILIES
+        .iter()
+        .find(|family| &family.service == service && family.contains(metric_name))
+}
+
+/// One series of a stacked chart
+#[derive(Debug, Clone)]
+pub struct StackLayer {
+    pub label: &'static str,
+    pub values: Vec<f64>,
+    pub hidden: bool, // Toggled off: listed in the legend but not stacked
+}
+
+/// Several series drawn on top of each other, sharing one time axis
+#[derive(Debug, Clone)]
+pub struct StackedChart {
+    pub family: &'static MetricFamily,
+    pub timestamps: Vec<SystemTime>,
+    pub layers: Vec<StackLayer>, // Same order as the family's layers
+}
+
+/// Draw a family as filled layers stacked from the bottom, one column per time bucket
+///
+/// The legend numbers each layer by the key that toggles it; hidden layers
+/// drop out of the stack and the scale.
+pub fn render_stacked_area(f: &mut Frame, area: Rect, chart: &StackedChart) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("{} (stacked)", chart.family.name))
+        .border_style(Style::default().fg(Color::White));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    if inner.height < 4 {
+        return;
+    }
+
+    let unit = chart.family.unit;
+    let visible: Vec<(usize, &[f64])> = chart
+        .layers
+        .iter()
+        .enumerate()
+        .filter(|(_, layer)| !layer.hidden)
+        .map(|(index, layer)| (index, layer.values.as_slice()))
+        .collect();
+    let Some(columns) = usize::from(inner.width)
+        .checked_sub(GUTTER)
+        .filter(|columns| *columns > 0)
+    else {
+        return;
+    };
+    let tops = stack_columns(
+        &visible
+            .iter()
+            .map(|(_, values)| *values)
+            .collect::<Vec<_>>(),
+        columns,
+    );
+    let max = tops
+        .iter()
+        .filter_map(|column| column.last())
+        .fold(0.0, |max: f64, top| max.max(*top));
+
+    let mut lines = vec![legend(chart)];
+    let rows = inner.height - 2;
+    let step = if max > 0.0 {
+        max / f64::from(rows)
+    } else {
+        1.0
+    };
+    for row in (0..rows).rev() {
+        let label = match row {
+            row if row + 1 == rows => format_value(max, unit),
+            0 => format_value(0.0, unit),
+            _ => String::new(),
+        };
+        let mut spans = vec![Span::styled(
+            format!("{label:>width$} ", width = GUTTER - 1),
+            Style::default().fg(Color::DarkGray),
+        )];
+        spans.extend(
+            tops.iter()
+                .map(|column| match cell(column, f64::from(row) * step, step) {
+                    Some((glyph, layer)) => Span::styled(
+                        glyph.to_string(),
+                        Style::default().fg(layer_color(visible[layer].0)),
+                    ),
+                    None => Span::raw(" "),
+                }),
+        );
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::from(Span::styled(
+        format!(
+            "{}{}",
+            " ".repeat(GUTTER),
+            time_axis(&chart.timestamps, columns)
+        ),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn layer_color(index: usize) -> Color {
+    LAYER_COLORS[index % LAYER_COLORS.len()]
+}
+
+/// Each layer's key, color, label and latest value
+fn legend(chart: &StackedChart) -> Line<'static> {
+    let mut spans = vec![Span::raw(" ".repeat(GUTTER))];
+    for (index, layer) in chart.layers.iter().enumerate() {
+        let latest = layer
+            .values
+            .iter()
+            .rev()
+            .find(|value| value.is_finite())
+            .map_or_else(|| "-".to_string(), |v| format_value(*v, chart.family.unit));
+        let (swatch, text) = if layer.hidden {
+            (
+                Style::default().fg(Color::DarkGray),
+                Style::default().fg(Color::DarkGray),
+            )
+        } else {
+            (
+                Style::default().fg(layer_color(index)),
+                Style::default().fg(Color::Gray),
+            )
+        };
+        spans.push(Span::styled("■ ", swatch));
+        let state = if layer.hidden { " (hidden)" } else { "" };
+        spans.push(Span::styled(
+            format!("{} {} {latest}{state}   ", index + 1, layer.label),
+            text,
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Running totals of the layers' bucket means, per column, bottom layer first
+///
+/// Missing points count as zero so a gap in one layer does not hide the others.
+fn stack_columns(layers: &[&[f64]], columns: usize) -> Vec<Vec<f64>> {
+    let means: Vec<Vec<f64>> = layers
+        .iter()
+        .map(|values| {
+            let stats = column_stats(values, columns);
+            (0..columns)
+                .map(|column| {
+                    stats
+                        .get(column)
+                        .copied()
+                        .flatten()
+                        .map_or(0.0, |stats| stats.mean.max(0.0))
+                })
+                .collect()
+        })
+        .collect();
+    (0..columns)
+        .map(|column| {
+            means
+                .iter()
+                .scan(0.0, |total, layer| {
+                    *total += layer[column];
+                    Some(*total)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Glyph and layer of the cell spanning `bottom..bottom + step` in a column
+///
+/// A cell takes the layer at its midpoint; the cell the stack ends in is
+/// filled in eighths with the top layer's color.
+fn cell(tops: &[f64], bottom: f64, step: f64) -> Option<(char, usize)> {
+    let middle = bottom + step / 2.0;
+    if let Some(layer) = tops.iter().position(|top| *top >= middle) {
+        return Some(('█', layer));
+    }
+    let total = *tops.last()?;
+    let eighths = ((total - bottom) / step * 8.0).round() as usize;
+    // Layers above the one reaching the total add nothing to this column
+    let layer = tops.iter().position(|top| *top >= total)?;
+    (1..=EIGHTHS.len())
+        .contains(&eighths)
+        .then(|| (EIGHTHS[eighths - 1], layer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layers_stack_into_cells() {
+        let read = [10.0, 10.0, 30.0, 30.0];
+        let write = [5.0, 5.0, f64::NAN, f64::NAN];
+        let tops = stack_columns(&[&read, &write], 2);
+        assert_eq!(tops, vec![vec![10.0, 15.0], vec![30.0, 30.0]]);
+
+        // Rows of 10: read fills the first, write the second up to its middle
+        assert_eq!(cell(&tops[0], 0.0, 10.0), Some(('█', 0)));
+        assert_eq!(cell(&tops[0], 10.0, 10.0), Some(('█', 1)));
+        assert_eq!(cell(&tops[0], 20.0, 10.0), None);
+        // A stack ending a quarter of the way into a cell
+        assert_eq!(cell(&[12.5], 10.0, 10.0), Some(('▂', 0)));
+
+        assert_eq!(
+            family_of(&AwsService::Rds, "WriteIOPS").unwrap().name,
+            "IOPS"
+        );
+        assert!(family_of(&AwsService::Sqs, "WriteIOPS").is_none());
+    }
+}
@@ -0,0 +1,199 @@
+// Charts as text, for pasting into chat during incidents
+//
+// The charted series is drawn as a small area chart of block characters (or
+// `_ - = #` in ASCII mode) with the value axis on the left, the first and
+// last time below it and min/avg/max/last underneath. The whole chart is
+// fenced as a code block so Slack and most chat tools keep it monospaced.
+
+use super::metrics_chart::calculate_y_bounds;
+use crate::ui::components::sparkline_utils::downsample_buckets;
+use crate::utils::formatting::{display_width, locale};
+use chrono::{DateTime, Local};
+use std::time::{Duration, SystemTime};
+
+/// Plot size in characters; longer series are averaged down to `WIDTH` columns
+pub const WIDTH: usize = 60;
+pub const HEIGHT: usize = 8;
+
+/// Partially filled cells, one to seven eighths from the bottom
+const UNICODE_PARTIALS: [char; 7] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇'];
+const ASCII_PARTIALS: [char; 7] = ['_', '_', '-', '-', '=', '=', '#'];
+
+/// Spans from one day on show the date with the time
+const DATED_SPAN: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Glyphs of the plot and its axes
+struct Glyphs {
+    full: char,
+    partials: [char; 7],
+    tick: char,
+    axis: char,
+    corner: char,
+    rule: char,
+}
+
+const UNICODE: Glyphs = Glyphs {
+    full: '█',
+    partials: UNICODE_PARTIALS,
+    tick: '┤',
+    axis: '│',
+    corner: '└',
+    rule: '─',
+};
+
+const ASCII: Glyphs = Glyphs {
+    full: '#',
+    partials: ASCII_PARTIALS,
+    tick: '+',
+    axis: '|',
+    corner: '+',
+    rule: '-',
+};
+
+/// The series as a fenced text chart; `format` renders values in the metric's unit
+pub fn render_text_chart(
+    title: &str,
+    timestamps: &[SystemTime],
+    history: &[f64],
+    format: impl Fn(f64) -> String,
+    ascii: bool,
+) -> String {
+    let glyphs = if ascii { &ASCII } else { &UNICODE };
+    let finite: Vec<f64> = history.iter().copied().filter(|v| v.is_finite()).collect();
+    let mut lines = vec!["```".to_string(), title.to_string()];
+    if finite.is_empty() {
+        lines.push("(no datapoints)".to_string());
+        lines.push("```".to_string());
+        return lines.join("\n");
+    }
+
+    let (low, high) = calculate_y_bounds(&finite);
+    let high = if high > low { high } else { low + 1.0 };
+    let columns = downsample_buckets(history, WIDTH);
+    let levels: Vec<Option<usize>> = columns
+        .iter()
+        .map(|value| {
+            value.is_finite().then(|| {
+                let fraction = ((value - low) / (high - low)).clamp(0.0, 1.0);
+                // At least one eighth, so the lowest values still show
+                ((fraction * (HEIGHT * 8) as f64).round() as usize).max(1)
+            })
+        })
+        .collect();
+
+    // Value labels on the top, middle and bottom rows
+    let labels: Vec<Option<String>> = (0..HEIGHT)
+        .map(|row| match row {
+            0 => Some(format(high)),
+            row if row == HEIGHT / 2 => Some(format((low + high) / 2.0)),
+            row if row == HEIGHT - 1 => Some(format(low)),
+            _ => None,
+        })
+        .collect();
+    let label_width = labels
+        .iter()
+        .flatten()
+        .map(|label| display_width(label))
+        .max()
+        .unwrap_or(0);
+
+    for (row, label) in labels.iter().enumerate() {
+        let floor = (HEIGHT - 1 - row) * 8;
+        let cells: String = levels
+            .iter()
+            .map(
+                |level| match level.map(|level| level.saturating_sub(floor).min(8)) {
+                    None | Some(0) => ' ',
+                    Some(8) => glyphs.full,
+                    Some(eighths) => glyphs.partials[eighths - 1],
+                },
+            )
+            .collect();
+        let (label, edge) = match label {
+            Some(label) => (label.as_str(), glyphs.tick),
+            None => ("", glyphs.axis),
+        };
+        let padding = " ".repeat(label_width - display_width(label));
+        lines.push(format!("{padding}{label} {edge}{}", cells.trim_end()));
+    }
+    lines.push(format!(
+        "{} {}{}",
+        " ".repeat(label_width),
+        glyphs.corner,
+        glyphs.rule.to_string().repeat(columns.len())
+    ));
+
+    if let (Some(&first), Some(&last)) = (timestamps.first(), timestamps.last()) {
+        let dated = last.duration_since(first).unwrap_or_default() >= DATED_SPAN;
+        let (start, end) = (time_text(first, dated), time_text(last, dated));
+        let gap = (columns.len() + 1).saturating_sub(display_width(&start) + display_width(&end));
+        lines.push(format!(
+            "{} {start}{}{end}",
+            " ".repeat(label_width),
+            " ".repeat(gap.max(2))
+        ));
+    }
+
+    let min = finite.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = finite.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let avg = finite.iter().sum::<f64>() / finite.len() as f64;
+    let last = finite[finite.len() - 1];
+    lines.push(format!(
+        "min {}  avg {}  max {}  last {}",
+        format(min),
+        format(avg),
+        format(max),
+        format(last)
+    ));
+    lines.push("```".to_string());
+    lines.join("\n")
+}
+
+fn time_text(timestamp: SystemTime, dated: bool) -> String {
+    let time = DateTime::<Local>::from(timestamp);
+    if dated {
+        format!(
+            "{} {}",
+            locale().short_date(&time),
+            locale().time(&time, false)
+        )
+    } else {
+        locale().time(&time, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_chart_layout_and_stats() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let timestamps: Vec<SystemTime> = (0..4)
+            .map(|i| start + Duration::from_secs(i * 300))
+            .collect();
+        let history = [f64::NAN, 10.0, 20.0, 40.0];
+
+        let text = render_text_chart(
+            "orders-db CPU Utilization",
+            &timestamps,
+            &history,
+            |value| format!("{value:.0}%"),
+            true,
+        );
+        let lines: Vec<&str> = text.lines().collect();
+        // Fences, title, plot rows, axis, times, stats
+        assert_eq!(lines.len(), HEIGHT + 6);
+        assert_eq!(lines[0], "```");
+        assert_eq!(lines[1], "orders-db CPU Utilization");
+        assert_eq!(lines[HEIGHT + 4], "min 10%  avg 23%  max 40%  last 40%");
+        assert!(text.is_ascii());
+        // The top row holds only the peak, partly filled; the missing first datapoint stays blank
+        assert_eq!(lines[2], "43% +   -");
+        assert!(lines[HEIGHT + 1].starts_with(" 7% +"));
+        assert_eq!(lines[HEIGHT + 1].chars().nth(5), Some(' '));
+
+        let empty = render_text_chart("idle", &[], &[], |value| value.to_string(), false);
+        assert_eq!(empty, "```\nidle\n(no datapoints)\n```");
+    }
+}
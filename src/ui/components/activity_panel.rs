@@ -0,0 +1,92 @@
+use super::details_pane::centered_rect;
+use super::queue_summary::format_age;
+use crate::models::App;
+use crate::ui::keymap::{overlay_hints, KeyContext};
+use crate::ui::theme::Severity;
+use crate::utils::formatting::locale;
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Render recent CloudTrail write calls against the selected resource as an overlay
+///
+/// Calls that failed are listed dimmed with their error code, since they did
+/// not change the resource and are not marked on the charts.
+pub fn render_activity_panel(f: &mut Frame, app: &App, area: Rect) {
+    let popup = centered_rect(80, 70, area);
+    let theme = &app.config.theme;
+    let activity = &app.view.activity;
+    let title = format!(
+        "API Activity ({})",
+        overlay_hints(app, KeyContext::ActivityPanel)
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    f.render_widget(Clear, popup);
+    if activity.events.is_empty() {
+        let empty = Paragraph::new("No write API calls recorded for this resource in this range")
+            .style(Style::default().fg(Color::Gray))
+            .block(block);
+        f.render_widget(empty, popup);
+        return;
+    }
+
+    let items: Vec<ListItem> = activity
+        .events
+        .iter()
+        .map(|event| {
+            let when = event
+                .time
+                .map(|at| {
+                    let age = at.elapsed().unwrap_or_default().as_secs_f64();
+                    format!(
+                        "{} ({} ago)",
+                        locale().time(&DateTime::<Local>::from(at), false),
+                        format_age(age)
+                    )
+                })
+                .unwrap_or_else(|| "-".to_string());
+            let (name_style, outcome) = match &event.error {
+                Some(code) => (
+                    Style::default()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::DIM),
+                    Span::styled(
+                        format!("  {}{code}", theme.marker(Severity::Warning)),
+                        theme.style(Severity::Warning),
+                    ),
+                ),
+                None => (
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                    Span::raw(""),
+                ),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{when:<24} "), Style::default().fg(Color::Gray)),
+                Span::styled(event.name.clone(), name_style),
+                Span::styled(
+                    format!("  by {}", event.user),
+                    Style::default().fg(Color::Cyan),
+                ),
+                outcome,
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().bg(Color::DarkGray));
+    let mut list_state = ListState::default();
+    list_state.select(Some(activity.selected_index));
+    f.render_stateful_widget(list, popup, &mut list_state);
+}
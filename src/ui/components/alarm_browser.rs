@@ -0,0 +1,150 @@
+use super::composite_alarms::{state_cell, state_severity};
+use super::footer::render_footer;
+use crate::models::App;
+use crate::utils::formatting::format_number;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+/// States counted in the header, in the order alarms are listed
+const STATES: [&str; 3] = ["ALARM", "INSUFFICIENT_DATA", "OK"];
+
+/// The account's metric alarms, firing first, with the reason of the one selected
+///
+/// The header counts the alarms in each state for the namespace listed, so
+/// the page doubles as a status panel for it.
+pub fn render_alarm_browser(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header - alarms per state
+            Constraint::Min(0),    // Alarms
+            Constraint::Length(4), // Why the selected alarm is in its state
+            Constraint::Length(1), // Controls at bottom
+        ])
+        .split(f.area());
+
+    render_header(f, chunks[0], app);
+    render_alarms(f, chunks[1], app);
+    render_reason(f, chunks[2], app);
+    render_footer(f, chunks[3], app);
+}
+
+fn render_header(f: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.config.theme;
+    let shown = app.browsed_alarms();
+    let mut spans = Vec::new();
+    for state in STATES {
+        let count = shown.iter().filter(|alarm| alarm.state == state).count();
+        if !spans.is_empty() {
+            spans.push(Span::raw("  "));
+        }
+        spans.push(match state_severity(state).filter(|_| count > 0) {
+            Some(severity) => Span::styled(
+                format!("{}{state} {count}", theme.marker(severity)),
+                theme.style(severity),
+            ),
+            None => Span::styled(format!("{state} {count}"), Style::default().fg(Color::Gray)),
+        });
+    }
+
+    let header = Paragraph::new(Line::from(spans)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "Alarms: {}",
+                app.view
+                    .alarm_browser
+                    .namespace
+                    .as_deref()
+                    .unwrap_or("every namespace")
+            ))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(header, area);
+}
+
+fn render_alarms(f: &mut Frame, area: Rect, app: &mut App) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Metric alarms (Enter opens the metric, n filters by namespace)")
+        .border_style(Style::default().fg(Color::White));
+
+    let shown = app.browsed_alarms();
+    if shown.is_empty() {
+        let (text, color) = match &app.view.alarm_browser.notice {
+            Some(notice) => (notice.as_str(), Color::Red),
+            None => ("Listing alarms...", Color::Gray),
+        };
+        let empty = Paragraph::new(text.to_string())
+            .style(Style::default().fg(color))
+            .wrap(Wrap { trim: true })
+            .block(block);
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let theme = &app.config.theme;
+    let rows: Vec<Row> = shown
+        .iter()
+        .map(|alarm| {
+            let gray = Style::default().fg(Color::Gray);
+            Row::new(vec![
+                Cell::from(alarm.name.clone()),
+                state_cell(theme, &alarm.state),
+                Cell::from(alarm.namespace.clone()).style(gray),
+                Cell::from(alarm.metric_name.clone()),
+                Cell::from(
+                    alarm
+                        .threshold
+                        .map_or_else(|| "-".to_string(), |value| format_number(value, 2)),
+                )
+                .style(gray),
+            ])
+        })
+        .collect();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(24),
+            Constraint::Length(20),
+            Constraint::Length(22),
+            Constraint::Length(30),
+            Constraint::Length(12),
+        ],
+    )
+    .header(
+        Row::new(vec!["Name", "State", "Namespace", "Metric", "Threshold"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .highlight_style(Style::default().bg(Color::DarkGray))
+    .block(block);
+    f.render_stateful_widget(table, area, &mut app.view.alarm_browser.table_state);
+}
+
+fn render_reason(f: &mut Frame, area: Rect, app: &App) {
+    let shown = app.browsed_alarms();
+    let selected = app
+        .view
+        .alarm_browser
+        .table_state
+        .selected()
+        .and_then(|index| shown.get(index));
+    let text = selected
+        .and_then(|alarm| alarm.reason.clone())
+        .unwrap_or_default();
+    let reason = Paragraph::new(text)
+        .style(Style::default().fg(Color::Gray))
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("State reason")
+                .border_style(Style::default().fg(Color::White)),
+        );
+    f.render_widget(reason, area);
+}
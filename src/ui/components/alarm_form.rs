@@ -0,0 +1,149 @@
+use super::details_pane::centered_rect;
+use super::queue_summary::format_age;
+use crate::analytics::backtest::Backtest;
+use crate::aws::alarms::form::{AlarmForm, FormField};
+use crate::aws::metrics::types::StatisticType;
+use crate::utils::formatting::{format_number, locale};
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Popup form for creating an alarm on the charted metric
+///
+/// Below the fields, the rule as entered is replayed over the loaded history
+/// so the threshold can be tuned before the alarm is created.
+pub fn render_alarm_form(
+    f: &mut Frame,
+    form: &AlarmForm,
+    backtest: Option<&Backtest>,
+    hints: &str,
+    area: Rect,
+) {
+    let popup = centered_rect(70, 60, area);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Alarm: ", Style::default().fg(Color::Gray)),
+            Span::styled(form.alarm_name(), Style::default().fg(Color::White)),
+        ]),
+        Line::from(Span::styled(
+            format!(
+                "{} {} ({})",
+                form.namespace.trim_start_matches("AWS/"),
+                form.metric_name,
+                match form.statistic {
+                    StatisticType::Average => "Average",
+                    StatisticType::Sum => "Sum",
+                    StatisticType::Maximum => "Maximum",
+                    StatisticType::Minimum => "Minimum",
+                }
+            ),
+            Style::default().fg(Color::Gray),
+        )),
+        Line::from(""),
+    ];
+
+    for field in FormField::ALL {
+        let value = match field {
+            FormField::Threshold => format!("{}_", form.threshold),
+            FormField::Comparison => format!("metric {} threshold", form.comparison.symbol()),
+            FormField::Period => format!("{} s", form.period_secs()),
+            FormField::EvaluationPeriods => form.evaluation_periods.to_string(),
+            FormField::Topic => form.topic().unwrap_or("none").to_string(),
+        };
+        let focused = field == form.focused;
+        let value_style = if focused {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{} {:<20}", if focused { "▶" } else { " " }, field.label()),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(value, value_style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.extend(backtest_lines(backtest));
+
+    let form_widget = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Create Alarm ({hints})"))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    f.render_widget(Clear, popup);
+    f.render_widget(form_widget, popup);
+}
+
+/// When the entered rule would have fired over the loaded history
+fn backtest_lines(backtest: Option<&Backtest>) -> Vec<Line<'static>> {
+    let Some(backtest) = backtest else {
+        return vec![Line::from(Span::styled(
+            "Backtest: enter a numeric threshold to replay it over the loaded history",
+            Style::default().fg(Color::Gray),
+        ))];
+    };
+    let span = backtest
+        .to
+        .duration_since(backtest.from)
+        .unwrap_or_default();
+    let time = |at| locale().time(&DateTime::<Local>::from(at), false);
+
+    let mut lines = vec![Line::from(vec![
+        Span::styled("Backtest: ", Style::default().fg(Color::Gray)),
+        if backtest.firings.is_empty() {
+            Span::styled(
+                format!(
+                    "would not have fired in the last {}",
+                    format_age(span.as_secs_f64())
+                ),
+                Style::default().fg(Color::Green),
+            )
+        } else {
+            Span::styled(
+                format!(
+                    "would have fired {} time(s) in the last {}, in ALARM for {}",
+                    backtest.firings.len(),
+                    format_age(span.as_secs_f64()),
+                    format_age(backtest.time_firing().as_secs_f64())
+                ),
+                Style::default().fg(Color::Yellow),
+            )
+        },
+    ])];
+    // The most recent episodes matter most when tuning
+    for firing in backtest.firings.iter().rev().take(3) {
+        let until = firing
+            .end
+            .map(|end| format!("until {}", time(end)))
+            .unwrap_or_else(|| "still firing".to_string());
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  {} {until} ({}, worst {})",
+                time(firing.start),
+                format_age(firing.duration(backtest.to).as_secs_f64()),
+                format_number(firing.worst, 2)
+            ),
+            Style::default().fg(Color::White),
+        )));
+    }
+    if backtest.coarser_history {
+        lines.push(Line::from(Span::styled(
+            "  History is coarser than the alarm period; shorter spikes may be missed",
+            Style::default().fg(Color::Gray),
+        )));
+    }
+    lines
+}
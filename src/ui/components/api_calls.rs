@@ -0,0 +1,122 @@
+use super::footer::render_footer;
+use crate::aws::usage::{self, Usage};
+use crate::models::App;
+use crate::utils::formatting::format_number;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Paragraph, Row, Table},
+    Frame,
+};
+
+/// The API calls made this session, per operation and per page, with their estimated cost
+pub fn render_api_calls(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(55), // Per operation
+            Constraint::Min(0),         // Per page
+            Constraint::Length(1),      // Controls at bottom
+        ])
+        .split(f.area());
+
+    let usage = usage::snapshot();
+    render_operations(f, chunks[0], &usage);
+    render_pages(f, chunks[1], &usage);
+    render_footer(f, chunks[2], app);
+}
+
+fn header(cells: Vec<&'static str>) -> Row<'static> {
+    Row::new(cells).style(Style::default().add_modifier(Modifier::BOLD))
+}
+
+fn count(value: u64) -> String {
+    format_number(value as f64, 0)
+}
+
+fn render_operations(f: &mut Frame, area: Rect, usage: &Usage) {
+    let total = usage.total();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "API Calls This Session ({} calls, estimated CloudWatch cost {})",
+            count(total.calls),
+            usage::format_cost(total.cost)
+        ))
+        .border_style(Style::default().fg(Color::Cyan));
+    let operations = usage.operations();
+    if operations.is_empty() {
+        let empty = Paragraph::new("No AWS calls made yet")
+            .style(Style::default().fg(Color::Gray))
+            .block(block);
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let rows: Vec<Row> = operations
+        .iter()
+        .map(|operation| {
+            let metrics = match operation.tally.metrics {
+                0 => String::new(),
+                metrics => count(metrics),
+            };
+            Row::new(vec![
+                operation.service.clone(),
+                operation.operation.clone(),
+                count(operation.tally.calls),
+                metrics,
+                usage::format_cost(operation.tally.cost),
+            ])
+        })
+        .collect();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(18),
+            Constraint::Min(24),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(10),
+        ],
+    )
+    .header(header(vec![
+        "Service",
+        "Operation",
+        "Calls",
+        "Metrics",
+        "Est. cost",
+    ]))
+    .block(block);
+    f.render_widget(table, area);
+}
+
+/// Calls made while each page was shown, to tell which pages are expensive to keep open
+fn render_pages(f: &mut Frame, area: Rect, usage: &Usage) {
+    let pages = usage.pages();
+    let rows: Vec<Row> = pages
+        .iter()
+        .map(|(page, tally)| {
+            Row::new(vec![
+                page.clone(),
+                count(tally.calls),
+                usage::format_cost(tally.cost),
+            ])
+        })
+        .collect();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(24),
+            Constraint::Length(8),
+            Constraint::Length(10),
+        ],
+    )
+    .header(header(vec!["Page", "Calls", "Est. cost"]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Per Page (calls made while it was shown, refreshes included)")
+            .border_style(Style::default().fg(Color::White)),
+    );
+    f.render_widget(table, area);
+}
@@ -0,0 +1,218 @@
+use super::{
+    footer::render_footer,
+    instance_details::{render_metrics_loading, render_no_recent_data},
+    metric_rows::{render_metric_rows, MetricRow},
+};
+use crate::aws::batch::{format_job_count, JOB_STATUSES};
+use crate::aws::metrics::providers::{BatchMetricProvider, MetricProvider};
+use crate::models::{App, BatchJobQueue, ComputeEnvironment};
+use crate::ui::theme::{Severity, Theme};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Metrics view for a single Batch job queue
+///
+/// The header shows job counts per status from ListJobs and the queue's
+/// compute environments in scheduling order; the sparklines show the ECS
+/// metrics of the compute environment selected with Tab.
+pub fn render_batch_queue_summary(f: &mut Frame, app: &mut App) {
+    let environment_lines = app
+        .get_selected_batch_queue()
+        .map_or(1, |queue| queue.compute_environments.len().max(1));
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4), // Header - state and job counts
+            Constraint::Length(2 + environment_lines as u16), // Compute environments
+            Constraint::Min(0),    // Content
+            Constraint::Length(1), // Controls at bottom
+        ])
+        .split(f.area());
+
+    if let Some(queue) = app.get_selected_batch_queue() {
+        render_queue_info(f, chunks[0], app, queue);
+        render_compute_environments(f, chunks[1], app, queue);
+    }
+
+    if let Some(error_msg) = &app.error_message {
+        let error = Paragraph::new(error_msg.as_str())
+            .style(Style::default().fg(Color::Red))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Error")
+                    .border_style(Style::default().fg(Color::Red)),
+            )
+            .wrap(Wrap { trim: false });
+        f.render_widget(error, chunks[2]);
+    } else if app.metrics_loading {
+        render_metrics_loading(f, chunks[2]);
+    } else if let Some(reason) = app.stale_data_reason() {
+        render_no_recent_data(f, chunks[2], &reason);
+    } else {
+        render_environment_metrics(f, chunks[2], app);
+    }
+
+    render_footer(f, chunks[3], app);
+}
+
+/// Severity of a job count: failures are critical, a backlog with nothing running a warning
+pub fn job_count_severity(queue: &BatchJobQueue, status: &str) -> Option<Severity> {
+    let count = queue.job_count(status);
+    match status {
+        "FAILED" if count > 0 => Some(Severity::Critical),
+        "RUNNABLE" if count > 0 && queue.job_count("RUNNING") == 0 => Some(Severity::Warning),
+        _ => None,
+    }
+}
+
+fn render_queue_info(f: &mut Frame, area: Rect, app: &App, queue: &BatchJobQueue) {
+    let theme = &app.config.theme;
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::White));
+    let state_style = if queue.is_enabled() {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+
+    let mut counts = vec![label("Jobs: ")];
+    for status in JOB_STATUSES {
+        let style = match job_count_severity(queue, status) {
+            Some(severity) => theme.style(severity),
+            None => Style::default().fg(Color::Cyan),
+        };
+        counts.push(Span::styled(
+            format!(
+                "{} {}",
+                status.to_lowercase(),
+                format_job_count(queue.job_count(status))
+            ),
+            style,
+        ));
+        counts.push(Span::raw("  "));
+    }
+
+    let lines = vec![
+        Line::from(vec![
+            label("State: "),
+            Span::styled(queue.state.to_lowercase(), state_style),
+            Span::raw("  "),
+            label("Status: "),
+            Span::styled(
+                queue.status.to_lowercase(),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::raw("  "),
+            label("Priority: "),
+            Span::styled(queue.priority.to_string(), Style::default().fg(Color::Gray)),
+        ]),
+        Line::from(counts),
+    ];
+
+    let info = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Job Queue: {}", queue.name))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(info, area);
+}
+
+fn render_compute_environments(f: &mut Frame, area: Rect, app: &App, queue: &BatchJobQueue) {
+    let theme = &app.config.theme;
+    let lines: Vec<Line> = if queue.compute_environments.is_empty() {
+        vec![Line::from(Span::styled(
+            "No compute environments attached",
+            Style::default().fg(Color::Gray),
+        ))]
+    } else {
+        queue
+            .compute_environments
+            .iter()
+            .enumerate()
+            .map(|(index, environment)| {
+                environment_line(
+                    theme,
+                    environment,
+                    index == app.view.compute_environment_index,
+                )
+            })
+            .collect()
+    };
+
+    let list = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Compute Environments")
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(list, area);
+}
+
+fn environment_line<'a>(
+    theme: &Theme,
+    environment: &'a ComputeEnvironment,
+    selected: bool,
+) -> Line<'a> {
+    let vcpu = |value: Option<i32>| value.map_or_else(|| "-".to_string(), |v| v.to_string());
+    let name_style = if selected {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::White)
+    };
+    Line::from(vec![
+        Span::styled(if selected { "> " } else { "  " }, name_style),
+        Span::styled(environment.name.clone(), name_style),
+        Span::raw(" | "),
+        Span::styled(
+            environment.kind.to_lowercase(),
+            Style::default().fg(Color::Gray),
+        ),
+        Span::raw(" | "),
+        // An INVALID environment cannot scale, so jobs stay RUNNABLE
+        Span::styled(
+            format!(
+                "{}, {}",
+                environment.state.to_lowercase(),
+                environment.status.to_lowercase()
+            ),
+            if environment.status == "INVALID" {
+                theme.style(Severity::Critical)
+            } else {
+                Style::default().fg(Color::Gray)
+            },
+        ),
+        Span::raw(" | "),
+        Span::styled(
+            format!(
+                "vCPUs {} desired ({}-{})",
+                vcpu(environment.desired_vcpus),
+                vcpu(environment.min_vcpus),
+                vcpu(environment.max_vcpus)
+            ),
+            Style::default().fg(Color::Cyan),
+        ),
+    ])
+}
+
+fn render_environment_metrics(f: &mut Frame, area: Rect, app: &App) {
+    render_metric_rows(
+        f,
+        area,
+        app,
+        BatchMetricProvider::new().get_metrics_config(),
+        app.view.service_metrics.as_ref().map(|m| &m.raw_metrics),
+        "No CloudWatch data for this compute environment yet",
+        |definition, value| MetricRow {
+            max: Some(100),
+            ..MetricRow::latest(definition, value)
+        },
+    );
+}
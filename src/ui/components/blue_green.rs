@@ -0,0 +1,187 @@
+use super::footer::footer_line;
+use super::instance_details::render_metrics_loading;
+use super::sparkline_utils::generate_inline_sparkline;
+use crate::aws::rds::blue_green::{identifier_from_arn, BlueGreenDeployment};
+use crate::models::App;
+use crate::ui::theme::{Severity, Theme};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+/// Width of the inline lag sparkline next to each green member
+const LAG_SPARKLINE_WIDTH: usize = 20;
+
+/// A blue/green deployment: switchover status, member pairs with lag, and tasks
+pub fn render_blue_green(f: &mut Frame, app: &mut App) {
+    let Some(deployment) = app.get_selected_blue_green_deployment().cloned() else {
+        let empty = Paragraph::new("Blue/green deployment is no longer listed")
+            .style(Style::default().fg(Color::Gray))
+            .block(Block::default().borders(Borders::ALL).title("Blue/Green"));
+        f.render_widget(empty, f.area());
+        return;
+    };
+
+    let member_height = (deployment.members.len() as u16).clamp(1, 10) + 2;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(5),             // Header - deployment status
+            Constraint::Length(member_height), // Blue -> green members
+            Constraint::Min(0),                // Tasks
+            Constraint::Length(1),             // Controls at bottom
+        ])
+        .split(f.area());
+
+    render_header(f, chunks[0], &app.config.theme, &deployment);
+
+    if app.metrics_loading {
+        render_metrics_loading(f, chunks[1].union(chunks[2]));
+    } else {
+        render_members(f, chunks[1], app, &deployment);
+        render_tasks(f, chunks[2], &app.config.theme, &deployment);
+    }
+
+    let mut footer = footer_line(app);
+    if deployment.is_switching_over() && app.status_message.is_none() {
+        footer.spans.insert(
+            0,
+            Span::styled("auto-refresh 5s • ", Style::default().fg(Color::Gray)),
+        );
+    }
+    f.render_widget(Paragraph::new(footer), chunks[3]);
+}
+
+fn render_header(f: &mut Frame, area: Rect, theme: &Theme, deployment: &BlueGreenDeployment) {
+    let lines = vec![
+        Line::from(vec![
+            Span::styled("Status: ", Style::default().fg(Color::White)),
+            status_span(theme, &deployment.status),
+            Span::raw("  "),
+            Span::styled(
+                deployment.status_details.clone().unwrap_or_default(),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Blue: ", Style::default().fg(Color::Blue)),
+            Span::raw(identifier_from_arn(&deployment.source_arn).to_string()),
+            Span::raw("  →  "),
+            Span::styled("Green: ", Style::default().fg(Color::Green)),
+            Span::raw(identifier_from_arn(&deployment.target_arn).to_string()),
+        ]),
+        Line::from(Span::styled(
+            deployment.identifier.clone(),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let header = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Blue/Green: {}", deployment.name))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(header, area);
+}
+
+fn render_members(f: &mut Frame, area: Rect, app: &App, deployment: &BlueGreenDeployment) {
+    let version = |arn: &str| app.engine_version_for_arn(arn).unwrap_or("-").to_string();
+
+    let items: Vec<ListItem> = deployment
+        .members
+        .iter()
+        .map(|member| {
+            let target = identifier_from_arn(&member.target_arn);
+            let lag = app
+                .view
+                .blue_green
+                .lags
+                .iter()
+                .find(|lag| lag.identifier == target);
+            let (lag_text, sparkline) = match lag {
+                Some(lag) => (
+                    lag.current
+                        .map(|secs| format!("{secs:.1} s"))
+                        .unwrap_or_else(|| "no data".to_string()),
+                    generate_inline_sparkline(&lag.history, LAG_SPARKLINE_WIDTH),
+                ),
+                None => ("no data".to_string(), String::new()),
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!(
+                        "{} ({})",
+                        identifier_from_arn(&member.source_arn),
+                        version(&member.source_arn)
+                    ),
+                    Style::default().fg(Color::Blue),
+                ),
+                Span::raw("  →  "),
+                Span::styled(
+                    format!("{target} ({})", version(&member.target_arn)),
+                    Style::default().fg(Color::Green),
+                ),
+                Span::raw("  "),
+                status_span(&app.config.theme, &member.status),
+                Span::raw("  lag "),
+                Span::styled(lag_text, Style::default().fg(Color::Cyan)),
+                Span::raw(" "),
+                Span::styled(sparkline, Style::default().fg(Color::Cyan)),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Members (blue → green)")
+            .border_style(Style::default().fg(Color::White)),
+    );
+    f.render_widget(list, area);
+}
+
+fn render_tasks(f: &mut Frame, area: Rect, theme: &Theme, deployment: &BlueGreenDeployment) {
+    let items: Vec<ListItem> = deployment
+        .tasks
+        .iter()
+        .map(|(name, status)| {
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{name}  "), Style::default().fg(Color::White)),
+                status_span(theme, status),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Tasks")
+            .border_style(Style::default().fg(Color::White)),
+    );
+    f.render_widget(list, area);
+}
+
+fn status_severity(status: &str) -> Option<Severity> {
+    match status {
+        "AVAILABLE" | "COMPLETED" | "SWITCHOVER_COMPLETED" => Some(Severity::Ok),
+        "SWITCHOVER_IN_PROGRESS" | "IN_PROGRESS" | "PROVISIONING" => Some(Severity::Warning),
+        "FAILED" | "INVALID_CONFIGURATION" | "SWITCHOVER_FAILED" => Some(Severity::Critical),
+        _ => None,
+    }
+}
+
+/// Status badge, colored by state and prefixed with a marker when patterns are on
+fn status_span(theme: &Theme, status: &str) -> Span<'static> {
+    match status_severity(status) {
+        Some(severity) => Span::styled(
+            format!("{}{status}", theme.marker(severity)),
+            theme.style(severity),
+        ),
+        None => Span::styled(status.to_string(), Style::default().fg(Color::Gray)),
+    }
+}
@@ -0,0 +1,91 @@
+use super::details_pane::centered_rect;
+use crate::aws::time_range::format_period;
+use crate::bookmarks::{Bookmark, BookmarksPanel};
+use crate::models::App;
+use crate::ui::keymap::{overlay_hints, KeyContext};
+use crate::utils::formatting::pad_to_width;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Cells given to a bookmark's name; longer names are cut short
+const NAME_WIDTH: usize = 28;
+
+/// Render the saved chart views, with the name being typed for a new one
+pub fn render_bookmarks_panel(f: &mut Frame, app: &App, panel: &BookmarksPanel, area: Rect) {
+    let popup = centered_rect(70, 60, area);
+    f.render_widget(Clear, popup);
+
+    let (list_area, input_area) = match panel.name_input {
+        Some(_) => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(3)])
+                .split(popup);
+            (chunks[0], Some(chunks[1]))
+        }
+        None => (popup, None),
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "Bookmarks ({})",
+            overlay_hints(app, KeyContext::BookmarksPanel)
+        ))
+        .border_style(Style::default().fg(Color::Cyan));
+    if app.config.bookmarks.is_empty() {
+        let empty =
+            Paragraph::new("No bookmarks yet. Press 'n' on a resource's metrics to save the view.")
+                .style(Style::default().fg(Color::Gray))
+                .block(block);
+        f.render_widget(empty, list_area);
+    } else {
+        let items: Vec<ListItem> = app.config.bookmarks.iter().map(bookmark_item).collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::default().bg(Color::DarkGray));
+        let mut list_state = ListState::default();
+        list_state.select(Some(panel.selected_index));
+        f.render_stateful_widget(list, list_area, &mut list_state);
+    }
+
+    if let (Some(name), Some(input_area)) = (&panel.name_input, input_area) {
+        let input = Paragraph::new(format!("{name}_"))
+            .style(Style::default().fg(Color::White))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Name (Enter: Save • Esc: Cancel)")
+                    .border_style(Style::default().fg(Color::Yellow)),
+            );
+        f.render_widget(input, input_area);
+    }
+}
+
+fn bookmark_item(bookmark: &Bookmark) -> ListItem<'_> {
+    let mut view = format!("{} {}", bookmark.service, bookmark.resource);
+    if let Some(metric) = &bookmark.metric {
+        view.push_str(&format!(" / {metric}"));
+    }
+    view.push_str(&format!(" / {}", bookmark.range));
+    if let Some(period) = bookmark.period_secs {
+        view.push_str(&format!(" @ {}", format_period(period)));
+    }
+    if bookmark.chart {
+        view.push_str(" (chart)");
+    }
+    ListItem::new(Line::from(vec![
+        Span::styled(
+            format!("{} ", pad_to_width(&bookmark.name, NAME_WIDTH)),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(view, Style::default().fg(Color::Gray)),
+    ]))
+}
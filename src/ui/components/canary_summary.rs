@@ -0,0 +1,157 @@
+use super::{
+    footer::render_footer,
+    instance_details::{render_metrics_loading, render_no_recent_data},
+    metric_rows::{render_metric_rows, MetricRow},
+    queue_summary::format_age,
+};
+use crate::aws::metrics::providers::{MetricProvider, SyntheticsMetricProvider};
+use crate::aws::synthetics::artifacts_console_url;
+use crate::models::{App, Canary};
+use crate::ui::theme::Severity;
+use crate::utils::formatting::locale;
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Metrics view for a single Synthetics canary
+///
+/// The header shows the last run from DescribeCanariesLastRun and where its
+/// artifacts are stored; the sparklines show the canary's CloudWatch series.
+pub fn render_canary_summary(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(6), // Header - state, last run and artifacts
+            Constraint::Min(0),    // Content
+            Constraint::Length(1), // Controls at bottom
+        ])
+        .split(f.area());
+
+    if let Some(canary) = app.get_selected_canary() {
+        render_canary_info(f, chunks[0], app, canary);
+    }
+
+    if let Some(error_msg) = &app.error_message {
+        let error = Paragraph::new(error_msg.as_str())
+            .style(Style::default().fg(Color::Red))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Error")
+                    .border_style(Style::default().fg(Color::Red)),
+            )
+            .wrap(Wrap { trim: false });
+        f.render_widget(error, chunks[1]);
+    } else if app.metrics_loading {
+        render_metrics_loading(f, chunks[1]);
+    } else if let Some(reason) = app.stale_data_reason() {
+        render_no_recent_data(f, chunks[1], &reason);
+    } else {
+        render_canary_metrics(f, chunks[1], app);
+    }
+
+    render_footer(f, chunks[2], app);
+}
+
+fn render_canary_info(f: &mut Frame, area: Rect, app: &App, canary: &Canary) {
+    let theme = &app.config.theme;
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::White));
+
+    let mut lines = vec![Line::from(vec![
+        label("State: "),
+        Span::styled(
+            canary.state.to_lowercase(),
+            Style::default().fg(Color::Green),
+        ),
+        Span::raw("  "),
+        label("Schedule: "),
+        Span::styled(canary.schedule.clone(), Style::default().fg(Color::Cyan)),
+        Span::raw("  "),
+        label("Runtime: "),
+        Span::styled(canary.runtime.clone(), Style::default().fg(Color::Gray)),
+    ])];
+
+    let location = match &canary.last_run {
+        Some(run) => {
+            let severity = if run.failed() {
+                Severity::Critical
+            } else {
+                Severity::Ok
+            };
+            let when = run
+                .completed
+                .map(|at| {
+                    let age = at.elapsed().unwrap_or_default().as_secs_f64();
+                    format!(
+                        " at {} ({} ago)",
+                        locale().time(&DateTime::<Local>::from(at), false),
+                        format_age(age)
+                    )
+                })
+                .unwrap_or_default();
+            lines.push(Line::from(vec![
+                label("Last run: "),
+                Span::styled(
+                    format!(
+                        "{}{}{when}",
+                        theme.marker(severity),
+                        run.state.to_lowercase()
+                    ),
+                    theme.style(severity),
+                ),
+            ]));
+            if let Some(reason) = run.failure_reason.as_ref().filter(|_| run.failed()) {
+                lines.push(Line::from(vec![
+                    label("Reason: "),
+                    Span::styled(reason.clone(), theme.style(Severity::Critical)),
+                ]));
+            }
+            Some(run.artifact_location.as_str()).filter(|location| !location.is_empty())
+        }
+        None => {
+            lines.push(Line::from(vec![
+                label("Last run: "),
+                Span::styled("none yet", Style::default().fg(Color::Gray)),
+            ]));
+            None
+        }
+    }
+    .unwrap_or(&canary.artifact_location);
+
+    lines.push(Line::from(vec![
+        label("Artifacts: "),
+        Span::styled(
+            artifacts_console_url(location).unwrap_or_else(|| "-".to_string()),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    let info = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Canary: {}", canary.name))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(info, area);
+}
+
+fn render_canary_metrics(f: &mut Frame, area: Rect, app: &App) {
+    render_metric_rows(
+        f,
+        area,
+        app,
+        SyntheticsMetricProvider::new().get_metrics_config(),
+        app.view.service_metrics.as_ref().map(|m| &m.raw_metrics),
+        "No CloudWatch data for this canary yet",
+        |definition, value| MetricRow {
+            // A dip in SuccessPercent is the failure signal, so draw it against a fixed 100
+            max: (definition.unit.as_deref() == Some("Percent")).then_some(100),
+            ..MetricRow::latest(definition, value)
+        },
+    );
+}
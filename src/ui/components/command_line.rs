@@ -0,0 +1,21 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+/// The ':' command line, over the footer on the bottom row
+pub fn render_command_line(f: &mut Frame, line: &str, hints: &str, area: Rect) {
+    if area.height == 0 {
+        return;
+    }
+    let row = Rect::new(area.x, area.bottom() - 1, area.width, 1);
+    let text = Line::from(vec![
+        Span::styled(format!(":{line}_"), Style::default().fg(Color::Cyan)),
+        Span::styled(format!("   {hints}"), Style::default().fg(Color::DarkGray)),
+    ]);
+    f.render_widget(Clear, row);
+    f.render_widget(Paragraph::new(text), row);
+}
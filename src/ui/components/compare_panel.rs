@@ -0,0 +1,203 @@
+use super::details_pane::centered_rect;
+use super::display_utils::get_selected_time_range_display;
+use super::metric_utils::{format_value, get_metric_unit};
+use super::replica_topology::{chart_bounds, epoch_seconds, time_label};
+use crate::aws::rds::compare::MetricComparison;
+use crate::models::App;
+use crate::ui::keymap::{overlay_hints, KeyContext};
+use crate::ui::theme::{Severity, Theme};
+use crate::utils::formatting::pad_to_width;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Axis, Block, Borders, Chart, Clear, Dataset, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+const CURRENT_COLOR: Color = Color::Cyan;
+const PREVIOUS_COLOR: Color = Color::Magenta;
+
+/// Render the shown range against the same range earlier as an overlay
+///
+/// Each metric's mean change is listed, highlighted when it is larger than the
+/// two windows' noise explains; the selected metric's windows are overlaid in
+/// the chart below, the earlier one shifted onto the current one's times.
+pub fn render_compare_panel(f: &mut Frame, app: &App, area: Rect) {
+    let popup = centered_rect(90, 85, area);
+    let compare = &app.view.compare;
+    let range = App::get_time_range_options()
+        .get(app.get_current_time_range_index())
+        .map_or_else(String::new, |(label, _, _, _)| {
+            get_selected_time_range_display(label)
+        });
+    let title = format!(
+        "Compare {}: last {range} vs {} ({})",
+        compare.instance.as_deref().unwrap_or("-"),
+        compare.offset.label(),
+        overlay_hints(app, KeyContext::ComparePanel)
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    f.render_widget(Clear, popup);
+    if compare.rows.is_empty() {
+        let empty = Paragraph::new("No datapoints in either window")
+            .style(Style::default().fg(Color::Gray))
+            .block(block);
+        f.render_widget(empty, popup);
+        return;
+    }
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(inner);
+
+    render_change_table(f, chunks[0], app);
+    if let Some(row) = compare.rows.get(compare.selected_index) {
+        render_overlaid_chart(f, chunks[1], app, row);
+    }
+}
+
+fn render_change_table(f: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.config.theme;
+    let compare = &app.view.compare;
+    let header = Line::from(Span::styled(
+        format!(
+            "{}{:>14}{:>14}{:>12}",
+            pad_to_width("Metric", 30),
+            "Earlier",
+            "Now",
+            "Change"
+        ),
+        Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    ));
+
+    let items: Vec<ListItem> = compare
+        .rows
+        .iter()
+        .map(|row| change_item(theme, row))
+        .collect();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(area);
+    f.render_widget(Paragraph::new(header), chunks[0]);
+
+    let list = List::new(items).highlight_style(Style::default().bg(Color::DarkGray));
+    let mut list_state = ListState::default();
+    list_state.select(Some(compare.selected_index));
+    f.render_stateful_widget(list, chunks[1], &mut list_state);
+}
+
+fn change_item<'a>(theme: &Theme, row: &MetricComparison) -> ListItem<'a> {
+    let unit = get_metric_unit(&row.metric);
+    let mean =
+        |value: Option<f64>| value.map_or_else(|| "-".to_string(), |v| format_value(v, unit));
+    let diff = row.diff.as_ref();
+    let change = match diff.and_then(|diff| diff.change_pct) {
+        Some(pct) => format!("{pct:+.1}%"),
+        None if diff.is_some() => "new".to_string(),
+        None => "-".to_string(),
+    };
+    let (marker, change_style) = match diff {
+        Some(diff) if diff.significant => (
+            theme.marker(Severity::Warning),
+            theme.style(Severity::Warning).add_modifier(Modifier::BOLD),
+        ),
+        _ => ("", Style::default().fg(Color::Gray)),
+    };
+
+    ListItem::new(Line::from(vec![
+        Span::styled(
+            pad_to_width(row.metric.display_name(), 30),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled(
+            format!("{:>14}", mean(diff.map(|d| d.previous_mean))),
+            Style::default().fg(PREVIOUS_COLOR),
+        ),
+        Span::styled(
+            format!("{:>14}", mean(diff.map(|d| d.current_mean))),
+            Style::default().fg(CURRENT_COLOR),
+        ),
+        Span::styled(format!("{:>12}", format!("{marker}{change}")), change_style),
+    ]))
+}
+
+fn render_overlaid_chart(f: &mut Frame, area: Rect, app: &App, row: &MetricComparison) {
+    let unit = get_metric_unit(&row.metric);
+    let shift = app.view.compare.offset.duration().as_secs_f64();
+    let points = |values: &[f64], timestamps: &[std::time::SystemTime], shift: f64| {
+        timestamps
+            .iter()
+            .zip(values)
+            .map(|(timestamp, &value)| (epoch_seconds(*timestamp) + shift, value))
+            .collect::<Vec<_>>()
+    };
+    let series = vec![
+        (
+            PREVIOUS_COLOR,
+            points(&row.previous, &row.previous_timestamps, shift),
+        ),
+        (
+            CURRENT_COLOR,
+            points(&row.current, &row.current_timestamps, 0.0),
+        ),
+    ];
+
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .title(Line::from(vec![
+            Span::raw(format!("{} ", row.metric.display_name())),
+            Span::styled("now", Style::default().fg(CURRENT_COLOR)),
+            Span::raw(" vs "),
+            Span::styled(
+                app.view.compare.offset.label(),
+                Style::default().fg(PREVIOUS_COLOR),
+            ),
+        ]));
+    let Some((x_bounds, y_bounds)) = chart_bounds(&series) else {
+        return;
+    };
+
+    let datasets = series
+        .iter()
+        .map(|(color, points)| {
+            Dataset::default()
+                .marker(app.config.theme.series_marker(app.config.charts.marker))
+                .graph_type(app.config.charts.graph_type())
+                .style(Style::default().fg(*color))
+                .data(points)
+        })
+        .collect();
+
+    let chart = Chart::new(datasets)
+        .block(block)
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds(x_bounds)
+                .labels(vec![
+                    Line::from(time_label(x_bounds[0])),
+                    Line::from(time_label(x_bounds[1])),
+                ]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds(y_bounds)
+                .labels(vec![
+                    Line::from(format_value(y_bounds[0], unit)),
+                    Line::from(format_value(y_bounds[1], unit)),
+                ]),
+        );
+    f.render_widget(chart, area);
+}
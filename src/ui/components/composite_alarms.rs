@@ -0,0 +1,214 @@
+use super::footer::render_footer;
+use crate::aws::alarms::composite::RuleLine;
+use crate::models::App;
+use crate::ui::theme::{Severity, Theme};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+/// The account's composite alarms, or the rule of the one opened as a tree
+///
+/// Each line of the tree is a condition of the rule with the current state of
+/// the child alarm it names. Lines driving the composite into ALARM are marked.
+pub fn render_composite_alarms(f: &mut Frame, app: &mut App) {
+    let opened = app.view.composite_alarms.open.is_some();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(if opened { 4 } else { 0 }), // Header - composite state and reason
+            Constraint::Min(0),                             // Composites or rule tree
+            Constraint::Length(1),                          // Controls at bottom
+        ])
+        .split(f.area());
+
+    if opened {
+        render_header(f, chunks[0], app);
+        render_tree(f, chunks[1], app);
+    } else {
+        render_list(f, chunks[1], app);
+    }
+
+    render_footer(f, chunks[2], app);
+}
+
+/// Severity an alarm state is shown with; None for INSUFFICIENT_DATA or unknown alarms
+pub fn state_severity(state: &str) -> Option<Severity> {
+    match state {
+        "ALARM" => Some(Severity::Critical),
+        "OK" => Some(Severity::Ok),
+        _ => None,
+    }
+}
+
+pub fn state_cell<'a>(theme: &Theme, state: &str) -> Cell<'a> {
+    match state_severity(state) {
+        Some(severity) => {
+            Cell::from(format!("{}{state}", theme.marker(severity))).style(theme.style(severity))
+        }
+        None => Cell::from(state.to_string()).style(Style::default().fg(Color::Gray)),
+    }
+}
+
+/// "drives" for a condition making the composite fire, else whether it holds
+pub fn holds_label(line: &RuleLine) -> &'static str {
+    match (line.driving, line.holds) {
+        (true, _) => "drives",
+        (false, true) => "true",
+        (false, false) => "false",
+    }
+}
+
+fn render_empty(f: &mut Frame, area: Rect, block: Block, app: &App, text: &str) {
+    let (text, color) = match &app.view.composite_alarms.notice {
+        Some(notice) => (notice.as_str(), Color::Red),
+        None => (text, Color::Gray),
+    };
+    let empty = Paragraph::new(text.to_string())
+        .style(Style::default().fg(color))
+        .wrap(Wrap { trim: true })
+        .block(block);
+    f.render_widget(empty, area);
+}
+
+fn render_list(f: &mut Frame, area: Rect, app: &mut App) {
+    let page = &app.view.composite_alarms;
+    let firing = page.alarms.iter().filter(|alarm| alarm.is_firing()).count();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "Composite alarms ({firing} firing of {}, Enter shows the rule)",
+            page.alarms.len()
+        ))
+        .border_style(Style::default().fg(Color::Cyan));
+
+    if page.alarms.is_empty() {
+        render_empty(f, area, block, app, "Listing composite alarms...");
+        return;
+    }
+
+    let theme = &app.config.theme;
+    let rows: Vec<Row> = page
+        .alarms
+        .iter()
+        .map(|alarm| {
+            Row::new(vec![
+                Cell::from(alarm.name.clone()),
+                state_cell(theme, &alarm.state),
+                Cell::from(alarm.rule.clone()).style(Style::default().fg(Color::Gray)),
+            ])
+        })
+        .collect();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(32),
+            Constraint::Length(20),
+            Constraint::Min(20),
+        ],
+    )
+    .header(
+        Row::new(vec!["Name", "State", "Rule"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .highlight_style(Style::default().bg(Color::DarkGray))
+    .block(block);
+    f.render_stateful_widget(table, area, &mut app.view.composite_alarms.table_state);
+}
+
+fn render_header(f: &mut Frame, area: Rect, app: &App) {
+    let page = &app.view.composite_alarms;
+    let Some(alarm) = page.open_alarm() else {
+        return;
+    };
+    let theme = &app.config.theme;
+    let state = match state_severity(&alarm.state) {
+        Some(severity) => Span::styled(
+            format!("{}{}", theme.marker(severity), alarm.state),
+            theme.style(severity),
+        ),
+        None => Span::styled(alarm.state.clone(), Style::default().fg(Color::Gray)),
+    };
+    let lines = vec![
+        Line::from(vec![
+            state,
+            Span::raw("  "),
+            Span::styled(alarm.rule.clone(), Style::default().fg(Color::White)),
+        ]),
+        Line::styled(
+            alarm.reason.clone().unwrap_or_default(),
+            Style::default().fg(Color::Gray),
+        ),
+    ];
+    let header = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Composite alarm: {}", alarm.name))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(header, area);
+}
+
+fn render_tree(f: &mut Frame, area: Rect, app: &mut App) {
+    let page = &app.view.composite_alarms;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Rule (marked conditions drive the alarm; Enter opens a child's metric)")
+        .border_style(Style::default().fg(Color::White));
+
+    if page.lines.is_empty() {
+        render_empty(f, area, block, app, "No rule to show");
+        return;
+    }
+
+    let theme = &app.config.theme;
+    let rows: Vec<Row> = page
+        .lines
+        .iter()
+        .map(|line| {
+            let marker = if line.driving {
+                theme.marker(Severity::Critical)
+            } else {
+                ""
+            };
+            let style = if line.driving {
+                theme.style(Severity::Critical)
+            } else if line.holds {
+                Style::default().fg(Color::White)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            let condition = format!("{}{marker}{}", "  ".repeat(line.depth), line.label);
+            let state = match &line.state {
+                Some(state) => state_cell(theme, state),
+                None if line.alarm.is_some() => {
+                    Cell::from("unknown").style(Style::default().fg(Color::Gray))
+                }
+                None => Cell::from(""),
+            };
+            Row::new(vec![
+                Cell::from(condition).style(style),
+                state,
+                Cell::from(holds_label(line)).style(style),
+            ])
+        })
+        .collect();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(30),
+            Constraint::Length(20),
+            Constraint::Length(8),
+        ],
+    )
+    .header(
+        Row::new(vec!["Condition", "Child state", "Holds"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .highlight_style(Style::default().bg(Color::DarkGray))
+    .block(block);
+    f.render_stateful_widget(table, area, &mut app.view.composite_alarms.table_state);
+}
@@ -0,0 +1,72 @@
+use super::details_pane::centered_rect;
+use crate::confirmation::Confirmation;
+use crate::ui::theme::{Severity, Theme};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Popup holding a write action until its phrase is typed
+///
+/// The phrase turns green once it matches; Enter does nothing before then.
+pub fn render_confirmation_prompt(
+    f: &mut Frame,
+    confirmation: &Confirmation,
+    theme: &Theme,
+    hints: &str,
+    area: Rect,
+) {
+    let popup = centered_rect(60, 30, area);
+    let gray = Style::default().fg(Color::Gray);
+
+    let mut lines = vec![
+        Line::styled(
+            confirmation.question.clone(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Line::from(""),
+    ];
+    if let Some(environment) = &confirmation.environment {
+        lines.push(Line::from(vec![
+            Span::styled("Environment: ", gray),
+            Span::styled(
+                format!("{}{environment}", theme.marker(Severity::Warning)),
+                theme.style(Severity::Warning),
+            ),
+        ]));
+    }
+    lines.push(Line::from(vec![
+        Span::styled("Type ", gray),
+        Span::styled(
+            confirmation.phrase.clone(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" to confirm", gray),
+    ]));
+    let typed_style = if confirmation.is_typed() {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default().fg(Color::Cyan)
+    };
+    lines.push(Line::from(vec![
+        Span::styled("> ", gray),
+        Span::styled(format!("{}_", confirmation.typed), typed_style),
+    ]));
+
+    let widget = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Confirm write action ({hints})"))
+            .border_style(Style::default().fg(Color::Yellow)),
+    );
+
+    f.render_widget(Clear, popup);
+    f.render_widget(widget, popup);
+}
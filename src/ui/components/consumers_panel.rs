@@ -0,0 +1,158 @@
+use super::details_pane::centered_rect;
+use super::queue_summary::format_age;
+use crate::aws::lambda::{LambdaConsumer, LOOKBACK};
+use crate::aws::metrics::types::StatisticType;
+use crate::models::App;
+use crate::ui::keymap::{overlay_hints, KeyContext};
+use crate::ui::theme::{Severity, Theme};
+use crate::utils::formatting::{format_number, locale};
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Error lines shown per consumer, newest first
+const SHOWN_ERRORS: usize = 5;
+
+/// Render the Lambda functions consuming the queue being viewed as an overlay
+///
+/// The queue's oldest message age heads the panel: it rises when the
+/// consumers below fall behind, whether by failing, being throttled or being
+/// held to a low concurrency.
+pub fn render_consumers_panel(f: &mut Frame, app: &App, area: Rect) {
+    let popup = centered_rect(80, 80, area);
+    let theme = &app.config.theme;
+    let panel = &app.view.consumers;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "Lambda consumers: {} ({})",
+            panel.queue.as_deref().unwrap_or("-"),
+            overlay_hints(app, KeyContext::ConsumersPanel)
+        ))
+        .border_style(Style::default().fg(Color::Cyan));
+    f.render_widget(Clear, popup);
+
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::White));
+    let oldest = app
+        .queue_oldest_age()
+        .map_or_else(|| "-".to_string(), format_age);
+    let mut lines = vec![
+        Line::from(vec![
+            label("Oldest message waiting: "),
+            Span::styled(oldest, Style::default().fg(Color::Cyan)),
+        ]),
+        Line::from(""),
+    ];
+
+    if panel.queue.is_some() && panel.consumers.is_empty() {
+        lines.push(Line::styled(
+            "No Lambda event source mapping reads from this queue. Consumers polling it \
+             directly, e.g. from ECS or EC2, are not listed.",
+            Style::default().fg(Color::Gray),
+        ));
+    }
+    for consumer in &panel.consumers {
+        consumer_lines(theme, consumer, &mut lines);
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((panel.scroll as u16, 0))
+        .block(block);
+    f.render_widget(paragraph, popup);
+}
+
+fn consumer_lines(theme: &Theme, consumer: &LambdaConsumer, lines: &mut Vec<Line<'static>>) {
+    let mapping = &consumer.mapping;
+    let severity = consumer.severity();
+    let gray = Style::default().fg(Color::Gray);
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!("{}{}", theme.marker(severity), mapping.function_name()),
+            theme.style(severity).add_modifier(Modifier::BOLD),
+        ),
+        Span::raw("  "),
+        Span::styled(mapping.state.clone(), gray),
+    ]));
+
+    let limit = |value: Option<i32>| value.map_or_else(|| "-".to_string(), |v| v.to_string());
+    lines.push(Line::styled(
+        format!(
+            "Batch size {}  Max concurrency {}  Reserved concurrency {}",
+            limit(mapping.batch_size),
+            limit(mapping.maximum_concurrency()),
+            limit(consumer.reserved_concurrency)
+        ),
+        gray,
+    ));
+    if let Some(result) = &mapping.last_processing_result {
+        lines.push(Line::styled(format!("Last poll: {result}"), gray));
+    }
+
+    let mut metrics = vec![Span::styled(
+        format!("Last {}h: ", LOOKBACK.as_secs() / 3600),
+        Style::default().fg(Color::White),
+    )];
+    for metric in &consumer.metrics {
+        let value = metric.summary().map_or_else(
+            || "-".to_string(),
+            |value| match metric.unit.as_str() {
+                "Milliseconds" => format!("{}ms", format_number(value, 0)),
+                _ => format_number(value, 0),
+            },
+        );
+        let prefix = match metric.statistic {
+            StatisticType::Maximum => "peak ",
+            StatisticType::Average => "avg ",
+            _ => "",
+        };
+        let flagged = matches!(metric.name.as_str(), "Errors" | "Throttles")
+            && metric.summary().unwrap_or(0.0) > 0.0;
+        let style = if flagged {
+            theme.style(Severity::Warning)
+        } else {
+            Style::default().fg(Color::Cyan)
+        };
+        metrics.push(Span::styled(
+            format!("{} {prefix}{value}  ", metric.name),
+            style,
+        ));
+    }
+    lines.push(Line::from(metrics));
+
+    match &consumer.recent_errors {
+        None => lines.push(Line::styled(
+            format!(
+                "No readable log group /aws/lambda/{}",
+                mapping.function_name()
+            ),
+            gray,
+        )),
+        Some(errors) if errors.is_empty() => {
+            lines.push(Line::styled("No error lines logged", gray))
+        }
+        Some(errors) => {
+            lines.push(Line::styled(
+                format!("Recent errors ({})", errors.len()),
+                Style::default().fg(Color::White),
+            ));
+            let locale = locale();
+            for event in errors.iter().rev().take(SHOWN_ERRORS) {
+                let time = DateTime::<Local>::from(event.timestamp);
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {} ", locale.time(&time, true)), gray),
+                    Span::styled(
+                        event.message.trim_end().to_string(),
+                        theme.style(Severity::Critical),
+                    ),
+                ]));
+            }
+        }
+    }
+    lines.push(Line::from(""));
+}
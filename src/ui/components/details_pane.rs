@@ -0,0 +1,97 @@
+use crate::models::App;
+use crate::ui::keymap::{overlay_hints, KeyContext};
+use crate::utils::formatting::{display_width, pad_to_width, slice_to_width};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+/// Render the expandable instance details pane as an overlay
+///
+/// Every attribute gets its own row; the selected row's value scrolls
+/// horizontally so long endpoints and ARNs can be read in full.
+pub fn render_details_pane(f: &mut Frame, app: &App, area: Rect) {
+    let popup = centered_rect(80, 80, area);
+    let attributes = app.get_selected_attributes();
+
+    let key_width = attributes
+        .iter()
+        .map(|(key, _)| display_width(key))
+        .max()
+        .unwrap_or(0);
+    let value_width = (popup.width as usize).saturating_sub(key_width + 6);
+
+    let items: Vec<ListItem> = attributes
+        .iter()
+        .enumerate()
+        .map(|(i, (key, value))| {
+            let is_selected = i == app.view.details_pane.selected_index;
+            let offset = if is_selected {
+                app.view.details_pane.hscroll
+            } else {
+                0
+            };
+            let visible = slice_to_width(value, offset, value_width);
+
+            let value_style = if is_selected {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{}  ", pad_to_width(key, key_width)),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::styled(visible, value_style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    "Instance Details ({})",
+                    overlay_hints(app, KeyContext::DetailsPane)
+                ))
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .highlight_style(Style::default().bg(Color::DarkGray));
+
+    let mut list_state = ListState::default();
+    if !attributes.is_empty() {
+        list_state.select(Some(app.view.details_pane.selected_index));
+    }
+
+    f.render_widget(Clear, popup);
+    f.render_stateful_widget(list, popup, &mut list_state);
+}
+
+/// Build a rectangle centered in `area` using percentage dimensions
+pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
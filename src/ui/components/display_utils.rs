@@ -1,12 +1,3 @@
-/// Truncate string to fit within specified length with ellipsis
-pub fn truncate_string(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
-    }
-}
-
 /// Calculate responsive time panel width based on terminal width
 pub fn calculate_time_panel_width(total_width: u16) -> u16 {
     // Responsive breakpoints: min 20, max 25 chars based on available space
@@ -19,6 +10,22 @@ pub fn calculate_time_panel_width(total_width: u16) -> u16 {
     }
 }
 
+/// Narrowest metric list column: a name, a sparkline of ~25 cells and the value
+const MIN_GRID_COLUMN_WIDTH: u16 = 65;
+const MAX_GRID_COLUMNS: usize = 4;
+
+/// Number of metric list columns (1-4) for the panel's shape and the metrics to show
+///
+/// Columns are added only while the metrics overflow the panel's height and
+/// every column stays readable, so a tall panel keeps one column with wide
+/// sparklines and a wide, short one spreads the metrics across.
+pub fn calculate_grid_columns(panel_width: u16, panel_height: u16, metric_count: usize) -> usize {
+    let fitting = usize::from(panel_width / MIN_GRID_COLUMN_WIDTH).clamp(1, MAX_GRID_COLUMNS);
+    // Each metric block is 3 lines inside the panel border
+    let rows = usize::from(panel_height.saturating_sub(2) / 3).max(1);
+    metric_count.div_ceil(rows).clamp(1, fitting)
+}
+
 /// Get abbreviated display name for time range selection
 pub fn get_selected_time_range_display(selected_time_period: &str) -> String {
     match selected_time_period {
@@ -35,3 +42,21 @@ pub fn get_selected_time_range_display(selected_time_period: &str) -> String {
         _ => selected_time_period.to_string(), // Fallback to original if no match
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_columns_follow_panel_shape_and_metric_count() {
+        // Tall enough for every metric: one column, whatever the width
+        assert_eq!(calculate_grid_columns(300, 80, 20), 1);
+        // Short and wide: as many columns as the metrics need
+        assert_eq!(calculate_grid_columns(300, 20, 20), 4);
+        assert_eq!(calculate_grid_columns(300, 32, 20), 2);
+        // Short and narrow: limited by what stays readable
+        assert_eq!(calculate_grid_columns(140, 20, 20), 2);
+        assert_eq!(calculate_grid_columns(60, 20, 20), 1);
+        assert_eq!(calculate_grid_columns(300, 20, 0), 1);
+    }
+}
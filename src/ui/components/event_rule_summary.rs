@@ -0,0 +1,180 @@
+use super::{
+    footer::render_footer,
+    instance_details::{render_metrics_loading, render_no_recent_data},
+    metric_rows::{render_metric_rows, MetricRow},
+    metric_utils::format_value,
+};
+use crate::aws::eventbridge::arn_resource;
+use crate::aws::metrics::providers::{EventBridgeMetricProvider, MetricProvider};
+use crate::models::{App, EventRule};
+use crate::ui::theme::Severity;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Targets listed in the header before the rest are summarised as a count
+const MAX_TARGET_LINES: usize = 4;
+
+/// Metrics whose datapoints mean events were lost rather than just delayed
+const FAILURE_METRICS: [&str; 3] = [
+    "FailedInvocations",
+    "ThrottledRules",
+    "InvocationsFailedToBeSentToDlq",
+];
+
+/// Metrics view for a single EventBridge rule
+///
+/// The header lists the rule's targets with their dead-letter queues, since a
+/// target without one drops failed deliveries silently; the sparklines show
+/// the rule's `AWS/Events` series.
+pub fn render_event_rule_summary(f: &mut Frame, app: &mut App) {
+    let target_lines = app
+        .get_selected_event_rule()
+        .map(|rule| rule.targets.len().clamp(1, MAX_TARGET_LINES + 1))
+        .unwrap_or(1);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4 + target_lines as u16), // Header - state, trigger and targets
+            Constraint::Min(0),                          // Content
+            Constraint::Length(1),                       // Controls at bottom
+        ])
+        .split(f.area());
+
+    if let Some(rule) = app.get_selected_event_rule() {
+        render_rule_info(f, chunks[0], app, rule);
+    }
+
+    if let Some(error_msg) = &app.error_message {
+        let error = Paragraph::new(error_msg.as_str())
+            .style(Style::default().fg(Color::Red))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Error")
+                    .border_style(Style::default().fg(Color::Red)),
+            )
+            .wrap(Wrap { trim: false });
+        f.render_widget(error, chunks[1]);
+    } else if app.metrics_loading {
+        render_metrics_loading(f, chunks[1]);
+    } else if let Some(reason) = app.stale_data_reason() {
+        render_no_recent_data(f, chunks[1], &reason);
+    } else {
+        render_rule_metrics(f, chunks[1], app);
+    }
+
+    render_footer(f, chunks[2], app);
+}
+
+fn render_rule_info(f: &mut Frame, area: Rect, app: &App, rule: &EventRule) {
+    let theme = &app.config.theme;
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::White));
+    let state_style = if rule.is_enabled() {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            label("State: "),
+            Span::styled(rule.state.to_lowercase(), state_style),
+            Span::raw("  "),
+            label("Bus: "),
+            Span::styled(rule.event_bus.clone(), Style::default().fg(Color::Cyan)),
+        ]),
+        Line::from(vec![
+            label("Trigger: "),
+            Span::styled(rule.trigger.clone(), Style::default().fg(Color::Gray)),
+        ]),
+    ];
+
+    if rule.targets.is_empty() {
+        lines.push(Line::from(vec![
+            label("Targets: "),
+            Span::styled("none", theme.style(Severity::Warning)),
+        ]));
+    }
+    for target in rule.targets.iter().take(MAX_TARGET_LINES) {
+        let dlq = match &target.dead_letter_arn {
+            Some(arn) => Span::styled(
+                format!("{}DLQ {}", theme.marker(Severity::Ok), arn_resource(arn)),
+                theme.style(Severity::Ok),
+            ),
+            None => Span::styled(
+                format!(
+                    "{}no DLQ, failed deliveries are dropped",
+                    theme.marker(Severity::Warning)
+                ),
+                theme.style(Severity::Warning),
+            ),
+        };
+        lines.push(Line::from(vec![
+            label("Target: "),
+            Span::styled(
+                format!("{} -> {}", target.id, arn_resource(&target.arn)),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::raw("  "),
+            dlq,
+        ]));
+    }
+    if rule.targets.len() > MAX_TARGET_LINES {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "... {} more targets (i: details)",
+                rule.targets.len() - MAX_TARGET_LINES
+            ),
+            Style::default().fg(Color::Gray),
+        )));
+    }
+
+    let info = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Rule: {}", rule.id))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(info, area);
+}
+
+fn render_rule_metrics(f: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.config.theme;
+    render_metric_rows(
+        f,
+        area,
+        app,
+        EventBridgeMetricProvider::new().get_metrics_config(),
+        app.view.service_metrics.as_ref().map(|m| &m.raw_metrics),
+        "No CloudWatch data for this rule yet",
+        |definition, value| {
+            let unit = definition.unit.as_deref().unwrap_or("");
+            let total: f64 = value.history.iter().sum();
+            let failing = FAILURE_METRICS.contains(&definition.name.as_str()) && total > 0.0;
+            MetricRow {
+                title: format!(
+                    "{}{}  latest: {}  total: {}",
+                    if failing {
+                        theme.marker(Severity::Critical)
+                    } else {
+                        ""
+                    },
+                    definition.name,
+                    format_value(value.current, unit),
+                    format_value(total, unit)
+                ),
+                style: if failing {
+                    theme.style(Severity::Critical)
+                } else {
+                    Style::default().fg(Color::Cyan)
+                },
+                max: None,
+            }
+        },
+    );
+}
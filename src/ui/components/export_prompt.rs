@@ -0,0 +1,50 @@
+use super::details_pane::centered_rect;
+use crate::utils::export::{ExportPrompt, SeriesFormat};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use std::path::Path;
+
+/// Popup for the file the open resource's metrics are exported to
+pub fn render_export_prompt(
+    f: &mut Frame,
+    prompt: &ExportPrompt,
+    resource: &str,
+    metrics: usize,
+    hints: &str,
+    area: Rect,
+) {
+    let popup = centered_rect(60, 25, area);
+    let format = match SeriesFormat::from_path(Path::new(prompt.text.trim())) {
+        SeriesFormat::Csv => "CSV",
+        SeriesFormat::Json => "JSON",
+    };
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled("File: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{}_", prompt.text),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]),
+        Line::from(Span::styled(
+            format!("Writes {metrics} metrics as {format}; end the name with .json for JSON."),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let widget = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Export {resource} ({hints})"))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    f.render_widget(Clear, popup);
+    f.render_widget(widget, popup);
+}
@@ -0,0 +1,142 @@
+use super::footer::render_footer;
+use crate::fleet::{health, resource_id};
+use crate::models::{App, AwsService};
+use crate::ui::theme::Severity;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+/// One service's resources across the configured accounts, with their health
+pub fn render_fleet(f: &mut Frame, app: &mut App) {
+    let failures = app.view.fleet.inventory.failures.len() as u16;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header - service and health counts
+            Constraint::Min(0),    // Inventory table
+            Constraint::Length(if failures > 0 { failures + 2 } else { 0 }), // Accounts that failed to list
+            Constraint::Length(1),                                           // Controls at bottom
+        ])
+        .split(f.area());
+
+    render_header(f, chunks[0], app);
+    render_table(f, chunks[1], app);
+    if failures > 0 {
+        render_failures(f, chunks[2], app);
+    }
+
+    render_footer(f, chunks[3], app);
+}
+
+fn render_header(f: &mut Frame, area: Rect, app: &App) {
+    let service = app.view.fleet.service.as_ref().unwrap_or(&AwsService::Rds);
+    let rows = &app.view.fleet.inventory.rows;
+    let count = |wanted: Severity| {
+        rows.iter()
+            .filter(|row| health(&row.instance).1 == Some(wanted))
+            .count()
+    };
+    let theme = &app.config.theme;
+    let mut line = vec![Span::styled(
+        format!(
+            "{} resources in {} accounts",
+            rows.len(),
+            app.config.accounts.len()
+        ),
+        Style::default().fg(Color::White),
+    )];
+    for severity in [Severity::Critical, Severity::Warning] {
+        let count = count(severity);
+        if count > 0 {
+            line.push(Span::raw("  "));
+            line.push(Span::styled(
+                format!("{}{count} {}", theme.marker(severity), severity.label()),
+                theme.style(severity),
+            ));
+        }
+    }
+    let header = Paragraph::new(Line::from(line)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Fleet: {}", service.short_name()))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(header, area);
+}
+
+fn render_table(f: &mut Frame, area: Rect, app: &mut App) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Resources (Enter opens one in its account)")
+        .border_style(Style::default().fg(Color::White));
+
+    if app.view.fleet.inventory.rows.is_empty() {
+        let empty = Paragraph::new("No resources listed in any account")
+            .style(Style::default().fg(Color::Gray))
+            .block(block);
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let theme = &app.config.theme;
+    let rows: Vec<Row> = app
+        .view
+        .fleet
+        .inventory
+        .rows
+        .iter()
+        .map(|row| {
+            let (label, severity) = health(&row.instance);
+            let health_cell = match severity {
+                Some(severity) => Cell::from(format!("{}{label}", theme.marker(severity)))
+                    .style(theme.style(severity)),
+                None => Cell::from(label).style(Style::default().fg(Color::Gray)),
+            };
+            Row::new(vec![
+                Cell::from(row.account.clone()),
+                Cell::from(row.region.clone()),
+                Cell::from(resource_id(row).to_string()),
+                health_cell,
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(16),
+            Constraint::Length(16),
+            Constraint::Min(20),
+            Constraint::Length(24),
+        ],
+    )
+    .header(
+        Row::new(vec!["Account", "Region", "Resource", "Health"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .highlight_style(Style::default().bg(Color::DarkGray))
+    .block(block);
+    f.render_stateful_widget(table, area, &mut app.view.fleet.table_state);
+}
+
+fn render_failures(f: &mut Frame, area: Rect, app: &App) {
+    let lines: Vec<Line> = app
+        .view
+        .fleet
+        .inventory
+        .failures
+        .iter()
+        .map(|failure| Line::styled(failure.clone(), Style::default().fg(Color::Red)))
+        .collect();
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Not listed")
+            .border_style(Style::default().fg(Color::Red)),
+    );
+    f.render_widget(paragraph, area);
+}
@@ -0,0 +1,53 @@
+use crate::aws::limiter;
+use crate::models::App;
+use crate::ui::keymap::page_hints;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Text leading the footer while CloudWatch calls are being throttled
+pub const THROTTLING_BANNER: &str = "CloudWatch throttling detected — data may be delayed";
+
+/// The status message if there is one, otherwise the page's key hints
+///
+/// While favorites are being warmed in the background, their progress leads
+/// the hints. A recent throttling response leads either.
+pub fn footer_line(app: &App) -> Line<'static> {
+    let mut spans = Vec::new();
+    if limiter::cloudwatch().cooling_down() {
+        spans.push(Span::styled(
+            format!("{THROTTLING_BANNER} • "),
+            Style::default().fg(Color::Black).bg(Color::Yellow),
+        ));
+    }
+    match &app.status_message {
+        Some(message) => {
+            spans.push(Span::styled(
+                message.clone(),
+                Style::default().fg(Color::Yellow),
+            ));
+            Line::from(spans)
+        }
+        None => {
+            if let Some((done, total)) = app.prefetch.progress() {
+                spans.push(Span::styled(
+                    format!("Warming favorites {done}/{total} • "),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            spans.push(Span::styled(
+                page_hints(app),
+                Style::default().fg(Color::Gray),
+            ));
+            Line::from(spans)
+        }
+    }
+}
+
+pub fn render_footer(f: &mut Frame, area: Rect, app: &App) {
+    f.render_widget(Paragraph::new(footer_line(app)), area);
+}
@@ -0,0 +1,159 @@
+use super::{
+    footer::render_footer,
+    instance_details::{render_metrics_loading, render_no_recent_data},
+    metric_rows::{render_metric_rows, MetricRow},
+    metric_utils::format_value,
+};
+use crate::aws::metrics::providers::{LambdaMetricProvider, MetricProvider};
+use crate::aws::metrics::types::StatisticType;
+use crate::models::{App, LambdaFunction};
+use crate::ui::theme::Severity;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Metrics whose datapoints mean invocations failed or were turned away
+pub const FAILURE_METRICS: [&str; 2] = ["Errors", "Throttles"];
+
+/// "512 MB" or "-" when ListFunctions left the setting out
+pub fn format_setting(value: Option<i64>, unit: &str) -> String {
+    value.map_or_else(|| "-".to_string(), |value| format!("{value} {unit}"))
+}
+
+/// Metrics view for a single Lambda function
+///
+/// The header shows the configuration that bounds the metrics below: the
+/// memory size, and the timeout a rising Duration runs into.
+pub fn render_function_summary(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4), // Header - runtime, limits and state
+            Constraint::Min(0),    // Content
+            Constraint::Length(1), // Controls at bottom
+        ])
+        .split(f.area());
+
+    if let Some(function) = app.get_selected_lambda_function() {
+        render_function_info(f, chunks[0], app, function);
+    }
+
+    if let Some(error_msg) = &app.error_message {
+        let error = Paragraph::new(error_msg.as_str())
+            .style(Style::default().fg(Color::Red))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Error")
+                    .border_style(Style::default().fg(Color::Red)),
+            )
+            .wrap(Wrap { trim: false });
+        f.render_widget(error, chunks[1]);
+    } else if app.metrics_loading {
+        render_metrics_loading(f, chunks[1]);
+    } else if let Some(reason) = app.stale_data_reason() {
+        render_no_recent_data(f, chunks[1], &reason);
+    } else {
+        render_function_metrics(f, chunks[1], app);
+    }
+
+    render_footer(f, chunks[2], app);
+}
+
+fn render_function_info(f: &mut Frame, area: Rect, app: &App, function: &LambdaFunction) {
+    let theme = &app.config.theme;
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::White));
+    let state = match function.state.as_deref() {
+        Some(state) if function.is_failed() => Span::styled(
+            format!(
+                "{}{}",
+                theme.marker(Severity::Critical),
+                state.to_lowercase()
+            ),
+            theme.style(Severity::Critical),
+        ),
+        Some(state) => Span::styled(state.to_lowercase(), Style::default().fg(Color::Green)),
+        None => Span::styled("unknown (r: refresh)", Style::default().fg(Color::Gray)),
+    };
+
+    let lines = vec![
+        Line::from(vec![
+            label("Runtime: "),
+            Span::styled(function.runtime.clone(), Style::default().fg(Color::Cyan)),
+            Span::raw("  "),
+            label("Memory: "),
+            Span::styled(
+                format_setting(function.memory_mb, "MB"),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::raw("  "),
+            label("Timeout: "),
+            Span::styled(
+                format_setting(function.timeout_secs, "s"),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]),
+        Line::from(vec![
+            label("State: "),
+            state,
+            Span::raw("  "),
+            label("Last modified: "),
+            Span::styled(
+                function.last_modified.clone(),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+    ];
+
+    let info = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Function: {}", function.name))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(info, area);
+}
+
+fn render_function_metrics(f: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.config.theme;
+    render_metric_rows(
+        f,
+        area,
+        app,
+        LambdaMetricProvider::new().get_metrics_config(),
+        app.view.service_metrics.as_ref().map(|m| &m.raw_metrics),
+        "No CloudWatch data for this function yet",
+        |definition, value| {
+            let unit = definition.unit.as_deref().unwrap_or("");
+            // Counts add up over the range; durations and concurrency peak
+            let (over_range, label) = match definition.statistic {
+                StatisticType::Sum => (value.history.iter().sum(), "total"),
+                _ => (value.history.iter().copied().fold(0.0, f64::max), "peak"),
+            };
+            let failing = FAILURE_METRICS.contains(&definition.name.as_str()) && over_range > 0.0;
+            MetricRow {
+                title: format!(
+                    "{}{}  latest: {}  {label}: {}",
+                    if failing {
+                        theme.marker(Severity::Critical)
+                    } else {
+                        ""
+                    },
+                    definition.name,
+                    format_value(value.current, unit),
+                    format_value(over_range, unit)
+                ),
+                style: if failing {
+                    theme.style(Severity::Critical)
+                } else {
+                    Style::default().fg(Color::Cyan)
+                },
+                max: None,
+            }
+        },
+    );
+}
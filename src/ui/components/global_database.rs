@@ -0,0 +1,329 @@
+use super::footer::render_footer;
+use super::instance_details::render_metrics_loading;
+use super::replica_topology::{chart_bounds, epoch_seconds, time_label};
+use crate::aws::rds::blue_green::identifier_from_arn;
+use crate::aws::rds::global::{
+    region_from_arn, GlobalDatabase, GlobalMember, SecondaryLag, LAG_METRICS,
+};
+use crate::config::Config;
+use crate::models::App;
+use crate::ui::theme::{Severity, Theme};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Axis, Block, Borders, Chart, Dataset, List, ListItem, Paragraph},
+    Frame,
+};
+use std::time::SystemTime;
+
+/// Series colors: replication lag, RPO lag
+const LAG_COLORS: [Color; 2] = [Color::Cyan, Color::Magenta];
+
+/// Lag above which a secondary is flagged, in milliseconds
+const LAG_WARNING_MS: f64 = 1_000.0;
+const LAG_CRITICAL_MS: f64 = 60_000.0;
+
+/// An Aurora global database: its members with lag and failover readiness,
+/// above a lag chart of the selected secondary
+pub fn render_global_database(f: &mut Frame, app: &mut App) {
+    let Some(database) = app.view.global_database.database.clone() else {
+        let empty = Paragraph::new("Global database is no longer listed")
+            .style(Style::default().fg(Color::Gray))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Global Database"),
+            );
+        f.render_widget(empty, f.area());
+        return;
+    };
+
+    let member_height = (database.members.len() as u16).clamp(1, 10) + 2;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4),             // Header - global database status
+            Constraint::Length(member_height), // Regional clusters
+            Constraint::Min(0),                // Lag chart of the selected secondary
+            Constraint::Length(1),             // Controls at bottom
+        ])
+        .split(f.area());
+
+    render_header(f, chunks[0], &app.config.theme, &database);
+
+    if app.metrics_loading {
+        render_metrics_loading(f, chunks[1].union(chunks[2]));
+    } else {
+        render_members(f, chunks[1], app, &database);
+        match app.get_selected_global_secondary() {
+            Some((secondary, lag)) => render_lag_chart(f, chunks[2], secondary, lag, &app.config),
+            None => {
+                let empty = Paragraph::new("This global database has no secondary regions")
+                    .style(Style::default().fg(Color::Gray))
+                    .block(Block::default().borders(Borders::ALL).title("Lag"));
+                f.render_widget(empty, chunks[2]);
+            }
+        }
+    }
+
+    render_footer(f, chunks[3], app);
+}
+
+fn render_header(f: &mut Frame, area: Rect, theme: &Theme, database: &GlobalDatabase) {
+    let status_style = if database.status == "available" {
+        theme.style(Severity::Ok)
+    } else {
+        theme.style(Severity::Warning)
+    };
+    let mut status = vec![
+        Span::styled("Status: ", Style::default().fg(Color::White)),
+        Span::styled(database.status.clone(), status_style),
+        Span::raw(format!("  {} {}", database.engine, database.engine_version)),
+    ];
+    if let Some(failover) = &database.failover {
+        let kind = if failover.data_loss_allowed {
+            "failover"
+        } else {
+            "switchover"
+        };
+        status.push(Span::styled(
+            format!(
+                "  {}{kind} {}: {} → {}",
+                theme.marker(Severity::Warning),
+                failover.status,
+                member_label(&failover.from_arn),
+                member_label(&failover.to_arn)
+            ),
+            theme.style(Severity::Warning),
+        ));
+    }
+
+    let primary = database
+        .primary()
+        .map(|primary| format!("{} ({})", primary.cluster_id(), primary.region()))
+        .unwrap_or_else(|| "-".to_string());
+    let lines = vec![
+        Line::from(status),
+        Line::from(vec![
+            Span::styled("Primary: ", Style::default().fg(Color::White)),
+            Span::raw(primary),
+            Span::styled(
+                format!("  {} secondary regions", database.secondaries().count()),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+    ];
+
+    let header = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Global Database: {}", database.identifier))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(header, area);
+}
+
+fn render_members(f: &mut Frame, area: Rect, app: &mut App, database: &GlobalDatabase) {
+    let theme = app.config.theme;
+    let mut items = Vec::new();
+    if let Some(primary) = database.primary() {
+        items.push(ListItem::new(Line::from(vec![
+            Span::styled(
+                format!("{:<16}", primary.region()),
+                Style::default().fg(Color::White),
+            ),
+            Span::styled(
+                format!("{}  ", primary.cluster_id()),
+                Style::default().fg(Color::Blue),
+            ),
+            Span::styled("primary (writer)", Style::default().fg(Color::Gray)),
+        ])));
+    }
+    for secondary in database.secondaries() {
+        let lag = app
+            .view
+            .global_database
+            .lags
+            .iter()
+            .find(|lag| lag.cluster_arn == secondary.cluster_arn);
+        let (replication, rpo) = lag.map_or((None, None), |lag| {
+            (lag.current_replication(), lag.current_rpo())
+        });
+        let readiness = match database.failover_readiness(secondary, rpo) {
+            Ok(()) => Span::styled(
+                format!("{}ready", theme.marker(Severity::Ok)),
+                theme.style(Severity::Ok),
+            ),
+            Err(reason) => Span::styled(
+                format!("{}not ready: {reason}", theme.marker(Severity::Warning)),
+                theme.style(Severity::Warning),
+            ),
+        };
+        let mut spans = vec![
+            Span::styled(
+                format!("{:<16}", secondary.region()),
+                Style::default().fg(Color::White),
+            ),
+            Span::styled(
+                format!("{}  ", secondary.cluster_id()),
+                Style::default().fg(Color::Green),
+            ),
+            Span::raw("replication "),
+            lag_span(&theme, replication),
+            Span::raw("  RPO "),
+            lag_span(&theme, rpo),
+            Span::raw("  "),
+            Span::styled(
+                format!("{}  ", secondary.synchronization.as_deref().unwrap_or("-")),
+                Style::default().fg(Color::Gray),
+            ),
+            readiness,
+        ];
+        if secondary.write_forwarding.as_deref() == Some("enabled") {
+            spans.push(Span::styled(
+                "  write forwarding",
+                Style::default().fg(Color::Gray),
+            ));
+        }
+        items.push(ListItem::new(Line::from(spans)));
+    }
+
+    // The primary row is not selectable; the selection indexes the secondaries
+    let offset = usize::from(database.primary().is_some());
+    let mut state = app.view.global_database.list_state.clone();
+    state.select(state.selected().map(|index| index + offset));
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Regions (planned failover readiness)")
+                .border_style(Style::default().fg(Color::White)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn render_lag_chart(
+    f: &mut Frame,
+    area: Rect,
+    secondary: &GlobalMember,
+    lag: &SecondaryLag,
+    config: &Config,
+) {
+    let points = |values: &[f64], timestamps: &[SystemTime]| -> Vec<(f64, f64)> {
+        timestamps
+            .iter()
+            .zip(values)
+            .map(|(timestamp, &value)| (epoch_seconds(*timestamp), value))
+            .collect()
+    };
+    let series = vec![
+        (
+            LAG_COLORS[0],
+            points(&lag.replication, &lag.replication_timestamps),
+        ),
+        (LAG_COLORS[1], points(&lag.rpo, &lag.rpo_timestamps)),
+    ];
+
+    let title = Line::from(vec![
+        Span::raw(format!(
+            "{} ({}) lag in ms: ",
+            secondary.cluster_id(),
+            secondary.region()
+        )),
+        Span::styled("■ replication ", Style::default().fg(LAG_COLORS[0])),
+        Span::styled("■ RPO", Style::default().fg(LAG_COLORS[1])),
+    ]);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(Color::White));
+
+    let Some((x_bounds, y_bounds)) = chart_bounds(&series) else {
+        let reason =
+            lag.failures.values().next().cloned().unwrap_or_else(|| {
+                format!("No {} data for this secondary", LAG_METRICS.join(" or "))
+            });
+        let empty = Paragraph::new(reason)
+            .style(Style::default().fg(Color::Gray))
+            .block(block);
+        f.render_widget(empty, area);
+        return;
+    };
+
+    let datasets = series
+        .iter()
+        .map(|(color, points)| {
+            Dataset::default()
+                .marker(config.theme.series_marker(config.charts.marker))
+                .graph_type(config.charts.graph_type())
+                .style(Style::default().fg(*color))
+                .data(points)
+        })
+        .collect();
+
+    let chart = Chart::new(datasets)
+        .block(block)
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds(x_bounds)
+                .labels(vec![
+                    Line::from(time_label(x_bounds[0])),
+                    Line::from(time_label(x_bounds[1])),
+                ]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds(y_bounds)
+                .labels(vec![
+                    Line::from(format!("{:.0}", y_bounds[0])),
+                    Line::from(format!("{:.0}", y_bounds[1])),
+                ]),
+        );
+    f.render_widget(chart, area);
+}
+
+pub fn lag_severity(ms: f64) -> Severity {
+    if ms > LAG_CRITICAL_MS {
+        Severity::Critical
+    } else if ms > LAG_WARNING_MS {
+        Severity::Warning
+    } else {
+        Severity::Ok
+    }
+}
+
+/// Lag in milliseconds, or seconds once it reaches a second
+pub fn format_lag(ms: f64) -> String {
+    if ms >= 1_000.0 {
+        format!("{:.1} s", ms / 1_000.0)
+    } else {
+        format!("{ms:.0} ms")
+    }
+}
+
+fn lag_span(theme: &Theme, lag_ms: Option<f64>) -> Span<'static> {
+    match lag_ms {
+        Some(ms) => {
+            let severity = lag_severity(ms);
+            Span::styled(
+                format!("{}{}", theme.marker(severity), format_lag(ms)),
+                theme.style(severity),
+            )
+        }
+        None => Span::styled("no data", Style::default().fg(Color::DarkGray)),
+    }
+}
+
+/// Cluster and region of a member ARN
+fn member_label(arn: &str) -> String {
+    format!("{} ({})", identifier_from_arn(arn), region_from_arn(arn))
+}
@@ -0,0 +1,75 @@
+use crate::incident::Incident;
+use crate::ui::theme::{Severity, Theme};
+use chrono::{DateTime, Utc};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Firing alarms and the error log tail of the instance under incident, newest line last
+pub fn render_incident_pane(f: &mut Frame, incident: &Incident, theme: &Theme, area: Rect) {
+    let minutes = incident.started_at.elapsed().unwrap_or_default().as_secs() / 60;
+    let title = format!(
+        "Incident on {} for {minutes} min{} (I: end)",
+        incident.resource,
+        if incident.recording.is_some() {
+            ", recording"
+        } else {
+            ""
+        }
+    );
+
+    let mut lines: Vec<Line> = if incident.firing.is_empty() {
+        vec![Line::styled(
+            format!("{}No alarms firing", theme.marker(Severity::Ok)),
+            theme.style(Severity::Ok),
+        )]
+    } else {
+        incident
+            .firing
+            .iter()
+            .map(|name| {
+                Line::styled(
+                    format!("{}ALARM {name}", theme.marker(Severity::Critical)),
+                    theme.style(Severity::Critical),
+                )
+            })
+            .collect()
+    };
+    if let Some(notice) = &incident.log_notice {
+        lines.push(Line::styled(
+            notice.clone(),
+            Style::default().fg(Color::Gray),
+        ));
+    }
+
+    // Only the newest log lines that fit under the alarms
+    let room = (area.height.saturating_sub(2) as usize).saturating_sub(lines.len());
+    let skip = incident.log.len().saturating_sub(room);
+    lines.extend(incident.log.iter().skip(skip).map(|event| {
+        Line::from(vec![
+            Span::styled(
+                format!(
+                    "{} ",
+                    DateTime::<Utc>::from(event.timestamp).format("%H:%M:%S")
+                ),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled(
+                event.message.trim_end().to_string(),
+                Style::default().fg(Color::White),
+            ),
+        ])
+    }));
+
+    let pane = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(Color::Red)),
+    );
+    f.render_widget(pane, area);
+}
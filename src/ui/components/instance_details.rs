@@ -1,10 +1,15 @@
-use super::super::charts::metrics_chart::render_metrics;
+use super::super::charts::metrics_chart::{
+    create_x_labels, format_axis_value, render_metrics, ChartOverlays,
+};
 use crate::models::App;
+use crate::ui::components::metric_utils::get_metric_unit;
+use crate::ui::graphics::ImageSource;
+use crate::ui::keymap::page_hints;
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
@@ -12,8 +17,9 @@ pub fn render_instance_details(f: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(4), // Header - increased height to show the endpoint
+            Constraint::Length(6), // Header - endpoint, storage and pending modifications
             Constraint::Min(0),    // Content (metrics chart will handle its own controls)
+            Constraint::Length(if app.status_message.is_some() { 1 } else { 0 }), // Status
         ])
         .split(f.area());
 
@@ -30,29 +36,185 @@ pub fn render_instance_details(f: &mut Frame, app: &mut App) {
 
     if app.metrics_loading {
         render_metrics_loading(f, chunks[1]);
+    } else if let Some(reason) = app.stale_data_reason() {
+        render_no_recent_data(f, chunks[1], &reason);
     } else {
         // For the detailed chart view, we want to show 1 metric per screen for maximum chart size
         // Don't use the app's metrics_per_screen as it's been adjusted for the list view
         let chart_metrics_per_screen = 1;
 
         // Get available metrics and calculate proper scroll offset
-        let available_metrics_count = app.metrics.count_available_metrics();
+        let available_metrics_count = app.view.metrics.count_available_metrics();
 
         // Ensure scroll_offset doesn't exceed available metrics for chart view
         let effective_scroll_offset = app
+            .view
             .scroll_offset
             .min(available_metrics_count.saturating_sub(1));
 
+        let overlays = ChartOverlays {
+            free_storage_ceiling: app.get_selected_rds_instance().and_then(|instance| {
+                instance.autoscaling_free_storage_ceiling(app.view.metrics.free_storage_space)
+            }),
+            alarm_thresholds: app.alarm_thresholds(),
+            baseline_bands: app.baseline_bands(),
+            change_markers: app.activity_markers(),
+            heat_strip: app.view.heat_strip,
+            derivative: app.view.derivative,
+            percentiles: app.view.percentiles.clone(),
+            stacked: app.stacked_chart(),
+            reference_lines: app.config.reference_lines.clone(),
+            theme: app.config.theme,
+            charts: app.config.charts.clone(),
+        };
+
         render_metrics(
             f,
             chunks[1],
-            &app.metrics,
+            &app.view.metrics,
             effective_scroll_offset,
             chart_metrics_per_screen,
+            &overlays,
+            &page_hints(app),
         );
+        render_chart_image_frame(f, chunks[1], app);
+    }
+
+    if let Some(message) = &app.status_message {
+        let status = Paragraph::new(message.as_str()).style(Style::default().fg(Color::Yellow));
+        f.render_widget(status, chunks[2]);
     }
 }
 
+/// Reserve the chart area for a CloudWatch-rendered image
+///
+/// The image itself is written to the terminal after the frame is drawn; until
+/// it has been fetched (or if CloudWatch cannot render it) the Braille chart
+/// stays visible. Popups over the chart, heat strips, stacked families and
+/// percentile bands hide the image.
+fn render_chart_image_frame(f: &mut Frame, area: Rect, app: &mut App) {
+    if app.graphics.is_none()
+        || app.alarm_form.is_some()
+        || app.view.details_pane.open
+        || app.view.activity.open
+        || app.bookmarks_panel.is_some()
+        || app.jobs_panel.is_some()
+        || app.profiles_panel.is_some()
+        || app.regions_panel.is_some()
+        || app.release_notes_open
+        || app.pin_prompt.is_some()
+        || app.pending_action.is_some()
+        || app.showing_heat_strip()
+        || app.showing_derivative()
+        || app.showing_percentiles()
+        || app.stacked_chart().is_some()
+    {
+        return;
+    }
+    // Same split as the chart and its instructions in render_metrics
+    let chart_area = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(area)[0];
+    let source = app.config.charts.image_source;
+    let title = app
+        .detail_chart_metric()
+        .map(|metric| match source {
+            ImageSource::Local => {
+                // The plot carries pinned lines but not their labels
+                let unit = get_metric_unit(&metric);
+                let pinned: String = app
+                    .pinned_lines(metric.metric_name())
+                    .iter()
+                    .map(|line| format!("  ({})", line.describe(unit)))
+                    .collect();
+                format!("{}{pinned}", metric.display_name())
+            }
+            ImageSource::CloudWatch => {
+                format!("{} (rendered by CloudWatch)", metric.display_name())
+            }
+        })
+        .unwrap_or_default();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(chart_area);
+    let showing = app.chart_image().is_some();
+    if showing {
+        f.render_widget(Clear, chart_area);
+        f.render_widget(block, chart_area);
+    }
+
+    // CloudWatch draws its own axes; local plots get terminal-text labels around them
+    app.chart_image_area = Some(match source {
+        ImageSource::CloudWatch => inner,
+        ImageSource::Local => render_raster_axes(f, inner, app, showing),
+    });
+}
+
+/// Lays out value labels left of and time labels below a locally plotted
+/// chart, drawing them when `draw` is set; returns the cells left for the image
+fn render_raster_axes(f: &mut Frame, inner: Rect, app: &App, draw: bool) -> Rect {
+    let bands = app.baseline_bands();
+    let Some(metric) = app.detail_chart_metric() else {
+        return inner;
+    };
+    let Some(chart) = app.detail_raster_chart(bands.get(metric.metric_name())) else {
+        return inner;
+    };
+    let [y_min, y_max] = chart.y_bounds();
+    let fractions = [1.0, 0.75, 0.5, 0.25, 0.0];
+    let y_labels = fractions.map(|fraction| {
+        format_axis_value(y_min + (y_max - y_min) * fraction, metric.metric_name())
+    });
+    let label_width = y_labels.iter().map(|label| label.len()).max().unwrap_or(0) as u16 + 1;
+
+    let [labels_area, image_area] = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(label_width), Constraint::Min(0)])
+        .areas(inner);
+    let [image_area, time_area] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .areas(image_area);
+    if !draw || image_area.height == 0 {
+        return image_area;
+    }
+
+    let style = Style::default().fg(Color::DarkGray);
+    for (fraction, label) in fractions.iter().zip(y_labels) {
+        let row = ((1.0 - fraction) * f64::from(image_area.height - 1)).round() as u16;
+        f.render_widget(
+            Paragraph::new(Span::styled(label, style)).alignment(Alignment::Right),
+            Rect {
+                y: labels_area.y + row,
+                height: 1,
+                width: label_width - 1,
+                ..labels_area
+            },
+        );
+    }
+
+    // First, middle and last of the Braille chart's time labels
+    let x_labels = create_x_labels(chart.timestamps);
+    let picks = [
+        (x_labels.first(), Alignment::Left),
+        (x_labels.get(x_labels.len() / 2), Alignment::Center),
+        (x_labels.last(), Alignment::Right),
+    ];
+    for (label, alignment) in picks {
+        if let Some(label) = label.filter(|_| x_labels.len() > 2 || alignment != Alignment::Center)
+        {
+            f.render_widget(
+                Paragraph::new(label.clone()).alignment(alignment),
+                time_area,
+            );
+        }
+    }
+    image_area
+}
+
 fn render_instance_info(
     f: &mut Frame,
     area: ratatui::layout::Rect,
@@ -77,6 +239,8 @@ fn render_instance_info(
                 Style::default().fg(Color::Cyan),
             ),
         ]),
+        create_storage_line(instance),
+        create_pending_line(instance),
     ];
 
     let info = Paragraph::new(info_text)
@@ -90,6 +254,47 @@ fn render_instance_info(
     f.render_widget(info, area);
 }
 
+fn create_storage_line(instance: &crate::models::RdsInstance) -> Line<'static> {
+    let allocated = instance
+        .allocated_storage_gib
+        .map(|gb| format!("{gb} GiB allocated"))
+        .unwrap_or_else(|| "N/A".to_string());
+    let autoscaling = match instance.max_allocated_storage_gib {
+        Some(max) => Span::styled(
+            format!("autoscaling up to {max} GiB"),
+            Style::default().fg(Color::Green),
+        ),
+        None => Span::styled("autoscaling off", Style::default().fg(Color::Gray)),
+    };
+
+    Line::from(vec![
+        Span::styled("Storage: ", Style::default().fg(Color::White)),
+        Span::styled(allocated, Style::default().fg(Color::White)),
+        Span::raw("  "),
+        autoscaling,
+    ])
+}
+
+fn create_pending_line(instance: &crate::models::RdsInstance) -> Line<'static> {
+    if instance.pending_modifications.is_empty() {
+        return Line::from(vec![
+            Span::styled("Pending: ", Style::default().fg(Color::White)),
+            Span::styled("none", Style::default().fg(Color::Gray)),
+        ]);
+    }
+
+    let changes = instance
+        .pending_modifications
+        .iter()
+        .map(|(field, value)| format!("{field} → {value}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Line::from(vec![
+        Span::styled("Pending: ", Style::default().fg(Color::White)),
+        Span::styled(changes, Style::default().fg(Color::Yellow)),
+    ])
+}
+
 pub fn render_metrics_loading(f: &mut Frame, area: ratatui::layout::Rect) {
     let loading_msg = Paragraph::new("Loading metrics...")
         .style(Style::default().fg(Color::Yellow))
@@ -102,6 +307,47 @@ pub fn render_metrics_loading(f: &mut Frame, area: ratatui::layout::Rect) {
     f.render_widget(loading_msg, area);
 }
 
+/// Tile of a metric whose fetch failed, in place of its sparkline
+pub fn render_failed_metric(f: &mut Frame, area: ratatui::layout::Rect, app: &App, name: &str) {
+    let theme = &app.config.theme;
+    let severity = crate::ui::theme::Severity::Critical;
+    let reason = app.view.failed_metrics.get(name).map_or("", String::as_str);
+    let tile = Paragraph::new(Line::from(vec![
+        Span::styled(reason.to_string(), theme.style(severity)),
+        Span::styled("  x: retry", Style::default().fg(Color::Gray)),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("{}{name}  failed", theme.marker(severity)))
+            .border_style(theme.style(severity)),
+    );
+    f.render_widget(tile, area);
+}
+
+/// Panel shown instead of empty charts when the resource is not producing datapoints
+pub fn render_no_recent_data(f: &mut Frame, area: ratatui::layout::Rect, reason: &str) {
+    let text = vec![
+        Line::from(Span::styled(
+            reason.to_string(),
+            Style::default().fg(Color::Yellow),
+        )),
+        Line::from(Span::styled(
+            "CloudWatch publishes no datapoints while the resource is inactive.",
+            Style::default().fg(Color::Gray),
+        )),
+    ];
+    let panel = Paragraph::new(text)
+        .alignment(ratatui::layout::Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("CloudWatch Metrics")
+                .border_style(Style::default().fg(Color::White)),
+        );
+    f.render_widget(panel, area);
+}
+
 fn render_error_message(f: &mut Frame, area: ratatui::layout::Rect, message: &str) {
     let error_msg = Paragraph::new(message)
         .style(Style::default().fg(Color::Red))
@@ -0,0 +1,64 @@
+use super::footer::render_footer;
+use super::metric_rows::{render_metric_rows, MetricRow};
+use super::metric_utils::format_value;
+use crate::internals;
+use crate::models::App;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// The app's own figures over the last ten minutes, drawn like a resource's metrics
+pub fn render_internals(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),    // Figures
+            Constraint::Length(1), // Controls at bottom
+        ])
+        .split(f.area());
+
+    render_figures(f, chunks[0], app);
+    render_footer(f, chunks[1], app);
+}
+
+fn render_figures(f: &mut Frame, area: Rect, app: &App) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "Internals ({} samples, one a second)",
+            app.internals.timestamps().len()
+        ))
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    if app.internals.timestamps().is_empty() {
+        let empty = Paragraph::new("No samples yet").style(Style::default().fg(Color::Gray));
+        f.render_widget(empty, inner);
+        return;
+    }
+
+    render_metric_rows(
+        f,
+        inner,
+        app,
+        internals::definitions(),
+        Some(&app.internals.metrics()),
+        "No samples yet",
+        |definition, value| {
+            let unit = definition.unit.as_deref().unwrap_or("");
+            let peak = value.history.iter().copied().fold(0.0, f64::max);
+            MetricRow {
+                title: format!(
+                    "{}  latest: {}  peak: {}",
+                    definition.name,
+                    format_value(value.current, unit),
+                    format_value(peak, unit)
+                ),
+                ..MetricRow::latest(definition, value)
+            }
+        },
+    );
+}
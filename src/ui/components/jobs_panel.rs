@@ -0,0 +1,137 @@
+use super::details_pane::centered_rect;
+use super::queue_summary::format_age;
+use crate::jobs::{Job, JobStatus, JobsPanel};
+use crate::models::App;
+use crate::ui::keymap::{overlay_hints, KeyContext};
+use crate::ui::theme::{Severity, Theme};
+use crate::utils::formatting::pad_to_width;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+/// Cells of the progress bar in each job's row
+const PROGRESS_BAR_WIDTH: usize = 20;
+
+/// Widest a toast gets, in cells
+const TOAST_WIDTH: u16 = 60;
+
+/// Render the background jobs, newest last, with their progress and latest status
+pub fn render_jobs_panel(f: &mut Frame, app: &App, panel: &JobsPanel, area: Rect) {
+    let popup = centered_rect(80, 50, area);
+    let theme = &app.config.theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "Jobs: {} running ({})",
+            app.jobs.running(),
+            overlay_hints(app, KeyContext::JobsPanel)
+        ))
+        .border_style(Style::default().fg(Color::Cyan));
+
+    f.render_widget(Clear, popup);
+    if app.jobs.jobs().is_empty() {
+        let empty = Paragraph::new(
+            "No jobs yet. Snapshots (S on an RDS instance), DLQ redrives (R on a queue) \
+             and fleet exports (E on the RDS list) run here in the background.",
+        )
+        .style(Style::default().fg(Color::Gray))
+        .wrap(Wrap { trim: true })
+        .block(block);
+        f.render_widget(empty, popup);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .jobs
+        .jobs()
+        .iter()
+        .map(|job| job_item(theme, job))
+        .collect();
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().bg(Color::DarkGray));
+    let mut list_state = ListState::default();
+    list_state.select(Some(panel.selected_index));
+    f.render_stateful_widget(list, popup, &mut list_state);
+}
+
+fn job_item<'a>(theme: &Theme, job: &Job) -> ListItem<'a> {
+    let status_style = match job.status {
+        JobStatus::Running => Style::default().fg(Color::Cyan),
+        JobStatus::Succeeded => theme.style(Severity::Ok),
+        JobStatus::Failed => theme.style(Severity::Critical),
+        JobStatus::Cancelled => Style::default().fg(Color::Gray),
+    };
+    ListItem::new(vec![
+        Line::from(vec![
+            Span::styled(
+                pad_to_width(&job.label, 32),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("{:<10}", job.status.label()), status_style),
+            Span::styled(
+                progress_bar(job.progress, theme.ascii_enabled()),
+                status_style,
+            ),
+            Span::styled(
+                format!("  {}", format_age(job.elapsed().as_secs_f64())),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        Line::from(Span::styled(
+            format!("  {}", job.detail),
+            Style::default().fg(Color::Gray),
+        )),
+    ])
+}
+
+/// `[#####-----]  50%`; a bar without a percentage while the share done is unknown
+fn progress_bar(progress: Option<f64>, ascii: bool) -> String {
+    let (done, todo) = if ascii { ('#', '-') } else { ('█', '░') };
+    match progress {
+        Some(fraction) => {
+            let filled = (fraction * PROGRESS_BAR_WIDTH as f64).round() as usize;
+            format!(
+                "[{}{}] {:>3.0}%",
+                done.to_string().repeat(filled),
+                todo.to_string().repeat(PROGRESS_BAR_WIDTH - filled),
+                fraction * 100.0
+            )
+        }
+        None => format!("[{}]     ", todo.to_string().repeat(PROGRESS_BAR_WIDTH)),
+    }
+}
+
+/// Render finished jobs' toasts in the top-right corner, below the tab bar
+pub fn render_toasts(f: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.config.theme;
+    let width = TOAST_WIDTH.min(area.width);
+    let mut y = area.y + 1;
+    for toast in app.jobs.toasts() {
+        if y + 3 > area.bottom() {
+            break;
+        }
+        let toast_area = Rect::new(area.right() - width, y, width, 3);
+        let severity = if toast.failed {
+            Severity::Critical
+        } else {
+            Severity::Ok
+        };
+        let text = Paragraph::new(format!("{}{}", theme.marker(severity), toast.text))
+            .style(Style::default().fg(Color::White))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(theme.style(severity)),
+            );
+        f.render_widget(Clear, toast_area);
+        f.render_widget(text, toast_area);
+        y += 3;
+    }
+}
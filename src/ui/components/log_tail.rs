@@ -0,0 +1,186 @@
+use super::footer::render_footer;
+use crate::aws::logs::tail;
+use crate::models::{App, LogInput};
+use crate::utils::formatting::locale;
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+/// Log groups of the selected resource, or the live tail of the one being followed
+pub fn render_log_tail(f: &mut Frame, app: &mut App) {
+    let typing = app.view.log_tail.input.is_some();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header - group, filter and state
+            Constraint::Min(0),    // Log groups or lines
+            Constraint::Length(if typing { 3 } else { 0 }), // Search or filter being typed
+            Constraint::Length(1), // Controls at bottom
+        ])
+        .split(f.area());
+
+    render_header(f, chunks[0], app);
+    if app.view.log_tail.group.is_some() {
+        render_lines(f, chunks[1], app);
+    } else {
+        render_groups(f, chunks[1], app);
+    }
+    if typing {
+        render_input(f, chunks[2], app);
+    }
+
+    render_footer(f, chunks[3], app);
+}
+
+fn render_header(f: &mut Frame, area: Rect, app: &App) {
+    let page = &app.view.log_tail;
+    let resource = app
+        .get_selected_instance_id()
+        .unwrap_or_else(|| "-".to_string());
+    let mut spans = Vec::new();
+    match &page.group {
+        Some(group) => {
+            spans.push(Span::styled(
+                group.clone(),
+                Style::default().fg(Color::Gray),
+            ));
+            let (state, color) = if page.paused {
+                ("PAUSED", Color::Yellow)
+            } else if page.scroll > 0 {
+                ("SCROLLED BACK", Color::Yellow)
+            } else {
+                ("LIVE", Color::Green)
+            };
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled(
+                state,
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::styled(
+                format!("  {} lines", page.buffer.len()),
+                Style::default().fg(Color::White),
+            ));
+            if let Some(filter) = &page.filter {
+                spans.push(Span::styled(
+                    format!("  filter: {filter}"),
+                    Style::default().fg(Color::Cyan),
+                ));
+            }
+            if let Some(search) = &page.search {
+                spans.push(Span::styled(
+                    format!("  search: {search}"),
+                    Style::default().fg(Color::Yellow),
+                ));
+            }
+        }
+        None => spans.push(Span::styled(
+            format!("{} log groups", page.groups.len()),
+            Style::default().fg(Color::White),
+        )),
+    }
+    let header = Paragraph::new(Line::from(spans)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Logs: {resource}"))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(header, area);
+}
+
+fn render_groups(f: &mut Frame, area: Rect, app: &mut App) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Log groups")
+        .border_style(Style::default().fg(Color::White));
+    let page = &mut app.view.log_tail;
+    if let Some(notice) = &page.notice {
+        let empty = Paragraph::new(notice.as_str())
+            .style(Style::default().fg(Color::Gray))
+            .wrap(Wrap { trim: true })
+            .block(block);
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = page
+        .groups
+        .iter()
+        .map(|group| ListItem::new(group.as_str()))
+        .collect();
+    let list = List::new(items).block(block).highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    );
+    f.render_stateful_widget(list, area, &mut page.group_state);
+}
+
+/// The lines ending `scroll` lines back from the newest, one per event
+fn render_lines(f: &mut Frame, area: Rect, app: &App) {
+    let page = &app.view.log_tail;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Events")
+        .border_style(Style::default().fg(Color::White));
+    let height = area.height.saturating_sub(2) as usize;
+
+    let mut lines: Vec<Line> = Vec::new();
+    if let Some(notice) = &page.notice {
+        lines.push(Line::styled(
+            notice.clone(),
+            Style::default().fg(Color::Red),
+        ));
+    }
+    let events = page.buffer.events();
+    let bottom = events.len().saturating_sub(page.scroll);
+    let top = bottom.saturating_sub(height.saturating_sub(lines.len()));
+    lines.extend(events.range(top..bottom).map(|event| {
+        let time = locale().time(&DateTime::<Local>::from(event.timestamp), true);
+        let message = event.message.trim_end().replace(['\n', '\r', '\t'], " ");
+        let matched = page
+            .search
+            .as_deref()
+            .is_some_and(|query| tail::matches(event, query));
+        let style = if matched {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        Line::from(vec![
+            Span::styled(format!("{time} "), Style::default().fg(Color::Gray)),
+            Span::styled(message, style),
+        ])
+    }));
+    if page.buffer.is_empty() && page.notice.is_none() {
+        lines.push(Line::styled(
+            "Waiting for events...",
+            Style::default().fg(Color::Gray),
+        ));
+    }
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn render_input(f: &mut Frame, area: Rect, app: &App) {
+    let (title, text) = match &app.view.log_tail.input {
+        Some(LogInput::Search(text)) => ("Search (Enter: find, Esc: cancel)", text),
+        Some(LogInput::Filter(text)) => (
+            "CloudWatch Logs filter pattern, e.g. ERROR or { $.level = \"error\" } (Enter: apply, empty clears)",
+            text,
+        ),
+        None => return,
+    };
+    let input = Paragraph::new(format!("{text}_")).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(Color::Yellow)),
+    );
+    f.render_widget(input, area);
+}
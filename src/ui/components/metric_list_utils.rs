@@ -15,11 +15,15 @@ use ratatui::{layout::Rect, Frame};
 /// Render the enhanced metric list with full functionality
 pub fn render_enhanced_metric_list(f: &mut Frame, app: &mut App, area: Rect) {
     use super::{
-        metric_utils::{format_value, get_available_metrics_with_history, get_metric_colors},
+        metric_utils::{
+            format_value, get_available_metrics_with_history, get_metric_colors,
+            get_metric_severity, get_quota_colors, get_quota_severity,
+        },
         sparkline_utils::generate_inline_sparkline,
         visual_utils::{create_metric_block, MetricBlockParams},
     };
     use ratatui::{
+        layout::{Constraint, Direction, Layout},
         style::{Color, Style},
         widgets::{Block, Borders, List, ListItem, Paragraph},
     };
@@ -57,9 +61,9 @@ pub fn render_enhanced_metric_list(f: &mut Frame, app: &mut App, area: Rect) {
     }
 
     // Get metrics with current values and history for enhanced display
-    let metrics_with_data = get_available_metrics_with_history(&app.metrics);
+    let metrics_with_data = get_available_metrics_with_history(&app.view.metrics);
 
-    if metrics_with_data.is_empty() {
+    if metrics_with_data.is_empty() && app.view.failed_metrics.is_empty() {
         let no_data = Paragraph::new("No metric data available")
             .style(Style::default().fg(Color::DarkGray))
             .alignment(ratatui::layout::Alignment::Center)
@@ -73,31 +77,50 @@ pub fn render_enhanced_metric_list(f: &mut Frame, app: &mut App, area: Rect) {
         return;
     }
 
-    // Calculate items that can fit on screen for scrolling
-    let _items_per_screen = (area.height.saturating_sub(2)) as usize; // Account for borders
     let total_items = available_metrics.len();
     let selected_index = app.get_sparkline_grid_selected_index();
 
-    // Use the pre-calculated metrics_per_screen value
-    // This should be set by calling app.update_metrics_per_screen() before rendering
+    // Use the pre-calculated metrics_per_screen and column count
+    // These should be set by calling app.update_metrics_per_screen() before rendering
     let actual_metrics_per_screen = app.metrics_per_screen;
+    let columns = app.metric_grid_columns.max(1);
 
     // Use the app's scroll offset directly
-    let scroll_offset = app.scroll_offset;
-
-    // Calculate responsive widths to fill the terminal width
-    let total_width = area.width.saturating_sub(4) as usize; // Account for borders
+    let scroll_offset = app.view.scroll_offset;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "{} ({}/{})",
+            title,
+            selected_index.saturating_add(1).min(total_items),
+            total_items
+        ))
+        .border_style(Style::default().fg(border_color));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let column_areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, columns as u32); columns])
+        .split(inner);
+
+    // Calculate responsive widths to fill each column; recomputed every draw so
+    // sparklines are re-bucketed to the new width after a resize
+    let total_width = column_areas[0].width.saturating_sub(2) as usize; // Account for the metric frame
     let value_width = 12; // Fixed width for values
     let separators_width = 8; // Space for separators and padding
-    let name_width = (total_width * 30 / 100).clamp(18, 30); // 30% of width for names
-    let sparkline_width = total_width
-        .saturating_sub(name_width + value_width + separators_width)
-        .max(20); // Rest for sparkline
+    let name_width = (total_width * 30 / 100).clamp(12, 30); // 30% of width for names
+    let sparkline_width = total_width.saturating_sub(name_width + value_width + separators_width); // Rest for sparkline
+
+    // Connection quota for the selected instance, shown next to Database Connections
+    let connections_quota = app
+        .get_selected_rds_instance()
+        .and_then(|instance| app.quotas.rds_max_connections(instance));
 
-    // Create enhanced metric blocks with distinct visual separation and spacing
+    // Metrics are laid out row by row across the columns
     let empty_history = Vec::new();
-    let mut items: Vec<ListItem> = Vec::new();
-    let mut metric_positions: Vec<usize> = Vec::new(); // Track which positions contain actual metrics
+    let mut column_items: Vec<Vec<ListItem>> = vec![Vec::new(); columns];
 
     for (original_index, metric_type) in available_metrics
         .iter()
@@ -107,12 +130,30 @@ pub fn render_enhanced_metric_list(f: &mut Frame, app: &mut App, area: Rect) {
     {
         let is_selected = original_index == selected_index;
 
-        // Track the position of this metric in the items list (content line is the middle line)
-        let metric_position = items.len() + 1; // +1 because content is the second line of the frame
-        metric_positions.push(metric_position);
-
         // Find corresponding data for this metric
         let metric_name = metric_type.display_name();
+        let theme = &app.config.theme;
+
+        // A metric whose fetch failed keeps its tile, with the reason in place of the sparkline
+        if let Some(reason) = app.view.failed_metrics.get(metric_type.metric_name()) {
+            let failed_color = theme.color(crate::ui::theme::Severity::Critical);
+            let content_lines = create_metric_block(MetricBlockParams {
+                metric_name: metric_name.to_string(),
+                sparkline: format!("{reason} (Enter/x: retry)"),
+                formatted_value: format!(
+                    "{}failed",
+                    theme.marker(crate::ui::theme::Severity::Critical)
+                ),
+                is_selected,
+                value_color: failed_color,
+                sparkline_color: failed_color,
+                name_width,
+                sparkline_width,
+            });
+            let column = (original_index - scroll_offset) % columns;
+            column_items[column].extend(content_lines.into_iter().map(ListItem::new));
+            continue;
+        }
         let metric_data = metrics_with_data
             .iter()
             .find(|(name, _, _, _)| *name == metric_name);
@@ -125,9 +166,31 @@ pub fn render_enhanced_metric_list(f: &mut Frame, app: &mut App, area: Rect) {
         // Generate elegant inline sparkline
         let sparkline = generate_inline_sparkline(history, sparkline_width);
 
-        // Format the value with proper styling
-        let formatted_value = format_value(current_value, unit);
-        let (value_color, sparkline_color) = get_metric_colors(metric_name, current_value);
+        // Format the value with proper styling; quota-limited metrics show their usage
+        let quota = connections_quota
+            .as_ref()
+            .filter(|_| *metric_type == crate::models::MetricType::DatabaseConnections);
+        let (formatted_value, severity, (value_color, sparkline_color)) = match quota {
+            Some(quota) => (
+                format!(
+                    "{} {:.0}%",
+                    format_value(current_value, unit),
+                    quota.usage(current_value) * 100.0
+                ),
+                Some(get_quota_severity(quota, current_value)),
+                get_quota_colors(theme, quota, current_value),
+            ),
+            None => (
+                format_value(current_value, unit),
+                get_metric_severity(&app.config.thresholds, metric_name, current_value),
+                get_metric_colors(theme, &app.config.thresholds, metric_name, current_value),
+            ),
+        };
+        // With patterns on, the state is also readable without color
+        let formatted_value = match severity {
+            Some(severity) => format!("{}{formatted_value}", theme.marker(severity)),
+            None => formatted_value,
+        };
 
         // Create distinct visual block for each metric (returns multiple lines for frame)
         let content_lines = create_metric_block(MetricBlockParams {
@@ -142,45 +205,14 @@ pub fn render_enhanced_metric_list(f: &mut Frame, app: &mut App, area: Rect) {
         });
 
         // Add each line of the framed metric block as separate list items
-        for line in content_lines {
-            items.push(ListItem::new(line));
-        }
+        let column = (original_index - scroll_offset) % columns;
+        column_items[column].extend(content_lines.into_iter().map(ListItem::new));
 
         // No additional spacing between metrics - frames provide sufficient visual separation
     }
 
-    // Create list state for navigation and scrolling
-    let mut list_state = ratatui::widgets::ListState::default();
-    let has_items = !items.is_empty();
-    if has_items
-        && selected_index >= scroll_offset
-        && selected_index < scroll_offset + actual_metrics_per_screen
-    {
-        // Find the position of the selected metric in our items list
-        let relative_index = selected_index - scroll_offset;
-        if let Some(&position) = metric_positions.get(relative_index) {
-            list_state.select(Some(position));
-        }
+    // Selection is drawn by the metric blocks themselves; scrolling is handled by scroll_offset
+    for (items, column_area) in column_items.into_iter().zip(column_areas.iter()) {
+        f.render_widget(List::new(items), *column_area);
     }
-
-    // Create the list widget with enhanced styling
-    let list = List::new(items)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(format!(
-                    "{} ({}/{})",
-                    title,
-                    selected_index.saturating_add(1).min(total_items),
-                    total_items
-                ))
-                .border_style(Style::default().fg(border_color)),
-        )
-        .highlight_style(Style::default()) // Remove highlight since we handle it manually
-        .highlight_symbol(""); // Remove default highlight symbol
-
-    // Render the list with scrolling support
-    f.render_stateful_widget(list, area, &mut list_state);
-
-    // Scroll indicator removed per user request
 }
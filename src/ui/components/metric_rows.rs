@@ -0,0 +1,94 @@
+use super::{instance_details::render_failed_metric, metric_utils::format_value};
+use crate::aws::metrics::types::{MetricDefinition, MetricValue};
+use crate::models::App;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph, Sparkline},
+    Frame,
+};
+use std::collections::HashMap;
+
+/// How a summary draws the sparkline of a metric that returned data
+pub struct MetricRow {
+    pub title: String,
+    pub style: Style,
+    // Top of the sparkline's scale; the series' own peak when None
+    pub max: Option<u64>,
+}
+
+impl MetricRow {
+    /// "Invocations  latest: 12", scaled to the series
+    pub fn latest(definition: &MetricDefinition, value: &MetricValue) -> Self {
+        let unit = definition.unit.as_deref().unwrap_or("");
+        Self {
+            title: format!(
+                "{}  latest: {}",
+                definition.name,
+                format_value(value.current, unit)
+            ),
+            style: Style::default().fg(Color::Cyan),
+            max: None,
+        }
+    }
+}
+
+/// One sparkline per metric that returned data or failed to load, in the order of `definitions`
+///
+/// `metrics` is None until the first fetch lands, which shows `empty` instead.
+/// Each sparkline draws the newest datapoints that fit its width.
+pub fn render_metric_rows(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    definitions: Vec<MetricDefinition>,
+    metrics: Option<&HashMap<String, MetricValue>>,
+    empty: &str,
+    row: impl Fn(&MetricDefinition, &MetricValue) -> MetricRow,
+) {
+    let Some(metrics) = metrics else {
+        let empty = Paragraph::new(empty.to_string())
+            .style(Style::default().fg(Color::Gray))
+            .block(Block::default().borders(Borders::ALL).title("Metrics"));
+        f.render_widget(empty, area);
+        return;
+    };
+
+    let rows: Vec<_> = definitions
+        .into_iter()
+        .filter_map(|definition| {
+            let value = metrics.get(&definition.name);
+            (value.is_some() || app.view.failed_metrics.contains_key(&definition.name))
+                .then_some((definition, value))
+        })
+        .collect();
+
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(4); rows.len()])
+        .split(area);
+
+    for ((definition, value), row_area) in rows.iter().zip(row_areas.iter()) {
+        let Some(value) = value else {
+            render_failed_metric(f, *row_area, app, &definition.name);
+            continue;
+        };
+        let MetricRow { title, style, max } = row(definition, value);
+        let width = usize::from(row_area.width.saturating_sub(2));
+        let skip = value.history.len().saturating_sub(width);
+        // Gaps (NaN) draw as zero, like negative values
+        let data: Vec<u64> = value.history[skip..]
+            .iter()
+            .map(|v| v.max(0.0).round() as u64)
+            .collect();
+
+        let mut sparkline = Sparkline::default()
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .data(&data)
+            .style(style);
+        if let Some(max) = max {
+            sparkline = sparkline.max(max);
+        }
+        f.render_widget(sparkline, *row_area);
+    }
+}
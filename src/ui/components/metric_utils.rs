@@ -1,4 +1,8 @@
+use crate::aws::quotas::{Quota, APPROACHING_QUOTA};
 use crate::models::{MetricData, MetricType};
+use crate::thresholds::Thresholds;
+use crate::ui::theme::{Severity, Theme};
+use crate::utils::formatting::format_number;
 use ratatui::style::Color;
 
 /// Get the unit string for a given metric type
@@ -83,104 +87,73 @@ pub fn get_available_metrics_with_history(
     available
 }
 
+/// Health of a metric based on its name and current value
+///
+/// Returns None for metrics without a threshold, which are shown in a
+/// neutral color.
+pub fn get_metric_severity(
+    thresholds: &Thresholds,
+    metric_name: &str,
+    current_value: f64,
+) -> Option<Severity> {
+    thresholds.severity(metric_name, current_value)
+}
+
 /// Get color scheme for a metric based on its name and current value
-pub fn get_metric_colors(metric_name: &str, current_value: f64) -> (Color, Color) {
-    let (value_color, trend_color) = match metric_name {
-        "CPU Utilization" => {
-            if current_value > 80.0 {
-                (Color::Red, Color::Red)
-            } else if current_value > 60.0 {
-                (Color::Yellow, Color::Yellow)
-            } else {
-                (Color::Green, Color::Green)
-            }
-        }
-        "Database Connections" => {
-            // Assume > 1000 is high, > 500 is moderate
-            if current_value > 1000.0 {
-                (Color::Red, Color::Red)
-            } else if current_value > 500.0 {
-                (Color::Yellow, Color::Yellow)
-            } else {
-                (Color::Green, Color::Green)
-            }
-        }
-        "Read Latency" | "Write Latency" => {
-            // Latency in seconds - > 0.1s is bad, > 0.05s is moderate
-            if current_value > 0.1 {
-                (Color::Red, Color::Red)
-            } else if current_value > 0.05 {
-                (Color::Yellow, Color::Yellow)
-            } else {
-                (Color::Green, Color::Green)
-            }
-        }
-        "Free Storage Space" | "Freeable Memory" => {
-            // For storage/memory, lower is worse (inverted logic)
-            if current_value < 1024.0 * 1024.0 * 1024.0 {
-                (Color::Red, Color::Red)
-            }
-            // < 1GB
-            else if current_value < 5.0 * 1024.0 * 1024.0 * 1024.0 {
-                (Color::Yellow, Color::Yellow)
-            }
-            // < 5GB
-            else {
-                (Color::Green, Color::Green)
-            }
-        }
-        "Burst Balance" => {
-            if current_value < 20.0 {
-                (Color::Red, Color::Red)
-            } else if current_value < 50.0 {
-                (Color::Yellow, Color::Yellow)
-            } else {
-                (Color::Green, Color::Green)
-            }
-        }
-        "Replica Lag" => {
-            if current_value > 300.0 {
-                (Color::Red, Color::Red)
-            }
-            // > 5 minutes
-            else if current_value > 60.0 {
-                (Color::Yellow, Color::Yellow)
-            }
-            // > 1 minute
-            else {
-                (Color::Green, Color::Green)
-            }
-        }
-        _ => (Color::Cyan, Color::Cyan), // Default neutral color
-    };
+pub fn get_metric_colors(
+    theme: &Theme,
+    thresholds: &Thresholds,
+    metric_name: &str,
+    current_value: f64,
+) -> (Color, Color) {
+    let color = get_metric_severity(thresholds, metric_name, current_value)
+        .map_or(Color::Cyan, |severity| theme.color(severity)); // Default neutral color
+    (color, color)
+}
+
+/// Health of a metric with a known quota, based on how much of it is used
+pub fn get_quota_severity(quota: &Quota, current_value: f64) -> Severity {
+    let usage = quota.usage(current_value);
+    if usage >= 0.95 {
+        Severity::Critical
+    } else if usage >= APPROACHING_QUOTA {
+        Severity::Warning
+    } else {
+        Severity::Ok
+    }
+}
 
-    (value_color, trend_color)
+/// Colors for a metric with a known quota, based on how much of it is used
+pub fn get_quota_colors(theme: &Theme, quota: &Quota, current_value: f64) -> (Color, Color) {
+    let color = theme.color(get_quota_severity(quota, current_value));
+    (color, color)
 }
 
 /// Format a metric value based on its unit
 pub fn format_value(value: f64, unit: &str) -> String {
     match unit {
         "Bytes" | "Bytes/Second" => format_bytes(value),
-        "Percent" => format!("{value:.1}%"),
+        "Percent" => format!("{}%", format_number(value, 1)),
         "Seconds" => {
             if value < 0.001 {
-                format!("{:.2} μs", value * 1_000_000.0)
+                format!("{} μs", format_number(value * 1_000_000.0, 2))
             } else if value < 1.0 {
-                format!("{:.2} ms", value * 1000.0)
+                format!("{} ms", format_number(value * 1000.0, 2))
             } else {
-                format!("{value:.2} s")
+                format!("{} s", format_number(value, 2))
             }
         }
+        "Milliseconds" => format!("{} ms", format_number(value, 0)),
         "Count" | "Count/Second" | "Credits" => {
             if value >= 1_000_000.0 {
-                format!("{:.1}M", value / 1_000_000.0)
+                format!("{}M", format_number(value / 1_000_000.0, 1))
             } else if value >= 1_000.0 {
-                format!("{:.1}K", value / 1_000.0)
+                format!("{}K", format_number(value / 1_000.0, 1))
             } else {
-                format!("{value:.1}")
+                format_number(value, 1)
             }
         }
-        _ => format!("{value:.2}"),
+        _ => format_number(value, 2),
     }
 }
 
@@ -195,9 +168,9 @@ pub fn format_bytes(bytes: f64) -> String {
 
     for &(unit, size) in UNITS {
         if bytes >= size {
-            return format!("{:.1} {}", bytes / size, unit);
+            return format!("{} {unit}", format_number(bytes / size, 1));
         }
     }
 
-    format!("{bytes:.0} B")
+    format!("{} B", format_number(bytes, 0))
 }
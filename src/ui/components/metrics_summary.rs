@@ -1,8 +1,16 @@
 use super::{
-    display_utils::calculate_time_panel_width, instance_details::render_metrics_loading,
-    metric_list_utils::render_enhanced_metric_list, time_range_utils::render_time_range_panel,
+    display_utils::{calculate_grid_columns, calculate_time_panel_width},
+    footer::render_footer,
+    incident_pane::render_incident_pane,
+    instance_details::{render_metrics_loading, render_no_recent_data},
+    metric_list_utils::render_enhanced_metric_list,
+    metric_utils::{get_quota_colors, get_quota_severity},
+    time_range_utils::render_time_range_panel,
 };
+use crate::journal::unix_now;
 use crate::models::App;
+use crate::scripting::HealthLevel;
+use crate::ui::theme::Severity;
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -13,59 +21,86 @@ use ratatui::{
 };
 
 pub fn render_metrics_summary(f: &mut Frame, app: &mut App) {
+    let mut script_lines = create_secret_lines(app);
+    script_lines.extend(create_script_lines(app));
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(4), // Header - increased height to show the endpoint
-            Constraint::Min(0),    // Content
-            Constraint::Length(1), // Controls at bottom
+            Constraint::Length(5 + script_lines.len() as u16), // Header - endpoint, quotas, credentials and script output
+            Constraint::Min(0),                                // Content
+            Constraint::Length(1),                             // Controls at bottom
         ])
         .split(f.area());
 
     // Header - Instance Information
     if let Some(instance) = app.get_selected_rds_instance() {
-        render_instance_info(f, chunks[0], app, instance);
+        let quota_line = create_quota_line(app, instance);
+        render_instance_info(f, chunks[0], quota_line, script_lines, instance);
     } else {
         render_default_header(f, chunks[0]);
     }
 
+    // Incident mode tails the error log under the metrics
+    let mut content = chunks[1];
+    if let Some(incident) = app.incident_on_selected() {
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Percentage(40)])
+            .split(content);
+        render_incident_pane(f, incident, &app.config.theme, split[1]);
+        content = split[0];
+    }
+
     // Content area - check for errors first, then loading, then normal content
     if let Some(error_msg) = &app.error_message {
-        render_error_message(f, chunks[1], error_msg);
+        render_error_message(f, content, error_msg);
     } else if app.metrics_loading {
-        render_metrics_loading(f, chunks[1]);
+        render_metrics_loading(f, content);
     } else {
         // Two-panel layout: Time ranges (left), Full-height metric list (right)
-        let time_panel_width = calculate_time_panel_width(chunks[1].width);
+        let time_panel_width = calculate_time_panel_width(content.width);
         let content_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
                 Constraint::Length(time_panel_width), // Compact Time Panel (responsive width)
                 Constraint::Min(0),                   // Right panel for full-height metric list
             ])
-            .split(chunks[1]);
+            .split(content);
 
         // Compact Time Range Panel
         render_compact_time_ranges(f, app, content_chunks[0]);
 
         // Full-height Metric List Panel
         // Update metrics_per_screen before rendering to ensure navigation works correctly
-        app.update_metrics_per_screen(content_chunks[1].height);
-        render_enhanced_metric_list(f, app, content_chunks[1]);
+        // Both are recomputed every draw, so a terminal resize re-lays out the grid
+        let metric_count = app.get_available_metrics().len();
+        app.update_metrics_per_screen(
+            content_chunks[1].height,
+            calculate_grid_columns(
+                content_chunks[1].width,
+                content_chunks[1].height,
+                metric_count,
+            ),
+        );
+        match app.stale_data_reason() {
+            Some(reason) => render_no_recent_data(f, content_chunks[1], &reason),
+            None => render_enhanced_metric_list(f, app, content_chunks[1]),
+        }
     }
 
     // Controls
-    render_controls(f, chunks[2]);
+    render_footer(f, chunks[2], app);
 }
 
 fn render_instance_info(
     f: &mut Frame,
     area: ratatui::layout::Rect,
-    _app: &crate::models::App,
+    quota_line: Line<'static>,
+    script_lines: Vec<Line<'static>>,
     instance: &crate::models::RdsInstance,
 ) {
     let na_string = "N/A".to_string();
-    let info_text = vec![
+    let mut info_text = vec![
         Line::from(vec![
             Span::styled("Engine: ", Style::default().fg(Color::White)),
             Span::styled(&instance.engine, Style::default().fg(Color::White)),
@@ -83,7 +118,9 @@ fn render_instance_info(
                 Style::default().fg(Color::Cyan),
             ),
         ]),
+        quota_line,
     ];
+    info_text.extend(script_lines);
 
     let info = Paragraph::new(info_text)
         .block(
@@ -96,6 +133,119 @@ fn render_instance_info(
     f.render_widget(info, area);
 }
 
+/// Quotas that bound this instance's metrics, colored when usage is approaching them
+fn create_quota_line(app: &App, instance: &crate::models::RdsInstance) -> Line<'static> {
+    let mut spans = vec![Span::styled("Quotas: ", Style::default().fg(Color::White))];
+
+    if let Some(quota) = app.quotas.rds_max_connections(instance) {
+        let connections = app.view.metrics.database_connections;
+        // Same thresholds as the connections tile: warning at 80%, critical at 95%
+        let theme = &app.config.theme;
+        let (style, marker) = match get_quota_severity(&quota, connections) {
+            Severity::Ok => (Style::default().fg(Color::Gray), ""),
+            severity => {
+                let (color, _) = get_quota_colors(theme, &quota, connections);
+                (Style::default().fg(color), theme.marker(severity))
+            }
+        };
+        spans.push(Span::styled(
+            format!("{marker}{} {}  ", quota.name, quota.label()),
+            style,
+        ));
+    }
+
+    let tps = app.quotas.cloudwatch_get_metric_statistics_tps();
+    spans.push(Span::styled(
+        format!("{} {}", tps.name, tps.label()),
+        Style::default().fg(Color::Gray),
+    ));
+
+    Line::from(spans)
+}
+
+/// Rotation state of the secrets holding the instance's credentials, when `[secrets]` is enabled
+fn create_secret_lines(app: &App) -> Vec<Line<'static>> {
+    let Some(instance) = app.get_selected_rds_instance() else {
+        return Vec::new();
+    };
+    let secrets = app.db_secrets_for(instance);
+    if secrets.is_empty() {
+        return Vec::new();
+    }
+
+    let theme = &app.config.theme;
+    let now = unix_now() as i64;
+    let mut spans = vec![Span::styled(
+        "Credentials: ",
+        Style::default().fg(Color::White),
+    )];
+    spans.extend(secrets.iter().map(|secret| {
+        let health = secret.rotation_health(now, app.config.secrets.max_age_days);
+        let (marker, style) = match health.severity {
+            Some(severity) => (theme.marker(severity), theme.style(severity)),
+            None => ("", Style::default().fg(Color::Gray)),
+        };
+        Span::styled(
+            format!("{marker}{} {}  ", secret.name, health.summary),
+            style,
+        )
+    }));
+    vec![Line::from(spans)]
+}
+
+/// Summary lines, derived values and health findings produced by user scripts
+fn create_script_lines(app: &App) -> Vec<Line<'static>> {
+    let output = &app.script_output;
+    let mut lines = Vec::new();
+
+    let mut spans: Vec<Span> = output
+        .summary_lines
+        .iter()
+        .map(|line| Span::styled(format!("{line}  "), Style::default().fg(Color::White)))
+        .collect();
+    spans.extend(output.derived.iter().map(|(name, value)| {
+        Span::styled(
+            format!("{name}: {value:.2}  "),
+            Style::default().fg(Color::Cyan),
+        )
+    }));
+    if !spans.is_empty() {
+        lines.push(Line::from(spans));
+    }
+
+    let mut findings: Vec<Span> = output
+        .health
+        .iter()
+        .filter(|finding| finding.level != HealthLevel::Ok)
+        .map(|finding| {
+            let severity = match finding.level {
+                HealthLevel::Critical => Severity::Critical,
+                _ => Severity::Warning,
+            };
+            let theme = &app.config.theme;
+            let marker = match theme.marker(severity) {
+                "" => "● ",
+                marker => marker,
+            };
+            Span::styled(
+                format!("{marker}{}  ", finding.message),
+                theme.style(severity),
+            )
+        })
+        .collect();
+    if let Some(error) = output.errors.first() {
+        findings.push(Span::styled(
+            format!("Script error: {error}"),
+            Style::default().fg(Color::Red),
+        ));
+    }
+    if !findings.is_empty() {
+        lines.push(Line::from(findings));
+    }
+
+    lines
+}
+
 fn render_default_header(f: &mut Frame, area: ratatui::layout::Rect) {
     let header_block = Paragraph::new("Metrics Summary")
         .style(Style::default().fg(Color::White))
@@ -108,13 +258,6 @@ fn render_default_header(f: &mut Frame, area: ratatui::layout::Rect) {
     f.render_widget(header_block, area);
 }
 
-fn render_controls(f: &mut Frame, area: ratatui::layout::Rect) {
-    let controls = Paragraph::new(
-        "↑/↓: Navigate • Tab: Switch Panels (Time/Sparklines) • Enter: Select • r: Refresh • b/Esc: Back • q: Quit")
-        .style(Style::default().fg(Color::Gray));
-    f.render_widget(controls, area);
-}
-
 fn render_compact_time_ranges(f: &mut Frame, app: &mut App, area: Rect) {
     render_time_range_panel(f, app, area);
 }
@@ -1,16 +1,80 @@
+pub mod activity_panel;
+pub mod alarm_browser;
+pub mod alarm_form;
+pub mod api_calls;
+pub mod batch_queue_summary;
+pub mod blue_green;
+pub mod bookmarks_panel;
+pub mod canary_summary;
+pub mod command_line;
+pub mod compare_panel;
+pub mod composite_alarms;
+pub mod confirmation_prompt;
+pub mod consumers_panel;
+pub mod details_pane;
+pub mod event_rule_summary;
+pub mod export_prompt;
+pub mod fleet;
+pub mod function_summary;
+pub mod global_database;
+pub mod incident_pane;
 pub mod instance_details;
+pub mod internals;
+pub mod jobs_panel;
+pub mod log_tail;
 pub mod metrics_summary;
+pub mod parameters;
+pub mod perf_hud;
+pub mod pin_prompt;
+pub mod profiles_panel;
+pub mod queue_access;
+pub mod queue_summary;
 pub mod rds_list;
+pub mod regions_panel;
+pub mod release_notes;
+pub mod replica_topology;
+pub mod resource_groups;
+pub mod rightsizing_panel;
+pub mod screen_reader;
+pub mod single_panel;
+pub mod slow_queries;
+pub mod stack_panel;
+pub mod tab_bar;
+pub mod threshold_editor;
+pub mod timings;
 
 pub mod display_utils;
+pub mod footer;
 pub mod metric_list_utils;
+pub mod metric_rows;
 pub mod metric_utils;
 pub mod service_list;
 pub mod sparkline_utils;
 pub mod time_range_utils;
 pub mod visual_utils;
 
+pub use alarm_browser::render_alarm_browser;
+pub use api_calls::render_api_calls;
+pub use batch_queue_summary::render_batch_queue_summary;
+pub use blue_green::render_blue_green;
+pub use canary_summary::render_canary_summary;
+pub use composite_alarms::render_composite_alarms;
+pub use event_rule_summary::render_event_rule_summary;
+pub use fleet::render_fleet;
+pub use function_summary::render_function_summary;
+pub use global_database::render_global_database;
 pub use instance_details::render_instance_details;
+pub use internals::render_internals;
+pub use log_tail::render_log_tail;
 pub use metrics_summary::render_metrics_summary;
+pub use parameters::render_parameters;
+pub use queue_summary::render_queue_summary;
 pub use rds_list::render_rds_list;
+pub use release_notes::render_release_notes;
+pub use replica_topology::render_replica_topology;
+pub use resource_groups::render_resource_groups;
+pub use screen_reader::render_screen_reader;
 pub use service_list::render_service_list;
+pub use slow_queries::render_slow_queries;
+pub use tab_bar::render_tab_bar;
+pub use timings::render_timings;
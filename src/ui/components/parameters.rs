@@ -0,0 +1,139 @@
+use super::footer::render_footer;
+use crate::models::App;
+use crate::ui::theme::Severity;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+/// Parameters of an RDS instance's parameter group that differ from the engine defaults
+///
+/// Static parameters are flagged: they only apply after a reboot, so while the
+/// group is pending reboot the instance is still running with the old values.
+pub fn render_parameters(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header - group, family and apply status
+            Constraint::Min(0),    // Overridden parameters
+            Constraint::Length(1), // Controls at bottom
+        ])
+        .split(f.area());
+
+    render_header(f, chunks[0], app);
+    render_table(f, chunks[1], app);
+
+    render_footer(f, chunks[2], app);
+}
+
+fn render_header(f: &mut Frame, area: Rect, app: &App) {
+    let instance = app
+        .get_selected_rds_instance_id()
+        .unwrap_or_else(|| "-".to_string());
+    let theme = &app.config.theme;
+    let line = match &app.view.parameters.drift {
+        Some(drift) => {
+            let status = drift.apply_status.as_deref().unwrap_or("unknown");
+            let status = if drift.pending_reboot() {
+                Span::styled(
+                    format!(
+                        "{}{status}: static changes apply after a reboot",
+                        theme.marker(Severity::Warning)
+                    ),
+                    theme.style(Severity::Warning),
+                )
+            } else {
+                Span::styled(status.to_string(), Style::default().fg(Color::Green))
+            };
+            Line::from(vec![
+                Span::styled(drift.group.clone(), Style::default().fg(Color::White)),
+                Span::styled(
+                    format!(" ({})", drift.family),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::raw("  "),
+                Span::styled(
+                    format!("{} overridden", drift.overrides.len()),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw("  "),
+                status,
+            ])
+        }
+        None => Line::from(""),
+    };
+    let header = Paragraph::new(line).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Parameters: {instance}"))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(header, area);
+}
+
+fn render_table(f: &mut Frame, area: Rect, app: &mut App) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Overridden parameters (value set on the group and engine default)")
+        .border_style(Style::default().fg(Color::White));
+
+    let Some(drift) = app
+        .view
+        .parameters
+        .drift
+        .as_ref()
+        .filter(|drift| !drift.overrides.is_empty())
+    else {
+        let notice = app.view.parameters.notice.clone().unwrap_or_default();
+        let empty = Paragraph::new(notice)
+            .style(Style::default().fg(Color::Gray))
+            .wrap(Wrap { trim: true })
+            .block(block);
+        f.render_widget(empty, area);
+        return;
+    };
+
+    let theme = &app.config.theme;
+    let pending = drift.pending_reboot();
+    let rows: Vec<Row> = drift
+        .overrides
+        .iter()
+        .map(|parameter| {
+            let apply = match (parameter.is_static, pending) {
+                (true, true) => Cell::from(format!(
+                    "{}static (reboot)",
+                    theme.marker(Severity::Warning)
+                ))
+                .style(theme.style(Severity::Warning)),
+                (true, false) => Cell::from("static").style(Style::default().fg(Color::Yellow)),
+                (false, _) => Cell::from("dynamic").style(Style::default().fg(Color::Gray)),
+            };
+            Row::new(vec![
+                Cell::from(parameter.name.clone()),
+                Cell::from(parameter.value.clone()).style(Style::default().fg(Color::Cyan)),
+                Cell::from(parameter.default.clone().unwrap_or_else(|| "-".to_string())),
+                apply,
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(30),
+            Constraint::Percentage(28),
+            Constraint::Percentage(28),
+            Constraint::Min(17),
+        ],
+    )
+    .header(
+        Row::new(vec!["Parameter", "Value", "Default", "Applies"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .highlight_style(Style::default().bg(Color::DarkGray))
+    .block(block);
+    f.render_stateful_widget(table, area, &mut app.view.parameters.table_state);
+}
@@ -0,0 +1,96 @@
+use super::metric_utils::format_bytes;
+use crate::aws::limiter;
+use crate::aws::metrics::retention;
+use crate::models::App;
+use crate::perf::resident_memory;
+use crate::utils::formatting::format_number;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+const HUD_WIDTH: u16 = 46;
+const HUD_HEIGHT: u16 = 8;
+
+/// Render frame times, process memory, the metric history held and recent
+/// throttling, above the footer
+pub fn render_perf_hud(f: &mut Frame, app: &App, area: Rect) {
+    if area.width < HUD_WIDTH || area.height < HUD_HEIGHT + 1 {
+        return;
+    }
+    let hud = Rect::new(
+        area.right() - HUD_WIDTH,
+        area.bottom() - HUD_HEIGHT - 1,
+        HUD_WIDTH,
+        HUD_HEIGHT,
+    );
+    let held = app.held_history();
+    let ms = |elapsed: std::time::Duration| format_number(elapsed.as_secs_f64() * 1000.0, 1);
+    let row = |label: &'static str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{label:<10}"), Style::default().fg(Color::Gray)),
+            Span::styled(value, Style::default().fg(Color::White)),
+        ])
+    };
+
+    let lines = vec![
+        row(
+            "Frame",
+            format!(
+                "{} ms (slowest {} ms)",
+                ms(app.perf.last_frame),
+                ms(app.perf.slowest_frame)
+            ),
+        ),
+        row(
+            "Memory",
+            resident_memory().map_or_else(
+                || "n/a".to_string(),
+                |bytes| format!("{} resident", format_bytes(bytes as f64)),
+            ),
+        ),
+        row(
+            "History",
+            format!(
+                "{} points, {} series",
+                format_number(held.points as f64, 0),
+                held.series
+            ),
+        ),
+        row(
+            "",
+            format!(
+                "~{}, cap {}/series",
+                format_bytes(held.bytes() as f64),
+                retention::max_points()
+            ),
+        ),
+        row("Jobs", format!("{} running", app.jobs.running())),
+        row("Throttled", throttled()),
+    ];
+
+    let panel = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Performance (F12)")
+            .border_style(Style::default().fg(Color::DarkGray)),
+    );
+    f.render_widget(Clear, hud);
+    f.render_widget(panel, hud);
+}
+
+/// Throttling responses per namespace over the cool-down, e.g. "AWS/RDS 3, AWS/SQS 1"
+fn throttled() -> String {
+    let recent = limiter::cloudwatch().recent_throttles();
+    if recent.is_empty() {
+        return format!("none in {} min", limiter::COOL_DOWN.as_secs() / 60);
+    }
+    recent
+        .iter()
+        .map(|(namespace, count)| format!("{namespace} {count}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
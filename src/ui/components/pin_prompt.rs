@@ -0,0 +1,69 @@
+use super::details_pane::centered_rect;
+use crate::reference_lines::{Bound, PinPrompt, PinnedLine};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Popup for pinning a reference line on the charted metric
+///
+/// Lists the lines already pinned on the metric; submitting an empty line
+/// clears them.
+pub fn render_pin_prompt(
+    f: &mut Frame,
+    prompt: &PinPrompt,
+    pinned: &[PinnedLine],
+    hints: &str,
+    area: Rect,
+) {
+    let popup = centered_rect(60, 40, area);
+    let unit = prompt.unit();
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Line: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{}_", prompt.text),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]),
+        Line::from(Span::styled(
+            "label=value, e.g. max_connections=500 or SLA=200ms; prefix with \"min \" \
+             for a floor. Enter on an empty line clears the metric's lines.",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(""),
+    ];
+    if pinned.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No lines pinned on this metric",
+            Style::default().fg(Color::Gray),
+        )));
+    }
+    for line in pinned {
+        let bound = match line.bound {
+            Bound::Max => "max",
+            Bound::Min => "min",
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{bound}  "), Style::default().fg(Color::Gray)),
+            Span::styled(line.describe(unit), Style::default().fg(Color::White)),
+        ]));
+    }
+
+    let widget = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "Pin Line on {} ({hints})",
+                prompt.metric.display_name()
+            ))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    f.render_widget(Clear, popup);
+    f.render_widget(widget, popup);
+}
@@ -0,0 +1,59 @@
+use super::details_pane::centered_rect;
+use crate::aws::profiles::ProfilesPanel;
+use crate::models::App;
+use crate::ui::keymap::{overlay_hints, KeyContext};
+use crate::utils::formatting::pad_to_width;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Cells given to a profile's name; longer names are cut short
+const NAME_WIDTH: usize = 32;
+
+/// Render the profiles of the shared AWS config files to switch the session to
+pub fn render_profiles_panel(f: &mut Frame, app: &App, panel: &ProfilesPanel, area: Rect) {
+    let popup = centered_rect(50, 50, area);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "AWS Profiles ({})",
+            overlay_hints(app, KeyContext::ProfilesPanel)
+        ))
+        .border_style(Style::default().fg(Color::Cyan));
+    if panel.profiles.is_empty() {
+        let empty = Paragraph::new("No profiles in ~/.aws/config or ~/.aws/credentials")
+            .style(Style::default().fg(Color::Gray))
+            .block(block);
+        f.render_widget(empty, popup);
+        return;
+    }
+
+    let items: Vec<ListItem> = panel
+        .profiles
+        .iter()
+        .map(|profile| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{} ", pad_to_width(&profile.name, NAME_WIDTH)),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled(
+                    profile.region.clone().unwrap_or_default(),
+                    Style::default().fg(Color::Gray),
+                ),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().bg(Color::DarkGray));
+    let mut list_state = ListState::default();
+    list_state.select(Some(panel.selected_index));
+    f.render_stateful_widget(list, popup, &mut list_state);
+}
@@ -0,0 +1,80 @@
+use super::details_pane::centered_rect;
+use crate::models::App;
+use crate::ui::keymap::{overlay_hints, KeyContext};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render the resource policy and encryption of the queue being viewed as an overlay
+///
+/// What the policy allows or denies, and encryption that keeps services from
+/// sending, are listed above the policy itself, its principals highlighted.
+pub fn render_queue_access(f: &mut Frame, app: &App, area: Rect) {
+    let popup = centered_rect(80, 80, area);
+    let theme = &app.config.theme;
+    let queue = app
+        .get_selected_sqs_queue()
+        .map_or("-", |queue| queue.name.as_str());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "Access: {queue} ({})",
+            overlay_hints(app, KeyContext::QueueAccessPanel)
+        ))
+        .border_style(Style::default().fg(Color::Cyan));
+    f.render_widget(Clear, popup);
+    let Some(access) = app.queue_access() else {
+        f.render_widget(block, popup);
+        return;
+    };
+
+    let encryption = access
+        .encryption
+        .as_ref()
+        .map_or_else(|| "unknown".to_string(), |encryption| encryption.label());
+    let mut summary = vec![Line::from(vec![
+        Span::styled("Encryption: ", Style::default().fg(Color::White)),
+        Span::styled(encryption, Style::default().fg(Color::Cyan)),
+    ])];
+    for finding in &access.findings {
+        summary.push(match finding.severity {
+            Some(severity) => Line::styled(
+                format!("{}{}", theme.marker(severity), finding.text),
+                theme.style(severity),
+            ),
+            None => Line::styled(finding.text.clone(), Style::default().fg(Color::Gray)),
+        });
+    }
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+    let summary_height = (summary.len() as u16 * 2).min(inner.height / 2);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(summary_height), Constraint::Min(0)])
+        .split(inner);
+    f.render_widget(Paragraph::new(summary).wrap(Wrap { trim: true }), chunks[0]);
+
+    let principal = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+    let lines: Vec<Line> = access
+        .lines
+        .iter()
+        .skip(app.view.queue_access.scroll)
+        .map(|line| {
+            if line.principal {
+                Line::styled(line.text.clone(), principal)
+            } else {
+                Line::raw(line.text.clone())
+            }
+        })
+        .collect();
+    let policy =
+        Paragraph::new(lines).block(Block::default().borders(Borders::TOP).title("Queue policy"));
+    f.render_widget(policy, chunks[1]);
+}
@@ -0,0 +1,360 @@
+use super::{
+    footer::render_footer,
+    instance_details::{render_failed_metric, render_metrics_loading, render_no_recent_data},
+    metric_utils::{format_value, get_quota_colors, get_quota_severity},
+};
+use crate::analytics::fifo_groups::{GroupActivity, FIFO_METRICS};
+use crate::analytics::queue_age::AGE_BUCKETS;
+use crate::aws::metrics::providers::{MetricProvider, SqsMetricProvider};
+use crate::aws::metrics::types::StatisticType;
+use crate::models::{App, SqsQueue};
+use crate::ui::charts::rendering::stacked_area::render_stacked_area;
+use crate::ui::theme::Severity;
+use crate::utils::formatting::format_number;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Paragraph, Sparkline},
+    Frame,
+};
+
+/// Metrics view for a single SQS queue
+///
+/// Live queue counts come from GetQueueAttributes; the sparklines below show
+/// the CloudWatch series defined by the SQS metric provider, or one family of
+/// them stacked.
+pub fn render_queue_summary(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(5), // Header - live queue attributes and quotas
+            Constraint::Min(0),    // Content
+            Constraint::Length(1), // Controls at bottom
+        ])
+        .split(f.area());
+
+    if let Some(queue) = app.get_selected_sqs_queue() {
+        render_queue_info(f, chunks[0], app, queue);
+    }
+
+    if let Some(error_msg) = &app.error_message {
+        let error = Paragraph::new(error_msg.as_str())
+            .style(Style::default().fg(Color::Red))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Error")
+                    .border_style(Style::default().fg(Color::Red)),
+            )
+            .wrap(ratatui::widgets::Wrap { trim: false });
+        f.render_widget(error, chunks[1]);
+    } else if app.metrics_loading {
+        render_metrics_loading(f, chunks[1]);
+    } else if let Some(reason) = app.stale_data_reason() {
+        render_no_recent_data(f, chunks[1], &reason);
+    } else {
+        let content = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(chunks[1]);
+        render_queue_metrics(f, content[0], app);
+        if let Some(activity) = app.fifo_group_activity() {
+            let side = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(content[1]);
+            render_backlog_age(f, side[0], app);
+            render_fifo_groups(f, side[1], app, &activity);
+        } else {
+            render_backlog_age(f, content[1], app);
+        }
+    }
+
+    render_footer(f, chunks[2], app);
+}
+
+fn render_queue_info(f: &mut Frame, area: Rect, app: &App, queue: &SqsQueue) {
+    let count = |name: &str| queue.attribute(name).unwrap_or("-").to_string();
+
+    let mut watch_line = vec![
+        Span::styled("Type: ", Style::default().fg(Color::White)),
+        Span::styled(queue.queue_type(), Style::default().fg(Color::Green)),
+    ];
+    if let Some(sent) = app
+        .sent_test_message
+        .as_ref()
+        .filter(|sent| sent.queue_name == queue.name)
+    {
+        watch_line.push(Span::raw("  "));
+        watch_line.push(Span::styled(
+            format!(
+                "Test message {} sent {}s ago",
+                sent.message_id,
+                sent.sent_at.elapsed().as_secs()
+            ),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+
+    let in_flight_quota = app.quotas.sqs_in_flight_messages(queue);
+    let in_flight = queue
+        .attribute("ApproximateNumberOfMessagesNotVisible")
+        .and_then(|value| value.parse::<f64>().ok());
+    let theme = &app.config.theme;
+    let (in_flight_color, in_flight_marker) = match in_flight {
+        Some(value) => (
+            get_quota_colors(theme, &in_flight_quota, value).0,
+            theme.marker(get_quota_severity(&in_flight_quota, value)),
+        ),
+        None => (Color::Cyan, ""),
+    };
+    let tps_quota = app.quotas.cloudwatch_get_metric_statistics_tps();
+
+    let info_text = vec![
+        Line::from(vec![
+            Span::styled("Visible: ", Style::default().fg(Color::White)),
+            Span::styled(
+                count("ApproximateNumberOfMessages"),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::raw("  "),
+            Span::styled("In Flight: ", Style::default().fg(Color::White)),
+            Span::styled(
+                format!(
+                    "{in_flight_marker}{}",
+                    count("ApproximateNumberOfMessagesNotVisible")
+                ),
+                Style::default().fg(in_flight_color),
+            ),
+            Span::raw("  "),
+            Span::styled("Delayed: ", Style::default().fg(Color::White)),
+            Span::styled(
+                count("ApproximateNumberOfMessagesDelayed"),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]),
+        Line::from(watch_line),
+        Line::from(vec![
+            Span::styled("Quotas: ", Style::default().fg(Color::White)),
+            Span::styled(
+                format!(
+                    "{} {}  {} {}",
+                    in_flight_quota.name,
+                    in_flight_quota.label(),
+                    tps_quota.name,
+                    tps_quota.label()
+                ),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+    ];
+
+    let info = Paragraph::new(info_text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Queue: {}", queue.name))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(info, area);
+}
+
+fn render_queue_metrics(f: &mut Frame, area: Rect, app: &App) {
+    let Some(metrics) = &app.view.service_metrics else {
+        let empty = Paragraph::new("No CloudWatch data for this queue yet")
+            .style(Style::default().fg(Color::Gray))
+            .block(Block::default().borders(Borders::ALL).title("Metrics"));
+        f.render_widget(empty, area);
+        return;
+    };
+
+    if let Some(chart) = app.stacked_chart() {
+        render_stacked_area(f, area, &chart);
+        return;
+    }
+
+    // Keep the provider's ordering; show metrics that returned data or failed to load.
+    // FIFO group metrics have their own panel.
+    let rows: Vec<_> = SqsMetricProvider::new()
+        .get_metrics_config()
+        .into_iter()
+        .filter(|definition| !FIFO_METRICS.contains(&definition.name.as_str()))
+        .filter_map(|definition| {
+            let value = metrics.raw_metrics.get(&definition.name);
+            (value.is_some() || app.view.failed_metrics.contains_key(&definition.name))
+                .then_some((definition, value))
+        })
+        .collect();
+
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(3); rows.len()])
+        .split(area);
+
+    for ((definition, value), row_area) in rows.iter().zip(row_areas.iter()) {
+        let Some(value) = value else {
+            render_failed_metric(f, *row_area, app, &definition.name);
+            continue;
+        };
+        let title = match definition.statistic {
+            StatisticType::Sum => format!(
+                "{}  latest: {:.0}  total: {:.0}",
+                definition.name,
+                value.current,
+                value.history.iter().sum::<f64>()
+            ),
+            _ => format!(
+                "{}  latest: {}",
+                definition.name,
+                format_number(value.current, 1)
+            ),
+        };
+        let data: Vec<u64> = value.history.iter().map(|v| v.max(0.0) as u64).collect();
+
+        let sparkline = Sparkline::default()
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .data(&data)
+            .style(Style::default().fg(Color::Cyan));
+        f.render_widget(sparkline, *row_area);
+    }
+}
+
+/// Estimated age distribution of the waiting messages and the retention outlook
+fn render_backlog_age(f: &mut Frame, area: Rect, app: &App) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Backlog age (estimated)");
+    let Some((queue, aging)) = app.get_selected_sqs_queue().zip(app.queue_age_estimate()) else {
+        f.render_widget(block, area);
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(4)])
+        .split(block.inner(area));
+    f.render_widget(block, area);
+
+    let theme = &app.config.theme;
+    let bar_style = if aging.at_risk {
+        theme.style(Severity::Critical)
+    } else {
+        Style::default().fg(Color::Cyan)
+    };
+    let bars: Vec<Bar> = AGE_BUCKETS
+        .iter()
+        .zip(aging.buckets)
+        .map(|((_, label), messages)| {
+            Bar::default()
+                .label(Line::from(*label))
+                .value(messages.round() as u64)
+                .text_value(format_value(messages, "Count"))
+        })
+        .collect();
+    let chart = BarChart::default()
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(6)
+        .bar_gap(1)
+        .bar_style(bar_style);
+    f.render_widget(chart, chunks[0]);
+
+    let retention = queue
+        .attribute("MessageRetentionPeriod")
+        .and_then(|value| value.parse::<f64>().ok())
+        .map(|secs| format!(" of {} retention", format_age(secs)))
+        .unwrap_or_default();
+    let mut lines = vec![
+        Line::from(format!(
+            "Oldest: {}{retention}",
+            format_age(aging.oldest_age_secs)
+        )),
+        Line::from(format!(
+            "In {}/s • out {}/s • net {:+.2}/s",
+            format_number(aging.arrival_rate, 2),
+            format_number(aging.drain_rate, 2),
+            aging.net_rate()
+        )),
+    ];
+    if aging.at_risk {
+        let when = aging
+            .time_to_retention_secs
+            .map(|secs| format!(" in ~{}", format_age(secs)))
+            .unwrap_or_default();
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{}Messages risk expiring at the retention limit{when}",
+                theme.marker(Severity::Critical)
+            ),
+            theme.style(Severity::Critical),
+        )));
+    }
+    f.render_widget(Paragraph::new(lines), chunks[1]);
+}
+
+/// Message group parallelism, deduplicated sends and what limits the queue
+fn render_fifo_groups(f: &mut Frame, area: Rect, app: &App, activity: &GroupActivity) {
+    let theme = &app.config.theme;
+    let value = |value: Option<f64>, digits| {
+        value.map_or_else(|| "-".to_string(), |value| format_number(value, digits))
+    };
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Groups in flight: ", Style::default().fg(Color::White)),
+            Span::styled(
+                value(activity.groups_in_flight, 0),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::raw("  "),
+            Span::styled("Received/s per group: ", Style::default().fg(Color::White)),
+            Span::styled(
+                value(activity.receive_rate_per_group, 2),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Sent/s: ", Style::default().fg(Color::White)),
+            Span::styled(
+                format_number(activity.send_rate, 2),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::raw("  "),
+            Span::styled("Deduplicated: ", Style::default().fg(Color::White)),
+            Span::styled(
+                activity
+                    .duplicate_fraction
+                    .map_or_else(|| "-".to_string(), |f| format!("{:.1}%", f * 100.0)),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]),
+        Line::styled(
+            "A group's messages are received one batch at a time, in order: \
+             at most one batch per group in flight, whatever the consumer count",
+            Style::default().fg(Color::Gray),
+        ),
+    ];
+    for hint in &activity.hints {
+        lines.push(if hint.warning {
+            Line::styled(
+                format!("{}{}", theme.marker(Severity::Warning), hint.text),
+                theme.style(Severity::Warning),
+            )
+        } else {
+            Line::styled(hint.text.clone(), Style::default().fg(Color::Yellow))
+        });
+    }
+    let panel = Paragraph::new(lines)
+        .wrap(ratatui::widgets::Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title("FIFO groups"));
+    f.render_widget(panel, area);
+}
+
+/// Compact age such as "45s", "12m" or "2h 13m"
+pub fn format_age(secs: f64) -> String {
+    let secs = secs.max(0.0) as u64;
+    match secs {
+        0..=59 => format!("{secs}s"),
+        60..=3599 => format!("{}m", secs / 60),
+        3600..=86399 => format!("{}h {}m", secs / 3600, secs % 3600 / 60),
+        _ => format!("{}d {}h", secs / 86400, secs % 86400 / 3600),
+    }
+}
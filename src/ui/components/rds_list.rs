@@ -1,4 +1,17 @@
-use crate::models::{App, RdsInstance};
+use super::footer::render_footer;
+use crate::aws::alarms::MetricAlarm;
+use crate::aws::batch::format_job_count;
+use crate::aws::rds::certificates::days_left_label;
+use crate::aws::sqs::folders::{Folder, FolderRow};
+use crate::journal::unix_now;
+use crate::models::{
+    App, BatchJobQueue, Canary, EventRule, LambdaFunction, RdsInstance, ServiceInstance, SqsQueue,
+};
+use crate::ui::components::function_summary::format_setting;
+use crate::ui::components::queue_summary::format_age;
+use crate::ui::theme::{Severity, Theme};
+use crate::utils::formatting::locale;
+use chrono::{Local, TimeZone};
 use ratatui::{
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
@@ -17,37 +30,94 @@ pub fn render_rds_list(f: &mut Frame, app: &mut App) {
         ])
         .split(f.area());
 
-    render_header(f, chunks[0]);
+    render_header(f, chunks[0], app);
 
     // Check for errors first
     if let Some(error_msg) = &app.error_message {
         render_error_message(f, chunks[1], error_msg);
     } else if app.loading {
-        render_loading_message(f, chunks[1]);
+        render_loading_message(f, chunks[1], app);
     } else if app.get_current_instances().is_empty() {
-        render_no_instances_message(f, chunks[1]);
+        render_no_instances_message(f, chunks[1], app);
     } else {
         render_instances_list(f, chunks[1], app);
     }
 
-    render_controls(f, chunks[2]);
+    render_footer(f, chunks[2], app);
 }
 
-fn render_header(f: &mut Frame, area: ratatui::layout::Rect) {
-    let header = Paragraph::new("AWS CloudWatch TUI - RDS Instances")
-        .style(Style::default().fg(Color::White))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("RDS Instances")
-                .border_style(Style::default().fg(Color::Cyan)),
-        );
+/// Plural label for the resources of the selected service
+fn resource_label(app: &App) -> &'static str {
+    match &app.view.selected_service {
+        Some(crate::models::AwsService::Sqs) => "SQS queues",
+        Some(crate::models::AwsService::Synthetics) => "Synthetics canaries",
+        Some(crate::models::AwsService::EventBridge) => "EventBridge rules",
+        Some(crate::models::AwsService::Batch) => "Batch job queues",
+        Some(crate::models::AwsService::Lambda) => "Lambda functions",
+        _ => "RDS instances",
+    }
+}
+
+fn render_header(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let title = match &app.view.selected_service {
+        Some(crate::models::AwsService::Sqs) => "SQS Queues",
+        Some(crate::models::AwsService::Synthetics) => "Synthetics Canaries",
+        Some(crate::models::AwsService::EventBridge) => "EventBridge Rules",
+        Some(crate::models::AwsService::Batch) => "Batch Job Queues",
+        Some(crate::models::AwsService::Lambda) => "Lambda Functions",
+        _ => "RDS Instances",
+    };
+    let mut header_line = vec![Span::styled(
+        format!("AWS CloudWatch TUI - {title}"),
+        Style::default().fg(Color::White),
+    )];
+    if let Some(account) = &app.active_account {
+        header_line.push(Span::styled(
+            format!("  [account: {account}]"),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    if app.all_regions {
+        header_line.push(Span::styled(
+            "  [all regions]",
+            Style::default().fg(Color::Magenta),
+        ));
+    } else if let Some(region) = &app.active_region {
+        header_line.push(Span::styled(
+            format!("  [region: {region}]"),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    if app.watchlist_only {
+        header_line.push(Span::styled(
+            format!("  [watchlist: {}]", app.watchlist.len()),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    if let Some(maintenance) = &app.alarm_maintenance {
+        header_line.push(Span::styled(
+            format!("  [{} alarms muted]", maintenance.alarm_names.len()),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    if let Some(error) = &app.watchlist_error {
+        header_line.push(Span::styled(
+            format!("  {error}"),
+            Style::default().fg(Color::Red),
+        ));
+    }
+    let header = Paragraph::new(Line::from(header_line)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
     f.render_widget(header, area);
 }
 
-fn render_loading_message(f: &mut Frame, area: ratatui::layout::Rect) {
+fn render_loading_message(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     let loading_text = [
-        "Loading RDS instances...".to_string(),
+        format!("Loading {}...", resource_label(app)),
         "".to_string(),
         "Press 'q' to quit or 'Esc' to go back".to_string(),
         "Loading will timeout after 30 seconds".to_string(),
@@ -65,13 +135,21 @@ fn render_loading_message(f: &mut Frame, area: ratatui::layout::Rect) {
     f.render_widget(loading_msg, area);
 }
 
-fn render_no_instances_message(f: &mut Frame, area: ratatui::layout::Rect) {
-    let no_instances = Paragraph::new("No RDS instances found in this account/region")
+fn render_no_instances_message(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let message = if app.watchlist_only {
+        format!(
+            "No watched {} found in this account/region (press 'w' to show all)",
+            resource_label(app)
+        )
+    } else {
+        format!("No {} found in this account/region", resource_label(app))
+    };
+    let no_instances = Paragraph::new(message)
         .style(Style::default().fg(Color::Red))
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("RDS Instances")
+                .title("Instances")
                 .border_style(Style::default().fg(Color::White)),
         );
     f.render_widget(no_instances, area);
@@ -92,24 +170,25 @@ fn render_error_message(f: &mut Frame, area: ratatui::layout::Rect, error_msg: &
 }
 fn render_instances_list(f: &mut Frame, area: ratatui::layout::Rect, app: &mut App) {
     // Get dynamic title based on selected service
-    let title = if let Some(service) = &app.selected_service {
+    let title = if let Some(service) = &app.view.selected_service {
         format!("{} Instances", service.short_name())
     } else {
         "Instances".to_string()
     };
 
+    if app.folders_shown() {
+        render_folder_tree(f, area, app, title);
+        return;
+    }
+
     // Clone the instances to avoid borrowing issues
     let current_instances = app.get_current_instances().clone();
 
-    // Create items from instances
     let items: Vec<ListItem> = current_instances
         .iter()
-        .map(|service_instance| {
-            match service_instance {
-                crate::models::ServiceInstance::Rds(instance) => {
-                    create_instance_list_item(instance)
-                } // Future service instances will be handled here
-            }
+        .enumerate()
+        .map(|(index, service_instance)| {
+            ListItem::new(Line::from(instance_row(app, index, service_instance)))
         })
         .collect();
 
@@ -127,11 +206,201 @@ fn render_instances_list(f: &mut Frame, area: ratatui::layout::Rect, app: &mut A
         )
         .highlight_symbol("");
 
-    f.render_stateful_widget(items_list, area, &mut app.list_state);
+    f.render_stateful_widget(items_list, area, &mut app.view.list_state);
 }
 
-fn create_instance_list_item(instance: &RdsInstance) -> ListItem<'_> {
-    let lines = vec![Line::from(vec![
+/// One resource's row: its markers, region when listing all regions, summary and alarms
+fn instance_row<'a>(
+    app: &App,
+    index: usize,
+    service_instance: &'a ServiceInstance,
+) -> Vec<Span<'a>> {
+    let mut spans = vec![
+        mark_marker(app.is_marked(index)),
+        watch_marker(app.is_watched(index)),
+    ];
+    if let Some(region) = app.instance_region(index) {
+        spans.push(Span::styled(
+            format!("{region:<15} "),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    if let Some(last_seen) = app.departed_since(service_instance) {
+        spans.extend(departed_spans(
+            service_instance.as_aws_instance().id(),
+            last_seen,
+        ));
+        return spans;
+    }
+    spans.extend(match service_instance {
+        ServiceInstance::Rds(instance) => {
+            let blue_green = app
+                .blue_green_deployment_for(&instance.identifier)
+                .map(|deployment| deployment.status.clone());
+            instance_spans(&app.config.theme, instance, blue_green)
+        }
+        ServiceInstance::Sqs(queue) => queue_spans(&app.config.theme, queue),
+        ServiceInstance::Canary(canary) => canary_spans(&app.config.theme, canary),
+        ServiceInstance::EventRule(rule) => rule_spans(&app.config.theme, rule),
+        ServiceInstance::BatchQueue(queue) => batch_queue_spans(&app.config.theme, queue),
+        ServiceInstance::Function(function) => function_spans(&app.config.theme, function),
+    });
+    spans.extend(alarm_spans(
+        &app.config.theme,
+        &app.alarms_for(service_instance),
+    ));
+    spans
+}
+
+/// The SQS list as folders, each followed by its queues unless collapsed
+fn render_folder_tree(f: &mut Frame, area: ratatui::layout::Rect, app: &mut App, title: String) {
+    let (folders, rows) = app.folder_tree();
+    let cursor = app.folder_cursor(&folders, &rows);
+    let instances = app.get_current_instances().clone();
+
+    let items: Vec<ListItem> = rows
+        .iter()
+        .map(|row| match *row {
+            FolderRow::Folder(index) => {
+                let folder = &folders[index];
+                let collapsed = app.view.queue_folders.collapsed.contains(&folder.name);
+                ListItem::new(Line::from(folder_spans(folder, collapsed)))
+            }
+            FolderRow::Queue(index) => {
+                let mut spans = vec![Span::raw("    ")];
+                spans.extend(instance_row(app, index, &instances[index]));
+                ListItem::new(Line::from(spans))
+            }
+        })
+        .collect();
+
+    let items_list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("{title} - {} folders", folders.len()))
+                .border_style(Style::default().fg(Color::White)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("");
+
+    let state = &mut app.view.queue_folders.list_state;
+    state.select(cursor);
+    f.render_stateful_widget(items_list, area, state);
+}
+
+/// A folder's row: its name, queue count and the totals across its queues
+fn folder_spans(folder: &Folder, collapsed: bool) -> Vec<Span<'static>> {
+    let oldest = folder
+        .oldest_age
+        .map_or_else(|| "-".to_string(), format_age);
+    vec![
+        Span::styled(
+            if collapsed { "▶ " } else { "▼ " },
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(
+            folder.name.clone(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(" ({} queues)", folder.queues.len()),
+            Style::default().fg(Color::Gray),
+        ),
+        Span::raw(" | "),
+        Span::styled(
+            format!("visible {}", folder.visible),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::raw(" | "),
+        Span::styled(
+            format!("in flight {}", folder.in_flight),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::raw(" | "),
+        Span::styled(format!("oldest {oldest}"), Style::default().fg(Color::Cyan)),
+    ]
+}
+
+/// A resource listed in an earlier session but not now, greyed out with when it was last seen
+fn departed_spans(id: &str, last_seen: u64) -> Vec<Span<'static>> {
+    let style = Style::default().fg(Color::DarkGray);
+    let last_seen = Local
+        .timestamp_opt(last_seen as i64, 0)
+        .single()
+        .map_or_else(
+            || "-".to_string(),
+            |time| {
+                let locale = locale();
+                format!("{} {}", locale.short_date(&time), locale.time(&time, false))
+            },
+        );
+    vec![
+        Span::styled(id.to_string(), style),
+        Span::styled(format!(" | gone, last seen {last_seen}"), style),
+    ]
+}
+
+/// Marker shown in front of resources on the watchlist
+fn watch_marker(watched: bool) -> Span<'static> {
+    if watched {
+        Span::styled("★ ", Style::default().fg(Color::Yellow))
+    } else {
+        Span::raw("  ")
+    }
+}
+
+/// Marker shown in front of resources selected for bulk alarm actions
+fn mark_marker(marked: bool) -> Span<'static> {
+    if marked {
+        Span::styled("✓ ", Style::default().fg(Color::Magenta))
+    } else {
+        Span::raw("  ")
+    }
+}
+
+/// Alarm count with firing and muted counts, omitted when there are no alarms
+fn alarm_spans(theme: &Theme, alarms: &[&MetricAlarm]) -> Vec<Span<'static>> {
+    if alarms.is_empty() {
+        return Vec::new();
+    }
+    let firing = alarms.iter().filter(|alarm| alarm.is_firing()).count();
+    let muted = alarms.iter().filter(|alarm| !alarm.actions_enabled).count();
+
+    let mut spans = vec![
+        Span::raw(" | "),
+        Span::styled(
+            format!("alarms {}", alarms.len()),
+            Style::default().fg(Color::Gray),
+        ),
+    ];
+    if firing > 0 {
+        spans.push(Span::styled(
+            format!(" {}{firing} firing", theme.marker(Severity::Critical)),
+            theme.style(Severity::Critical),
+        ));
+    }
+    if muted > 0 {
+        spans.push(Span::styled(
+            format!(" {}{muted} muted", theme.marker(Severity::Warning)),
+            theme.style(Severity::Warning),
+        ));
+    }
+    spans
+}
+
+fn instance_spans<'a>(
+    theme: &Theme,
+    instance: &'a RdsInstance,
+    blue_green: Option<String>,
+) -> Vec<Span<'a>> {
+    let mut spans = vec![
         Span::styled(
             instance.identifier.to_string(),
             Style::default()
@@ -141,26 +410,229 @@ fn create_instance_list_item(instance: &RdsInstance) -> ListItem<'_> {
         Span::raw(" | "),
         Span::styled(&instance.engine, Style::default().fg(Color::Green)),
         Span::raw(" | "),
-        Span::styled(&instance.status, get_status_style(&instance.status)),
+        status_span(theme, &instance.status),
         Span::raw(" | "),
         Span::styled(&instance.instance_class, Style::default().fg(Color::Cyan)),
-    ])];
-    ListItem::new(lines)
+    ];
+    if let Some(status) = blue_green {
+        spans.push(Span::raw(" | "));
+        spans.push(Span::styled(
+            format!("B/G {status}"),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    let now = unix_now() as i64;
+    if let Some(certificate) = &instance.certificate {
+        if let (Some(severity), Some(days_left)) =
+            (certificate.severity(now), certificate.days_left(now))
+        {
+            spans.push(Span::raw(" | "));
+            spans.push(Span::styled(
+                format!(
+                    "{}CA {}",
+                    theme.marker(severity),
+                    days_left_label(days_left)
+                ),
+                theme.style(severity),
+            ));
+        }
+    }
+    spans
 }
 
-fn render_controls(f: &mut Frame, area: ratatui::layout::Rect) {
-    let controls = Paragraph::new(
-        "↑/↓: Navigate • Enter: View Details • Esc: Back to Services • r: Refresh • q: Quit",
-    )
-    .style(Style::default().fg(Color::Gray));
-    f.render_widget(controls, area);
+fn queue_spans<'a>(theme: &Theme, queue: &'a SqsQueue) -> Vec<Span<'a>> {
+    let count = |name: &str| queue.attribute(name).unwrap_or("-").to_string();
+    let name = Span::styled(
+        queue.name.to_string(),
+        Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+    if let Some(error) = &queue.error {
+        let severity = Severity::Critical;
+        return vec![
+            name,
+            Span::raw(" | "),
+            Span::styled(
+                format!("{}{error}", theme.marker(severity)),
+                theme.style(severity),
+            ),
+        ];
+    }
+    vec![
+        name,
+        Span::raw(" | "),
+        Span::styled(queue.queue_type(), Style::default().fg(Color::Green)),
+        Span::raw(" | "),
+        Span::styled(
+            format!("visible {}", count("ApproximateNumberOfMessages")),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::raw(" | "),
+        Span::styled(
+            format!(
+                "in flight {}",
+                count("ApproximateNumberOfMessagesNotVisible")
+            ),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]
 }
 
-fn get_status_style(status: &str) -> Style {
-    match status {
-        "available" => Style::default().fg(Color::Green),
-        "stopped" => Style::default().fg(Color::Red),
-        "starting" | "stopping" => Style::default().fg(Color::Yellow),
+fn canary_spans<'a>(theme: &Theme, canary: &'a Canary) -> Vec<Span<'a>> {
+    let state_style = match canary.state.as_str() {
+        "RUNNING" => theme.style(Severity::Ok),
+        "ERROR" => theme.style(Severity::Critical),
         _ => Style::default().fg(Color::Gray),
+    };
+    let mut spans = vec![
+        Span::styled(
+            canary.name.to_string(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" | "),
+        Span::styled(canary.state.to_lowercase(), state_style),
+    ];
+    if let Some(run) = &canary.last_run {
+        let severity = if run.failed() {
+            Severity::Critical
+        } else {
+            Severity::Ok
+        };
+        spans.push(Span::raw(" | "));
+        spans.push(Span::styled(
+            format!(
+                "{}last run {}",
+                theme.marker(severity),
+                run.state.to_lowercase()
+            ),
+            theme.style(severity),
+        ));
+    }
+    spans
+}
+
+fn rule_spans<'a>(theme: &Theme, rule: &'a EventRule) -> Vec<Span<'a>> {
+    let state_style = if rule.is_enabled() {
+        theme.style(Severity::Ok)
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+    let mut spans = vec![
+        Span::styled(
+            rule.id.to_string(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" | "),
+        Span::styled(rule.state.to_lowercase(), state_style),
+        Span::raw(" | "),
+        Span::styled(
+            format!("{} targets", rule.targets.len()),
+            Style::default().fg(Color::Gray),
+        ),
+    ];
+    let without_dlq = rule.targets_without_dlq().count();
+    if without_dlq > 0 {
+        spans.push(Span::raw(" | "));
+        spans.push(Span::styled(
+            format!(
+                "{}{without_dlq} without DLQ",
+                theme.marker(Severity::Warning)
+            ),
+            theme.style(Severity::Warning),
+        ));
+    }
+    spans
+}
+
+fn batch_queue_spans<'a>(theme: &Theme, queue: &'a BatchJobQueue) -> Vec<Span<'a>> {
+    let state_style = if queue.is_enabled() {
+        theme.style(Severity::Ok)
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+    let failed = queue.job_count("FAILED");
+    let failed_style = if failed > 0 {
+        theme.style(Severity::Critical)
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+    vec![
+        Span::styled(
+            queue.name.to_string(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" | "),
+        Span::styled(queue.state.to_lowercase(), state_style),
+        Span::raw(" | "),
+        Span::styled(
+            format!(
+                "queued {} running {}",
+                format_job_count(queue.queued()),
+                format_job_count(queue.job_count("RUNNING"))
+            ),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::raw(" | "),
+        Span::styled(format!("failed {}", format_job_count(failed)), failed_style),
+    ]
+}
+
+fn function_spans<'a>(theme: &Theme, function: &'a LambdaFunction) -> Vec<Span<'a>> {
+    let mut spans = vec![
+        Span::styled(
+            function.name.to_string(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" | "),
+        Span::styled(function.runtime.clone(), Style::default().fg(Color::Cyan)),
+        Span::raw(" | "),
+        Span::styled(
+            format!(
+                "{}, {} timeout",
+                format_setting(function.memory_mb, "MB"),
+                format_setting(function.timeout_secs, "s")
+            ),
+            Style::default().fg(Color::Gray),
+        ),
+    ];
+    // ListFunctions leaves the state out; it is shown once refreshed from the metrics view
+    if let Some(state) = &function.state {
+        let style = if function.is_failed() {
+            theme.style(Severity::Critical)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        spans.push(Span::raw(" | "));
+        spans.push(Span::styled(state.to_lowercase(), style));
+    }
+    spans
+}
+
+fn get_status_severity(status: &str) -> Option<Severity> {
+    match status {
+        "available" => Some(Severity::Ok),
+        "stopped" => Some(Severity::Critical),
+        "starting" | "stopping" => Some(Severity::Warning),
+        _ => None,
+    }
+}
+
+/// Status badge, colored by state and prefixed with a marker when patterns are on
+fn status_span(theme: &Theme, status: &str) -> Span<'static> {
+    match get_status_severity(status) {
+        Some(severity) => Span::styled(
+            format!("{}{status}", theme.marker(severity)),
+            theme.style(severity),
+        ),
+        None => Span::styled(status.to_string(), Style::default().fg(Color::Gray)),
     }
 }
@@ -0,0 +1,55 @@
+use super::details_pane::centered_rect;
+use crate::aws::regions::RegionsPanel;
+use crate::models::App;
+use crate::ui::keymap::{overlay_hints, KeyContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+/// Render the regions to switch the session to, after "All regions"
+pub fn render_regions_panel(f: &mut Frame, app: &App, panel: &RegionsPanel, area: Rect) {
+    let popup = centered_rect(40, 60, area);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "AWS Regions ({})",
+            overlay_hints(app, KeyContext::RegionsPanel)
+        ))
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let current = |active: bool| {
+        if active {
+            Span::styled(" (current)", Style::default().fg(Color::Green))
+        } else {
+            Span::raw("")
+        }
+    };
+    let mut items = vec![ListItem::new(Line::from(vec![
+        Span::styled(
+            format!("All regions ({})", panel.regions.len()),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        current(app.all_regions),
+    ]))];
+    items.extend(panel.regions.iter().map(|region| {
+        let active = !app.all_regions && app.active_region.as_deref() == Some(region.as_str());
+        ListItem::new(Line::from(vec![
+            Span::styled(region.clone(), Style::default().fg(Color::White)),
+            current(active),
+        ]))
+    }));
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().bg(Color::DarkGray));
+    let mut list_state = ListState::default();
+    list_state.select(Some(panel.selected_index));
+    f.render_stateful_widget(list, popup, &mut list_state);
+}
@@ -0,0 +1,35 @@
+use super::details_pane::centered_rect;
+use crate::update_check::Release;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Popup with the release notes of a newer awscw release
+pub fn render_release_notes(f: &mut Frame, release: &Release, area: Rect) {
+    let popup = centered_rect(70, 70, area);
+    let notes = if release.notes.trim().is_empty() {
+        "No release notes were published for this release."
+    } else {
+        release.notes.as_str()
+    };
+
+    let notes_widget = Paragraph::new(notes)
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    "awscw v{} available - you have v{} (Esc: Close)",
+                    release.version,
+                    env!("CARGO_PKG_VERSION")
+                ))
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+
+    f.render_widget(Clear, popup);
+    f.render_widget(notes_widget, popup);
+}
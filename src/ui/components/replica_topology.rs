@@ -0,0 +1,228 @@
+use super::footer::render_footer;
+use super::instance_details::render_metrics_loading;
+use crate::aws::rds::replicas::{is_cross_region, ReplicaLag};
+use crate::config::Config;
+use crate::models::App;
+use crate::ui::theme::Severity;
+use crate::utils::formatting::{display_width, locale, pad_to_width};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Axis, Block, Borders, Chart, Dataset, List, ListItem, Paragraph},
+    Frame,
+};
+use std::time::SystemTime;
+
+/// Series colors, cycled when there are more replicas than colors
+const REPLICA_COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::LightRed,
+];
+
+/// Lag above which a replica is flagged, in seconds
+const LAG_WARNING_SECS: f64 = 60.0;
+const LAG_CRITICAL_SECS: f64 = 300.0;
+
+/// Read replicas of a primary: a lag table above a combined ReplicaLag chart
+pub fn render_replica_topology(f: &mut Frame, app: &mut App) {
+    let table_height = (app.view.topology.lags.len() as u16).clamp(1, 8) + 2;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),            // Header - primary
+            Constraint::Length(table_height), // Replica table
+            Constraint::Min(0),               // Combined lag chart
+            Constraint::Length(1),            // Controls at bottom
+        ])
+        .split(f.area());
+
+    let primary = app.view.topology.primary.clone().unwrap_or_default();
+    let header = Paragraph::new(format!(
+        "Primary: {primary}  ({} read replicas)",
+        app.view.topology.lags.len()
+    ))
+    .style(Style::default().fg(Color::White))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Replica Topology")
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(header, chunks[0]);
+
+    if app.metrics_loading {
+        render_metrics_loading(f, chunks[1].union(chunks[2]));
+    } else {
+        render_replica_table(f, chunks[1], app);
+        render_lag_chart(f, chunks[2], &app.view.topology.lags, &app.config);
+    }
+
+    render_footer(f, chunks[3], app);
+}
+
+fn render_replica_table(f: &mut Frame, area: Rect, app: &mut App) {
+    let name_width = app
+        .view
+        .topology
+        .lags
+        .iter()
+        .map(|lag| display_width(&lag.identifier))
+        .max()
+        .unwrap_or(0);
+
+    let theme = app.config.theme;
+    let items: Vec<ListItem> = app
+        .view
+        .topology
+        .lags
+        .iter()
+        .enumerate()
+        .map(|(i, lag)| {
+            let color = REPLICA_COLORS[i % REPLICA_COLORS.len()];
+            let (lag_text, lag_style) = match lag.current {
+                Some(secs) => {
+                    let severity = lag_severity(secs);
+                    (
+                        format!("{}{secs:.1} s", theme.marker(severity)),
+                        theme.style(severity),
+                    )
+                }
+                None if is_cross_region(&lag.identifier) => (
+                    "cross-region".to_string(),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                None => ("no data".to_string(), Style::default().fg(Color::DarkGray)),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled("■ ", Style::default().fg(color)),
+                Span::styled(
+                    format!("{}  ", pad_to_width(&lag.identifier, name_width)),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled(lag_text, lag_style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Replica Lag")
+                .border_style(Style::default().fg(Color::White)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+    f.render_stateful_widget(list, area, &mut app.view.topology.list_state);
+}
+
+pub fn lag_severity(secs: f64) -> Severity {
+    if secs > LAG_CRITICAL_SECS {
+        Severity::Critical
+    } else if secs > LAG_WARNING_SECS {
+        Severity::Warning
+    } else {
+        Severity::Ok
+    }
+}
+
+fn render_lag_chart(f: &mut Frame, area: Rect, lags: &[ReplicaLag], config: &Config) {
+    let series: Vec<(Color, Vec<(f64, f64)>)> = lags
+        .iter()
+        .enumerate()
+        .map(|(i, lag)| {
+            let points = lag
+                .timestamps
+                .iter()
+                .zip(&lag.history)
+                .map(|(timestamp, &value)| (epoch_seconds(*timestamp), value))
+                .collect();
+            (REPLICA_COLORS[i % REPLICA_COLORS.len()], points)
+        })
+        .collect();
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("ReplicaLag (seconds)")
+        .border_style(Style::default().fg(Color::White));
+
+    let Some((x_bounds, y_bounds)) = chart_bounds(&series) else {
+        let empty = Paragraph::new("No ReplicaLag data for these replicas")
+            .style(Style::default().fg(Color::Gray))
+            .block(block);
+        f.render_widget(empty, area);
+        return;
+    };
+
+    let datasets = series
+        .iter()
+        .map(|(color, points)| {
+            Dataset::default()
+                .marker(config.theme.series_marker(config.charts.marker))
+                .graph_type(config.charts.graph_type())
+                .style(Style::default().fg(*color))
+                .data(points)
+        })
+        .collect();
+
+    let chart = Chart::new(datasets)
+        .block(block)
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds(x_bounds)
+                .labels(vec![
+                    Line::from(time_label(x_bounds[0])),
+                    Line::from(time_label(x_bounds[1])),
+                ]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds(y_bounds)
+                .labels(vec![
+                    Line::from(format!("{:.0}", y_bounds[0])),
+                    Line::from(format!("{:.0}", y_bounds[1])),
+                ]),
+        );
+    f.render_widget(chart, area);
+}
+
+/// Shared axis bounds across all series; None when no series has data
+pub fn chart_bounds(series: &[(Color, Vec<(f64, f64)>)]) -> Option<([f64; 2], [f64; 2])> {
+    let points = series.iter().flat_map(|(_, points)| points.iter());
+    let (mut x_min, mut x_max, mut y_max) = (f64::INFINITY, f64::NEG_INFINITY, 0.0_f64);
+    for &(x, y) in points {
+        x_min = x_min.min(x);
+        x_max = x_max.max(x);
+        y_max = y_max.max(y);
+    }
+    if !x_min.is_finite() {
+        return None;
+    }
+    if x_max <= x_min {
+        x_max = x_min + 60.0;
+    }
+    Some(([x_min, x_max], [0.0, (y_max * 1.1).max(1.0)]))
+}
+
+pub fn epoch_seconds(timestamp: SystemTime) -> f64 {
+    timestamp
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs_f64())
+        .unwrap_or(0.0)
+}
+
+pub fn time_label(epoch: f64) -> String {
+    chrono::DateTime::from_timestamp(epoch as i64, 0)
+        .map(|dt| locale().time(&dt.with_timezone(&chrono::Local), false))
+        .unwrap_or_default()
+}
@@ -0,0 +1,201 @@
+use super::footer::render_footer;
+use crate::models::App;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+/// The account's resource groups or applications, or the members of the one opened
+pub fn render_resource_groups(f: &mut Frame, app: &mut App) {
+    let errors = app.view.resource_groups.members.query_errors.len() as u16;
+    let opened = app.view.resource_groups.group.is_some();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(if opened { 3 } else { 0 }), // Header - health by service
+            Constraint::Min(0),                             // Groups or members
+            Constraint::Length(if errors > 0 { errors + 2 } else { 0 }), // Why the query did not resolve in full
+            Constraint::Length(1),                                       // Controls at bottom
+        ])
+        .split(f.area());
+
+    if opened {
+        render_header(f, chunks[0], app);
+        render_members(f, chunks[1], app);
+    } else {
+        render_groups(f, chunks[1], app);
+    }
+    if errors > 0 {
+        render_query_errors(f, chunks[2], app);
+    }
+
+    render_footer(f, chunks[3], app);
+}
+
+/// Monitored members per service, marked with the worst health among them
+fn render_header(f: &mut Frame, area: Rect, app: &App) {
+    let page = &app.view.resource_groups;
+    let theme = &app.config.theme;
+    let mut spans = Vec::new();
+    for (service, count, worst) in page.service_health() {
+        let text = format!("{} {count}", service.short_name());
+        spans.push(match worst {
+            Some(severity) => Span::styled(
+                format!("{}{text} {}", theme.marker(severity), severity.label()),
+                theme.style(severity),
+            ),
+            None => Span::styled(text, Style::default().fg(Color::White)),
+        });
+    }
+    let others = page
+        .members
+        .members
+        .iter()
+        .filter(|member| member.service().is_none())
+        .count();
+    if others > 0 {
+        spans.push(Span::styled(
+            format!("{others} not monitored"),
+            Style::default().fg(Color::Gray),
+        ));
+    }
+    let mut line = Vec::new();
+    for span in spans {
+        if !line.is_empty() {
+            line.push(Span::raw("  "));
+        }
+        line.push(span);
+    }
+
+    let header = Paragraph::new(Line::from(line)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "{}: {}",
+                page.source.title(),
+                page.group.as_deref().unwrap_or_default()
+            ))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(header, area);
+}
+
+fn render_empty(f: &mut Frame, area: Rect, block: Block, app: &App, text: &str) {
+    let (text, color) = match &app.view.resource_groups.notice {
+        Some(notice) => (notice.as_str(), Color::Red),
+        None => (text, Color::Gray),
+    };
+    let empty = Paragraph::new(text.to_string())
+        .style(Style::default().fg(color))
+        .wrap(Wrap { trim: true })
+        .block(block);
+    f.render_widget(empty, area);
+}
+
+fn render_groups(f: &mut Frame, area: Rect, app: &mut App) {
+    let page = &app.view.resource_groups;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "{} ({}, Enter lists the members)",
+            page.source.title(),
+            page.groups.len()
+        ))
+        .border_style(Style::default().fg(Color::Cyan));
+
+    if page.groups.is_empty() {
+        render_empty(f, area, block, app, "Listing...");
+        return;
+    }
+
+    let rows: Vec<Row> = page
+        .groups
+        .iter()
+        .map(|group| Row::new(vec![Cell::from(group.clone())]))
+        .collect();
+    let table = Table::new(rows, [Constraint::Min(20)])
+        .header(Row::new(vec!["Name"]).style(Style::default().add_modifier(Modifier::BOLD)))
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .block(block);
+    f.render_stateful_widget(table, area, &mut app.view.resource_groups.table_state);
+}
+
+fn render_members(f: &mut Frame, area: Rect, app: &mut App) {
+    let page = &app.view.resource_groups;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "Members ({}, Enter opens a monitored one)",
+            page.members.members.len()
+        ))
+        .border_style(Style::default().fg(Color::White));
+
+    if page.members.members.is_empty() {
+        render_empty(f, area, block, app, "Listing members...");
+        return;
+    }
+
+    let theme = &app.config.theme;
+    let rows: Vec<Row> = page
+        .members
+        .members
+        .iter()
+        .map(|member| {
+            let health = match (member.service(), page.health.get(&member.arn)) {
+                (Some(_), Some((label, Some(severity)))) => {
+                    Cell::from(format!("{}{label}", theme.marker(*severity)))
+                        .style(theme.style(*severity))
+                }
+                (Some(_), Some((label, None))) => {
+                    Cell::from(label.clone()).style(Style::default().fg(Color::Gray))
+                }
+                (Some(_), None) => Cell::from("not listed").style(Style::default().fg(Color::Gray)),
+                (None, _) => {
+                    Cell::from("not monitored").style(Style::default().fg(Color::DarkGray))
+                }
+            };
+            Row::new(vec![
+                Cell::from(member.kind()),
+                Cell::from(member.id().to_string()),
+                health,
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(28),
+            Constraint::Min(20),
+            Constraint::Length(24),
+        ],
+    )
+    .header(
+        Row::new(vec!["Kind", "Resource", "Health"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .highlight_style(Style::default().bg(Color::DarkGray))
+    .block(block);
+    f.render_stateful_widget(table, area, &mut app.view.resource_groups.table_state);
+}
+
+fn render_query_errors(f: &mut Frame, area: Rect, app: &App) {
+    let lines: Vec<Line> = app
+        .view
+        .resource_groups
+        .members
+        .query_errors
+        .iter()
+        .map(|error| Line::styled(error.clone(), Style::default().fg(Color::Red)))
+        .collect();
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Not resolved")
+            .border_style(Style::default().fg(Color::Red)),
+    );
+    f.render_widget(paragraph, area);
+}
@@ -0,0 +1,172 @@
+use super::details_pane::centered_rect;
+use super::metric_utils::format_value;
+use crate::aws::compute_optimizer::{humanize_code, utilization_unit};
+use crate::models::{App, UtilizationMetric};
+use crate::ui::keymap::{overlay_hints, KeyContext};
+use crate::ui::theme::Severity;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render Compute Optimizer's suggested instance classes for the selected RDS instance
+///
+/// The current class's utilization over the lookback period is shown above the
+/// options so each projection can be compared against the charts behind the panel.
+pub fn render_rightsizing_panel(f: &mut Frame, app: &App, area: Rect) {
+    let popup = centered_rect(80, 60, area);
+    let theme = &app.config.theme;
+    let title = format!(
+        "Right-sizing: {} ({})",
+        app.view.rightsizing.instance.as_deref().unwrap_or("-"),
+        overlay_hints(app, KeyContext::RightsizingPanel)
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    f.render_widget(Clear, popup);
+    let Some(recommendation) = &app.view.rightsizing.recommendation else {
+        let empty = Paragraph::new(
+            "No Compute Optimizer recommendation for this instance. The account must be \
+             opted in to Compute Optimizer, and instances are analysed after about 30 hours of metrics.",
+        )
+        .style(Style::default().fg(Color::Gray))
+        .wrap(Wrap { trim: true })
+        .block(block);
+        f.render_widget(empty, popup);
+        return;
+    };
+
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::White));
+    let finding_style = match recommendation.finding.as_str() {
+        "Optimized" => theme.style(Severity::Ok),
+        "Underprovisioned" => theme.style(Severity::Critical),
+        _ => theme.style(Severity::Warning),
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            label("Current: "),
+            Span::styled(
+                recommendation.current_class.clone(),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("  "),
+            label("Finding: "),
+            Span::styled(humanize_code(&recommendation.finding), finding_style),
+        ]),
+        Line::from(vec![
+            label("Why: "),
+            Span::styled(
+                if recommendation.reasons.is_empty() {
+                    "-".to_string()
+                } else {
+                    recommendation
+                        .reasons
+                        .iter()
+                        .map(|reason| humanize_code(reason))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                },
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                format!(
+                    "Last {} days: ",
+                    recommendation.lookback_days.round() as u64
+                ),
+                Style::default().fg(Color::White),
+            ),
+            Span::styled(
+                format_utilization(&recommendation.utilization),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]),
+        Line::from(""),
+    ];
+
+    if recommendation.options.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No other instance class suggested",
+            Style::default().fg(Color::Gray),
+        )));
+    }
+    for option in &recommendation.options {
+        let savings = match &option.monthly_savings {
+            Some((amount, currency)) if *amount > 0.0 => {
+                format!("saves {amount:.2} {currency}/month")
+            }
+            _ => "no savings".to_string(),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("#{} {}", option.rank, option.instance_class),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("  {savings}"), theme.style(Severity::Ok)),
+            Span::styled(
+                format!(
+                    "  performance risk {}",
+                    performance_risk_label(option.performance_risk)
+                ),
+                Style::default().fg(Color::Gray),
+            ),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("   projected: ", Style::default().fg(Color::White)),
+            Span::styled(
+                format_utilization(&option.projected),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]));
+    }
+
+    let panel = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(block);
+    f.render_widget(panel, popup);
+}
+
+fn format_utilization(metrics: &[UtilizationMetric]) -> String {
+    if metrics.is_empty() {
+        return "-".to_string();
+    }
+    metrics
+        .iter()
+        .map(|metric| {
+            let statistic = match metric.statistic.as_str() {
+                "Maximum" => "max",
+                "Average" => "avg",
+                other => other,
+            };
+            format!(
+                "{} {statistic} {}",
+                metric.name,
+                format_value(metric.value, utilization_unit(&metric.name))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" • ")
+}
+
+/// Compute Optimizer rates the risk of a class not meeting the workload from 0 to 4
+fn performance_risk_label(risk: f64) -> &'static str {
+    match risk {
+        r if r < 1.0 => "very low",
+        r if r < 2.0 => "low",
+        r if r < 3.0 => "medium",
+        r if r < 4.0 => "high",
+        _ => "very high",
+    }
+}
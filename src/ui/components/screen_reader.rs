@@ -0,0 +1,76 @@
+use super::footer::THROTTLING_BANNER;
+use crate::aws::limiter;
+use crate::models::App;
+use crate::ui::accessibility::page_summary;
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Any page as plain lines of text: no borders, charts or color-only state
+///
+/// The selected line is prefixed with "> " so it can be found without
+/// highlighting. The last two rows hold the latest announcement and the keys.
+pub fn render_screen_reader(f: &mut Frame, app: &mut App) {
+    let mut summary = page_summary(app);
+    if app.tabs.len() > 1 {
+        summary.title = format!(
+            "Tab {} of {}: {}",
+            app.active_tab + 1,
+            app.tabs.len(),
+            summary.title
+        );
+    }
+    app.announcer.observe(&summary);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),    // Page text
+            Constraint::Length(1), // Announcements
+            Constraint::Length(1), // Controls
+        ])
+        .split(f.area());
+
+    let mut lines = vec![Line::styled(
+        summary.title.clone(),
+        Style::default().add_modifier(Modifier::BOLD),
+    )];
+    if let Some(error) = &summary.error {
+        lines.push(Line::from(format!("Error: {error}")));
+    } else if summary.busy {
+        lines.push(Line::from("Loading"));
+    } else if summary.items.is_empty() {
+        lines.push(Line::from("Nothing to show"));
+    } else {
+        for (i, item) in summary.items.iter().enumerate() {
+            let prefix = if summary.selected == Some(i) {
+                "> "
+            } else {
+                "  "
+            };
+            lines.push(Line::from(format!("{prefix}{}", item.line())));
+        }
+    }
+
+    // Keep the selected line on screen; the title is line 0
+    let selected_line = summary.selected.map(|i| i + 1).unwrap_or(0) as u16;
+    let scroll = selected_line.saturating_sub(chunks[0].height.saturating_sub(1));
+    f.render_widget(Paragraph::new(lines).scroll((scroll, 0)), chunks[0]);
+
+    let status = app
+        .status_message
+        .clone()
+        .or_else(|| app.announcer.announcement.clone())
+        .unwrap_or_default();
+    let status = if limiter::cloudwatch().cooling_down() {
+        format!("{THROTTLING_BANNER}. {status}")
+    } else {
+        status
+    };
+    f.render_widget(Paragraph::new(format!("Status: {status}")), chunks[1]);
+    f.render_widget(Paragraph::new(summary.controls), chunks[2]);
+}
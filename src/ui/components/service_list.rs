@@ -1,3 +1,4 @@
+use super::footer::footer_line;
 use crate::models::App;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -17,19 +18,27 @@ pub fn render_service_list(f: &mut Frame, app: &mut App) {
         ])
         .split(f.area());
 
-    render_header(f, chunks[0]);
+    render_header(f, chunks[0], app);
     render_services(f, chunks[1], app);
-    render_controls(f, chunks[2]);
+    render_controls(f, chunks[2], app);
 }
 
-fn render_header(f: &mut Frame, area: Rect) {
-    let header = Paragraph::new("AWS CloudWatch TUI - Service Selection")
-        .style(Style::default().fg(Color::White))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan)),
-        );
+fn render_header(f: &mut Frame, area: Rect, app: &App) {
+    let mut line = vec![Span::styled(
+        "AWS CloudWatch TUI - Service Selection",
+        Style::default().fg(Color::White),
+    )];
+    if let Some(account) = &app.active_account {
+        line.push(Span::styled(
+            format!("  [account: {account}]"),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    let header = Paragraph::new(Line::from(line)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
     f.render_widget(header, area);
 }
 
@@ -60,11 +69,16 @@ fn render_services(f: &mut Frame, area: Rect, app: &mut App) {
         )
         .highlight_symbol("");
 
-    f.render_stateful_widget(services_list, area, &mut app.service_list_state);
+    f.render_stateful_widget(services_list, area, &mut app.view.service_list_state);
 }
 
-fn render_controls(f: &mut Frame, area: Rect) {
-    let controls = Paragraph::new("↑/↓: Navigate • Enter: Select Service • q: Quit")
-        .style(Style::default().fg(Color::Gray));
-    f.render_widget(controls, area);
+fn render_controls(f: &mut Frame, area: Rect, app: &App) {
+    let mut line = footer_line(app);
+    if let Some(release) = &app.available_update {
+        line.push_span(Span::styled(
+            format!("  v{} available", release.version),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    f.render_widget(Paragraph::new(line), area);
 }
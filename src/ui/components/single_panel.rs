@@ -0,0 +1,120 @@
+use super::metric_utils::format_value;
+use crate::panel::{PanelData, PanelState, PanelWidget};
+use crate::ui::theme::{Severity, Theme};
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{List, ListItem, Paragraph, Sparkline},
+    Frame,
+};
+
+/// Render the one widget of `--panel` mode: a title line and the widget, without borders
+pub fn render_single_panel(f: &mut Frame, state: &PanelState, theme: &Theme) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(f.area());
+
+    let mut title = vec![Span::styled(
+        widget_title(state),
+        Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    )];
+    if let Some(error) = &state.error {
+        title.push(Span::styled(
+            format!("  {}{error}", theme.marker(Severity::Critical)),
+            theme.style(Severity::Critical),
+        ));
+    }
+    f.render_widget(Paragraph::new(Line::from(title)), chunks[0]);
+
+    match &state.data {
+        None => f.render_widget(
+            Paragraph::new("Loading...").style(Style::default().fg(Color::Gray)),
+            chunks[1],
+        ),
+        Some(PanelData::Series { values, .. }) => {
+            // Scaled to the range shown so small variations stay visible
+            let max = values.iter().copied().fold(0.0_f64, f64::max);
+            let scale = if max > 0.0 { 100.0 / max } else { 0.0 };
+            let data: Vec<u64> = values
+                .iter()
+                .map(|value| (value.max(0.0) * scale).round() as u64)
+                .collect();
+            let sparkline = Sparkline::default()
+                .data(&data)
+                .max(100)
+                .style(Style::default().fg(Color::Cyan));
+            f.render_widget(sparkline, chunks[1]);
+        }
+        Some(PanelData::Alarms(alarms)) => {
+            let items: Vec<ListItem> = alarms
+                .iter()
+                .map(|alarm| {
+                    let severity = match alarm.state.as_str() {
+                        "ALARM" => Some(Severity::Critical),
+                        "INSUFFICIENT_DATA" => Some(Severity::Warning),
+                        _ => None,
+                    };
+                    let state_style = severity
+                        .map_or(theme.style(Severity::Ok), |severity| theme.style(severity));
+                    let resource = alarm
+                        .dimensions
+                        .iter()
+                        .map(|(_, value)| value.as_str())
+                        .collect::<Vec<_>>()
+                        .join(",");
+                    ListItem::new(Line::from(vec![
+                        Span::styled(
+                            format!(
+                                "{}{:<17} ",
+                                severity.map_or("", |severity| theme.marker(severity)),
+                                alarm.state
+                            ),
+                            state_style,
+                        ),
+                        Span::styled(alarm.name.clone(), Style::default().fg(Color::White)),
+                        Span::styled(
+                            format!("  {} {resource}", alarm.metric_name),
+                            Style::default().fg(Color::Gray),
+                        ),
+                        Span::styled(
+                            if alarm.actions_enabled { "" } else { "  muted" },
+                            theme.style(Severity::Warning),
+                        ),
+                    ]))
+                })
+                .collect();
+            f.render_widget(List::new(items), chunks[1]);
+        }
+    }
+}
+
+fn widget_title(state: &PanelState) -> String {
+    match (&state.widget, &state.data) {
+        (
+            PanelWidget::Sparkline {
+                resource,
+                metric,
+                range,
+                ..
+            },
+            data,
+        ) => {
+            let latest = match data {
+                Some(PanelData::Series { values, unit }) => values
+                    .last()
+                    .map_or_else(|| "no data".to_string(), |value| format_value(*value, unit)),
+                _ => "-".to_string(),
+            };
+            format!("{resource} {metric} ({range}): {latest}")
+        }
+        (PanelWidget::Alarms, Some(PanelData::Alarms(alarms))) => {
+            let firing = alarms.iter().filter(|alarm| alarm.is_firing()).count();
+            format!("Alarms: {firing} firing of {}", alarms.len())
+        }
+        (PanelWidget::Alarms, _) => "Alarms".to_string(),
+    }
+}
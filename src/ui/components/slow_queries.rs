@@ -0,0 +1,145 @@
+use super::footer::render_footer;
+use super::metric_utils::get_metric_severity;
+use crate::models::App;
+use crate::utils::formatting::{locale, truncate_to_width};
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+/// Statement column is truncated to this many characters in the table
+const STATEMENT_WIDTH: usize = 120;
+
+/// Recent slow queries of an RDS instance with the CPU utilization at the time they ran
+pub fn render_slow_queries(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header - log group and sort order
+            Constraint::Min(0),    // Slow query table
+            Constraint::Length(5), // Full statement of the selected row
+            Constraint::Length(1), // Controls at bottom
+        ])
+        .split(f.area());
+
+    render_header(f, chunks[0], app);
+    render_table(f, chunks[1], app);
+    render_statement(f, chunks[2], app);
+
+    render_footer(f, chunks[3], app);
+}
+
+fn render_header(f: &mut Frame, area: Rect, app: &App) {
+    let instance = app
+        .get_selected_rds_instance_id()
+        .unwrap_or_else(|| "-".to_string());
+    let line = Line::from(vec![
+        Span::styled(
+            app.view.slow_query.log.clone().unwrap_or_default(),
+            Style::default().fg(Color::Gray),
+        ),
+        Span::raw("  "),
+        Span::styled(
+            format!(
+                "{} queries, sorted by {}",
+                app.view.slow_query.queries.len(),
+                app.view.slow_query.sort.label()
+            ),
+            Style::default().fg(Color::White),
+        ),
+    ]);
+    let header = Paragraph::new(line).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Slow Queries: {instance}"))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(header, area);
+}
+
+fn render_table(f: &mut Frame, area: Rect, app: &mut App) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Slow queries (CPU at the time they ran)")
+        .border_style(Style::default().fg(Color::White));
+
+    if let Some(notice) = &app.view.slow_query.notice {
+        let empty = Paragraph::new(notice.as_str())
+            .style(Style::default().fg(Color::Gray))
+            .wrap(Wrap { trim: true })
+            .block(block);
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let theme = &app.config.theme;
+    let rows: Vec<Row> = app
+        .view
+        .slow_query
+        .queries
+        .iter()
+        .map(|query| {
+            let cpu = app.cpu_during(query);
+            let cpu_cell = match cpu {
+                Some(cpu) => {
+                    let severity =
+                        get_metric_severity(&app.config.thresholds, "CPU Utilization", cpu);
+                    let style = severity.map_or(Style::default(), |severity| theme.style(severity));
+                    let marker = severity.map_or("", |severity| theme.marker(severity));
+                    Cell::from(format!("{marker}{cpu:.0}%")).style(style)
+                }
+                None => Cell::from("-"),
+            };
+            Row::new(vec![
+                Cell::from(locale().time(&DateTime::<Local>::from(query.timestamp), true)),
+                Cell::from(format!("{:.2} s", query.duration_secs)),
+                Cell::from(
+                    query
+                        .rows_examined
+                        .map_or("-".to_string(), |rows| rows.to_string()),
+                ),
+                cpu_cell,
+                Cell::from(truncate_to_width(&query.statement, STATEMENT_WIDTH)),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(11),
+            Constraint::Length(10),
+            Constraint::Length(13),
+            Constraint::Length(6),
+            Constraint::Min(20),
+        ],
+    )
+    .header(
+        Row::new(vec![
+            "Time",
+            "Duration",
+            "Rows examined",
+            "CPU",
+            "Statement",
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .highlight_style(Style::default().bg(Color::DarkGray))
+    .block(block);
+    f.render_stateful_widget(table, area, &mut app.view.slow_query.table_state);
+}
+
+fn render_statement(f: &mut Frame, area: Rect, app: &App) {
+    let statement = app
+        .get_selected_slow_query()
+        .map(|query| query.statement.clone())
+        .unwrap_or_default();
+    let paragraph = Paragraph::new(statement)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title("Statement"));
+    f.render_widget(paragraph, area);
+}
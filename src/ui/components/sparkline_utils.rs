@@ -4,7 +4,7 @@
 /// This leverages the existing sparkline functionality for consistency
 pub fn generate_inline_sparkline(history: &[f64], width: usize) -> String {
     if history.is_empty() || width == 0 {
-        return "⠀".repeat(width.max(8));
+        return "⠀".repeat(width);
     }
 
     if history.len() == 1 {
@@ -25,8 +25,8 @@ pub fn generate_inline_sparkline(history: &[f64], width: usize) -> String {
         return generate_flat_sparkline(width);
     }
 
-    // Sample data to fit the available width
-    let sampled_data = sample_sparkline_data(history, width);
+    // Average the data into one bucket per character of the current width
+    let sampled_data = downsample_buckets(history, width);
 
     // Normalize data to 0-1 range for Braille character selection
     let normalized_data: Vec<f64> = sampled_data
@@ -103,20 +103,40 @@ fn calculate_sparkline_y_bounds(history: &[f64]) -> (f64, f64) {
     }
 }
 
-/// Sample data points to fit the target width for sparklines
-fn sample_sparkline_data(history: &[f64], target_width: usize) -> Vec<f64> {
-    if history.len() <= target_width {
+/// Average consecutive values into `buckets` equal-sized buckets
+///
+/// Every data point contributes to exactly one bucket, so narrowing the
+/// terminal smooths the series rather than skipping points.
+pub fn downsample_buckets(history: &[f64], buckets: usize) -> Vec<f64> {
+    if buckets == 0 || history.len() <= buckets {
         return history.to_owned();
     }
 
-    let mut sampled = Vec::with_capacity(target_width);
-    for i in 0..target_width {
-        let idx = if target_width == 1 {
-            0
-        } else {
-            (i * (history.len() - 1)) / (target_width - 1)
-        };
-        sampled.push(history[idx]);
+    (0..buckets)
+        .map(|i| {
+            let start = i * history.len() / buckets;
+            let end = ((i + 1) * history.len() / buckets).max(start + 1);
+            let bucket = &history[start..end];
+            bucket.iter().sum::<f64>() / bucket.len() as f64
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_downsample_buckets_averages_every_point() {
+        let history: Vec<f64> = (1..=10).map(f64::from).collect();
+        assert_eq!(
+            downsample_buckets(&history, 5),
+            vec![1.5, 3.5, 5.5, 7.5, 9.5]
+        );
+        assert_eq!(downsample_buckets(&history, 20), history);
+
+        let sparkline = generate_inline_sparkline(&history, 4);
+        assert_eq!(sparkline.chars().count(), 4);
+        assert_eq!(generate_inline_sparkline(&history, 0), "");
     }
-    sampled
 }
@@ -0,0 +1,143 @@
+use super::details_pane::centered_rect;
+use crate::aws::cloudformation::{stack_status_severity, DriftState};
+use crate::models::App;
+use crate::ui::keymap::{overlay_hints, KeyContext};
+use crate::ui::theme::Theme;
+use crate::utils::formatting::locale;
+use chrono::{Local, TimeZone};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render the CloudFormation stack of the resource being viewed as an overlay
+///
+/// Drift is what CloudFormation found the last time drift detection ran, so
+/// it is shown with when that was.
+pub fn render_stack_panel(f: &mut Frame, app: &App, area: Rect) {
+    let popup = centered_rect(70, 45, area);
+    let theme = &app.config.theme;
+    let stack = &app.view.stack;
+    let title = format!(
+        "CloudFormation: {} ({})",
+        stack.resource.as_deref().unwrap_or("-"),
+        overlay_hints(app, KeyContext::StackPanel)
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    f.render_widget(Clear, popup);
+    let Some(membership) = &stack.membership else {
+        let empty = Paragraph::new(
+            "This resource is not part of a CloudFormation stack. Resources managed by \
+             Terraform or created by hand cannot be told apart.",
+        )
+        .style(Style::default().fg(Color::Gray))
+        .wrap(Wrap { trim: true })
+        .block(block);
+        f.render_widget(empty, popup);
+        return;
+    };
+
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::White));
+    let stack_severity = stack_status_severity(&membership.stack_status);
+    let mut lines = vec![
+        Line::from(vec![
+            label("Stack: "),
+            Span::styled(
+                membership.stack_name.clone(),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("  "),
+            Span::styled(
+                format!(
+                    "{}{}",
+                    theme.marker(stack_severity),
+                    membership.stack_status
+                ),
+                theme.style(stack_severity),
+            ),
+        ]),
+        drift_line(theme, "Stack drift: ", membership.stack_drift.as_ref()),
+    ];
+    if let Some(reason) = &membership.stack_status_reason {
+        lines.push(Line::from(Span::styled(
+            reason.clone(),
+            Style::default().fg(Color::Gray),
+        )));
+    }
+    lines.extend([
+        Line::from(""),
+        Line::from(vec![
+            label("Resource: "),
+            Span::styled(
+                membership.logical_id.clone(),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(
+                format!(" ({})  ", membership.resource_type),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(
+                membership.resource_status.clone(),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        drift_line(
+            theme,
+            "Resource drift: ",
+            membership.resource_drift.as_ref(),
+        ),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Run drift detection on the stack in CloudFormation to check again.",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ]);
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true }).block(block);
+    f.render_widget(paragraph, popup);
+}
+
+/// Drift status with when it was last checked
+fn drift_line<'a>(theme: &Theme, name: &'static str, drift: Option<&DriftState>) -> Line<'a> {
+    let label = Span::styled(name, Style::default().fg(Color::White));
+    let Some(drift) = drift.filter(|drift| drift.last_checked.is_some()) else {
+        return Line::from(vec![
+            label,
+            Span::styled("never checked", Style::default().fg(Color::Gray)),
+        ]);
+    };
+    let style = drift
+        .severity()
+        .map_or(Style::default().fg(Color::Gray), |severity| {
+            theme.style(severity)
+        });
+    let marker = drift
+        .severity()
+        .map_or("", |severity| theme.marker(severity));
+    let checked = drift
+        .last_checked
+        .and_then(|secs| Local.timestamp_opt(secs, 0).single())
+        .map(|time| {
+            let locale = locale();
+            format!(
+                " (checked {} {})",
+                locale.short_date(&time),
+                locale.time(&time, false)
+            )
+        })
+        .unwrap_or_default();
+    Line::from(vec![
+        label,
+        Span::styled(format!("{marker}{}", drift.status), style),
+        Span::styled(checked, Style::default().fg(Color::Gray)),
+    ])
+}
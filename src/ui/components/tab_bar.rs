@@ -0,0 +1,42 @@
+use crate::utils::formatting::truncate_to_width;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Widest tab label shown, in terminal cells, before it is cut short
+const MAX_LABEL: usize = 20;
+
+/// Numbered tab labels in the top-right corner, over the page's top border
+pub fn render_tab_bar(f: &mut Frame, labels: &[String], active: usize, area: Rect) {
+    let spans: Vec<Span> = labels
+        .iter()
+        .enumerate()
+        .map(|(index, label)| {
+            let label = truncate_to_width(label, MAX_LABEL);
+            let style = if index == active {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray).bg(Color::DarkGray)
+            };
+            Span::styled(format!(" {}:{label} ", index + 1), style)
+        })
+        .collect();
+    let line = Line::from(spans);
+
+    // Leave the corner of the border below visible
+    let width = (line.width() as u16).min(area.width.saturating_sub(2));
+    let bar = Rect {
+        x: area.x + area.width.saturating_sub(width + 1),
+        y: area.y,
+        width,
+        height: 1,
+    };
+    f.render_widget(Paragraph::new(line), bar);
+}
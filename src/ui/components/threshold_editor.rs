@@ -0,0 +1,156 @@
+use super::details_pane::centered_rect;
+use super::metric_utils::{format_value, get_metric_unit};
+use crate::thresholds::{EditorField, EditorRow, ThresholdEditor};
+use crate::ui::theme::{Severity, Theme};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Wrap},
+    Frame,
+};
+
+/// Popup for editing the RDS metric thresholds
+///
+/// Each metric shows how many instances would be red and yellow with the
+/// values as typed; the selected metric lists them below the table.
+pub fn render_threshold_editor(
+    f: &mut Frame,
+    editor: &ThresholdEditor,
+    theme: &Theme,
+    hints: &str,
+    area: Rect,
+) {
+    let popup = centered_rect(80, 80, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("RDS Thresholds ({hints})"))
+        .border_style(Style::default().fg(Color::Cyan));
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(4)])
+        .split(block.inner(popup));
+    f.render_widget(Clear, popup);
+    f.render_widget(block, popup);
+
+    let rows: Vec<Row> = editor
+        .rows
+        .iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let focused = (index == editor.selected).then_some(editor.field);
+            Row::new(vec![
+                Cell::from(row.name()),
+                value_cell(&row.warning, focused == Some(EditorField::Warning)),
+                value_cell(&row.critical, focused == Some(EditorField::Critical)),
+                Cell::from(direction(row)),
+                preview_cell(row, theme),
+            ])
+        })
+        .collect();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(32),
+            Constraint::Length(14),
+            Constraint::Length(14),
+            Constraint::Length(8),
+            Constraint::Min(16),
+        ],
+    )
+    .header(
+        Row::new(vec!["Metric", "Warning", "Critical", "Worse", "Preview"])
+            .style(Style::default().fg(Color::Gray)),
+    )
+    .highlight_style(Style::default().bg(Color::DarkGray));
+    let mut state = TableState::default().with_selected(Some(editor.selected));
+    f.render_stateful_widget(table, chunks[0], &mut state);
+
+    let details = Paragraph::new(preview_lines(editor.selected_row(), theme))
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::TOP));
+    f.render_widget(details, chunks[1]);
+}
+
+fn value_cell(text: &str, focused: bool) -> Cell<'static> {
+    if focused {
+        Cell::from(format!("{text}_")).style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Cell::from(text.to_string())
+    }
+}
+
+fn direction(row: &EditorRow) -> &'static str {
+    match row.threshold() {
+        Ok(Some(threshold)) if threshold.lower_is_worse() => "lower",
+        Ok(Some(_)) => "higher",
+        _ => "",
+    }
+}
+
+fn preview_cell(row: &EditorRow, theme: &Theme) -> Cell<'static> {
+    match (row.threshold(), row.preview()) {
+        (Err(_), _) => Cell::from("invalid").style(theme.style(Severity::Critical)),
+        (Ok(None), _) => Cell::from("-").style(Style::default().fg(Color::Gray)),
+        (Ok(Some(_)), None) => Cell::from("loading").style(Style::default().fg(Color::Gray)),
+        (Ok(Some(_)), Some(preview)) => Cell::from(Line::from(vec![
+            Span::styled(
+                format!("{} red", preview.red.len()),
+                theme.style(Severity::Critical),
+            ),
+            Span::raw(", "),
+            Span::styled(
+                format!("{} yellow", preview.yellow.len()),
+                theme.style(Severity::Warning),
+            ),
+        ])),
+    }
+}
+
+/// Why the selected metric's values are rejected, or which instances they flag
+fn preview_lines(row: &EditorRow, theme: &Theme) -> Vec<Line<'static>> {
+    if let Err(e) = row.threshold() {
+        return vec![Line::styled(
+            format!("{}: {e}", row.name()),
+            theme.style(Severity::Critical),
+        )];
+    }
+    let Some(preview) = row.preview() else {
+        return vec![Line::styled(
+            format!("{}: no threshold", row.name()),
+            Style::default().fg(Color::Gray),
+        )];
+    };
+    if row.latest.as_ref().is_some_and(Vec::is_empty) {
+        return vec![Line::styled(
+            format!("{}: no recent data from any instance", row.name()),
+            Style::default().fg(Color::Gray),
+        )];
+    }
+
+    let unit = get_metric_unit(&row.metric);
+    let list = |instances: Vec<&(String, f64)>| {
+        if instances.is_empty() {
+            return "none".to_string();
+        }
+        instances
+            .iter()
+            .map(|(identifier, value)| format!("{identifier} ({})", format_value(*value, unit)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    vec![
+        Line::from(vec![
+            Span::styled("Red: ", theme.style(Severity::Critical)),
+            Span::raw(list(preview.red)),
+        ]),
+        Line::from(vec![
+            Span::styled("Yellow: ", theme.style(Severity::Warning)),
+            Span::raw(list(preview.yellow)),
+        ]),
+    ]
+}
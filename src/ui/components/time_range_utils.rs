@@ -1,4 +1,6 @@
 use super::display_utils::get_selected_time_range_display;
+use crate::aws::metrics::types::MetricResolution;
+use crate::aws::time_range::{calculate_period_seconds, format_period};
 use crate::models::App;
 use ratatui::{
     layout::Rect,
@@ -63,14 +65,21 @@ pub fn get_time_range_title(app: &App, is_focused: bool) -> String {
         .map(|(label, _, _, _)| *label)
         .unwrap_or("Unknown");
 
+    // Show the active period, and flag high-resolution data when detected
+    let period = format_period(calculate_period_seconds(&app.view.time_range));
+    let resolution = match app.view.metric_resolution {
+        MetricResolution::High => format!(" {}", app.view.metric_resolution.label()),
+        MetricResolution::Standard => String::new(),
+    };
+
     if is_focused {
         format!(
-            "Time [F] ({})",
+            "Time [F] ({} @{period}{resolution})",
             get_selected_time_range_display(selected_time_period)
         )
     } else {
         format!(
-            "Time ({})",
+            "Time ({} @{period}{resolution})",
             get_selected_time_range_display(selected_time_period)
         )
     }
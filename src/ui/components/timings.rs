@@ -0,0 +1,169 @@
+use super::footer::render_footer;
+use crate::aws::limiter::{self, Throttle};
+use crate::models::App;
+use crate::utils::formatting::format_number;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Paragraph, Row, Table},
+    Frame,
+};
+use std::time::Duration;
+
+/// Latest page loads listed under the per-page figures
+const RECENT_LOADS: usize = 12;
+
+/// Latest throttling responses listed at the bottom, when there are any
+const RECENT_THROTTLES: usize = 5;
+
+/// How long page loads took, per page and the latest ones, from input to first frame
+///
+/// CloudWatch calls refused as throttled are listed below them, as they
+/// explain slow loads that nothing on this side can speed up.
+pub fn render_timings(f: &mut Frame, app: &mut App) {
+    let throttles = limiter::cloudwatch().throttles();
+    let throttle_rows = throttles.len().min(RECENT_THROTTLES);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(50), // Per page
+            Constraint::Min(0),         // Latest loads
+            Constraint::Length(if throttle_rows == 0 {
+                0
+            } else {
+                throttle_rows as u16 + 3
+            }), // Throttled calls
+            Constraint::Length(1),      // Controls at bottom
+        ])
+        .split(f.area());
+
+    render_per_page(f, chunks[0], app);
+    render_recent(f, chunks[1], app);
+    render_throttles(f, chunks[2], &throttles);
+    render_footer(f, chunks[3], app);
+}
+
+fn ms(elapsed: Duration) -> String {
+    format!("{} ms", format_number(elapsed.as_secs_f64() * 1000.0, 1))
+}
+
+fn header(cells: [&'static str; 6]) -> Row<'static> {
+    Row::new(cells).style(Style::default().add_modifier(Modifier::BOLD))
+}
+
+fn render_per_page(f: &mut Frame, area: Rect, app: &App) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Page Loads (medians: input → data fetched → first frame)")
+        .border_style(Style::default().fg(Color::Cyan));
+    let stats = app.timings.per_page();
+    if stats.is_empty() {
+        let empty = Paragraph::new("No page loads timed yet; open a page and come back")
+            .style(Style::default().fg(Color::Gray))
+            .block(block);
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let rows: Vec<Row> = stats
+        .iter()
+        .map(|page| {
+            Row::new(vec![
+                page.page.clone(),
+                page.loads.to_string(),
+                ms(page.median_fetch),
+                ms(page.median_render),
+                ms(page.median_total),
+                ms(page.slowest_total),
+            ])
+        })
+        .collect();
+    let table = Table::new(rows, widths())
+        .header(header([
+            "Page", "Loads", "Fetch", "Render", "Total", "Slowest",
+        ]))
+        .block(block);
+    f.render_widget(table, area);
+}
+
+fn render_recent(f: &mut Frame, area: Rect, app: &App) {
+    let rows: Vec<Row> = app
+        .timings
+        .loads()
+        .iter()
+        .rev()
+        .take(RECENT_LOADS)
+        .map(|load| {
+            // Time spent neither fetching nor drawing: other work between the two
+            let other = load.total.saturating_sub(load.fetch + load.render);
+            Row::new(vec![
+                load.page.clone(),
+                String::new(),
+                ms(load.fetch),
+                ms(load.render),
+                ms(load.total),
+                ms(other),
+            ])
+        })
+        .collect();
+    let table = Table::new(rows, widths())
+        .header(header(["Page", "", "Fetch", "Render", "Total", "Other"]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Latest")
+                .border_style(Style::default().fg(Color::White)),
+        );
+    f.render_widget(table, area);
+}
+
+fn render_throttles(f: &mut Frame, area: Rect, throttles: &[Throttle]) {
+    if throttles.is_empty() {
+        return;
+    }
+    let rows: Vec<Row> = throttles
+        .iter()
+        .rev()
+        .take(RECENT_THROTTLES)
+        .map(|throttle| {
+            Row::new(vec![
+                throttle.namespace.clone(),
+                throttle.code.clone(),
+                format!("{}s ago", throttle.at.elapsed().as_secs()),
+            ])
+        })
+        .collect();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(18),
+            Constraint::Length(24),
+            Constraint::Length(12),
+        ],
+    )
+    .header(
+        Row::new(["Namespace", "Error", "When"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "Throttled CloudWatch calls (refreshes slowed for {} min after each)",
+                limiter::COOL_DOWN.as_secs() / 60
+            ))
+            .border_style(Style::default().fg(Color::Yellow)),
+    );
+    f.render_widget(table, area);
+}
+
+fn widths() -> [Constraint; 6] {
+    [
+        Constraint::Min(18),
+        Constraint::Length(6),
+        Constraint::Length(12),
+        Constraint::Length(12),
+        Constraint::Length(12),
+        Constraint::Length(12),
+    ]
+}
@@ -1,4 +1,4 @@
-use super::display_utils::truncate_string;
+use crate::utils::formatting::{display_width, pad_to_width};
 use ratatui::{
     style::{Color, Modifier, Style},
     text::{Line, Span},
@@ -19,15 +19,13 @@ pub struct MetricBlockParams {
 /// Creates a distinct visual block for each metric item with proper spacing and styling
 pub fn create_metric_block(params: MetricBlockParams) -> Vec<Line<'static>> {
     let content = format!(
-        " {:<name_width$}  {:<sparkline_width$}  {:>12} ",
-        truncate_string(&params.metric_name, params.name_width),
-        params.sparkline,
+        " {}  {}  {:>12} ",
+        pad_to_width(&params.metric_name, params.name_width),
+        pad_to_width(&params.sparkline, params.sparkline_width),
         params.formatted_value,
-        name_width = params.name_width,
-        sparkline_width = params.sparkline_width,
     );
 
-    let total_width = content.chars().count();
+    let total_width = display_width(&content);
 
     // Create the frame characters
     let top_border = format!("┌{}┐", "─".repeat(total_width));
@@ -57,11 +55,7 @@ fn create_selected_metric_block(
             Span::styled("│", Style::default().fg(Color::Yellow)),
             Span::styled(" ", Style::default().bg(Color::DarkGray)),
             Span::styled(
-                format!(
-                    "{:<width$}",
-                    truncate_string(&params.metric_name, params.name_width),
-                    width = params.name_width
-                ),
+                pad_to_width(&params.metric_name, params.name_width),
                 Style::default()
                     .fg(Color::Cyan)
                     .bg(Color::DarkGray)
@@ -69,11 +63,7 @@ fn create_selected_metric_block(
             ),
             Span::styled("  ", Style::default().bg(Color::DarkGray)),
             Span::styled(
-                format!(
-                    "{:<width$}",
-                    params.sparkline,
-                    width = params.sparkline_width
-                ),
+                pad_to_width(&params.sparkline, params.sparkline_width),
                 Style::default()
                     .fg(params.sparkline_color)
                     .bg(Color::DarkGray)
@@ -112,20 +102,12 @@ fn create_regular_metric_block(
             Span::styled("│", Style::default().fg(Color::Yellow)),
             Span::styled(" ", Style::default()),
             Span::styled(
-                format!(
-                    "{:<width$}",
-                    truncate_string(&params.metric_name, params.name_width),
-                    width = params.name_width
-                ),
+                pad_to_width(&params.metric_name, params.name_width),
                 Style::default().fg(Color::Cyan),
             ),
             Span::styled("  ", Style::default()),
             Span::styled(
-                format!(
-                    "{:<width$}",
-                    params.sparkline,
-                    width = params.sparkline_width
-                ),
+                pad_to_width(&params.sparkline, params.sparkline_width),
                 Style::default().fg(params.sparkline_color),
             ),
             Span::styled("  ", Style::default()),
@@ -0,0 +1,331 @@
+// Terminal graphics protocols
+//
+// Terminals that implement the kitty graphics protocol or DEC sixel can show
+// real raster charts instead of Braille: plotted locally from the loaded data,
+// or the PNG CloudWatch renders itself. Kitty-protocol terminals take PNG or
+// raw RGBA directly; for sixel the pixels are reduced to a 216-color palette
+// and sent as sixel bands, leaving transparent pixels untouched. Detection
+// only looks at the environment, so nothing is written to the terminal to
+// query it.
+
+use anyhow::{anyhow, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+/// Largest payload of one kitty graphics escape sequence
+const KITTY_CHUNK: usize = 4096;
+
+/// Cell size assumed when the terminal does not report its pixel size
+const DEFAULT_CELL_PX: (u32, u32) = (8, 16);
+
+/// `[charts] images` in the config file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChartImages {
+    Off,
+    #[default]
+    Auto,
+    Kitty,
+    Sixel,
+}
+
+/// `[charts] image_source`: who draws the raster charts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageSource {
+    #[default]
+    Local, // Plotted here from the loaded datapoints
+    CloudWatch, // Rendered by GetMetricWidgetImage
+}
+
+/// Image to draw into a block of cells
+#[derive(Debug, Clone, PartialEq)]
+pub enum RasterImage {
+    Png(Vec<u8>),
+    Rgba {
+        width: u32,
+        height: u32,
+        pixels: Vec<u8>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    Kitty,
+    Sixel,
+}
+
+impl GraphicsProtocol {
+    /// Protocol to draw chart images with, or None to keep the Braille charts
+    pub fn select(setting: ChartImages) -> Option<Self> {
+        match setting {
+            ChartImages::Off => None,
+            ChartImages::Kitty => Some(GraphicsProtocol::Kitty),
+            ChartImages::Sixel => Some(GraphicsProtocol::Sixel),
+            ChartImages::Auto => detect(|name| std::env::var(name).ok()),
+        }
+    }
+
+    /// Escape sequence drawing `image` over `cols` x `rows` cells from the cursor position
+    pub fn encode(self, image: &RasterImage, cols: u16, rows: u16) -> Result<String> {
+        match (self, image) {
+            (GraphicsProtocol::Kitty, RasterImage::Png(png)) => {
+                Ok(kitty_sequence("f=100", png, cols, rows))
+            }
+            (
+                GraphicsProtocol::Kitty,
+                RasterImage::Rgba {
+                    width,
+                    height,
+                    pixels,
+                },
+            ) => Ok(kitty_sequence(
+                &format!("f=32,s={width},v={height}"),
+                pixels,
+                cols,
+                rows,
+            )),
+            (GraphicsProtocol::Sixel, RasterImage::Png(png)) => {
+                let (width, height, rgba) = decode_png(png)?;
+                Ok(sixel_sequence(&rgba, width, height))
+            }
+            (
+                GraphicsProtocol::Sixel,
+                RasterImage::Rgba {
+                    width,
+                    height,
+                    pixels,
+                },
+            ) => Ok(sixel_sequence(pixels, *width as usize, *height as usize)),
+        }
+    }
+
+    /// Sequence removing images drawn earlier, where the protocol has one
+    pub fn delete_sequence(self) -> Option<&'static str> {
+        match self {
+            GraphicsProtocol::Kitty => Some("\x1b_Ga=d,d=A,q=2\x1b\\"),
+            // Sixel pixels live in the cells; redrawing the cells removes them
+            GraphicsProtocol::Sixel => None,
+        }
+    }
+}
+
+/// Protocol implied by the terminal's environment variables
+fn detect(var: impl Fn(&str) -> Option<String>) -> Option<GraphicsProtocol> {
+    // Inside tmux or screen the outer terminal is unknown and images are not passed through
+    if var("TMUX").is_some() || var("STY").is_some() {
+        return None;
+    }
+    let term = var("TERM").unwrap_or_default();
+    let program = var("TERM_PROGRAM").unwrap_or_default().to_lowercase();
+    if var("KITTY_WINDOW_ID").is_some()
+        || term == "xterm-kitty"
+        || term == "xterm-ghostty"
+        || matches!(program.as_str(), "wezterm" | "ghostty")
+    {
+        Some(GraphicsProtocol::Kitty)
+    } else if term.contains("sixel")
+        || term.starts_with("foot")
+        || term.starts_with("mlterm")
+        || program == "contour"
+    {
+        Some(GraphicsProtocol::Sixel)
+    } else {
+        None
+    }
+}
+
+/// Pixel size of one cell, from the terminal's reported window size
+pub fn cell_size_px() -> (u32, u32) {
+    crossterm::terminal::window_size()
+        .ok()
+        .filter(|size| size.width > 0 && size.columns > 0 && size.rows > 0)
+        .map(|size| {
+            (
+                u32::from(size.width / size.columns),
+                u32::from(size.height / size.rows),
+            )
+        })
+        .unwrap_or(DEFAULT_CELL_PX)
+}
+
+/// Kitty graphics transmission of `data` in the given `format` (keys such as `f=100`)
+fn kitty_sequence(format: &str, data: &[u8], cols: u16, rows: u16) -> String {
+    let payload = STANDARD.encode(data);
+    let chunks: Vec<&str> = payload
+        .as_bytes()
+        .chunks(KITTY_CHUNK)
+        .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default())
+        .collect();
+
+    let mut sequence = String::with_capacity(payload.len() + chunks.len() * 16);
+    for (index, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(index + 1 < chunks.len());
+        if index == 0 {
+            // Transmit and display, scaled to the cells, without moving the cursor or replying
+            let _ = write!(
+                sequence,
+                "\x1b_Ga=T,{format},c={cols},r={rows},C=1,q=2,m={more};{chunk}\x1b\\"
+            );
+        } else {
+            let _ = write!(sequence, "\x1b_Gm={more};{chunk}\x1b\\");
+        }
+    }
+    sequence
+}
+
+/// Width, height and RGBA pixels of a PNG
+fn decode_png(png: &[u8]) -> Result<(usize, usize, Vec<u8>)> {
+    let mut decoder = png::Decoder::new(png);
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder.read_info().context("Invalid chart image")?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut buffer)
+        .context("Invalid chart image")?;
+    let pixels = &buffer[..info.buffer_size()];
+
+    let rgba: Vec<u8> = match info.color_type {
+        png::ColorType::Rgba => pixels.to_vec(),
+        png::ColorType::Rgb => pixels
+            .chunks_exact(3)
+            .flat_map(|p| [p[0], p[1], p[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => pixels
+            .chunks_exact(2)
+            .flat_map(|p| [p[0], p[0], p[0], p[1]])
+            .collect(),
+        png::ColorType::Grayscale => pixels.iter().flat_map(|&g| [g, g, g, 255]).collect(),
+        other => return Err(anyhow!("Unsupported chart image color type {other:?}")),
+    };
+    Ok((info.width as usize, info.height as usize, rgba))
+}
+
+/// Index into the 6x6x6 color cube; None for transparent pixels
+fn palette_index(pixel: &[u8]) -> Option<u8> {
+    if pixel[3] < 128 {
+        return None;
+    }
+    let level = |c: u8| (u16::from(c) * 5 + 127) / 255;
+    Some((level(pixel[0]) * 36 + level(pixel[1]) * 6 + level(pixel[2])) as u8)
+}
+
+fn sixel_sequence(rgba: &[u8], width: usize, height: usize) -> String {
+    let indices: Vec<Option<u8>> = rgba.chunks_exact(4).map(palette_index).collect();
+
+    // Unset pixels stay transparent (P2=1); raster attributes, then only the
+    // palette entries the image uses (in percent)
+    let mut sequence = format!("\x1bP0;1q\"1;1;{width};{height}");
+    let used: BTreeSet<u8> = indices.iter().flatten().copied().collect();
+    for &color in &used {
+        let percent = |level: u8| u16::from(level) * 100 / 5;
+        let _ = write!(
+            sequence,
+            "#{color};2;{};{};{}",
+            percent(color / 36),
+            percent(color / 6 % 6),
+            percent(color % 6)
+        );
+    }
+
+    // Each band covers six pixel rows; every color in it is drawn as one pass
+    for band_top in (0..height).step_by(6) {
+        let rows = band_top..(band_top + 6).min(height);
+        let band_colors: BTreeSet<u8> = rows
+            .clone()
+            .flat_map(|y| {
+                indices[y * width..(y + 1) * width]
+                    .iter()
+                    .flatten()
+                    .copied()
+            })
+            .collect();
+
+        for (pass, &color) in band_colors.iter().enumerate() {
+            if pass > 0 {
+                sequence.push('$'); // Back to the start of the band
+            }
+            let _ = write!(sequence, "#{color}");
+            let mut run: Option<(u8, usize)> = None;
+            for x in 0..width {
+                let bits = rows.clone().fold(0u8, |bits, y| {
+                    if indices[y * width + x] == Some(color) {
+                        bits | 1 << (y - band_top)
+                    } else {
+                        bits
+                    }
+                });
+                match &mut run {
+                    Some((previous, count)) if *previous == bits => *count += 1,
+                    _ => {
+                        if let Some((previous, count)) = run {
+                            push_sixel_run(&mut sequence, previous, count);
+                        }
+                        run = Some((bits, 1));
+                    }
+                }
+            }
+            if let Some((bits, count)) = run {
+                push_sixel_run(&mut sequence, bits, count);
+            }
+        }
+        sequence.push('-'); // Next band
+    }
+    sequence.push_str("\x1b\\");
+    sequence
+}
+
+fn push_sixel_run(sequence: &mut String, bits: u8, count: usize) {
+    let symbol = char::from(63 + bits);
+    if count > 3 {
+        let _ = write!(sequence, "!{count}{symbol}");
+    } else {
+        sequence.extend(std::iter::repeat_n(symbol, count));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_protocol_detection_and_encoding() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(
+            detect(env(&[("TERM", "xterm-kitty")])),
+            Some(GraphicsProtocol::Kitty)
+        );
+        assert_eq!(
+            detect(env(&[("TERM", "foot"), ("TERM_PROGRAM", "")])),
+            Some(GraphicsProtocol::Sixel)
+        );
+        assert_eq!(
+            detect(env(&[("TERM", "xterm-kitty"), ("TMUX", "/tmp/tmux")])),
+            None
+        );
+        assert_eq!(detect(env(&[("TERM", "xterm-256color")])), None);
+
+        // Kitty payloads are split into chunks, all but the last flagged m=1
+        let kitty = kitty_sequence("f=100", &[0u8; 4000], 80, 20);
+        assert!(kitty.starts_with("\x1b_Ga=T,f=100,c=80,r=20,C=1,q=2,m=1;"));
+        assert_eq!(kitty.matches("\x1b_G").count(), 2);
+        assert!(kitty.contains("\x1b_Gm=0;"));
+
+        // A 5x2 image: a red row over a white row
+        let red = [255, 0, 0, 255];
+        let white = [255, 255, 255, 255];
+        let pixels: Vec<u8> = [red; 5].into_iter().chain([white; 5]).flatten().collect();
+        assert_eq!(
+            sixel_sequence(&pixels, 5, 2),
+            "\x1bP0;1q\"1;1;5;2#180;2;100;0;0#215;2;100;100;100#180!5@$#215!5A-\x1b\\"
+        );
+    }
+}
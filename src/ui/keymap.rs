@@ -0,0 +1,490 @@
+// Key bindings shown as hints at the bottom of each page
+//
+// Every key a page or overlay handles is listed here once, with the page it
+// belongs to and, where it only sometimes applies, when it does (e.g. "t:
+// Replicas" only for instances that have replicas). Footers and overlay
+// titles are built from this table, so a new binding added here shows up on
+// the screens it applies to.
+
+use crate::aws::logs::slow_query;
+use crate::models::{App, AppState, AwsService};
+use KeyContext::*;
+
+/// Where a binding applies: a page, an overlay, or everywhere
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyContext {
+    Global,
+    ServiceList,
+    InstanceList,
+    RdsMetrics,
+    QueueMetrics,
+    CanaryMetrics,
+    RuleMetrics,
+    BatchMetrics,
+    FunctionMetrics,
+    Chart,
+    ReplicaTopology,
+    BlueGreen,
+    GlobalDatabase,
+    SlowQueries,
+    LogTail,
+    Parameters,
+    Fleet,
+    ResourceGroups,
+    CompositeAlarms,
+    Alarms,
+    Timings,
+    ApiCalls,
+    Internals,
+    DetailsPane,
+    ActivityPanel,
+    RightsizingPanel,
+    StackPanel,
+    QueueAccessPanel,
+    ConsumersPanel,
+    ComparePanel,
+    BookmarksPanel,
+    ProfilesPanel,
+    RegionsPanel,
+    JobsPanel,
+    AlarmForm,
+    ThresholdEditor,
+    PinPrompt,
+    ExportPrompt,
+    CommandLine,
+    Confirmation,
+}
+
+pub struct Binding {
+    pub context: KeyContext,
+    pub keys: &'static str,
+    pub action: &'static str,
+    pub when: Option<fn(&App) -> bool>, // None: always available
+}
+
+const fn key(context: KeyContext, keys: &'static str, action: &'static str) -> Binding {
+    Binding {
+        context,
+        keys,
+        action,
+        when: None,
+    }
+}
+
+const fn key_when(
+    context: KeyContext,
+    keys: &'static str,
+    action: &'static str,
+    when: fn(&App) -> bool,
+) -> Binding {
+    Binding {
+        context,
+        keys,
+        action,
+        when: Some(when),
+    }
+}
+
+/// In the order they are shown
+pub const BINDINGS: &[Binding] = &[
+    key(ServiceList, "↑/↓", "Navigate"),
+    key(ServiceList, "Enter", "Select Service"),
+    key_when(ServiceList, "F", "Fleet", |app| {
+        !app.config.accounts.is_empty()
+    }),
+    key(ServiceList, "G", "Resource Groups"),
+    key(ServiceList, "A", "Applications"),
+    key(ServiceList, "C", "Composite Alarms"),
+    key(ServiceList, "M", "Alarms"),
+    key(ServiceList, "p", "Switch Profile"),
+    key(ServiceList, "R", "Region"),
+    key_when(ServiceList, "U", "Release Notes", |app| {
+        app.available_update.is_some()
+    }),
+    key(InstanceList, "↑/↓", "Navigate"),
+    key_when(InstanceList, "Enter", "View Details", |app| {
+        !app.folder_selected()
+    }),
+    key_when(
+        InstanceList,
+        "Enter",
+        "Open/Close Folder",
+        App::folder_selected,
+    ),
+    key_when(InstanceList, "←/→", "Close/Open Folder", App::folders_shown),
+    key(InstanceList, "Space", "Mark"),
+    key(InstanceList, "m/M", "Mute/Unmute Alarms"),
+    key_when(InstanceList, "w", "Watchlist Only", |app| {
+        !app.watchlist_only
+    }),
+    key_when(InstanceList, "w", "Show All", |app| app.watchlist_only),
+    key_when(InstanceList, "T", "Thresholds", |app| {
+        app.view.selected_service == Some(AwsService::Rds)
+    }),
+    key_when(InstanceList, "E", "Export Fleet", |app| {
+        app.view.selected_service == Some(AwsService::Rds)
+    }),
+    key_when(InstanceList, "f", "Folders", |app| {
+        app.view.selected_service == Some(AwsService::Sqs) && !app.view.queue_folders.shown
+    }),
+    key_when(InstanceList, "f", "Flat List", App::folders_shown),
+    key(InstanceList, "r", "Refresh"),
+    key(InstanceList, "Esc", "Back"),
+    key(RdsMetrics, "↑/↓", "Navigate"),
+    key(RdsMetrics, "Tab", "Switch Panels (Time/Sparklines)"),
+    key(RdsMetrics, "Enter", "Select"),
+    key(RdsMetrics, "r", "Refresh"),
+    key(RdsMetrics, "R", "Refresh Metric"),
+    key_when(RdsMetrics, "x", "Retry Failed", has_failed_metrics),
+    key(RdsMetrics, "p", "Period"),
+    key(RdsMetrics, "i", "Details"),
+    key(RdsMetrics, "c/C", "Probe"),
+    key(RdsMetrics, "T", "Thresholds"),
+    key_when(RdsMetrics, "t", "Replicas", has_replicas),
+    key_when(RdsMetrics, "gb", "Blue/Green", in_blue_green),
+    key_when(RdsMetrics, "gd", "Global Database", in_aurora_cluster),
+    key_when(RdsMetrics, "s", "Slow Queries", has_slow_query_log),
+    key(RdsMetrics, "l", "Logs"),
+    key(RdsMetrics, "P", "Parameters"),
+    key(RdsMetrics, "o", "Right-sizing"),
+    key(RdsMetrics, "v", "Compare"),
+    key(RdsMetrics, "S", "Snapshot"),
+    key_when(RdsMetrics, "y", "Copy Metric as Text", |app| {
+        app.view.selected_service != Some(AwsService::Rds)
+    }),
+    key(RdsMetrics, "b/Esc", "Back"),
+    key(QueueMetrics, "s", "Send Test Message"),
+    key(QueueMetrics, "R", "Redrive DLQ"),
+    key(QueueMetrics, "i", "Details"),
+    key(QueueMetrics, "P", "Policy & Encryption"),
+    key(QueueMetrics, "L", "Lambda Consumers"),
+    key(QueueMetrics, "A", "DLQ Alarm"),
+    key(QueueMetrics, "y", "Copy Metric as Text"),
+    key(QueueMetrics, "f", "Stack Family"),
+    key_when(QueueMetrics, "1-9", "Toggle Layer", is_stacked),
+    key(QueueMetrics, "r", "Refresh"),
+    key_when(QueueMetrics, "x", "Retry Failed", has_failed_metrics),
+    key(QueueMetrics, "b/Esc", "Back"),
+    key(CanaryMetrics, "y", "Copy Artifacts Link"),
+    key(CanaryMetrics, "Y", "Copy Metric as Text"),
+    key(CanaryMetrics, "i", "Details"),
+    key(CanaryMetrics, "r", "Refresh"),
+    key_when(CanaryMetrics, "x", "Retry Failed", has_failed_metrics),
+    key(CanaryMetrics, "b/Esc", "Back"),
+    key(RuleMetrics, "i", "Details"),
+    key(RuleMetrics, "y", "Copy Metric as Text"),
+    key(RuleMetrics, "r", "Refresh"),
+    key_when(RuleMetrics, "x", "Retry Failed", has_failed_metrics),
+    key(RuleMetrics, "b/Esc", "Back"),
+    key(BatchMetrics, "Tab", "Next Compute Env"),
+    key(BatchMetrics, "i", "Details"),
+    key(BatchMetrics, "y", "Copy Metric as Text"),
+    key(BatchMetrics, "r", "Refresh"),
+    key_when(BatchMetrics, "x", "Retry Failed", has_failed_metrics),
+    key(BatchMetrics, "b/Esc", "Back"),
+    key(FunctionMetrics, "i", "Details"),
+    key(FunctionMetrics, "l", "Logs"),
+    key(FunctionMetrics, "y", "Copy Metric as Text"),
+    key(FunctionMetrics, "r", "Refresh"),
+    key_when(FunctionMetrics, "x", "Retry Failed", has_failed_metrics),
+    key(FunctionMetrics, "b/Esc", "Back"),
+    key(Chart, "A", "Create Alarm"),
+    key(Chart, "P", "Pin Line"),
+    key(Chart, "y", "Copy as Text"),
+    key(Chart, "r", "Refresh"),
+    key(Chart, "R", "Refresh Metric"),
+    key_when(Chart, "h", "Heat Strip", |app| {
+        charting_latency(app) && !app.view.heat_strip
+    }),
+    key_when(Chart, "h", "Line Chart", App::showing_heat_strip),
+    key_when(Chart, "d", "Rate of Change", |app| {
+        charting_counter(app) && !app.view.derivative
+    }),
+    key_when(Chart, "d", "Values", App::showing_derivative),
+    key_when(Chart, "f", "Stack Family", |app| {
+        !app.stackable_families().is_empty() && !is_stacked(app)
+    }),
+    key_when(Chart, "f", "Unstack", is_stacked),
+    key_when(Chart, "1-9", "Toggle Layer", is_stacked),
+    key(Chart, "b/Esc", "Back"),
+    key(ReplicaTopology, "↑/↓", "Navigate"),
+    key(ReplicaTopology, "Enter", "Open Replica"),
+    key(ReplicaTopology, "r", "Refresh"),
+    key(ReplicaTopology, "b/Esc", "Back"),
+    key(BlueGreen, "r", "Refresh"),
+    key(BlueGreen, "b/Esc", "Back"),
+    key(GlobalDatabase, "↑/↓", "Select Secondary"),
+    key(GlobalDatabase, "r", "Refresh"),
+    key(GlobalDatabase, "b/Esc", "Back"),
+    key(SlowQueries, "↑/↓", "Navigate"),
+    key(SlowQueries, "s", "Sort"),
+    key(SlowQueries, "r", "Refresh"),
+    key(SlowQueries, "b/Esc", "Back"),
+    key_when(LogTail, "↑/↓", "Navigate", picking_log_group),
+    key_when(LogTail, "Enter", "Follow", picking_log_group),
+    key_when(LogTail, "↑/↓/PgUp/PgDn", "Scroll", tailing_logs),
+    key_when(LogTail, "End", "Follow", |app| {
+        tailing_logs(app) && app.view.log_tail.scroll > 0
+    }),
+    key_when(LogTail, "Space", "Pause", |app| {
+        tailing_logs(app) && !app.view.log_tail.paused
+    }),
+    key_when(LogTail, "Space", "Resume", |app| {
+        tailing_logs(app) && app.view.log_tail.paused
+    }),
+    key_when(LogTail, "/", "Search", tailing_logs),
+    key_when(LogTail, "n/N", "Older/Newer Match", |app| {
+        tailing_logs(app) && app.view.log_tail.search.is_some()
+    }),
+    key_when(LogTail, "f", "Filter Pattern", tailing_logs),
+    key(LogTail, "b/Esc", "Back"),
+    key(Parameters, "↑/↓", "Navigate"),
+    key(Parameters, "r", "Refresh"),
+    key(Parameters, "b/Esc", "Back"),
+    key(Fleet, "↑/↓", "Navigate"),
+    key(Fleet, "Enter", "Open in Its Account"),
+    key(Fleet, "s", "Service"),
+    key(Fleet, "r", "Refresh"),
+    key(Fleet, "b/Esc", "Back"),
+    key(ResourceGroups, "↑/↓", "Navigate"),
+    key_when(ResourceGroups, "Enter", "Open", |app| {
+        app.view.resource_groups.group.is_none()
+    }),
+    key_when(ResourceGroups, "Enter", "View Metrics", |app| {
+        app.view.resource_groups.group.is_some()
+    }),
+    key(ResourceGroups, "r", "Refresh"),
+    key(ResourceGroups, "b/Esc", "Back"),
+    key(CompositeAlarms, "↑/↓", "Navigate"),
+    key_when(CompositeAlarms, "Enter", "Show Rule", |app| {
+        app.view.composite_alarms.open.is_none()
+    }),
+    key_when(CompositeAlarms, "Enter", "View Metric", |app| {
+        app.view.composite_alarms.open.is_some()
+    }),
+    key(CompositeAlarms, "r", "Refresh"),
+    key(CompositeAlarms, "b/Esc", "Back"),
+    key(Alarms, "↑/↓", "Navigate"),
+    key(Alarms, "Enter", "View Metric"),
+    key(Alarms, "n", "Next Namespace"),
+    key(Alarms, "r", "Refresh"),
+    key(Alarms, "b/Esc", "Back"),
+    key(Timings, "c", "Clear"),
+    key(Timings, "b/Esc", "Back"),
+    key(ApiCalls, "b/Esc", "Back"),
+    key(Internals, "y", "Copy for a bug report"),
+    key(Internals, "b/Esc", "Back"),
+    key(DetailsPane, "y", "Copy Value"),
+    key(DetailsPane, "Y", "Copy Key/Value"),
+    key(DetailsPane, "←/→", "Scroll"),
+    key(DetailsPane, "i/Esc", "Close"),
+    key(ActivityPanel, "↑/↓", "Navigate"),
+    key(ActivityPanel, "r", "Refresh"),
+    key(ActivityPanel, "a/Esc", "Close"),
+    key(RightsizingPanel, "r", "Refresh"),
+    key(RightsizingPanel, "o/Esc", "Close"),
+    key(StackPanel, "r", "Refresh"),
+    key(StackPanel, "K/Esc", "Close"),
+    key(QueueAccessPanel, "↑/↓", "Scroll"),
+    key(QueueAccessPanel, "y", "Copy Policy"),
+    key(QueueAccessPanel, "Y", "Copy KMS Key"),
+    key(QueueAccessPanel, "P/Esc", "Close"),
+    key(ConsumersPanel, "↑/↓", "Scroll"),
+    key(ConsumersPanel, "r", "Refresh"),
+    key(ConsumersPanel, "L/Esc", "Close"),
+    key(ComparePanel, "↑/↓", "Navigate"),
+    key(ComparePanel, "w", "Earlier Window"),
+    key(ComparePanel, "r", "Refresh"),
+    key(ComparePanel, "v/Esc", "Close"),
+    key(BookmarksPanel, "↑/↓", "Navigate"),
+    key(BookmarksPanel, "Enter", "Open"),
+    key_when(BookmarksPanel, "n", "Bookmark This View", |app| {
+        matches!(
+            app.view.state,
+            AppState::MetricsSummary | AppState::InstanceDetails
+        )
+    }),
+    key(BookmarksPanel, "d", "Delete"),
+    key(BookmarksPanel, "y", "Copy JSON"),
+    key(BookmarksPanel, "B/Esc", "Close"),
+    key(ProfilesPanel, "↑/↓", "Navigate"),
+    key(ProfilesPanel, "Enter", "Switch"),
+    key(ProfilesPanel, "p/Esc", "Close"),
+    key(RegionsPanel, "↑/↓", "Navigate"),
+    key(RegionsPanel, "Enter", "Switch"),
+    key(RegionsPanel, "R/Esc", "Close"),
+    key(JobsPanel, "↑/↓", "Navigate"),
+    key(JobsPanel, "x", "Cancel"),
+    key(JobsPanel, "c", "Clear Finished"),
+    key(JobsPanel, "J/Esc", "Close"),
+    key(AlarmForm, "↑/↓", "Field"),
+    key(AlarmForm, "←/→", "Change"),
+    key(AlarmForm, "Enter", "Create"),
+    key(AlarmForm, "Esc", "Cancel"),
+    key(ThresholdEditor, "↑/↓", "Metric"),
+    key(ThresholdEditor, "←/→", "Warning/Critical"),
+    key(ThresholdEditor, "d", "Default"),
+    key(ThresholdEditor, "Enter", "Save"),
+    key(ThresholdEditor, "Esc", "Cancel"),
+    key(PinPrompt, "Enter", "Pin"),
+    key(PinPrompt, "Esc", "Cancel"),
+    key(ExportPrompt, "Enter", "Export"),
+    key(ExportPrompt, "Esc", "Cancel"),
+    key(CommandLine, "Enter", "Run"),
+    key(CommandLine, "Esc", "Cancel"),
+    key(Confirmation, "Enter", "Confirm"),
+    key(Confirmation, "Esc", "Cancel"),
+    key_when(Global, "a", "API Activity", |app| {
+        matches!(
+            app.view.state,
+            AppState::MetricsSummary | AppState::InstanceDetails
+        )
+    }),
+    key_when(Global, "K", "Stack", |app| {
+        matches!(
+            app.view.state,
+            AppState::MetricsSummary | AppState::InstanceDetails
+        )
+    }),
+    key_when(Global, "e", "Export", |app| {
+        app.view.state == AppState::MetricsSummary
+    }),
+    key(Global, "B", "Bookmarks"),
+    key_when(Global, "I", "Incident Mode", |app| {
+        app.incident.is_none()
+            && app.view.state == AppState::MetricsSummary
+            && app.view.selected_service == Some(AwsService::Rds)
+    }),
+    key_when(Global, "I", "End Incident", |app| app.incident.is_some()),
+    key_when(Global, "J", "Jobs", |app| !app.jobs.jobs().is_empty()),
+    key_when(Global, "F12", "Hide HUD", |app| app.perf_hud_open),
+    key(Global, "u", "Undo"),
+    key_when(Global, "gt/gT", "Tabs", |app| app.tabs.len() > 1),
+    key(Global, "q", "Quit"),
+];
+
+/// Keys of the page being shown, followed by the keys that work everywhere
+pub fn page_hints(app: &App) -> String {
+    let context = match app.view.state {
+        AppState::ServiceList => ServiceList,
+        AppState::InstanceList => InstanceList,
+        AppState::MetricsSummary if app.view.selected_service == Some(AwsService::Sqs) => {
+            QueueMetrics
+        }
+        AppState::MetricsSummary if app.view.selected_service == Some(AwsService::Synthetics) => {
+            CanaryMetrics
+        }
+        AppState::MetricsSummary if app.view.selected_service == Some(AwsService::EventBridge) => {
+            RuleMetrics
+        }
+        AppState::MetricsSummary if app.view.selected_service == Some(AwsService::Batch) => {
+            BatchMetrics
+        }
+        AppState::MetricsSummary if app.view.selected_service == Some(AwsService::Lambda) => {
+            FunctionMetrics
+        }
+        AppState::MetricsSummary => RdsMetrics,
+        AppState::InstanceDetails => Chart,
+        AppState::ReplicaTopology => ReplicaTopology,
+        AppState::BlueGreen => BlueGreen,
+        AppState::GlobalDatabase => GlobalDatabase,
+        AppState::SlowQueries => SlowQueries,
+        AppState::LogTail => LogTail,
+        AppState::Parameters => Parameters,
+        AppState::Fleet => Fleet,
+        AppState::ResourceGroups => ResourceGroups,
+        AppState::CompositeAlarms => CompositeAlarms,
+        AppState::Alarms => Alarms,
+        AppState::Timings => Timings,
+        AppState::ApiCalls => ApiCalls,
+        AppState::Internals => Internals,
+    };
+    join(app, |binding| {
+        binding.context == context || binding.context == Global
+    })
+}
+
+/// Keys of an overlay, which takes every key while open
+pub fn overlay_hints(app: &App, context: KeyContext) -> String {
+    join(app, |binding| binding.context == context)
+}
+
+fn join(app: &App, include: impl Fn(&Binding) -> bool) -> String {
+    BINDINGS
+        .iter()
+        .filter(|binding| include(binding) && binding.when.is_none_or(|when| when(app)))
+        .map(|binding| format!("{}: {}", binding.keys, binding.action))
+        .collect::<Vec<_>>()
+        .join(" • ")
+}
+
+fn has_failed_metrics(app: &App) -> bool {
+    !app.view.failed_metrics.is_empty()
+}
+
+fn charting_latency(app: &App) -> bool {
+    app.detail_chart_metric()
+        .is_some_and(|metric| metric.is_latency())
+}
+
+fn charting_counter(app: &App) -> bool {
+    app.detail_chart_metric()
+        .is_some_and(|metric| metric.is_counter())
+}
+
+fn is_stacked(app: &App) -> bool {
+    app.stacked_chart().is_some()
+}
+
+fn has_replicas(app: &App) -> bool {
+    app.get_selected_rds_instance().is_some_and(|instance| {
+        !instance.topology.replicas.is_empty() || instance.topology.source.is_some()
+    })
+}
+
+fn in_blue_green(app: &App) -> bool {
+    app.get_selected_rds_instance_id()
+        .is_some_and(|id| app.blue_green_deployment_for(&id).is_some())
+}
+
+fn in_aurora_cluster(app: &App) -> bool {
+    app.get_selected_rds_instance()
+        .is_some_and(|instance| instance.attribute("Cluster").is_some())
+}
+
+fn picking_log_group(app: &App) -> bool {
+    app.view.log_tail.group.is_none()
+}
+
+fn tailing_logs(app: &App) -> bool {
+    app.view.log_tail.group.is_some()
+}
+
+fn has_slow_query_log(app: &App) -> bool {
+    app.get_selected_rds_instance().is_some_and(|instance| {
+        slow_query::log_group(
+            &instance.identifier,
+            instance.attribute("Cluster"),
+            &instance.engine,
+        )
+        .is_some()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bindings_always_shown_do_not_share_keys() {
+        for (i, a) in BINDINGS.iter().enumerate() {
+            for b in &BINDINGS[i + 1..] {
+                let clash = a.context == b.context
+                    && a.keys == b.keys
+                    && a.when.is_none()
+                    && b.when.is_none();
+                assert!(!clash, "{:?} binds {} twice", a.context, a.keys);
+            }
+        }
+    }
+}
@@ -1,6 +1,11 @@
 // UI module exports
+pub mod accessibility;
+pub mod ascii;
 pub mod charts;
 pub mod components;
+pub mod graphics;
+pub mod keymap;
 pub mod renderer;
+pub mod theme;
 
 pub use renderer::render_app;
@@ -1,14 +1,179 @@
+use super::ascii::asciify;
 use super::components::{
-    render_instance_details, render_metrics_summary, render_rds_list, render_service_list,
+    activity_panel::render_activity_panel,
+    alarm_form::render_alarm_form,
+    bookmarks_panel::render_bookmarks_panel,
+    command_line::render_command_line,
+    compare_panel::render_compare_panel,
+    confirmation_prompt::render_confirmation_prompt,
+    consumers_panel::render_consumers_panel,
+    details_pane::render_details_pane,
+    export_prompt::render_export_prompt,
+    jobs_panel::{render_jobs_panel, render_toasts},
+    perf_hud::render_perf_hud,
+    pin_prompt::render_pin_prompt,
+    profiles_panel::render_profiles_panel,
+    queue_access::render_queue_access,
+    regions_panel::render_regions_panel,
+    render_alarm_browser, render_api_calls, render_batch_queue_summary, render_blue_green,
+    render_canary_summary, render_composite_alarms, render_event_rule_summary, render_fleet,
+    render_function_summary, render_global_database, render_instance_details, render_internals,
+    render_log_tail, render_metrics_summary, render_parameters, render_queue_summary,
+    render_rds_list, render_release_notes, render_replica_topology, render_resource_groups,
+    render_screen_reader, render_service_list, render_slow_queries, render_tab_bar, render_timings,
+    rightsizing_panel::render_rightsizing_panel,
+    stack_panel::render_stack_panel,
+    threshold_editor::render_threshold_editor,
 };
-use crate::models::{App, AppState};
+use super::keymap::{overlay_hints, KeyContext};
+use crate::models::{App, AppState, AwsService};
 use ratatui::Frame;
 
 pub fn render_app(f: &mut Frame, app: &mut App) {
-    match app.state {
+    // Pages showing a chart image claim its area again on every render
+    app.chart_image_area = None;
+    render_page(f, app);
+    if app.config.theme.ascii_enabled() {
+        asciify(f.buffer_mut());
+    }
+}
+
+fn render_page(f: &mut Frame, app: &mut App) {
+    if app.config.accessibility.screen_reader {
+        render_screen_reader(f, app);
+    } else {
+        render_screen(f, app);
+    }
+    render_overlays(f, app);
+}
+
+/// Popups and the tab bar, drawn the same way over every page and in
+/// screen-reader mode; the last one drawn is the one taking keys
+fn render_overlays(f: &mut Frame, app: &App) {
+    let area = f.area();
+    if app.view.details_pane.open {
+        render_details_pane(f, app, area);
+    }
+    if app.view.activity.open {
+        render_activity_panel(f, app, area);
+    }
+    if app.view.rightsizing.open {
+        render_rightsizing_panel(f, app, area);
+    }
+    if app.view.stack.open {
+        render_stack_panel(f, app, area);
+    }
+    if app.view.queue_access.open {
+        render_queue_access(f, app, area);
+    }
+    if app.view.consumers.open {
+        render_consumers_panel(f, app, area);
+    }
+    if app.view.compare.open {
+        render_compare_panel(f, app, area);
+    }
+    if let Some(panel) = &app.bookmarks_panel {
+        render_bookmarks_panel(f, app, panel, area);
+    }
+    if let Some(panel) = &app.jobs_panel {
+        render_jobs_panel(f, app, panel, area);
+    }
+    if let Some(panel) = &app.profiles_panel {
+        render_profiles_panel(f, app, panel, area);
+    }
+    if let Some(panel) = &app.regions_panel {
+        render_regions_panel(f, app, panel, area);
+    }
+    if let Some(form) = &app.alarm_form {
+        render_alarm_form(
+            f,
+            form,
+            app.alarm_form_backtest().as_ref(),
+            &overlay_hints(app, KeyContext::AlarmForm),
+            area,
+        );
+    }
+    if let Some(editor) = &app.threshold_editor {
+        render_threshold_editor(
+            f,
+            editor,
+            &app.config.theme,
+            &overlay_hints(app, KeyContext::ThresholdEditor),
+            area,
+        );
+    }
+    if let Some(prompt) = &app.pin_prompt {
+        render_pin_prompt(
+            f,
+            prompt,
+            app.pinned_lines(prompt.metric.metric_name()),
+            &overlay_hints(app, KeyContext::PinPrompt),
+            area,
+        );
+    }
+    if let Some(prompt) = &app.export_prompt {
+        render_export_prompt(
+            f,
+            prompt,
+            &app.get_selected_instance_id().unwrap_or_default(),
+            app.loaded_series().map_or(0, |columns| columns.len()),
+            &overlay_hints(app, KeyContext::ExportPrompt),
+            area,
+        );
+    }
+    if let Some(confirmation) = &app.pending_action {
+        render_confirmation_prompt(
+            f,
+            confirmation,
+            &app.config.theme,
+            &overlay_hints(app, KeyContext::Confirmation),
+            area,
+        );
+    }
+    if let Some(line) = &app.command_line {
+        render_command_line(f, line, &overlay_hints(app, KeyContext::CommandLine), area);
+    }
+    if app.tabs.len() > 1 && !app.config.accessibility.screen_reader {
+        render_tab_bar(f, &app.tab_labels(), app.active_tab, area);
+    }
+    render_toasts(f, app, area);
+    if app.perf_hud_open {
+        render_perf_hud(f, app, area);
+    }
+    if let Some(release) = app
+        .available_update
+        .as_ref()
+        .filter(|_| app.release_notes_open)
+    {
+        render_release_notes(f, release, area);
+    }
+}
+
+fn render_screen(f: &mut Frame, app: &mut App) {
+    match app.view.state {
         AppState::ServiceList => render_service_list(f, app),
         AppState::InstanceList => render_rds_list(f, app),
-        AppState::MetricsSummary => render_metrics_summary(f, app),
+        AppState::MetricsSummary => match app.view.selected_service {
+            Some(AwsService::Sqs) => render_queue_summary(f, app),
+            Some(AwsService::Synthetics) => render_canary_summary(f, app),
+            Some(AwsService::EventBridge) => render_event_rule_summary(f, app),
+            Some(AwsService::Batch) => render_batch_queue_summary(f, app),
+            Some(AwsService::Lambda) => render_function_summary(f, app),
+            _ => render_metrics_summary(f, app),
+        },
         AppState::InstanceDetails => render_instance_details(f, app),
+        AppState::ReplicaTopology => render_replica_topology(f, app),
+        AppState::BlueGreen => render_blue_green(f, app),
+        AppState::GlobalDatabase => render_global_database(f, app),
+        AppState::SlowQueries => render_slow_queries(f, app),
+        AppState::LogTail => render_log_tail(f, app),
+        AppState::Parameters => render_parameters(f, app),
+        AppState::Fleet => render_fleet(f, app),
+        AppState::ResourceGroups => render_resource_groups(f, app),
+        AppState::CompositeAlarms => render_composite_alarms(f, app),
+        AppState::Alarms => render_alarm_browser(f, app),
+        AppState::Timings => render_timings(f, app),
+        AppState::ApiCalls => render_api_calls(f, app),
+        AppState::Internals => render_internals(f, app),
     }
 }
@@ -0,0 +1,175 @@
+// Severity colors and non-color encodings, selected by `[theme]` in config.toml
+//
+// The default palette uses the terminal's green/yellow/red. The alternative
+// palettes use hues that stay distinguishable with common color-vision
+// deficiencies, and turn on markers (✓ ▲ ✖) and dotted chart lines so states
+// never depend on color alone. `ascii` restricts output to ASCII glyphs for
+// terminals that cannot draw Braille or box-drawing characters.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::symbols::Marker;
+use serde::Deserialize;
+
+/// `[charts] marker`: how data series are plotted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChartMarker {
+    #[default]
+    Braille, // Two by four dots per cell
+    Block, // One full cell per point
+    Dot,   // One dot per cell
+}
+
+/// State of a metric, badge or chart overlay, ordered from best to worst
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Ok,
+    Warning,
+    Critical,
+}
+
+impl Severity {
+    /// Spoken form used by the screen-reader view
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Ok => "ok",
+            Severity::Warning => "warning",
+            Severity::Critical => "critical",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Palette {
+    #[default]
+    Default,
+    RedGreen,   // Deuteranopia / protanopia (Okabe-Ito blue, yellow, vermillion)
+    BlueYellow, // Tritanopia (bluish green, reddish purple, vermillion)
+    Monochrome, // No hue at all; states are carried by markers and weight
+}
+
+impl Palette {
+    /// Palette from its config name, e.g. `red-green`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Palette::Default),
+            "red-green" => Some(Palette::RedGreen),
+            "blue-yellow" => Some(Palette::BlueYellow),
+            "monochrome" => Some(Palette::Monochrome),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub palette: Palette,
+    /// Markers and textures; on by default for every palette except `default`
+    pub patterns: Option<bool>,
+    /// ASCII-only glyphs; detected from the terminal when unset
+    pub ascii: Option<bool>,
+}
+
+impl Theme {
+    pub fn color(&self, severity: Severity) -> Color {
+        match (self.palette, severity) {
+            (Palette::Default, Severity::Ok) => Color::Green,
+            (Palette::Default, Severity::Warning) => Color::Yellow,
+            (Palette::Default, Severity::Critical) => Color::Red,
+            (Palette::RedGreen, Severity::Ok) => Color::Rgb(0, 114, 178),
+            (Palette::RedGreen, Severity::Warning) => Color::Rgb(240, 228, 66),
+            (Palette::RedGreen, Severity::Critical) => Color::Rgb(213, 94, 0),
+            (Palette::BlueYellow, Severity::Ok) => Color::Rgb(0, 158, 115),
+            (Palette::BlueYellow, Severity::Warning) => Color::Rgb(204, 121, 167),
+            (Palette::BlueYellow, Severity::Critical) => Color::Rgb(213, 94, 0),
+            (Palette::Monochrome, Severity::Ok) => Color::Gray,
+            (Palette::Monochrome, _) => Color::White,
+        }
+    }
+
+    pub fn style(&self, severity: Severity) -> Style {
+        let style = Style::default().fg(self.color(severity));
+        match (self.palette, severity) {
+            (Palette::Monochrome, Severity::Critical) => {
+                style.add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            }
+            (Palette::Monochrome, Severity::Warning) => style.add_modifier(Modifier::BOLD),
+            _ => style,
+        }
+    }
+
+    pub fn patterns_enabled(&self) -> bool {
+        self.patterns.unwrap_or(self.palette != Palette::Default)
+    }
+
+    pub fn ascii_enabled(&self) -> bool {
+        self.ascii.unwrap_or(false)
+    }
+
+    /// Prefix that encodes the state without color, empty when patterns are off
+    pub fn marker(&self, severity: Severity) -> &'static str {
+        if !self.patterns_enabled() {
+            return "";
+        }
+        match (self.ascii_enabled(), severity) {
+            (false, Severity::Ok) => "✓ ",
+            (false, Severity::Warning) => "▲ ",
+            (false, Severity::Critical) => "✖ ",
+            (true, Severity::Ok) => "+ ",
+            (true, Severity::Warning) => "! ",
+            (true, Severity::Critical) => "x ",
+        }
+    }
+
+    /// Plot marker for data series; one point per cell in ASCII mode
+    pub fn chart_marker(&self) -> Marker {
+        if self.ascii_enabled() {
+            Marker::Dot
+        } else {
+            Marker::Braille
+        }
+    }
+
+    /// Plot marker for data series of the kind chosen in `[charts] marker`
+    ///
+    /// Braille has no ASCII form beyond single dots, so it falls back to them
+    /// in ASCII mode; blocks are drawn as `#`.
+    pub fn series_marker(&self, choice: ChartMarker) -> Marker {
+        match choice {
+            ChartMarker::Braille => self.chart_marker(),
+            ChartMarker::Block => Marker::Block,
+            ChartMarker::Dot => Marker::Dot,
+        }
+    }
+
+    /// Plot marker for chart reference lines; warnings and criticals are dotted
+    pub fn line_marker(&self, severity: Severity) -> Marker {
+        if self.patterns_enabled() && severity != Severity::Ok {
+            Marker::Dot
+        } else {
+            self.chart_marker()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_blind_palettes_enable_patterns_by_default() {
+        let default = Theme::default();
+        assert_eq!(default.color(Severity::Critical), Color::Red);
+        assert_eq!(default.marker(Severity::Critical), "");
+
+        let theme: Theme = toml::from_str(r#"palette = "red-green""#).unwrap();
+        assert!(theme.patterns_enabled());
+        assert_eq!(theme.marker(Severity::Warning), "▲ ");
+        assert_ne!(theme.color(Severity::Ok), theme.color(Severity::Critical));
+
+        let theme: Theme = toml::from_str("palette = \"monochrome\"\npatterns = false").unwrap();
+        assert!(!theme.patterns_enabled());
+    }
+}
@@ -0,0 +1,150 @@
+// Release update check
+//
+// Unless `[updates] check = false`, the latest GitHub release is looked up in
+// a background task at startup. The answer is cached for a day, so most
+// launches read it from disk and never touch the network, and a slow or
+// failed request never delays the UI. A newer release shows a notice on the
+// service list with its release notes one key away. The request goes through
+// the same connector as the AWS calls, so `[network]` proxy settings apply.
+
+use crate::aws::session::AwsSessionManager;
+use anyhow::{anyhow, Context, Result};
+use aws_smithy_runtime_api::client::http::HttpConnector;
+use aws_smithy_runtime_api::client::orchestrator::HttpRequest;
+use aws_smithy_types::body::SdkBody;
+use aws_smithy_types::byte_stream::ByteStream;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+const LATEST_RELEASE_URL: &str =
+    "https://api.github.com/repos/emamulandalib/aws-cw-tui/releases/latest";
+const CACHE_FILE: &str = "latest-release.toml";
+
+/// How long a cached answer is trusted before asking GitHub again
+const CHECK_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Release {
+    pub version: String, // Without the leading "v" of the tag
+    pub notes: String,
+    pub checked_at: u64, // Seconds since the Unix epoch
+}
+
+impl Release {
+    /// Whether this release is newer than `current`; false if either is not semver
+    pub fn is_newer_than(&self, current: &str) -> bool {
+        match (
+            semver::Version::parse(&self.version),
+            semver::Version::parse(current),
+        ) {
+            (Ok(latest), Ok(current)) => latest > current,
+            _ => false,
+        }
+    }
+
+    fn is_fresh(&self, now: SystemTime) -> bool {
+        let checked_at = SystemTime::UNIX_EPOCH + Duration::from_secs(self.checked_at);
+        now.duration_since(checked_at)
+            .is_ok_and(|age| age < CHECK_INTERVAL)
+    }
+}
+
+/// The cached release if it was fetched within the last day
+pub fn load_fresh_cache() -> Option<Release> {
+    let contents = std::fs::read_to_string(cache_path()?).ok()?;
+    toml::from_str::<Release>(&contents)
+        .ok()
+        .filter(|release| release.is_fresh(SystemTime::now()))
+}
+
+/// Fetch the latest release from GitHub and cache it
+pub async fn check_latest() -> Result<Release> {
+    let (status, body) = tokio::time::timeout(REQUEST_TIMEOUT, fetch_latest_release())
+        .await
+        .map_err(|_| {
+            anyhow!(
+                "GitHub did not answer within {}s",
+                REQUEST_TIMEOUT.as_secs()
+            )
+        })??;
+    let checked_at = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let release = parse_response(status, &body, checked_at)?;
+    if let Some(path) = cache_path() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        std::fs::write(&path, toml::to_string(&release)?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    Ok(release)
+}
+
+/// Status code and body of GitHub's answer
+async fn fetch_latest_release() -> Result<(u16, Vec<u8>)> {
+    let request = http::Request::builder()
+        .uri(LATEST_RELEASE_URL)
+        .header("user-agent", concat!("awscw/", env!("CARGO_PKG_VERSION")))
+        .header("accept", "application/vnd.github+json")
+        .body(SdkBody::empty())?;
+    let response = AwsSessionManager::http_connector()
+        .call(HttpRequest::try_from(request)?)
+        .await
+        .map_err(|e| anyhow!("Could not reach GitHub: {e}"))?;
+    let status = response.status().as_u16();
+    let body = ByteStream::new(response.into_body())
+        .collect()
+        .await?
+        .into_bytes();
+    Ok((status, body.to_vec()))
+}
+
+#[derive(Deserialize)]
+struct GitHubRelease {
+    tag_name: String,
+    #[serde(default)]
+    body: Option<String>,
+}
+
+fn parse_response(status: u16, body: &[u8], checked_at: u64) -> Result<Release> {
+    if status != 200 {
+        return Err(anyhow!("GitHub answered HTTP {status}"));
+    }
+    let release: GitHubRelease = serde_json::from_slice(body)?;
+    Ok(Release {
+        version: release.tag_name.trim_start_matches('v').to_string(),
+        notes: release.body.unwrap_or_default().replace("\r\n", "\n"),
+        checked_at,
+    })
+}
+
+/// Location of the cached answer (e.g. `~/.cache/awscw/latest-release.toml`)
+fn cache_path() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("awscw").join(CACHE_FILE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_release_response_parsing_and_comparison() {
+        let body = br###"{"tag_name":"v0.3.0","body":"## Changes\r\n- Lambda support"}"###;
+        let release = parse_response(200, body, 1_700_000_000).unwrap();
+        assert_eq!(release.version, "0.3.0");
+        assert_eq!(release.notes, "## Changes\n- Lambda support");
+        assert!(release.is_newer_than("0.1.0"));
+        assert!(!release.is_newer_than("0.3.0"));
+
+        let checked = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        assert!(release.is_fresh(checked + Duration::from_secs(3600)));
+        assert!(!release.is_fresh(checked + CHECK_INTERVAL));
+
+        assert!(parse_response(403, b"{}", 0).is_err());
+    }
+}
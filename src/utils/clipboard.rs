@@ -0,0 +1,29 @@
+use anyhow::Result;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::io::Write;
+
+/// Copy text to the system clipboard using the OSC 52 escape sequence
+///
+/// OSC 52 is handled by the terminal emulator itself, so copying works over
+/// SSH and inside tmux (with `set-clipboard on`) without a native clipboard
+/// dependency.
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    stdout.write_all(osc52_sequence(text).as_bytes())?;
+    stdout.flush()?;
+    Ok(())
+}
+
+fn osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", STANDARD.encode(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osc52_sequence_encodes_payload() {
+        assert_eq!(osc52_sequence("db:5432"), "\x1b]52;c;ZGI6NTQzMg==\x07");
+    }
+}
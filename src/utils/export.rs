@@ -0,0 +1,247 @@
+// Metric series export, shared by every service's metrics page
+//
+// The series of the open resource are written as CSV (one row per timestamp,
+// one column per metric) or JSON (each metric with its timestamped points).
+// Timestamps are RFC 3339 in UTC; each value is written against its own
+// datapoint's timestamp, so a metric without a datapoint at some timestamp
+// leaves that cell empty, or has no point there.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+use std::time::SystemTime;
+
+/// A metric's name and timestamped history, one CSV column
+pub type Column = (String, Vec<(SystemTime, f64)>);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SeriesFormat {
+    Csv,
+    Json,
+}
+
+impl SeriesFormat {
+    /// JSON for a `.json` path, otherwise CSV
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Csv,
+        }
+    }
+}
+
+/// Path-entry prompt for exporting the open resource's metrics
+#[derive(Debug, Clone)]
+pub struct ExportPrompt {
+    pub text: String,
+}
+
+impl ExportPrompt {
+    /// Starts with `awscw-<resource>-<time>.csv` in the working directory
+    pub fn new(resource: &str) -> Self {
+        let resource: String = resource
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        Self {
+            text: format!(
+                "awscw-{resource}-{}.csv",
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            ),
+        }
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        self.text.push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.text.pop();
+    }
+}
+
+#[derive(Serialize)]
+struct JsonExport<'a> {
+    resource: &'a str,
+    metrics: Vec<JsonMetric<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonMetric<'a> {
+    name: &'a str,
+    points: Vec<JsonPoint>,
+}
+
+#[derive(Serialize)]
+struct JsonPoint {
+    timestamp: String,
+    value: f64,
+}
+
+fn timestamp_text(timestamp: SystemTime) -> String {
+    DateTime::<Utc>::from(timestamp).to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// One row per timestamp any metric has (RFC 3339, UTC) and one column per metric;
+/// missing values are empty
+pub fn series_csv(columns: &[Column]) -> String {
+    let mut csv = String::from("timestamp");
+    for (name, _) in columns {
+        csv.push(',');
+        csv.push_str(&csv_field(name));
+    }
+    csv.push('\n');
+    let timestamps: BTreeSet<SystemTime> = columns
+        .iter()
+        .flat_map(|(_, points)| points.iter().map(|(timestamp, _)| *timestamp))
+        .collect();
+    let values: Vec<HashMap<SystemTime, f64>> = columns
+        .iter()
+        .map(|(_, points)| points.iter().copied().collect())
+        .collect();
+    for timestamp in timestamps {
+        csv.push_str(&timestamp_text(timestamp));
+        for column in &values {
+            csv.push(',');
+            if let Some(value) = column.get(&timestamp) {
+                csv.push_str(&value.to_string());
+            }
+        }
+        csv.push('\n');
+    }
+    csv
+}
+
+/// A field as written to CSV: quoted when it holds a comma, quote or line break,
+/// with embedded quotes doubled (RFC 4180)
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// The resource and each metric's points, pretty-printed
+pub fn series_json(resource: &str, columns: &[Column]) -> String {
+    let export = JsonExport {
+        resource,
+        metrics: columns
+            .iter()
+            .map(|(name, points)| JsonMetric {
+                name,
+                points: points
+                    .iter()
+                    .map(|&(timestamp, value)| JsonPoint {
+                        timestamp: timestamp_text(timestamp),
+                        value,
+                    })
+                    .collect(),
+            })
+            .collect(),
+    };
+    serde_json::to_string_pretty(&export).unwrap_or_default()
+}
+
+/// Write the series to `path` in `format`
+pub fn write_series(
+    path: &Path,
+    format: SeriesFormat,
+    resource: &str,
+    columns: &[Column],
+) -> Result<()> {
+    let contents = match format {
+        SeriesFormat::Csv => series_csv(columns),
+        SeriesFormat::Json => series_json(resource, columns),
+    };
+    std::fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_series_csv_and_json() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let later = start + Duration::from_secs(300);
+        let columns = [
+            (
+                "CPUUtilization".to_string(),
+                vec![(start, 12.5), (later, 14.0)],
+            ),
+            ("DatabaseConnections".to_string(), vec![(start, 3.0)]),
+        ];
+        assert_eq!(
+            series_csv(&columns),
+            "timestamp,CPUUtilization,DatabaseConnections\n\
+             2023-11-14T22:13:20Z,12.5,3\n\
+             2023-11-14T22:18:20Z,14,\n"
+        );
+
+        // Names of custom metrics and expressions may hold CSV's own delimiters
+        let awkward = [
+            ("p99, \"write\"".to_string(), vec![(start, 1.0)]),
+            ("two\nlines".to_string(), vec![(start, 2.0)]),
+        ];
+        assert_eq!(
+            series_csv(&awkward),
+            "timestamp,\"p99, \"\"write\"\"\",\"two\nlines\"\n\
+             2023-11-14T22:13:20Z,1,2\n"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&series_json("orders-db", &columns)).unwrap();
+        assert_eq!(json["resource"], "orders-db");
+        assert_eq!(json["metrics"][0]["points"][1]["value"], 14.0);
+        assert_eq!(
+            json["metrics"][1]["points"].as_array().map(Vec::len),
+            Some(1)
+        );
+
+        assert_eq!(
+            SeriesFormat::from_path(Path::new("/tmp/orders.JSON")),
+            SeriesFormat::Json
+        );
+        assert_eq!(
+            SeriesFormat::from_path(Path::new("orders.csv")),
+            SeriesFormat::Csv
+        );
+        assert!(ExportPrompt::new("arn:aws:events:rule/x")
+            .text
+            .starts_with("awscw-arn-aws-events-rule-x-"));
+    }
+
+    #[test]
+    fn test_gapped_series_leave_empty_cells() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let at = |minutes: u64| start + Duration::from_secs(minutes * 60);
+        let columns = [
+            (
+                "CPUUtilization".to_string(),
+                vec![(at(0), 10.0), (at(5), 11.0), (at(10), 12.0)],
+            ),
+            // No datapoint for the middle period
+            (
+                "DatabaseConnections".to_string(),
+                vec![(at(0), 3.0), (at(10), 5.0)],
+            ),
+        ];
+        assert_eq!(
+            series_csv(&columns),
+            "timestamp,CPUUtilization,DatabaseConnections\n\
+             2023-11-14T22:13:20Z,10,3\n\
+             2023-11-14T22:18:20Z,11,\n\
+             2023-11-14T22:23:20Z,12,5\n"
+        );
+    }
+}
@@ -0,0 +1,210 @@
+// Text layout and locale-aware number and date formatting
+//
+// CJK characters and most emoji take two terminal cells, so padding and
+// truncating by `len()` or `chars().count()` misaligns columns. Everything
+// here measures in cells instead.
+//
+// Numbers, times and dates follow the `[locale]` config section. It is
+// installed once at startup with `set_locale`; until then (and in tests) the
+// defaults below are used, which match the previous fixed output.
+
+use chrono::{DateTime, Local};
+use serde::Deserialize;
+use std::sync::OnceLock;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+static LOCALE: OnceLock<LocaleConfig> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum Clock {
+    #[default]
+    #[serde(rename = "24h")]
+    H24,
+    #[serde(rename = "12h")]
+    H12,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DateOrder {
+    #[default]
+    Ymd, // 03-05 for 5 March
+    Dmy, // 05/03
+    Mdy, // 03/05
+}
+
+/// Number and date conventions, from `[locale]` in config.toml
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct LocaleConfig {
+    pub decimal_separator: char,
+    pub clock: Clock,
+    pub date_order: DateOrder,
+}
+
+impl Default for LocaleConfig {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            clock: Clock::H24,
+            date_order: DateOrder::Ymd,
+        }
+    }
+}
+
+impl LocaleConfig {
+    /// `value` with `precision` decimals, e.g. "72,5" with a comma separator
+    pub fn number(&self, value: f64, precision: usize) -> String {
+        let formatted = format!("{value:.precision$}");
+        if self.decimal_separator == '.' {
+            formatted
+        } else {
+            formatted.replace('.', &self.decimal_separator.to_string())
+        }
+    }
+
+    pub fn time(&self, time: &DateTime<Local>, with_seconds: bool) -> String {
+        let format = match (self.clock, with_seconds) {
+            (Clock::H24, false) => "%H:%M",
+            (Clock::H24, true) => "%H:%M:%S",
+            (Clock::H12, false) => "%-I:%M %p",
+            (Clock::H12, true) => "%-I:%M:%S %p",
+        };
+        time.format(format).to_string()
+    }
+
+    /// Day and month only, for axis labels
+    pub fn short_date(&self, time: &DateTime<Local>) -> String {
+        let format = match self.date_order {
+            DateOrder::Ymd => "%m-%d",
+            DateOrder::Dmy => "%d/%m",
+            DateOrder::Mdy => "%m/%d",
+        };
+        time.format(format).to_string()
+    }
+}
+
+/// Install the configured locale; only the first call has an effect
+pub fn set_locale(locale: LocaleConfig) {
+    let _ = LOCALE.set(locale);
+}
+
+pub fn locale() -> LocaleConfig {
+    LOCALE.get().copied().unwrap_or_default()
+}
+
+/// Shorthand for `locale().number(value, precision)`
+pub fn format_number(value: f64, precision: usize) -> String {
+    locale().number(value, precision)
+}
+
+/// Number of terminal cells `s` occupies
+pub fn display_width(s: &str) -> usize {
+    UnicodeWidthStr::width(s)
+}
+
+/// Truncate to at most `max_width` cells, ending in "..." when shortened
+pub fn truncate_to_width(s: &str, max_width: usize) -> String {
+    if display_width(s) <= max_width {
+        return s.to_string();
+    }
+    let mut truncated = take_width(s, max_width.saturating_sub(3));
+    truncated.push_str(&".".repeat(max_width.min(3)));
+    truncated
+}
+
+/// Left-align `s` in a column of `width` cells, truncating if it does not fit
+pub fn pad_to_width(s: &str, width: usize) -> String {
+    let mut padded = truncate_to_width(s, width);
+    let used = display_width(&padded);
+    padded.push_str(&" ".repeat(width.saturating_sub(used)));
+    padded
+}
+
+/// Window of `width` cells starting `offset` characters in, with "…" marking
+/// hidden text on either side
+pub fn slice_to_width(s: &str, offset: usize, width: usize) -> String {
+    if width == 0 {
+        return String::new();
+    }
+    if offset == 0 && display_width(s) <= width {
+        return s.to_string();
+    }
+
+    let mut chars = s.chars().skip(offset).peekable();
+    let mut visible = String::new();
+    let mut used = 0;
+    if offset > 0 {
+        chars.next();
+        visible.push('…');
+        used = 1;
+    }
+    while let Some(&c) = chars.peek() {
+        let char_width = c.width().unwrap_or(0);
+        if used + char_width > width {
+            break;
+        }
+        visible.push(c);
+        used += char_width;
+        chars.next();
+    }
+
+    if chars.peek().is_some() {
+        while used + 1 > width {
+            match visible.pop() {
+                Some(c) => used -= c.width().unwrap_or(0),
+                None => break,
+            }
+        }
+        visible.push('…');
+    }
+    visible
+}
+
+/// Leading characters that fit in `width` cells; never splits a wide character
+fn take_width(s: &str, width: usize) -> String {
+    let mut taken = String::new();
+    let mut used = 0;
+    for c in s.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if used + char_width > width {
+            break;
+        }
+        taken.push(c);
+        used += char_width;
+    }
+    taken
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wide_characters_are_measured_in_cells() {
+        assert_eq!(display_width("注文-db"), 7);
+        assert_eq!(truncate_to_width("注文データベース", 9), "注文デ...");
+        assert_eq!(pad_to_width("注文", 6), "注文  ");
+        assert_eq!(pad_to_width("orders-db", 4), "o...");
+
+        let sliced = slice_to_width("キュー🚀orders", 2, 6);
+        assert_eq!(sliced, "…🚀or…");
+        assert_eq!(display_width(&sliced), 6);
+    }
+
+    #[test]
+    fn test_locale_conventions() {
+        let locale = LocaleConfig {
+            decimal_separator: ',',
+            clock: Clock::H12,
+            date_order: DateOrder::Dmy,
+        };
+        let time = chrono::TimeZone::with_ymd_and_hms(&Local, 2024, 3, 5, 14, 7, 9).unwrap();
+
+        assert_eq!(locale.number(72.456, 1), "72,5");
+        assert_eq!(locale.time(&time, false), "2:07 PM");
+        assert_eq!(locale.short_date(&time), "05/03");
+        assert_eq!(LocaleConfig::default().time(&time, true), "14:07:09");
+        assert_eq!(LocaleConfig::default().short_date(&time), "03-05");
+    }
+}
@@ -0,0 +1,4 @@
+// Shared helpers that are not tied to AWS or rendering
+pub mod clipboard;
+pub mod export;
+pub mod formatting;
@@ -0,0 +1,159 @@
+// Shared watchlists - curated sets of critical resources
+//
+// A team publishes a watchlist to an SSM parameter (String or SecureString)
+// as TOML, keyed by service:
+//
+//   rds = ["orders-db", "payments-db"]
+//   sqs = ["orders", "orders-dlq"]
+//   synthetics = ["checkout-flow"]
+//   eventbridge = ["nightly-export", "orders-bus/order-placed"]
+//   batch = ["etl-high-priority"]
+//   lambda = ["orders-worker"]
+//
+// An entry can be qualified with a region, as in "eu-west-1:orders-db", to
+// watch that resource only there when listing all regions; a bare ID is
+// watched in every region.
+//
+// The last fetched value is cached locally so the list is available at
+// startup and when Parameter Store cannot be reached. Local `include` and
+// `exclude` lists from the config file are applied on top.
+
+use crate::models::ServiceInstance;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+const CACHE_FILE: &str = "watchlist.toml";
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Watchlist {
+    pub rds: BTreeSet<String>,
+    pub sqs: BTreeSet<String>,
+    pub synthetics: BTreeSet<String>,
+    pub eventbridge: BTreeSet<String>,
+    pub batch: BTreeSet<String>,
+    pub lambda: BTreeSet<String>,
+}
+
+impl Watchlist {
+    pub fn parse(contents: &str) -> Result<Self> {
+        Ok(toml::from_str(contents)?)
+    }
+
+    /// Watchlist from the local cache, or an empty one if nothing was cached yet
+    pub fn load_cached() -> Self {
+        cache_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| Self::parse(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// Store the raw parameter value so the next start has it without a fetch
+    pub fn save_cache(contents: &str) -> Result<()> {
+        let Some(path) = cache_path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        std::fs::write(&path, contents)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Add local includes and drop local excludes
+    pub fn with_overrides(mut self, include: &Watchlist, exclude: &Watchlist) -> Self {
+        self.rds.extend(include.rds.iter().cloned());
+        self.sqs.extend(include.sqs.iter().cloned());
+        self.synthetics.extend(include.synthetics.iter().cloned());
+        self.eventbridge.extend(include.eventbridge.iter().cloned());
+        self.batch.extend(include.batch.iter().cloned());
+        self.lambda.extend(include.lambda.iter().cloned());
+        self.rds.retain(|id| !exclude.rds.contains(id));
+        self.sqs.retain(|id| !exclude.sqs.contains(id));
+        self.synthetics
+            .retain(|id| !exclude.synthetics.contains(id));
+        self.eventbridge
+            .retain(|id| !exclude.eventbridge.contains(id));
+        self.batch.retain(|id| !exclude.batch.contains(id));
+        self.lambda.retain(|id| !exclude.lambda.contains(id));
+        self
+    }
+
+    /// Whether `instance`, listed in `region` when the list spans all regions, is watched
+    pub fn contains(&self, instance: &ServiceInstance, region: Option<&str>) -> bool {
+        let (entries, id) = match instance {
+            ServiceInstance::Rds(instance) => (&self.rds, &instance.identifier),
+            ServiceInstance::Sqs(queue) => (&self.sqs, &queue.name),
+            ServiceInstance::Canary(canary) => (&self.synthetics, &canary.name),
+            ServiceInstance::EventRule(rule) => (&self.eventbridge, &rule.id),
+            ServiceInstance::BatchQueue(queue) => (&self.batch, &queue.name),
+            ServiceInstance::Function(function) => (&self.lambda, &function.name),
+        };
+        entries.contains(id)
+            || region.is_some_and(|region| entries.contains(&format!("{region}:{id}")))
+    }
+
+    pub fn len(&self) -> usize {
+        self.rds.len()
+            + self.sqs.len()
+            + self.synthetics.len()
+            + self.eventbridge.len()
+            + self.batch.len()
+            + self.lambda.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Location of the cached parameter value (e.g. `~/.cache/awscw/watchlist.toml`)
+fn cache_path() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("awscw").join(CACHE_FILE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SqsQueue;
+
+    #[test]
+    fn test_overrides_apply_on_top_of_shared_list() {
+        let shared = Watchlist::parse(
+            r#"
+                rds = ["orders-db", "legacy-db"]
+                sqs = ["orders"]
+            "#,
+        )
+        .unwrap();
+        let include = Watchlist::parse(r#"sqs = ["my-dev-queue"]"#).unwrap();
+        let exclude = Watchlist::parse(r#"rds = ["legacy-db"]"#).unwrap();
+
+        let watchlist = shared.with_overrides(&include, &exclude);
+        assert_eq!(watchlist.rds, BTreeSet::from(["orders-db".to_string()]));
+        assert_eq!(watchlist.len(), 3);
+        assert!(watchlist.contains(
+            &ServiceInstance::Sqs(SqsQueue {
+                name: "my-dev-queue".to_string(),
+                url: String::new(),
+                attributes: Vec::new(),
+                error: None,
+            }),
+            None
+        ));
+
+        let regional = Watchlist::parse(r#"sqs = ["eu-west-1:orders"]"#).unwrap();
+        let orders = ServiceInstance::Sqs(SqsQueue {
+            name: "orders".to_string(),
+            url: String::new(),
+            attributes: Vec::new(),
+            error: None,
+        });
+        assert!(regional.contains(&orders, Some("eu-west-1")));
+        assert!(!regional.contains(&orders, Some("us-east-1")));
+        assert!(!regional.contains(&orders, None));
+    }
+}